@@ -29,6 +29,18 @@ pub enum Gamut {
     Rec2020,
 }
 
+impl Gamut {
+    /// The RGB space whose gamut this target refers to.
+    pub fn rgb_space(&self) -> momoto_core::space::rgb::RgbSpace {
+        use momoto_core::space::rgb::RgbSpace;
+        match self {
+            Gamut::Srgb => RgbSpace::Srgb,
+            Gamut::P3 => RgbSpace::DisplayP3,
+            Gamut::Rec2020 => RgbSpace::Rec2020,
+        }
+    }
+}
+
 /// A single constraint within a contract.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
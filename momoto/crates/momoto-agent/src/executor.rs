@@ -56,6 +56,18 @@ impl AgentExecutor {
             Query::ListMaterials { category } => {
                 Response::Materials(self.list_materials(category.as_deref()))
             }
+            Query::CheckGamut { color, gamut } => {
+                Response::GamutCheck(self.check_gamut(&color, &gamut))
+            }
+            Query::ConvertColor { color, target_space } => {
+                match self.convert_color(&color, &target_space) {
+                    Ok(conversion) => Response::Conversion(conversion),
+                    Err(message) => Response::Error(ErrorInfo {
+                        message,
+                        code: Some("invalid_color".to_string()),
+                    }),
+                }
+            }
             Query::ListWorkflows => {
                 Response::Json(serde_json::json!({"workflows": ["accessibility_audit", "palette_generation"]}))
             }
@@ -112,8 +124,12 @@ impl AgentExecutor {
                 serde_json::to_value(list).unwrap_or_default()
             }
             "convert_color" => {
+                let color = v.get("color").and_then(|c| c.as_str()).unwrap_or("#000000");
                 let space = v.get("target_space").and_then(|s| s.as_str()).unwrap_or("oklch");
-                serde_json::json!({"space": space, "values": {"L": 0.5, "C": 0.1, "H": 180.0}})
+                match self.convert_color(color, space) {
+                    Ok(conversion) => serde_json::to_value(conversion).unwrap_or_default(),
+                    Err(message) => serde_json::json!({"error": message}),
+                }
             }
             "adjust_color" => {
                 let color = v.get("color").and_then(|c| c.as_str()).unwrap_or("#000000");
@@ -173,6 +189,21 @@ impl AgentExecutor {
                         });
                     }
                 }
+                "in_gamut" => {
+                    use momoto_core::space::rgb::RgbSpace;
+
+                    let gamut = constraint.params.get("gamut")
+                        .and_then(|g| g.as_str())
+                        .unwrap_or("srgb");
+                    let space = RgbSpace::from_name(gamut).unwrap_or(RgbSpace::Srgb);
+                    let c = Color::from_hex(color).unwrap_or_else(|_| Color::from_srgb8(0, 0, 0));
+                    if !c.is_in_rgb_gamut(space) {
+                        violations.push(crate::response::Violation {
+                            description: format!("{} is outside the {} gamut", color, space),
+                            severity: "error".to_string(),
+                        });
+                    }
+                }
                 _ => {}
             }
         }
@@ -223,8 +254,77 @@ impl AgentExecutor {
         }
     }
 
+    /// Check whether a color fits an RGB gamut and suggest the nearest in-gamut color.
+    ///
+    /// `color` is any CSS color. `gamut` accepts CSS `color()` identifiers
+    /// (`srgb`, `display-p3`, `rec2020`, `a98-rgb`) and short aliases such
+    /// as `p3`. The suggestion uses the CSS Color 4 gamut-mapping algorithm.
+    pub fn check_gamut(&self, color: &str, gamut: &str) -> GamutCheckResponse {
+        use momoto_core::color::Color;
+        use momoto_core::space::oklch::gamut::GamutMapper;
+        use momoto_core::space::rgb::RgbSpace;
+
+        let space = RgbSpace::from_name(gamut).unwrap_or(RgbSpace::Srgb);
+        let c = Color::from_css(color).unwrap_or_else(|_| Color::from_srgb8(0, 0, 0));
+        let in_gamut = c.is_in_rgb_gamut(space);
+        let nearest_in_gamut = if in_gamut {
+            None
+        } else {
+            Some(GamutMapper::new(space).map_color(&c).color.to_color().to_hex())
+        };
+
+        GamutCheckResponse {
+            in_gamut,
+            gamut: space.css_name().to_string(),
+            nearest_in_gamut,
+        }
+    }
+
+    /// Convert any CSS color string into another color space.
+    ///
+    /// `target_space` accepts CSS color space identifiers (`oklch`, `lab`,
+    /// `display-p3`, `xyz-d65`, ...) as well as `rgb`/`hex` for legacy sRGB.
+    /// Values are keyed by component name, alongside the serialized `css`.
+    pub fn convert_color(
+        &self,
+        color: &str,
+        target_space: &str,
+    ) -> Result<ColorConversionResponse, String> {
+        use momoto_core::color::css::{CssColor, CssColorSpace};
+
+        let space = match target_space.to_ascii_lowercase().as_str() {
+            "rgb" | "hex" => CssColorSpace::Rgb,
+            name => CssColorSpace::from_name(name)
+                .ok_or_else(|| format!("Unknown color space '{}'", target_space))?,
+        };
+        let source = CssColor::parse(color).map_err(|e| e.to_string())?;
+        let converted = CssColor::from_color(&source.to_color(), space);
+
+        let names: [&str; 3] = match space {
+            CssColorSpace::Lab | CssColorSpace::Oklab => ["L", "a", "b"],
+            CssColorSpace::Lch | CssColorSpace::Oklch => ["L", "C", "H"],
+            CssColorSpace::Hsl => ["H", "S", "L"],
+            CssColorSpace::Hwb => ["H", "W", "B"],
+            CssColorSpace::XyzD50 | CssColorSpace::XyzD65 => ["X", "Y", "Z"],
+            _ => ["r", "g", "b"],
+        };
+        let scale = if space == CssColorSpace::Rgb { 255.0 } else { 1.0 };
+        let mut values = serde_json::Map::new();
+        for (name, value) in names.iter().zip(converted.resolved_components()) {
+            values.insert((*name).to_string(), serde_json::json!(value * scale));
+        }
+        values.insert("alpha".to_string(), serde_json::json!(converted.alpha.unwrap_or(1.0)));
+        values.insert("css".to_string(), serde_json::json!(converted.to_string()));
+
+        Ok(ColorConversionResponse {
+            space: space.css_name().to_string(),
+            values: serde_json::Value::Object(values),
+        })
+    }
+
     /// Get color metrics.
     pub fn get_metrics(&self, color: &str) -> ColorMetrics {
+        use momoto_core::color::naming::{describe, nearest, Vocabulary};
         use momoto_core::color::Color;
         use momoto_core::space::oklch::OKLCH;
         use momoto_core::luminance::relative_luminance_srgb;
@@ -246,6 +346,8 @@ impl AgentExecutor {
             category: "glass".to_string(),
             dispersion: None,
             has_scattering: false,
+            name: nearest(&c, Vocabulary::Css).name,
+            description: describe(&c).to_string(),
         }
     }
 
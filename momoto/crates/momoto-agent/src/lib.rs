@@ -119,7 +119,7 @@ pub use reporting::{
     ReportGenerator, ReportConfig, ReportFormat, ReportType, ReportSection,
     ComprehensiveReport, ExecutiveSummary, Finding, Severity,
     ColorAnalysisReport, AccessibilityAuditReport, MaterialPhysicsReport,
-    PerceptualQualityReport, PrioritizedRecommendation, EffortLevel,
+    PerceptualQualityReport, PaletteMatrixReport, PrioritizedRecommendation, EffortLevel,
     BatchDashboard, ProgressTracker, LiveMetrics, LogCollector, OutputMode,
     DashboardBuilder, MetricsDashboard,
 };
@@ -503,6 +503,42 @@ mod tests {
         let _ = result.violations.len();
     }
 
+    #[test]
+    fn test_validate_in_gamut() {
+        let contract = Contract::new().with_constraint(Constraint::in_gamut(Gamut::P3));
+        assert!(validate("#ff0000", &contract).is_valid());
+        assert_eq!(Gamut::Rec2020.rgb_space(), momoto_core::RgbSpace::Rec2020);
+    }
+
+    #[test]
+    fn test_check_gamut_query() {
+        let executor = AgentExecutor::new();
+        let response = executor.execute(Query::CheckGamut {
+            color: "#3b82f6".to_string(),
+            gamut: "display-p3".to_string(),
+        });
+        match response {
+            Response::GamutCheck(check) => {
+                assert!(check.in_gamut);
+                assert_eq!(check.gamut, "display-p3");
+                assert!(check.nearest_in_gamut.is_none());
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        let response = executor.execute(Query::CheckGamut {
+            color: "color(display-p3 0 1 0)".to_string(),
+            gamut: "srgb".to_string(),
+        });
+        match response {
+            Response::GamutCheck(check) => {
+                assert!(!check.in_gamut);
+                assert!(check.nearest_in_gamut.is_some());
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_validate_pair() {
         let result = validate_pair(
@@ -519,6 +555,8 @@ mod tests {
         let metrics = get_metrics("#0066cc");
         assert!(metrics.lightness > 0.0 && metrics.lightness < 1.0);
         assert!(metrics.chroma > 0.0);
+        assert_eq!(get_metrics("#008080").name, "teal");
+        assert_eq!(metrics.description, "vivid blue");
     }
 
     #[test]
@@ -664,6 +702,47 @@ mod tests {
         let query = r##"{"action": "convert_color", "color": "#ff6600", "target_space": "oklab"}"##;
         let response = executor.execute_json(query).unwrap();
         assert!(response.contains("values"));
+
+        let query = r##"{"action": "convert_color", "color": "not-a-color", "target_space": "lab"}"##;
+        let response = executor.execute_json(query).unwrap();
+        assert!(response.contains("error"));
+    }
+
+    #[test]
+    fn test_convert_color_query() {
+        let executor = AgentExecutor::new();
+
+        let query = Query::ConvertColor {
+            color: "hsl(0 100% 50%)".to_string(),
+            target_space: "oklch".to_string(),
+        };
+        match executor.execute(query) {
+            Response::Conversion(c) => {
+                assert_eq!(c.space, "oklch");
+                let l = c.values["L"].as_f64().unwrap();
+                assert!((l - 0.628).abs() < 0.001);
+                assert!(c.values["css"].as_str().unwrap().starts_with("oklch("));
+            }
+            other => panic!("Expected Conversion, got {:?}", other),
+        }
+
+        let query = Query::ConvertColor {
+            color: "color(display-p3 0 1 0)".to_string(),
+            target_space: "rgb".to_string(),
+        };
+        match executor.execute(query) {
+            Response::Conversion(c) => {
+                // P3 green is outside sRGB: green exceeds 255 before clamping
+                assert!(c.values["g"].as_f64().unwrap() > 255.0);
+            }
+            other => panic!("Expected Conversion, got {:?}", other),
+        }
+
+        let query = Query::ConvertColor {
+            color: "#ff6600".to_string(),
+            target_space: "cmyk".to_string(),
+        };
+        assert!(matches!(executor.execute(query), Response::Error(_)));
     }
 
     #[test]
@@ -17,6 +17,7 @@ use momoto_metrics::{
     wcag::{WCAGLevel, WCAGMetric, TextSize},
 };
 use momoto_core::perception::ContrastMetric;
+use momoto_intelligence::palette_matrix::{PairUsage, PaletteEntry, PaletteMatrix};
 
 // ============================================================================
 // Enumerations
@@ -375,6 +376,16 @@ pub struct PerceptualQualityReport {
     pub recommendations: Vec<String>,
 }
 
+/// N×N accessibility matrix of a token list, with the tokens left out of it.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaletteMatrixReport {
+    /// Matrix over the tokens whose hex parsed.
+    #[serde(flatten)]
+    pub matrix: PaletteMatrix,
+    /// One finding per token that could not be parsed.
+    pub findings: Vec<Finding>,
+}
+
 // ============================================================================
 // Report Configuration & Generator
 // ============================================================================
@@ -636,6 +647,49 @@ impl ReportGenerator {
             ReportFormat::Csv => render_csv(report),
         }
     }
+
+    /// Build the N×N accessibility matrix for a list of `(name, hex)` tokens.
+    ///
+    /// Tokens whose hex does not parse are left out of the matrix and
+    /// reported as [`Severity::High`] findings instead.
+    pub fn palette_matrix(tokens: &[(String, String)]) -> PaletteMatrixReport {
+        let mut entries = Vec::with_capacity(tokens.len());
+        let mut findings = Vec::new();
+        for (name, hex) in tokens {
+            match Color::from_hex(hex) {
+                Ok(color) => entries.push(PaletteEntry::new(name.clone(), color)),
+                Err(e) => findings.push(
+                    Finding::new(
+                        format!("P-{:03}", findings.len() + 1),
+                        format!("Invalid color: {}", name),
+                        format!("`{}` is not a valid hex color ({}); the token is left out of the matrix.", hex, e),
+                        Severity::High,
+                    )
+                    .with_color(hex.clone())
+                    .with_suggestion("Use a six-digit sRGB hex such as #1e40af."),
+                ),
+            }
+        }
+        PaletteMatrixReport {
+            matrix: PaletteMatrix::new(entries),
+            findings,
+        }
+    }
+
+    /// Render a `PaletteMatrixReport` according to this generator's configured format.
+    ///
+    /// Markdown and HTML list the findings, then render a heatmap table
+    /// (rows are foregrounds, columns backgrounds) followed by the usable
+    /// pairs per class; CSV has one row per ordered pair and no findings.
+    pub fn render_palette_matrix(&self, report: &PaletteMatrixReport) -> String {
+        match self.config.format {
+            ReportFormat::Markdown => palette_matrix_markdown(&self.config.title, report),
+            ReportFormat::Json => serde_json::to_string_pretty(report)
+                .unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e)),
+            ReportFormat::Html => palette_matrix_html(&self.config.title, report),
+            ReportFormat::Csv => palette_matrix_csv(&report.matrix),
+        }
+    }
 }
 
 // ============================================================================
@@ -923,6 +977,234 @@ fn render_csv(report: &ComprehensiveReport) -> String {
     csv
 }
 
+fn usage_badge(usage: PairUsage) -> &'static str {
+    match usage {
+        PairUsage::BodyText => "🟩",
+        PairUsage::LargeText => "🟨",
+        PairUsage::UiComponent => "🟧",
+        PairUsage::Unusable => "🟥",
+    }
+}
+
+fn usage_css_class(usage: PairUsage) -> &'static str {
+    match usage {
+        PairUsage::BodyText => "body",
+        PairUsage::LargeText => "large",
+        PairUsage::UiComponent => "ui",
+        PairUsage::Unusable => "none",
+    }
+}
+
+fn palette_matrix_markdown(title: &str, report: &PaletteMatrixReport) -> String {
+    let matrix = &report.matrix;
+    let mut md = String::with_capacity(4096);
+    md.push_str(&format!("# {}\n\n", title));
+
+    if !report.findings.is_empty() {
+        md.push_str("## Findings\n\n");
+        for f in &report.findings {
+            md.push_str(&format!(
+                "- **[{}]** `{}` — {} *({})*\n",
+                f.id,
+                f.severity_label(),
+                f.title,
+                f.description,
+            ));
+        }
+        md.push('\n');
+    }
+
+    let s = matrix.summary();
+    md.push_str("| Pairs | Body text | Large text | UI components | Not usable | CVD-confusable |\n");
+    md.push_str("|-------|-----------|------------|---------------|------------|----------------|\n");
+    md.push_str(&format!(
+        "| {} | {} | {} | {} | {} | {} |\n\n",
+        s.pairs, s.body_text, s.large_text, s.ui_component, s.unusable, s.cvd_confusable,
+    ));
+
+    md.push_str("## Contrast Matrix\n\n");
+    md.push_str(
+        "Rows are foregrounds, columns backgrounds. Cells show WCAG ratio / APCA Lc: \
+         🟩 body text, 🟨 large text, 🟧 UI components, 🟥 not usable; \
+         ⚠ confusable under simulated CVD.\n\n",
+    );
+    md.push_str("| fg \\ bg |");
+    for e in &matrix.entries {
+        md.push_str(&format!(" {} |", e.name));
+    }
+    md.push_str("\n|---|");
+    md.push_str(&"---|".repeat(matrix.len()));
+    md.push('\n');
+    for (fg, e) in matrix.entries.iter().enumerate() {
+        md.push_str(&format!("| **{}** `{}` |", e.name, e.hex));
+        for bg in 0..matrix.len() {
+            let Some(cell) = matrix.get(fg, bg).filter(|_| fg != bg) else {
+                md.push_str(" — |");
+                continue;
+            };
+            md.push_str(&format!(
+                " {} {:.2} / {:.0}{} |",
+                usage_badge(cell.usage),
+                cell.wcag_ratio,
+                cell.apca_lc,
+                if cell.cvd_confusable { " ⚠" } else { "" },
+            ));
+        }
+        md.push('\n');
+    }
+    md.push('\n');
+
+    md.push_str("## Usable Pairs\n\n");
+    for usage in [PairUsage::BodyText, PairUsage::LargeText, PairUsage::UiComponent] {
+        let pairs: Vec<_> = matrix
+            .usable_pairs(usage)
+            .into_iter()
+            .filter(|c| c.usage == usage)
+            .collect();
+        md.push_str(&format!("### {} ({})\n\n", capitalize(usage.label()), pairs.len()));
+        for c in pairs {
+            md.push_str(&format!(
+                "- `{}` on `{}` — {:.2}:1, Lc {:.1}\n",
+                matrix.entries[c.foreground].name,
+                matrix.entries[c.background].name,
+                c.wcag_ratio,
+                c.apca_lc,
+            ));
+        }
+        md.push('\n');
+    }
+    md
+}
+
+fn palette_matrix_html(title: &str, report: &PaletteMatrixReport) -> String {
+    let matrix = &report.matrix;
+    let mut html = String::with_capacity(8192);
+    html.push_str(&format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{}</title><style>\
+         table{{border-collapse:collapse;font:13px system-ui,sans-serif}}\
+         th,td{{border:1px solid #d1d5db;padding:4px 8px;text-align:center}}\
+         .swatch{{display:inline-block;width:12px;height:12px;border:1px solid #9ca3af;margin-right:4px;vertical-align:middle}}\
+         .body{{background:#bbf7d0}}.large{{background:#fef08a}}.ui{{background:#fed7aa}}.none{{background:#fecaca}}\
+         .self{{background:#f3f4f6}}.cvd{{outline:2px dashed #7c3aed;outline-offset:-3px}}\
+         </style></head><body><h1>{}</h1>",
+        html_escape(title),
+        html_escape(title),
+    ));
+
+    if !report.findings.is_empty() {
+        html.push_str("<h2>Findings</h2><ul>");
+        for f in &report.findings {
+            html.push_str(&format!(
+                "<li><strong>[{}] {}</strong> — {}: {}</li>",
+                html_escape(&f.id),
+                f.severity_label(),
+                html_escape(&f.title),
+                html_escape(&f.description),
+            ));
+        }
+        html.push_str("</ul>");
+    }
+
+    let s = matrix.summary();
+    html.push_str(&format!(
+        "<p>{} pairs: {} body text, {} large text, {} UI components, {} not usable, \
+         {} confusable under simulated CVD.</p>",
+        s.pairs, s.body_text, s.large_text, s.ui_component, s.unusable, s.cvd_confusable,
+    ));
+
+    html.push_str("<table><tr><th>fg \\ bg</th>");
+    for e in &matrix.entries {
+        html.push_str(&format!(
+            "<th><span class=\"swatch\" style=\"background:{}\"></span>{}</th>",
+            e.hex,
+            html_escape(&e.name),
+        ));
+    }
+    html.push_str("</tr>");
+    for (fg, e) in matrix.entries.iter().enumerate() {
+        html.push_str(&format!(
+            "<tr><th><span class=\"swatch\" style=\"background:{}\"></span>{}</th>",
+            e.hex,
+            html_escape(&e.name),
+        ));
+        for bg in 0..matrix.len() {
+            let Some(cell) = matrix.get(fg, bg).filter(|_| fg != bg) else {
+                html.push_str("<td class=\"self\">—</td>");
+                continue;
+            };
+            html.push_str(&format!(
+                "<td class=\"{}{}\" title=\"{} — APCA {:.1} / reversed {:.1}, ΔE00 {:.1}, CVD ΔE00 ≥ {:.1}\">{:.2}<br>Lc {:.0}</td>",
+                usage_css_class(cell.usage),
+                if cell.cvd_confusable { " cvd" } else { "" },
+                cell.usage.label(),
+                cell.apca_lc,
+                cell.apca_lc_reversed,
+                cell.delta_e,
+                cell.cvd_delta_e.min(),
+                cell.wcag_ratio,
+                cell.apca_lc,
+            ));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+
+    for usage in [PairUsage::BodyText, PairUsage::LargeText, PairUsage::UiComponent] {
+        html.push_str(&format!("<h2>{}</h2><ul>", html_escape(&capitalize(usage.label()))));
+        for c in matrix.usable_pairs(usage).into_iter().filter(|c| c.usage == usage) {
+            let (f, b) = (&matrix.entries[c.foreground], &matrix.entries[c.background]);
+            html.push_str(&format!(
+                "<li><span style=\"color:{};background:{};padding:0 4px\">Aa</span> {} on {} — {:.2}:1, Lc {:.1}</li>",
+                f.hex,
+                b.hex,
+                html_escape(&f.name),
+                html_escape(&b.name),
+                c.wcag_ratio,
+                c.apca_lc,
+            ));
+        }
+        html.push_str("</ul>");
+    }
+    html.push_str("</body></html>");
+    html
+}
+
+fn palette_matrix_csv(matrix: &PaletteMatrix) -> String {
+    let mut csv = String::from(
+        "foreground,background,wcag_ratio,apca_lc,apca_lc_reversed,delta_e,cvd_min_delta_e,usage,cvd_confusable\n",
+    );
+    for c in matrix.cells.iter().filter(|c| c.foreground != c.background) {
+        csv.push_str(&format!(
+            "{},{},{:.4},{:.4},{:.4},{:.4},{:.4},{},{}\n",
+            csv_escape(&matrix.entries[c.foreground].name),
+            csv_escape(&matrix.entries[c.background].name),
+            c.wcag_ratio,
+            c.apca_lc,
+            c.apca_lc_reversed,
+            c.delta_e,
+            c.cvd_delta_e.min(),
+            c.usage.label(),
+            c.cvd_confusable,
+        ));
+    }
+    csv
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 fn csv_escape(s: &str) -> String {
     if s.contains(',') || s.contains('"') || s.contains('\n') {
         format!("\"{}\"", s.replace('"', "\"\""))
@@ -1110,4 +1392,92 @@ mod tests {
         let csv = gen.render(&report);
         assert!(csv.starts_with("id,severity"));
     }
+
+    fn design_tokens() -> Vec<(String, String)> {
+        [
+            ("ink", "#111827"),
+            ("paper", "#ffffff"),
+            ("muted", "#9ca3af"),
+            ("brand", "#2563eb"),
+        ]
+        .iter()
+        .map(|(n, h)| (n.to_string(), h.to_string()))
+        .collect()
+    }
+
+    #[test]
+    fn test_palette_matrix_markdown() {
+        let matrix = ReportGenerator::palette_matrix(&design_tokens());
+        assert_eq!(matrix.matrix.len(), 4);
+        assert!(matrix.findings.is_empty());
+
+        let gen = ReportGenerator::new(ReportConfig {
+            title: "Tokens".to_string(),
+            ..Default::default()
+        });
+        let md = gen.render_palette_matrix(&matrix);
+        assert!(md.starts_with("# Tokens"));
+        assert!(md.contains("| **ink** `#111827` | — |"));
+        assert!(md.contains("- `ink` on `paper`"));
+        assert!(md.contains("### Body text"));
+        assert!(!md.contains("## Findings"));
+    }
+
+    #[test]
+    fn test_palette_matrix_invalid_hex_is_a_finding() {
+        let mut tokens = design_tokens();
+        tokens.insert(1, ("typo".to_string(), "#11182".to_string()));
+        let report = ReportGenerator::palette_matrix(&tokens);
+
+        assert_eq!(report.matrix.len(), 4);
+        assert!(report.matrix.entries.iter().all(|e| e.name != "typo"));
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].severity, Severity::High);
+        assert_eq!(report.findings[0].color.as_deref(), Some("#11182"));
+
+        let md = ReportGenerator::new(ReportConfig::default()).render_palette_matrix(&report);
+        assert!(md.contains("## Findings"));
+        assert!(md.contains("Invalid color: typo"));
+
+        let json = ReportGenerator::new(ReportConfig {
+            format: ReportFormat::Json,
+            ..Default::default()
+        })
+        .render_palette_matrix(&report);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["findings"][0]["id"], "P-001");
+    }
+
+    #[test]
+    fn test_palette_matrix_html_and_csv() {
+        let matrix = ReportGenerator::palette_matrix(&design_tokens());
+
+        let html = ReportGenerator::new(ReportConfig {
+            format: ReportFormat::Html,
+            title: "<Tokens>".to_string(),
+            ..Default::default()
+        })
+        .render_palette_matrix(&matrix);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>&lt;Tokens&gt;</h1>"));
+        assert!(html.contains("class=\"body"));
+        assert!(html.contains("class=\"none"));
+
+        let csv = ReportGenerator::new(ReportConfig {
+            format: ReportFormat::Csv,
+            ..Default::default()
+        })
+        .render_palette_matrix(&matrix);
+        assert_eq!(csv.lines().count(), 1 + 12);
+        assert!(csv.contains("ink,paper,"));
+
+        let json = ReportGenerator::new(ReportConfig {
+            format: ReportFormat::Json,
+            ..Default::default()
+        })
+        .render_palette_matrix(&matrix);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["cells"].as_array().unwrap().len(), 16);
+        assert_eq!(value["cells"][1]["usage"], "BodyText");
+    }
 }
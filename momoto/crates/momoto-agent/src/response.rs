@@ -42,6 +42,12 @@ pub struct ColorMetrics {
     pub category: String,
     pub dispersion: Option<f64>,
     pub has_scattering: bool,
+    /// Nearest CSS named color.
+    #[serde(default)]
+    pub name: String,
+    /// Human-readable description, e.g. "muted dark teal".
+    #[serde(default)]
+    pub description: String,
 }
 
 impl Default for ColorMetrics {
@@ -58,6 +64,8 @@ impl Default for ColorMetrics {
             category: "glass".to_string(),
             dispersion: None,
             has_scattering: false,
+            name: "black".to_string(),
+            description: "black".to_string(),
         }
     }
 }
@@ -0,0 +1,217 @@
+//! Jetpack Compose source backend.
+//!
+//! Emits Kotlin source for Compose Material 3:
+//!
+//! - [`RenderBackend::render`] turns an `EvaluatedMaterial` into an
+//!   `object` holding the Display P3 tint `Color`, the specular sheen
+//!   `Brush`, the Fresnel edge color and the blur radius in `dp`.
+//! - [`ComposeBackend::render_scheme`] turns a [`DynamicScheme`] into a
+//!   `lightColorScheme(…)` / `darkColorScheme(…)` value.
+//!
+//! ## Example
+//!
+//! ```
+//! use momoto_core::{
+//!     backend::compose::ComposeBackend,
+//!     evaluated::{Evaluable, MaterialContext},
+//!     material::GlassMaterial,
+//!     render::{RenderBackend, RenderContext},
+//! };
+//!
+//! let evaluated = GlassMaterial::regular().evaluate(&MaterialContext::default());
+//! let kotlin = ComposeBackend::new()
+//!     .render(&evaluated, &RenderContext::mobile())
+//!     .unwrap();
+//!
+//! assert!(kotlin.contains("object MomotoGlass {"));
+//! assert!(kotlin.contains("colorSpace = ColorSpaces.DisplayP3"));
+//! ```
+
+use super::native;
+use crate::{
+    evaluated::EvaluatedMaterial,
+    render::{
+        BackendCapabilities, PerformanceCharacteristics, RenderBackend, RenderContext, RenderError,
+    },
+    space::hct::dynamic::DynamicScheme,
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Jetpack Compose source backend.
+#[derive(Debug, Clone)]
+pub struct ComposeBackend {
+    /// Prefix for generated declarations.
+    namespace: String,
+}
+
+impl ComposeBackend {
+    /// Create new Compose backend with the `Momoto` namespace.
+    pub fn new() -> Self {
+        Self {
+            namespace: "Momoto".to_string(),
+        }
+    }
+
+    /// Use a custom declaration prefix (`{namespace}Glass`,
+    /// `{namespace}LightColorScheme`).
+    ///
+    /// Characters that cannot appear in an identifier are dropped, as are
+    /// leading digits; a namespace with nothing left is ignored.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        let namespace = native::identifier(namespace);
+        if !namespace.is_empty() {
+            self.namespace = namespace;
+        }
+        self
+    }
+
+    /// Material 3 `ColorScheme` parameter for a role, if Compose has one.
+    ///
+    /// Compose has no `shadow` or fixed-accent slots.
+    fn scheme_parameter(role: &str) -> Option<String> {
+        if role == "shadow" || role.contains("fixed") {
+            None
+        } else {
+            Some(native::camel_case(role))
+        }
+    }
+
+    /// Emit a `lightColorScheme` or `darkColorScheme` value for `scheme`.
+    pub fn render_scheme(&self, scheme: &DynamicScheme) -> String {
+        let (mode, builder) = if scheme.is_dark {
+            ("Dark", "darkColorScheme")
+        } else {
+            ("Light", "lightColorScheme")
+        };
+        let mut out = String::new();
+        let _ = writeln!(out, "import androidx.compose.material3.{}", builder);
+        out.push_str("import androidx.compose.ui.graphics.Color\n\n");
+        let _ = writeln!(
+            out,
+            "/** {:?} scheme from #{:06X}, contrast level {:.1}. */",
+            scheme.variant,
+            scheme.source_color_argb & 0x00ff_ffff,
+            scheme.contrast_level
+        );
+        let _ = writeln!(
+            out,
+            "val {}{}ColorScheme = {}(",
+            self.namespace, mode, builder
+        );
+        for (role, argb) in native::scheme_roles(scheme) {
+            if let Some(parameter) = Self::scheme_parameter(role) {
+                let _ = writeln!(out, "    {} = Color(0x{:08X}),", parameter, argb);
+            }
+        }
+        out.push_str(")\n");
+        out
+    }
+}
+
+impl Default for ComposeBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderBackend for ComposeBackend {
+    type Output = String;
+
+    fn name(&self) -> &'static str {
+        "compose"
+    }
+
+    fn render(
+        &self,
+        material: &EvaluatedMaterial,
+        _context: &RenderContext,
+    ) -> Result<Self::Output, RenderError> {
+        let [r, g, b] = native::display_p3(&material.base_color);
+        let blur = native::blur_radius(material);
+        let edge = native::edge_highlight(material);
+        let sheen = native::sheen(material);
+
+        let mut out = String::from(
+            "import androidx.compose.ui.graphics.Brush\n\
+             import androidx.compose.ui.graphics.Color\n\
+             import androidx.compose.ui.graphics.colorspace.ColorSpaces\n\
+             import androidx.compose.ui.unit.dp\n\n",
+        );
+        let _ = writeln!(
+            out,
+            "/** {:?} material: blur {:.1}dp, opacity {:.2}. */",
+            material.material_type, blur, material.opacity
+        );
+        let _ = writeln!(out, "object {}Glass {{", self.namespace);
+        let _ = writeln!(
+            out,
+            "    val Tint = Color(red = {:.4}f, green = {:.4}f, blue = {:.4}f, alpha = {:.3}f, colorSpace = ColorSpaces.DisplayP3)",
+            r, g, b, material.opacity
+        );
+        let _ = writeln!(
+            out,
+            "    val Sheen = Brush.linearGradient(listOf(Color.White.copy(alpha = {:.3}f), Color.Transparent))",
+            sheen
+        );
+        let _ = writeln!(out, "    val Edge = Color.White.copy(alpha = {:.3}f)", edge);
+        out.push_str("    val EdgeWidth = 1.dp\n");
+        let _ = writeln!(out, "    val BlurRadius = {:.1}.dp", blur);
+        out.push_str("}\n");
+        Ok(out)
+    }
+
+    fn supports(&self, _context: &RenderContext) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        let mut features = HashMap::new();
+        features.insert("backdrop-filter".to_string(), false);
+        features.insert("display-p3".to_string(), true);
+        features.insert("hdr".to_string(), false);
+
+        BackendCapabilities {
+            name: "Compose".to_string(),
+            output_formats: vec!["text/x-kotlin".to_string()],
+            features,
+            performance: PerformanceCharacteristics {
+                render_time_us: 2.0,
+                batch_speedup: 1.0,
+                memory_per_material_bytes: 768,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluated::{Evaluable, MaterialContext};
+    use crate::material::GlassMaterial;
+    use crate::space::hct::dynamic::Variant;
+
+    #[test]
+    fn test_compose_material_object() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let kotlin = ComposeBackend::new()
+            .render(&evaluated, &RenderContext::mobile())
+            .unwrap();
+
+        assert!(kotlin.contains("object MomotoGlass {"));
+        assert!(kotlin.contains("val BlurRadius = 25.7.dp"));
+        assert!(kotlin.contains("Brush.linearGradient"));
+    }
+
+    #[test]
+    fn test_compose_scheme_parameters() {
+        let scheme = DynamicScheme::from_argb(0xff0000ff, Variant::TonalSpot, false, 0.0);
+        let kotlin = ComposeBackend::new().render_scheme(&scheme);
+
+        assert!(kotlin.contains("val MomotoLightColorScheme = lightColorScheme("));
+        assert!(kotlin.contains("    primary = Color(0xFF555992),"));
+        assert!(!kotlin.contains("shadow"));
+        assert!(!kotlin.contains("Fixed"));
+        assert_eq!(kotlin.matches(" = Color(0x").count(), 36);
+    }
+}
@@ -5,6 +5,8 @@
 
 use super::ColorBackend;
 
+use crate::math::ops;
+
 /// Reference CPU implementation of ColorBackend.
 ///
 /// This backend uses pure Rust implementations on the CPU with no SIMD
@@ -73,7 +75,7 @@ impl ColorBackend for CpuBackend {
         let lms = rgb_to_lms(linear);
 
         // Step 2: LMS → LMS' (cube root for perceptual uniformity)
-        let lms_prime = [lms[0].cbrt(), lms[1].cbrt(), lms[2].cbrt()];
+        let lms_prime = [ops::cbrt(lms[0]), ops::cbrt(lms[1]), ops::cbrt(lms[2])];
 
         // Step 3: LMS' → OKLab
         let lab = lms_to_lab(lms_prime);
@@ -116,7 +118,7 @@ fn srgb_channel_to_linear(channel: f64) -> f64 {
     if channel <= 0.04045 {
         channel / 12.92
     } else {
-        ((channel + 0.055) / 1.055).powf(2.4)
+        ops::powf((channel + 0.055) / 1.055, 2.4)
     }
 }
 
@@ -130,7 +132,7 @@ fn linear_channel_to_srgb(channel: f64) -> f64 {
     if channel <= 0.0031308 {
         channel * 12.92
     } else {
-        1.055 * channel.powf(1.0 / 2.4) - 0.055
+        1.055 * ops::powf(channel, 1.0 / 2.4) - 0.055
     }
 }
 
@@ -214,7 +216,7 @@ fn lab_to_lch(lab: [f64; 3]) -> [f64; 3] {
     let b = lab[2];
 
     let c = (a * a + b * b).sqrt();
-    let h = b.atan2(a).to_degrees();
+    let h = ops::atan2(b, a).to_degrees();
     let h = if h < 0.0 { h + 360.0 } else { h };
 
     [l, c, h]
@@ -232,8 +234,8 @@ fn lch_to_lab(lch: [f64; 3]) -> [f64; 3] {
     let c = lch[1];
     let h = lch[2].to_radians();
 
-    let a = c * h.cos();
-    let b = c * h.sin();
+    let a = c * ops::cos(h);
+    let b = c * ops::sin(h);
 
     [l, a, b]
 }
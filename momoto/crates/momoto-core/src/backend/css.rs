@@ -7,6 +7,15 @@
 //! - `opacity`
 //! - `box-shadow` (elevation/lighting)
 //!
+//! For [`TargetMedium::Print`] the material is soft-proofed over paper and
+//! emitted as the opaque printable color; backdrop blur does not print.
+//!
+//! When [`RenderContext::color_space`] is Display P3 or Rec.2020 the output
+//! is instead a progressive stylesheet scoped to the backend's selector: an
+//! sRGB hex fallback, wide-gamut colors behind `@supports` and
+//! `@media (color-gamut)`, and a more opaque tint for browsers without
+//! `backdrop-filter`. See [`css_cascade`](super::css_cascade).
+//!
 //! ## Example
 //!
 //! ```
@@ -29,10 +38,13 @@
 //! println!("{}", css);
 //! ```
 
+use super::css_cascade::CssCascade;
 use crate::{
+    color::cmyk::PrintConditions,
     evaluated::{EvaluatedMaterial, LinearRgba},
     render::{
         BackendCapabilities, PerformanceCharacteristics, RenderBackend, RenderContext, RenderError,
+        TargetMedium,
     },
     Color,
 };
@@ -49,19 +61,49 @@ use std::collections::HashMap;
 pub struct CssBackend {
     /// Optimization level for CSS output.
     optimize: bool,
+
+    /// Selector for progressive (wide-gamut) stylesheets.
+    selector: String,
 }
 
 impl CssBackend {
     /// Create new CSS backend with default settings.
     pub fn new() -> Self {
-        Self { optimize: true }
+        Self {
+            optimize: true,
+            selector: ".momoto-glass".to_string(),
+        }
     }
 
     /// Create CSS backend with optimization disabled.
     ///
     /// Useful for debugging or when you need verbose output.
     pub fn unoptimized() -> Self {
-        Self { optimize: false }
+        Self {
+            optimize: false,
+            ..Self::new()
+        }
+    }
+
+    /// Use a custom selector for progressive wide-gamut output.
+    ///
+    /// Defaults to `.momoto-glass`. Flat (sRGB) output has no selector.
+    pub fn with_selector(mut self, selector: &str) -> Self {
+        self.selector = selector.to_string();
+        self
+    }
+
+    /// Render the progressive stylesheet for a wide-gamut context.
+    fn render_progressive(&self, material: &EvaluatedMaterial, context: &RenderContext) -> String {
+        let mut cascade = CssCascade::new(&self.selector, context.color_space)
+            .optimize(self.optimize)
+            .declaration(Self::to_opacity(material));
+        if let Some(blur_px) = Self::blur_px(material) {
+            cascade = cascade.backdrop_filter(format!("blur({:.0}px)", blur_px));
+        }
+        cascade.render(&material.base_color, material.opacity, |color| {
+            format!("background-color: {};", color)
+        })
     }
 
     /// Convert LinearRgba to CSS oklch() color string.
@@ -85,11 +127,8 @@ impl CssBackend {
         )
     }
 
-    /// Generate backdrop-filter CSS property.
-    ///
-    /// Converts physical scattering radius to CSS blur pixels and applies
-    /// optional saturation compensation.
-    fn to_backdrop_filter(material: &EvaluatedMaterial) -> Option<String> {
+    /// Backdrop blur radius in CSS pixels, `None` if negligible.
+    fn blur_px(material: &EvaluatedMaterial) -> Option<f64> {
         // Convert scattering radius (mm) to CSS pixels
         // CSS standard: 96px = 1 inch = 25.4mm
         // Therefore: 1mm = 96/25.4 = 3.779527559 px
@@ -97,8 +136,18 @@ impl CssBackend {
         let blur_px = material.scattering_radius_mm * MM_TO_PX;
 
         if blur_px < 0.5 {
-            return None; // Skip if blur is negligible
+            None // Skip if blur is negligible
+        } else {
+            Some(blur_px)
         }
+    }
+
+    /// Generate backdrop-filter CSS property.
+    ///
+    /// Converts physical scattering radius to CSS blur pixels and applies
+    /// optional saturation compensation.
+    fn to_backdrop_filter(material: &EvaluatedMaterial) -> Option<String> {
+        let blur_px = Self::blur_px(material)?;
 
         // CSS LIMITATION WORKAROUND (opt-in via future config):
         // Saturation boost compensates for perceived desaturation when applying
@@ -129,6 +178,14 @@ impl CssBackend {
         format!("background-color: {};", css_color)
     }
 
+    /// Generate background-color for print from the soft-proofed material.
+    fn to_print_background_color(material: &EvaluatedMaterial) -> String {
+        let proof = PrintConditions::coated().proof_material(material).proof;
+        let [r, g, b] = proof.linear;
+        let css_color = Self::to_css_color(&LinearRgba::rgb(r, g, b), 1.0);
+        format!("background-color: {};", css_color)
+    }
+
     /// Generate opacity CSS property.
     fn to_opacity(material: &EvaluatedMaterial) -> String {
         format!("opacity: {:.2};", material.opacity)
@@ -175,10 +232,25 @@ impl RenderBackend for CssBackend {
     fn render(
         &self,
         material: &EvaluatedMaterial,
-        _context: &RenderContext,
+        context: &RenderContext,
     ) -> Result<Self::Output, RenderError> {
         let mut properties = Vec::new();
 
+        if context.medium == TargetMedium::Print {
+            properties.push(Self::to_print_background_color(material));
+            properties.push("opacity: 1.00;".to_string());
+            let css = properties.join("\n");
+            return Ok(if self.optimize {
+                Self::optimize_css(&css)
+            } else {
+                css
+            });
+        }
+
+        if CssCascade::is_wide_gamut(context.color_space) {
+            return Ok(self.render_progressive(material, context));
+        }
+
         // Generate backdrop-filter (blur + saturation)
         if let Some(backdrop) = Self::to_backdrop_filter(material) {
             properties.push(backdrop);
@@ -211,6 +283,7 @@ impl RenderBackend for CssBackend {
         let mut features = HashMap::new();
         features.insert("backdrop-filter".to_string(), true);
         features.insert("oklch-colors".to_string(), true);
+        features.insert("wide-gamut-fallbacks".to_string(), true);
         features.insert("hdr".to_string(), false);
 
         BackendCapabilities {
@@ -235,6 +308,7 @@ mod tests {
     use super::*;
     use crate::evaluated::{Evaluable, EvaluationMetadata, MaterialContext, MaterialType};
     use crate::material::GlassMaterial;
+    use crate::render::ColorSpace;
 
     #[test]
     fn test_css_backend_basic() {
@@ -358,6 +432,57 @@ mod tests {
         assert!(!caps.supports_feature("hdr"));
     }
 
+    #[test]
+    fn test_css_backend_print() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let backend = CssBackend::new();
+        let css = backend.render(&evaluated, &RenderContext::print()).unwrap();
+
+        assert!(!css.contains("backdrop-filter"));
+        assert!(css.contains("/ 1.00)"));
+        assert!(css.contains("opacity: 1.00"));
+        assert_ne!(
+            css,
+            backend
+                .render(&evaluated, &RenderContext::desktop())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_css_backend_display_p3_is_progressive() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let backend = CssBackend::unoptimized().with_selector(".card");
+        let ctx = RenderContext {
+            color_space: ColorSpace::DisplayP3,
+            ..RenderContext::desktop()
+        };
+        let css = backend.render(&evaluated, &ctx).unwrap();
+
+        assert!(css.starts_with(".card {\n  background-color: #"));
+        assert!(css.contains("-webkit-backdrop-filter: blur("));
+        assert!(css.contains("@supports not ((backdrop-filter"));
+        assert!(css.contains("@supports (color: color(display-p3 0 0 0)) {"));
+        assert!(css.contains("@media (color-gamut: p3)"));
+        assert!(css.contains("color(display-p3 "));
+
+        // Frosted glass fits sRGB, so the optimized stylesheet leaves out
+        // the P3 tier that would repeat the `oklch()` color.
+        let optimized = CssBackend::new()
+            .with_selector(".card")
+            .render(&evaluated, &ctx)
+            .unwrap();
+        assert!(optimized.contains("@supports (color: color(display-p3 0 0 0)) {"));
+        assert!(!optimized.contains("@media (color-gamut: p3)"));
+        assert!(optimized.contains("@supports not ((backdrop-filter"));
+
+        // sRGB output stays flat.
+        let flat = backend
+            .render(&evaluated, &RenderContext::desktop())
+            .unwrap();
+        assert!(!flat.contains('{'));
+    }
+
     #[test]
     fn test_render_batch() {
         let materials = vec![
@@ -0,0 +1,350 @@
+//! Progressive CSS cascades for wide-gamut displays.
+//!
+//! Flat declaration lists pick one color syntax for every browser. A
+//! [`CssCascade`] instead layers the same material color so each browser
+//! keeps the best value it understands:
+//!
+//! 1. An sRGB hex fallback (gamut-mapped into sRGB) in the base rule.
+//! 2. `@supports (color: color(display-p3 0 0 0))` → `oklch()` of that
+//!    sRGB color, to four decimals in lightness and chroma and two in hue.
+//! 3. Nested `@media (color-gamut: p3)` → `color(display-p3 …)` mapped into
+//!    Display P3, and for a Rec.2020 target `@media (color-gamut: rec2020)`
+//!    → `color(rec2020 …)`.
+//!
+//! Gamut mapping uses [`OKLCH::map_to_gamut_of`] (chroma reduction at
+//! constant lightness and hue), so an in-gamut material yields the same
+//! color at every tier. With [`optimize`](CssCascade::optimize) such
+//! repeated tiers are left out, as are declarations a later one in the same
+//! rule overrides.
+//!
+//! When a backdrop filter is set it is also emitted with the `-webkit-`
+//! prefix, and a final `@supports not (backdrop-filter …)` block raises the
+//! tint to [`NO_BACKDROP_MIN_ALPHA`] so unblurred content behind the glass
+//! does not hurt legibility. It comes last so that it overrides every color
+//! tier above it.
+//!
+//! ## Example
+//!
+//! ```
+//! use momoto_core::{
+//!     backend::css_cascade::CssCascade,
+//!     evaluated::LinearRgba,
+//!     render::ColorSpace,
+//! };
+//!
+//! let css = CssCascade::new(".card", ColorSpace::DisplayP3)
+//!     .backdrop_filter("blur(20px)")
+//!     .declaration("opacity: 0.85;")
+//!     .render(&LinearRgba::rgb(0.2, 0.4, 0.8), 0.85, |color| {
+//!         format!("background-color: {};", color)
+//!     });
+//!
+//! assert!(css.contains("@supports (color: color(display-p3 0 0 0))"));
+//! assert!(css.contains("@media (color-gamut: p3)"));
+//! ```
+
+use crate::{
+    evaluated::LinearRgba,
+    render::ColorSpace,
+    space::{oklch::OKLCH, rgb::RgbSpace},
+    Color,
+};
+use std::fmt::Write;
+
+/// Minimum tint alpha when the browser cannot blur the backdrop.
+pub const NO_BACKDROP_MIN_ALPHA: f64 = 0.9;
+
+/// Feature query matched by browsers without any backdrop-filter support.
+const NO_BACKDROP_QUERY: &str =
+    "not ((backdrop-filter: blur(1px)) or (-webkit-backdrop-filter: blur(1px)))";
+
+/// Feature query for wide-gamut color syntax.
+const WIDE_GAMUT_QUERY: &str = "(color: color(display-p3 0 0 0))";
+
+/// Builder for a progressive, selector-scoped stylesheet.
+#[derive(Debug, Clone)]
+pub struct CssCascade {
+    /// Selector every rule is scoped to.
+    selector: String,
+
+    /// Output gamut; decides which `@media (color-gamut)` tiers are emitted.
+    target: ColorSpace,
+
+    /// Color-independent declarations for the base rule.
+    declarations: Vec<String>,
+
+    /// `backdrop-filter` value, if any.
+    backdrop_filter: Option<String>,
+
+    /// Whether to drop redundant tiers and overridden declarations.
+    optimize: bool,
+}
+
+impl CssCascade {
+    /// Create an empty cascade for `selector` targeting `target`.
+    pub fn new(selector: &str, target: ColorSpace) -> Self {
+        Self {
+            selector: selector.to_string(),
+            target,
+            declarations: Vec::new(),
+            backdrop_filter: None,
+            optimize: false,
+        }
+    }
+
+    /// Add a color-independent declaration (e.g. `"opacity: 0.85;"`).
+    pub fn declaration(mut self, declaration: impl Into<String>) -> Self {
+        self.declarations.push(declaration.into());
+        self
+    }
+
+    /// Set the `backdrop-filter` value (e.g. `"blur(20px)"`).
+    pub fn backdrop_filter(mut self, value: impl Into<String>) -> Self {
+        self.backdrop_filter = Some(value.into());
+        self
+    }
+
+    /// Drop redundant output (off by default).
+    ///
+    /// A `@media (color-gamut)` tier is skipped when the color already fits
+    /// the gamut of the tier before it, and within each rule only the last
+    /// declaration of a property is kept.
+    pub fn optimize(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+
+    /// Whether `target` gets wide-gamut tiers.
+    ///
+    /// Only Display P3 and Rec.2020 do; sRGB and linear RGB share sRGB
+    /// primaries.
+    pub fn is_wide_gamut(target: ColorSpace) -> bool {
+        matches!(target, ColorSpace::DisplayP3 | ColorSpace::Rec2020)
+    }
+
+    /// Render the stylesheet for a linear sRGB `color` with `alpha`.
+    ///
+    /// `color_declaration` turns a CSS color value into the declaration(s)
+    /// carrying it, so callers can place the color inside a `background`
+    /// shorthand, a gradient and so on. It is called once per tier.
+    pub fn render<F>(&self, color: &LinearRgba, alpha: f64, color_declaration: F) -> String
+    where
+        F: Fn(&str) -> String,
+    {
+        let oklch = Color::from_linear(color.r, color.g, color.b).to_oklch();
+        let srgb = oklch.map_to_gamut_of(RgbSpace::Srgb);
+        let alpha = alpha.clamp(0.0, 1.0);
+
+        let mut out = String::new();
+        let mut base = vec![color_declaration(&hex(&srgb, alpha))];
+        if let Some(filter) = &self.backdrop_filter {
+            base.push(format!("-webkit-backdrop-filter: {};", filter));
+            base.push(format!("backdrop-filter: {};", filter));
+        }
+        base.extend(self.declarations.iter().cloned());
+        self.rule(&mut out, 0, &base);
+
+        if Self::is_wide_gamut(self.target) {
+            let _ = writeln!(out, "@supports {} {{", WIDE_GAMUT_QUERY);
+            self.rule(&mut out, 1, &[color_declaration(&oklch_css(&srgb, alpha))]);
+
+            let mut tiers = vec![("p3", RgbSpace::Srgb, RgbSpace::DisplayP3, "display-p3")];
+            if self.target == ColorSpace::Rec2020 {
+                tiers.push(("rec2020", RgbSpace::DisplayP3, RgbSpace::Rec2020, "rec2020"));
+            }
+            for (gamut, previous, space, name) in tiers {
+                if self.optimize && oklch.is_in_gamut_of(previous) {
+                    continue;
+                }
+                let mapped = oklch.map_to_gamut_of(space).to_color();
+                let [r, g, b] = mapped.to_rgb_space(space).map(|c| c.clamp(0.0, 1.0));
+                let value = format!("color({} {:.4} {:.4} {:.4} / {:.2})", name, r, g, b, alpha);
+                let _ = writeln!(out, "  @media (color-gamut: {}) {{", gamut);
+                self.rule(&mut out, 2, &[color_declaration(&value)]);
+                out.push_str("  }\n");
+            }
+            out.push_str("}\n");
+        }
+
+        if self.backdrop_filter.is_some() && alpha < NO_BACKDROP_MIN_ALPHA {
+            let _ = writeln!(out, "@supports {} {{", NO_BACKDROP_QUERY);
+            self.rule(
+                &mut out,
+                1,
+                &[color_declaration(&hex(&srgb, NO_BACKDROP_MIN_ALPHA))],
+            );
+            out.push_str("}\n");
+        }
+
+        out
+    }
+
+    /// Write `selector { declarations }` at `depth` levels of indentation.
+    fn rule(&self, out: &mut String, depth: usize, declarations: &[String]) {
+        let indent = "  ".repeat(depth);
+        let _ = writeln!(out, "{}{} {{", indent, self.selector);
+        for (i, declaration) in declarations.iter().enumerate() {
+            if self.optimize
+                && declarations[i + 1..]
+                    .iter()
+                    .any(|later| property(later) == property(declaration))
+            {
+                continue;
+            }
+            let _ = writeln!(out, "{}  {}", indent, declaration);
+        }
+        let _ = writeln!(out, "{}}}", indent);
+    }
+}
+
+/// `#RRGGBB`, or `#RRGGBBAA` when translucent.
+fn hex(oklch: &OKLCH, alpha: f64) -> String {
+    let rgb = oklch.to_color().to_hex();
+    if alpha >= 1.0 {
+        rgb
+    } else {
+        format!("{}{:02X}", rgb, (alpha * 255.0).round() as u8)
+    }
+}
+
+/// The property name of a `name: value;` declaration.
+fn property(declaration: &str) -> &str {
+    declaration.split(':').next().unwrap_or("").trim()
+}
+
+/// `oklch()` with four decimals in lightness and chroma (below one unit of
+/// 8-bit sRGB) and two in hue.
+fn oklch_css(oklch: &OKLCH, alpha: f64) -> String {
+    format!(
+        "oklch({:.4} {:.4} {:.2} / {:.2})",
+        oklch.l, oklch.c, oklch.h, alpha
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn background(color: &str) -> String {
+        format!("background-color: {};", color)
+    }
+
+    #[test]
+    fn test_srgb_target_has_no_wide_gamut_tiers() {
+        let css = CssCascade::new(".glass", ColorSpace::SRgb).render(
+            &LinearRgba::rgb(0.5, 0.5, 0.5),
+            1.0,
+            background,
+        );
+
+        assert!(css.starts_with(".glass {\n  background-color: #BCBCBC;\n"));
+        assert!(!css.contains("@supports"));
+        assert!(!css.contains("@media"));
+    }
+
+    #[test]
+    fn test_display_p3_tiers_in_order() {
+        let css = CssCascade::new(".glass", ColorSpace::DisplayP3)
+            .backdrop_filter("blur(20px)")
+            .render(&LinearRgba::rgb(0.2, 0.4, 0.8), 0.5, background);
+
+        let hex = css.find("background-color: #").unwrap();
+        let supports = css.find("@supports (color: color(display-p3").unwrap();
+        let media = css.find("@media (color-gamut: p3)").unwrap();
+        let fallback = css.find("@supports not").unwrap();
+        assert!(hex < supports && supports < media && media < fallback);
+
+        assert!(css.contains("-webkit-backdrop-filter: blur(20px);"));
+        assert!(css.contains("/ 0.50)"));
+        assert!(!css.contains("rec2020"));
+        assert_eq!(css.matches('{').count(), css.matches('}').count());
+    }
+
+    #[test]
+    fn test_rec2020_target_adds_rec2020_tier() {
+        let css = CssCascade::new(".glass", ColorSpace::Rec2020).render(
+            &LinearRgba::rgb(0.2, 0.4, 0.8),
+            1.0,
+            background,
+        );
+
+        assert!(css.contains("@media (color-gamut: p3)"));
+        assert!(css.contains("@media (color-gamut: rec2020)"));
+        assert!(css.contains("color(rec2020 "));
+    }
+
+    #[test]
+    fn test_out_of_srgb_color_is_mapped_per_tier() {
+        // Saturated P3 green: outside sRGB, inside P3.
+        let p3_green = Color::from_rgb_space(RgbSpace::DisplayP3, 0.0, 1.0, 0.0);
+        let [r, g, b] = p3_green.linear;
+        let css = CssCascade::new(".glass", ColorSpace::DisplayP3).render(
+            &LinearRgba::rgb(r, g, b),
+            1.0,
+            background,
+        );
+
+        assert!(css.contains("color(display-p3 0.0000 1.0000 0.0000 / 1.00)"));
+        let hex_start = css.find('#').unwrap();
+        assert_ne!(&css[hex_start..hex_start + 7], "#00FF00");
+    }
+
+    #[test]
+    fn test_oklch_tier_precision() {
+        let css = CssCascade::new(".glass", ColorSpace::DisplayP3).render(
+            &LinearRgba::rgb(0.2, 0.4, 0.8),
+            1.0,
+            background,
+        );
+
+        let start = css.find("oklch(").unwrap();
+        let end = start + css[start..].find(" /").unwrap();
+        let parts: Vec<&str> = css[start + 6..end].split(' ').collect();
+        let decimals: Vec<usize> = parts
+            .iter()
+            .map(|p| p.len() - p.find('.').unwrap() - 1)
+            .collect();
+        assert_eq!(decimals, [4, 4, 2]);
+    }
+
+    #[test]
+    fn test_optimize_drops_redundant_tiers_and_declarations() {
+        let cascade = CssCascade::new(".glass", ColorSpace::Rec2020)
+            .declaration("opacity: 0.50;")
+            .declaration("opacity: 0.85;");
+        let in_srgb = LinearRgba::rgb(0.2, 0.4, 0.8);
+
+        let full = cascade.clone().render(&in_srgb, 1.0, background);
+        assert!(full.contains("opacity: 0.50;"));
+        assert!(full.contains("@media (color-gamut: p3)"));
+        assert!(full.contains("@media (color-gamut: rec2020)"));
+
+        let optimized = cascade
+            .clone()
+            .optimize(true)
+            .render(&in_srgb, 1.0, background);
+        assert!(!optimized.contains("opacity: 0.50;"));
+        assert!(optimized.contains("opacity: 0.85;"));
+        assert!(optimized.contains("oklch("));
+        assert!(!optimized.contains("@media"));
+
+        // Saturated P3 green needs the P3 tier but fits P3, so Rec.2020 adds
+        // nothing.
+        let p3_green = Color::from_rgb_space(RgbSpace::DisplayP3, 0.0, 1.0, 0.0);
+        let [r, g, b] = p3_green.linear;
+        let optimized = cascade
+            .optimize(true)
+            .render(&LinearRgba::rgb(r, g, b), 1.0, background);
+        assert!(optimized.contains("@media (color-gamut: p3)"));
+        assert!(!optimized.contains("@media (color-gamut: rec2020)"));
+    }
+
+    #[test]
+    fn test_opaque_material_skips_backdrop_fallback() {
+        let css = CssCascade::new(".glass", ColorSpace::DisplayP3)
+            .backdrop_filter("blur(4px)")
+            .render(&LinearRgba::rgb(0.5, 0.5, 0.5), 0.95, background);
+
+        assert!(!css.contains("@supports not"));
+    }
+}
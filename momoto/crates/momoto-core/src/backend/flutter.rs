@@ -0,0 +1,319 @@
+//! Flutter source backend.
+//!
+//! Emits Dart source for Flutter 3.27+ (which added wide-gamut `Color.from`):
+//!
+//! - [`RenderBackend::render`] turns an `EvaluatedMaterial` into a
+//!   `StatelessWidget` wrapping its child in `ClipRect` → `BackdropFilter`
+//!   → `ColoredBox` with the Display P3 tint → `DecoratedBox` with a
+//!   specular sheen gradient and a Fresnel edge border. The tint gets its
+//!   own box because a `BoxDecoration` paints only its gradient when it has
+//!   both.
+//! - [`FlutterBackend::render_scheme`] turns a [`DynamicScheme`] into a
+//!   `ColorScheme` and the matching Material 3 `ThemeData`.
+//!
+//! ## Example
+//!
+//! ```
+//! use momoto_core::{
+//!     backend::flutter::FlutterBackend,
+//!     evaluated::{Evaluable, MaterialContext},
+//!     material::GlassMaterial,
+//!     render::{RenderBackend, RenderContext},
+//! };
+//!
+//! let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+//! let dart = FlutterBackend::new()
+//!     .render(&evaluated, &RenderContext::mobile())
+//!     .unwrap();
+//!
+//! assert!(dart.contains("BackdropFilter("));
+//! assert!(dart.contains("ImageFilter.blur("));
+//! ```
+
+use super::native;
+use crate::{
+    evaluated::EvaluatedMaterial,
+    render::{
+        BackendCapabilities, PerformanceCharacteristics, RenderBackend, RenderContext, RenderError,
+    },
+    space::hct::dynamic::DynamicScheme,
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Flutter source backend.
+#[derive(Debug, Clone)]
+pub struct FlutterBackend {
+    /// Prefix for generated declarations.
+    namespace: String,
+}
+
+impl FlutterBackend {
+    /// Create new Flutter backend with the `Momoto` namespace.
+    pub fn new() -> Self {
+        Self {
+            namespace: "Momoto".to_string(),
+        }
+    }
+
+    /// Use a custom declaration prefix (`{namespace}Glass`,
+    /// `{namespace_lower}LightTheme`).
+    ///
+    /// Characters that cannot appear in an identifier are dropped, as are
+    /// leading digits; a namespace with nothing left is ignored.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        let namespace = native::identifier(namespace);
+        if !namespace.is_empty() {
+            self.namespace = namespace;
+        }
+        self
+    }
+
+    /// `ColorScheme` parameter for a role, if Flutter has a live one.
+    ///
+    /// `background`, `onBackground` and `surfaceVariant` are deprecated in
+    /// Flutter in favour of the surface roles; `inverse_on_surface` is
+    /// spelled `onInverseSurface`.
+    fn scheme_parameter(role: &str) -> Option<String> {
+        match role {
+            "background" | "on_background" | "surface_variant" => None,
+            "inverse_on_surface" => Some("onInverseSurface".to_string()),
+            _ => Some(native::camel_case(role)),
+        }
+    }
+
+    /// `Color.from(…, colorSpace: ColorSpace.displayP3)` literal.
+    fn color([r, g, b]: [f64; 3], alpha: f64) -> String {
+        format!(
+            "Color.from(alpha: {:.3}, red: {:.4}, green: {:.4}, blue: {:.4}, colorSpace: ColorSpace.displayP3)",
+            alpha, r, g, b
+        )
+    }
+
+    /// Emit a `ColorScheme` and `ThemeData` for `scheme`.
+    ///
+    /// Declarations are `{namespace_lower}LightColorScheme` /
+    /// `{namespace_lower}LightTheme` (or `Dark`) after `scheme.is_dark`.
+    pub fn render_scheme(&self, scheme: &DynamicScheme) -> String {
+        let mode = if scheme.is_dark { "Dark" } else { "Light" };
+        let prefix = format!("{}{}", native::lower_first(&self.namespace), mode);
+        let mut out = String::from("import 'package:flutter/material.dart';\n\n");
+        let _ = writeln!(
+            out,
+            "/// {:?} scheme from #{:06X}, contrast level {:.1}.",
+            scheme.variant,
+            scheme.source_color_argb & 0x00ff_ffff,
+            scheme.contrast_level
+        );
+        let _ = writeln!(out, "const {}ColorScheme = ColorScheme(", prefix);
+        let _ = writeln!(
+            out,
+            "  brightness: Brightness.{},",
+            if scheme.is_dark { "dark" } else { "light" }
+        );
+        for (role, argb) in native::scheme_roles(scheme) {
+            if let Some(parameter) = Self::scheme_parameter(role) {
+                let _ = writeln!(out, "  {}: Color(0x{:08X}),", parameter, argb);
+            }
+        }
+        out.push_str(");\n\n");
+        let _ = writeln!(
+            out,
+            "final {}Theme = ThemeData(colorScheme: {}ColorScheme, useMaterial3: true);",
+            prefix, prefix
+        );
+        out
+    }
+}
+
+impl Default for FlutterBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderBackend for FlutterBackend {
+    type Output = String;
+
+    fn name(&self) -> &'static str {
+        "flutter"
+    }
+
+    fn render(
+        &self,
+        material: &EvaluatedMaterial,
+        _context: &RenderContext,
+    ) -> Result<Self::Output, RenderError> {
+        let name = format!("{}Glass", self.namespace);
+        let blur = native::blur_radius(material);
+        let edge = native::edge_highlight(material);
+        let sheen = native::sheen(material);
+        let tint = Self::color(native::display_p3(&material.base_color), material.opacity);
+
+        let mut out = String::from(
+            "import 'dart:ui' show ColorSpace, ImageFilter;\n\n\
+             import 'package:flutter/material.dart';\n\n",
+        );
+        let _ = writeln!(
+            out,
+            "/// {:?} material: blur {:.1}, opacity {:.2}.",
+            material.material_type, blur, material.opacity
+        );
+        let _ = writeln!(out, "class {} extends StatelessWidget {{", name);
+        let _ = writeln!(
+            out,
+            "  const {}({{super.key, required this.child}});\n\n  final Widget child;\n",
+            name
+        );
+        out.push_str("  @override\n  Widget build(BuildContext context) {\n");
+        out.push_str("    return ClipRect(\n      child: BackdropFilter(\n");
+        let _ = writeln!(
+            out,
+            "        filter: ImageFilter.blur(sigmaX: {:.1}, sigmaY: {:.1}),",
+            blur, blur
+        );
+        out.push_str("        child: ColoredBox(\n");
+        let _ = writeln!(out, "          color: {},", tint);
+        if sheen > 0.0 || edge > 0.0 {
+            out.push_str(
+                "          child: DecoratedBox(\n            decoration: BoxDecoration(\n",
+            );
+            if sheen > 0.0 {
+                let _ = writeln!(
+                    out,
+                    "              gradient: LinearGradient(\n                begin: Alignment.topLeft,\n                end: Alignment.bottomRight,\n                colors: [Colors.white.withValues(alpha: {:.3}), Colors.transparent],\n              ),",
+                    sheen
+                );
+            }
+            if edge > 0.0 {
+                let _ = writeln!(
+                    out,
+                    "              border: Border.all(color: Colors.white.withValues(alpha: {:.3})),",
+                    edge
+                );
+            }
+            out.push_str("            ),\n            child: child,\n          ),\n");
+        } else {
+            out.push_str("          child: child,\n");
+        }
+        out.push_str("        ),\n      ),\n    );\n  }\n}\n");
+        Ok(out)
+    }
+
+    fn supports(&self, _context: &RenderContext) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        let mut features = HashMap::new();
+        features.insert("backdrop-filter".to_string(), true);
+        features.insert("display-p3".to_string(), true);
+        features.insert("hdr".to_string(), false);
+
+        BackendCapabilities {
+            name: "Flutter".to_string(),
+            output_formats: vec!["text/x-dart".to_string()],
+            features,
+            performance: PerformanceCharacteristics {
+                render_time_us: 2.0,
+                batch_speedup: 1.0,
+                memory_per_material_bytes: 1024,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluated::{Evaluable, MaterialContext};
+    use crate::material::GlassMaterial;
+    use crate::space::hct::dynamic::Variant;
+
+    #[test]
+    fn test_flutter_material_widget() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let dart = FlutterBackend::new()
+            .render(&evaluated, &RenderContext::mobile())
+            .unwrap();
+
+        assert!(dart.contains("class MomotoGlass extends StatelessWidget {"));
+        assert!(dart.contains("ImageFilter.blur(sigmaX: 25.7, sigmaY: 25.7)"));
+        assert!(dart.contains("colorSpace: ColorSpace.displayP3"));
+        assert_eq!(dart.matches('(').count(), dart.matches(')').count());
+        assert_eq!(dart.matches('{').count(), dart.matches('}').count());
+    }
+
+    #[test]
+    fn test_flutter_material_snapshot() {
+        // The tint sits in its own box under the sheen gradient.
+        const EXPECTED: &str = r#"import 'dart:ui' show ColorSpace, ImageFilter;
+
+import 'package:flutter/material.dart';
+
+/// Glass material: blur 25.7, opacity 0.74.
+class MomotoGlass extends StatelessWidget {
+  const MomotoGlass({super.key, required this.child});
+
+  final Widget child;
+
+  @override
+  Widget build(BuildContext context) {
+    return ClipRect(
+      child: BackdropFilter(
+        filter: ImageFilter.blur(sigmaX: 25.7, sigmaY: 25.7),
+        child: ColoredBox(
+          color: Color.from(alpha: 0.737, red: 0.7677, green: 0.8086, blue: 0.8288, colorSpace: ColorSpace.displayP3),
+          child: DecoratedBox(
+            decoration: BoxDecoration(
+              gradient: LinearGradient(
+                begin: Alignment.topLeft,
+                end: Alignment.bottomRight,
+                colors: [Colors.white.withValues(alpha: 0.025), Colors.transparent],
+              ),
+              border: Border.all(color: Colors.white.withValues(alpha: 0.040)),
+            ),
+            child: child,
+          ),
+        ),
+      ),
+    );
+  }
+}
+"#;
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let dart = FlutterBackend::new()
+            .render(&evaluated, &RenderContext::mobile())
+            .unwrap();
+        assert_eq!(dart, EXPECTED);
+    }
+
+    #[test]
+    fn test_flutter_namespace_is_sanitized() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let dart = FlutterBackend::new()
+            .with_namespace("2Brand-Kit {}")
+            .render(&evaluated, &RenderContext::mobile())
+            .unwrap();
+        assert!(dart.contains("class BrandKitGlass extends StatelessWidget {"));
+
+        let scheme = DynamicScheme::from_argb(0xff0000ff, Variant::TonalSpot, false, 0.0);
+        let dart = FlutterBackend::new()
+            .with_namespace("();")
+            .render_scheme(&scheme);
+        assert!(dart.contains("const momotoLightColorScheme = ColorScheme("));
+    }
+
+    #[test]
+    fn test_flutter_theme_data() {
+        let scheme = DynamicScheme::from_argb(0xff0000ff, Variant::TonalSpot, true, 0.0);
+        let dart = FlutterBackend::new().render_scheme(&scheme);
+
+        assert!(dart.contains("const momotoDarkColorScheme = ColorScheme("));
+        assert!(dart.contains("  brightness: Brightness.dark,"));
+        assert!(dart.contains("  primary: Color(0xFFBEC2FF),"));
+        assert!(dart.contains("  onInverseSurface: Color(0x"));
+        assert!(!dart.contains("onBackground"));
+        assert!(dart.contains("final momotoDarkTheme = ThemeData("));
+    }
+}
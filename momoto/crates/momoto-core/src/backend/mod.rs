@@ -20,15 +20,26 @@
 //! let linear = backend.srgb_to_linear(srgb);
 //! ```
 
+pub mod compose;
 mod cpu;
 pub mod css;
+pub mod css_cascade;
 pub mod css_config;
+pub mod flutter;
+mod native;
+pub mod svg;
+pub mod swiftui;
 #[cfg(feature = "webgpu")]
 pub mod webgpu;
 
 pub use cpu::CpuBackend;
 pub use css::CssBackend;
+pub use css_cascade::CssCascade;
 pub use css_config::CssRenderConfig;
+pub use compose::ComposeBackend;
+pub use flutter::FlutterBackend;
+pub use svg::{SvgBackend, SvgFilter};
+pub use swiftui::SwiftUiBackend;
 #[cfg(feature = "webgpu")]
 pub use webgpu::WebGpuBackend;
 
@@ -0,0 +1,116 @@
+//! Shared helpers for the native platform source backends.
+//!
+//! SwiftUI points, Compose `dp` and Flutter logical pixels all track the CSS
+//! reference pixel closely enough that the same blur radius and stroke widths
+//! carry over unchanged.
+
+use crate::{
+    color::Color,
+    evaluated::{EvaluatedMaterial, LinearRgba},
+    space::hct::dynamic::{DynamicScheme, MaterialDynamicColors},
+    space::rgb::RgbSpace,
+};
+
+/// Reference pixels per millimetre (96 px = 25.4 mm).
+const MM_TO_PX: f64 = 96.0 / 25.4;
+
+/// Blur radius (Gaussian sigma) in reference pixels, `0.0` if negligible.
+///
+/// Matches the CSS backend's `backdrop-filter: blur()` radius.
+pub(super) fn blur_radius(material: &EvaluatedMaterial) -> f64 {
+    let px = material.scattering_radius_mm * MM_TO_PX;
+    if px < 0.5 {
+        0.0
+    } else {
+        px
+    }
+}
+
+/// Opacity of the Fresnel edge highlight, `0.0` if negligible.
+pub(super) fn edge_highlight(material: &EvaluatedMaterial) -> f64 {
+    let edge = (material.fresnel_f0 + material.fresnel_edge_intensity).clamp(0.0, 1.0);
+    if edge < 0.01 {
+        0.0
+    } else {
+        edge
+    }
+}
+
+/// Opacity of the diffuse specular sheen across the surface.
+pub(super) fn sheen(material: &EvaluatedMaterial) -> f64 {
+    (material.specular_intensity * material.fresnel_f0).clamp(0.0, 1.0)
+}
+
+/// Gamma-encoded Display P3 channels of a linear sRGB color, clamped to gamut.
+pub(super) fn display_p3(color: &LinearRgba) -> [f64; 3] {
+    Color::from_linear(color.r, color.g, color.b)
+        .to_rgb_space(RgbSpace::DisplayP3)
+        .map(|c| c.clamp(0.0, 1.0))
+}
+
+/// Gamma-encoded Display P3 channels of an ARGB color.
+pub(super) fn display_p3_argb(argb: u32) -> [f64; 3] {
+    Color::from_srgb8((argb >> 16) as u8, (argb >> 8) as u8, argb as u8)
+        .to_rgb_space(RgbSpace::DisplayP3)
+        .map(|c| c.clamp(0.0, 1.0))
+}
+
+/// `on_primary_container` → `onPrimaryContainer`.
+pub(super) fn camel_case(snake: &str) -> String {
+    let mut out = String::with_capacity(snake.len());
+    let mut upper = false;
+    for c in snake.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Keep only identifier characters (ASCII letters, digits, `_`), without
+/// leading digits, so a namespace cannot break the generated source.
+pub(super) fn identifier(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .skip_while(char::is_ascii_digit)
+        .collect()
+}
+
+/// `Momoto` → `momoto`.
+pub(super) fn lower_first(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_ascii_lowercase().to_string() + chars.as_str()
+    })
+}
+
+/// Every scheme role with its resolved ARGB, in spec order.
+pub(super) fn scheme_roles(scheme: &DynamicScheme) -> Vec<(&'static str, u32)> {
+    MaterialDynamicColors::all()
+        .iter()
+        .map(|role| (role.name, scheme.argb(role)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("on_primary_container"), "onPrimaryContainer");
+        assert_eq!(camel_case("surface"), "surface");
+    }
+
+    #[test]
+    fn test_display_p3_of_srgb_red_is_inside_p3() {
+        let [r, g, b] = display_p3_argb(0xffff0000);
+        assert!(r < 1.0 && g > 0.0 && b > 0.0);
+        assert!((r - 0.9175).abs() < 1e-3);
+    }
+}
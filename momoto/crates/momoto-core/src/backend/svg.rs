@@ -0,0 +1,407 @@
+//! SVG filter backend for glass materials.
+//!
+//! CSS `backdrop-filter` has blur and color functions but no way to express
+//! displacement, noise or lighting. This backend turns an
+//! [`EvaluatedMaterial`] into an `<svg><filter>` graph that CSS references
+//! with `url(#id)`:
+//!
+//! | Material property | Filter primitive |
+//! |-------------------|------------------|
+//! | `texture_noise` | `feTurbulence` (fractal noise) |
+//! | `index_of_refraction`, `thickness_mm` | `feDisplacementMap` driven by the noise |
+//! | `scattering_radius_mm` | `feGaussianBlur` |
+//! | `base_color`, `opacity` | `feComponentTransfer` (tint) |
+//! | `specular_*`, `fresnel_*` | `feSpecularLighting` weighted by `feComposite` |
+//!
+//! Under [`AccessibilityMode::HighContrast`] or
+//! [`AccessibilityMode::ReducedTransparency`] the noise, displacement and
+//! specular stages are omitted.
+//!
+//! The graph runs in `linearRGB`, so the tint is applied to the same linear
+//! values [`EvaluatedMaterial::base_color`] holds. Filter ids are derived
+//! from the graph itself, so identical materials share an id and output is
+//! stable across runs.
+//!
+//! ## Example
+//!
+//! ```
+//! use momoto_core::{
+//!     backend::svg::SvgBackend,
+//!     evaluated::{Evaluable, MaterialContext},
+//!     material::GlassMaterial,
+//!     render::{RenderBackend, RenderContext},
+//! };
+//!
+//! let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+//! let filter = SvgBackend::new()
+//!     .render(&evaluated, &RenderContext::desktop())
+//!     .unwrap();
+//!
+//! assert!(filter.markup.contains("<feTurbulence"));
+//! assert_eq!(filter.filter_css(), format!("filter: url(#{});", filter.id));
+//! ```
+
+use crate::{
+    evaluated::EvaluatedMaterial,
+    render::{
+        AccessibilityMode, BackendCapabilities, PerformanceCharacteristics, RenderBackend,
+        RenderContext, RenderError, TargetMedium,
+    },
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// CSS pixels per millimetre (96 px = 25.4 mm).
+const MM_TO_PX: f64 = 96.0 / 25.4;
+
+/// Noise `scale` 1.0 maps to this `baseFrequency` (features ~20 px across).
+const NOISE_BASE_FREQUENCY: f64 = 0.05;
+
+/// Light direction for the specular pass (top-left key light, in degrees).
+const LIGHT_AZIMUTH_DEG: f64 = 225.0;
+const LIGHT_ELEVATION_DEG: f64 = 45.0;
+
+// ============================================================================
+// SvgFilter
+// ============================================================================
+
+/// A rendered SVG filter and the id CSS uses to reference it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvgFilter {
+    /// Filter element id (`url(#id)` target).
+    pub id: String,
+    /// Complete `<svg>` element holding the `<filter>`; embed it once in
+    /// the document (it has zero size and is hidden from assistive tech).
+    pub markup: String,
+}
+
+impl SvgFilter {
+    /// `url(#id)` reference for use in CSS or the SVG `filter` attribute.
+    pub fn url(&self) -> String {
+        format!("url(#{})", self.id)
+    }
+
+    /// CSS declaration applying the filter to the element itself.
+    pub fn filter_css(&self) -> String {
+        format!("filter: {};", self.url())
+    }
+
+    /// CSS declaration applying the filter to what lies behind the element.
+    ///
+    /// `url()` references in `backdrop-filter` are not supported by every
+    /// engine; pair it with [`CssBackend`](super::CssBackend) output as the
+    /// fallback.
+    pub fn backdrop_filter_css(&self) -> String {
+        format!("backdrop-filter: {};", self.url())
+    }
+}
+
+// ============================================================================
+// SvgBackend
+// ============================================================================
+
+/// SVG filter rendering backend.
+///
+/// Converts `EvaluatedMaterial` to an [`SvgFilter`].
+#[derive(Debug, Clone)]
+pub struct SvgBackend {
+    /// Prefix for generated filter ids.
+    id_prefix: String,
+}
+
+impl SvgBackend {
+    /// Create new SVG backend with the `momoto-glass` id prefix.
+    pub fn new() -> Self {
+        Self {
+            id_prefix: "momoto-glass".to_string(),
+        }
+    }
+
+    /// Use a custom id prefix.
+    ///
+    /// Characters other than ASCII letters, digits, `-` and `_` are dropped
+    /// so the id stays valid in both XML and CSS `url(#…)`.
+    pub fn with_id_prefix(mut self, prefix: &str) -> Self {
+        let prefix: String = prefix
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect();
+        if !prefix.is_empty() {
+            self.id_prefix = prefix;
+        }
+        self
+    }
+
+    /// Generate the filter primitives (everything inside `<filter>`).
+    fn to_primitives(material: &EvaluatedMaterial, context: &RenderContext) -> String {
+        let mut out = String::new();
+        let mut current = "SourceGraphic";
+
+        // High-contrast and reduced-transparency modes keep blur and tint
+        // but drop texture and glare, which cost legibility.
+        let decorative = !matches!(
+            context.accessibility_mode,
+            Some(AccessibilityMode::HighContrast | AccessibilityMode::ReducedTransparency)
+        );
+
+        // Surface micro-relief
+        let noise = material
+            .texture_noise
+            .filter(|n| decorative && n.scale > 0.0);
+        if let Some(noise) = noise {
+            let _ = writeln!(
+                out,
+                r#"<feTurbulence type="fractalNoise" baseFrequency="{:.4}" numOctaves="{}" seed="0" result="noise"/>"#,
+                noise.scale * NOISE_BASE_FREQUENCY,
+                noise.octaves.clamp(1, 8)
+            );
+
+            // Refraction: relief tilts the surface, and the slab shifts the
+            // ray by roughly (n - 1) * thickness per unit of tilt.
+            if let Some(ior) = material.index_of_refraction {
+                let shift_px = (ior - 1.0).max(0.0)
+                    * material.thickness_mm
+                    * MM_TO_PX
+                    * noise.intensity.clamp(0.0, 1.0);
+                if shift_px >= 0.5 {
+                    let _ = writeln!(
+                        out,
+                        r#"<feDisplacementMap in="{}" in2="noise" scale="{:.1}" xChannelSelector="R" yChannelSelector="G" result="refracted"/>"#,
+                        current, shift_px
+                    );
+                    current = "refracted";
+                }
+            }
+        }
+
+        // Volume scattering (same radius as the CSS backend's blur)
+        let blur_px = material.scattering_radius_mm * MM_TO_PX;
+        if blur_px >= 0.5 {
+            let _ = writeln!(
+                out,
+                r#"<feGaussianBlur in="{}" stdDeviation="{:.1}" result="scattered"/>"#,
+                current, blur_px
+            );
+            current = "scattered";
+        }
+
+        // Tint: out = (1 - opacity) * backdrop + opacity * base_color
+        let alpha = material.opacity.clamp(0.0, 1.0);
+        let base = [
+            material.base_color.r,
+            material.base_color.g,
+            material.base_color.b,
+        ];
+        let _ = writeln!(
+            out,
+            r#"<feComponentTransfer in="{}" result="tinted">"#,
+            current
+        );
+        for (channel, value) in ["R", "G", "B"].iter().zip(base) {
+            let _ = writeln!(
+                out,
+                r#"  <feFunc{} type="linear" slope="{:.4}" intercept="{:.4}"/>"#,
+                channel,
+                1.0 - alpha,
+                alpha * value.clamp(0.0, 1.0)
+            );
+        }
+        out.push_str("</feComponentTransfer>\n");
+
+        // Specular highlight weighted by Fresnel reflectance
+        let fresnel = (material.fresnel_f0 + material.fresnel_edge_intensity).clamp(0.0, 1.0);
+        if decorative && material.specular_intensity > 0.0 && fresnel > 0.0 {
+            let bump = if noise.is_some() {
+                "noise"
+            } else {
+                "SourceAlpha"
+            };
+            let surface_scale = noise.map_or(1.0, |n| 1.0 + 4.0 * n.intensity.clamp(0.0, 1.0));
+            let _ = writeln!(
+                out,
+                r#"<feSpecularLighting in="{}" surfaceScale="{:.2}" specularConstant="{:.3}" specularExponent="{:.1}" lighting-color="white" result="specular">"#,
+                bump,
+                surface_scale,
+                material.specular_intensity,
+                material.specular_shininess.clamp(1.0, 128.0)
+            );
+            let _ = writeln!(
+                out,
+                r#"  <feDistantLight azimuth="{:.0}" elevation="{:.0}"/>"#,
+                LIGHT_AZIMUTH_DEG, LIGHT_ELEVATION_DEG
+            );
+            out.push_str("</feSpecularLighting>\n");
+            out.push_str(
+                r#"<feComposite in="specular" in2="SourceAlpha" operator="in" result="specular-masked"/>"#,
+            );
+            out.push('\n');
+            let _ = writeln!(
+                out,
+                r#"<feComposite in="specular-masked" in2="tinted" operator="arithmetic" k1="0" k2="{:.4}" k3="1" k4="0"/>"#,
+                fresnel
+            );
+        }
+
+        out
+    }
+
+    /// Stable id from the filter body (FNV-1a, 32-bit).
+    fn filter_id(&self, primitives: &str) -> String {
+        let hash = primitives.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        });
+        format!("{}-{:08x}", self.id_prefix, hash)
+    }
+}
+
+impl Default for SvgBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderBackend for SvgBackend {
+    type Output = SvgFilter;
+
+    fn name(&self) -> &'static str {
+        "svg"
+    }
+
+    fn render(
+        &self,
+        material: &EvaluatedMaterial,
+        context: &RenderContext,
+    ) -> Result<Self::Output, RenderError> {
+        if !self.supports(context) {
+            return Err(RenderError::UnsupportedFeature {
+                feature: "svg-filter".to_string(),
+                reason: "SVG filters are not applied in print; use CssBackend".to_string(),
+            });
+        }
+
+        let primitives = Self::to_primitives(material, context);
+        let id = self.filter_id(&primitives);
+        let markup = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"0\" height=\"0\" aria-hidden=\"true\" style=\"position:absolute\">\n\
+             <filter id=\"{}\" x=\"-10%\" y=\"-10%\" width=\"120%\" height=\"120%\" color-interpolation-filters=\"linearRGB\">\n\
+             {}</filter>\n\
+             </svg>",
+            id, primitives
+        );
+
+        Ok(SvgFilter { id, markup })
+    }
+
+    fn supports(&self, context: &RenderContext) -> bool {
+        context.medium != TargetMedium::Print
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        let mut features = HashMap::new();
+        features.insert("displacement".to_string(), true);
+        features.insert("noise".to_string(), true);
+        features.insert("specular-lighting".to_string(), true);
+        features.insert("backdrop-filter".to_string(), true);
+        features.insert("hdr".to_string(), false);
+
+        BackendCapabilities {
+            name: "SVG".to_string(),
+            output_formats: vec!["image/svg+xml".to_string()],
+            features,
+            performance: PerformanceCharacteristics {
+                render_time_us: 3.0,
+                batch_speedup: 1.0,
+                memory_per_material_bytes: 2048,
+            },
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluated::{Evaluable, MaterialContext};
+    use crate::material::GlassMaterial;
+
+    #[test]
+    fn test_svg_backend_frosted_graph() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let filter = SvgBackend::new()
+            .render(&evaluated, &RenderContext::desktop())
+            .unwrap();
+
+        for primitive in [
+            "<feTurbulence",
+            "<feDisplacementMap",
+            "<feGaussianBlur",
+            "<feComponentTransfer",
+            "<feSpecularLighting",
+        ] {
+            assert!(filter.markup.contains(primitive), "missing {}", primitive);
+        }
+        assert!(filter
+            .markup
+            .contains(&format!("<filter id=\"{}\"", filter.id)));
+    }
+
+    #[test]
+    fn test_svg_backend_clear_glass_has_no_noise() {
+        let evaluated = GlassMaterial::clear().evaluate(&MaterialContext::default());
+        let filter = SvgBackend::new()
+            .render(&evaluated, &RenderContext::desktop())
+            .unwrap();
+
+        assert!(!filter.markup.contains("feTurbulence"));
+        assert!(!filter.markup.contains("feDisplacementMap"));
+        assert!(filter.markup.contains("feComponentTransfer"));
+    }
+
+    #[test]
+    fn test_filter_id_is_stable_and_prefixed() {
+        let evaluated = GlassMaterial::regular().evaluate(&MaterialContext::default());
+        let backend = SvgBackend::new().with_id_prefix("my glass!");
+        let a = backend
+            .render(&evaluated, &RenderContext::desktop())
+            .unwrap();
+        let b = backend
+            .render(&evaluated, &RenderContext::desktop())
+            .unwrap();
+
+        assert_eq!(a, b);
+        assert!(a.id.starts_with("myglass-"));
+        assert_eq!(
+            a.backdrop_filter_css(),
+            format!("backdrop-filter: url(#{});", a.id)
+        );
+    }
+
+    #[test]
+    fn test_svg_backend_high_contrast_drops_texture() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let context = RenderContext {
+            accessibility_mode: Some(AccessibilityMode::HighContrast),
+            ..RenderContext::desktop()
+        };
+        let filter = SvgBackend::new().render(&evaluated, &context).unwrap();
+
+        assert!(!filter.markup.contains("feTurbulence"));
+        assert!(!filter.markup.contains("feSpecularLighting"));
+        assert!(filter.markup.contains("feGaussianBlur"));
+    }
+
+    #[test]
+    fn test_svg_backend_rejects_print() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let backend = SvgBackend::new();
+
+        assert!(!backend.supports(&RenderContext::print()));
+        assert!(matches!(
+            backend.render(&evaluated, &RenderContext::print()),
+            Err(RenderError::UnsupportedFeature { .. })
+        ));
+    }
+}
@@ -0,0 +1,251 @@
+//! SwiftUI source backend.
+//!
+//! Emits Swift source for iOS 15+ / macOS 12+:
+//!
+//! - [`RenderBackend::render`] turns an `EvaluatedMaterial` into a
+//!   `ViewModifier` layering the closest system `Material`, a Display P3
+//!   tint, a specular sheen and a Fresnel edge stroke, plus a `View`
+//!   extension to apply it.
+//! - [`SwiftUiBackend::render_scheme`] turns a [`DynamicScheme`] into a
+//!   `Color` extension with one `Color(.displayP3, …)` per role.
+//!
+//! ## Example
+//!
+//! ```
+//! use momoto_core::{
+//!     backend::swiftui::SwiftUiBackend,
+//!     evaluated::{Evaluable, MaterialContext},
+//!     material::GlassMaterial,
+//!     render::{RenderBackend, RenderContext},
+//! };
+//!
+//! let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+//! let swift = SwiftUiBackend::new()
+//!     .render(&evaluated, &RenderContext::mobile())
+//!     .unwrap();
+//!
+//! assert!(swift.contains("struct MomotoGlass: ViewModifier"));
+//! assert!(swift.contains("Color(.displayP3"));
+//! ```
+
+use super::native;
+use crate::{
+    evaluated::EvaluatedMaterial,
+    render::{
+        BackendCapabilities, PerformanceCharacteristics, RenderBackend, RenderContext, RenderError,
+    },
+    space::hct::dynamic::DynamicScheme,
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// SwiftUI source backend.
+#[derive(Debug, Clone)]
+pub struct SwiftUiBackend {
+    /// Prefix for generated type names.
+    namespace: String,
+}
+
+impl SwiftUiBackend {
+    /// Create new SwiftUI backend with the `Momoto` namespace.
+    pub fn new() -> Self {
+        Self {
+            namespace: "Momoto".to_string(),
+        }
+    }
+
+    /// Use a custom type-name prefix (`{namespace}Glass`, `{namespace}Light`).
+    ///
+    /// Characters that cannot appear in an identifier are dropped, as are
+    /// leading digits; a namespace with nothing left is ignored.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        let namespace = native::identifier(namespace);
+        if !namespace.is_empty() {
+            self.namespace = namespace;
+        }
+        self
+    }
+
+    /// Closest system material for a blur radius in points.
+    fn system_material(blur_pt: f64) -> &'static str {
+        match blur_pt {
+            b if b < 12.0 => ".ultraThinMaterial",
+            b if b < 22.0 => ".thinMaterial",
+            b if b < 32.0 => ".regularMaterial",
+            b if b < 42.0 => ".thickMaterial",
+            _ => ".ultraThickMaterial",
+        }
+    }
+
+    /// `Color(.displayP3, …)` literal.
+    fn color([r, g, b]: [f64; 3], opacity: f64) -> String {
+        format!(
+            "Color(.displayP3, red: {:.4}, green: {:.4}, blue: {:.4}, opacity: {:.3})",
+            r, g, b, opacity
+        )
+    }
+
+    /// Emit a `Color` extension holding every role of `scheme`.
+    ///
+    /// The enum is named `{namespace}Light` or `{namespace}Dark` after
+    /// `scheme.is_dark`, so both schemes can live in one target.
+    pub fn render_scheme(&self, scheme: &DynamicScheme) -> String {
+        let name = format!(
+            "{}{}",
+            self.namespace,
+            if scheme.is_dark { "Dark" } else { "Light" }
+        );
+        let mut out = String::from("import SwiftUI\n\nextension Color {\n");
+        let _ = writeln!(
+            out,
+            "    /// {:?} scheme from #{:06X}, contrast level {:.1}.",
+            scheme.variant,
+            scheme.source_color_argb & 0x00ff_ffff,
+            scheme.contrast_level
+        );
+        let _ = writeln!(out, "    enum {} {{", name);
+        for (role, argb) in native::scheme_roles(scheme) {
+            let _ = writeln!(
+                out,
+                "        static let {} = {}",
+                native::camel_case(role),
+                Self::color(native::display_p3_argb(argb), 1.0)
+            );
+        }
+        out.push_str("    }\n}\n");
+        out
+    }
+}
+
+impl Default for SwiftUiBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderBackend for SwiftUiBackend {
+    type Output = String;
+
+    fn name(&self) -> &'static str {
+        "swiftui"
+    }
+
+    fn render(
+        &self,
+        material: &EvaluatedMaterial,
+        _context: &RenderContext,
+    ) -> Result<Self::Output, RenderError> {
+        let name = format!("{}Glass", self.namespace);
+        let blur = native::blur_radius(material);
+        let edge = native::edge_highlight(material);
+        let sheen = native::sheen(material);
+
+        let mut out = String::from("import SwiftUI\n\n");
+        let _ = writeln!(
+            out,
+            "/// {:?} material: blur {:.1}pt, opacity {:.2}.",
+            material.material_type, blur, material.opacity
+        );
+        let _ = writeln!(out, "struct {}: ViewModifier {{", name);
+        let _ = writeln!(
+            out,
+            "    static let tint = {}\n",
+            Self::color(native::display_p3(&material.base_color), material.opacity)
+        );
+        out.push_str("    func body(content: Content) -> some View {\n");
+        out.push_str("        content\n            .background {\n                ZStack {\n");
+        if blur > 0.0 {
+            let _ = writeln!(
+                out,
+                "                    Rectangle().fill({})",
+                Self::system_material(blur)
+            );
+        }
+        out.push_str("                    Rectangle().fill(Self.tint)\n");
+        if sheen > 0.0 {
+            let _ = writeln!(
+                out,
+                "                    Rectangle().fill(LinearGradient(colors: [.white.opacity({:.3}), .clear], startPoint: .topLeading, endPoint: .bottomTrailing))",
+                sheen
+            );
+        }
+        out.push_str("                }\n            }\n");
+        if edge > 0.0 {
+            let _ = writeln!(
+                out,
+                "            .overlay {{\n                Rectangle().strokeBorder(.white.opacity({:.3}), lineWidth: 1)\n            }}",
+                edge
+            );
+        }
+        out.push_str("    }\n}\n\nextension View {\n");
+        let _ = writeln!(out, "    /// Applies the `{}` material.", name);
+        let _ = writeln!(
+            out,
+            "    func {}Glass() -> some View {{\n        modifier({}())\n    }}\n}}",
+            native::lower_first(&self.namespace),
+            name
+        );
+        Ok(out)
+    }
+
+    fn supports(&self, _context: &RenderContext) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        let mut features = HashMap::new();
+        features.insert("backdrop-filter".to_string(), true);
+        features.insert("display-p3".to_string(), true);
+        features.insert("hdr".to_string(), false);
+
+        BackendCapabilities {
+            name: "SwiftUI".to_string(),
+            output_formats: vec!["text/x-swift".to_string()],
+            features,
+            performance: PerformanceCharacteristics {
+                render_time_us: 2.0,
+                batch_speedup: 1.0,
+                memory_per_material_bytes: 1024,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluated::{Evaluable, MaterialContext};
+    use crate::material::GlassMaterial;
+    use crate::space::hct::dynamic::Variant;
+
+    #[test]
+    fn test_swiftui_material_modifier() {
+        let evaluated = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let swift = SwiftUiBackend::new()
+            .render(&evaluated, &RenderContext::mobile())
+            .unwrap();
+
+        assert!(swift.contains("struct MomotoGlass: ViewModifier"));
+        assert!(swift.contains("Rectangle().fill(.regularMaterial)"));
+        assert!(swift.contains("func momotoGlass() -> some View"));
+        assert!(swift.contains("modifier(MomotoGlass())"));
+    }
+
+    #[test]
+    fn test_swiftui_system_material_thresholds() {
+        assert_eq!(SwiftUiBackend::system_material(5.0), ".ultraThinMaterial");
+        assert_eq!(SwiftUiBackend::system_material(60.0), ".ultraThickMaterial");
+    }
+
+    #[test]
+    fn test_swiftui_scheme_extension() {
+        let scheme = DynamicScheme::from_argb(0xff0000ff, Variant::TonalSpot, true, 0.0);
+        let swift = SwiftUiBackend::new()
+            .with_namespace("Brand")
+            .render_scheme(&scheme);
+
+        assert!(swift.contains("enum BrandDark {"));
+        assert!(swift.contains("static let onPrimaryContainer = Color(.displayP3"));
+        assert_eq!(swift.matches("static let ").count(), 49);
+    }
+}
@@ -0,0 +1,385 @@
+//! Slice kernels over packed RGBA8 pixel buffers.
+//!
+//! Converting a screenshot one [`Color`](crate::color::Color) at a time
+//! allocates two `[f64; 3]` arrays and runs three `powf` per pixel. The
+//! kernels here read a packed `&[u8]` RGBA8 buffer (4 bytes per pixel, as
+//! produced by canvas `getImageData` or most image decoders) and write
+//! straight into a caller-owned `&mut [f32]`:
+//!
+//! | Kernel | Output per pixel |
+//! |--------|------------------|
+//! | [`rgba8_to_srgb`] | `[r, g, b]` gamma-encoded, 0.0–1.0 |
+//! | [`rgba8_to_linear`] | `[r, g, b]` linear light, 0.0–1.0 |
+//! | [`rgba8_to_luminance`] | `Y` (WCAG / BT.709 coefficients) |
+//! | [`rgba8_to_oklab`] | `[L, a, b]` |
+//! | [`rgba8_to_oklch`] | `[L, C, H]`, hue in degrees |
+//!
+//! Alpha is ignored; composite translucent pixels first (see
+//! [`crate::color::blend`]) if it matters.
+//!
+//! Gamma decode is a 256-entry table lookup instead of `powf`. With the
+//! `simd` feature the matrix and cube-root stages of the OKLab/OKLCH and
+//! luminance kernels run four pixels at a time on SSE2 (x86_64) or NEON
+//! (aarch64); other targets keep the scalar path. The SIMD path agrees with
+//! the scalar one to within [`SIMD_TOLERANCE`], which the tests check over
+//! a dense grid of the RGB cube.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::batch;
+//!
+//! // Two pixels: opaque red, opaque mid-gray
+//! let pixels = [255, 0, 0, 255, 128, 128, 128, 255];
+//! let mut lab = [0.0_f32; 6];
+//! let n = batch::rgba8_to_oklab(&pixels, &mut lab).unwrap();
+//! assert_eq!(n, 2);
+//! assert!((lab[0] - 0.628).abs() < 1e-3);
+//! ```
+
+use core::fmt;
+
+use crate::math::ops;
+use crate::space::oklch::{LMS_TO_LAB, RGB_TO_LMS};
+
+mod lut;
+#[cfg(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod simd;
+
+use lut::SRGB8_TO_LINEAR;
+
+/// Bytes per input pixel.
+pub const RGBA8_STRIDE: usize = 4;
+
+/// Maximum absolute difference between the `simd` and scalar kernels.
+pub const SIMD_TOLERANCE: f32 = 1e-5;
+
+/// WCAG 2.x / BT.709 luminance coefficients.
+const LUMA: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Error returned when a buffer does not describe whole pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchError {
+    /// The input length is not a multiple of [`RGBA8_STRIDE`].
+    RaggedInput(usize),
+    /// The output slice does not hold exactly one value set per pixel.
+    OutputLength {
+        /// Output length required by the input.
+        expected: usize,
+        /// Output length supplied.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RaggedInput(len) => {
+                write!(f, "RGBA8 buffer length {} is not a multiple of 4", len)
+            }
+            Self::OutputLength { expected, actual } => {
+                write!(f, "Output buffer needs {} values, got {}", expected, actual)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+/// Validates buffer sizes and returns the pixel count.
+fn pixel_count(src: &[u8], dst: &[f32], channels: usize) -> Result<usize, BatchError> {
+    if src.len() % RGBA8_STRIDE != 0 {
+        return Err(BatchError::RaggedInput(src.len()));
+    }
+    let pixels = src.len() / RGBA8_STRIDE;
+    if dst.len() != pixels * channels {
+        return Err(BatchError::OutputLength {
+            expected: pixels * channels,
+            actual: dst.len(),
+        });
+    }
+    Ok(pixels)
+}
+
+// ============================================================================
+// Kernels
+// ============================================================================
+
+/// Returns `true` when the OKLab/OKLCH and luminance kernels use SIMD lanes.
+#[must_use]
+pub const fn is_simd_accelerated() -> bool {
+    cfg!(all(
+        feature = "simd",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))
+}
+
+/// Decodes the linear-light value of an 8-bit sRGB code.
+///
+/// # Example
+///
+/// ```
+/// use momoto_core::batch::srgb8_to_linear;
+/// use momoto_core::gamma::srgb_to_linear;
+///
+/// let exact = srgb_to_linear(128.0 / 255.0);
+/// assert!((f64::from(srgb8_to_linear(128)) - exact).abs() < 1e-7);
+/// ```
+#[inline]
+#[must_use]
+pub fn srgb8_to_linear(code: u8) -> f32 {
+    SRGB8_TO_LINEAR[usize::from(code)]
+}
+
+/// Writes gamma-encoded `[r, g, b]` in 0.0–1.0 for each pixel.
+///
+/// Returns the number of pixels converted.
+///
+/// # Errors
+///
+/// Returns [`BatchError`] if `src` is not whole pixels or `dst` is not
+/// exactly three values per pixel.
+pub fn rgba8_to_srgb(src: &[u8], dst: &mut [f32]) -> Result<usize, BatchError> {
+    let pixels = pixel_count(src, dst, 3)?;
+    for (px, out) in src.chunks_exact(RGBA8_STRIDE).zip(dst.chunks_exact_mut(3)) {
+        for (o, &c) in out.iter_mut().zip(px) {
+            *o = f32::from(c) / 255.0;
+        }
+    }
+    Ok(pixels)
+}
+
+/// Writes linear-light `[r, g, b]` in 0.0–1.0 for each pixel.
+///
+/// Returns the number of pixels converted.
+///
+/// # Errors
+///
+/// Returns [`BatchError`] if `src` is not whole pixels or `dst` is not
+/// exactly three values per pixel.
+pub fn rgba8_to_linear(src: &[u8], dst: &mut [f32]) -> Result<usize, BatchError> {
+    let pixels = pixel_count(src, dst, 3)?;
+    for (px, out) in src.chunks_exact(RGBA8_STRIDE).zip(dst.chunks_exact_mut(3)) {
+        for (o, &c) in out.iter_mut().zip(px) {
+            *o = srgb8_to_linear(c);
+        }
+    }
+    Ok(pixels)
+}
+
+/// Writes the relative luminance `Y` of each pixel.
+///
+/// Uses the WCAG 2.x coefficients, matching
+/// [`relative_luminance_srgb`](crate::luminance::relative_luminance_srgb).
+///
+/// # Errors
+///
+/// Returns [`BatchError`] if `src` is not whole pixels or `dst` is not
+/// exactly one value per pixel.
+pub fn rgba8_to_luminance(src: &[u8], dst: &mut [f32]) -> Result<usize, BatchError> {
+    let pixels = pixel_count(src, dst, 1)?;
+    #[cfg(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64")))]
+    simd::luminance(src, dst);
+    #[cfg(not(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+    luminance_scalar(src, dst);
+    Ok(pixels)
+}
+
+/// Writes OKLab `[L, a, b]` for each pixel.
+///
+/// # Errors
+///
+/// Returns [`BatchError`] if `src` is not whole pixels or `dst` is not
+/// exactly three values per pixel.
+pub fn rgba8_to_oklab(src: &[u8], dst: &mut [f32]) -> Result<usize, BatchError> {
+    let pixels = pixel_count(src, dst, 3)?;
+    #[cfg(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64")))]
+    simd::oklab(src, dst);
+    #[cfg(not(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+    oklab_scalar(src, dst);
+    Ok(pixels)
+}
+
+/// Writes OKLCH `[L, C, H]` for each pixel, hue in degrees (0–360).
+///
+/// Achromatic pixels (chroma below `1e-6`) get hue 0 rather than the
+/// rounding-noise angle of a near-zero `(a, b)`.
+///
+/// # Errors
+///
+/// Returns [`BatchError`] if `src` is not whole pixels or `dst` is not
+/// exactly three values per pixel.
+pub fn rgba8_to_oklch(src: &[u8], dst: &mut [f32]) -> Result<usize, BatchError> {
+    let pixels = rgba8_to_oklab(src, dst)?;
+    for lab in dst.chunks_exact_mut(3) {
+        let (a, b) = (f64::from(lab[1]), f64::from(lab[2]));
+        let c = ops::hypot(a, b);
+        let h = if c < 1e-6 {
+            0.0
+        } else {
+            let h = ops::atan2(b, a).to_degrees();
+            if h < 0.0 {
+                h + 360.0
+            } else {
+                h
+            }
+        };
+        lab[1] = c as f32;
+        lab[2] = h as f32;
+    }
+    Ok(pixels)
+}
+
+// ============================================================================
+// Scalar reference path
+// ============================================================================
+
+/// Scalar luminance kernel; sizes already validated.
+fn luminance_scalar(src: &[u8], dst: &mut [f32]) {
+    for (px, y) in src.chunks_exact(RGBA8_STRIDE).zip(dst.iter_mut()) {
+        *y = LUMA[0] * srgb8_to_linear(px[0])
+            + LUMA[1] * srgb8_to_linear(px[1])
+            + LUMA[2] * srgb8_to_linear(px[2]);
+    }
+}
+
+/// Scalar OKLab kernel; sizes already validated.
+///
+/// Runs the matrices and cube root in `f64`, so it stays within `f32`
+/// rounding of [`OKLab::from_color`](crate::space::oklch::OKLab::from_color).
+fn oklab_scalar(src: &[u8], dst: &mut [f32]) {
+    for (px, out) in src.chunks_exact(RGBA8_STRIDE).zip(dst.chunks_exact_mut(3)) {
+        let rgb = [
+            f64::from(srgb8_to_linear(px[0])),
+            f64::from(srgb8_to_linear(px[1])),
+            f64::from(srgb8_to_linear(px[2])),
+        ];
+        let lms =
+            RGB_TO_LMS.map(|row| ops::cbrt(row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]));
+        for (o, row) in out.iter_mut().zip(LMS_TO_LAB) {
+            *o = (row[0] * lms[0] + row[1] * lms[1] + row[2] * lms[2]) as f32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{gamma::srgb_to_linear, Color};
+    use crate::luminance::relative_luminance_srgb;
+    use crate::space::oklch::{OKLab, OKLCH};
+    use alloc::vec::Vec;
+
+    /// Every 5th code per channel plus the 255 end point.
+    fn grid() -> Vec<u8> {
+        let codes: Vec<u8> = (0..=255).step_by(5).chain([255]).collect();
+        let mut px = Vec::new();
+        for &r in &codes {
+            for &g in &codes {
+                for &b in &codes {
+                    px.extend_from_slice(&[r, g, b, 255]);
+                }
+            }
+        }
+        px
+    }
+
+    #[test]
+    fn test_lut_matches_transfer_function() {
+        for code in 0..=255u8 {
+            let exact = srgb_to_linear(f64::from(code) / 255.0) as f32;
+            assert_eq!(srgb8_to_linear(code), exact, "code {}", code);
+        }
+    }
+
+    #[test]
+    fn test_size_errors() {
+        let mut out = [0.0; 3];
+        assert_eq!(
+            rgba8_to_oklab(&[0; 5], &mut out),
+            Err(BatchError::RaggedInput(5))
+        );
+        assert_eq!(
+            rgba8_to_oklab(&[0; 8], &mut out),
+            Err(BatchError::OutputLength {
+                expected: 6,
+                actual: 3
+            })
+        );
+        assert_eq!(rgba8_to_oklab(&[], &mut []), Ok(0));
+    }
+
+    #[test]
+    fn test_kernels_match_color() {
+        let src = grid();
+        let n = src.len() / 4;
+        let (mut srgb, mut linear, mut lab, mut lch) = (
+            vec![0.0; n * 3],
+            vec![0.0; n * 3],
+            vec![0.0; n * 3],
+            vec![0.0; n * 3],
+        );
+        let mut luma = vec![0.0; n];
+        rgba8_to_srgb(&src, &mut srgb).unwrap();
+        rgba8_to_linear(&src, &mut linear).unwrap();
+        rgba8_to_luminance(&src, &mut luma).unwrap();
+        rgba8_to_oklab(&src, &mut lab).unwrap();
+        rgba8_to_oklch(&src, &mut lch).unwrap();
+
+        for (i, px) in src.chunks_exact(4).enumerate() {
+            let color = Color::from_srgb8(px[0], px[1], px[2]);
+            let ok = OKLab::from_color(&color);
+            let oklch = OKLCH::from_color(&color);
+            for c in 0..3 {
+                assert!((f64::from(srgb[i * 3 + c]) - color.srgb[c]).abs() < 1e-6);
+                assert!((f64::from(linear[i * 3 + c]) - color.linear[c]).abs() < 1e-6);
+            }
+            let y = relative_luminance_srgb(&color).value();
+            assert!((f64::from(luma[i]) - y).abs() < 1e-5, "Y of {:?}", px);
+            let got = [lab[i * 3], lab[i * 3 + 1], lab[i * 3 + 2]].map(f64::from);
+            for (g, e) in got.iter().zip([ok.l, ok.a, ok.b]) {
+                assert!((g - e).abs() < 1e-5, "OKLab of {:?}: {:?}", px, got);
+            }
+            assert!((f64::from(lch[i * 3]) - oklch.l).abs() < 1e-5);
+            assert!((f64::from(lch[i * 3 + 1]) - oklch.c).abs() < 1e-5);
+            if oklch.c > 1e-3 {
+                let dh = (f64::from(lch[i * 3 + 2]) - oklch.h).abs();
+                assert!(dh.min(360.0 - dh) < 1e-2, "hue of {:?}", px);
+            }
+        }
+    }
+
+    #[cfg(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64")))]
+    #[test]
+    fn test_simd_matches_scalar() {
+        let mut src = grid();
+        // Every gray level, and a ragged tail of three pixels
+        for v in 0..=255u8 {
+            src.extend_from_slice(&[v, v, v, 255]);
+        }
+        src.extend_from_slice(&[1, 2, 3, 4, 250, 10, 128, 0, 0, 0, 0, 255]);
+        let n = src.len() / 4;
+
+        let (mut scalar, mut lanes) = (vec![0.0; n * 3], vec![0.0; n * 3]);
+        oklab_scalar(&src, &mut scalar);
+        simd::oklab(&src, &mut lanes);
+        let worst = scalar
+            .iter()
+            .zip(&lanes)
+            .map(|(s, l)| (s - l).abs())
+            .fold(0.0, f32::max);
+        assert!(worst <= SIMD_TOLERANCE, "OKLab SIMD error {}", worst);
+
+        let (mut scalar, mut lanes) = (vec![0.0; n], vec![0.0; n]);
+        luminance_scalar(&src, &mut scalar);
+        simd::luminance(&src, &mut lanes);
+        for (s, l) in scalar.iter().zip(&lanes) {
+            assert!((s - l).abs() <= SIMD_TOLERANCE);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! Four-lane `f32` kernels on SSE2 (x86_64) and NEON (aarch64).
+//!
+//! Both instruction sets are part of their target's baseline, so no runtime
+//! feature detection is needed. Gamma decode stays a table lookup; the lanes
+//! carry the matrix products and the cube root, which uses a bit-level
+//! initial guess refined by three Newton steps. Pixels that do not fill a
+//! group of four go through the scalar kernels.
+
+use super::{luminance_scalar, oklab_scalar, srgb8_to_linear, LUMA, RGBA8_STRIDE};
+use crate::space::oklch::{LMS_TO_LAB, RGB_TO_LMS};
+
+const LANES: usize = 4;
+
+/// `RGB_TO_LMS` rounded to `f32`.
+const RGB_TO_LMS_F32: [[f32; 3]; 3] = narrow(RGB_TO_LMS);
+
+/// `LMS_TO_LAB` rounded to `f32`.
+const LMS_TO_LAB_F32: [[f32; 3]; 3] = narrow(LMS_TO_LAB);
+
+const fn narrow(m: [[f64; 3]; 3]) -> [[f32; 3]; 3] {
+    [
+        [m[0][0] as f32, m[0][1] as f32, m[0][2] as f32],
+        [m[1][0] as f32, m[1][1] as f32, m[1][2] as f32],
+        [m[2][0] as f32, m[2][1] as f32, m[2][2] as f32],
+    ]
+}
+
+/// Decodes one channel of four pixels into lanes.
+#[inline(always)]
+fn gather(block: &[u8], channel: usize) -> F32x4 {
+    F32x4::load([
+        srgb8_to_linear(block[channel]),
+        srgb8_to_linear(block[RGBA8_STRIDE + channel]),
+        srgb8_to_linear(block[2 * RGBA8_STRIDE + channel]),
+        srgb8_to_linear(block[3 * RGBA8_STRIDE + channel]),
+    ])
+}
+
+/// `m · [x, y, z]` for each lane.
+#[inline(always)]
+fn mat3(m: &[[f32; 3]; 3], v: [F32x4; 3]) -> [F32x4; 3] {
+    m.map(|row| {
+        v[0].mul(F32x4::splat(row[0]))
+            .add(v[1].mul(F32x4::splat(row[1])))
+            .add(v[2].mul(F32x4::splat(row[2])))
+    })
+}
+
+/// Cube root of non-negative lanes.
+#[inline(always)]
+fn cbrt(x: F32x4) -> F32x4 {
+    let third = F32x4::splat(1.0 / 3.0);
+    let mut y = x.cbrt_estimate();
+    for _ in 0..3 {
+        // y ← (2y + x / y²) / 3
+        y = y.add(y).add(x.div(y.mul(y))).mul(third);
+    }
+    y.zero_where_not_positive(x)
+}
+
+pub(super) fn oklab(src: &[u8], dst: &mut [f32]) {
+    let blocks = src.len() / (LANES * RGBA8_STRIDE);
+    let (head, tail) = src.split_at(blocks * LANES * RGBA8_STRIDE);
+    let (out_head, out_tail) = dst.split_at_mut(blocks * LANES * 3);
+
+    for (block, out) in head
+        .chunks_exact(LANES * RGBA8_STRIDE)
+        .zip(out_head.chunks_exact_mut(LANES * 3))
+    {
+        let rgb = [gather(block, 0), gather(block, 1), gather(block, 2)];
+        let lms = mat3(&RGB_TO_LMS_F32, rgb).map(cbrt);
+        let lab = mat3(&LMS_TO_LAB_F32, lms).map(F32x4::store);
+        for (lane, o) in out.chunks_exact_mut(3).enumerate() {
+            o.copy_from_slice(&[lab[0][lane], lab[1][lane], lab[2][lane]]);
+        }
+    }
+    oklab_scalar(tail, out_tail);
+}
+
+pub(super) fn luminance(src: &[u8], dst: &mut [f32]) {
+    let blocks = src.len() / (LANES * RGBA8_STRIDE);
+    let (head, tail) = src.split_at(blocks * LANES * RGBA8_STRIDE);
+    let (out_head, out_tail) = dst.split_at_mut(blocks * LANES);
+
+    for (block, out) in head
+        .chunks_exact(LANES * RGBA8_STRIDE)
+        .zip(out_head.chunks_exact_mut(LANES))
+    {
+        let y = gather(block, 0)
+            .mul(F32x4::splat(LUMA[0]))
+            .add(gather(block, 1).mul(F32x4::splat(LUMA[1])))
+            .add(gather(block, 2).mul(F32x4::splat(LUMA[2])));
+        out.copy_from_slice(&y.store());
+    }
+    luminance_scalar(tail, out_tail);
+}
+
+/// Magic constant for the `bits / 3` cube-root estimate of an `f32`.
+const CBRT_MAGIC: i32 = 0x2a51_4067;
+
+#[cfg(target_arch = "x86_64")]
+use x86::F32x4;
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::CBRT_MAGIC;
+    use core::arch::x86_64::*;
+
+    // SAFETY (whole module): every intrinsic used is SSE2, which is part of
+    // the x86_64 baseline, and none of them touch memory through pointers
+    // other than the `[f32; 4]` locals passed to `loadu`/`storeu`.
+
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4(__m128);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub(super) fn load(v: [f32; 4]) -> Self {
+            Self(unsafe { _mm_loadu_ps(v.as_ptr()) })
+        }
+
+        #[inline(always)]
+        pub(super) fn store(self) -> [f32; 4] {
+            let mut out = [0.0; 4];
+            unsafe { _mm_storeu_ps(out.as_mut_ptr(), self.0) };
+            out
+        }
+
+        #[inline(always)]
+        pub(super) fn splat(v: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(v) })
+        }
+
+        #[inline(always)]
+        pub(super) fn add(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_add_ps(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        pub(super) fn mul(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_mul_ps(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        pub(super) fn div(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_div_ps(self.0, rhs.0) })
+        }
+
+        /// `from_bits(to_bits(x) / 3 + CBRT_MAGIC)`, a few percent off.
+        #[inline(always)]
+        pub(super) fn cbrt_estimate(self) -> Self {
+            unsafe {
+                let bits = _mm_cvtepi32_ps(_mm_castps_si128(self.0));
+                let third = _mm_cvttps_epi32(_mm_mul_ps(bits, _mm_set1_ps(1.0 / 3.0)));
+                Self(_mm_castsi128_ps(_mm_add_epi32(
+                    third,
+                    _mm_set1_epi32(CBRT_MAGIC),
+                )))
+            }
+        }
+
+        /// Lanes of `self` where `x > 0`, zero elsewhere.
+        #[inline(always)]
+        pub(super) fn zero_where_not_positive(self, x: Self) -> Self {
+            Self(unsafe { _mm_and_ps(self.0, _mm_cmpgt_ps(x.0, _mm_setzero_ps())) })
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+use neon::F32x4;
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use super::CBRT_MAGIC;
+    use core::arch::aarch64::*;
+
+    // SAFETY (whole module): NEON is part of the aarch64 baseline, and no
+    // intrinsic touches memory other than the `[f32; 4]` locals passed to
+    // `vld1q`/`vst1q`.
+
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4(float32x4_t);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub(super) fn load(v: [f32; 4]) -> Self {
+            Self(unsafe { vld1q_f32(v.as_ptr()) })
+        }
+
+        #[inline(always)]
+        pub(super) fn store(self) -> [f32; 4] {
+            let mut out = [0.0; 4];
+            unsafe { vst1q_f32(out.as_mut_ptr(), self.0) };
+            out
+        }
+
+        #[inline(always)]
+        pub(super) fn splat(v: f32) -> Self {
+            Self(unsafe { vdupq_n_f32(v) })
+        }
+
+        #[inline(always)]
+        pub(super) fn add(self, rhs: Self) -> Self {
+            Self(unsafe { vaddq_f32(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        pub(super) fn mul(self, rhs: Self) -> Self {
+            Self(unsafe { vmulq_f32(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        pub(super) fn div(self, rhs: Self) -> Self {
+            Self(unsafe { vdivq_f32(self.0, rhs.0) })
+        }
+
+        /// `from_bits(to_bits(x) / 3 + CBRT_MAGIC)`, a few percent off.
+        #[inline(always)]
+        pub(super) fn cbrt_estimate(self) -> Self {
+            unsafe {
+                let bits = vcvtq_f32_s32(vreinterpretq_s32_f32(self.0));
+                let third = vcvtq_s32_f32(vmulq_n_f32(bits, 1.0 / 3.0));
+                Self(vreinterpretq_f32_s32(vaddq_s32(
+                    third,
+                    vdupq_n_s32(CBRT_MAGIC),
+                )))
+            }
+        }
+
+        /// Lanes of `self` where `x > 0`, zero elsewhere.
+        #[inline(always)]
+        pub(super) fn zero_where_not_positive(self, x: Self) -> Self {
+            unsafe {
+                let zero = vdupq_n_f32(0.0);
+                Self(vbslq_f32(vcgtq_f32(x.0, zero), self.0, zero))
+            }
+        }
+    }
+}
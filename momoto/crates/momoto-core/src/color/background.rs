@@ -0,0 +1,663 @@
+//! Non-uniform backgrounds: CSS gradients and RGBA rasters.
+//!
+//! Contrast metrics take one background color, but text often sits on a
+//! gradient or an image. A [`Background`] describes such a surface and
+//! [`Background::samples`] splits a text [`Region`] into area-weighted
+//! [`BackgroundSample`]s that a metric can be run over:
+//!
+//! - **Solid**: one sample covering the region.
+//! - **Gradient**: a grid of at most [`GRADIENT_RESOLUTION`]² cells, one
+//!   per pixel for small regions.
+//! - **Raster**: every pixel the region touches, weighted by overlap, so
+//!   thin features are never skipped.
+//!
+//! Gradient geometry follows CSS Images 3: linear angles run clockwise from
+//! "to top", radial gradients are `circle farthest-corner`, and stops
+//! without a position are spread evenly between their neighbours. Stops are
+//! [`CssColor`]s in any space and are interpolated with premultiplied alpha
+//! in sRGB unless [`Gradient::interpolate_in`] picks another space.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::background::{Background, Gradient, GradientStop, Region};
+//! use momoto_core::color::Color;
+//!
+//! let gradient = Gradient::linear(
+//!     90.0,
+//!     vec![
+//!         GradientStop::from_color(Color::from_srgb8(0, 0, 0), None),
+//!         GradientStop::from_color(Color::from_srgb8(255, 255, 255), None),
+//!     ],
+//! )
+//! .unwrap();
+//! let background = Background::Gradient { gradient, width: 200.0, height: 50.0 };
+//!
+//! let samples = background.samples(&Region::new(0.0, 0.0, 100.0, 50.0)).unwrap();
+//! let area: f64 = samples.iter().map(|s| s.weight()).sum();
+//! assert!((area - 5000.0).abs() < 1e-9);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::css::{CssColor, CssColorSpace, HueInterpolation};
+use super::Color;
+use crate::math::ops;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// Maximum number of gradient samples along each axis of a region.
+pub const GRADIENT_RESOLUTION: usize = 64;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Error produced when describing or sampling a background.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackgroundError {
+    /// A gradient needs at least one color stop.
+    NoStops,
+    /// The pixel buffer does not hold `width × height` RGBA pixels.
+    RasterSize {
+        /// Expected buffer length in bytes.
+        expected: usize,
+        /// Actual buffer length in bytes.
+        actual: usize,
+    },
+    /// `width × height × 4` bytes does not fit in a `usize`.
+    RasterOverflow {
+        /// Width in pixels.
+        width: usize,
+        /// Height in pixels.
+        height: usize,
+    },
+    /// The region has no area, or does not overlap the background.
+    EmptyRegion,
+}
+
+impl fmt::Display for BackgroundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoStops => write!(f, "Gradient has no color stops"),
+            Self::RasterSize { expected, actual } => write!(
+                f,
+                "Raster buffer has {} bytes, expected {}",
+                actual, expected
+            ),
+            Self::RasterOverflow { width, height } => {
+                write!(f, "Raster of {}×{} pixels is too large", width, height)
+            }
+            Self::EmptyRegion => write!(f, "Region does not cover any background"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BackgroundError {}
+
+// ============================================================================
+// Region
+// ============================================================================
+
+/// Axis-aligned rectangle in CSS pixels, relative to the background's
+/// top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region {
+    /// Left edge.
+    pub x: f64,
+    /// Top edge.
+    pub y: f64,
+    /// Width (≥ 0).
+    pub width: f64,
+    /// Height (≥ 0).
+    pub height: f64,
+}
+
+impl Region {
+    /// Create a region from its top-left corner and size.
+    #[must_use]
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Area in square pixels.
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        self.width.max(0.0) * self.height.max(0.0)
+    }
+
+    /// Center point.
+    #[must_use]
+    pub fn center(&self) -> (f64, f64) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Overlap with `other`, or `None` if they do not overlap.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let x0 = self.x.max(other.x);
+        let y0 = self.y.max(other.y);
+        let x1 = (self.x + self.width).min(other.x + other.width);
+        let y1 = (self.y + self.height).min(other.y + other.height);
+        (x1 > x0 && y1 > y0).then(|| Self::new(x0, y0, x1 - x0, y1 - y0))
+    }
+
+    /// Smallest region containing both.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let x0 = self.x.min(other.x);
+        let y0 = self.y.min(other.y);
+        let x1 = (self.x + self.width).max(other.x + other.width);
+        let y1 = (self.y + self.height).max(other.y + other.height);
+        Self::new(x0, y0, x1 - x0, y1 - y0)
+    }
+}
+
+// ============================================================================
+// Gradient
+// ============================================================================
+
+/// A gradient color stop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// Stop color, in any CSS color space.
+    pub color: CssColor,
+    /// Position along the gradient line in [0, 1]; `None` to auto-place.
+    pub position: Option<f64>,
+}
+
+impl GradientStop {
+    /// Create a stop from a CSS color.
+    #[must_use]
+    pub const fn new(color: CssColor, position: Option<f64>) -> Self {
+        Self { color, position }
+    }
+
+    /// Create a stop from a [`Color`], keeping its alpha.
+    #[must_use]
+    pub fn from_color(color: Color, position: Option<f64>) -> Self {
+        Self::new(CssColor::from_color(&color, CssColorSpace::Srgb), position)
+    }
+}
+
+/// Gradient geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientShape {
+    /// `linear-gradient(<angle>, …)`; 0° points up, 90° points right.
+    Linear {
+        /// Direction in degrees, clockwise from "to top".
+        angle_deg: f64,
+    },
+    /// `radial-gradient(circle farthest-corner at <x> <y>, …)`.
+    Radial {
+        /// Center x as a fraction of the background width.
+        center_x: f64,
+        /// Center y as a fraction of the background height.
+        center_y: f64,
+    },
+}
+
+/// A CSS linear or radial gradient.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    /// Geometry.
+    pub shape: GradientShape,
+    /// Stops with resolved, non-decreasing positions.
+    stops: Vec<(CssColor, f64)>,
+    /// Interpolation color space.
+    pub space: CssColorSpace,
+    /// Hue interpolation for polar spaces.
+    pub hue: HueInterpolation,
+}
+
+impl Gradient {
+    /// Create a gradient, resolving stop positions as CSS does.
+    ///
+    /// # Errors
+    ///
+    /// [`BackgroundError::NoStops`] if `stops` is empty.
+    pub fn new(shape: GradientShape, stops: Vec<GradientStop>) -> Result<Self, BackgroundError> {
+        if stops.is_empty() {
+            return Err(BackgroundError::NoStops);
+        }
+        Ok(Self {
+            shape,
+            stops: resolve_positions(&stops),
+            space: CssColorSpace::Srgb,
+            hue: HueInterpolation::Shorter,
+        })
+    }
+
+    /// `linear-gradient(<angle_deg>deg, stops…)`.
+    ///
+    /// # Errors
+    ///
+    /// [`BackgroundError::NoStops`] if `stops` is empty.
+    pub fn linear(angle_deg: f64, stops: Vec<GradientStop>) -> Result<Self, BackgroundError> {
+        Self::new(GradientShape::Linear { angle_deg }, stops)
+    }
+
+    /// `radial-gradient(circle farthest-corner at <center>, stops…)`, with
+    /// the center given as fractions of the background size.
+    ///
+    /// # Errors
+    ///
+    /// [`BackgroundError::NoStops`] if `stops` is empty.
+    pub fn radial(
+        center_x: f64,
+        center_y: f64,
+        stops: Vec<GradientStop>,
+    ) -> Result<Self, BackgroundError> {
+        Self::new(GradientShape::Radial { center_x, center_y }, stops)
+    }
+
+    /// Interpolate in `space` (`linear-gradient(in <space> …)`).
+    #[must_use]
+    pub fn interpolate_in(mut self, space: CssColorSpace, hue: HueInterpolation) -> Self {
+        self.space = space;
+        self.hue = hue;
+        self
+    }
+
+    /// Resolved `(color, position)` stops.
+    #[must_use]
+    pub fn stops(&self) -> &[(CssColor, f64)] {
+        &self.stops
+    }
+
+    /// Color at position `t` along the gradient line.
+    #[must_use]
+    pub fn color_at(&self, t: f64) -> Color {
+        let first = &self.stops[0];
+        let last = &self.stops[self.stops.len() - 1];
+        if t <= first.1 {
+            return first.0.to_color();
+        }
+        if t >= last.1 {
+            return last.0.to_color();
+        }
+        // Last stop at or before t; the one after it is strictly past t.
+        let i = self.stops.iter().rposition(|s| s.1 <= t).unwrap_or(0);
+        let (c0, p0) = &self.stops[i];
+        let (c1, p1) = &self.stops[i + 1];
+        let local = (t - p0) / (p1 - p0);
+        CssColor::mix(
+            c0,
+            Some((1.0 - local) * 100.0),
+            c1,
+            Some(local * 100.0),
+            self.space,
+            self.hue,
+        )
+        .map_or_else(|| c0.to_color(), |mixed| mixed.to_color())
+    }
+
+    /// Color at point `(x, y)` of a `width × height` background.
+    #[must_use]
+    pub fn color_at_point(&self, x: f64, y: f64, width: f64, height: f64) -> Color {
+        let t = match self.shape {
+            GradientShape::Linear { angle_deg } => {
+                let a = angle_deg.to_radians();
+                let (dx, dy) = (ops::sin(a), -ops::cos(a));
+                let length = (width * dx).abs() + (height * dy).abs();
+                if length == 0.0 {
+                    0.0
+                } else {
+                    ((x - width / 2.0) * dx + (y - height / 2.0) * dy) / length + 0.5
+                }
+            }
+            GradientShape::Radial { center_x, center_y } => {
+                let (cx, cy) = (center_x * width, center_y * height);
+                let radius = ops::hypot(cx.max(width - cx), cy.max(height - cy));
+                if radius == 0.0 {
+                    0.0
+                } else {
+                    ops::hypot(x - cx, y - cy) / radius
+                }
+            }
+        };
+        self.color_at(t)
+    }
+}
+
+/// CSS Images 3 §3.5.3 color stop fix-up.
+fn resolve_positions(stops: &[GradientStop]) -> Vec<(CssColor, f64)> {
+    let n = stops.len();
+    let mut positions: Vec<Option<f64>> = stops.iter().map(|s| s.position).collect();
+    if positions[0].is_none() {
+        positions[0] = Some(0.0);
+    }
+    if positions[n - 1].is_none() {
+        positions[n - 1] = Some(1.0);
+    }
+
+    // Positions never go backwards.
+    let mut max = f64::NEG_INFINITY;
+    for p in positions.iter_mut().flatten() {
+        max = max.max(*p);
+        *p = max;
+    }
+
+    // Spread runs of missing positions evenly between their neighbours.
+    let mut i = 0;
+    while i < n {
+        if positions[i].is_some() {
+            i += 1;
+            continue;
+        }
+        let start = i - 1;
+        let end = (i..n).find(|&j| positions[j].is_some()).unwrap_or(n - 1);
+        let (p0, p1) = (
+            positions[start].unwrap_or(0.0),
+            positions[end].unwrap_or(1.0),
+        );
+        for (k, p) in positions.iter_mut().enumerate().take(end).skip(i) {
+            *p = Some(p0 + (p1 - p0) * (k - start) as f64 / (end - start) as f64);
+        }
+        i = end;
+    }
+
+    stops
+        .iter()
+        .zip(positions)
+        .map(|(s, p)| (s.color, p.unwrap_or(0.0)))
+        .collect()
+}
+
+// ============================================================================
+// Raster
+// ============================================================================
+
+/// Borrowed 8-bit sRGB RGBA image with straight (unpremultiplied) alpha.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Raster<'a> {
+    width: usize,
+    height: usize,
+    pixels: &'a [u8],
+}
+
+impl<'a> Raster<'a> {
+    /// Wrap a row-major RGBA buffer of `width × height` pixels.
+    ///
+    /// # Errors
+    ///
+    /// [`BackgroundError::RasterOverflow`] if `width × height × 4` overflows
+    /// `usize`, [`BackgroundError::RasterSize`] if it is not `pixels.len()`.
+    pub fn new(width: usize, height: usize, pixels: &'a [u8]) -> Result<Self, BackgroundError> {
+        let expected = width
+            .checked_mul(height)
+            .and_then(|n| n.checked_mul(4))
+            .ok_or(BackgroundError::RasterOverflow { width, height })?;
+        if pixels.len() != expected {
+            return Err(BackgroundError::RasterSize {
+                expected,
+                actual: pixels.len(),
+            });
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Width in pixels.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Color of pixel `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// If the pixel is outside the raster.
+    #[must_use]
+    pub fn pixel(&self, x: usize, y: usize) -> Color {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        let i = (y * self.width + x) * 4;
+        let p = &self.pixels[i..i + 4];
+        Color::from_srgb8(p[0], p[1], p[2]).with_alpha(f64::from(p[3]) / 255.0)
+    }
+}
+
+// ============================================================================
+// Background
+// ============================================================================
+
+/// A surface text can sit on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Background<'a> {
+    /// A single color.
+    Solid(Color),
+    /// A gradient painted over a `width × height` box.
+    Gradient {
+        /// The gradient.
+        gradient: Gradient,
+        /// Painted width in pixels.
+        width: f64,
+        /// Painted height in pixels.
+        height: f64,
+    },
+    /// An image, one CSS pixel per image pixel.
+    Raster(Raster<'a>),
+}
+
+/// Part of a region with a uniform background color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackgroundSample {
+    /// Area this sample stands for.
+    pub cell: Region,
+    /// Background color over the cell (may be translucent).
+    pub color: Color,
+}
+
+impl BackgroundSample {
+    /// Area weight in square pixels.
+    #[must_use]
+    pub fn weight(&self) -> f64 {
+        self.cell.area()
+    }
+}
+
+impl Background<'_> {
+    /// Split `region` into area-weighted samples of this background.
+    ///
+    /// Regions extending past a gradient or raster are clipped to it.
+    ///
+    /// # Errors
+    ///
+    /// [`BackgroundError::EmptyRegion`] if nothing is left to sample.
+    pub fn samples(&self, region: &Region) -> Result<Vec<BackgroundSample>, BackgroundError> {
+        let bounds = match self {
+            Self::Solid(_) => *region,
+            Self::Gradient { width, height, .. } => Region::new(0.0, 0.0, *width, *height),
+            Self::Raster(raster) => {
+                Region::new(0.0, 0.0, raster.width as f64, raster.height as f64)
+            }
+        };
+        let region = region
+            .intersect(&bounds)
+            .ok_or(BackgroundError::EmptyRegion)?;
+
+        let samples = match self {
+            Self::Solid(color) => alloc::vec![BackgroundSample {
+                cell: region,
+                color: *color,
+            }],
+            Self::Gradient {
+                gradient,
+                width,
+                height,
+            } => {
+                let cols = (region.width.ceil() as usize).clamp(1, GRADIENT_RESOLUTION);
+                let rows = (region.height.ceil() as usize).clamp(1, GRADIENT_RESOLUTION);
+                let (cw, ch) = (region.width / cols as f64, region.height / rows as f64);
+                let mut samples = Vec::with_capacity(cols * rows);
+                for row in 0..rows {
+                    for col in 0..cols {
+                        let cell = Region::new(
+                            region.x + col as f64 * cw,
+                            region.y + row as f64 * ch,
+                            cw,
+                            ch,
+                        );
+                        let (x, y) = cell.center();
+                        let color = gradient.color_at_point(x, y, *width, *height);
+                        samples.push(BackgroundSample { cell, color });
+                    }
+                }
+                samples
+            }
+            Self::Raster(raster) => {
+                let x0 = region.x.floor() as usize;
+                let y0 = region.y.floor() as usize;
+                let x1 = ((region.x + region.width).ceil() as usize).min(raster.width);
+                let y1 = ((region.y + region.height).ceil() as usize).min(raster.height);
+                let mut samples = Vec::with_capacity((x1 - x0) * (y1 - y0));
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        let pixel = Region::new(x as f64, y as f64, 1.0, 1.0);
+                        if let Some(cell) = pixel.intersect(&region) {
+                            samples.push(BackgroundSample {
+                                cell,
+                                color: raster.pixel(x, y),
+                            });
+                        }
+                    }
+                }
+                samples
+            }
+        };
+        Ok(samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn stop(r: u8, g: u8, b: u8, position: Option<f64>) -> GradientStop {
+        GradientStop::from_color(Color::from_srgb8(r, g, b), position)
+    }
+
+    #[test]
+    fn test_stop_position_fixup() {
+        let gradient = Gradient::linear(
+            180.0,
+            vec![
+                stop(0, 0, 0, None),
+                stop(255, 0, 0, None),
+                stop(0, 255, 0, Some(0.8)),
+                stop(0, 0, 255, Some(0.5)),
+                stop(255, 255, 255, None),
+            ],
+        )
+        .unwrap();
+
+        let positions: Vec<f64> = gradient.stops().iter().map(|s| s.1).collect();
+        assert_eq!(positions, vec![0.0, 0.4, 0.8, 0.8, 1.0]);
+    }
+
+    #[test]
+    fn test_linear_gradient_geometry() {
+        // "to right": black on the left edge, white on the right.
+        let gradient =
+            Gradient::linear(90.0, vec![stop(0, 0, 0, None), stop(255, 255, 255, None)]).unwrap();
+
+        assert_eq!(
+            gradient.color_at_point(0.0, 10.0, 200.0, 20.0).to_srgb8(),
+            [0, 0, 0]
+        );
+        assert_eq!(
+            gradient.color_at_point(200.0, 10.0, 200.0, 20.0).to_srgb8(),
+            [255, 255, 255]
+        );
+        let mid = gradient.color_at_point(100.0, 0.0, 200.0, 20.0).to_srgb8();
+        assert!(mid[0].abs_diff(128) <= 1);
+    }
+
+    #[test]
+    fn test_radial_gradient_farthest_corner() {
+        let gradient =
+            Gradient::radial(0.5, 0.5, vec![stop(255, 0, 0, None), stop(0, 0, 255, None)]).unwrap();
+
+        assert_eq!(
+            gradient.color_at_point(50.0, 50.0, 100.0, 100.0).to_srgb8(),
+            [255, 0, 0]
+        );
+        assert_eq!(
+            gradient.color_at_point(0.0, 0.0, 100.0, 100.0).to_srgb8(),
+            [0, 0, 255]
+        );
+    }
+
+    #[test]
+    fn test_hard_stop() {
+        let gradient = Gradient::linear(
+            90.0,
+            vec![stop(0, 0, 0, Some(0.5)), stop(255, 255, 255, Some(0.5))],
+        )
+        .unwrap();
+
+        assert_eq!(gradient.color_at(0.49).to_srgb8(), [0, 0, 0]);
+        assert_eq!(gradient.color_at(0.51).to_srgb8(), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_raster_samples_are_area_weighted() {
+        let pixels = [
+            0, 0, 0, 255, 255, 255, 255, 255, //
+            0, 0, 0, 255, 255, 255, 255, 128,
+        ];
+        let raster = Raster::new(2, 2, &pixels).unwrap();
+        let samples = Background::Raster(raster)
+            .samples(&Region::new(0.5, 0.0, 10.0, 2.0))
+            .unwrap();
+
+        assert_eq!(samples.len(), 4);
+        let total: f64 = samples.iter().map(BackgroundSample::weight).sum();
+        assert!((total - 3.0).abs() < 1e-12);
+        assert!((samples[3].color.alpha - 128.0 / 255.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Gradient::linear(0.0, vec![]), Err(BackgroundError::NoStops));
+        assert_eq!(
+            Raster::new(2, 2, &[0; 15]),
+            Err(BackgroundError::RasterSize {
+                expected: 16,
+                actual: 15
+            })
+        );
+        assert_eq!(
+            Raster::new(usize::MAX / 2, 3, &[]),
+            Err(BackgroundError::RasterOverflow {
+                width: usize::MAX / 2,
+                height: 3
+            })
+        );
+        let raster = Raster::new(1, 1, &[0; 4]).unwrap();
+        assert_eq!(
+            Background::Raster(raster).samples(&Region::new(5.0, 5.0, 1.0, 1.0)),
+            Err(BackgroundError::EmptyRegion)
+        );
+    }
+}
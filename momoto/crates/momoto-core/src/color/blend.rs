@@ -0,0 +1,514 @@
+// =============================================================================
+// momoto-core: Alpha Compositing and Blend Modes
+// File: crates/momoto-core/src/color/blend.rs
+//
+// Specification:
+//   W3C Compositing and Blending Level 1 (Candidate Recommendation, 2024).
+//   https://www.w3.org/TR/compositing-1/
+//
+//   Porter, T. & Duff, T. (1984). Compositing digital images. SIGGRAPH '84,
+//   253–259. https://doi.org/10.1145/800031.808606
+//
+// Implementation:
+//   General formula (§5.8), with B the blend function of the mode:
+//     Cs' = (1 − αb)·Cs + αb·B(Cb, Cs)
+//     αo  = αs + αb·(1 − αs)
+//     Co  = (αs·Cs' + αb·Cb·(1 − αs)) / αo
+//
+//   Browsers blend and composite gamma-encoded sRGB values, so that is the
+//   default; linear-light mixing is available for physically based work.
+// =============================================================================
+
+use super::Color;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// Space in which channel values are mixed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompositeSpace {
+    /// Gamma-encoded sRGB, as browsers and most design tools composite.
+    #[default]
+    Srgb,
+    /// Linear-light sRGB, matching how light physically adds up.
+    Linear,
+}
+
+/// CSS `mix-blend-mode` / `background-blend-mode` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    /// Source replaces the backdrop.
+    #[default]
+    Normal,
+    /// Product of the channels; always darker.
+    Multiply,
+    /// Inverse of multiplying the inverses; always lighter.
+    Screen,
+    /// Multiply or screen depending on the backdrop.
+    Overlay,
+    /// Per-channel minimum.
+    Darken,
+    /// Per-channel maximum.
+    Lighten,
+    /// Brightens the backdrop to reflect the source.
+    ColorDodge,
+    /// Darkens the backdrop to reflect the source.
+    ColorBurn,
+    /// Multiply or screen depending on the source.
+    HardLight,
+    /// Softer version of hard light.
+    SoftLight,
+    /// Absolute per-channel difference.
+    Difference,
+    /// Like difference, with lower contrast.
+    Exclusion,
+    /// Source hue with backdrop saturation and luminosity.
+    Hue,
+    /// Source saturation with backdrop hue and luminosity.
+    Saturation,
+    /// Source hue and saturation with backdrop luminosity.
+    Color,
+    /// Source luminosity with backdrop hue and saturation.
+    Luminosity,
+}
+
+impl BlendMode {
+    /// All blend modes, in CSS specification order.
+    pub const ALL: [Self; 16] = [
+        Self::Normal,
+        Self::Multiply,
+        Self::Screen,
+        Self::Overlay,
+        Self::Darken,
+        Self::Lighten,
+        Self::ColorDodge,
+        Self::ColorBurn,
+        Self::HardLight,
+        Self::SoftLight,
+        Self::Difference,
+        Self::Exclusion,
+        Self::Hue,
+        Self::Saturation,
+        Self::Color,
+        Self::Luminosity,
+    ];
+
+    /// Parse a CSS blend mode keyword (`"multiply"`, `"color-dodge"`, …).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase().replace('_', "-");
+        Self::ALL.into_iter().find(|mode| mode.name() == name)
+    }
+
+    /// CSS keyword for this mode.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Multiply => "multiply",
+            Self::Screen => "screen",
+            Self::Overlay => "overlay",
+            Self::Darken => "darken",
+            Self::Lighten => "lighten",
+            Self::ColorDodge => "color-dodge",
+            Self::ColorBurn => "color-burn",
+            Self::HardLight => "hard-light",
+            Self::SoftLight => "soft-light",
+            Self::Difference => "difference",
+            Self::Exclusion => "exclusion",
+            Self::Hue => "hue",
+            Self::Saturation => "saturation",
+            Self::Color => "color",
+            Self::Luminosity => "luminosity",
+        }
+    }
+
+    /// Whether the mode works on each channel independently.
+    #[inline]
+    #[must_use]
+    pub const fn is_separable(self) -> bool {
+        !matches!(
+            self,
+            Self::Hue | Self::Saturation | Self::Color | Self::Luminosity
+        )
+    }
+
+    /// Blend function B(Cb, Cs) on opaque channel triples in [0, 1].
+    #[must_use]
+    pub fn apply(self, backdrop: [f64; 3], source: [f64; 3]) -> [f64; 3] {
+        match self {
+            Self::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            Self::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            Self::Color => set_lum(source, lum(backdrop)),
+            Self::Luminosity => set_lum(backdrop, lum(source)),
+            _ => core::array::from_fn(|i| self.apply_channel(backdrop[i], source[i])),
+        }
+    }
+
+    /// Separable blend function on one channel.
+    fn apply_channel(self, cb: f64, cs: f64) -> f64 {
+        match self {
+            Self::Multiply => cb * cs,
+            Self::Screen => screen(cb, cs),
+            Self::Overlay => hard_light(cs, cb),
+            Self::Darken => cb.min(cs),
+            Self::Lighten => cb.max(cs),
+            Self::ColorDodge => {
+                if cb == 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            Self::HardLight => hard_light(cb, cs),
+            Self::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        cb.sqrt()
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            Self::Difference => (cb - cs).abs(),
+            Self::Exclusion => cb + cs - 2.0 * cb * cs,
+            _ => cs,
+        }
+    }
+}
+
+// =============================================================================
+// Blend helpers (W3C Compositing §10)
+// =============================================================================
+
+#[inline]
+fn screen(cb: f64, cs: f64) -> f64 {
+    cb + cs - cb * cs
+}
+
+#[inline]
+fn hard_light(cb: f64, cs: f64) -> f64 {
+    if cs <= 0.5 {
+        cb * 2.0 * cs
+    } else {
+        screen(cb, 2.0 * cs - 1.0)
+    }
+}
+
+#[inline]
+fn lum(c: [f64; 3]) -> f64 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c: [f64; 3]) -> [f64; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    let mut out = c;
+    if n < 0.0 {
+        out = out.map(|v| l + (v - l) * l / (l - n));
+    }
+    if x > 1.0 {
+        out = out.map(|v| l + (v - l) * (1.0 - l) / (x - l));
+    }
+    out
+}
+
+fn set_lum(c: [f64; 3], l: f64) -> [f64; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|v| v + d))
+}
+
+#[inline]
+fn sat(c: [f64; 3]) -> f64 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: [f64; 3], s: f64) -> [f64; 3] {
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| c[a].total_cmp(&c[b]));
+    let [min, mid, max] = order;
+    let mut out = [0.0; 3];
+    if c[max] > c[min] {
+        out[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        out[max] = s;
+    }
+    out
+}
+
+// =============================================================================
+// Color API
+// =============================================================================
+
+impl Color {
+    /// Whether the color is fully opaque.
+    #[inline]
+    #[must_use]
+    pub fn is_opaque(&self) -> bool {
+        self.alpha >= 1.0
+    }
+
+    /// Porter-Duff source-over: this color painted over `backdrop`, mixed in
+    /// gamma-encoded sRGB like a browser.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use momoto_core::color::Color;
+    ///
+    /// let black = Color::from_srgb8(0, 0, 0).with_alpha(0.5);
+    /// let white = Color::from_srgb8(255, 255, 255);
+    /// assert_eq!(black.over(&white).to_srgb8(), [128, 128, 128]);
+    /// ```
+    #[must_use]
+    pub fn over(&self, backdrop: &Color) -> Color {
+        self.blend_in(backdrop, BlendMode::Normal, CompositeSpace::Srgb)
+    }
+
+    /// Porter-Duff source-over in the given space.
+    #[must_use]
+    pub fn over_in(&self, backdrop: &Color, space: CompositeSpace) -> Color {
+        self.blend_in(backdrop, BlendMode::Normal, space)
+    }
+
+    /// Blend this color onto `backdrop` with a CSS blend mode, then
+    /// composite source-over, in gamma-encoded sRGB.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use momoto_core::color::Color;
+    /// use momoto_core::color::blend::BlendMode;
+    ///
+    /// let red = Color::from_srgb8(255, 0, 0);
+    /// let gray = Color::from_srgb8(128, 128, 128);
+    /// assert_eq!(red.blend(&gray, BlendMode::Multiply).to_srgb8(), [128, 0, 0]);
+    /// ```
+    #[must_use]
+    pub fn blend(&self, backdrop: &Color, mode: BlendMode) -> Color {
+        self.blend_in(backdrop, mode, CompositeSpace::Srgb)
+    }
+
+    /// Blend and composite this color onto `backdrop` in the given space.
+    ///
+    /// The result's alpha is the union of both alphas; a fully transparent
+    /// result is transparent black.
+    #[must_use]
+    pub fn blend_in(&self, backdrop: &Color, mode: BlendMode, space: CompositeSpace) -> Color {
+        let alpha_s = self.alpha.clamp(0.0, 1.0);
+        let alpha_b = backdrop.alpha.clamp(0.0, 1.0);
+        let alpha_o = alpha_s + alpha_b * (1.0 - alpha_s);
+
+        let (cs, cb) = match space {
+            CompositeSpace::Srgb => (self.srgb, backdrop.srgb),
+            CompositeSpace::Linear => (self.linear, backdrop.linear),
+        };
+        let mixed = if alpha_o <= 0.0 {
+            [0.0; 3]
+        } else {
+            let blended = mode.apply(cb, cs);
+            core::array::from_fn(|i| {
+                let source = (1.0 - alpha_b) * cs[i] + alpha_b * blended[i];
+                (alpha_s * source + alpha_b * cb[i] * (1.0 - alpha_s)) / alpha_o
+            })
+        };
+
+        let mut out = match space {
+            CompositeSpace::Srgb => Color::from_srgb(mixed[0], mixed[1], mixed[2]),
+            CompositeSpace::Linear => Color::from_linear(mixed[0], mixed[1], mixed[2]),
+        };
+        out.alpha = alpha_o;
+        out
+    }
+}
+
+/// Resolve a foreground/background pair to the opaque colors a viewer sees.
+///
+/// A translucent background is first composited over a white canvas (the
+/// default page color); a translucent foreground is then composited over
+/// that result. Opaque pairs are returned unchanged. Contrast metrics call
+/// this so that `rgba()` text and overlays are measured as rendered.
+#[must_use]
+pub fn composite_for_contrast(foreground: Color, background: Color) -> (Color, Color) {
+    if foreground.is_opaque() && background.is_opaque() {
+        return (foreground, background);
+    }
+    let background = if background.is_opaque() {
+        background
+    } else {
+        background.over(&Color::from_srgb(1.0, 1.0, 1.0))
+    };
+    let foreground = if foreground.is_opaque() {
+        foreground
+    } else {
+        foreground.over(&background)
+    };
+    (foreground, background)
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb8(r: u8, g: u8, b: u8) -> Color {
+        Color::from_srgb8(r, g, b)
+    }
+
+    fn assert_close(a: [f64; 3], b: [f64; 3]) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < 1e-9, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_over_gamma_and_linear() {
+        let black = rgb8(0, 0, 0).with_alpha(0.5);
+        let white = rgb8(255, 255, 255);
+        assert_eq!(black.over(&white).to_srgb8(), [128, 128, 128]);
+        // Half the light of white: linear 0.5 ≈ sRGB 188
+        let linear = black.over_in(&white, CompositeSpace::Linear);
+        assert_eq!(linear.to_srgb8(), [188, 188, 188]);
+        assert!((linear.linear[0] - 0.5).abs() < 1e-9);
+        assert!(linear.is_opaque());
+    }
+
+    #[test]
+    fn test_over_alpha_union() {
+        let a = rgb8(255, 0, 0).with_alpha(0.5);
+        let b = rgb8(0, 0, 255).with_alpha(0.5);
+        let out = a.over(&b);
+        assert!((out.alpha - 0.75).abs() < 1e-12);
+        // Premultiplied: red 0.5, blue 0.25, over alpha 0.75
+        assert_close(out.srgb, [2.0 / 3.0, 0.0, 1.0 / 3.0]);
+    }
+
+    #[test]
+    fn test_over_edge_alphas() {
+        let red = rgb8(255, 0, 0);
+        let blue = rgb8(0, 0, 255);
+        assert_eq!(red.with_alpha(0.0).over(&blue), blue);
+        assert_eq!(red.over(&blue.with_alpha(0.3)).to_srgb8(), [255, 0, 0]);
+        let clear = red.with_alpha(0.0).over(&blue.with_alpha(0.0));
+        assert_eq!(clear.alpha, 0.0);
+        assert_eq!(clear.to_srgb8(), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_separable_modes() {
+        let cb = [0.2, 0.5, 0.8];
+        let cs = [0.6, 0.6, 0.1];
+        let cases: [(BlendMode, [f64; 3]); 11] = [
+            (BlendMode::Normal, cs),
+            (BlendMode::Multiply, [0.12, 0.3, 0.08]),
+            (BlendMode::Screen, [0.68, 0.8, 0.82]),
+            (BlendMode::Overlay, [0.24, 0.6, 0.64]),
+            (BlendMode::Darken, [0.2, 0.5, 0.1]),
+            (BlendMode::Lighten, [0.6, 0.6, 0.8]),
+            (BlendMode::ColorDodge, [0.5, 1.0, 0.8 / 0.9]),
+            (BlendMode::ColorBurn, [0.0, 1.0 - 0.5 / 0.6, 0.0]),
+            (BlendMode::HardLight, [0.36, 0.6, 0.16]),
+            (BlendMode::Difference, [0.4, 0.1, 0.7]),
+            (BlendMode::Exclusion, [0.56, 0.5, 0.74]),
+        ];
+        for (mode, expected) in cases {
+            assert_close(mode.apply(cb, cs), expected);
+        }
+    }
+
+    #[test]
+    fn test_soft_light() {
+        let mode = BlendMode::SoftLight;
+        // cs ≤ 0.5 darkens, cs = 0.5 is identity, cs > 0.5 lightens
+        assert_close(mode.apply([0.5; 3], [0.5; 3]), [0.5; 3]);
+        assert_close(mode.apply([0.5; 3], [0.0; 3]), [0.25; 3]);
+        let lighter = mode.apply([0.1, 0.5, 0.9], [1.0; 3]);
+        // D(0.1) = ((1.6 − 12)·0.1 + 4)·0.1 = 0.296
+        assert_close(lighter, [0.296, 0.5f64.sqrt(), 0.9f64.sqrt()]);
+    }
+
+    #[test]
+    fn test_non_separable_modes() {
+        let red = [1.0, 0.0, 0.0];
+        let gray = [0.5, 0.5, 0.5];
+        // Luminosity of gray onto red keeps red's hue at gray's luminance
+        let out = BlendMode::Luminosity.apply(red, gray);
+        assert!((lum(out) - 0.5).abs() < 1e-9);
+        assert!(out[0] > out[1] && (out[1] - out[2]).abs() < 1e-12);
+        // Color of red onto gray: red hue and saturation at gray luminance
+        let out = BlendMode::Color.apply(gray, red);
+        assert!((lum(out) - 0.5).abs() < 1e-9);
+        assert!(out[0] > out[1]);
+        // Saturation from a gray source removes all saturation
+        let out = BlendMode::Saturation.apply([0.8, 0.4, 0.2], gray);
+        assert!(sat(out) < 1e-12);
+        // Hue onto an achromatic backdrop stays achromatic
+        let out = BlendMode::Hue.apply(gray, red);
+        assert!(sat(out) < 1e-12 && (lum(out) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_blend_with_alpha() {
+        let red = rgb8(255, 0, 0);
+        let white = rgb8(255, 255, 255);
+        // Multiply over white is the source; at half alpha, halfway there
+        let out = red.with_alpha(0.5).blend(&white, BlendMode::Multiply);
+        assert_eq!(out.to_srgb8(), [255, 128, 128]);
+        // A transparent backdrop ignores the blend mode
+        let out = red.blend(&white.with_alpha(0.0), BlendMode::Difference);
+        assert_eq!(out.to_srgb8(), [255, 0, 0]);
+    }
+
+    #[test]
+    fn test_composite_for_contrast() {
+        let white = rgb8(255, 255, 255);
+        let black = rgb8(0, 0, 0);
+        assert_eq!(composite_for_contrast(black, white), (black, white));
+
+        let (fg, bg) = composite_for_contrast(black.with_alpha(0.5), white);
+        assert_eq!((fg.to_srgb8(), bg), ([128, 128, 128], white));
+
+        // Translucent background lands on the white canvas first
+        let (fg, bg) = composite_for_contrast(black, black.with_alpha(0.25));
+        assert_eq!(fg, black);
+        assert_eq!(bg.to_srgb8(), [191, 191, 191]);
+        assert!(bg.is_opaque());
+    }
+
+    #[test]
+    fn test_mode_names_roundtrip() {
+        for mode in BlendMode::ALL {
+            assert_eq!(BlendMode::from_name(mode.name()), Some(mode));
+        }
+        assert_eq!(
+            BlendMode::from_name("Color_Dodge"),
+            Some(BlendMode::ColorDodge)
+        );
+        assert_eq!(BlendMode::from_name("plus-lighter"), None);
+        assert!(BlendMode::Multiply.is_separable());
+        assert!(!BlendMode::Luminosity.is_separable());
+    }
+}
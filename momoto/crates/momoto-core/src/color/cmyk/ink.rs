@@ -0,0 +1,254 @@
+//! Parametric halftone ink model (Yule–Nielsen modified spectral Neugebauer,
+//! evaluated on XYZ).
+//!
+//! The 16 Neugebauer primaries (paper, every ink and every overprint) are
+//! derived from the measured solids by treating each ink as a filter over
+//! the paper. Halftone area coverage is mixed with the Demichel equations
+//! after a parabolic tone value increase (dot gain).
+
+use super::Cmyk;
+use crate::color::Color;
+use crate::math::ops;
+use crate::space::lab::Lab;
+use crate::space::xyz::{Illuminant, Xyz};
+
+/// Characterization of a printing condition by its paper and solid inks.
+///
+/// All Lab values are D50 (ISO 13655 M0/M1), as press data is measured.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InkModel {
+    /// Unprinted substrate.
+    pub paper: Lab,
+    /// 100% cyan solid.
+    pub cyan: Lab,
+    /// 100% magenta solid.
+    pub magenta: Lab,
+    /// 100% yellow solid.
+    pub yellow: Lab,
+    /// 100% black solid.
+    pub black: Lab,
+    /// Tone value increase at 50% coverage (0.15 = 15%).
+    pub dot_gain: f64,
+    /// Yule–Nielsen n factor (1.0 = Murray–Davies; ~1.7 for offset).
+    pub yule_nielsen_n: f64,
+}
+
+impl InkModel {
+    /// Offset on coated paper (ISO 12647-2 PC1, FOGRA39-like).
+    #[must_use]
+    pub const fn coated() -> Self {
+        Self {
+            paper: Lab::new(95.0, 0.0, -2.0),
+            cyan: Lab::new(55.0, -37.0, -50.0),
+            magenta: Lab::new(48.0, 74.0, -3.0),
+            yellow: Lab::new(89.0, -5.0, 93.0),
+            black: Lab::new(16.0, 0.0, 0.0),
+            dot_gain: 0.14,
+            yule_nielsen_n: 1.7,
+        }
+    }
+
+    /// Offset on uncoated paper (ISO 12647-2 PC5, FOGRA29-like).
+    #[must_use]
+    pub const fn uncoated() -> Self {
+        Self {
+            paper: Lab::new(95.0, 0.0, -2.0),
+            cyan: Lab::new(60.0, -26.0, -44.0),
+            magenta: Lab::new(56.0, 61.0, -1.0),
+            yellow: Lab::new(89.0, -4.0, 78.0),
+            black: Lab::new(31.0, 1.0, 1.0),
+            dot_gain: 0.20,
+            yule_nielsen_n: 2.0,
+        }
+    }
+
+    /// Apply the tone value increase to a nominal coverage.
+    #[inline]
+    #[must_use]
+    pub fn effective_coverage(&self, nominal: f64) -> f64 {
+        let a = nominal.clamp(0.0, 1.0);
+        (a + 4.0 * self.dot_gain * a * (1.0 - a)).clamp(0.0, 1.0)
+    }
+
+    /// XYZ (D50) of the 16 Neugebauer primaries, indexed by the bit set of
+    /// inks (bit 0 = C, 1 = M, 2 = Y, 3 = K).
+    #[must_use]
+    pub fn neugebauer_primaries(&self) -> [[f64; 3]; 16] {
+        let xyz = |lab: Lab| lab.to_xyz(Illuminant::D50).to_array();
+        let paper = xyz(self.paper);
+        let filters = [self.cyan, self.magenta, self.yellow, self.black]
+            .map(|ink| core::array::from_fn::<f64, 3, _>(|i| xyz(ink)[i] / paper[i]));
+        core::array::from_fn(|set| {
+            core::array::from_fn(|i| {
+                (0..4)
+                    .filter(|ink| set & (1 << ink) != 0)
+                    .fold(paper[i], |acc, ink| acc * filters[ink][i])
+            })
+        })
+    }
+
+    /// Predicted XYZ (D50) of a CMYK tint.
+    #[must_use]
+    pub fn to_xyz(&self, cmyk: Cmyk) -> Xyz {
+        let primaries = self.neugebauer_primaries();
+        let a = cmyk.to_array().map(|v| self.effective_coverage(v));
+        let n = self.yule_nielsen_n.max(1.0);
+        let mut sum = [0.0; 3];
+        for (set, primary) in primaries.iter().enumerate() {
+            let weight: f64 = (0..4)
+                .map(|ink| {
+                    if set & (1 << ink) != 0 {
+                        a[ink]
+                    } else {
+                        1.0 - a[ink]
+                    }
+                })
+                .product();
+            if weight == 0.0 {
+                continue;
+            }
+            for i in 0..3 {
+                sum[i] += weight * ops::powf(primary[i].max(0.0), 1.0 / n);
+            }
+        }
+        Xyz::from(sum.map(|v| ops::powf(v, n)))
+    }
+
+    /// Predicted Lab (D50) of a CMYK tint.
+    #[must_use]
+    pub fn to_lab(&self, cmyk: Cmyk) -> Lab {
+        Lab::from_xyz(self.to_xyz(cmyk), Illuminant::D50)
+    }
+
+    /// Predicted appearance of a CMYK tint as a screen [`Color`].
+    #[must_use]
+    pub fn to_color(&self, cmyk: Cmyk) -> Color {
+        self.to_lab(cmyk).to_color()
+    }
+
+    /// Separate a color into CMYK.
+    ///
+    /// Black is generated by gray component replacement (`black_generation`
+    /// 0 = none, 1 = maximum); CMY are then solved by damped Gauss–Newton to
+    /// the closest reachable Lab. If the total area coverage exceeds
+    /// `tac_limit` (e.g. 3.0 for 300%), black is raised and CMY re-solved,
+    /// with a proportional CMY cut as a last resort.
+    #[must_use]
+    pub fn separate(&self, color: &Color, black_generation: f64, tac_limit: f64) -> Cmyk {
+        let target = Lab::from_color(color);
+        let naive = Cmyk::from_color_naive(color);
+        let mut k = naive.k * black_generation.clamp(0.0, 1.0);
+        let (mut cmy, mut error) = self.solve_cmy(&target, [naive.c, naive.m, naive.y], k);
+
+        while error > 1.0 && k < 1.0 {
+            let next_k = (k + 0.05).min(1.0);
+            let (next, next_error) = self.solve_cmy(&target, cmy, next_k);
+            if next_error >= error - 1e-6 {
+                break;
+            }
+            (cmy, error, k) = (next, next_error, next_k);
+        }
+        while cmy.iter().sum::<f64>() + k > tac_limit + 1e-9 && k < 1.0 {
+            k = (k + 0.05).min(1.0);
+            cmy = self.solve_cmy(&target, cmy, k).0;
+        }
+        let sum: f64 = cmy.iter().sum();
+        let room = (tac_limit - k).max(0.0);
+        if sum > room {
+            cmy = cmy.map(|v| v * room / sum);
+        }
+        Cmyk::new(cmy[0], cmy[1], cmy[2], k)
+    }
+
+    /// Closest CMY at fixed black, with its squared Lab residual.
+    fn solve_cmy(&self, target: &Lab, start: [f64; 3], k: f64) -> ([f64; 3], f64) {
+        const H: f64 = 1e-4;
+        let residual = |cmy: [f64; 3]| {
+            let lab = self.to_lab(Cmyk::new(cmy[0], cmy[1], cmy[2], k));
+            [lab.l - target.l, lab.a - target.a, lab.b - target.b]
+        };
+        let error = |r: [f64; 3]| r[0] * r[0] + r[1] * r[1] + r[2] * r[2];
+
+        let mut x = start.map(|v| v.clamp(0.0, 1.0));
+        let mut r = residual(x);
+        let mut lambda = 1e-3;
+        for _ in 0..40 {
+            if error(r) < 1e-8 {
+                break;
+            }
+            // Forward-difference Jacobian; columns are ∂Lab/∂c, ∂m, ∂y
+            let mut jac = [[0.0; 3]; 3];
+            for j in 0..3 {
+                let mut xh = x;
+                let step = if x[j] + H > 1.0 { -H } else { H };
+                xh[j] += step;
+                let rh = residual(xh);
+                for i in 0..3 {
+                    jac[i][j] = (rh[i] - r[i]) / step;
+                }
+            }
+            // Levenberg–Marquardt step: (JᵀJ + λ·diag) δ = −Jᵀr
+            let mut jtj = [[0.0; 3]; 3];
+            let mut jtr = [0.0; 3];
+            for a in 0..3 {
+                for b in 0..3 {
+                    jtj[a][b] = (0..3).map(|i| jac[i][a] * jac[i][b]).sum();
+                }
+                jtr[a] = (0..3).map(|i| jac[i][a] * r[i]).sum();
+            }
+            let mut improved = false;
+            for _ in 0..8 {
+                let mut m = jtj;
+                for (d, row) in m.iter_mut().enumerate() {
+                    row[d] += lambda * (jtj[d][d] + 1e-9);
+                }
+                let Some(delta) = solve3(&m, jtr.map(|v| -v)) else {
+                    lambda *= 10.0;
+                    continue;
+                };
+                let candidate: [f64; 3] =
+                    core::array::from_fn(|i| (x[i] + delta[i]).clamp(0.0, 1.0));
+                let rc = residual(candidate);
+                if error(rc) < error(r) {
+                    x = candidate;
+                    r = rc;
+                    lambda = (lambda * 0.3).max(1e-9);
+                    improved = true;
+                    break;
+                }
+                lambda *= 10.0;
+            }
+            if !improved {
+                break;
+            }
+        }
+        (x, error(r))
+    }
+}
+
+impl Default for InkModel {
+    fn default() -> Self {
+        Self::coated()
+    }
+}
+
+/// Solve a 3×3 linear system by Cramer's rule.
+fn solve3(m: &[[f64; 3]; 3], b: [f64; 3]) -> Option<[f64; 3]> {
+    let det = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det(m);
+    if d.abs() < 1e-18 {
+        return None;
+    }
+    Some(core::array::from_fn(|col| {
+        let mut mc = *m;
+        for row in 0..3 {
+            mc[row][col] = b[row];
+        }
+        det(&mc) / d
+    }))
+}
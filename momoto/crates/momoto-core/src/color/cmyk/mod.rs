@@ -0,0 +1,509 @@
+//! CMYK separation, print gamut checking and soft-proofing.
+//!
+//! A [`PrintConditions`] describes a press: either the parametric
+//! [`InkModel`] (paper and solid ink colorimetry, dot gain, Yule–Nielsen n)
+//! or a CMYK ICC output profile, plus a total area coverage (TAC) limit and
+//! black generation. It separates screen colors into [`Cmyk`] and predicts
+//! how the print will look, flagging colors the press cannot reproduce.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::Color;
+//! use momoto_core::color::cmyk::PrintConditions;
+//!
+//! let press = PrintConditions::coated();
+//! let proof = press.proof(&Color::from_srgb8(0, 200, 80));
+//!
+//! // Vivid screen green is outside the offset gamut
+//! assert!(!proof.is_in_gamut());
+//! assert!(proof.cmyk.total_area_coverage() <= press.tac_limit + 1e-9);
+//! println!("{} prints as {}", proof.original.to_hex(), proof.proof.to_hex());
+//! ```
+//!
+//! # References
+//!
+//! - ISO 12647-2:2013 Process control for offset lithographic processes
+//! - Wyble, D. R. & Berns, R. S. (2000). A critical review of spectral models
+//!   applied to binary color printing. Color Research & Application 25(1).
+
+use alloc::{boxed::Box, vec::Vec};
+
+mod ink;
+
+pub use ink::InkModel;
+
+use crate::color::icc::{ColorSignature, IccError, IccProfile, RenderingIntent};
+use crate::color::Color;
+use crate::evaluated::EvaluatedMaterial;
+use crate::space::lab::Lab;
+
+// ============================================================================
+// Cmyk
+// ============================================================================
+
+/// Process-ink coverage, each channel in [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmyk {
+    /// Cyan
+    pub c: f64,
+    /// Magenta
+    pub m: f64,
+    /// Yellow
+    pub y: f64,
+    /// Black (key)
+    pub k: f64,
+}
+
+impl Cmyk {
+    /// Create CMYK coverage, clamping each channel to [0, 1].
+    #[must_use]
+    pub fn new(c: f64, m: f64, y: f64, k: f64) -> Self {
+        Self {
+            c: c.clamp(0.0, 1.0),
+            m: m.clamp(0.0, 1.0),
+            y: y.clamp(0.0, 1.0),
+            k: k.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Channels as `[c, m, y, k]`.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f64; 4] {
+        [self.c, self.m, self.y, self.k]
+    }
+
+    /// Sum of all channels (0.0–4.0; 3.0 = 300%).
+    #[inline]
+    #[must_use]
+    pub fn total_area_coverage(&self) -> f64 {
+        self.c + self.m + self.y + self.k
+    }
+
+    /// Device-dependent "naive" conversion (`k = 1 − max(r, g, b)`),
+    /// as used by CSS `device-cmyk()` fallbacks. Not colorimetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::Color;
+    /// use momoto_core::color::cmyk::Cmyk;
+    ///
+    /// let red = Cmyk::from_color_naive(&Color::from_srgb8(255, 0, 0));
+    /// assert_eq!(red, Cmyk::new(0.0, 1.0, 1.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn from_color_naive(color: &Color) -> Self {
+        let [r, g, b] = color.srgb.map(|v| v.clamp(0.0, 1.0));
+        let k = 1.0 - r.max(g).max(b);
+        if k >= 1.0 {
+            return Self::new(0.0, 0.0, 0.0, 1.0);
+        }
+        let d = 1.0 - k;
+        Self::new((d - r) / d, (d - g) / d, (d - b) / d, k)
+    }
+
+    /// Inverse of [`Cmyk::from_color_naive`].
+    #[must_use]
+    pub fn to_color_naive(&self) -> Color {
+        let w = 1.0 - self.k;
+        Color::from_srgb((1.0 - self.c) * w, (1.0 - self.m) * w, (1.0 - self.y) * w)
+    }
+}
+
+impl core::fmt::Display for Cmyk {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "device-cmyk({:.0}% {:.0}% {:.0}% {:.0}%)",
+            self.c * 100.0,
+            self.m * 100.0,
+            self.y * 100.0,
+            self.k * 100.0
+        )
+    }
+}
+
+// ============================================================================
+// Print conditions
+// ============================================================================
+
+/// How CMYK values map to color.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputProfile {
+    /// Parametric halftone model.
+    Ink(InkModel),
+    /// CMYK ICC output profile (needs `A2Bx` and `B2Ax` tables).
+    Icc(Box<IccProfile>),
+}
+
+/// Default ΔE2000 above which a proofed color is flagged out of gamut.
+pub const DEFAULT_GAMUT_TOLERANCE: f64 = 2.0;
+
+/// A printing condition: output characterization plus separation settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintConditions {
+    /// Ink model or ICC profile.
+    pub output: OutputProfile,
+    /// Maximum total area coverage (3.0 = 300%).
+    pub tac_limit: f64,
+    /// Gray component replacement, 0 (none) to 1 (maximum). Ink model only;
+    /// ICC profiles carry their own black generation.
+    pub black_generation: f64,
+    /// Rendering intent for ICC profiles.
+    pub intent: RenderingIntent,
+    /// ΔE2000 above which a color is reported out of gamut.
+    pub gamut_tolerance: f64,
+}
+
+impl PrintConditions {
+    /// Sheet-fed offset on coated paper, 300% TAC.
+    #[must_use]
+    pub fn coated() -> Self {
+        Self {
+            output: OutputProfile::Ink(InkModel::coated()),
+            tac_limit: 3.0,
+            black_generation: 0.5,
+            intent: RenderingIntent::RelativeColorimetric,
+            gamut_tolerance: DEFAULT_GAMUT_TOLERANCE,
+        }
+    }
+
+    /// Offset on uncoated paper, 260% TAC.
+    #[must_use]
+    pub fn uncoated() -> Self {
+        Self {
+            output: OutputProfile::Ink(InkModel::uncoated()),
+            tac_limit: 2.6,
+            black_generation: 0.5,
+            ..Self::coated()
+        }
+    }
+
+    /// Use a CMYK ICC output profile.
+    ///
+    /// # Errors
+    ///
+    /// [`IccError::ChannelMismatch`] if the profile or one of its tables is
+    /// not CMYK, [`IccError::NoTransform`] if it lacks `A2Bx` or `B2Ax`
+    /// tables.
+    pub fn from_icc(profile: IccProfile, tac_limit: f64) -> Result<Self, IccError> {
+        if profile.header.color_space != ColorSignature::Cmyk {
+            return Err(IccError::ChannelMismatch {
+                expected: 4,
+                actual: profile.channels(),
+            });
+        }
+        if profile.a_to_b.iter().all(Option::is_none) || profile.b_to_a.iter().all(Option::is_none)
+        {
+            return Err(IccError::NoTransform);
+        }
+        let device_sides = profile
+            .a_to_b
+            .iter()
+            .flatten()
+            .map(|lut| lut.input_channels)
+            .chain(
+                profile
+                    .b_to_a
+                    .iter()
+                    .flatten()
+                    .map(|lut| lut.output_channels),
+            );
+        for channels in device_sides {
+            if channels != 4 {
+                return Err(IccError::ChannelMismatch {
+                    expected: 4,
+                    actual: channels,
+                });
+            }
+        }
+        Ok(Self {
+            output: OutputProfile::Icc(Box::new(profile)),
+            tac_limit,
+            ..Self::coated()
+        })
+    }
+
+    /// Separate a screen color into CMYK within the TAC limit.
+    #[must_use]
+    pub fn separate(&self, color: &Color) -> Cmyk {
+        match &self.output {
+            OutputProfile::Ink(ink) => ink.separate(color, self.black_generation, self.tac_limit),
+            OutputProfile::Icc(profile) => {
+                let cmyk = match profile.from_color(color, self.intent).as_deref() {
+                    Ok(&[c, m, y, k]) => Cmyk::new(c, m, y, k),
+                    _ => Cmyk::from_color_naive(color),
+                };
+                self.limit_coverage(cmyk)
+            }
+        }
+    }
+
+    /// Predicted printed appearance of a CMYK tint.
+    #[must_use]
+    pub fn simulate(&self, cmyk: Cmyk) -> Color {
+        match &self.output {
+            OutputProfile::Ink(ink) => ink.to_color(cmyk),
+            OutputProfile::Icc(profile) => profile
+                .to_color(&cmyk.to_array(), self.intent)
+                .unwrap_or_else(|_| cmyk.to_color_naive()),
+        }
+    }
+
+    /// Predicted color of the unprinted paper.
+    #[must_use]
+    pub fn paper_white(&self) -> Color {
+        self.simulate(Cmyk::default())
+    }
+
+    /// Soft-proof a color: separate, simulate and measure the difference.
+    #[must_use]
+    pub fn proof(&self, color: &Color) -> ProofResult {
+        let original = color.with_alpha(1.0);
+        let cmyk = self.separate(&original);
+        let proof = self.simulate(cmyk);
+        let delta_e = Lab::from_color(&original).delta_e_2000(&Lab::from_color(&proof));
+        let coverage = cmyk.total_area_coverage();
+
+        let mut warnings = Vec::new();
+        if delta_e > self.gamut_tolerance {
+            warnings.push(PrintWarning::OutOfGamut { delta_e });
+        }
+        if coverage > self.tac_limit + 1e-9 {
+            warnings.push(PrintWarning::TacExceeded {
+                coverage,
+                limit: self.tac_limit,
+            });
+        }
+        ProofResult {
+            original,
+            cmyk,
+            proof,
+            delta_e,
+            warnings,
+        }
+    }
+
+    /// Soft-proof every color of a palette.
+    #[must_use]
+    pub fn proof_palette(&self, colors: &[Color]) -> Vec<ProofResult> {
+        colors.iter().map(|c| self.proof(c)).collect()
+    }
+
+    /// Soft-proof a material's base color as it would print: the color is
+    /// first composited at the material's opacity over the paper.
+    #[must_use]
+    pub fn proof_material(&self, material: &EvaluatedMaterial) -> ProofResult {
+        let base = &material.base_color;
+        let color = Color::from_linear(base.r, base.g, base.b)
+            .with_alpha(material.opacity.clamp(0.0, 1.0))
+            .over(&self.paper_white());
+        self.proof(&color)
+    }
+
+    /// Scale CMY down (keeping K) so the total fits the TAC limit.
+    fn limit_coverage(&self, cmyk: Cmyk) -> Cmyk {
+        let cmy = cmyk.c + cmyk.m + cmyk.y;
+        let room = (self.tac_limit - cmyk.k).max(0.0);
+        if cmy <= room || cmy == 0.0 {
+            return cmyk;
+        }
+        let s = room / cmy;
+        Cmyk::new(cmyk.c * s, cmyk.m * s, cmyk.y * s, cmyk.k)
+    }
+}
+
+impl Default for PrintConditions {
+    fn default() -> Self {
+        Self::coated()
+    }
+}
+
+// ============================================================================
+// Results
+// ============================================================================
+
+/// A problem found while proofing a color for print.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrintWarning {
+    /// The printed color differs visibly from the screen color.
+    OutOfGamut {
+        /// ΔE2000 between screen and proof.
+        delta_e: f64,
+    },
+    /// The separation uses more ink than the press allows.
+    TacExceeded {
+        /// Total area coverage of the separation.
+        coverage: f64,
+        /// Press limit.
+        limit: f64,
+    },
+}
+
+/// Result of soft-proofing one color.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofResult {
+    /// Screen color that was proofed (opaque).
+    pub original: Color,
+    /// Separation sent to press.
+    pub cmyk: Cmyk,
+    /// Predicted printed appearance.
+    pub proof: Color,
+    /// ΔE2000 between `original` and `proof`.
+    pub delta_e: f64,
+    /// Gamut and ink-limit warnings; empty when the color prints faithfully.
+    pub warnings: Vec<PrintWarning>,
+}
+
+impl ProofResult {
+    /// Whether the color prints within the gamut tolerance.
+    #[must_use]
+    pub fn is_in_gamut(&self) -> bool {
+        !self
+            .warnings
+            .iter()
+            .any(|w| matches!(w, PrintWarning::OutOfGamut { .. }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::icc::{Lut, LutKind};
+    use crate::evaluated::Evaluable;
+    use crate::evaluated::MaterialContext;
+    use crate::material::GlassMaterial;
+
+    #[test]
+    fn test_naive_roundtrip() {
+        for rgb in [[255, 0, 0], [0, 0, 0], [255, 255, 255], [59, 130, 246]] {
+            let color = Color::from_srgb8(rgb[0], rgb[1], rgb[2]);
+            let cmyk = Cmyk::from_color_naive(&color);
+            assert_eq!(cmyk.to_color_naive().to_srgb8(), rgb);
+        }
+        let black = Cmyk::from_color_naive(&Color::from_srgb8(0, 0, 0));
+        assert_eq!(black.to_array(), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(black.to_string(), "device-cmyk(0% 0% 0% 100%)");
+    }
+
+    #[test]
+    fn test_ink_model_primaries() {
+        let ink = InkModel::coated();
+        // Solids and paper reproduce their measured Lab
+        let paper = ink.to_lab(Cmyk::default());
+        assert!(paper.delta_e_76(&ink.paper) < 1e-9);
+        let cyan = ink.to_lab(Cmyk::new(1.0, 0.0, 0.0, 0.0));
+        assert!(cyan.delta_e_76(&ink.cyan) < 1e-9, "{cyan:?}");
+        // Dot gain darkens midtones beyond the nominal coverage
+        assert!((ink.effective_coverage(0.5) - 0.64).abs() < 1e-12);
+        assert_eq!(ink.effective_coverage(1.0), 1.0);
+        // More ink is always darker
+        let light = ink.to_lab(Cmyk::new(0.2, 0.2, 0.2, 0.0));
+        let dark = ink.to_lab(Cmyk::new(0.6, 0.6, 0.6, 0.3));
+        assert!(dark.l < light.l);
+    }
+
+    #[test]
+    fn test_in_gamut_color_proofs_faithfully() {
+        let press = PrintConditions::coated();
+        // A muted brown sits well inside the offset gamut
+        let proof = press.proof(&Color::from_srgb8(140, 100, 70));
+        assert!(proof.delta_e < 1.0, "{proof:?}");
+        assert!(proof.is_in_gamut());
+        assert!(proof.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_out_of_gamut_warnings() {
+        let press = PrintConditions::coated();
+        let results = press.proof_palette(&[
+            Color::from_srgb8(0, 255, 0),
+            Color::from_srgb8(0, 0, 255),
+            Color::from_srgb8(128, 128, 128),
+        ]);
+        assert!(!results[0].is_in_gamut());
+        assert!(!results[1].is_in_gamut());
+        assert!(results[2].is_in_gamut(), "{:?}", results[2]);
+        // The proof is the closest printable color, nearer than a naive split
+        let naive = press.simulate(Cmyk::from_color_naive(&results[0].original));
+        let naive_de = Lab::from_color(&results[0].original).delta_e_2000(&Lab::from_color(&naive));
+        assert!(results[0].delta_e <= naive_de + 1e-6);
+    }
+
+    #[test]
+    fn test_tac_limit() {
+        let mut press = PrintConditions::coated();
+        let black = Color::from_srgb8(0, 0, 0);
+        let mut lightness = Vec::new();
+        for limit in [3.2, 2.4, 1.6] {
+            press.tac_limit = limit;
+            let cmyk = press.separate(&black);
+            assert!(
+                cmyk.total_area_coverage() <= limit + 1e-9,
+                "{limit}: {cmyk}"
+            );
+            let proof = press.proof(&black);
+            assert!(!proof
+                .warnings
+                .iter()
+                .any(|w| matches!(w, PrintWarning::TacExceeded { .. })));
+            lightness.push(Lab::from_color(&proof.proof).l);
+        }
+        // Less ink allowed means a lighter, out-of-gamut black
+        assert!(lightness[0] < 20.0, "{lightness:?}");
+        assert!(lightness[2] > lightness[0], "{lightness:?}");
+        press.tac_limit = 1.6;
+        assert!(!press.proof(&black).is_in_gamut());
+    }
+
+    #[test]
+    fn test_proof_material_over_paper() {
+        let press = PrintConditions::coated();
+        let glass = GlassMaterial::frosted().evaluate(&MaterialContext::default());
+        let proof = press.proof_material(&glass);
+        assert!(proof.original.is_opaque());
+        // Translucent glass over paper stays light
+        assert!(Lab::from_color(&proof.proof).l > 50.0, "{proof:?}");
+    }
+
+    #[test]
+    fn test_icc_requires_cmyk() {
+        let mut bytes = vec![0u8; 132];
+        bytes[3] = 132;
+        bytes[16..20].copy_from_slice(b"RGB ");
+        bytes[20..24].copy_from_slice(b"XYZ ");
+        bytes[36..40].copy_from_slice(b"acsp");
+        let rgb = IccProfile::parse(&bytes).unwrap();
+        assert!(matches!(
+            PrintConditions::from_icc(rgb, 3.0),
+            Err(IccError::ChannelMismatch { expected: 4, .. })
+        ));
+        bytes[16..20].copy_from_slice(b"CMYK");
+        let lutless = IccProfile::parse(&bytes).unwrap();
+        assert_eq!(
+            PrintConditions::from_icc(lutless.clone(), 3.0),
+            Err(IccError::NoTransform)
+        );
+
+        // B2A producing three channels instead of four
+        let lut = |inputs, outputs| Lut {
+            kind: LutKind::AToB,
+            input_channels: inputs,
+            output_channels: outputs,
+            stages: Vec::new(),
+        };
+        let mut short = lutless;
+        short.a_to_b[0] = Some(lut(4, 3));
+        short.b_to_a[0] = Some(lut(3, 3));
+        assert_eq!(
+            PrintConditions::from_icc(short, 3.0),
+            Err(IccError::ChannelMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+}
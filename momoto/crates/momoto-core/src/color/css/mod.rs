@@ -0,0 +1,1103 @@
+//! CSS Color Level 4/5 parsing and serialization.
+//!
+//! Parses every color syntax a design token can contain and serializes
+//! colors back to spec-conformant CSS strings.
+//!
+//! # Supported syntax
+//!
+//! | Syntax | Example |
+//! |--------|---------|
+//! | Hex (3/4/6/8 digits) | `#3b82f6`, `#3b82f680` |
+//! | Named colors | `rebeccapurple`, `transparent` |
+//! | `rgb()` / `rgba()` (legacy and modern) | `rgb(59, 130, 246)`, `rgb(59 130 246 / 50%)` |
+//! | `hsl()` / `hsla()`, `hwb()` | `hsl(217 91% 60%)`, `hwb(217 23% 4%)` |
+//! | `lab()`, `lch()` (CIE, D50) | `lab(54.6 8.3 -65.5)`, `lch(54.6 66 277)` |
+//! | `oklab()`, `oklch()` | `oklch(0.62 0.19 259.8)` |
+//! | `color()` predefined spaces | `color(display-p3 0.27 0.5 0.94)` |
+//! | `color-mix()` (Level 5) | `color-mix(in oklch, red 30%, blue)` |
+//! | Relative colors (Level 5) | `oklch(from #3b82f6 calc(l + 0.1) c h)` |
+//!
+//! Components may be `none` (missing) and channels accept numbers,
+//! percentages and angles (`deg`, `rad`, `grad`, `turn`) as the spec allows.
+//! Relative color channels may use `calc()` with `+ - * /` and parentheses.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::css::{CssColor, CssColorSpace};
+//!
+//! let token = CssColor::parse("oklch(from #3b82f6 calc(l + 0.1) c h)").unwrap();
+//! assert_eq!(token.space, CssColorSpace::Oklch);
+//!
+//! let color = token.to_color();
+//! let hex = CssColor::from_color(&color, CssColorSpace::Rgb).to_string();
+//! assert!(hex.starts_with("rgb("));
+//! ```
+//!
+//! # References
+//!
+//! - [CSS Color Module Level 4](https://www.w3.org/TR/css-color-4/)
+//! - [CSS Color Module Level 5](https://www.w3.org/TR/css-color-5/)
+
+pub mod named;
+mod parser;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use crate::color::Color;
+use crate::math::ops;
+use crate::space::lab::{Lab, Lch};
+use crate::space::oklch::OKLab;
+use crate::space::rgb::{RgbSpace, TransferFunction};
+use crate::space::xyz::{ChromaticAdaptation, Illuminant, Xyz};
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Error produced when a CSS color string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssColorError {
+    /// The input was empty or contained only whitespace.
+    Empty,
+    /// The input ended before the color was complete.
+    UnexpectedEnd,
+    /// A token appeared where it is not allowed.
+    UnexpectedToken(String),
+    /// A `#` hex color had the wrong length or non-hex digits.
+    InvalidHex(String),
+    /// An identifier is not a CSS named color.
+    UnknownName(String),
+    /// A function is not a CSS color function.
+    UnknownFunction(String),
+    /// A `color()` or `color-mix()` color space is not recognised.
+    UnknownColorSpace(String),
+    /// A value is out of its allowed range.
+    InvalidValue(String),
+    /// Valid CSS that has no meaning outside a document (e.g. `currentcolor`).
+    Unsupported(String),
+}
+
+impl fmt::Display for CssColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Empty color string"),
+            Self::UnexpectedEnd => write!(f, "Unexpected end of color string"),
+            Self::UnexpectedToken(t) => write!(f, "Unexpected token '{}'", t),
+            Self::InvalidHex(h) => write!(f, "Invalid hex color '#{}'", h),
+            Self::UnknownName(n) => write!(f, "Unknown color name '{}'", n),
+            Self::UnknownFunction(n) => write!(f, "Unknown color function '{}()'", n),
+            Self::UnknownColorSpace(s) => write!(f, "Unknown color space '{}'", s),
+            Self::InvalidValue(v) => write!(f, "Invalid value '{}'", v),
+            Self::Unsupported(s) => write!(f, "Unsupported color value '{}'", s),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CssColorError {}
+
+// ============================================================================
+// Color spaces
+// ============================================================================
+
+/// Color space of a [`CssColor`], named after its CSS syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CssColorSpace {
+    /// Legacy sRGB syntax (`#hex`, named colors, `rgb()`). Components in [0, 1].
+    Rgb,
+    /// `hsl()` — hue in degrees, saturation and lightness in [0, 100].
+    Hsl,
+    /// `hwb()` — hue in degrees, whiteness and blackness in [0, 100].
+    Hwb,
+    /// `lab()` — CIELAB (D50), L in [0, 100].
+    Lab,
+    /// `lch()` — CIELCh (D50), L in [0, 100], hue in degrees.
+    Lch,
+    /// `oklab()` — L in [0, 1].
+    Oklab,
+    /// `oklch()` — L in [0, 1], hue in degrees.
+    Oklch,
+    /// `color(srgb …)`.
+    Srgb,
+    /// `color(srgb-linear …)`.
+    SrgbLinear,
+    /// `color(display-p3 …)`.
+    DisplayP3,
+    /// `color(a98-rgb …)`.
+    A98Rgb,
+    /// `color(prophoto-rgb …)` (D50).
+    ProphotoRgb,
+    /// `color(rec2020 …)`.
+    Rec2020,
+    /// `color(xyz-d50 …)`.
+    XyzD50,
+    /// `color(xyz-d65 …)` (also `color(xyz …)`).
+    XyzD65,
+}
+
+/// Role of a component, used to carry missing components across spaces
+/// (CSS Color 4 §12.2 "analogous components").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComponentKind {
+    Red,
+    Green,
+    Blue,
+    Lightness,
+    Colorfulness,
+    Hue,
+    OpponentA,
+    OpponentB,
+    Other,
+}
+
+impl CssColorSpace {
+    /// Parse a `color()` / `color-mix()` color space identifier.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "srgb" => Some(Self::Srgb),
+            "srgb-linear" => Some(Self::SrgbLinear),
+            "display-p3" => Some(Self::DisplayP3),
+            "a98-rgb" => Some(Self::A98Rgb),
+            "prophoto-rgb" => Some(Self::ProphotoRgb),
+            "rec2020" => Some(Self::Rec2020),
+            "xyz" | "xyz-d65" => Some(Self::XyzD65),
+            "xyz-d50" => Some(Self::XyzD50),
+            "hsl" => Some(Self::Hsl),
+            "hwb" => Some(Self::Hwb),
+            "lab" => Some(Self::Lab),
+            "lch" => Some(Self::Lch),
+            "oklab" => Some(Self::Oklab),
+            "oklch" => Some(Self::Oklch),
+            _ => None,
+        }
+    }
+
+    /// CSS identifier of this space (`rgb` for the legacy sRGB syntax).
+    #[must_use]
+    pub const fn css_name(self) -> &'static str {
+        match self {
+            Self::Rgb => "rgb",
+            Self::Hsl => "hsl",
+            Self::Hwb => "hwb",
+            Self::Lab => "lab",
+            Self::Lch => "lch",
+            Self::Oklab => "oklab",
+            Self::Oklch => "oklch",
+            Self::Srgb => "srgb",
+            Self::SrgbLinear => "srgb-linear",
+            Self::DisplayP3 => "display-p3",
+            Self::A98Rgb => "a98-rgb",
+            Self::ProphotoRgb => "prophoto-rgb",
+            Self::Rec2020 => "rec2020",
+            Self::XyzD50 => "xyz-d50",
+            Self::XyzD65 => "xyz-d65",
+        }
+    }
+
+    /// Whether this space is written with the `color()` function.
+    #[must_use]
+    pub const fn is_predefined(self) -> bool {
+        matches!(
+            self,
+            Self::Srgb
+                | Self::SrgbLinear
+                | Self::DisplayP3
+                | Self::A98Rgb
+                | Self::ProphotoRgb
+                | Self::Rec2020
+                | Self::XyzD50
+                | Self::XyzD65
+        )
+    }
+
+    /// Index of the hue component, for polar spaces.
+    #[must_use]
+    pub const fn hue_index(self) -> Option<usize> {
+        match self {
+            Self::Hsl | Self::Hwb => Some(0),
+            Self::Lch | Self::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    /// The RGB space backing this CSS space, when it is one of [`RgbSpace`].
+    #[must_use]
+    pub const fn rgb_space(self) -> Option<RgbSpace> {
+        match self {
+            Self::Rgb | Self::Srgb | Self::Hsl | Self::Hwb => Some(RgbSpace::Srgb),
+            Self::DisplayP3 => Some(RgbSpace::DisplayP3),
+            Self::A98Rgb => Some(RgbSpace::AdobeRgb),
+            Self::Rec2020 => Some(RgbSpace::Rec2020),
+            _ => None,
+        }
+    }
+
+    const fn component_kinds(self) -> [ComponentKind; 3] {
+        use ComponentKind::*;
+        match self {
+            Self::Rgb
+            | Self::Srgb
+            | Self::SrgbLinear
+            | Self::DisplayP3
+            | Self::A98Rgb
+            | Self::ProphotoRgb
+            | Self::Rec2020
+            | Self::XyzD50
+            | Self::XyzD65 => [Red, Green, Blue],
+            Self::Hsl => [Hue, Colorfulness, Lightness],
+            Self::Hwb => [Hue, Other, Other],
+            Self::Lab | Self::Oklab => [Lightness, OpponentA, OpponentB],
+            Self::Lch | Self::Oklch => [Lightness, Colorfulness, Hue],
+        }
+    }
+}
+
+impl From<RgbSpace> for CssColorSpace {
+    fn from(space: RgbSpace) -> Self {
+        match space {
+            RgbSpace::Srgb => Self::Srgb,
+            RgbSpace::DisplayP3 => Self::DisplayP3,
+            RgbSpace::Rec2020 => Self::Rec2020,
+            RgbSpace::AdobeRgb => Self::A98Rgb,
+        }
+    }
+}
+
+impl fmt::Display for CssColorSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.css_name())
+    }
+}
+
+/// Hue interpolation method for `color-mix()` in polar spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueInterpolation {
+    /// Take the shorter arc (default).
+    #[default]
+    Shorter,
+    /// Take the longer arc.
+    Longer,
+    /// Always move toward increasing hue angles.
+    Increasing,
+    /// Always move toward decreasing hue angles.
+    Decreasing,
+}
+
+// ============================================================================
+// CssColor
+// ============================================================================
+
+/// A color as written in CSS: a color space plus three components and alpha.
+///
+/// Components are `None` when the author wrote `none` (a *missing*
+/// component). Missing components behave as zero when converting, but are
+/// preserved by serialization and filled from the other color by
+/// [`CssColor::mix`].
+///
+/// Component units follow the space: see [`CssColorSpace`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CssColor {
+    /// Color space the components are expressed in.
+    pub space: CssColorSpace,
+    /// The three color components (`None` = missing).
+    pub components: [Option<f64>; 3],
+    /// Alpha in [0, 1] (`None` = missing).
+    pub alpha: Option<f64>,
+}
+
+impl CssColor {
+    /// Create a color with all components present.
+    #[must_use]
+    pub const fn new(space: CssColorSpace, components: [f64; 3], alpha: f64) -> Self {
+        Self {
+            space,
+            components: [
+                Some(components[0]),
+                Some(components[1]),
+                Some(components[2]),
+            ],
+            alpha: Some(alpha),
+        }
+    }
+
+    /// Parse any CSS Color 4/5 color value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CssColorError`] describing the first problem found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::css::{CssColor, CssColorSpace};
+    ///
+    /// let c = CssColor::parse("color(display-p3 1 0.5 none / 80%)").unwrap();
+    /// assert_eq!(c.space, CssColorSpace::DisplayP3);
+    /// assert_eq!(c.components, [Some(1.0), Some(0.5), None]);
+    /// assert_eq!(c.alpha, Some(0.8));
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, CssColorError> {
+        parser::parse(input)
+    }
+
+    /// Components with missing values replaced by zero.
+    #[must_use]
+    pub fn resolved_components(&self) -> [f64; 3] {
+        [
+            self.components[0].unwrap_or(0.0),
+            self.components[1].unwrap_or(0.0),
+            self.components[2].unwrap_or(0.0),
+        ]
+    }
+
+    /// Convert to a [`Color`].
+    ///
+    /// Wide-gamut colors keep their out-of-sRGB values (channels may fall
+    /// outside [0, 1]); missing components count as zero.
+    #[must_use]
+    pub fn to_color(&self) -> Color {
+        let c = self.resolved_components();
+        // RGB-based spaces skip the XYZ round trip to keep sRGB values exact
+        let mut color = match self.space {
+            CssColorSpace::Hsl => from_srgb_extended(hsl_to_srgb(c)),
+            CssColorSpace::Hwb => from_srgb_extended(hwb_to_srgb(c)),
+            space => match space.rgb_space() {
+                Some(rgb) => Color::from_rgb_space(rgb, c[0], c[1], c[2]),
+                None => Color::from_xyz_d65(to_xyz_d65(space, c)),
+            },
+        };
+        color.alpha = self.alpha.unwrap_or(0.0).clamp(0.0, 1.0);
+        color
+    }
+
+    /// Express a [`Color`] in the given CSS color space.
+    #[must_use]
+    pub fn from_color(color: &Color, space: CssColorSpace) -> Self {
+        let components = match space {
+            CssColorSpace::Hsl => srgb_to_hsl(color.to_rgb_space(RgbSpace::Srgb)),
+            CssColorSpace::Hwb => srgb_to_hwb(color.to_rgb_space(RgbSpace::Srgb)),
+            _ => match space.rgb_space() {
+                Some(rgb) => color.to_rgb_space(rgb),
+                None => from_xyz_d65(space, color.to_xyz_d65()),
+            },
+        };
+        Self::new(space, components, color.alpha)
+    }
+
+    /// Convert to another CSS color space. Missing components count as zero.
+    #[must_use]
+    pub fn convert(&self, space: CssColorSpace) -> Self {
+        if space == self.space {
+            return *self;
+        }
+        let xyz = to_xyz_d65(self.space, self.resolved_components());
+        let c = from_xyz_d65(space, xyz);
+        Self {
+            space,
+            components: [Some(c[0]), Some(c[1]), Some(c[2])],
+            alpha: self.alpha,
+        }
+    }
+
+    /// Mix two colors as `color-mix()` does (CSS Color 5 §2).
+    ///
+    /// `p1`/`p2` are the percentages (0–100) written after each color; pass
+    /// `None` for an omitted percentage. Returns `None` if both percentages
+    /// are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::css::{CssColor, CssColorSpace, HueInterpolation};
+    ///
+    /// let red = CssColor::parse("red").unwrap();
+    /// let blue = CssColor::parse("blue").unwrap();
+    /// let mix = CssColor::mix(&red, None, &blue, None, CssColorSpace::Srgb, HueInterpolation::Shorter).unwrap();
+    /// assert_eq!(mix.to_string(), "color(srgb 0.5 0 0.5)");
+    /// ```
+    #[must_use]
+    pub fn mix(
+        c1: &Self,
+        p1: Option<f64>,
+        c2: &Self,
+        p2: Option<f64>,
+        space: CssColorSpace,
+        hue: HueInterpolation,
+    ) -> Option<Self> {
+        let (p1, p2) = match (p1, p2) {
+            (None, None) => (50.0, 50.0),
+            (Some(a), None) => (a, 100.0 - a),
+            (None, Some(b)) => (100.0 - b, b),
+            (Some(a), Some(b)) => (a, b),
+        };
+        let sum = p1 + p2;
+        if sum <= 0.0 {
+            return None;
+        }
+        let alpha_mult = if sum < 100.0 { sum / 100.0 } else { 1.0 };
+        let t = p2 / sum;
+
+        let mut a = c1.to_interpolation_space(space);
+        let mut b = c2.to_interpolation_space(space);
+
+        // Missing components take the other color's value.
+        for i in 0..3 {
+            match (a.components[i], b.components[i]) {
+                (None, Some(v)) => a.components[i] = Some(v),
+                (Some(v), None) => b.components[i] = Some(v),
+                _ => {}
+            }
+        }
+        match (a.alpha, b.alpha) {
+            (None, Some(v)) => a.alpha = Some(v),
+            (Some(v), None) => b.alpha = Some(v),
+            _ => {}
+        }
+
+        let alpha_a = a.alpha.unwrap_or(1.0);
+        let alpha_b = b.alpha.unwrap_or(1.0);
+        let alpha = alpha_a + (alpha_b - alpha_a) * t;
+        let hue_index = space.hue_index();
+
+        let mut components = [None; 3];
+        for (i, out) in components.iter_mut().enumerate() {
+            let (Some(va), Some(vb)) = (a.components[i], b.components[i]) else {
+                continue;
+            };
+            *out = Some(if Some(i) == hue_index {
+                let (ha, hb) = fixup_hues(va, vb, hue);
+                (ha + (hb - ha) * t).rem_euclid(360.0)
+            } else {
+                // Premultiplied interpolation
+                let v = va * alpha_a + (vb * alpha_b - va * alpha_a) * t;
+                if alpha != 0.0 {
+                    v / alpha
+                } else {
+                    v
+                }
+            });
+        }
+
+        let out_alpha = if a.alpha.is_none() && b.alpha.is_none() {
+            None
+        } else {
+            Some(alpha * alpha_mult)
+        };
+
+        Some(Self {
+            space,
+            components,
+            alpha: out_alpha,
+        })
+    }
+
+    /// Convert for interpolation, carrying analogous missing components
+    /// forward and marking achromatic hues as missing.
+    fn to_interpolation_space(self, space: CssColorSpace) -> Self {
+        let mut out = self.convert(space);
+        if space == self.space {
+            return out;
+        }
+        let src_kinds = self.space.component_kinds();
+        let dst_kinds = space.component_kinds();
+        for (i, kind) in src_kinds.iter().enumerate() {
+            if self.components[i].is_none() && *kind != ComponentKind::Other {
+                if let Some(j) = dst_kinds.iter().position(|k| k == kind) {
+                    out.components[j] = None;
+                }
+            }
+        }
+        if let Some(h) = space.hue_index() {
+            let c = out.resolved_components();
+            let achromatic = match space {
+                CssColorSpace::Hsl => c[1].abs() < 1e-6,
+                CssColorSpace::Hwb => c[1] + c[2] >= 100.0 - 1e-6,
+                CssColorSpace::Lch => c[1] < 1e-4,
+                _ => c[1] < 1e-6,
+            };
+            if achromatic {
+                out.components[h] = None;
+            }
+        }
+        out
+    }
+}
+
+impl core::str::FromStr for CssColor {
+    type Err = CssColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+// ============================================================================
+// Serialization
+// ============================================================================
+
+impl fmt::Display for CssColor {
+    /// Serialize per CSS Color 4 §15.
+    ///
+    /// Legacy sRGB syntaxes (`Rgb`, `Hsl`, `Hwb`) serialize as `rgb()`/`rgba()`
+    /// with 0–255 integers; everything else keeps its own function.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alpha = self.alpha;
+        match self.space {
+            CssColorSpace::Rgb | CssColorSpace::Hsl | CssColorSpace::Hwb => {
+                let srgb = self.convert(CssColorSpace::Rgb).resolved_components();
+                let [r, g, b] = srgb.map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8);
+                match alpha {
+                    Some(a) if a < 1.0 => {
+                        write!(f, "rgba({}, {}, {}, {})", r, g, b, format_number(a))
+                    }
+                    None => write!(f, "rgba({}, {}, {}, 0)", r, g, b),
+                    _ => write!(f, "rgb({}, {}, {})", r, g, b),
+                }
+            }
+            space => {
+                if space.is_predefined() {
+                    write!(f, "color({} ", space.css_name())?;
+                } else {
+                    write!(f, "{}(", space.css_name())?;
+                }
+                for (i, c) in self.components.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    match c {
+                        Some(v) => f.write_str(&format_number(*v))?,
+                        None => f.write_str("none")?,
+                    }
+                }
+                match alpha {
+                    Some(a) if a >= 1.0 => {}
+                    Some(a) => write!(f, " / {}", format_number(a))?,
+                    None => f.write_str(" / none")?,
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// Format a component with up to six decimals, trimming trailing zeros.
+fn format_number(v: f64) -> String {
+    let s = format!("{:.6}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+// ============================================================================
+// Color integration
+// ============================================================================
+
+impl Color {
+    /// Parse any CSS Color 4/5 color string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::Color;
+    ///
+    /// let a = Color::from_css("rebeccapurple").unwrap();
+    /// let b = Color::from_css("rgb(102 51 153)").unwrap();
+    /// assert_eq!(a.to_srgb8(), b.to_srgb8());
+    ///
+    /// let translucent = Color::from_css("#3b82f680").unwrap();
+    /// assert!((translucent.alpha - 0.502).abs() < 0.001);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CssColorError`] if the string is not a valid CSS color.
+    pub fn from_css(input: &str) -> Result<Self, CssColorError> {
+        Ok(CssColor::parse(input)?.to_color())
+    }
+
+    /// Serialize this color in the given CSS color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::Color;
+    /// use momoto_core::color::css::CssColorSpace;
+    ///
+    /// let white = Color::from_srgb8(255, 255, 255);
+    /// assert_eq!(white.to_css(CssColorSpace::Rgb), "rgb(255, 255, 255)");
+    /// assert_eq!(white.to_css(CssColorSpace::DisplayP3), "color(display-p3 1 1 1)");
+    /// ```
+    #[must_use]
+    pub fn to_css(&self, space: CssColorSpace) -> String {
+        CssColor::from_color(self, space).to_string()
+    }
+}
+
+// ============================================================================
+// Conversions (all spaces ↔ XYZ D65, white Y = 1.0)
+// ============================================================================
+
+/// Linear ProPhoto RGB → XYZ D50.
+const PROPHOTO_TO_XYZ_D50: [[f64; 3]; 3] = [
+    [0.7977666449006423, 0.13518129740053308, 0.0313477341283922],
+    [0.2880748288194013, 0.711835234241873, 0.00008993693872564],
+    [0.0, 0.0, 0.8251046025104602],
+];
+
+/// XYZ D50 → linear ProPhoto RGB.
+const XYZ_D50_TO_PROPHOTO: [[f64; 3]; 3] = [
+    [
+        1.3457868816471583,
+        -0.25557208737979464,
+        -0.05110186497554526,
+    ],
+    [-0.5446307051249019, 1.5082477428451468, 0.02052744743642139],
+    [0.0, 0.0, 1.2119675456389452],
+];
+
+/// D50 → D65 with the Bradford transform, as CSS Color 4 specifies.
+fn d50_to_d65(xyz: [f64; 3]) -> [f64; 3] {
+    Xyz::from(xyz)
+        .adapt(
+            Illuminant::D50,
+            Illuminant::D65,
+            ChromaticAdaptation::Bradford,
+        )
+        .to_array()
+}
+
+/// D65 → D50 with the Bradford transform, as CSS Color 4 specifies.
+fn d65_to_d50(xyz: [f64; 3]) -> [f64; 3] {
+    Xyz::from(xyz)
+        .adapt(
+            Illuminant::D65,
+            Illuminant::D50,
+            ChromaticAdaptation::Bradford,
+        )
+        .to_array()
+}
+
+fn to_xyz_d65(space: CssColorSpace, c: [f64; 3]) -> [f64; 3] {
+    match space {
+        CssColorSpace::Rgb
+        | CssColorSpace::Srgb
+        | CssColorSpace::DisplayP3
+        | CssColorSpace::A98Rgb
+        | CssColorSpace::Rec2020 => {
+            let rgb = space.rgb_space().unwrap_or(RgbSpace::Srgb);
+            rgb.linear_to_xyz(rgb.decode(c))
+        }
+        CssColorSpace::SrgbLinear => RgbSpace::Srgb.linear_to_xyz(c),
+        CssColorSpace::Hsl => to_xyz_d65(CssColorSpace::Rgb, hsl_to_srgb(c)),
+        CssColorSpace::Hwb => to_xyz_d65(CssColorSpace::Rgb, hwb_to_srgb(c)),
+        CssColorSpace::ProphotoRgb => {
+            let tf = prophoto_transfer();
+            let linear = [tf.decode(c[0]), tf.decode(c[1]), tf.decode(c[2])];
+            d50_to_d65(mul(&PROPHOTO_TO_XYZ_D50, linear))
+        }
+        CssColorSpace::XyzD65 => c,
+        CssColorSpace::XyzD50 => d50_to_d65(c),
+        CssColorSpace::Lab => d50_to_d65(
+            Lab::new(c[0], c[1], c[2])
+                .to_xyz(Illuminant::D50)
+                .to_array(),
+        ),
+        CssColorSpace::Lch => d50_to_d65(
+            Lch::new(c[0], c[1], c[2])
+                .to_lab()
+                .to_xyz(Illuminant::D50)
+                .to_array(),
+        ),
+        CssColorSpace::Oklab => OKLab::new(c[0], c[1], c[2]).to_xyz().to_array(),
+        CssColorSpace::Oklch => {
+            let [l, a, b] = polar_to_cartesian(c);
+            OKLab::new(l, a, b).to_xyz().to_array()
+        }
+    }
+}
+
+fn from_xyz_d65(space: CssColorSpace, xyz: [f64; 3]) -> [f64; 3] {
+    match space {
+        CssColorSpace::Rgb
+        | CssColorSpace::Srgb
+        | CssColorSpace::DisplayP3
+        | CssColorSpace::A98Rgb
+        | CssColorSpace::Rec2020 => {
+            let rgb = space.rgb_space().unwrap_or(RgbSpace::Srgb);
+            rgb.encode(rgb.xyz_to_linear(xyz))
+        }
+        CssColorSpace::SrgbLinear => RgbSpace::Srgb.xyz_to_linear(xyz),
+        CssColorSpace::Hsl => srgb_to_hsl(from_xyz_d65(CssColorSpace::Rgb, xyz)),
+        CssColorSpace::Hwb => srgb_to_hwb(from_xyz_d65(CssColorSpace::Rgb, xyz)),
+        CssColorSpace::ProphotoRgb => {
+            let tf = prophoto_transfer();
+            let linear = mul(&XYZ_D50_TO_PROPHOTO, d65_to_d50(xyz));
+            [
+                tf.encode(linear[0]),
+                tf.encode(linear[1]),
+                tf.encode(linear[2]),
+            ]
+        }
+        CssColorSpace::XyzD65 => xyz,
+        CssColorSpace::XyzD50 => d65_to_d50(xyz),
+        CssColorSpace::Lab => {
+            let lab = Lab::from_xyz(d65_to_d50(xyz).into(), Illuminant::D50);
+            [lab.l, lab.a, lab.b]
+        }
+        CssColorSpace::Lch => {
+            let lch = Lab::from_xyz(d65_to_d50(xyz).into(), Illuminant::D50).to_lch();
+            [lch.l, lch.c, lch.h]
+        }
+        CssColorSpace::Oklab => {
+            let lab = OKLab::from_xyz(xyz.into());
+            [lab.l, lab.a, lab.b]
+        }
+        CssColorSpace::Oklch => {
+            let lab = OKLab::from_xyz(xyz.into());
+            cartesian_to_polar([lab.l, lab.a, lab.b])
+        }
+    }
+}
+
+fn from_srgb_extended(srgb: [f64; 3]) -> Color {
+    Color::from_rgb_space(RgbSpace::Srgb, srgb[0], srgb[1], srgb[2])
+}
+
+/// ProPhoto uses a 1.8 gamma with a linear toe below 1/512; the toe only
+/// affects values under 0.2% so the pure power law is used here.
+const fn prophoto_transfer() -> TransferFunction {
+    TransferFunction::Gamma(1.8)
+}
+
+fn polar_to_cartesian(lch: [f64; 3]) -> [f64; 3] {
+    let h = lch[2].to_radians();
+    [lch[0], lch[1] * ops::cos(h), lch[1] * ops::sin(h)]
+}
+
+fn cartesian_to_polar(lab: [f64; 3]) -> [f64; 3] {
+    let c = (lab[1] * lab[1] + lab[2] * lab[2]).sqrt();
+    let h = ops::atan2(lab[2], lab[1]).to_degrees().rem_euclid(360.0);
+    [lab[0], c, h]
+}
+
+fn hsl_to_srgb(hsl: [f64; 3]) -> [f64; 3] {
+    let h = hsl[0].rem_euclid(360.0);
+    let s = hsl[1] / 100.0;
+    let l = hsl[2] / 100.0;
+    let f = |n: f64| {
+        let k = (n + h / 30.0) % 12.0;
+        let a = s * l.min(1.0 - l);
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+fn srgb_to_hsl(rgb: [f64; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (min + max) / 2.0;
+    let d = max - min;
+    let (mut h, mut s) = (0.0, 0.0);
+    if d != 0.0 {
+        s = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            (max - l) / l.min(1.0 - l)
+        };
+        h = if max == r {
+            (g - b) / d + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        h *= 60.0;
+    }
+    if s < 0.0 {
+        h += 180.0;
+        s = s.abs();
+    }
+    [h.rem_euclid(360.0), s * 100.0, l * 100.0]
+}
+
+fn hwb_to_srgb(hwb: [f64; 3]) -> [f64; 3] {
+    let w = hwb[1] / 100.0;
+    let b = hwb[2] / 100.0;
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return [gray, gray, gray];
+    }
+    hsl_to_srgb([hwb[0], 100.0, 50.0]).map(|c| c * (1.0 - w - b) + w)
+}
+
+fn srgb_to_hwb(rgb: [f64; 3]) -> [f64; 3] {
+    let h = srgb_to_hsl(rgb)[0];
+    let w = rgb[0].min(rgb[1]).min(rgb[2]);
+    let b = 1.0 - rgb[0].max(rgb[1]).max(rgb[2]);
+    [h, w * 100.0, b * 100.0]
+}
+
+/// Adjust two hues for interpolation along the requested arc.
+fn fixup_hues(h1: f64, h2: f64, method: HueInterpolation) -> (f64, f64) {
+    let mut a = h1.rem_euclid(360.0);
+    let mut b = h2.rem_euclid(360.0);
+    let d = b - a;
+    match method {
+        HueInterpolation::Shorter => {
+            if d > 180.0 {
+                a += 360.0;
+            } else if d < -180.0 {
+                b += 360.0;
+            }
+        }
+        HueInterpolation::Longer => {
+            if 0.0 < d && d < 180.0 {
+                a += 360.0;
+            } else if -180.0 < d && d <= 0.0 {
+                b += 360.0;
+            }
+        }
+        HueInterpolation::Increasing => {
+            if d < 0.0 {
+                b += 360.0;
+            }
+        }
+        HueInterpolation::Decreasing => {
+            if d > 0.0 {
+                a += 360.0;
+            }
+        }
+    }
+    (a, b)
+}
+
+#[inline]
+fn mul(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> CssColor {
+        CssColor::parse(s).unwrap_or_else(|e| panic!("{}: {}", s, e))
+    }
+
+    fn assert_rgb8(s: &str, expected: [u8; 3]) {
+        let color = parse(s).to_color();
+        assert_eq!(color.to_srgb8(), expected, "{}", s);
+    }
+
+    fn assert_close(a: [f64; 3], b: [f64; 3], tol: f64) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < tol, "{:?} vs {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_hex_and_named() {
+        assert_rgb8("#f80", [255, 136, 0]);
+        assert_rgb8("#FF8000", [255, 128, 0]);
+        assert_rgb8("rebeccapurple", [102, 51, 153]);
+        let c = parse("#3b82f680");
+        assert!((c.alpha.unwrap() - 128.0 / 255.0).abs() < 1e-9);
+        assert_eq!(parse("transparent").alpha, Some(0.0));
+        assert!(CssColor::parse("#12345").is_err());
+        assert!(CssColor::parse("notacolor").is_err());
+        assert!(matches!(
+            CssColor::parse("currentcolor"),
+            Err(CssColorError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_rgb_legacy_and_modern() {
+        assert_rgb8("rgb(59, 130, 246)", [59, 130, 246]);
+        assert_rgb8("rgba(59, 130, 246, 0.5)", [59, 130, 246]);
+        assert_rgb8("rgb(59 130 246 / 50%)", [59, 130, 246]);
+        assert_rgb8("rgb(100% 60% 0%)", [255, 153, 0]);
+        assert_rgb8("rgb(300 -10 0)", [255, 0, 0]); // clamped
+        assert_eq!(parse("rgb(59 130 246 / 50%)").alpha, Some(0.5));
+        assert_eq!(parse("rgb(none 130 246)").components[0], None);
+        // Legacy syntax may not mix commas with `none`
+        assert!(CssColor::parse("rgb(none, 130, 246)").is_err());
+        assert!(CssColor::parse("rgb(59, 130 246)").is_err());
+    }
+
+    #[test]
+    fn test_hsl_hwb() {
+        assert_rgb8("hsl(120, 100%, 25%)", [0, 128, 0]);
+        assert_rgb8("hsl(0.5turn 100% 50%)", [0, 255, 255]);
+        assert_rgb8("hsla(240deg 100% 50% / 0.3)", [0, 0, 255]);
+        assert_rgb8("hwb(0 0% 0%)", [255, 0, 0]);
+        assert_rgb8("hwb(90 50% 50%)", [128, 128, 128]);
+    }
+
+    #[test]
+    fn test_lab_lch_golden() {
+        // CSS Color 4 §9 examples: lab(29.2345% 39.3825 20.0664) = rgb(125 35 41)
+        assert_rgb8("lab(29.2345% 39.3825 20.0664)", [125, 35, 41]);
+        assert_rgb8("lch(29.2345% 44.2 27)", [125, 35, 41]);
+        // White and black are exact
+        assert_rgb8("lab(100 0 0)", [255, 255, 255]);
+        assert_rgb8("lch(0 0 0)", [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_oklab_oklch() {
+        assert_rgb8("oklch(62.8% 0.2577 29.23)", [255, 0, 0]);
+        assert_rgb8("oklab(0.628 0.2249 0.1258)", [255, 0, 0]);
+        assert_rgb8("oklch(100% 0 0)", [255, 255, 255]);
+        let c = parse("oklch(0.7 40% 120deg)");
+        assert_close(c.resolved_components(), [0.7, 0.16, 120.0], 1e-12);
+    }
+
+    #[test]
+    fn test_predefined_spaces() {
+        assert_rgb8("color(srgb 1 0.6 0)", [255, 153, 0]);
+        assert_rgb8("color(srgb-linear 1 0.318547 0)", [255, 153, 0]);
+        assert_rgb8("color(xyz-d65 0.9505 1 1.089)", [255, 255, 255]);
+        assert_rgb8("color(xyz-d50 0.9643 1 0.8251)", [255, 255, 255]);
+        assert_rgb8("color(prophoto-rgb 1 1 1)", [255, 255, 255]);
+        let p3 = parse("color(display-p3 1 0 0)").to_color();
+        assert!(p3.srgb[0] > 1.0 && p3.srgb[1] < 0.0, "{:?}", p3.srgb);
+        assert!(CssColor::parse("color(cmyk 1 0 0)").is_err());
+    }
+
+    #[test]
+    fn test_roundtrip_all_spaces() {
+        let color = Color::from_srgb8(59, 130, 246);
+        for space in [
+            CssColorSpace::Rgb,
+            CssColorSpace::Hsl,
+            CssColorSpace::Hwb,
+            CssColorSpace::Lab,
+            CssColorSpace::Lch,
+            CssColorSpace::Oklab,
+            CssColorSpace::Oklch,
+            CssColorSpace::Srgb,
+            CssColorSpace::SrgbLinear,
+            CssColorSpace::DisplayP3,
+            CssColorSpace::A98Rgb,
+            CssColorSpace::ProphotoRgb,
+            CssColorSpace::Rec2020,
+            CssColorSpace::XyzD50,
+            CssColorSpace::XyzD65,
+        ] {
+            let css = color.to_css(space);
+            let back = Color::from_css(&css).unwrap_or_else(|e| panic!("{}: {}", css, e));
+            assert_eq!(back.to_srgb8(), color.to_srgb8(), "{:?}: {}", space, css);
+        }
+    }
+
+    #[test]
+    fn test_serialization() {
+        assert_eq!(parse("#ff0000").to_string(), "rgb(255, 0, 0)");
+        assert_eq!(
+            parse("hsl(0 100% 50% / 25%)").to_string(),
+            "rgba(255, 0, 0, 0.25)"
+        );
+        assert_eq!(
+            parse("oklch(0.5 0.1 none / 0.5)").to_string(),
+            "oklch(0.5 0.1 none / 0.5)"
+        );
+        assert_eq!(parse("LAB(50% 0 0)").to_string(), "lab(50 0 0)");
+        assert_eq!(
+            parse("color(display-p3 1 0.5 0)").to_string(),
+            "color(display-p3 1 0.5 0)"
+        );
+    }
+
+    #[test]
+    fn test_relative_colors() {
+        let base = parse("oklch(0.6 0.15 250)");
+        let rel = parse("oklch(from oklch(0.6 0.15 250) calc(l + 0.1) c h)");
+        assert_close(
+            rel.resolved_components(),
+            [0.7, base.components[1].unwrap(), 250.0],
+            1e-9,
+        );
+        // Keyword swizzle and constants
+        let rel = parse("rgb(from rgb(10 20 30) b g r / 0.5)");
+        assert_eq!(rel.to_color().to_srgb8(), [30, 20, 10]);
+        assert_eq!(rel.alpha, Some(0.5));
+        // Numbers replace channels; `alpha` keyword refers to the origin alpha
+        let rel = parse("hsl(from #ff000080 h s 25% / alpha)");
+        assert!((rel.alpha.unwrap() - 128.0 / 255.0).abs() < 1e-9);
+        assert_rgb8("hsl(from #ff0000 calc(h + 120) s l)", [0, 255, 0]);
+        assert_rgb8("color(from red display-p3 r g b)", [255, 0, 0]);
+        assert_rgb8("lch(from white calc(l / 2) 0 0)", [119, 119, 119]);
+        assert!(CssColor::parse("oklch(from red q c h)").is_err());
+    }
+
+    #[test]
+    fn test_color_mix() {
+        let mix = parse("color-mix(in srgb, red, blue)");
+        assert_close(mix.resolved_components(), [0.5, 0.0, 0.5], 1e-9);
+
+        let mix = parse("color-mix(in srgb, red 25%, blue)");
+        assert_close(mix.resolved_components(), [0.25, 0.0, 0.75], 1e-9);
+
+        // Percentages summing below 100% scale alpha
+        let mix = parse("color-mix(in srgb, red 20%, blue 20%)");
+        assert!((mix.alpha.unwrap() - 0.4).abs() < 1e-9);
+
+        // Hue interpolation: red (≈29°) to blue (≈264°) shorter arc passes 360°
+        let shorter = parse("color-mix(in oklch, red, blue)");
+        let longer = parse("color-mix(in oklch longer hue, red, blue)");
+        let hs = shorter.components[2].unwrap();
+        let hl = longer.components[2].unwrap();
+        assert!(!(90.0..270.0).contains(&hs), "shorter hue {}", hs);
+        assert!((90.0..270.0).contains(&hl), "longer hue {}", hl);
+
+        // Achromatic colors do not pull the hue
+        let mix = parse("color-mix(in oklch, white, blue)");
+        let blue = parse("blue").convert(CssColorSpace::Oklch);
+        assert!((mix.components[2].unwrap() - blue.components[2].unwrap()).abs() < 1e-6);
+
+        // Missing components are taken from the other color
+        let mix = parse("color-mix(in oklch, oklch(0.5 none 100), oklch(0.7 0.2 none))");
+        assert_close(mix.resolved_components(), [0.6, 0.2, 100.0], 1e-9);
+
+        // Premultiplied alpha: transparent contributes no color
+        let mix = parse("color-mix(in srgb, transparent, blue)");
+        assert_close(mix.resolved_components(), [0.0, 0.0, 1.0], 1e-9);
+        assert!((mix.alpha.unwrap() - 0.5).abs() < 1e-9);
+
+        assert!(CssColor::parse("color-mix(in srgb, red 0%, blue 0%)").is_err());
+        assert!(CssColor::parse("color-mix(in cmyk, red, blue)").is_err());
+    }
+
+    #[test]
+    fn test_nested_color_mix_and_relative() {
+        let c = parse("oklch(from color-mix(in oklab, red, white) l 0 h)");
+        assert_eq!(c.components[1], Some(0.0));
+    }
+
+    #[test]
+    fn test_from_css_errors_display() {
+        let err = Color::from_css("rgb(1 2)").unwrap_err();
+        assert!(!err.to_string().is_empty());
+        assert_eq!(CssColor::parse("   "), Err(CssColorError::Empty));
+    }
+}
@@ -0,0 +1,684 @@
+//! Tokenizer and recursive-descent parser for CSS color values.
+//!
+//! The grammar covered here is the subset of CSS Syntax needed for color
+//! values: identifiers, functions, hashes, numbers, percentages, angles and
+//! the `,` `/` `+` `-` `*` delimiters used by color functions and `calc()`.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use super::{named, CssColor, CssColorError, CssColorSpace, HueInterpolation};
+
+// ============================================================================
+// Tokenizer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Function(String),
+    Hash(String),
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, String),
+    Comma,
+    Slash,
+    Delim(char),
+    OpenParen,
+    CloseParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ident(s) => write!(f, "{}", s),
+            Self::Function(s) => write!(f, "{}(", s),
+            Self::Hash(s) => write!(f, "#{}", s),
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Percentage(n) => write!(f, "{}%", n),
+            Self::Dimension(n, unit) => write!(f, "{}{}", n, unit),
+            Self::Comma => write!(f, ","),
+            Self::Slash => write!(f, "/"),
+            Self::Delim(c) => write!(f, "{}", c),
+            Self::OpenParen => write!(f, "("),
+            Self::CloseParen => write!(f, ")"),
+        }
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+}
+
+fn is_ident_char(c: char) -> bool {
+    is_ident_start(c) || c.is_ascii_digit() || c == '-'
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, CssColorError> {
+    let chars: Vec<char> = input.chars().collect();
+    let at = |i: usize| chars.get(i).copied();
+    let starts_number = |i: usize| match at(i) {
+        Some(c) if c.is_ascii_digit() => true,
+        Some('.') => at(i + 1).is_some_and(|c| c.is_ascii_digit()),
+        Some('+' | '-') => {
+            at(i + 1).is_some_and(|c| c.is_ascii_digit())
+                || (at(i + 1) == Some('.') && at(i + 2).is_some_and(|c| c.is_ascii_digit()))
+        }
+        _ => false,
+    };
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while let Some(c) = at(i) {
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '#' {
+            let start = i + 1;
+            i = start;
+            while at(i).is_some_and(is_ident_char) {
+                i += 1;
+            }
+            tokens.push(Token::Hash(chars[start..i].iter().collect()));
+        } else if starts_number(i) {
+            let start = i;
+            if matches!(c, '+' | '-') {
+                i += 1;
+            }
+            while at(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+            if at(i) == Some('.') && at(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+                while at(i).is_some_and(|c| c.is_ascii_digit()) {
+                    i += 1;
+                }
+            }
+            if matches!(at(i), Some('e' | 'E')) {
+                let digit_at = if matches!(at(i + 1), Some('+' | '-')) {
+                    i + 2
+                } else {
+                    i + 1
+                };
+                if at(digit_at).is_some_and(|c| c.is_ascii_digit()) {
+                    i = digit_at;
+                    while at(i).is_some_and(|c| c.is_ascii_digit()) {
+                        i += 1;
+                    }
+                }
+            }
+            let text: String = chars[start..i].iter().collect();
+            let value: f64 = text
+                .parse()
+                .map_err(|_| CssColorError::UnexpectedToken(text.clone()))?;
+            if at(i) == Some('%') {
+                i += 1;
+                tokens.push(Token::Percentage(value));
+            } else if at(i).is_some_and(is_ident_start) {
+                let unit_start = i;
+                while at(i).is_some_and(is_ident_char) {
+                    i += 1;
+                }
+                let unit: String = chars[unit_start..i].iter().collect();
+                tokens.push(Token::Dimension(value, unit.to_ascii_lowercase()));
+            } else {
+                tokens.push(Token::Number(value));
+            }
+        } else if is_ident_start(c)
+            || (c == '-' && at(i + 1).is_some_and(|n| is_ident_start(n) || n == '-'))
+        {
+            let start = i;
+            while at(i).is_some_and(is_ident_char) {
+                i += 1;
+            }
+            let name = chars[start..i]
+                .iter()
+                .collect::<String>()
+                .to_ascii_lowercase();
+            if at(i) == Some('(') {
+                i += 1;
+                tokens.push(Token::Function(name));
+            } else {
+                tokens.push(Token::Ident(name));
+            }
+        } else {
+            tokens.push(match c {
+                ',' => Token::Comma,
+                '/' => Token::Slash,
+                '(' => Token::OpenParen,
+                ')' => Token::CloseParen,
+                '+' | '-' | '*' => Token::Delim(c),
+                _ => return Err(CssColorError::UnexpectedToken(c.to_string())),
+            });
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+// ============================================================================
+// Channel descriptions
+// ============================================================================
+
+/// How a single component is written in a color function.
+#[derive(Debug, Clone, Copy)]
+struct Channel {
+    /// Keyword naming this channel in relative color syntax.
+    name: &'static str,
+    /// Multiplier from the written number to the stored component.
+    number_scale: f64,
+    /// Stored value that `100%` maps to.
+    percent_ref: f64,
+    /// Whether the channel is a hue (accepts angles, rejects percentages).
+    hue: bool,
+    /// Parse-time clamping range.
+    clamp: Option<(f64, f64)>,
+}
+
+impl Channel {
+    const fn new(name: &'static str, percent_ref: f64) -> Self {
+        Self {
+            name,
+            number_scale: 1.0,
+            percent_ref,
+            hue: false,
+            clamp: None,
+        }
+    }
+
+    const fn hue(name: &'static str) -> Self {
+        Self {
+            name,
+            number_scale: 1.0,
+            percent_ref: 0.0,
+            hue: true,
+            clamp: None,
+        }
+    }
+
+    const fn scaled(mut self, number_scale: f64) -> Self {
+        self.number_scale = number_scale;
+        self
+    }
+
+    const fn clamped(mut self, min: f64, max: f64) -> Self {
+        self.clamp = Some((min, max));
+        self
+    }
+}
+
+const ALPHA: Channel = Channel::new("alpha", 1.0).clamped(0.0, 1.0);
+
+fn channels(space: CssColorSpace) -> [Channel; 3] {
+    use CssColorSpace as S;
+    match space {
+        S::Rgb => {
+            let ch = |n| Channel::new(n, 1.0).scaled(1.0 / 255.0).clamped(0.0, 1.0);
+            [ch("r"), ch("g"), ch("b")]
+        }
+        S::Hsl => [
+            Channel::hue("h"),
+            Channel::new("s", 100.0).clamped(0.0, 100.0),
+            Channel::new("l", 100.0).clamped(0.0, 100.0),
+        ],
+        S::Hwb => [
+            Channel::hue("h"),
+            Channel::new("w", 100.0),
+            Channel::new("b", 100.0),
+        ],
+        S::Lab => [
+            Channel::new("l", 100.0).clamped(0.0, 100.0),
+            Channel::new("a", 125.0),
+            Channel::new("b", 125.0),
+        ],
+        S::Lch => [
+            Channel::new("l", 100.0).clamped(0.0, 100.0),
+            Channel::new("c", 150.0).clamped(0.0, f64::INFINITY),
+            Channel::hue("h"),
+        ],
+        S::Oklab => [
+            Channel::new("l", 1.0).clamped(0.0, 1.0),
+            Channel::new("a", 0.4),
+            Channel::new("b", 0.4),
+        ],
+        S::Oklch => [
+            Channel::new("l", 1.0).clamped(0.0, 1.0),
+            Channel::new("c", 0.4).clamped(0.0, f64::INFINITY),
+            Channel::hue("h"),
+        ],
+        S::XyzD50 | S::XyzD65 => [
+            Channel::new("x", 1.0),
+            Channel::new("y", 1.0),
+            Channel::new("z", 1.0),
+        ],
+        S::Srgb | S::SrgbLinear | S::DisplayP3 | S::A98Rgb | S::ProphotoRgb | S::Rec2020 => [
+            Channel::new("r", 1.0),
+            Channel::new("g", 1.0),
+            Channel::new("b", 1.0),
+        ],
+    }
+}
+
+fn angle_to_degrees(value: f64, unit: &str) -> Option<f64> {
+    match unit {
+        "deg" => Some(value),
+        "rad" => Some(value.to_degrees()),
+        "grad" => Some(value * 0.9),
+        "turn" => Some(value * 360.0),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+/// Parse a complete CSS color value.
+pub(super) fn parse(input: &str) -> Result<CssColor, CssColorError> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Err(CssColorError::Empty);
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let color = parser.color()?;
+    match parser.peek() {
+        Some(t) => Err(CssColorError::UnexpectedToken(t.to_string())),
+        None => Ok(color),
+    }
+}
+
+/// Relative color channel keywords, with values in written (number) units.
+type Keywords = Vec<(&'static str, f64)>;
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, CssColorError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(CssColorError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_ident(&mut self, name: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(s)) if s == name) && {
+            self.pos += 1;
+            true
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<(), CssColorError> {
+        match self.next()? {
+            t if &t == token => Ok(()),
+            t => Err(CssColorError::UnexpectedToken(t.to_string())),
+        }
+    }
+
+    /// `<color>`
+    fn color(&mut self) -> Result<CssColor, CssColorError> {
+        match self.next()? {
+            Token::Hash(hex) => parse_hex(&hex),
+            Token::Ident(name) => match name.as_str() {
+                "transparent" => Ok(CssColor::new(CssColorSpace::Rgb, [0.0; 3], 0.0)),
+                "currentcolor" => Err(CssColorError::Unsupported(name)),
+                _ => named::lookup(&name)
+                    .map(rgb_from_u32)
+                    .ok_or(CssColorError::UnknownName(name)),
+            },
+            Token::Function(name) => match name.as_str() {
+                "rgb" | "rgba" => self.color_function(CssColorSpace::Rgb),
+                "hsl" | "hsla" => self.color_function(CssColorSpace::Hsl),
+                "hwb" => self.color_function(CssColorSpace::Hwb),
+                "lab" => self.color_function(CssColorSpace::Lab),
+                "lch" => self.color_function(CssColorSpace::Lch),
+                "oklab" => self.color_function(CssColorSpace::Oklab),
+                "oklch" => self.color_function(CssColorSpace::Oklch),
+                "color" => self.predefined_color(),
+                "color-mix" => self.color_mix(),
+                _ => Err(CssColorError::UnknownFunction(name)),
+            },
+            t => Err(CssColorError::UnexpectedToken(t.to_string())),
+        }
+    }
+
+    /// `[from <color>]?` at the start of a relative color function.
+    fn relative_origin(&mut self) -> Result<Option<CssColor>, CssColorError> {
+        if self.eat_ident("from") {
+            self.color().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()`.
+    fn color_function(&mut self, space: CssColorSpace) -> Result<CssColor, CssColorError> {
+        let origin = self.relative_origin()?;
+        self.components(space, origin.map(|o| o.convert(space)))
+    }
+
+    /// `color([from <color>]? <space> c1 c2 c3 [/ alpha]?)`
+    fn predefined_color(&mut self) -> Result<CssColor, CssColorError> {
+        let origin = self.relative_origin()?;
+        let space = match self.next()? {
+            Token::Ident(name) => CssColorSpace::from_name(&name)
+                .filter(|s| s.is_predefined())
+                .ok_or(CssColorError::UnknownColorSpace(name))?,
+            t => return Err(CssColorError::UnexpectedToken(t.to_string())),
+        };
+        self.components(space, origin.map(|o| o.convert(space)))
+    }
+
+    /// Component list and closing parenthesis of a color function.
+    fn components(
+        &mut self,
+        space: CssColorSpace,
+        origin: Option<CssColor>,
+    ) -> Result<CssColor, CssColorError> {
+        let channels = channels(space);
+        let keywords: Keywords = match origin {
+            Some(o) => {
+                let values = o.resolved_components();
+                let mut kw: Keywords = channels
+                    .iter()
+                    .zip(values)
+                    .map(|(ch, v)| (ch.name, v / ch.number_scale))
+                    .collect();
+                kw.push(("alpha", o.alpha.unwrap_or(0.0)));
+                kw
+            }
+            None => Vec::new(),
+        };
+
+        let first = self.value(&channels[0], &keywords)?;
+        let legacy = origin.is_none()
+            && matches!(space, CssColorSpace::Rgb | CssColorSpace::Hsl)
+            && self.peek() == Some(&Token::Comma);
+
+        let mut components = [first, None, None];
+        let mut alpha = Some(origin.map_or(1.0, |o| o.alpha.unwrap_or(0.0)));
+
+        if legacy {
+            for (slot, channel) in components.iter_mut().zip(&channels).skip(1) {
+                self.expect(&Token::Comma)?;
+                *slot = self.value(channel, &keywords)?;
+            }
+            if self.eat(&Token::Comma) {
+                alpha = self.value(&ALPHA, &keywords)?;
+            }
+            if components.iter().any(Option::is_none) || alpha.is_none() {
+                return Err(CssColorError::UnexpectedToken("none".to_string()));
+            }
+        } else {
+            for (slot, channel) in components.iter_mut().zip(&channels).skip(1) {
+                *slot = self.value(channel, &keywords)?;
+            }
+            if self.eat(&Token::Slash) {
+                alpha = self.value(&ALPHA, &keywords)?;
+            }
+        }
+        self.expect(&Token::CloseParen)?;
+
+        Ok(CssColor {
+            space,
+            components,
+            alpha,
+        })
+    }
+
+    /// A single component: number, percentage, angle, `none`, channel
+    /// keyword or `calc()`. Returns the stored value (`None` for `none`).
+    fn value(
+        &mut self,
+        channel: &Channel,
+        keywords: &Keywords,
+    ) -> Result<Option<f64>, CssColorError> {
+        let written = match self.next()? {
+            Token::Ident(name) if name == "none" => return Ok(None),
+            Token::Function(name) if name == "calc" => self.calc(channel, keywords)?,
+            token => self.operand(token, channel, keywords)?,
+        };
+        let mut value = written * channel.number_scale;
+        if let Some((min, max)) = channel.clamp {
+            value = value.clamp(min, max);
+        }
+        Ok(Some(value))
+    }
+
+    /// A plain operand, in written (number) units.
+    fn operand(
+        &mut self,
+        token: Token,
+        channel: &Channel,
+        keywords: &Keywords,
+    ) -> Result<f64, CssColorError> {
+        match token {
+            Token::Number(n) => Ok(n),
+            Token::Percentage(p) if !channel.hue => {
+                Ok(p / 100.0 * channel.percent_ref / channel.number_scale)
+            }
+            Token::Dimension(v, unit) if channel.hue => angle_to_degrees(v, &unit)
+                .ok_or_else(|| CssColorError::UnexpectedToken(format!("{}{}", v, unit))),
+            Token::Ident(name) => keywords
+                .iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| *v)
+                .ok_or(CssColorError::UnexpectedToken(name)),
+            t => Err(CssColorError::UnexpectedToken(t.to_string())),
+        }
+    }
+
+    /// Body of `calc(…)` after the function token, including `)`.
+    fn calc(&mut self, channel: &Channel, keywords: &Keywords) -> Result<f64, CssColorError> {
+        let value = self.calc_sum(channel, keywords)?;
+        self.expect(&Token::CloseParen)?;
+        // NaN resolves to zero (CSS Values 4 §10.9)
+        Ok(if value.is_nan() { 0.0 } else { value })
+    }
+
+    fn calc_sum(&mut self, channel: &Channel, keywords: &Keywords) -> Result<f64, CssColorError> {
+        let mut value = self.calc_product(channel, keywords)?;
+        loop {
+            if self.eat(&Token::Delim('+')) {
+                value += self.calc_product(channel, keywords)?;
+            } else if self.eat(&Token::Delim('-')) {
+                value -= self.calc_product(channel, keywords)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn calc_product(
+        &mut self,
+        channel: &Channel,
+        keywords: &Keywords,
+    ) -> Result<f64, CssColorError> {
+        let mut value = self.calc_term(channel, keywords)?;
+        loop {
+            if self.eat(&Token::Delim('*')) {
+                value *= self.calc_term(channel, keywords)?;
+            } else if self.eat(&Token::Slash) {
+                value /= self.calc_term(channel, keywords)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn calc_term(&mut self, channel: &Channel, keywords: &Keywords) -> Result<f64, CssColorError> {
+        match self.next()? {
+            Token::OpenParen => {
+                let value = self.calc_sum(channel, keywords)?;
+                self.expect(&Token::CloseParen)?;
+                Ok(value)
+            }
+            Token::Function(name) if name == "calc" => self.calc(channel, keywords),
+            Token::Ident(name) if name == "pi" => Ok(core::f64::consts::PI),
+            Token::Ident(name) if name == "e" => Ok(core::f64::consts::E),
+            token => self.operand(token, channel, keywords),
+        }
+    }
+
+    /// `color-mix(in <space> [<hue-method> hue]?, <color> <p>?, <color> <p>?)`
+    fn color_mix(&mut self) -> Result<CssColor, CssColorError> {
+        if !self.eat_ident("in") {
+            return Err(CssColorError::UnexpectedToken(self.next()?.to_string()));
+        }
+        let space = match self.next()? {
+            Token::Ident(name) => {
+                CssColorSpace::from_name(&name).ok_or(CssColorError::UnknownColorSpace(name))?
+            }
+            t => return Err(CssColorError::UnexpectedToken(t.to_string())),
+        };
+
+        let mut hue = HueInterpolation::default();
+        if let Some(Token::Ident(name)) = self.peek() {
+            let method = match name.as_str() {
+                "shorter" => HueInterpolation::Shorter,
+                "longer" => HueInterpolation::Longer,
+                "increasing" => HueInterpolation::Increasing,
+                "decreasing" => HueInterpolation::Decreasing,
+                other => return Err(CssColorError::UnexpectedToken(other.to_string())),
+            };
+            if space.hue_index().is_none() {
+                return Err(CssColorError::UnexpectedToken(name.clone()));
+            }
+            self.pos += 1;
+            if !self.eat_ident("hue") {
+                return Err(CssColorError::UnexpectedToken(self.next()?.to_string()));
+            }
+            hue = method;
+        }
+
+        self.expect(&Token::Comma)?;
+        let (c1, p1) = self.mix_item()?;
+        self.expect(&Token::Comma)?;
+        let (c2, p2) = self.mix_item()?;
+        self.expect(&Token::CloseParen)?;
+
+        CssColor::mix(&c1, p1, &c2, p2, space, hue)
+            .ok_or_else(|| CssColorError::InvalidValue("color-mix() percentages sum to 0%".into()))
+    }
+
+    /// `<color> && <percentage [0,100]>?`
+    fn mix_item(&mut self) -> Result<(CssColor, Option<f64>), CssColorError> {
+        let mut percentage = self.mix_percentage()?;
+        let color = self.color()?;
+        if percentage.is_none() {
+            percentage = self.mix_percentage()?;
+        }
+        Ok((color, percentage))
+    }
+
+    fn mix_percentage(&mut self) -> Result<Option<f64>, CssColorError> {
+        match self.peek() {
+            Some(Token::Percentage(p)) => {
+                let p = *p;
+                if !(0.0..=100.0).contains(&p) {
+                    return Err(CssColorError::InvalidValue(format!("{}%", p)));
+                }
+                self.pos += 1;
+                Ok(Some(p))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+fn rgb_from_u32(rgb: u32) -> CssColor {
+    let channel = |shift: u32| f64::from((rgb >> shift) & 0xFF) / 255.0;
+    CssColor::new(
+        CssColorSpace::Rgb,
+        [channel(16), channel(8), channel(0)],
+        1.0,
+    )
+}
+
+/// `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`.
+fn parse_hex(hex: &str) -> Result<CssColor, CssColorError> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(CssColorError::InvalidHex(hex.to_string()));
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).unwrap_or(0);
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+    let bytes: [u8; 4] = match hex.len() {
+        3 | 4 => {
+            let a = if hex.len() == 4 { digit(3) * 17 } else { 255 };
+            [digit(0) * 17, digit(1) * 17, digit(2) * 17, a]
+        }
+        6 | 8 => {
+            let a = if hex.len() == 8 { pair(6) } else { 255 };
+            [pair(0), pair(2), pair(4), a]
+        }
+        _ => return Err(CssColorError::InvalidHex(hex.to_string())),
+    };
+    let [r, g, b, a] = bytes.map(|v| f64::from(v) / 255.0);
+    Ok(CssColor::new(CssColorSpace::Rgb, [r, g, b], a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("rgb(10 20.5% 3e1deg / .5)").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Function("rgb".into()),
+                Token::Number(10.0),
+                Token::Percentage(20.5),
+                Token::Dimension(30.0, "deg".into()),
+                Token::Slash,
+                Token::Number(0.5),
+                Token::CloseParen,
+            ]
+        );
+        let tokens = tokenize("calc(l - 0.1)").unwrap();
+        assert_eq!(tokens[2], Token::Delim('-'));
+        assert_eq!(tokens[3], Token::Number(0.1));
+    }
+
+    #[test]
+    fn test_calc_precedence() {
+        let c =
+            parse("oklch(from black calc(0.1 + 0.2 * 2) calc((1 + 1) / 20) calc(pi * 0))").unwrap();
+        let v = c.resolved_components();
+        assert!((v[0] - 0.5).abs() < 1e-12);
+        assert!((v[1] - 0.1).abs() < 1e-12);
+        assert_eq!(v[2], 0.0);
+    }
+
+    #[test]
+    fn test_angles() {
+        for hue in ["180", "180deg", "200grad", "0.5turn", "3.14159265358979rad"] {
+            let c = parse(&format!("hsl({} 50% 50%)", hue)).unwrap();
+            assert!((c.components[0].unwrap() - 180.0).abs() < 1e-6, "{}", hue);
+        }
+        assert!(parse("hsl(180px 50% 50%)").is_err());
+        assert!(parse("hsl(50% 50% 50%)").is_err());
+    }
+}
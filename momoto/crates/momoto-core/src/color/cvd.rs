@@ -11,15 +11,34 @@
 //   for checking the legibility of displays by dichromats. Color Research &
 //   Application, 24(4), 243–252.
 //
+//   Machado, G. M., Oliveira, M. M., & Fernandes, L. A. F. (2009). A
+//   physiologically-based model for simulation of color vision deficiency.
+//   IEEE Transactions on Visualization and Computer Graphics, 15(6),
+//   1291–1298. https://doi.org/10.1109/TVCG.2009.113
+//
 // Implementation:
 //   Pipeline: linear sRGB → LMS (Hunt-Pointer-Estevez D65) → dichromat
 //   projection (Brettel 1997 two-half-plane method) → linear sRGB → sRGB
 //
+//   Anomalous trichromacy uses the Machado 2009 precomputed matrices,
+//   linearly interpolated between their 0.1 severity steps. Monochromacy
+//   collapses every color onto a single achromatic signal.
+//
 // Matrices validated against the daltonlens.org reference implementation.
 // =============================================================================
 
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
 use super::Color;
 
+use crate::math::ops;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
 // =============================================================================
 // Type
 // =============================================================================
@@ -38,18 +57,84 @@ pub enum CVDType {
     /// Tritanopia — S-cone (short-wavelength) absent.
     /// Affects blue–yellow discrimination; rare (~0.01% of population).
     Tritanopia,
+
+    /// Protanomaly — L-cone sensitivity shifted toward M.
+    /// Milder, far more common form of protan deficiency (~1% of males).
+    Protanomaly,
+
+    /// Deuteranomaly — M-cone sensitivity shifted toward L.
+    /// The most common CVD overall (~5% of males).
+    Deuteranomaly,
+
+    /// Tritanomaly — S-cone sensitivity shifted; very rare.
+    Tritanomaly,
+
+    /// Achromatopsia (rod monochromacy) — no functioning cones.
+    /// Only luminance is perceived.
+    Achromatopsia,
+
+    /// Blue-cone monochromacy — only S cones (and rods) function.
+    /// No hue discrimination; short wavelengths appear brightest.
+    BlueConeMonochromacy,
 }
 
 impl CVDType {
+    /// Every supported deficiency.
+    pub const ALL: [Self; 8] = [
+        Self::Protanopia,
+        Self::Deuteranopia,
+        Self::Tritanopia,
+        Self::Protanomaly,
+        Self::Deuteranomaly,
+        Self::Tritanomaly,
+        Self::Achromatopsia,
+        Self::BlueConeMonochromacy,
+    ];
+
     /// Parse from a string identifier (case-insensitive).
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "protanopia" | "protan" | "p" => Some(Self::Protanopia),
             "deuteranopia" | "deutan" | "d" => Some(Self::Deuteranopia),
             "tritanopia" | "tritan" | "t" => Some(Self::Tritanopia),
+            "protanomaly" => Some(Self::Protanomaly),
+            "deuteranomaly" => Some(Self::Deuteranomaly),
+            "tritanomaly" => Some(Self::Tritanomaly),
+            "achromatopsia" | "monochromacy" | "achroma" => Some(Self::Achromatopsia),
+            "blue-cone-monochromacy" | "bcm" => Some(Self::BlueConeMonochromacy),
             _ => None,
         }
     }
+
+    /// Whether this is an anomalous trichromacy (Machado 2009 model).
+    pub fn is_anomalous(self) -> bool {
+        matches!(self, Self::Protanomaly | Self::Deuteranomaly | Self::Tritanomaly)
+    }
+
+    /// Whether this is a monochromacy (no hue discrimination at all).
+    pub fn is_monochromacy(self) -> bool {
+        matches!(self, Self::Achromatopsia | Self::BlueConeMonochromacy)
+    }
+
+    /// Linear-sRGB simulation matrix at the given severity.
+    ///
+    /// `severity` is clamped to [0, 1]; 0 is normal vision (identity).
+    /// Anomalous types interpolate the Machado 2009 tables, whose 1.0 entry
+    /// is the dichromat limit. Dichromacy and monochromacy types blend
+    /// from the identity to their full-deficiency matrix.
+    pub fn matrix(self, severity: f64) -> [[f64; 3]; 3] {
+        let s = if severity.is_nan() { 1.0 } else { severity.clamp(0.0, 1.0) };
+        match self {
+            Self::Protanopia   => blend_identity(&M_PROTAN, s),
+            Self::Deuteranopia => blend_identity(&M_DEUTAN, s),
+            Self::Tritanopia   => blend_identity(&M_TRITAN, s),
+            Self::Protanomaly   => machado(&MACHADO_PROTAN, s),
+            Self::Deuteranomaly => machado(&MACHADO_DEUTAN, s),
+            Self::Tritanomaly   => machado(&MACHADO_TRITAN, s),
+            Self::Achromatopsia        => blend_identity(&M_ACHROMA, s),
+            Self::BlueConeMonochromacy => blend_identity(&M_BLUE_CONE, s),
+        }
+    }
 }
 
 // =============================================================================
@@ -90,6 +175,79 @@ const M_TRITAN: [[f64; 3]; 3] = [
     [0.00000, 0.47500, 0.52500],  // B' = 0.475G + 0.525B
 ];
 
+// =============================================================================
+// Anomalous trichromacy matrices (Machado 2009)
+// =============================================================================
+//
+// Precomputed linear-sRGB matrices for severities 0.0, 0.1, …, 1.0, from the
+// supplementary material of Machado, Oliveira & Fernandes (2009). Rows sum to
+// 1.0 (white invariance). Severities in between are linearly interpolated.
+
+/// Protanomaly, severity 0.0–1.0 in steps of 0.1.
+const MACHADO_PROTAN: [[[f64; 3]; 3]; 11] = [
+    [[1.000000, 0.000000, 0.000000], [0.000000, 1.000000, 0.000000], [0.000000, 0.000000, 1.000000]],
+    [[0.856167, 0.182038, -0.038205], [0.029342, 0.955115, 0.015544], [-0.002880, -0.001563, 1.004443]],
+    [[0.734766, 0.334872, -0.069637], [0.051840, 0.919198, 0.028963], [-0.004928, -0.004209, 1.009137]],
+    [[0.630323, 0.465641, -0.095964], [0.069181, 0.890046, 0.040773], [-0.006308, -0.007724, 1.014032]],
+    [[0.539009, 0.579343, -0.118352], [0.082546, 0.866121, 0.051332], [-0.007136, -0.011959, 1.019095]],
+    [[0.458064, 0.679578, -0.137642], [0.092785, 0.846313, 0.060902], [-0.007494, -0.016807, 1.024301]],
+    [[0.385450, 0.769005, -0.154455], [0.100526, 0.829802, 0.069673], [-0.007442, -0.022190, 1.029632]],
+    [[0.319627, 0.849633, -0.169261], [0.106241, 0.815969, 0.077790], [-0.007025, -0.028051, 1.035076]],
+    [[0.259411, 0.923008, -0.182420], [0.110296, 0.804340, 0.085364], [-0.006276, -0.034346, 1.040622]],
+    [[0.203876, 0.990338, -0.194214], [0.112975, 0.794542, 0.092483], [-0.005222, -0.041043, 1.046265]],
+    [[0.152286, 1.052583, -0.204868], [0.114503, 0.786281, 0.099216], [-0.003882, -0.048116, 1.051998]],
+];
+
+/// Deuteranomaly, severity 0.0–1.0 in steps of 0.1.
+const MACHADO_DEUTAN: [[[f64; 3]; 3]; 11] = [
+    [[1.000000, 0.000000, 0.000000], [0.000000, 1.000000, 0.000000], [0.000000, 0.000000, 1.000000]],
+    [[0.866435, 0.177704, -0.044139], [0.049567, 0.939063, 0.011370], [-0.003453, 0.007233, 0.996220]],
+    [[0.760729, 0.319078, -0.079807], [0.090568, 0.889315, 0.020117], [-0.006027, 0.013325, 0.992702]],
+    [[0.675425, 0.433850, -0.109275], [0.125303, 0.847755, 0.026942], [-0.007950, 0.018572, 0.989378]],
+    [[0.605511, 0.528560, -0.134071], [0.155318, 0.812366, 0.032316], [-0.009376, 0.023176, 0.986200]],
+    [[0.547494, 0.607765, -0.155259], [0.181692, 0.781742, 0.036566], [-0.010410, 0.027275, 0.983136]],
+    [[0.498864, 0.674741, -0.173604], [0.205199, 0.754872, 0.039929], [-0.011131, 0.030969, 0.980162]],
+    [[0.457771, 0.731899, -0.189670], [0.226409, 0.731012, 0.042579], [-0.011595, 0.034333, 0.977261]],
+    [[0.422823, 0.781057, -0.203881], [0.245752, 0.709602, 0.044646], [-0.011843, 0.037423, 0.974421]],
+    [[0.392952, 0.823610, -0.216562], [0.263559, 0.690210, 0.046232], [-0.011910, 0.040281, 0.971630]],
+    [[0.367322, 0.860646, -0.227968], [0.280085, 0.672501, 0.047413], [-0.011820, 0.042940, 0.968881]],
+];
+
+/// Tritanomaly, severity 0.0–1.0 in steps of 0.1.
+const MACHADO_TRITAN: [[[f64; 3]; 3]; 11] = [
+    [[1.000000, 0.000000, 0.000000], [0.000000, 1.000000, 0.000000], [0.000000, 0.000000, 1.000000]],
+    [[0.926670, 0.092514, -0.019184], [0.021191, 0.964503, 0.014306], [0.008437, 0.054813, 0.936750]],
+    [[0.895720, 0.133330, -0.029050], [0.029997, 0.945400, 0.024603], [0.013027, 0.104707, 0.882266]],
+    [[0.905871, 0.127791, -0.033662], [0.026856, 0.941251, 0.031893], [0.013410, 0.148296, 0.838294]],
+    [[0.948035, 0.089490, -0.037526], [0.014364, 0.946792, 0.038844], [0.010853, 0.193991, 0.795156]],
+    [[1.017277, 0.027029, -0.044306], [-0.006113, 0.958479, 0.047634], [0.006379, 0.248708, 0.744913]],
+    [[1.104996, -0.046633, -0.058363], [-0.032137, 0.971635, 0.060503], [0.001336, 0.317922, 0.680742]],
+    [[1.193214, -0.109812, -0.083402], [-0.058496, 0.979410, 0.079086], [-0.002346, 0.403492, 0.598854]],
+    [[1.257728, -0.139648, -0.118081], [-0.078003, 0.975409, 0.102594], [-0.003316, 0.501214, 0.502102]],
+    [[1.278864, -0.125333, -0.153531], [-0.084748, 0.957674, 0.127074], [-0.000989, 0.601151, 0.399838]],
+    [[1.255528, -0.076749, -0.178779], [-0.078411, 0.930809, 0.147602], [0.004733, 0.691367, 0.303900]],
+];
+
+// =============================================================================
+// Monochromacy matrices
+// =============================================================================
+
+/// Achromatopsia: every channel becomes the relative luminance Y
+/// (Rec. 709 / sRGB luminance coefficients).
+const M_ACHROMA: [[f64; 3]; 3] = [
+    [0.2126, 0.7152, 0.0722],
+    [0.2126, 0.7152, 0.0722],
+    [0.2126, 0.7152, 0.0722],
+];
+
+/// Blue-cone monochromacy: every channel becomes the S-cone response
+/// (Viénot 1999 LMS row, normalised so white stays white).
+const M_BLUE_CONE: [[f64; 3]; 3] = [
+    [0.01782, 0.10963, 0.87255],
+    [0.01782, 0.10963, 0.87255],
+    [0.01782, 0.10963, 0.87255],
+];
+
 // =============================================================================
 // Core simulation function
 // =============================================================================
@@ -108,13 +266,30 @@ const M_TRITAN: [[f64; 3]; 3] = [
 /// The simulated color as seen by a trichromat (i.e. what the dichromat would
 /// see, expressed in trichromat sRGB coordinates).
 pub fn simulate_cvd(color: &Color, cvd: CVDType) -> Color {
-    let rgb = color.linear;
-    let m = match cvd {
-        CVDType::Protanopia   => &M_PROTAN,
-        CVDType::Deuteranopia => &M_DEUTAN,
-        CVDType::Tritanopia   => &M_TRITAN,
-    };
-    let sim = mat3_mul_vec3(m, rgb);
+    simulate_cvd_with_severity(color, cvd, 1.0)
+}
+
+/// Simulate a color vision deficiency at a given severity.
+///
+/// # Arguments
+/// * `color` — input color in sRGB
+/// * `cvd` — type of color vision deficiency to simulate
+/// * `severity` — 0.0 (normal vision) to 1.0 (full deficiency); see
+///   [`CVDType::matrix`]
+///
+/// # Example
+///
+/// ```
+/// use momoto_core::color::Color;
+/// use momoto_core::color::cvd::{simulate_cvd_with_severity, CVDType};
+///
+/// let red = Color::from_srgb8(220, 30, 30);
+/// let mild = simulate_cvd_with_severity(&red, CVDType::Deuteranomaly, 0.3);
+/// let strong = simulate_cvd_with_severity(&red, CVDType::Deuteranomaly, 0.9);
+/// assert_ne!(mild.to_srgb8(), strong.to_srgb8());
+/// ```
+pub fn simulate_cvd_with_severity(color: &Color, cvd: CVDType, severity: f64) -> Color {
+    let sim = mat3_mul_vec3(&cvd.matrix(severity), color.linear);
     Color::from_linear(
         sim[0].clamp(0.0, 1.0),
         sim[1].clamp(0.0, 1.0),
@@ -131,18 +306,23 @@ pub fn simulate_cvd(color: &Color, cvd: CVDType) -> Color {
 ///
 /// ΔE in [0, ∞). Typically < 30 for mild confusion, > 60 for severe.
 pub fn cvd_delta_e(color: &Color, cvd: CVDType) -> f64 {
+    cvd_delta_e_with_severity(color, cvd, 1.0)
+}
+
+/// [`cvd_delta_e`] for a deficiency of the given severity (0.0–1.0).
+pub fn cvd_delta_e_with_severity(color: &Color, cvd: CVDType, severity: f64) -> f64 {
     use crate::space::oklch::OKLCH;
 
-    let simulated = simulate_cvd(color, cvd);
+    let simulated = simulate_cvd_with_severity(color, cvd, severity);
     let orig_lch = OKLCH::from_color(color);
     let sim_lch = OKLCH::from_color(&simulated);
 
     // OKLCH Euclidean ΔE (scaled to approximate CIE 2000 magnitude)
     let dl = orig_lch.l - sim_lch.l;
-    let da = orig_lch.c * (orig_lch.h.to_radians()).cos()
-           - sim_lch.c * (sim_lch.h.to_radians()).cos();
-    let db = orig_lch.c * (orig_lch.h.to_radians()).sin()
-           - sim_lch.c * (sim_lch.h.to_radians()).sin();
+    let da = orig_lch.c * ops::cos(orig_lch.h.to_radians())
+           - sim_lch.c * ops::cos(sim_lch.h.to_radians());
+    let db = orig_lch.c * ops::sin(orig_lch.h.to_radians())
+           - sim_lch.c * ops::sin(sim_lch.h.to_radians());
 
     // Scale factor to match CIE 2000 magnitude (empirical, ~100x)
     100.0 * (dl * dl + da * da + db * db).sqrt()
@@ -150,8 +330,9 @@ pub fn cvd_delta_e(color: &Color, cvd: CVDType) -> f64 {
 
 /// Suggest a CVD-safe alternative foreground color.
 ///
-/// Adjusts the foreground hue and/or lightness until the CVD-simulated
-/// contrast ratio against the background exceeds `min_contrast`.
+/// Adjusts the foreground hue and/or lightness until the contrast ratio
+/// between the CVD-simulated foreground and the CVD-simulated background
+/// exceeds `min_contrast`.
 ///
 /// Uses WCAG 2.1 relative luminance contrast.
 ///
@@ -164,19 +345,36 @@ pub fn cvd_delta_e(color: &Color, cvd: CVDType) -> f64 {
 /// # Returns
 ///
 /// Modified foreground color, guaranteed to have contrast ≥ `min_contrast`
-/// against `bg` under the given CVD simulation.
+/// against `bg` when both are seen under the given CVD simulation.
 pub fn suggest_cvd_safe_alternative(fg: &Color, bg: &Color, cvd: CVDType, min_contrast: f64) -> Color {
+    suggest_cvd_safe_alternative_with_severity(fg, bg, cvd, 1.0, min_contrast)
+}
+
+/// [`suggest_cvd_safe_alternative`] for a deficiency of the given severity
+/// (0.0–1.0). Both colors are simulated at that severity.
+pub fn suggest_cvd_safe_alternative_with_severity(
+    fg: &Color,
+    bg: &Color,
+    cvd: CVDType,
+    severity: f64,
+    min_contrast: f64,
+) -> Color {
     use crate::space::oklch::OKLCH;
     use crate::luminance::relative_luminance_srgb;
 
     let mut candidate = *fg;
 
+    // The background as the viewer sees it
+    let bg_lum = {
+        let sim_bg = simulate_cvd_with_severity(bg, cvd, severity);
+        relative_luminance_srgb(&sim_bg).value()
+    };
+
     // Check if already safe
     let check = |fg: &Color| -> bool {
-        let sim_fg = simulate_cvd(fg, cvd);
+        let sim_fg = simulate_cvd_with_severity(fg, cvd, severity);
         let l1 = relative_luminance_srgb(&sim_fg).value();
-        let l2 = relative_luminance_srgb(bg).value();
-        let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+        let (lighter, darker) = if l1 > bg_lum { (l1, bg_lum) } else { (bg_lum, l1) };
         (lighter + 0.05) / (darker + 0.05) >= min_contrast
     };
 
@@ -185,10 +383,6 @@ pub fn suggest_cvd_safe_alternative(fg: &Color, bg: &Color, cvd: CVDType, min_co
     }
 
     // Strategy: shift lightness toward higher contrast
-    let bg_lum = {
-        let sim_bg = simulate_cvd(bg, cvd);
-        relative_luminance_srgb(&sim_bg).value()
-    };
 
     let mut lch = OKLCH::from_color(&candidate);
 
@@ -229,7 +423,7 @@ pub fn suggest_cvd_safe_alternative(fg: &Color, bg: &Color, cvd: CVDType, min_co
 ///
 /// # Arguments
 /// * `hex` — input hex (e.g. "#ff5500" or "ff5500")
-/// * `cvd` — CVD type string ("protanopia", "deuteranopia", "tritanopia")
+/// * `cvd` — CVD type string ("protanopia", "deuteranomaly", "achromatopsia", …)
 ///
 /// # Returns
 ///
@@ -269,6 +463,33 @@ pub fn to_hex(color: &Color) -> String {
 // Matrix algebra helpers
 // =============================================================================
 
+/// `(1 − s)·I + s·M`
+fn blend_identity(m: &[[f64; 3]; 3], s: f64) -> [[f64; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            let id = if i == j { 1.0 } else { 0.0 };
+            *v = id + s * (m[i][j] - id);
+        }
+    }
+    out
+}
+
+/// Interpolate a Machado table at severity `s` ∈ [0, 1].
+fn machado(table: &[[[f64; 3]; 3]; 11], s: f64) -> [[f64; 3]; 3] {
+    let pos = s * 10.0;
+    let i = (pos.floor() as usize).min(9);
+    let t = pos - i as f64;
+    let (a, b) = (&table[i], &table[i + 1]);
+    let mut out = [[0.0; 3]; 3];
+    for (r, row) in out.iter_mut().enumerate() {
+        for (c, v) in row.iter_mut().enumerate() {
+            *v = a[r][c] + t * (b[r][c] - a[r][c]);
+        }
+    }
+    out
+}
+
 #[inline]
 fn mat3_mul_vec3(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
     [
@@ -362,6 +583,102 @@ mod tests {
         assert_eq!(CVDType::from_str("invalid"), None);
     }
 
+    #[test]
+    fn test_all_types_preserve_white_and_black() {
+        let white = Color::from_srgb8(255, 255, 255);
+        let black = Color::from_srgb8(0, 0, 0);
+        for cvd in CVDType::ALL {
+            for severity in [0.25, 0.5, 1.0] {
+                let w = simulate_cvd_with_severity(&white, cvd, severity);
+                let b = simulate_cvd_with_severity(&black, cvd, severity);
+                assert_eq!(w.to_srgb8(), [255, 255, 255], "{:?} @ {}", cvd, severity);
+                assert_eq!(b.to_srgb8(), [0, 0, 0], "{:?} @ {}", cvd, severity);
+            }
+        }
+    }
+
+    #[test]
+    fn test_machado_rows_sum_to_one() {
+        for table in [&MACHADO_PROTAN, &MACHADO_DEUTAN, &MACHADO_TRITAN] {
+            for m in table.iter() {
+                for row in m {
+                    let sum: f64 = row.iter().sum();
+                    assert!((sum - 1.0).abs() < 1e-5, "row {:?} sums to {}", row, sum);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_severity_endpoints() {
+        let color = Color::from_srgb8(200, 80, 40);
+        for cvd in CVDType::ALL {
+            let none = simulate_cvd_with_severity(&color, cvd, 0.0);
+            assert_eq!(none.to_srgb8(), color.to_srgb8(), "{:?}", cvd);
+        }
+        // Severity 1.0 is the unparameterised simulation
+        let full = simulate_cvd_with_severity(&color, CVDType::Protanopia, 1.0);
+        assert_eq!(full.to_srgb8(), simulate_cvd(&color, CVDType::Protanopia).to_srgb8());
+        // Table entries are hit exactly, midpoints interpolate
+        let m = CVDType::Deuteranomaly.matrix(0.5);
+        assert_eq!(m, MACHADO_DEUTAN[5]);
+        let m = CVDType::Protanomaly.matrix(0.55);
+        let expected = (MACHADO_PROTAN[5][0][0] + MACHADO_PROTAN[6][0][0]) / 2.0;
+        assert!((m[0][0] - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_delta_e_grows_with_severity() {
+        let red = Color::from_srgb8(200, 0, 0);
+        for cvd in [CVDType::Protanomaly, CVDType::Deuteranomaly] {
+            let mut prev = 0.0;
+            for step in 1..=10 {
+                let de = cvd_delta_e_with_severity(&red, cvd, f64::from(step) / 10.0);
+                assert!(de > prev, "{:?} ΔE not increasing at {}: {} <= {}", cvd, step, de, prev);
+                prev = de;
+            }
+        }
+        assert_eq!(cvd_delta_e_with_severity(&red, CVDType::Tritanomaly, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_monochromacy_removes_hue() {
+        for rgb in [[255, 0, 0], [0, 180, 90], [30, 60, 250]] {
+            let color = Color::from_srgb8(rgb[0], rgb[1], rgb[2]);
+            for cvd in [CVDType::Achromatopsia, CVDType::BlueConeMonochromacy] {
+                let [r, g, b] = simulate_cvd(&color, cvd).srgb;
+                assert!((r - g).abs() < 1e-9 && (g - b).abs() < 1e-9, "{:?}", cvd);
+            }
+        }
+        // Blue looks brighter than yellow to a blue-cone monochromat
+        let blue = simulate_cvd(&Color::from_srgb8(0, 0, 255), CVDType::BlueConeMonochromacy);
+        let yellow = simulate_cvd(&Color::from_srgb8(255, 255, 0), CVDType::BlueConeMonochromacy);
+        assert!(blue.srgb[0] > yellow.srgb[0]);
+    }
+
+    #[test]
+    fn test_safe_alternative_with_severity() {
+        let fg = Color::from_srgb8(200, 60, 60);
+        let bg = Color::from_srgb8(60, 140, 60);
+        let safe = suggest_cvd_safe_alternative_with_severity(&fg, &bg, CVDType::Deuteranomaly, 0.6, 4.5);
+        let sim = simulate_cvd_with_severity(&safe, CVDType::Deuteranomaly, 0.6);
+        let sim_bg = simulate_cvd_with_severity(&bg, CVDType::Deuteranomaly, 0.6);
+        let l1 = crate::luminance::relative_luminance_srgb(&sim).value();
+        let l2 = crate::luminance::relative_luminance_srgb(&sim_bg).value();
+        let ratio = (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05);
+        assert!(ratio >= 4.5, "ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_new_type_names() {
+        assert_eq!(CVDType::from_str("Deuteranomaly"), Some(CVDType::Deuteranomaly));
+        assert_eq!(CVDType::from_str("achromatopsia"), Some(CVDType::Achromatopsia));
+        assert_eq!(CVDType::from_str("bcm"), Some(CVDType::BlueConeMonochromacy));
+        assert!(CVDType::Tritanomaly.is_anomalous());
+        assert!(!CVDType::Tritanopia.is_anomalous());
+        assert!(CVDType::Achromatopsia.is_monochromacy());
+    }
+
     #[test]
     fn test_hex_roundtrip() {
         let sim = simulate_cvd_hex("#ff0000", "protanopia");
@@ -0,0 +1,155 @@
+//! Tone reproduction curves (`curv` and `para` tag types).
+
+use super::reader::{signature_to_string, Reader};
+use super::IccError;
+use alloc::vec::Vec;
+
+use crate::math::ops;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// One-dimensional transfer curve over [0, 1].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve {
+    /// `curv` with no entries: output equals input.
+    Identity,
+    /// `curv` with one entry: pure power law.
+    Gamma(f64),
+    /// `curv` with a sampled table, linearly interpolated.
+    Sampled(Vec<f64>),
+    /// `para` function type 0–4 with parameters `[g, a, b, c, d, e, f]`.
+    Parametric {
+        /// ICC function type (0–4).
+        function: u16,
+        /// Parameters `g, a, b, c, d, e, f`; unused entries are zero.
+        params: [f64; 7],
+    },
+}
+
+impl Curve {
+    /// Parse a `curv` or `para` element; returns the curve and its size in
+    /// bytes (unpadded).
+    pub(super) fn parse(r: &Reader<'_>) -> Result<(Self, usize), IccError> {
+        match &r.signature(0)? {
+            b"curv" => {
+                let count = r.usize(8)?;
+                let size = count
+                    .checked_mul(2)
+                    .and_then(|n| n.checked_add(12))
+                    .ok_or_else(|| r.malformed())?;
+                let curve = match count {
+                    0 => Self::Identity,
+                    1 => Self::Gamma(f64::from(r.u16(12)?) / 256.0),
+                    _ => Self::Sampled(
+                        (0..count)
+                            .map(|i| Ok(f64::from(r.u16(12 + 2 * i)?) / 65535.0))
+                            .collect::<Result<_, IccError>>()?,
+                    ),
+                };
+                Ok((curve, size))
+            }
+            b"para" => {
+                let function = r.u16(8)?;
+                let count = match function {
+                    0 => 1,
+                    1 => 3,
+                    2 => 4,
+                    3 => 5,
+                    4 => 7,
+                    _ => return Err(r.malformed()),
+                };
+                let mut params = [0.0; 7];
+                for (i, p) in params.iter_mut().take(count).enumerate() {
+                    *p = r.s15f16(12 + 4 * i)?;
+                }
+                Ok((Self::Parametric { function, params }, 12 + 4 * count))
+            }
+            other => Err(IccError::UnsupportedTagType(signature_to_string(*other))),
+        }
+    }
+
+    /// Evaluate the curve; input is clamped to [0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::icc::Curve;
+    ///
+    /// assert!((Curve::Gamma(2.2).eval(0.5) - 0.5f64.powf(2.2)).abs() < 1e-12);
+    /// assert_eq!(Curve::Sampled(vec![0.0, 0.2, 1.0]).eval(0.25), 0.1);
+    /// ```
+    #[must_use]
+    pub fn eval(&self, x: f64) -> f64 {
+        let x = x.clamp(0.0, 1.0);
+        match self {
+            Self::Identity => x,
+            Self::Gamma(g) => ops::powf(x, *g),
+            Self::Sampled(table) if table.len() < 2 => table.first().copied().unwrap_or(x),
+            Self::Sampled(table) => {
+                let pos = x * (table.len() - 1) as f64;
+                let i = (pos.floor() as usize).min(table.len() - 2);
+                let t = pos - i as f64;
+                table[i] + (table[i + 1] - table[i]) * t
+            }
+            Self::Parametric { function, params } => {
+                let [g, a, b, c, d, e, f] = *params;
+                let pow = |v: f64| if v > 0.0 { ops::powf(v, g) } else { 0.0 };
+                match function {
+                    0 => pow(x),
+                    1 => {
+                        if x >= -b / a {
+                            pow(a * x + b)
+                        } else {
+                            0.0
+                        }
+                    }
+                    2 => {
+                        if x >= -b / a {
+                            pow(a * x + b) + c
+                        } else {
+                            c
+                        }
+                    }
+                    3 => {
+                        if x >= d {
+                            pow(a * x + b)
+                        } else {
+                            c * x
+                        }
+                    }
+                    _ => {
+                        if x >= d {
+                            pow(a * x + b) + e
+                        } else {
+                            c * x + f
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Invert the curve numerically, assuming it is monotonic.
+    ///
+    /// Returns the input in [0, 1] whose output is closest to `y`.
+    #[must_use]
+    pub fn eval_inverse(&self, y: f64) -> f64 {
+        match self {
+            Self::Identity => return y.clamp(0.0, 1.0),
+            Self::Gamma(g) if *g > 0.0 => return ops::powf(y.clamp(0.0, 1.0), 1.0 / g),
+            _ => {}
+        }
+        let increasing = self.eval(1.0) >= self.eval(0.0);
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..52 {
+            let mid = 0.5 * (lo + hi);
+            if (self.eval(mid) < y) == increasing {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        0.5 * (lo + hi)
+    }
+}
@@ -0,0 +1,347 @@
+//! Multi-dimensional lookup tables (`mft1`, `mft2`, `mAB `, `mBA ` tag types).
+
+use super::curve::Curve;
+use super::reader::{signature_to_string, Reader};
+use super::IccError;
+use alloc::{vec, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// Most channels a LUT may have on either side (ICC.1:2022 §10.12).
+const MAX_CHANNELS: usize = 15;
+
+/// Tag type a [`Lut`] was read from; decides the PCS Lab encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LutKind {
+    /// `lut8Type` (`mft1`).
+    Lut8,
+    /// `lut16Type` (`mft2`); uses the legacy 16-bit PCS Lab encoding.
+    Lut16,
+    /// `lutAToBType` (`mAB `).
+    AToB,
+    /// `lutBToAType` (`mBA `).
+    BToA,
+}
+
+/// One processing element of a LUT pipeline.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LutStage {
+    /// One curve per channel.
+    Curves(Vec<Curve>),
+    /// 3×3 matrix followed by an offset.
+    Matrix {
+        /// Row-major matrix.
+        matrix: [[f64; 3]; 3],
+        /// Offset added after the multiply.
+        offset: [f64; 3],
+    },
+    /// Multi-dimensional color lookup table.
+    Clut(Clut),
+}
+
+/// Color lookup table with multilinear interpolation.
+///
+/// The first input channel varies slowest, as in the ICC layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clut {
+    /// Grid points per input channel.
+    pub grid: Vec<usize>,
+    /// Output channels per grid point.
+    pub outputs: usize,
+    /// Normalized [0, 1] grid values.
+    pub values: Vec<f64>,
+}
+
+impl Clut {
+    /// Interpolate the table at normalized input coordinates.
+    #[must_use]
+    pub fn eval(&self, input: &[f64]) -> Vec<f64> {
+        let dims = self.grid.len();
+        let mut base = 0;
+        let mut fracs = Vec::with_capacity(dims);
+        let mut steps = Vec::with_capacity(dims);
+        let mut stride = self.outputs;
+        for d in (0..dims).rev() {
+            let n = self.grid[d];
+            let pos = input.get(d).copied().unwrap_or(0.0).clamp(0.0, 1.0) * (n - 1) as f64;
+            let i = (pos.floor() as usize).min(n.saturating_sub(2));
+            fracs.push(pos - i as f64);
+            steps.push(if n > 1 { stride } else { 0 });
+            base += i * stride;
+            stride *= n;
+        }
+
+        let mut out = vec![0.0; self.outputs];
+        for corner in 0..(1usize << dims) {
+            let mut weight = 1.0;
+            let mut offset = base;
+            for (bit, (&f, &step)) in fracs.iter().zip(&steps).enumerate() {
+                if corner & (1 << bit) != 0 {
+                    weight *= f;
+                    offset += step;
+                } else {
+                    weight *= 1.0 - f;
+                }
+            }
+            if weight == 0.0 {
+                continue;
+            }
+            for (o, v) in out
+                .iter_mut()
+                .zip(&self.values[offset..offset + self.outputs])
+            {
+                *o += weight * v;
+            }
+        }
+        out
+    }
+
+    fn parse(
+        r: &Reader<'_>,
+        grid: Vec<usize>,
+        outputs: usize,
+        precision: usize,
+    ) -> Result<Self, IccError> {
+        let count = grid
+            .iter()
+            .try_fold(outputs, |acc, &n| acc.checked_mul(n))
+            .filter(|_| grid.iter().all(|&n| n > 0))
+            .ok_or_else(|| r.malformed())?;
+        let bytes = r.bytes(
+            0,
+            count.checked_mul(precision).ok_or_else(|| r.malformed())?,
+        )?;
+        let values = match precision {
+            1 => bytes.iter().map(|&b| f64::from(b) / 255.0).collect(),
+            _ => bytes
+                .chunks_exact(2)
+                .map(|c| f64::from(u16::from_be_bytes([c[0], c[1]])) / 65535.0)
+                .collect(),
+        };
+        Ok(Self {
+            grid,
+            outputs,
+            values,
+        })
+    }
+}
+
+/// An `A2Bx` or `B2Ax` transform: a pipeline of curves, matrices and CLUTs
+/// on normalized [0, 1] values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut {
+    /// Tag type the LUT was read from.
+    pub kind: LutKind,
+    /// Number of input channels.
+    pub input_channels: usize,
+    /// Number of output channels.
+    pub output_channels: usize,
+    /// Processing elements, in evaluation order.
+    pub stages: Vec<LutStage>,
+}
+
+impl Lut {
+    /// Parse a LUT tag. `xyz_input` enables the `mft1`/`mft2` matrix, which
+    /// the ICC spec applies only when the input space is XYZ. `channels` are
+    /// the input and output counts implied by the header, where known.
+    pub(super) fn parse(
+        r: &Reader<'_>,
+        xyz_input: bool,
+        channels: (Option<usize>, Option<usize>),
+    ) -> Result<Self, IccError> {
+        let sig = r.signature(0)?;
+        let inputs = usize::from(r.u8(8)?);
+        let outputs = usize::from(r.u8(9)?);
+        if !(1..=MAX_CHANNELS).contains(&inputs) || !(1..=MAX_CHANNELS).contains(&outputs) {
+            return Err(r.malformed());
+        }
+        if channels.0.is_some_and(|n| n != inputs) || channels.1.is_some_and(|n| n != outputs) {
+            return Err(r.color_space_mismatch());
+        }
+        match &sig {
+            b"mft1" | b"mft2" => Self::parse_mft(r, sig == *b"mft2", inputs, outputs, xyz_input),
+            b"mAB " => Self::parse_mab(r, true, inputs, outputs),
+            b"mBA " => Self::parse_mab(r, false, inputs, outputs),
+            other => Err(IccError::UnsupportedTagType(signature_to_string(*other))),
+        }
+    }
+
+    fn parse_mft(
+        r: &Reader<'_>,
+        wide: bool,
+        inputs: usize,
+        outputs: usize,
+        xyz_input: bool,
+    ) -> Result<Self, IccError> {
+        let grid = usize::from(r.u8(10)?);
+        let (precision, in_entries, out_entries, mut pos) = if wide {
+            (2, usize::from(r.u16(48)?), usize::from(r.u16(50)?), 52)
+        } else {
+            (1, 256, 256, 48)
+        };
+        let scale = if wide { 65535.0 } else { 255.0 };
+        let table = |r: &Reader<'_>, entries: usize, pos: &mut usize| -> Result<Curve, IccError> {
+            let values = (0..entries)
+                .map(|i| {
+                    let off = *pos + i * precision;
+                    Ok(if wide {
+                        f64::from(r.u16(off)?)
+                    } else {
+                        f64::from(r.u8(off)?)
+                    } / scale)
+                })
+                .collect::<Result<Vec<_>, IccError>>()?;
+            *pos += entries * precision;
+            Ok(Curve::Sampled(values))
+        };
+
+        let mut stages = Vec::with_capacity(4);
+        if xyz_input && inputs == 3 {
+            let mut matrix = [[0.0; 3]; 3];
+            for (i, row) in matrix.iter_mut().enumerate() {
+                for (j, v) in row.iter_mut().enumerate() {
+                    *v = r.s15f16(12 + 4 * (3 * i + j))?;
+                }
+            }
+            stages.push(LutStage::Matrix {
+                matrix,
+                offset: [0.0; 3],
+            });
+        }
+        let input_curves = (0..inputs)
+            .map(|_| table(r, in_entries, &mut pos))
+            .collect::<Result<_, _>>()?;
+        stages.push(LutStage::Curves(input_curves));
+
+        let clut = Clut::parse(&r.at(pos)?, vec![grid; inputs], outputs, precision)?;
+        pos += clut.values.len() * precision;
+        stages.push(LutStage::Clut(clut));
+
+        let output_curves = (0..outputs)
+            .map(|_| table(r, out_entries, &mut pos))
+            .collect::<Result<_, _>>()?;
+        stages.push(LutStage::Curves(output_curves));
+
+        Ok(Self {
+            kind: if wide { LutKind::Lut16 } else { LutKind::Lut8 },
+            input_channels: inputs,
+            output_channels: outputs,
+            stages,
+        })
+    }
+
+    fn parse_mab(
+        r: &Reader<'_>,
+        a_to_b: bool,
+        inputs: usize,
+        outputs: usize,
+    ) -> Result<Self, IccError> {
+        let offset_b = r.usize(12)?;
+        let offset_matrix = r.usize(16)?;
+        let offset_m = r.usize(20)?;
+        let offset_clut = r.usize(24)?;
+        let offset_a = r.usize(28)?;
+
+        // A curves sit on the device side, B curves on the PCS side.
+        let (a_count, b_count) = if a_to_b {
+            (inputs, outputs)
+        } else {
+            (outputs, inputs)
+        };
+        let curves = |offset: usize, count: usize| -> Result<Option<LutStage>, IccError> {
+            if offset == 0 {
+                return Ok(None);
+            }
+            let mut pos = offset;
+            let mut list = Vec::with_capacity(count);
+            for _ in 0..count {
+                let (curve, size) = Curve::parse(&r.at(pos)?)?;
+                list.push(curve);
+                pos += (size + 3) & !3;
+            }
+            Ok(Some(LutStage::Curves(list)))
+        };
+
+        let a = curves(offset_a, a_count)?;
+        let b = curves(offset_b, b_count)?;
+        let m = curves(offset_m, 3)?;
+        let matrix = if offset_matrix == 0 {
+            None
+        } else {
+            let mut matrix = [[0.0; 3]; 3];
+            for (i, row) in matrix.iter_mut().enumerate() {
+                for (j, v) in row.iter_mut().enumerate() {
+                    *v = r.s15f16(offset_matrix + 4 * (3 * i + j))?;
+                }
+            }
+            let mut offset = [0.0; 3];
+            for (i, v) in offset.iter_mut().enumerate() {
+                *v = r.s15f16(offset_matrix + 36 + 4 * i)?;
+            }
+            Some(LutStage::Matrix { matrix, offset })
+        };
+        let clut = if offset_clut == 0 {
+            None
+        } else {
+            let c = r.at(offset_clut)?;
+            // 16 grid-point bytes, one per input channel, the rest unused.
+            let grid = c.bytes(0, 16)?[..inputs]
+                .iter()
+                .map(|&n| usize::from(n))
+                .collect();
+            let precision = usize::from(c.u8(16)?);
+            if precision != 1 && precision != 2 {
+                return Err(c.malformed());
+            }
+            Some(LutStage::Clut(Clut::parse(
+                &c.at(20)?,
+                grid,
+                outputs,
+                precision,
+            )?))
+        };
+
+        let order = if a_to_b {
+            [a, clut, m, matrix, b]
+        } else {
+            [b, matrix, m, clut, a]
+        };
+        Ok(Self {
+            kind: if a_to_b { LutKind::AToB } else { LutKind::BToA },
+            input_channels: inputs,
+            output_channels: outputs,
+            stages: order.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Run normalized input values through every stage.
+    #[must_use]
+    pub fn eval(&self, input: &[f64]) -> Vec<f64> {
+        let mut values = input.to_vec();
+        for stage in &self.stages {
+            values = match stage {
+                LutStage::Curves(curves) => {
+                    values.iter().zip(curves).map(|(&v, c)| c.eval(v)).collect()
+                }
+                LutStage::Matrix { matrix, offset } => {
+                    let v = [
+                        values.first().copied().unwrap_or(0.0),
+                        values.get(1).copied().unwrap_or(0.0),
+                        values.get(2).copied().unwrap_or(0.0),
+                    ];
+                    (0..3)
+                        .map(|i| {
+                            matrix[i][0] * v[0]
+                                + matrix[i][1] * v[1]
+                                + matrix[i][2] * v[2]
+                                + offset[i]
+                        })
+                        .collect()
+                }
+                LutStage::Clut(clut) => clut.eval(&values),
+            };
+        }
+        values
+    }
+}
@@ -0,0 +1,1089 @@
+//! ICC profile reader and color conversion.
+//!
+//! Reads ICC v2 and v4 profiles — header, tag table, matrix/TRC colorants
+//! (`rXYZ`/`gXYZ`/`bXYZ` with `rTRC`/`gTRC`/`bTRC`), gray TRCs and the
+//! `A2Bx`/`B2Ax` lookup tables — and converts device values to and from the
+//! profile connection space (PCS) and [`Color`].
+//!
+//! | Tag type | Used for                              |
+//! |----------|---------------------------------------|
+//! | `XYZ `   | Colorants, media white point          |
+//! | `curv`   | Identity, gamma or sampled TRC        |
+//! | `para`   | Parametric TRC, function types 0–4    |
+//! | `mft1`   | 8-bit LUT (`lut8Type`)                |
+//! | `mft2`   | 16-bit LUT (`lut16Type`)              |
+//! | `mAB `   | v4 A-to-B LUT (curves, CLUT, matrix)  |
+//! | `mBA `   | v4 B-to-A LUT                         |
+//! | `desc`, `mluc` | Profile description             |
+//!
+//! LUT-based transforms are preferred over matrix/TRC when both are present,
+//! as color management modules do. PCS values are D50-relative XYZ
+//! (Y = 1.0); [`IccProfile::to_color`] Bradford-adapts them to D65.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use momoto_core::color::icc::{IccProfile, RenderingIntent};
+//!
+//! let bytes = std::fs::read("DisplayP3.icc").unwrap();
+//! let profile = IccProfile::parse(&bytes).unwrap();
+//! let color = profile
+//!     .to_color(&[1.0, 0.0, 0.0], RenderingIntent::RelativeColorimetric)
+//!     .unwrap();
+//! println!("{}", color.to_hex());
+//! ```
+//!
+//! # References
+//!
+//! - ICC.1:2022 (Profile version 4.4.0.0), International Color Consortium
+//! - ICC.1:2001-04 (Profile version 2.4.0)
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+mod curve;
+mod lut;
+mod reader;
+
+pub use curve::Curve;
+pub use lut::{Clut, Lut, LutKind, LutStage};
+
+use core::fmt;
+
+use crate::color::Color;
+use crate::space::hct::cam16::mat3_mul_vec3;
+use crate::space::lab::Lab;
+use crate::space::xyz::{mat3_inverse, ChromaticAdaptation, Illuminant, Xyz};
+use reader::{signature_to_string, Reader};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Error produced when an ICC profile cannot be read or applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IccError {
+    /// The data is shorter than the 128-byte header plus tag count.
+    TooShort(usize),
+    /// The `acsp` profile file signature is missing.
+    InvalidSignature,
+    /// The header declares more bytes than were supplied.
+    Truncated {
+        /// Size declared in the header.
+        declared: usize,
+        /// Bytes actually supplied.
+        actual: usize,
+    },
+    /// A tag table entry points outside the profile.
+    TagOutOfBounds(String),
+    /// A tag's contents are inconsistent with its type.
+    MalformedTag(String),
+    /// A tag uses a type this reader does not understand.
+    UnsupportedTagType(String),
+    /// A tag's channel count does not fit the header's data color space or
+    /// PCS, e.g. RGB colorant tags in a gray profile.
+    ColorSpaceMismatch(String),
+    /// The number of device values does not match the profile.
+    ChannelMismatch {
+        /// Channels the profile expects.
+        expected: usize,
+        /// Channels supplied.
+        actual: usize,
+    },
+    /// The profile has no transform usable in the requested direction.
+    NoTransform,
+}
+
+impl fmt::Display for IccError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort(n) => write!(f, "ICC profile too short ({} bytes)", n),
+            Self::InvalidSignature => write!(f, "Missing 'acsp' ICC profile signature"),
+            Self::Truncated { declared, actual } => write!(
+                f,
+                "ICC profile truncated: header declares {} bytes, got {}",
+                declared, actual
+            ),
+            Self::TagOutOfBounds(t) => write!(f, "ICC tag '{}' lies outside the profile", t),
+            Self::MalformedTag(t) => write!(f, "Malformed ICC tag '{}'", t),
+            Self::UnsupportedTagType(t) => write!(f, "Unsupported ICC tag type '{}'", t),
+            Self::ColorSpaceMismatch(t) => {
+                write!(
+                    f,
+                    "ICC tag '{}' does not match the profile's color space",
+                    t
+                )
+            }
+            Self::ChannelMismatch { expected, actual } => {
+                write!(f, "Expected {} device channels, got {}", expected, actual)
+            }
+            Self::NoTransform => write!(f, "ICC profile has no usable transform"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IccError {}
+
+// ============================================================================
+// Header types
+// ============================================================================
+
+/// Profile/device class (header bytes 12–15).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfileClass {
+    /// `scnr` — input device (scanner, camera).
+    Input,
+    /// `mntr` — display device.
+    Display,
+    /// `prtr` — output device (printer, press).
+    Output,
+    /// `link` — device link.
+    DeviceLink,
+    /// `spac` — color space conversion.
+    ColorSpace,
+    /// `abst` — abstract (PCS to PCS).
+    Abstract,
+    /// `nmcl` — named color.
+    NamedColor,
+    /// Any other signature.
+    Unknown([u8; 4]),
+}
+
+impl ProfileClass {
+    fn from_signature(sig: [u8; 4]) -> Self {
+        match &sig {
+            b"scnr" => Self::Input,
+            b"mntr" => Self::Display,
+            b"prtr" => Self::Output,
+            b"link" => Self::DeviceLink,
+            b"spac" => Self::ColorSpace,
+            b"abst" => Self::Abstract,
+            b"nmcl" => Self::NamedColor,
+            _ => Self::Unknown(sig),
+        }
+    }
+}
+
+/// Data color space or PCS signature (header bytes 16–23).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSignature {
+    /// `XYZ `
+    Xyz,
+    /// `Lab `
+    Lab,
+    /// `RGB `
+    Rgb,
+    /// `GRAY`
+    Gray,
+    /// `CMYK`
+    Cmyk,
+    /// `CMY `
+    Cmy,
+    /// `2CLR`–`FCLR` — n-channel device space.
+    MultiChannel(u8),
+    /// Any other signature (HSV, YCbCr, …).
+    Other([u8; 4]),
+}
+
+impl ColorSignature {
+    fn from_signature(sig: [u8; 4]) -> Self {
+        match &sig {
+            b"XYZ " => Self::Xyz,
+            b"Lab " => Self::Lab,
+            b"RGB " => Self::Rgb,
+            b"GRAY" => Self::Gray,
+            b"CMYK" => Self::Cmyk,
+            b"CMY " => Self::Cmy,
+            [n, b'C', b'L', b'R'] if n.is_ascii_hexdigit() => {
+                Self::MultiChannel(char::from(*n).to_digit(16).unwrap_or(0) as u8)
+            }
+            _ => Self::Other(sig),
+        }
+    }
+
+    /// Number of channels, or `None` for unrecognised signatures.
+    #[must_use]
+    pub fn channels(self) -> Option<usize> {
+        match self {
+            Self::Gray => Some(1),
+            Self::Xyz | Self::Lab | Self::Rgb | Self::Cmy => Some(3),
+            Self::Cmyk => Some(4),
+            Self::MultiChannel(n) => Some(usize::from(n)),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// ICC rendering intent (header bytes 64–67).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RenderingIntent {
+    /// Compress the whole gamut, preserving relationships (`A2B0`/`B2A0`).
+    #[default]
+    Perceptual,
+    /// Map white to white, clip the rest (`A2B1`/`B2A1`).
+    RelativeColorimetric,
+    /// Preserve saturation at the cost of hue accuracy (`A2B2`/`B2A2`).
+    Saturation,
+    /// Like relative, but keep the media white (`A2B1`/`B2A1` + scaling).
+    AbsoluteColorimetric,
+}
+
+impl RenderingIntent {
+    fn from_u32(v: u32) -> Self {
+        match v & 0xFFFF {
+            1 => Self::RelativeColorimetric,
+            2 => Self::Saturation,
+            3 => Self::AbsoluteColorimetric,
+            _ => Self::Perceptual,
+        }
+    }
+
+    /// Index of the `A2Bx`/`B2Ax` tag this intent reads.
+    const fn lut_index(self) -> usize {
+        match self {
+            Self::Perceptual => 0,
+            Self::RelativeColorimetric | Self::AbsoluteColorimetric => 1,
+            Self::Saturation => 2,
+        }
+    }
+}
+
+/// Parsed 128-byte profile header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IccHeader {
+    /// Profile size in bytes.
+    pub size: usize,
+    /// Major version (2 or 4).
+    pub version_major: u8,
+    /// Minor version (high nibble of byte 9).
+    pub version_minor: u8,
+    /// Device class.
+    pub class: ProfileClass,
+    /// Device-side color space.
+    pub color_space: ColorSignature,
+    /// Profile connection space (`XYZ ` or `Lab `).
+    pub pcs: ColorSignature,
+    /// Default rendering intent.
+    pub rendering_intent: RenderingIntent,
+    /// PCS illuminant (nominally D50).
+    pub illuminant: Xyz,
+}
+
+/// One entry of the tag table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagEntry {
+    /// Tag signature, e.g. `*b"rTRC"`.
+    pub signature: [u8; 4],
+    /// Byte offset from the start of the profile.
+    pub offset: usize,
+    /// Size in bytes.
+    pub size: usize,
+}
+
+// ============================================================================
+// Profile
+// ============================================================================
+
+/// A parsed ICC profile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IccProfile {
+    /// Profile header.
+    pub header: IccHeader,
+    /// Tag table, in file order.
+    pub tags: Vec<TagEntry>,
+    /// Profile description (`desc`), if present.
+    pub description: Option<String>,
+    /// Media white point (`wtpt`), PCS-relative.
+    pub media_white_point: Option<Xyz>,
+    /// Colorant matrix; columns are `rXYZ`, `gXYZ`, `bXYZ`.
+    pub matrix: Option<[[f64; 3]; 3]>,
+    /// `rTRC`, `gTRC`, `bTRC`.
+    pub trc: Option<[Curve; 3]>,
+    /// `kTRC` of gray profiles.
+    pub gray_trc: Option<Curve>,
+    /// `A2B0`, `A2B1`, `A2B2` (device → PCS).
+    pub a_to_b: [Option<Lut>; 3],
+    /// `B2A0`, `B2A1`, `B2A2` (PCS → device).
+    pub b_to_a: [Option<Lut>; 3],
+}
+
+impl IccProfile {
+    /// Parse a profile from its raw bytes.
+    ///
+    /// Tags this reader does not need are listed in [`IccProfile::tags`] but
+    /// otherwise ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IccError`] if the header or tag table is invalid, or if a
+    /// tag needed for conversion is malformed or of an unsupported type.
+    pub fn parse(bytes: &[u8]) -> Result<Self, IccError> {
+        if bytes.len() < 132 {
+            return Err(IccError::TooShort(bytes.len()));
+        }
+        let r = Reader::new(bytes, *b"head");
+        if &r.signature(36)? != b"acsp" {
+            return Err(IccError::InvalidSignature);
+        }
+        let size = r.usize(0)?;
+        if size > bytes.len() {
+            return Err(IccError::Truncated {
+                declared: size,
+                actual: bytes.len(),
+            });
+        }
+        let data = &bytes[..size.max(132)];
+        let r = Reader::new(data, *b"head");
+
+        let version = r.signature(8)?;
+        let header = IccHeader {
+            size,
+            version_major: version[0],
+            version_minor: version[1] >> 4,
+            class: ProfileClass::from_signature(r.signature(12)?),
+            color_space: ColorSignature::from_signature(r.signature(16)?),
+            pcs: ColorSignature::from_signature(r.signature(20)?),
+            rendering_intent: RenderingIntent::from_u32(r.u32(64)?),
+            illuminant: r.xyz(68)?.into(),
+        };
+
+        let count = r.usize(128)?;
+        let table = Reader::new(data, *b"tags");
+        let mut tags = Vec::with_capacity(count.min(256));
+        for i in 0..count {
+            let base = 132 + 12 * i;
+            let entry = TagEntry {
+                signature: table.signature(base)?,
+                offset: table.usize(base + 4)?,
+                size: table.usize(base + 8)?,
+            };
+            if entry
+                .offset
+                .checked_add(entry.size)
+                .map_or(true, |end| end > data.len())
+            {
+                return Err(IccError::TagOutOfBounds(signature_to_string(
+                    entry.signature,
+                )));
+            }
+            tags.push(entry);
+        }
+
+        let mut profile = Self {
+            header,
+            tags,
+            description: None,
+            media_white_point: None,
+            matrix: None,
+            trc: None,
+            gray_trc: None,
+            a_to_b: [None, None, None],
+            b_to_a: [None, None, None],
+        };
+        profile.read_tags(data)?;
+        Ok(profile)
+    }
+
+    fn tag<'a>(&self, data: &'a [u8], sig: &[u8; 4]) -> Option<Reader<'a>> {
+        self.tags
+            .iter()
+            .find(|t| &t.signature == sig)
+            .map(|t| Reader::new(&data[t.offset..t.offset + t.size], t.signature))
+    }
+
+    fn read_tags(&mut self, data: &[u8]) -> Result<(), IccError> {
+        self.description = self.tag(data, b"desc").and_then(|r| read_text(&r));
+        if let Some(r) = self.tag(data, b"wtpt") {
+            self.media_white_point = Some(read_xyz(&r)?.into());
+        }
+
+        // Matrix/TRC tags describe exactly three device channels.
+        let device_channels = self.header.color_space.channels();
+        if device_channels.is_some_and(|n| n != 3) {
+            if let Some(sig) = [b"rXYZ", b"gXYZ", b"bXYZ", b"rTRC", b"gTRC", b"bTRC"]
+                .into_iter()
+                .find(|sig| self.tag(data, sig).is_some())
+            {
+                return Err(IccError::ColorSpaceMismatch(signature_to_string(*sig)));
+            }
+        }
+
+        let colorants =
+            [b"rXYZ", b"gXYZ", b"bXYZ"].map(|sig| self.tag(data, sig).map(|r| read_xyz(&r)));
+        if let [Some(r), Some(g), Some(b)] = colorants {
+            let (r, g, b) = (r?, g?, b?);
+            self.matrix = Some([[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]]);
+        }
+        let curves = [b"rTRC", b"gTRC", b"bTRC"].map(|sig| self.tag(data, sig));
+        if let [Some(r), Some(g), Some(b)] = curves {
+            self.trc = Some([
+                Curve::parse(&r)?.0,
+                Curve::parse(&g)?.0,
+                Curve::parse(&b)?.0,
+            ]);
+        }
+        if let Some(r) = self.tag(data, b"kTRC") {
+            self.gray_trc = Some(Curve::parse(&r)?.0);
+        }
+
+        let device_is_xyz = self.header.color_space == ColorSignature::Xyz;
+        let pcs_is_xyz = self.header.pcs == ColorSignature::Xyz;
+        let pcs_channels = self.header.pcs.channels();
+        for (i, (a2b, b2a)) in [(b"A2B0", b"B2A0"), (b"A2B1", b"B2A1"), (b"A2B2", b"B2A2")]
+            .into_iter()
+            .enumerate()
+        {
+            if let Some(r) = self.tag(data, a2b) {
+                let lut = Lut::parse(&r, device_is_xyz, (device_channels, pcs_channels))?;
+                self.a_to_b[i] = Some(lut);
+            }
+            if let Some(r) = self.tag(data, b2a) {
+                let lut = Lut::parse(&r, pcs_is_xyz, (pcs_channels, device_channels))?;
+                self.b_to_a[i] = Some(lut);
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of device channels (3 for RGB, 4 for CMYK, …).
+    #[must_use]
+    pub fn channels(&self) -> usize {
+        self.header
+            .color_space
+            .channels()
+            .or_else(|| {
+                self.a_to_b
+                    .iter()
+                    .flatten()
+                    .map(|l| l.input_channels)
+                    .next()
+            })
+            .unwrap_or(3)
+    }
+
+    /// Whether the profile can convert via matrix/TRC.
+    #[must_use]
+    pub fn is_matrix_shaper(&self) -> bool {
+        self.matrix.is_some() && self.trc.is_some()
+    }
+
+    fn lut(luts: &[Option<Lut>; 3], intent: RenderingIntent) -> Option<&Lut> {
+        luts[intent.lut_index()].as_ref().or(luts[0].as_ref())
+    }
+
+    /// Convert normalized device values ([0, 1] per channel) to PCS XYZ
+    /// (D50-relative, Y = 1.0).
+    ///
+    /// # Errors
+    ///
+    /// [`IccError::ChannelMismatch`] if `device` has the wrong length,
+    /// [`IccError::NoTransform`] if the profile has no device → PCS path.
+    pub fn to_pcs(&self, device: &[f64], intent: RenderingIntent) -> Result<Xyz, IccError> {
+        let expected = self.channels();
+        if device.len() != expected {
+            return Err(IccError::ChannelMismatch {
+                expected,
+                actual: device.len(),
+            });
+        }
+        let mismatch = |expected: usize| IccError::ChannelMismatch {
+            expected,
+            actual: device.len(),
+        };
+        let xyz = if let Some(lut) = Self::lut(&self.a_to_b, intent) {
+            if lut.input_channels != device.len() {
+                return Err(mismatch(lut.input_channels));
+            }
+            self.decode_pcs(&lut.eval(device), lut.kind)
+        } else if let (Some(m), Some(trc)) = (&self.matrix, &self.trc) {
+            let &[r, g, b] = device else {
+                return Err(mismatch(3));
+            };
+            let linear = [trc[0].eval(r), trc[1].eval(g), trc[2].eval(b)];
+            mat3_mul_vec3(m, linear).into()
+        } else if let Some(k) = &self.gray_trc {
+            let &[gray] = device else {
+                return Err(mismatch(1));
+            };
+            let y = k.eval(gray);
+            let w = self.header.illuminant;
+            Xyz::new(w.x * y, w.y * y, w.z * y)
+        } else {
+            return Err(IccError::NoTransform);
+        };
+        Ok(self.apply_media_white(xyz, intent))
+    }
+
+    /// Convert PCS XYZ (D50-relative, Y = 1.0) to normalized device values.
+    ///
+    /// Matrix/TRC and gray profiles are inverted analytically when no
+    /// `B2Ax` table is present.
+    ///
+    /// # Errors
+    ///
+    /// [`IccError::ChannelMismatch`] if the transform does not produce
+    /// [`IccProfile::channels`] values,
+    /// [`IccError::NoTransform`] if the profile has no PCS → device path.
+    pub fn from_pcs(&self, xyz: Xyz, intent: RenderingIntent) -> Result<Vec<f64>, IccError> {
+        let xyz = self.remove_media_white(xyz, intent);
+        let expected = self.channels();
+        let produced = if let Some(lut) = Self::lut(&self.b_to_a, intent) {
+            lut.output_channels
+        } else if self.is_matrix_shaper() {
+            3
+        } else if self.gray_trc.is_some() {
+            1
+        } else {
+            return Err(IccError::NoTransform);
+        };
+        if produced != expected {
+            return Err(IccError::ChannelMismatch {
+                expected,
+                actual: produced,
+            });
+        }
+
+        if let Some(lut) = Self::lut(&self.b_to_a, intent) {
+            let out = lut.eval(&self.encode_pcs(xyz, lut.kind));
+            Ok(out.into_iter().map(|v| v.clamp(0.0, 1.0)).collect())
+        } else if let (Some(m), Some(trc)) = (&self.matrix, &self.trc) {
+            let linear = mat3_mul_vec3(&mat3_inverse(m), xyz.to_array());
+            Ok((0..3).map(|i| trc[i].eval_inverse(linear[i])).collect())
+        } else if let Some(k) = &self.gray_trc {
+            Ok(vec![k.eval_inverse(xyz.y / self.header.illuminant.y)])
+        } else {
+            Err(IccError::NoTransform)
+        }
+    }
+
+    /// Convert device values to a [`Color`], adapting the PCS white to D65.
+    ///
+    /// Out-of-sRGB results are kept unclamped.
+    ///
+    /// # Errors
+    ///
+    /// See [`IccProfile::to_pcs`].
+    pub fn to_color(&self, device: &[f64], intent: RenderingIntent) -> Result<Color, IccError> {
+        let xyz = self.to_pcs(device, intent)?.adapt(
+            self.pcs_white(),
+            Illuminant::D65,
+            ChromaticAdaptation::Bradford,
+        );
+        Ok(xyz.to_color())
+    }
+
+    /// Convert a [`Color`] to device values for this profile.
+    ///
+    /// # Errors
+    ///
+    /// See [`IccProfile::from_pcs`].
+    pub fn from_color(&self, color: &Color, intent: RenderingIntent) -> Result<Vec<f64>, IccError> {
+        let xyz = Xyz::from_color(color).adapt(
+            Illuminant::D65,
+            self.pcs_white(),
+            ChromaticAdaptation::Bradford,
+        );
+        self.from_pcs(xyz, intent)
+    }
+
+    /// Convert device values of this profile into device values of `target`
+    /// through the PCS.
+    ///
+    /// # Errors
+    ///
+    /// Any error of [`IccProfile::to_pcs`] or [`IccProfile::from_pcs`].
+    pub fn convert(
+        &self,
+        device: &[f64],
+        target: &IccProfile,
+        intent: RenderingIntent,
+    ) -> Result<Vec<f64>, IccError> {
+        let xyz = self.to_pcs(device, intent)?;
+        let xyz = xyz.adapt(
+            self.pcs_white(),
+            target.pcs_white(),
+            ChromaticAdaptation::Bradford,
+        );
+        target.from_pcs(xyz, intent)
+    }
+
+    fn pcs_white(&self) -> Illuminant {
+        Illuminant::Custom(self.header.illuminant)
+    }
+
+    /// LUT output (normalized) → PCS XYZ.
+    fn decode_pcs(&self, v: &[f64], kind: LutKind) -> Xyz {
+        let v = [
+            v.first().copied().unwrap_or(0.0),
+            v.get(1).copied().unwrap_or(0.0),
+            v.get(2).copied().unwrap_or(0.0),
+        ];
+        if self.header.pcs == ColorSignature::Lab {
+            let scale = lab_scale(kind);
+            Lab::new(
+                v[0] * scale * 100.0,
+                v[1] * scale * 255.0 - 128.0,
+                v[2] * scale * 255.0 - 128.0,
+            )
+            .to_xyz(self.pcs_white())
+        } else {
+            Xyz::from(v.map(|c| c * XYZ_ENCODING_MAX))
+        }
+    }
+
+    /// PCS XYZ → LUT input (normalized).
+    fn encode_pcs(&self, xyz: Xyz, kind: LutKind) -> Vec<f64> {
+        let v = if self.header.pcs == ColorSignature::Lab {
+            let lab = Lab::from_xyz(xyz, self.pcs_white());
+            let scale = lab_scale(kind);
+            [
+                lab.l / 100.0 / scale,
+                (lab.a + 128.0) / 255.0 / scale,
+                (lab.b + 128.0) / 255.0 / scale,
+            ]
+        } else {
+            xyz.to_array().map(|c| c / XYZ_ENCODING_MAX)
+        };
+        v.iter().map(|c| c.clamp(0.0, 1.0)).collect()
+    }
+
+    fn apply_media_white(&self, xyz: Xyz, intent: RenderingIntent) -> Xyz {
+        match (intent, self.media_white_point) {
+            (RenderingIntent::AbsoluteColorimetric, Some(w)) => {
+                let p = self.header.illuminant;
+                Xyz::new(xyz.x * w.x / p.x, xyz.y * w.y / p.y, xyz.z * w.z / p.z)
+            }
+            _ => xyz,
+        }
+    }
+
+    fn remove_media_white(&self, xyz: Xyz, intent: RenderingIntent) -> Xyz {
+        match (intent, self.media_white_point) {
+            (RenderingIntent::AbsoluteColorimetric, Some(w)) => {
+                let p = self.header.illuminant;
+                Xyz::new(xyz.x * p.x / w.x, xyz.y * p.y / w.y, xyz.z * p.z / w.z)
+            }
+            _ => xyz,
+        }
+    }
+}
+
+/// Largest PCS XYZ value (1 + 32767/32768) representable by the 16-bit
+/// encoding; LUT values of 1.0 map to it.
+const XYZ_ENCODING_MAX: f64 = 65535.0 / 32768.0;
+
+/// `lut16Type` stores Lab with the legacy encoding (L* = 100 at 0xFF00).
+fn lab_scale(kind: LutKind) -> f64 {
+    match kind {
+        LutKind::Lut16 => 65535.0 / 65280.0,
+        _ => 1.0,
+    }
+}
+
+fn read_xyz(r: &Reader<'_>) -> Result<[f64; 3], IccError> {
+    match &r.signature(0)? {
+        b"XYZ " => r.xyz(8),
+        other => Err(IccError::UnsupportedTagType(signature_to_string(*other))),
+    }
+}
+
+/// Read a `desc` (v2) or `mluc` (v4) text tag; the first `mluc` record wins.
+fn read_text(r: &Reader<'_>) -> Option<String> {
+    match &r.signature(0).ok()? {
+        b"desc" => {
+            let len = r.usize(8).ok()?;
+            let bytes = r.bytes(12, len).ok()?;
+            let text: String = bytes
+                .iter()
+                .take_while(|&&b| b != 0)
+                .map(|&b| char::from(b))
+                .collect();
+            Some(text)
+        }
+        b"mluc" => {
+            if r.usize(8).ok()? == 0 {
+                return None;
+            }
+            let len = r.usize(20).ok()?;
+            let offset = r.usize(24).ok()?;
+            let units: Vec<u16> = r
+                .bytes(offset, len)
+                .ok()?
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            Some(
+                String::from_utf16_lossy(&units)
+                    .trim_end_matches('\0')
+                    .to_string(),
+            )
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------------------------------------------------
+    // Minimal profile writer for tests
+    // ------------------------------------------------------------------
+
+    fn s15f16(v: f64) -> [u8; 4] {
+        ((v * 65536.0).round() as i32).to_be_bytes()
+    }
+
+    fn xyz_tag(v: [f64; 3]) -> Vec<u8> {
+        let mut out = b"XYZ \0\0\0\0".to_vec();
+        for c in v {
+            out.extend(s15f16(c));
+        }
+        out
+    }
+
+    fn para_tag(function: u16, params: &[f64]) -> Vec<u8> {
+        let mut out = b"para\0\0\0\0".to_vec();
+        out.extend(function.to_be_bytes());
+        out.extend([0, 0]);
+        for &p in params {
+            out.extend(s15f16(p));
+        }
+        out
+    }
+
+    fn curv_tag(values: &[u16]) -> Vec<u8> {
+        let mut out = b"curv\0\0\0\0".to_vec();
+        out.extend((values.len() as u32).to_be_bytes());
+        for v in values {
+            out.extend(v.to_be_bytes());
+        }
+        out
+    }
+
+    fn pad4(mut v: Vec<u8>) -> Vec<u8> {
+        while v.len() % 4 != 0 {
+            v.push(0);
+        }
+        v
+    }
+
+    fn build(space: &[u8; 4], pcs: &[u8; 4], tags: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+        let mut header = vec![0u8; 128];
+        header[8] = 4;
+        header[9] = 0x30;
+        header[12..16].copy_from_slice(b"mntr");
+        header[16..20].copy_from_slice(space);
+        header[20..24].copy_from_slice(pcs);
+        header[36..40].copy_from_slice(b"acsp");
+        for (i, c) in [0.9642, 1.0, 0.8249].into_iter().enumerate() {
+            header[68 + 4 * i..72 + 4 * i].copy_from_slice(&s15f16(c));
+        }
+        let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+        let mut body = Vec::new();
+        let mut offset = 128 + 4 + 12 * tags.len();
+        for (sig, data) in tags {
+            table.extend(*sig);
+            table.extend((offset as u32).to_be_bytes());
+            table.extend((data.len() as u32).to_be_bytes());
+            let padded = pad4(data.clone());
+            offset += padded.len();
+            body.extend(padded);
+        }
+        let mut out = header;
+        out.extend(table);
+        out.extend(body);
+        let size = out.len() as u32;
+        out[0..4].copy_from_slice(&size.to_be_bytes());
+        out
+    }
+
+    /// sRGB primaries Bradford-adapted to D50, as in the ICC sRGB profiles.
+    const SRGB_D50: [[f64; 3]; 3] = [
+        [0.4360747, 0.3850649, 0.1430804],
+        [0.2225045, 0.7168786, 0.0606169],
+        [0.0139322, 0.0971045, 0.7141733],
+    ];
+
+    fn srgb_profile() -> Vec<u8> {
+        let trc = para_tag(3, &[2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045]);
+        let col = |j: usize| xyz_tag([SRGB_D50[0][j], SRGB_D50[1][j], SRGB_D50[2][j]]);
+        let mut desc = b"desc\0\0\0\0".to_vec();
+        desc.extend(5u32.to_be_bytes());
+        desc.extend(b"sRGB\0");
+        build(
+            b"RGB ",
+            b"XYZ ",
+            &[
+                (b"desc", desc),
+                (b"wtpt", xyz_tag([0.9642, 1.0, 0.8249])),
+                (b"rXYZ", col(0)),
+                (b"gXYZ", col(1)),
+                (b"bXYZ", col(2)),
+                (b"rTRC", trc.clone()),
+                (b"gTRC", trc.clone()),
+                (b"bTRC", trc),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_parse_header_and_tags() {
+        let profile = IccProfile::parse(&srgb_profile()).unwrap();
+        assert_eq!(profile.header.version_major, 4);
+        assert_eq!(profile.header.version_minor, 3);
+        assert_eq!(profile.header.class, ProfileClass::Display);
+        assert_eq!(profile.header.color_space, ColorSignature::Rgb);
+        assert_eq!(profile.header.pcs, ColorSignature::Xyz);
+        assert!((profile.header.illuminant.z - 0.8249).abs() < 1e-4);
+        assert_eq!(profile.tags.len(), 8);
+        assert_eq!(profile.description.as_deref(), Some("sRGB"));
+        assert!(profile.is_matrix_shaper());
+        assert_eq!(profile.channels(), 3);
+    }
+
+    #[test]
+    fn test_matrix_shaper_matches_srgb() {
+        let profile = IccProfile::parse(&srgb_profile()).unwrap();
+        let intent = RenderingIntent::RelativeColorimetric;
+        for rgb in [[255, 0, 0], [0, 255, 0], [59, 130, 246], [255, 255, 255]] {
+            let device = rgb.map(|c| f64::from(c) / 255.0);
+            let color = profile.to_color(&device, intent).unwrap();
+            assert_eq!(color.to_srgb8(), rgb.map(|c| c as u8), "{rgb:?}");
+
+            let back = profile.from_color(&color, intent).unwrap();
+            for i in 0..3 {
+                assert!((back[i] - device[i]).abs() < 1e-3, "{rgb:?}: {back:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_curves() {
+        let gamma = curv_tag(&[563]); // 2.19921875 as u8Fixed8
+        let (curve, size) = Curve::parse(&Reader::new(&gamma, *b"test")).unwrap();
+        assert_eq!(size, 14);
+        assert_eq!(curve, Curve::Gamma(563.0 / 256.0));
+
+        let sampled = curv_tag(&[0, 16384, 65535]);
+        let (curve, _) = Curve::parse(&Reader::new(&sampled, *b"test")).unwrap();
+        assert!((curve.eval(0.25) - 0.125).abs() < 1e-4);
+        assert!((curve.eval_inverse(curve.eval(0.7)) - 0.7).abs() < 1e-9);
+
+        // Type 4 with offsets e and f
+        let para = para_tag(4, &[2.0, 1.0, 0.0, 0.5, 0.5, 0.1, 0.05]);
+        let (curve, size) = Curve::parse(&Reader::new(&para, *b"test")).unwrap();
+        assert_eq!(size, 40);
+        assert!((curve.eval(0.2) - 0.15).abs() < 1e-4);
+        assert!((curve.eval(0.8) - 0.74).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_mab_mba_xyz_pcs() {
+        // Linear-light RGB → XYZ through the v4 matrix element
+        let mut scaled = SRGB_D50;
+        for row in &mut scaled {
+            for v in row.iter_mut() {
+                *v /= XYZ_ENCODING_MAX;
+            }
+        }
+        let lut = |sig: &[u8; 4], m: [[f64; 3]; 3]| {
+            let identity = pad4(curv_tag(&[]));
+            let mut out = sig.to_vec();
+            out.extend([0; 4]);
+            out.extend([3, 3, 0, 0]);
+            // B curves at 32, matrix after them; no M, CLUT or A
+            let b_offset = 32u32;
+            let m_offset = b_offset + 3 * identity.len() as u32;
+            for off in [b_offset, m_offset, 0, 0, 0] {
+                out.extend(off.to_be_bytes());
+            }
+            for _ in 0..3 {
+                out.extend(&identity);
+            }
+            for row in m {
+                for v in row {
+                    out.extend(s15f16(v));
+                }
+            }
+            out.extend([0; 12]);
+            out
+        };
+        let bytes = build(
+            b"RGB ",
+            b"XYZ ",
+            &[
+                (b"A2B0", lut(b"mAB ", scaled)),
+                (b"B2A0", lut(b"mBA ", mat3_inverse(&scaled))),
+            ],
+        );
+        let profile = IccProfile::parse(&bytes).unwrap();
+        let lut = profile.a_to_b[0].as_ref().unwrap();
+        assert_eq!(lut.kind, LutKind::AToB);
+        assert_eq!(lut.stages.len(), 2);
+
+        let white = profile
+            .to_pcs(&[1.0; 3], RenderingIntent::Perceptual)
+            .unwrap();
+        assert!((white.x - 0.9642).abs() < 1e-3, "{white:?}");
+        assert!((white.y - 1.0).abs() < 1e-3, "{white:?}");
+
+        let device = profile
+            .from_pcs(Xyz::new(0.3, 0.4, 0.2), RenderingIntent::Saturation)
+            .unwrap();
+        let back = profile
+            .to_pcs(&device, RenderingIntent::Saturation)
+            .unwrap();
+        assert!((back.y - 0.4).abs() < 1e-3, "{back:?}");
+    }
+
+    #[test]
+    fn test_mft1_gray_to_lab() {
+        // 1 input, 3 outputs, 2 grid points: gray 0 → Lab(0,0,0), 1 → Lab(100,0,0)
+        let mut mft = b"mft1\0\0\0\0".to_vec();
+        mft.extend([1, 3, 2, 0]);
+        for i in 0..9 {
+            mft.extend(s15f16(if i % 4 == 0 { 1.0 } else { 0.0 }));
+        }
+        let ramp: Vec<u8> = (0..=255).collect();
+        mft.extend(&ramp);
+        mft.extend([0, 128, 128, 255, 128, 128]);
+        for _ in 0..3 {
+            mft.extend(&ramp);
+        }
+        let bytes = build(b"GRAY", b"Lab ", &[(b"A2B0", mft)]);
+        let profile = IccProfile::parse(&bytes).unwrap();
+        assert_eq!(profile.channels(), 1);
+
+        let xyz = profile.to_pcs(&[0.5], RenderingIntent::Perceptual).unwrap();
+        let lab = Lab::from_xyz(xyz, profile.pcs_white());
+        assert!((lab.l - 50.0).abs() < 0.5, "{lab:?}");
+        assert!(lab.a.abs() < 0.5 && lab.b.abs() < 0.5, "{lab:?}");
+        // No B2A and no TRC: the reverse direction is unavailable
+        assert_eq!(
+            profile.from_pcs(xyz, RenderingIntent::Perceptual),
+            Err(IccError::NoTransform)
+        );
+    }
+
+    #[test]
+    fn test_convert_between_profiles() {
+        let srgb = IccProfile::parse(&srgb_profile()).unwrap();
+        let gray = build(b"GRAY", b"XYZ ", &[(b"kTRC", curv_tag(&[256]))]);
+        let gray = IccProfile::parse(&gray).unwrap();
+        let out = srgb
+            .convert(
+                &[0.5, 0.5, 0.5],
+                &gray,
+                RenderingIntent::RelativeColorimetric,
+            )
+            .unwrap();
+        // Linear-gamma gray receives sRGB mid-gray's linear luminance
+        assert!((out[0] - 0.2140).abs() < 1e-3, "{out:?}");
+    }
+
+    #[test]
+    fn test_absolute_colorimetric_scales_media_white() {
+        let mut bytes = srgb_profile();
+        let profile = IccProfile::parse(&bytes).unwrap();
+        let wtpt = profile
+            .tags
+            .iter()
+            .find(|t| &t.signature == b"wtpt")
+            .unwrap();
+        // Paper-like media white at 90% luminance
+        let y_at = wtpt.offset + 12;
+        bytes[y_at..y_at + 4].copy_from_slice(&s15f16(0.9));
+        let profile = IccProfile::parse(&bytes).unwrap();
+        let abs = profile
+            .to_pcs(&[1.0; 3], RenderingIntent::AbsoluteColorimetric)
+            .unwrap();
+        assert!((abs.y - 0.9).abs() < 1e-3, "{abs:?}");
+        let device = profile
+            .from_pcs(abs, RenderingIntent::AbsoluteColorimetric)
+            .unwrap();
+        assert!(device.iter().all(|&v| (v - 1.0).abs() < 1e-3), "{device:?}");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(IccProfile::parse(&[0; 64]), Err(IccError::TooShort(64)));
+        let mut bytes = srgb_profile();
+        bytes[36] = b'x';
+        assert_eq!(IccProfile::parse(&bytes), Err(IccError::InvalidSignature));
+
+        let mut bytes = srgb_profile();
+        let len = bytes.len();
+        bytes.truncate(len - 8);
+        assert!(matches!(
+            IccProfile::parse(&bytes),
+            Err(IccError::Truncated { .. })
+        ));
+
+        let mut bytes = srgb_profile();
+        bytes[132 + 4..132 + 8].copy_from_slice(&0xFFFF_u32.to_be_bytes());
+        assert_eq!(
+            IccProfile::parse(&bytes),
+            Err(IccError::TagOutOfBounds("desc".to_string()))
+        );
+
+        let profile = IccProfile::parse(&srgb_profile()).unwrap();
+        assert_eq!(
+            profile.to_pcs(&[0.5; 4], RenderingIntent::Perceptual),
+            Err(IccError::ChannelMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_malformed_profiles_are_errors() {
+        // RGB colorants and TRCs in a gray profile
+        let trc = curv_tag(&[256]);
+        let bytes = build(
+            b"GRAY",
+            b"XYZ ",
+            &[
+                (b"rXYZ", xyz_tag([0.4, 0.2, 0.0])),
+                (b"gXYZ", xyz_tag([0.4, 0.7, 0.1])),
+                (b"bXYZ", xyz_tag([0.1, 0.1, 0.7])),
+                (b"rTRC", trc.clone()),
+                (b"gTRC", trc.clone()),
+                (b"bTRC", trc),
+            ],
+        );
+        assert_eq!(
+            IccProfile::parse(&bytes),
+            Err(IccError::ColorSpaceMismatch("rXYZ".to_string()))
+        );
+
+        // LUT declaring 70 inputs
+        let mut mab = b"mAB \0\0\0\0".to_vec();
+        mab.extend([70, 3, 0, 0]);
+        mab.extend([0; 20]);
+        let bytes = build(b"RGB ", b"XYZ ", &[(b"A2B0", mab.clone())]);
+        assert_eq!(
+            IccProfile::parse(&bytes),
+            Err(IccError::MalformedTag("A2B0".to_string()))
+        );
+
+        // LUT inputs disagreeing with the header
+        mab[8] = 4;
+        let bytes = build(b"RGB ", b"XYZ ", &[(b"A2B0", mab)]);
+        assert_eq!(
+            IccProfile::parse(&bytes),
+            Err(IccError::ColorSpaceMismatch("A2B0".to_string()))
+        );
+
+        // Sampled curve with an impossible entry count
+        let mut curv = b"curv\0\0\0\0".to_vec();
+        curv.extend(u32::MAX.to_be_bytes());
+        assert!(Curve::parse(&Reader::new(&curv, *b"test")).is_err());
+    }
+}
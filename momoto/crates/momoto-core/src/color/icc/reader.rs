@@ -0,0 +1,84 @@
+//! Big-endian reader over the bytes of an ICC profile.
+
+use super::IccError;
+use alloc::string::{String, ToString};
+
+/// Bounds-checked big-endian cursor over one tag's bytes.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Reader<'a> {
+    data: &'a [u8],
+    tag: [u8; 4],
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(data: &'a [u8], tag: [u8; 4]) -> Self {
+        Self { data, tag }
+    }
+
+    /// Sub-reader starting at `offset` to the end of this one.
+    pub(super) fn at(&self, offset: usize) -> Result<Self, IccError> {
+        let data = self.data.get(offset..).ok_or_else(|| self.malformed())?;
+        Ok(Self::new(data, self.tag))
+    }
+
+    pub(super) fn malformed(&self) -> IccError {
+        IccError::MalformedTag(signature_to_string(self.tag))
+    }
+
+    pub(super) fn color_space_mismatch(&self) -> IccError {
+        IccError::ColorSpaceMismatch(signature_to_string(self.tag))
+    }
+
+    pub(super) fn bytes(&self, offset: usize, len: usize) -> Result<&'a [u8], IccError> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or_else(|| self.malformed())
+    }
+
+    pub(super) fn signature(&self, offset: usize) -> Result<[u8; 4], IccError> {
+        let b = self.bytes(offset, 4)?;
+        Ok([b[0], b[1], b[2], b[3]])
+    }
+
+    pub(super) fn u8(&self, offset: usize) -> Result<u8, IccError> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+
+    pub(super) fn u16(&self, offset: usize) -> Result<u16, IccError> {
+        let b = self.bytes(offset, 2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    pub(super) fn u32(&self, offset: usize) -> Result<u32, IccError> {
+        Ok(u32::from_be_bytes(self.signature(offset)?))
+    }
+
+    /// `u32` used as a byte offset or count.
+    pub(super) fn usize(&self, offset: usize) -> Result<usize, IccError> {
+        usize::try_from(self.u32(offset)?).map_err(|_| self.malformed())
+    }
+
+    /// `s15Fixed16Number`: signed 32-bit with 16 fractional bits.
+    pub(super) fn s15f16(&self, offset: usize) -> Result<f64, IccError> {
+        Ok(f64::from(self.u32(offset)? as i32) / 65536.0)
+    }
+
+    /// `XYZNumber`: three `s15Fixed16Number`s.
+    pub(super) fn xyz(&self, offset: usize) -> Result<[f64; 3], IccError> {
+        Ok([
+            self.s15f16(offset)?,
+            self.s15f16(offset + 4)?,
+            self.s15f16(offset + 8)?,
+        ])
+    }
+}
+
+/// Four-character signature as text, e.g. `"rXYZ"`.
+pub(super) fn signature_to_string(sig: [u8; 4]) -> String {
+    sig.iter()
+        .map(|&b| char::from(b))
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
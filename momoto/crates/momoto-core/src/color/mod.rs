@@ -4,7 +4,19 @@
 //! in both sRGB (gamma-corrected) and linear RGB spaces, with alpha channel support.
 
 mod operations;
+/// CSS gradients and RGBA rasters sampled as text backgrounds.
+pub mod background;
+/// Porter-Duff alpha compositing and CSS blend modes.
+pub mod blend;
+/// CMYK separation, print gamut checking and soft-proofing.
+pub mod cmyk;
 pub mod cvd;
+/// CSS Color Level 4/5 parsing and serialization.
+pub mod css;
+/// ICC v2/v4 profile reader and device ↔ PCS conversion.
+pub mod icc;
+/// Perceptual color names and human-readable descriptions.
+pub mod naming;
 
 /// sRGB gamma correction transfer functions.
 ///
@@ -47,6 +59,8 @@ pub mod cvd;
 /// assert!((back - srgb).abs() < 0.0001);
 /// ```
 pub mod gamma {
+    use crate::math::ops;
+
     /// Converts an sRGB channel value (0.0-1.0) to linear RGB.
     ///
     /// Uses the standard sRGB transfer function (IEC 61966-2-1:1999):
@@ -81,7 +95,7 @@ pub mod gamma {
         if channel <= 0.04045 {
             channel / 12.92
         } else {
-            ((channel + 0.055) / 1.055).powf(2.4)
+            ops::powf((channel + 0.055) / 1.055, 2.4)
         }
     }
 
@@ -119,13 +133,17 @@ pub mod gamma {
         if channel <= 0.0031308 {
             channel * 12.92
         } else {
-            1.055 * channel.powf(1.0 / 2.4) - 0.055
+            1.055 * ops::powf(channel, 1.0 / 2.4) - 0.055
         }
     }
 }
 
+use alloc::{format, string::String};
 use core::fmt;
 
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
 /// A color represented in both sRGB and linear RGB color spaces.
 ///
 /// All channels are stored as `f64` in the range [0.0, 1.0].
@@ -136,6 +154,7 @@ use core::fmt;
 /// gamma conversions. This is a space-time tradeoff optimized for
 /// typical usage patterns where colors are created once and used many times.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     /// sRGB channels (gamma-corrected, 0.0-1.0)
     pub srgb: [f64; 3],
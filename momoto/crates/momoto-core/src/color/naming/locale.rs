@@ -0,0 +1,159 @@
+//! Word tables for [`ColorDescription`](super::ColorDescription).
+
+use super::{BaseName, Chroma, Lightness};
+
+/// Language of generated color descriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// English: adjectives before the color word ("muted dark teal").
+    #[default]
+    En,
+    /// Spanish: adjectives after the color word ("verde azulado oscuro apagado").
+    Es,
+    /// French: adjectives after the color word ("bleu sarcelle foncé terne").
+    Fr,
+}
+
+impl Locale {
+    /// Every supported locale.
+    pub const ALL: [Self; 3] = [Self::En, Self::Es, Self::Fr];
+
+    /// Locale for a BCP 47 tag such as `"es"` or `"fr-CA"`, by its primary
+    /// language subtag.
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next().unwrap_or("");
+        match primary.to_ascii_lowercase().as_str() {
+            "en" => Some(Self::En),
+            "es" => Some(Self::Es),
+            "fr" => Some(Self::Fr),
+            _ => None,
+        }
+    }
+
+    /// Primary language subtag.
+    #[must_use]
+    pub const fn tag(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Es => "es",
+            Self::Fr => "fr",
+        }
+    }
+
+    /// Whether adjectives follow the color word.
+    #[must_use]
+    pub const fn adjectives_follow(self) -> bool {
+        !matches!(self, Self::En)
+    }
+
+    /// Word for a base color name.
+    #[must_use]
+    pub const fn base(self, base: BaseName) -> &'static str {
+        let table = match self {
+            Self::En => &EN_BASE,
+            Self::Es => &ES_BASE,
+            Self::Fr => &FR_BASE,
+        };
+        table[base as usize]
+    }
+
+    /// Lightness adjective; empty for [`Lightness::Medium`].
+    #[must_use]
+    pub const fn lightness(self, lightness: Lightness) -> &'static str {
+        let table = match self {
+            Self::En => &["very dark", "dark", "", "light", "very light"],
+            Self::Es => &["muy oscuro", "oscuro", "", "claro", "muy claro"],
+            Self::Fr => &["très foncé", "foncé", "", "clair", "très clair"],
+        };
+        table[lightness as usize]
+    }
+
+    /// Chroma adjective; empty for [`Chroma::Neutral`] and [`Chroma::Moderate`].
+    #[must_use]
+    pub const fn chroma(self, chroma: Chroma) -> &'static str {
+        let table = match self {
+            Self::En => &["", "grayish", "muted", "", "vivid"],
+            Self::Es => &["", "grisáceo", "apagado", "", "vivo"],
+            Self::Fr => &["", "grisâtre", "terne", "", "vif"],
+        };
+        table[chroma as usize]
+    }
+}
+
+// Indexed by `BaseName as usize`.
+const EN_BASE: [&str; BaseName::COUNT] = [
+    "black",
+    "dark gray",
+    "gray",
+    "light gray",
+    "white",
+    "red",
+    "orange",
+    "yellow",
+    "lime",
+    "green",
+    "teal",
+    "cyan",
+    "azure",
+    "blue",
+    "violet",
+    "purple",
+    "magenta",
+    "rose",
+    "pink",
+    "brown",
+    "olive",
+    "olive green",
+];
+
+const ES_BASE: [&str; BaseName::COUNT] = [
+    "negro",
+    "gris oscuro",
+    "gris",
+    "gris claro",
+    "blanco",
+    "rojo",
+    "naranja",
+    "amarillo",
+    "lima",
+    "verde",
+    "verde azulado",
+    "cian",
+    "celeste",
+    "azul",
+    "violeta",
+    "morado",
+    "magenta",
+    "fucsia",
+    "rosa",
+    "marrón",
+    "oliva",
+    "verde oliva",
+];
+
+const FR_BASE: [&str; BaseName::COUNT] = [
+    "noir",
+    "gris foncé",
+    "gris",
+    "gris clair",
+    "blanc",
+    "rouge",
+    "orange",
+    "jaune",
+    "citron vert",
+    "vert",
+    "bleu sarcelle",
+    "cyan",
+    "azur",
+    "bleu",
+    "violet",
+    "pourpre",
+    "magenta",
+    "framboise",
+    "rose",
+    "brun",
+    "olive",
+    "vert olive",
+];
@@ -0,0 +1,836 @@
+//! Perceptual color naming and human-readable descriptions.
+//!
+//! Two complementary ways to put a color into words:
+//!
+//! - **Nearest name** ([`nearest`], [`nearest_n`]): the closest entry of a
+//!   [`Vocabulary`] by ΔEOK (Euclidean distance in OKLab). CSS keywords and
+//!   the [xkcd survey](xkcd::XKCD_COLORS) are fixed tables; systematic names
+//!   are built from a lightness/chroma modifier and a hue name.
+//! - **Description** ([`describe`]): a structured [`ColorDescription`] of
+//!   chroma, lightness and hue terms, rendered in any [`Locale`] —
+//!   "muted dark teal", "verde azulado oscuro apagado".
+//!
+//! Both classify in OKLCH. Chroma terms are relative to the most saturated
+//! sRGB color of the same hue (the gamut cusp), so a fully saturated yellow
+//! and a fully saturated blue are both "vivid" despite very different
+//! absolute chroma. Alpha is ignored.
+//!
+//! Systematic names borrow the ISCC–NBS modifier and hue words ("moderate
+//! olive green", "very deep red") but are generated from this module's OKLCH
+//! terms. They are not the published ISCC–NBS Munsell blocks or centroid
+//! colors.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::naming::{describe, nearest, Locale, Vocabulary};
+//! use momoto_core::color::Color;
+//!
+//! let color = Color::from_srgb8(47, 92, 92);
+//! assert_eq!(describe(&color).to_string(), "muted dark teal");
+//! assert_eq!(describe(&color).text(Locale::Es), "verde azulado oscuro apagado");
+//!
+//! let name = nearest(&color, Vocabulary::Css);
+//! assert_eq!(name.name, "darkslategray");
+//! assert!(name.distance < 0.05);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::css::named::NAMED_COLORS;
+use super::Color;
+use crate::space::oklch::gamut::GamutMapper;
+use crate::space::oklch::{OKLab, OKLCH};
+use crate::space::rgb::RgbSpace;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+mod locale;
+pub mod xkcd;
+
+pub use locale::Locale;
+
+/// OKLCH chroma below which a color is described as a neutral gray.
+pub const NEUTRAL_CHROMA: f64 = 0.02;
+
+// ============================================================================
+// Terms
+// ============================================================================
+
+/// Lightness term, from OKLCH L.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Lightness {
+    /// L < 0.30
+    VeryDark,
+    /// 0.30 ≤ L < 0.45
+    Dark,
+    /// 0.45 ≤ L < 0.70
+    Medium,
+    /// 0.70 ≤ L < 0.85
+    Light,
+    /// L ≥ 0.85
+    VeryLight,
+}
+
+impl Lightness {
+    /// Every term, darkest first.
+    pub const ALL: [Self; 5] = [
+        Self::VeryDark,
+        Self::Dark,
+        Self::Medium,
+        Self::Light,
+        Self::VeryLight,
+    ];
+
+    /// Classify OKLCH lightness.
+    #[must_use]
+    pub fn from_l(l: f64) -> Self {
+        if l < 0.30 {
+            Self::VeryDark
+        } else if l < 0.45 {
+            Self::Dark
+        } else if l < 0.70 {
+            Self::Medium
+        } else if l < 0.85 {
+            Self::Light
+        } else {
+            Self::VeryLight
+        }
+    }
+
+    /// OKLCH lightness range `[low, high)` of this term.
+    #[must_use]
+    pub const fn range(self) -> (f64, f64) {
+        match self {
+            Self::VeryDark => (0.0, 0.30),
+            Self::Dark => (0.30, 0.45),
+            Self::Medium => (0.45, 0.70),
+            Self::Light => (0.70, 0.85),
+            Self::VeryLight => (0.85, 1.0),
+        }
+    }
+}
+
+/// Chroma term, from OKLCH C relative to the sRGB cusp of the hue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Chroma {
+    /// C < [`NEUTRAL_CHROMA`]: no hue is named.
+    Neutral,
+    /// Relative chroma < 0.15
+    Grayish,
+    /// 0.15 ≤ relative chroma < 0.40
+    Muted,
+    /// 0.40 ≤ relative chroma < 0.70
+    Moderate,
+    /// Relative chroma ≥ 0.70
+    Vivid,
+}
+
+impl Chroma {
+    /// Every term, least saturated first.
+    pub const ALL: [Self; 5] = [
+        Self::Neutral,
+        Self::Grayish,
+        Self::Muted,
+        Self::Moderate,
+        Self::Vivid,
+    ];
+
+    /// Classify a relative chroma in [0, 1].
+    #[must_use]
+    pub fn from_relative(relative: f64) -> Self {
+        if relative < 0.15 {
+            Self::Grayish
+        } else if relative < 0.40 {
+            Self::Muted
+        } else if relative < 0.70 {
+            Self::Moderate
+        } else {
+            Self::Vivid
+        }
+    }
+
+    /// Relative chroma at the middle of this term.
+    #[must_use]
+    pub const fn center(self) -> f64 {
+        match self {
+            Self::Neutral => 0.0,
+            Self::Grayish => 0.075,
+            Self::Muted => 0.275,
+            Self::Moderate => 0.55,
+            Self::Vivid => 0.85,
+        }
+    }
+}
+
+/// Base color word: a gray level or a hue family.
+///
+/// Hue ranges are OKLCH degrees. Some families split by lightness, so dark
+/// oranges are brown and light roses are pink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BaseName {
+    /// Gray with L < 0.25.
+    Black,
+    /// Gray with 0.25 ≤ L < 0.5.
+    DarkGray,
+    /// Gray with 0.5 ≤ L < 0.75.
+    Gray,
+    /// Gray with 0.75 ≤ L < 0.95.
+    LightGray,
+    /// Gray with L ≥ 0.95.
+    White,
+    /// Hue 12°–40°, L < 0.75.
+    Red,
+    /// Hue 40°–75°, L ≥ 0.55.
+    Orange,
+    /// Hue 75°–118°, L ≥ 0.65.
+    Yellow,
+    /// Hue 118°–138°, L ≥ 0.55.
+    Lime,
+    /// Hue 138°–165°.
+    Green,
+    /// Hue 165°–215°, L < 0.75.
+    Teal,
+    /// Hue 165°–215°, L ≥ 0.75.
+    Cyan,
+    /// Hue 215°–245°.
+    Azure,
+    /// Hue 245°–282°.
+    Blue,
+    /// Hue 282°–305°.
+    Violet,
+    /// Hue 305°–322°, or 322°–345° with L < 0.5.
+    Purple,
+    /// Hue 322°–345°, L ≥ 0.5.
+    Magenta,
+    /// Hue 345°–12°, L < 0.75.
+    Rose,
+    /// Hue 345°–40°, L ≥ 0.75.
+    Pink,
+    /// Hue 40°–75°, L < 0.55.
+    Brown,
+    /// Hue 75°–118°, L < 0.65.
+    Olive,
+    /// Hue 118°–138°, L < 0.55.
+    OliveGreen,
+}
+
+impl BaseName {
+    /// Number of base names.
+    pub const COUNT: usize = 22;
+
+    /// Every base name, grays first, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = [
+        Self::Black,
+        Self::DarkGray,
+        Self::Gray,
+        Self::LightGray,
+        Self::White,
+        Self::Red,
+        Self::Orange,
+        Self::Yellow,
+        Self::Lime,
+        Self::Green,
+        Self::Teal,
+        Self::Cyan,
+        Self::Azure,
+        Self::Blue,
+        Self::Violet,
+        Self::Purple,
+        Self::Magenta,
+        Self::Rose,
+        Self::Pink,
+        Self::Brown,
+        Self::Olive,
+        Self::OliveGreen,
+    ];
+
+    /// Whether this is a gray level rather than a hue.
+    #[must_use]
+    pub const fn is_neutral(self) -> bool {
+        matches!(
+            self,
+            Self::Black | Self::DarkGray | Self::Gray | Self::LightGray | Self::White
+        )
+    }
+
+    /// Gray level for OKLCH lightness `l`.
+    #[must_use]
+    pub fn neutral(l: f64) -> Self {
+        if l < 0.25 {
+            Self::Black
+        } else if l < 0.5 {
+            Self::DarkGray
+        } else if l < 0.75 {
+            Self::Gray
+        } else if l < 0.95 {
+            Self::LightGray
+        } else {
+            Self::White
+        }
+    }
+
+    /// Hue family for OKLCH lightness `l` and hue `h` in degrees.
+    #[must_use]
+    pub fn hue(l: f64, h: f64) -> Self {
+        let h = normalize_hue(h);
+        if (12.0..40.0).contains(&h) {
+            if l >= 0.75 {
+                Self::Pink
+            } else {
+                Self::Red
+            }
+        } else if (40.0..75.0).contains(&h) {
+            if l < 0.55 {
+                Self::Brown
+            } else {
+                Self::Orange
+            }
+        } else if (75.0..118.0).contains(&h) {
+            if l < 0.65 {
+                Self::Olive
+            } else {
+                Self::Yellow
+            }
+        } else if (118.0..138.0).contains(&h) {
+            if l < 0.55 {
+                Self::OliveGreen
+            } else {
+                Self::Lime
+            }
+        } else if (138.0..165.0).contains(&h) {
+            Self::Green
+        } else if (165.0..215.0).contains(&h) {
+            if l >= 0.75 {
+                Self::Cyan
+            } else {
+                Self::Teal
+            }
+        } else if (215.0..245.0).contains(&h) {
+            Self::Azure
+        } else if (245.0..282.0).contains(&h) {
+            Self::Blue
+        } else if (282.0..305.0).contains(&h) {
+            Self::Violet
+        } else if (305.0..322.0).contains(&h) {
+            Self::Purple
+        } else if (322.0..345.0).contains(&h) {
+            if l < 0.5 {
+                Self::Purple
+            } else {
+                Self::Magenta
+            }
+        } else if l >= 0.75 {
+            Self::Pink
+        } else {
+            Self::Rose
+        }
+    }
+
+    /// Representative OKLCH hue of the family, or `None` for grays.
+    #[must_use]
+    pub const fn reference_hue(self) -> Option<f64> {
+        match self {
+            Self::Black | Self::DarkGray | Self::Gray | Self::LightGray | Self::White => None,
+            Self::Red => Some(29.0),
+            Self::Orange | Self::Brown => Some(55.0),
+            Self::Yellow | Self::Olive => Some(100.0),
+            Self::Lime | Self::OliveGreen => Some(128.0),
+            Self::Green => Some(150.0),
+            Self::Teal | Self::Cyan => Some(195.0),
+            Self::Azure => Some(232.0),
+            Self::Blue => Some(264.0),
+            Self::Violet => Some(294.0),
+            Self::Purple => Some(318.0),
+            Self::Magenta => Some(332.0),
+            Self::Rose => Some(358.0),
+            Self::Pink => Some(5.0),
+        }
+    }
+
+    /// OKLCH lightness range `[low, high)` the family is limited to.
+    #[must_use]
+    pub const fn lightness_range(self) -> (f64, f64) {
+        match self {
+            Self::Black => (0.0, 0.25),
+            Self::DarkGray => (0.25, 0.5),
+            Self::Gray => (0.5, 0.75),
+            Self::LightGray => (0.75, 0.95),
+            Self::White => (0.95, 1.0),
+            Self::Brown | Self::OliveGreen => (0.0, 0.55),
+            Self::Olive => (0.0, 0.65),
+            Self::Orange | Self::Lime => (0.55, 1.0),
+            Self::Yellow => (0.65, 1.0),
+            Self::Pink | Self::Cyan => (0.75, 1.0),
+            Self::Red | Self::Rose | Self::Teal => (0.0, 0.75),
+            _ => (0.0, 1.0),
+        }
+    }
+
+    /// Hue word of systematic names, from the ISCC–NBS vocabulary.
+    fn systematic_hue(self) -> &'static str {
+        match self {
+            Self::Black => "black",
+            Self::DarkGray => "dark gray",
+            Self::Gray => "medium gray",
+            Self::LightGray => "light gray",
+            Self::White => "white",
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Lime => "yellow green",
+            Self::Green => "green",
+            Self::Teal => "bluish green",
+            Self::Cyan => "greenish blue",
+            Self::Azure | Self::Blue => "blue",
+            Self::Violet => "violet",
+            Self::Purple => "purple",
+            Self::Magenta => "reddish purple",
+            Self::Rose => "purplish red",
+            Self::Pink => "pink",
+            Self::Brown => "brown",
+            Self::Olive => "olive",
+            Self::OliveGreen => "olive green",
+        }
+    }
+}
+
+/// Systematic-name modifier for a lightness and chroma term.
+///
+/// [`Chroma::Neutral`] on a hue family (only possible for hand-built
+/// descriptions) takes the grayish modifiers.
+fn systematic_modifier(lightness: Lightness, chroma: Chroma) -> &'static str {
+    const TABLE: [[&str; 4]; 5] = [
+        ["blackish", "very dark", "very deep", "very deep"],
+        ["dark grayish", "dark", "deep", "deep"],
+        ["grayish", "moderate", "strong", "vivid"],
+        ["light grayish", "pale", "light", "brilliant"],
+        ["very pale", "pale", "very light", "brilliant"],
+    ];
+    TABLE[lightness as usize][(chroma as usize).saturating_sub(1)]
+}
+
+fn normalize_hue(h: f64) -> f64 {
+    let h = h % 360.0;
+    if h < 0.0 {
+        h + 360.0
+    } else {
+        h
+    }
+}
+
+/// Largest sRGB chroma at hue `h`, over all lightnesses.
+fn cusp_chroma(h: f64) -> f64 {
+    GamutMapper::new(RgbSpace::Srgb).cusp(h).1
+}
+
+// ============================================================================
+// Description
+// ============================================================================
+
+/// A color described by lightness, chroma and hue terms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorDescription {
+    /// Lightness term.
+    pub lightness: Lightness,
+    /// Chroma term; [`Chroma::Neutral`] for grays.
+    pub chroma: Chroma,
+    /// Gray level or hue family.
+    pub base: BaseName,
+    /// Chroma relative to the sRGB cusp of the hue, in [0, 1].
+    pub relative_chroma: f64,
+}
+
+impl ColorDescription {
+    /// Render the description in `locale`.
+    ///
+    /// Grays are named by level alone. A vivid color omits its lightness
+    /// adjective, since full saturation already fixes how light it is.
+    #[must_use]
+    pub fn text(&self, locale: Locale) -> String {
+        let base = locale.base(self.base);
+        if self.base.is_neutral() {
+            return String::from(base);
+        }
+        let lightness = if self.chroma == Chroma::Vivid {
+            ""
+        } else {
+            locale.lightness(self.lightness)
+        };
+        let chroma = locale.chroma(self.chroma);
+
+        let words = if locale.adjectives_follow() {
+            [base, lightness, chroma]
+        } else {
+            [chroma, lightness, base]
+        };
+        let mut text = String::new();
+        for word in words.iter().filter(|w| !w.is_empty()) {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(word);
+        }
+        text
+    }
+
+    /// Systematic name: modifier and hue in ISCC–NBS words, e.g.
+    /// "moderate olive green".
+    #[must_use]
+    pub fn systematic_name(&self) -> String {
+        let hue = self.base.systematic_hue();
+        if self.base.is_neutral() {
+            return String::from(hue);
+        }
+        let mut name = String::from(systematic_modifier(self.lightness, self.chroma));
+        name.push(' ');
+        name.push_str(hue);
+        name
+    }
+
+    /// A representative color for these terms: the middle of the lightness
+    /// and chroma ranges at the family's reference hue.
+    #[must_use]
+    pub fn reference(&self) -> Color {
+        let (low, high) = self.lightness.range();
+        let (base_low, base_high) = self.base.lightness_range();
+        let l = (low.max(base_low) + high.min(base_high)) * 0.5;
+        match self.base.reference_hue() {
+            None => OKLCH::new(l, 0.0, 0.0).to_color(),
+            Some(h) => {
+                let c = self.chroma.center() * cusp_chroma(h);
+                OKLCH::new(l, c, h).map_to_gamut().to_color()
+            }
+        }
+    }
+}
+
+impl fmt::Display for ColorDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text(Locale::En))
+    }
+}
+
+/// Describe `color` by lightness, chroma and hue.
+///
+/// # Example
+///
+/// ```
+/// use momoto_core::color::naming::{describe, BaseName, Locale};
+/// use momoto_core::color::Color;
+///
+/// let navy = describe(&Color::from_srgb8(0, 0, 128));
+/// assert_eq!(navy.base, BaseName::Blue);
+/// assert_eq!(navy.to_string(), "very dark blue");
+/// assert_eq!(navy.text(Locale::Fr), "bleu très foncé");
+/// ```
+#[must_use]
+pub fn describe(color: &Color) -> ColorDescription {
+    let oklch = OKLCH::from_color(color);
+    let lightness = Lightness::from_l(oklch.l);
+    if oklch.c < NEUTRAL_CHROMA {
+        return ColorDescription {
+            lightness,
+            chroma: Chroma::Neutral,
+            base: BaseName::neutral(oklch.l),
+            relative_chroma: 0.0,
+        };
+    }
+
+    let cusp = cusp_chroma(oklch.h);
+    let relative_chroma = if cusp > 0.0 {
+        (oklch.c / cusp).min(1.0)
+    } else {
+        0.0
+    };
+    ColorDescription {
+        lightness,
+        chroma: Chroma::from_relative(relative_chroma),
+        base: BaseName::hue(oklch.l, oklch.h),
+        relative_chroma,
+    }
+}
+
+// ============================================================================
+// Nearest names
+// ============================================================================
+
+/// A vocabulary of color names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Vocabulary {
+    /// The 148 CSS Color 4 named colors.
+    #[default]
+    Css,
+    /// Systematic modifier + hue names ("moderate olive green") generated
+    /// from OKLCH terms; see the [module docs](self).
+    Systematic,
+    /// Popular names from the xkcd color survey.
+    Xkcd,
+}
+
+impl Vocabulary {
+    /// `(name, 0xRRGGBB)` table, or `None` for the generated systematic
+    /// names.
+    #[must_use]
+    pub const fn table(self) -> Option<&'static [(&'static str, u32)]> {
+        match self {
+            Self::Css => Some(&NAMED_COLORS),
+            Self::Systematic => None,
+            Self::Xkcd => Some(&xkcd::XKCD_COLORS),
+        }
+    }
+}
+
+/// A color name and how far it is from the named color.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorName {
+    /// The name.
+    pub name: String,
+    /// The color the name refers to. For systematic names, the terms'
+    /// [representative color](ColorDescription::reference).
+    pub reference: Color,
+    /// ΔEOK between the queried color and `reference`.
+    pub distance: f64,
+}
+
+fn delta_e_ok(a: &OKLab, b: &OKLab) -> f64 {
+    let (dl, da, db) = (a.l - b.l, a.a - b.a, a.b - b.b);
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+fn hex_color(rgb: u32) -> Color {
+    Color::from_srgb8((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// Closest name to `color` in `vocabulary`.
+///
+/// Ties keep table order, so for CSS synonyms such as `aqua`/`cyan` the
+/// alphabetically first name wins.
+#[must_use]
+pub fn nearest(color: &Color, vocabulary: Vocabulary) -> ColorName {
+    let mut names = nearest_n(color, vocabulary, 1);
+    names.swap_remove(0)
+}
+
+/// The `n` closest names to `color` in `vocabulary`, closest first.
+///
+/// Systematic names are compared through the representative color of each
+/// name; a name shared by several term combinations counts once, at its
+/// closest reference.
+#[must_use]
+pub fn nearest_n(color: &Color, vocabulary: Vocabulary, n: usize) -> Vec<ColorName> {
+    let lab = OKLab::from_color(color);
+    let Some(table) = vocabulary.table() else {
+        let mut scored: Vec<ColorName> = systematic_names()
+            .map(|(name, reference)| ColorName {
+                name,
+                distance: delta_e_ok(&lab, &OKLab::from_color(&reference)),
+                reference,
+            })
+            .collect();
+        scored.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        let mut names: Vec<ColorName> = Vec::with_capacity(n.max(1));
+        for candidate in scored {
+            if names.len() == n.max(1) {
+                break;
+            }
+            if names.iter().all(|c| c.name != candidate.name) {
+                names.push(candidate);
+            }
+        }
+        return names;
+    };
+
+    let mut scored: Vec<(f64, usize)> = table
+        .iter()
+        .enumerate()
+        .map(|(i, &(_, rgb))| (delta_e_ok(&lab, &OKLab::from_color(&hex_color(rgb))), i))
+        .collect();
+    scored.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+    scored
+        .into_iter()
+        .take(n.max(1))
+        .map(|(distance, i)| ColorName {
+            name: String::from(table[i].0),
+            reference: hex_color(table[i].1),
+            distance,
+        })
+        .collect()
+}
+
+/// Every systematic name with its representative color: the grays, then
+/// each hue family at every lightness it spans and every chroma term.
+fn systematic_names() -> impl Iterator<Item = (String, Color)> {
+    BaseName::ALL.into_iter().flat_map(|base| {
+        Lightness::ALL.into_iter().flat_map(move |lightness| {
+            let (low, high) = lightness.range();
+            let (base_low, base_high) = base.lightness_range();
+            let spanned = low.max(base_low) < high.min(base_high);
+            let chromas: &[Chroma] = if !spanned {
+                &[]
+            } else if base.is_neutral() {
+                &[Chroma::Neutral]
+            } else {
+                &Chroma::ALL[1..]
+            };
+            chromas.iter().map(move |&chroma| {
+                let description = ColorDescription {
+                    lightness,
+                    chroma,
+                    base,
+                    relative_chroma: chroma.center(),
+                };
+                (description.systematic_name(), description.reference())
+            })
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color::from_srgb8(r, g, b)
+    }
+
+    #[test]
+    fn test_exact_css_match() {
+        let name = nearest(&rgb(0, 128, 128), Vocabulary::Css);
+        assert_eq!(name.name, "teal");
+        assert!(name.distance < 1e-9);
+
+        // Synonyms resolve alphabetically.
+        assert_eq!(nearest(&rgb(0, 255, 255), Vocabulary::Css).name, "aqua");
+    }
+
+    #[test]
+    fn test_xkcd_nearest() {
+        assert_eq!(
+            nearest(&rgb(0x7E, 0x1E, 0x9C), Vocabulary::Xkcd).name,
+            "purple"
+        );
+        assert_eq!(nearest(&rgb(250, 120, 10), Vocabulary::Xkcd).name, "orange");
+    }
+
+    #[test]
+    fn test_nearest_n_is_sorted() {
+        let names = nearest_n(&rgb(30, 90, 200), Vocabulary::Css, 5);
+        assert_eq!(names.len(), 5);
+        assert!(names.windows(2).all(|w| w[0].distance <= w[1].distance));
+
+        let names = nearest_n(&rgb(30, 90, 200), Vocabulary::Systematic, 5);
+        assert_eq!(names.len(), 5);
+        assert!(names.windows(2).all(|w| w[0].distance <= w[1].distance));
+        for (i, a) in names.iter().enumerate() {
+            assert!(names[i + 1..].iter().all(|b| b.name != a.name), "{names:?}");
+        }
+    }
+
+    #[test]
+    fn test_neutrals() {
+        assert_eq!(describe(&rgb(0, 0, 0)).to_string(), "black");
+        assert_eq!(describe(&rgb(128, 128, 128)).to_string(), "gray");
+        assert_eq!(describe(&rgb(211, 211, 211)).to_string(), "light gray");
+        assert_eq!(describe(&rgb(255, 255, 255)).text(Locale::Fr), "blanc");
+    }
+
+    #[test]
+    fn test_hue_families() {
+        let cases = [
+            ((255, 0, 0), BaseName::Red),
+            ((255, 128, 0), BaseName::Orange),
+            ((255, 255, 0), BaseName::Yellow),
+            ((0, 200, 0), BaseName::Green),
+            ((0, 0, 255), BaseName::Blue),
+            ((128, 0, 255), BaseName::Violet),
+            ((255, 0, 255), BaseName::Magenta),
+            ((255, 192, 203), BaseName::Pink),
+            ((139, 69, 19), BaseName::Brown),
+            ((128, 128, 0), BaseName::Olive),
+            ((85, 107, 47), BaseName::OliveGreen),
+            ((128, 0, 128), BaseName::Purple),
+        ];
+        for ((r, g, b), expected) in cases {
+            assert_eq!(describe(&rgb(r, g, b)).base, expected, "rgb({r}, {g}, {b})");
+        }
+    }
+
+    #[test]
+    fn test_vivid_drops_lightness() {
+        assert_eq!(describe(&rgb(255, 255, 0)).to_string(), "vivid yellow");
+        assert_eq!(describe(&rgb(0, 0, 255)).to_string(), "vivid blue");
+        assert_eq!(describe(&rgb(255, 0, 0)).text(Locale::Es), "rojo vivo");
+    }
+
+    #[test]
+    fn test_systematic_names() {
+        assert_eq!(
+            nearest(&rgb(128, 128, 128), Vocabulary::Systematic).name,
+            "medium gray"
+        );
+        assert_eq!(
+            nearest(&rgb(255, 0, 0), Vocabulary::Systematic).name,
+            "vivid red"
+        );
+        assert_eq!(
+            nearest(&rgb(85, 107, 47), Vocabulary::Systematic).name,
+            "moderate olive green"
+        );
+    }
+
+    #[test]
+    fn test_systematic_name_is_total() {
+        // Public fields allow a neutral chroma on a hue family.
+        let description = ColorDescription {
+            lightness: Lightness::Dark,
+            chroma: Chroma::Neutral,
+            base: BaseName::Red,
+            relative_chroma: 0.0,
+        };
+        assert_eq!(description.systematic_name(), "dark grayish red");
+    }
+
+    #[test]
+    fn test_reference_has_same_description() {
+        for color in [
+            rgb(47, 92, 92),
+            rgb(139, 69, 19),
+            rgb(200, 160, 220),
+            rgb(90, 90, 90),
+        ] {
+            let description = describe(&color);
+            let reference = describe(&description.reference());
+            assert_eq!(reference.base, description.base);
+            assert_eq!(reference.lightness, description.lightness);
+        }
+    }
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("fr-CA"), Some(Locale::Fr));
+        assert_eq!(Locale::from_tag("ES"), Some(Locale::Es));
+        assert_eq!(Locale::from_tag("de"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_color_name_serde_roundtrip() {
+        let name = nearest(&rgb(200, 30, 40), Vocabulary::Css);
+        let json = serde_json::to_string(&name).unwrap();
+        let back: ColorName = serde_json::from_str(&json).unwrap();
+        // serde_json may round the last bit of a float.
+        assert_eq!(back.name, name.name);
+        assert_eq!(back.reference.to_hex(), name.reference.to_hex());
+        assert!((back.distance - name.distance).abs() < 1e-12);
+    }
+}
@@ -33,7 +33,9 @@
 //! println!("Scattering: {}mm", evaluated.scattering_radius_mm);
 //! ```
 
+use crate::math::ops;
 use crate::space::oklch::OKLCH;
+use alloc::string::{String, ToString};
 
 // ============================================================================
 // Core Types
@@ -341,25 +343,30 @@ impl MaterialContext {
 
     /// Compute cosine of viewing angle (for Fresnel calculations)
     pub fn cos_viewing_angle(&self) -> f64 {
-        self.viewing_angle_deg.to_radians().cos().max(0.0)
+        ops::cos(self.viewing_angle_deg.to_radians()).max(0.0)
     }
 
     /// Create hash of context for caching
+    ///
+    /// FNV-1a over the bit patterns of the significant properties, so the
+    /// value is stable across platforms and does not need `std`.
     pub fn compute_hash(&self) -> u64 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut hasher = DefaultHasher::new();
-
-        // Hash significant properties (convert f64 to bits for hashing)
-        self.background.l.to_bits().hash(&mut hasher);
-        self.background.c.to_bits().hash(&mut hasher);
-        self.background.h.to_bits().hash(&mut hasher);
-        self.viewing_angle_deg.to_bits().hash(&mut hasher);
-        self.ambient_light.to_bits().hash(&mut hasher);
-        self.key_light.to_bits().hash(&mut hasher);
-
-        hasher.finish()
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        [
+            self.background.l,
+            self.background.c,
+            self.background.h,
+            self.viewing_angle_deg,
+            self.ambient_light,
+            self.key_light,
+        ]
+        .iter()
+        .flat_map(|v| v.to_bits().to_le_bytes())
+        .fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
     }
 }
 
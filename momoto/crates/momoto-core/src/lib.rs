@@ -15,7 +15,8 @@
 //! This crate provides the foundational types and algorithms:
 //!
 //! - **[`color`]**: Color representations (RGB, sRGB, linear)
-//! - **[`space`]**: Color space transformations (OKLCH, OKLab)
+//! - **[`batch`]**: Slice kernels from packed RGBA8 buffers to OKLab/OKLCH, linear RGB and luminance
+//! - **[`space`]**: Color space transformations (OKLCH, OKLab, HCT, wide-gamut RGB, XYZ, CIELAB, HDR/ICtCp)
 //! - **[`luminance`]**: Luminance calculations and coefficients
 //! - **[`perception`]**: Perceptual primitives and result types
 //! - **[`gamut`]**: sRGB gamut boundary estimation and mapping
@@ -92,11 +93,24 @@
 //!
 //! | Feature | Description |
 //! |---------|-------------|
+//! | `std` (default) | Standard library; also enables [`backend`] and [`render`] |
+//! | `libm` | Float math via `libm` for `no_std` + `alloc` targets |
+//! | `deterministic` | Bit-identical transcendentals on every target (see [`math::ops`]) |
+//! | `simd` | SSE2/NEON lanes for the [`batch`] OKLab/OKLCH and luminance kernels |
 //! | `serde` | Enable serialization support for types |
 //! | `webgpu` | Enable WebGPU backend (stub for Phase 4) |
 //! | `internals` | Expose transformation matrices and internal constants |
 //! | `experimental` | Enable experimental features under development |
 //!
+//! ### `no_std`
+//!
+//! Colors, color spaces (OKLCH, HCT, Lab, …), luminance and the contrast
+//! traits only need `alloc`:
+//!
+//! ```toml
+//! momoto-core = { version = "7", default-features = false, features = ["libm"] }
+//! ```
+//!
 //! ### Using the `internals` Feature
 //!
 //! ```toml
@@ -110,7 +124,7 @@
 //! println!("RGB→LMS matrix: {:?}", RGB_TO_LMS);
 //! ```
 
-// Note: no_std support planned for future release - requires libm for powf/round
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(
     missing_docs,
     missing_debug_implementations,
@@ -118,13 +132,21 @@
     unreachable_pub
 )]
 
+#[cfg(not(any(feature = "std", feature = "libm", feature = "deterministic")))]
+compile_error!("momoto-core requires the `std`, `libm` or `deterministic` feature");
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod backend;
+pub mod batch;
 pub mod color;
 pub mod evaluated;
 pub mod luminance;
 pub mod material;
 pub mod math;
 pub mod perception;
+#[cfg(feature = "std")]
 pub mod render;
 pub mod space;
 pub mod traits;
@@ -133,16 +155,25 @@ pub mod traits;
 // Core Type Re-exports
 // ============================================================================
 
-#[cfg(feature = "webgpu")]
+#[cfg(all(feature = "std", feature = "webgpu"))]
 pub use backend::WebGpuBackend;
-pub use backend::{ColorBackend, CpuBackend, CssBackend};
+#[cfg(feature = "std")]
+pub use backend::{
+    ColorBackend, ComposeBackend, CpuBackend, CssBackend, FlutterBackend, SvgBackend,
+    SwiftUiBackend,
+};
 pub use color::Color;
 pub use evaluated::{Evaluable, EvaluatedMaterial, LinearRgba, MaterialContext};
 pub use luminance::RelativeLuminance;
 pub use material::GlassMaterial;
 pub use perception::{ContrastMetric, PerceptualResult, Polarity};
+#[cfg(feature = "std")]
 pub use render::{BackendCapabilities, ColorSpace, RenderBackend, RenderContext, RenderError};
+pub use space::hdr::{HdrColor, ICtCp};
+pub use space::lab::{Lab, Lch};
 pub use space::oklch::{HuePath, OKLab, OKLCH};
+pub use space::rgb::RgbSpace;
+pub use space::xyz::{ChromaticAdaptation, Illuminant, Xyz};
 
 // ============================================================================
 // Luminance Module - Complete Exposure
@@ -172,24 +203,30 @@ pub use luminance::{relative_luminance_apca, relative_luminance_srgb, soft_clamp
 /// Gamut boundary estimation and mapping utilities.
 ///
 /// Provides functions for estimating sRGB gamut boundaries in OKLCH space
-/// and for mapping out-of-gamut colors back into the displayable range.
+/// and for mapping out-of-gamut colors back into the displayable range
+/// of sRGB, Display P3 or Rec.2020.
 ///
 /// # Example
 ///
 /// ```rust
+/// use momoto_core::gamut::GamutMapper;
 /// use momoto_core::space::oklch::OKLCH;
+/// use momoto_core::space::rgb::RgbSpace;
 ///
 /// let color = OKLCH::new(0.7, 0.15, 180.0);
 /// let max_chroma = color.estimate_max_chroma();
 /// let is_safe = color.is_in_gamut();
+/// let mapped = GamutMapper::new(RgbSpace::DisplayP3).map(&color);
 /// ```
 pub mod gamut {
-    //! sRGB gamut boundary estimation and mapping.
+    //! Gamut boundary estimation and mapping.
     //!
-    //! This module provides tools for working with the sRGB color gamut
+    //! This module provides tools for working with RGB color gamuts
     //! in OKLCH color space, including fast estimation of maximum achievable
-    //! chroma for any lightness/hue combination.
+    //! sRGB chroma for any lightness/hue combination and the CSS Color 4
+    //! gamut-mapping algorithm.
 
+    pub use crate::space::oklch::gamut::{GamutMapResult, GamutMapper, GamutMapping, JND};
     pub use crate::space::oklch::GAMUT_COEFFICIENTS;
 }
 
@@ -4,6 +4,7 @@
 //! according to various standards (sRGB, APCA, etc.).
 
 use crate::color::Color;
+use crate::math::ops;
 
 /// Relative luminance (Y) of a color.
 ///
@@ -129,7 +130,7 @@ pub fn relative_luminance_apca(color: &Color) -> RelativeLuminance {
 #[must_use]
 pub fn soft_clamp(y: RelativeLuminance, threshold: f64, exponent: f64) -> RelativeLuminance {
     let value = if y.0 <= threshold {
-        y.0 + (threshold - y.0).powf(exponent)
+        y.0 + ops::powf(threshold - y.0, exponent)
     } else {
         y.0
     };
@@ -41,7 +41,12 @@
 //! let shininess = custom.shininess();
 //! ```
 
+use crate::math::ops;
 use crate::space::oklch::OKLCH;
+use alloc::string::ToString;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
 
 /// Material properties for glass rendering
 ///
@@ -416,7 +421,7 @@ impl Evaluable for GlassMaterial {
         let fresnel_schlick = f0 + (1.0 - f0) * (1.0 - cos_theta).powi(5);
 
         // Edge intensity is controlled by edge_power parameter
-        let edge_intensity = (1.0 - cos_theta).powf(self.edge_power);
+        let edge_intensity = ops::powf(1.0 - cos_theta, self.edge_power);
 
         // ====================================================================
         // 2. Opacity (Beer-Lambert + Fresnel)
@@ -428,7 +433,7 @@ impl Evaluable for GlassMaterial {
         // Beer-Lambert absorption (simplified)
         // T = exp(-absorption * distance)
         let absorption_coeff = 0.1; // Glass typical absorption
-        let beer_lambert = (-absorption_coeff * self.thickness).exp();
+        let beer_lambert = ops::exp(-absorption_coeff * self.thickness);
 
         // Combine: less opaque when transparent (high beer_lambert) and low Fresnel
         let opacity =
@@ -466,7 +471,7 @@ impl Evaluable for GlassMaterial {
 
         // Specular properties from roughness
         let specular_shininess = self.shininess();
-        let specular_intensity = (1.0 - self.roughness).powf(0.5); // Less rough = more specular
+        let specular_intensity = ops::powf(1.0 - self.roughness, 0.5); // Less rough = more specular
 
         // ====================================================================
         // 5. Subsurface Properties
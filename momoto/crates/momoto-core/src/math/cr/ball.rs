@@ -0,0 +1,830 @@
+//! Exact dyadic numbers and midpoint-radius intervals ("balls") over them.
+//!
+//! [`Dy`] is `±mag · 2^exp` with an arbitrary-precision magnitude; addition,
+//! subtraction and multiplication are exact, division and square root
+//! truncate to a caller-chosen absolute granularity `2^-w`. [`Mag`] is a
+//! 33-bit upper bound used for error radii, and [`Ball`] pairs the two so
+//! that every kernel carries a rigorous bound on its own error.
+
+use super::Arith;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+// ============================================================================
+// Magnitudes (little-endian u64 limbs, no high zero limbs)
+// ============================================================================
+
+fn trim(v: &mut Vec<u64>) {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+}
+
+fn bit_len(v: &[u64]) -> i64 {
+    match v.last() {
+        None => 0,
+        Some(&top) => 64 * (v.len() as i64 - 1) + i64::from(64 - top.leading_zeros()),
+    }
+}
+
+fn cmp_mag(a: &[u64], b: &[u64]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(a.len() + 1);
+    let mut carry = false;
+    for (i, &x) in a.iter().enumerate() {
+        let (s, c1) = x.overflowing_add(b.get(i).copied().unwrap_or(0));
+        let (s, c2) = s.overflowing_add(u64::from(carry));
+        out.push(s);
+        carry = c1 || c2;
+    }
+    if carry {
+        out.push(1);
+    }
+    out
+}
+
+/// `a - b`, requires `a >= b`.
+fn sub_mag(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = false;
+    for (i, &x) in a.iter().enumerate() {
+        let (d, b1) = x.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (d, b2) = d.overflowing_sub(u64::from(borrow));
+        out.push(d);
+        borrow = b1 || b2;
+    }
+    trim(&mut out);
+    out
+}
+
+fn shl_mag(a: &[u64], n: i64) -> Vec<u64> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let (limbs, bits) = ((n / 64) as usize, (n % 64) as u32);
+    let mut out = vec![0; limbs];
+    if bits == 0 {
+        out.extend_from_slice(a);
+    } else {
+        let mut carry = 0;
+        for &x in a {
+            out.push((x << bits) | carry);
+            carry = x >> (64 - bits);
+        }
+        out.push(carry);
+    }
+    trim(&mut out);
+    out
+}
+
+/// `floor(a / 2^n)`.
+fn shr_mag(a: &[u64], n: i64) -> Vec<u64> {
+    let (limbs, bits) = ((n / 64) as usize, (n % 64) as u32);
+    if limbs >= a.len() {
+        return Vec::new();
+    }
+    let a = &a[limbs..];
+    let mut out: Vec<u64> = if bits == 0 {
+        a.to_vec()
+    } else {
+        (0..a.len())
+            .map(|i| (a[i] >> bits) | a.get(i + 1).map_or(0, |&hi| hi << (64 - bits)))
+            .collect()
+    };
+    trim(&mut out);
+    out
+}
+
+/// Whether any of the low `n` bits of `a` are set.
+fn low_bits_set(a: &[u64], n: i64) -> bool {
+    let (limbs, bits) = ((n / 64) as usize, (n % 64) as u32);
+    a.iter().take(limbs).any(|&x| x != 0)
+        || (bits > 0 && a.get(limbs).is_some_and(|&x| x & ((1 << bits) - 1) != 0))
+}
+
+fn bit(a: &[u64], n: i64) -> bool {
+    a.get((n / 64) as usize)
+        .is_some_and(|&x| (x >> (n % 64)) & 1 == 1)
+}
+
+fn mul_mag(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = u128::from(x) * u128::from(y) + u128::from(out[i + j]) + carry;
+            out[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        out[i + b.len()] = carry as u64;
+    }
+    trim(&mut out);
+    out
+}
+
+/// `floor(a / d)` for a single-limb divisor.
+fn div_small_mag(a: &[u64], d: u64) -> Vec<u64> {
+    let mut out = vec![0u64; a.len()];
+    let mut rem = 0u128;
+    for i in (0..a.len()).rev() {
+        let cur = (rem << 64) | u128::from(a[i]);
+        out[i] = (cur / u128::from(d)) as u64;
+        rem = cur % u128::from(d);
+    }
+    trim(&mut out);
+    out
+}
+
+/// `floor(a / b)`, `b != 0` (Knuth, TAOCP vol. 2, algorithm 4.3.1 D).
+fn div_mag(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if cmp_mag(a, b) == Ordering::Less {
+        return Vec::new();
+    }
+    if b.len() == 1 {
+        return div_small_mag(a, b[0]);
+    }
+    // Normalize so the divisor's top limb has its high bit set.
+    let shift = i64::from(b[b.len() - 1].leading_zeros());
+    let v = shl_mag(b, shift);
+    let mut u = shl_mag(a, shift);
+    u.resize(a.len() + 1, 0);
+    let n = v.len();
+    let (v_top, v_next) = (u128::from(v[n - 1]), u128::from(v[n - 2]));
+    let mut q = vec![0u64; u.len() - n];
+    for j in (0..q.len()).rev() {
+        let num = (u128::from(u[j + n]) << 64) | u128::from(u[j + n - 1]);
+        let mut qhat = num / v_top;
+        let mut rhat = num % v_top;
+        while qhat >> 64 != 0 || qhat * v_next > ((rhat << 64) | u128::from(u[j + n - 2])) {
+            qhat -= 1;
+            rhat += v_top;
+            if rhat >> 64 != 0 {
+                break;
+            }
+        }
+        // u[j..=j+n] -= qhat · v
+        let mut borrow = 0i128;
+        let mut carry = 0u128;
+        for i in 0..n {
+            let p = qhat * u128::from(v[i]) + carry;
+            carry = p >> 64;
+            let t = i128::from(u[i + j]) - i128::from(p as u64) + borrow;
+            u[i + j] = t as u64;
+            borrow = t >> 64;
+        }
+        let t = i128::from(u[j + n]) - carry as i128 + borrow;
+        u[j + n] = t as u64;
+        if t < 0 {
+            // qhat was one too large: add v back.
+            qhat -= 1;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let s = u128::from(u[i + j]) + u128::from(v[i]) + carry;
+                u[i + j] = s as u64;
+                carry = s >> 64;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u64);
+        }
+        q[j] = qhat as u64;
+    }
+    trim(&mut q);
+    q
+}
+
+/// `floor(sqrt(a))` by Newton's iteration from a float estimate above it.
+fn isqrt_mag(a: &[u64]) -> Vec<u64> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    // sqrt(a) <= sqrt(top + 1) · 2^(drop/2) with top = a >> drop < 2^104.
+    let drop = (bit_len(a) - 104).max(0) & !1;
+    let top = shr_mag(a, drop);
+    let top = u128::from(top[0]) | (u128::from(top.get(1).copied().unwrap_or(0)) << 64);
+    let est = libm::sqrt(top as f64) * (1.0 + 1e-12) + 2.0;
+    let est = est as u128;
+    let mut x = shl_mag(&[est as u64, (est >> 64) as u64], drop / 2);
+    loop {
+        let y = shr_mag(&add_mag(&x, &div_mag(a, &x)), 1);
+        if cmp_mag(&y, &x) != Ordering::Less {
+            return x;
+        }
+        x = y;
+    }
+}
+
+// ============================================================================
+// Dyadic numbers
+// ============================================================================
+
+/// An exact dyadic rational `±mag · 2^exp`.
+#[derive(Clone, Debug)]
+pub(super) struct Dy {
+    neg: bool,
+    mag: Vec<u64>,
+    exp: i64,
+}
+
+impl Dy {
+    pub(super) fn zero() -> Self {
+        Self {
+            neg: false,
+            mag: Vec::new(),
+            exp: 0,
+        }
+    }
+
+    pub(super) fn from_i64(v: i64) -> Self {
+        Self::from_parts(v < 0, vec![v.unsigned_abs()], 0)
+    }
+
+    /// `2^e`.
+    pub(super) fn pow2(e: i64) -> Self {
+        Self::from_parts(false, vec![1], e)
+    }
+
+    /// The exact value of a finite `x`.
+    pub(super) fn from_f64(x: f64) -> Self {
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let frac = bits & ((1 << 52) - 1);
+        let (mant, exp) = if biased == 0 {
+            (frac, -1074)
+        } else {
+            (frac | (1 << 52), biased - 1075)
+        };
+        Self::from_parts(x.is_sign_negative(), vec![mant], exp)
+    }
+
+    /// `floor(v · 2^frac_bits) · 2^-frac_bits` for a constant stored as
+    /// `floor(v · 2^frac_bits)`, truncated to granularity `2^-w`.
+    pub(super) fn from_table(limbs: &[u64], frac_bits: i64, w: i64) -> Self {
+        let drop = (frac_bits - w).max(0);
+        Self::from_parts(false, shr_mag(limbs, drop), drop - frac_bits)
+    }
+
+    pub(super) fn from_parts(neg: bool, mut mag: Vec<u64>, exp: i64) -> Self {
+        trim(&mut mag);
+        Self {
+            neg: neg && !mag.is_empty(),
+            mag,
+            exp,
+        }
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub(super) fn is_neg(&self) -> bool {
+        self.neg
+    }
+
+    /// The leading bits of `|self|` as `man · 2^exp` with the top bit of
+    /// `man` set, and whether any bits below them were dropped.
+    pub(super) fn leading_u128(&self) -> (u128, i64, bool) {
+        if self.is_zero() {
+            return (0, 0, false);
+        }
+        let bits = bit_len(&self.mag);
+        let (top, lost) = if bits <= 128 {
+            (shl_mag(&self.mag, 128 - bits), false)
+        } else {
+            (
+                shr_mag(&self.mag, bits - 128),
+                low_bits_set(&self.mag, bits - 128),
+            )
+        };
+        let man = u128::from(top[0]) | (u128::from(top[1]) << 64);
+        (man, self.exp + bits - 128, lost)
+    }
+
+    /// `floor(log2 |self|)`; meaningless for zero.
+    pub(super) fn exponent(&self) -> i64 {
+        bit_len(&self.mag) - 1 + self.exp
+    }
+
+    pub(super) fn neg(mut self) -> Self {
+        self.neg = !self.neg && !self.is_zero();
+        self
+    }
+
+    pub(super) fn mul_pow2(mut self, k: i64) -> Self {
+        self.exp += k;
+        self
+    }
+
+    pub(super) fn add(&self, other: &Self) -> Self {
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+        let exp = self.exp.min(other.exp);
+        let a = shl_mag(&self.mag, self.exp - exp);
+        let b = shl_mag(&other.mag, other.exp - exp);
+        if self.neg == other.neg {
+            return Self::from_parts(self.neg, add_mag(&a, &b), exp);
+        }
+        match cmp_mag(&a, &b) {
+            Ordering::Less => Self::from_parts(other.neg, sub_mag(&b, &a), exp),
+            _ => Self::from_parts(self.neg, sub_mag(&a, &b), exp),
+        }
+    }
+
+    pub(super) fn sub(&self, other: &Self) -> Self {
+        self.add(&other.clone().neg())
+    }
+
+    pub(super) fn mul(&self, other: &Self) -> Self {
+        Self::from_parts(
+            self.neg != other.neg,
+            mul_mag(&self.mag, &other.mag),
+            self.exp + other.exp,
+        )
+    }
+
+    /// `|self|` compared with `|other|`.
+    #[cfg(test)]
+    pub(super) fn cmp_abs(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+        let by_exponent = self.exponent().cmp(&other.exponent());
+        if by_exponent != Ordering::Equal {
+            return by_exponent;
+        }
+        let exp = self.exp.min(other.exp);
+        cmp_mag(
+            &shl_mag(&self.mag, self.exp - exp),
+            &shl_mag(&other.mag, other.exp - exp),
+        )
+    }
+
+    /// Truncates toward zero to a multiple of `2^-w`.
+    pub(super) fn trunc(self, w: i64) -> Self {
+        if self.exp >= -w {
+            return self;
+        }
+        Self::from_parts(self.neg, shr_mag(&self.mag, -w - self.exp), -w)
+    }
+
+    /// `self / other` truncated toward zero to a multiple of `2^-w`.
+    pub(super) fn div(&self, other: &Self, w: i64) -> Self {
+        let shift = self.exp - other.exp + w;
+        let q = if shift >= 0 {
+            div_mag(&shl_mag(&self.mag, shift), &other.mag)
+        } else {
+            div_mag(&self.mag, &shl_mag(&other.mag, -shift))
+        };
+        Self::from_parts(self.neg != other.neg, q, -w)
+    }
+
+    /// `self / d` truncated toward zero to a multiple of `2^-w`.
+    pub(super) fn div_small(&self, d: u64, w: i64) -> Self {
+        let shift = self.exp + w;
+        let q = if shift >= 0 {
+            div_small_mag(&shl_mag(&self.mag, shift), d)
+        } else {
+            div_small_mag(&shr_mag(&self.mag, -shift), d)
+        };
+        Self::from_parts(self.neg, q, -w)
+    }
+
+    /// `sqrt(|self|)` truncated to a multiple of `2^-w`.
+    pub(super) fn sqrt(&self, w: i64) -> Self {
+        let shift = self.exp + 2 * w;
+        let r = if shift >= 0 {
+            isqrt_mag(&shl_mag(&self.mag, shift))
+        } else {
+            isqrt_mag(&shr_mag(&self.mag, -shift))
+        };
+        Self::from_parts(false, r, -w)
+    }
+
+    /// The nearest integer, ties away from zero.
+    pub(super) fn round_int(&self) -> Self {
+        if self.exp >= 0 {
+            return self.clone();
+        }
+        let half = shl_mag(&[1], -self.exp - 1);
+        Self::from_parts(self.neg, shr_mag(&add_mag(&self.mag, &half), -self.exp), 0)
+    }
+
+    /// `self mod 4` for an integer-valued `self`.
+    pub(super) fn mod4(&self) -> u8 {
+        if self.is_zero() || self.exp >= 2 {
+            return 0;
+        }
+        let low = (self.mag[0] << self.exp) & 3;
+        let low = if self.neg { (4 - low) & 3 } else { low };
+        low as u8
+    }
+
+    /// Rounds to the nearest `f64`, ties to even, with gradual underflow
+    /// and overflow to infinity.
+    pub(super) fn to_f64(&self) -> f64 {
+        let sign = if self.neg { -1.0 } else { 1.0 };
+        if self.is_zero() {
+            return 0.0;
+        }
+        let top = self.exponent();
+        if top > 1023 {
+            return sign * f64::INFINITY;
+        }
+        let lsb = (top - 52).max(-1074);
+        let shift = lsb - self.exp;
+        let mut m = if shift <= 0 {
+            shl_mag(&self.mag, -shift).first().copied().unwrap_or(0)
+        } else {
+            let m = shr_mag(&self.mag, shift).first().copied().unwrap_or(0);
+            let half = bit(&self.mag, shift - 1);
+            let sticky = low_bits_set(&self.mag, shift - 1);
+            m + u64::from(half && (sticky || m & 1 == 1))
+        };
+        let mut lsb = lsb;
+        if m == 1 << 53 {
+            m >>= 1;
+            lsb += 1;
+        }
+        if lsb + 52 > 1023 && m >= 1 << 52 {
+            return sign * f64::INFINITY;
+        }
+        sign * (m as f64) * pow2_f64(lsb)
+    }
+}
+
+/// `2^e` for `-1074 <= e <= 1023`.
+pub(super) fn pow2_f64(e: i64) -> f64 {
+    if e >= -1022 {
+        f64::from_bits(((e + 1023) as u64) << 52)
+    } else {
+        f64::from_bits(1 << (e + 1074))
+    }
+}
+
+// ============================================================================
+// Error radii
+// ============================================================================
+
+/// An upper bound `man · 2^exp`; non-zero mantissas are kept in
+/// `[2^32, 2^33]` so that lower bounds stay tight.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Mag {
+    man: u64,
+    exp: i64,
+}
+
+impl Mag {
+    pub(super) const ZERO: Self = Self { man: 0, exp: 0 };
+
+    /// A radius that no rounding test can pass; marks a failed operation.
+    pub(super) const HUGE: Self = Self {
+        man: 1,
+        exp: 1 << 40,
+    };
+
+    pub(super) fn pow2(e: i64) -> Self {
+        Self { man: 1, exp: e }
+    }
+
+    fn norm(man: u128, exp: i64) -> Self {
+        if man == 0 {
+            return Self::ZERO;
+        }
+        let bits = 128 - i64::from(man.leading_zeros());
+        if bits <= 33 {
+            return Self {
+                man: (man as u64) << (33 - bits),
+                exp: exp - (33 - bits),
+            };
+        }
+        let shift = bits - 33;
+        let lost = man & ((1 << shift) - 1) != 0;
+        Self {
+            man: (man >> shift) as u64 + u64::from(lost),
+            exp: exp + shift,
+        }
+    }
+
+    /// A lower bound on `man · 2^exp`.
+    pub(super) fn lower_u128(man: u128, exp: i64) -> Self {
+        let bits = 128 - i64::from(man.leading_zeros());
+        if bits <= 33 {
+            return Self::norm(man, exp);
+        }
+        Self {
+            man: (man >> (bits - 33)) as u64,
+            exp: exp + bits - 33,
+        }
+    }
+
+    /// An upper bound on `|d|`.
+    pub(super) fn upper(d: &Dy) -> Self {
+        let bits = bit_len(&d.mag);
+        if bits <= 33 {
+            return Self::norm(u128::from(d.mag.first().copied().unwrap_or(0)), d.exp);
+        }
+        let top = shr_mag(&d.mag, bits - 33)[0];
+        Self::norm(u128::from(top) + 1, d.exp + bits - 33)
+    }
+
+    /// A lower bound on `|d|`.
+    fn lower(d: &Dy) -> Self {
+        let bits = bit_len(&d.mag);
+        if bits <= 33 {
+            return Self::norm(u128::from(d.mag.first().copied().unwrap_or(0)), d.exp);
+        }
+        Self {
+            man: shr_mag(&d.mag, bits - 33)[0],
+            exp: d.exp + bits - 33,
+        }
+    }
+
+    /// A lower bound on `self · (1 - 2^-19)`.
+    fn shrink(self) -> Self {
+        Self {
+            man: (self.man - (self.man >> 19)).saturating_sub(1),
+            exp: self.exp,
+        }
+    }
+
+    pub(super) fn is_zero(self) -> bool {
+        self.man == 0
+    }
+
+    pub(super) fn add(self, other: Self) -> Self {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let (hi, lo) = if self.exp >= other.exp {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let d = hi.exp - lo.exp;
+        let lo_man = if d >= 64 {
+            1
+        } else {
+            (lo.man >> d) + u64::from(lo.man & ((1 << d) - 1) != 0)
+        };
+        Self::norm(u128::from(hi.man) + u128::from(lo_man), hi.exp)
+    }
+
+    pub(super) fn mul(self, other: Self) -> Self {
+        Self::norm(
+            u128::from(self.man) * u128::from(other.man),
+            self.exp + other.exp,
+        )
+    }
+
+    /// `self / other` rounded up, `other` being a lower bound.
+    pub(super) fn div(self, other: Self) -> Self {
+        if other.is_zero() {
+            return Self::HUGE;
+        }
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+        // Mantissas stay below 2^34, so the quotient keeps 29 bits.
+        let shift = self.man.leading_zeros();
+        let n = self.man << shift;
+        let q = n / other.man + u64::from(n % other.man != 0);
+        Self::norm(u128::from(q), self.exp - other.exp - i64::from(shift))
+    }
+
+    pub(super) fn mul_pow2(self, k: i64) -> Self {
+        Self {
+            man: self.man,
+            exp: self.exp + k,
+        }
+    }
+
+    fn le(self, other: Self) -> bool {
+        if self.is_zero() {
+            return true;
+        }
+        if other.is_zero() {
+            return false;
+        }
+        let top = |m: Self| 64 - i64::from(m.man.leading_zeros()) + m.exp;
+        match top(self).cmp(&top(other)) {
+            Ordering::Less => true,
+            Ordering::Greater => false,
+            Ordering::Equal => {
+                let e = self.exp.min(other.exp);
+                (u128::from(self.man) << (self.exp - e))
+                    <= (u128::from(other.man) << (other.exp - e))
+            }
+        }
+    }
+
+    /// Whether the bound is below `2^e`.
+    pub(super) fn lt_pow2(self, e: i64) -> bool {
+        self.is_zero() || 64 - i64::from(self.man.leading_zeros()) + self.exp <= e
+    }
+
+    /// The radius of `a / b` before the quotient itself is rounded, given
+    /// an upper bound `a` on the dividend, a lower bound `b` on the divisor
+    /// and their radii; `None` unless `rb` is small against `b`.
+    pub(super) fn quotient(a: Self, ra: Self, b: Self, rb: Self) -> Option<Self> {
+        if !rb.mul_pow2(20).le(b) {
+            return None;
+        }
+        Some(ra.add(a.div(b).mul(rb)).div(b.shrink()))
+    }
+
+    /// The radius of a square root before it is rounded, given the radius
+    /// `ra` and a lower bound `a` of the radicand and a lower bound `root`
+    /// of its root; `None` unless `ra` is small against `a`.
+    pub(super) fn root(ra: Self, a: Self, root: Self) -> Option<Self> {
+        if !ra.mul_pow2(20).le(a) {
+            return None;
+        }
+        Some(if ra.is_zero() {
+            Self::ZERO
+        } else {
+            ra.div(root.shrink())
+        })
+    }
+
+    pub(super) fn to_dy(self) -> Dy {
+        Dy::from_parts(false, vec![self.man], self.exp)
+    }
+}
+
+// ============================================================================
+// Balls
+// ============================================================================
+
+/// The interval `mid ± rad`, which always contains the exact value.
+#[derive(Clone, Debug)]
+pub(super) struct Ball {
+    pub(super) mid: Dy,
+    pub(super) rad: Mag,
+}
+
+impl Ball {
+    pub(super) fn exact(mid: Dy) -> Self {
+        Self {
+            mid,
+            rad: Mag::ZERO,
+        }
+    }
+
+    /// An upper bound on every value in the ball.
+    fn upper(&self) -> Mag {
+        Mag::upper(&self.mid).add(self.rad)
+    }
+}
+
+impl Arith for Ball {
+    fn from_dy(d: &Dy) -> Self {
+        Self::exact(d.clone())
+    }
+
+    fn from_f64(x: f64) -> Self {
+        Self::exact(Dy::from_f64(x))
+    }
+
+    fn from_table(limbs: &[u64], frac_bits: i64, w: i64) -> Self {
+        Self {
+            mid: Dy::from_table(limbs, frac_bits, w),
+            rad: Mag::pow2(-w),
+        }
+    }
+
+    fn failed() -> Self {
+        Self {
+            mid: Dy::zero(),
+            rad: Mag::HUGE,
+        }
+    }
+
+    fn mid(&self) -> Dy {
+        self.mid.clone()
+    }
+
+    fn approx(&self) -> f64 {
+        self.mid.to_f64()
+    }
+
+    fn bounds(&self) -> Option<(f64, f64)> {
+        if !self.rad.lt_pow2(1100) {
+            return None;
+        }
+        let rad = self.rad.to_dy();
+        Some((self.mid.sub(&rad).to_f64(), self.mid.add(&rad).to_f64()))
+    }
+
+    fn negligible(&self, w: i64) -> bool {
+        Mag::upper(&self.mid).lt_pow2(-w)
+    }
+
+    fn lt_pow2(&self, e: i64) -> bool {
+        self.upper().lt_pow2(e)
+    }
+
+    fn absorb(self, other: &Self, k: i64) -> Self {
+        Self {
+            mid: self.mid,
+            rad: self.rad.add(other.upper().mul_pow2(k)),
+        }
+    }
+
+    fn neg(self) -> Self {
+        Self {
+            mid: self.mid.neg(),
+            rad: self.rad,
+        }
+    }
+
+    fn mul_pow2(self, k: i64) -> Self {
+        Self {
+            mid: self.mid.mul_pow2(k),
+            rad: self.rad.mul_pow2(k),
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self {
+            mid: self.mid.add(&other.mid),
+            rad: self.rad.add(other.rad),
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Self {
+            mid: self.mid.sub(&other.mid),
+            rad: self.rad.add(other.rad),
+        }
+    }
+
+    fn mul(&self, other: &Self, w: i64) -> Self {
+        let rad = Mag::upper(&self.mid)
+            .mul(other.rad)
+            .add(Mag::upper(&other.mid).mul(self.rad))
+            .add(self.rad.mul(other.rad))
+            .add(Mag::pow2(-w));
+        Self {
+            mid: self.mid.mul(&other.mid).trunc(w),
+            rad,
+        }
+    }
+
+    fn div_small(&self, d: u64, w: i64) -> Self {
+        Self {
+            mid: self.mid.div_small(d, w),
+            rad: self
+                .rad
+                .div(Mag::lower_u128(u128::from(d), 0))
+                .add(Mag::pow2(-w)),
+        }
+    }
+
+    fn div(&self, other: &Self, w: i64) -> Self {
+        let rad = Mag::quotient(
+            Mag::upper(&self.mid),
+            self.rad,
+            Mag::lower(&other.mid),
+            other.rad,
+        );
+        match rad {
+            Some(rad) => Self {
+                mid: self.mid.div(&other.mid, w),
+                rad: rad.add(Mag::pow2(-w)),
+            },
+            None => Self::failed(),
+        }
+    }
+
+    fn sqrt(&self, w: i64) -> Self {
+        if self.mid.is_neg() {
+            return Self::failed();
+        }
+        let mid = self.mid.sqrt(w);
+        match Mag::root(self.rad, Mag::lower(&self.mid), Mag::lower(&mid)) {
+            Some(rad) => Self {
+                mid,
+                rad: rad.add(Mag::pow2(-w)),
+            },
+            None => Self::failed(),
+        }
+    }
+}
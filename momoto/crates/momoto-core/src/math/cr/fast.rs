@@ -0,0 +1,279 @@
+//! A double-double first attempt at every evaluation.
+//!
+//! [`Fast`] is a ball whose midpoint is an unevaluated sum `hi + lo` of two
+//! `f64`s (about 106 bits) and whose radius is an `f64` rounded upward, so
+//! the common operations are a handful of float instructions. Each
+//! operation adds its own rounding error to the radius, using the relative
+//! bounds of Joldes, Muller and Popescu, "Tight and rigorous error bounds
+//! for basic building blocks of double-word arithmetic" (ACM TOMS 44,
+//! 2017) with a generous margin; an absolute `2^-1060` covers underflow.
+//! Overflow turns the midpoint or the radius into an infinity or a NaN,
+//! which [`bounds`](Arith::bounds) rejects, and [`ziv`](super::ziv) moves on
+//! to [`Ball`](super::ball::Ball).
+
+use super::ball::{pow2_f64, Dy};
+use super::Arith;
+
+/// Relative error of a double-double sum (3u² in the paper).
+const EPS_ADD: f64 = 4.0 * f64::EPSILON * f64::EPSILON;
+
+/// Relative error of a double-double product (about 6u² here).
+const EPS_MUL: f64 = 16.0 * f64::EPSILON * f64::EPSILON;
+
+/// Relative error of a double-double quotient or square root.
+const EPS_DIV: f64 = 64.0 * f64::EPSILON * f64::EPSILON;
+
+/// `1 + 2^-48`: covers the rounding of the few operations that compute a
+/// radius.
+const UP: f64 = 1.0 + 16.0 * f64::EPSILON;
+
+/// `2^-1060`: covers every rounding error of an underflowing operation.
+const TINY_RAD: f64 = f64::MIN_POSITIVE / 274_877_906_944.0;
+
+/// `2^20`: a divisor or radicand must exceed its radius this many times.
+const MARGIN: f64 = 1_048_576.0;
+
+/// The interval `hi + lo ± rad`, with `|lo|` at most half an ulp of `hi`.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Fast {
+    hi: f64,
+    lo: f64,
+    rad: f64,
+}
+
+/// An upper bound on a radius computed in a few roundings to nearest.
+fn up(r: f64) -> f64 {
+    r * UP + TINY_RAD
+}
+
+/// `a + b` as `(sum, error)`, exactly.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// `a + b` as `(sum, error)`, exactly, for `|a| >= |b|` or `a == 0`.
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// `a` as two halves of at most 26 significant bits each (Veltkamp).
+fn split(a: f64) -> (f64, f64) {
+    let c = 134_217_729.0 * a;
+    let h = c - (c - a);
+    (h, a - h)
+}
+
+/// `a · b` as `(product, error)`, exactly unless it underflows (Dekker).
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    (p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+}
+
+/// `v · 2^k`, exactly while it stays a normal number.
+fn scale(v: f64, k: i64) -> f64 {
+    let k = k.clamp(-2000, 2000);
+    v * pow2_f64(k / 2) * pow2_f64(k - k / 2)
+}
+
+impl Fast {
+    /// The leading 106 bits of `±man · 2^exp` for a `man` with its top bit
+    /// set, the rest going into the radius.
+    fn from_u128(neg: bool, man: u128, exp: i64, lost: bool) -> Self {
+        if man == 0 {
+            return Self::from_f64(0.0);
+        }
+        if exp + 22 < -1022 || exp + 128 > 1023 {
+            return Self::failed();
+        }
+        let sign = if neg { -1.0 } else { 1.0 };
+        let hi = sign * ((man >> 75) as f64) * pow2_f64(exp + 75);
+        let lo = sign * (((man >> 22) & ((1 << 53) - 1)) as f64) * pow2_f64(exp + 22);
+        let dropped = lost || man & ((1 << 22) - 1) != 0;
+        let rad = if dropped { pow2_f64(exp + 22) } else { 0.0 };
+        let (hi, lo) = fast_two_sum(hi, lo);
+        Self { hi, lo, rad }
+    }
+
+    /// An upper bound on `|hi + lo|`.
+    fn magnitude(&self) -> f64 {
+        self.hi.abs() + self.lo.abs()
+    }
+}
+
+impl Arith for Fast {
+    fn from_dy(d: &Dy) -> Self {
+        let (man, exp, lost) = d.leading_u128();
+        Self::from_u128(d.is_neg(), man, exp, lost)
+    }
+
+    fn from_f64(x: f64) -> Self {
+        Self {
+            hi: x,
+            lo: 0.0,
+            rad: 0.0,
+        }
+    }
+
+    fn from_table(limbs: &[u64], frac_bits: i64, _w: i64) -> Self {
+        let n = limbs.len();
+        let man = (u128::from(limbs[n - 1]) << 64) | u128::from(limbs[n - 2]);
+        let shift = man.leading_zeros();
+        let exp = 64 * (n as i64 - 2) - frac_bits - i64::from(shift);
+        Self::from_u128(false, man << shift, exp, true)
+    }
+
+    fn failed() -> Self {
+        Self {
+            hi: 0.0,
+            lo: 0.0,
+            rad: f64::INFINITY,
+        }
+    }
+
+    fn from_i64(v: i64) -> Self {
+        debug_assert!(v.unsigned_abs() < 1 << 53);
+        Self::from_f64(v as f64)
+    }
+
+    fn one() -> Self {
+        Self::from_f64(1.0)
+    }
+
+    fn mid(&self) -> Dy {
+        Dy::from_f64(self.hi).add(&Dy::from_f64(self.lo))
+    }
+
+    fn approx(&self) -> f64 {
+        self.hi
+    }
+
+    fn bounds(&self) -> Option<(f64, f64)> {
+        if !(self.hi.is_finite() && self.lo.is_finite() && self.rad.is_finite()) {
+            return None;
+        }
+        // Rounding `lo ∓ r` to nearest moves it by less than 2^-53 of its
+        // size, which the widening absorbs; `hi + (lo ∓ r)` is then
+        // rounded once, and rounding is monotonic.
+        let r = up(self.rad + (self.lo.abs() + self.rad) * (4.0 * f64::EPSILON));
+        Some((self.hi + (self.lo - r), self.hi + (self.lo + r)))
+    }
+
+    fn negligible(&self, w: i64) -> bool {
+        if !self.hi.is_finite() {
+            // Stops a series that has diverged; its sum fails to round.
+            return true;
+        }
+        let bits = self.hi.to_bits() & !(1 << 63);
+        let biased = (bits >> 52) as i64;
+        // |hi + lo| < 2^(biased - 1022), and subnormals are below 2^-1022.
+        bits == 0 || biased.max(1) - 1022 <= -w
+    }
+
+    fn lt_pow2(&self, e: i64) -> bool {
+        up(self.magnitude() + self.rad) < pow2_f64(e.clamp(-1022, 1023))
+    }
+
+    fn absorb(self, other: &Self, k: i64) -> Self {
+        Self {
+            rad: up(self.rad + (other.magnitude() + other.rad) * pow2_f64(k)),
+            ..self
+        }
+    }
+
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+            rad: self.rad,
+        }
+    }
+
+    fn mul_pow2(self, k: i64) -> Self {
+        Self {
+            hi: scale(self.hi, k),
+            lo: scale(self.lo, k),
+            rad: up(scale(self.rad, k)),
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        // AccurateDWPlusDW.
+        let (sh, sl) = two_sum(self.hi, other.hi);
+        let (th, tl) = two_sum(self.lo, other.lo);
+        let (vh, vl) = fast_two_sum(sh, sl + th);
+        let (hi, lo) = fast_two_sum(vh, vl + tl);
+        Self {
+            hi,
+            lo,
+            rad: up(self.rad + other.rad + EPS_ADD * hi.abs()),
+        }
+    }
+
+    fn mul(&self, other: &Self, _w: i64) -> Self {
+        let (p, e) = two_prod(self.hi, other.hi);
+        let e = e + (self.hi * other.lo + self.lo * other.hi);
+        let (hi, lo) = fast_two_sum(p, e);
+        let rad = self.magnitude() * other.rad
+            + other.magnitude() * self.rad
+            + self.rad * other.rad
+            + EPS_MUL * hi.abs();
+        Self {
+            hi,
+            lo,
+            rad: up(rad),
+        }
+    }
+
+    fn div_small(&self, d: u64, _w: i64) -> Self {
+        debug_assert!(d < 1 << 53);
+        let d = d as f64;
+        let q1 = self.hi / d;
+        let (p, e) = two_prod(q1, d);
+        let q2 = (((self.hi - p) - e) + self.lo) / d;
+        let (hi, lo) = fast_two_sum(q1, q2);
+        Self {
+            hi,
+            lo,
+            rad: up(self.rad / d + EPS_DIV * hi.abs()),
+        }
+    }
+
+    fn div(&self, other: &Self, _w: i64) -> Self {
+        // A lower bound on |other|'s midpoint.
+        let low = (other.hi.abs() - other.lo.abs()) * (1.0 - 4.0 * f64::EPSILON);
+        if !(low > 0.0 && other.rad * MARGIN <= low) {
+            return Self::failed();
+        }
+        let q1 = self.hi / other.hi;
+        let (p, e) = two_prod(q1, other.hi);
+        let r = ((self.hi - p) - e) + (self.lo - q1 * other.lo);
+        let q2 = r / other.hi;
+        let (hi, lo) = fast_two_sum(q1, q2);
+        let q = Self { hi, lo, rad: 0.0 };
+        // |a'/b' - a/b| <= (ra + |a/b| rb) / (|b| - rb).
+        let rad = (self.rad + q.magnitude() * other.rad) / (low - other.rad) + EPS_DIV * hi.abs();
+        Self { rad: up(rad), ..q }
+    }
+
+    fn sqrt(&self, _w: i64) -> Self {
+        if !(self.hi > 0.0 && self.rad * MARGIN <= 0.5 * self.hi) {
+            return Self::failed();
+        }
+        let s = libm::sqrt(self.hi);
+        let (p, e) = two_prod(s, s);
+        let r = ((self.hi - p) - e) + self.lo;
+        let (hi, lo) = fast_two_sum(s, r / (2.0 * s));
+        // |sqrt(a') - sqrt(a)| <= ra / sqrt(a).
+        let rad = self.rad / (0.99 * hi) + EPS_DIV * hi;
+        Self {
+            hi,
+            lo,
+            rad: up(rad),
+        }
+    }
+}
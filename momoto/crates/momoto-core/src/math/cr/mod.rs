@@ -0,0 +1,864 @@
+//! Correctly rounded transcendentals for the `deterministic` feature.
+//!
+//! Every function evaluates its result as an interval and rounds that
+//! interval to the nearest `f64`: first in double-double arithmetic
+//! ([`fast`]), then in exact dyadic arithmetic ([`ball`]). When the two
+//! ends of the interval round differently, the working precision is
+//! doubled and the evaluation repeated (Ziv's strategy), so the value
+//! returned is the exact result rounded to nearest, ties to even: the same
+//! bits CORE-MATH, CRlibm or MPFR produce, on every target. An interval
+//! that still straddles a rounding boundary at 32× the starting precision
+//! means the exact result *is* that boundary (a midpoint such as
+//! `powf(94906267.0, 2.0)`), and it is rounded to even.
+//!
+//! Special values (NaN, infinities, signed zeros, domain errors) follow
+//! C99 Annex F; they are taken from `libm` wherever its answer is exact.
+//! `cbrt` is `libm`'s port of the CORE-MATH routine, which is already
+//! correctly rounded.
+
+mod ball;
+mod consts;
+mod fast;
+
+use ball::{Ball, Dy};
+use fast::Fast;
+
+/// Bits of relative precision the double-double attempt aims for.
+const FAST_BITS: i64 = 80;
+
+/// Bits of relative precision in the first exact evaluation.
+const START_BITS: i64 = 96;
+
+/// Precision doublings before an undecided result is taken as a midpoint.
+const MAX_DOUBLINGS: u32 = 5;
+
+/// Halvings of the reduced argument of `exp` before its Taylor series.
+const EXP_HALVINGS: i64 = 8;
+
+/// Halvings of the reduced argument of `sin` and `cos` before their Taylor
+/// series.
+const SIN_COS_HALVINGS: i64 = 3;
+
+/// Square roots that bring the argument of `ln` close to one.
+const LN_ROOTS: i64 = 3;
+
+/// `2^-27`: below it `sin`, `tan`, `asin`, `atan`, `sinh` and `tanh` round
+/// to their argument and `cos`, `cosh` to one.
+const TINY: f64 = 1.0 / 134_217_728.0;
+
+/// `2^-54`: below it `exp` rounds to one and `exp_m1`, `ln_1p` to their
+/// argument.
+const TINIER: f64 = f64::EPSILON / 4.0;
+
+// ============================================================================
+// Interval arithmetic
+// ============================================================================
+
+/// Midpoint-radius intervals that always contain the exact value: [`Fast`]
+/// for the first attempt, [`Ball`] for every later one.
+///
+/// Operations taking `w` may truncate the midpoint to a multiple of `2^-w`;
+/// every rounding error goes into the radius.
+trait Arith: Clone {
+    /// The value of `d`, rounded if it does not fit.
+    fn from_dy(d: &Dy) -> Self;
+
+    /// The exact value of a finite `x`.
+    fn from_f64(x: f64) -> Self;
+
+    /// A constant stored as `floor(v · 2^frac_bits)`, to within `2^-w`.
+    fn from_table(limbs: &[u64], frac_bits: i64, w: i64) -> Self;
+
+    /// A ball no rounding test passes, for an evaluation that cannot be
+    /// carried out at this precision.
+    fn failed() -> Self;
+
+    fn mid(&self) -> Dy;
+
+    /// The midpoint to about `f64` precision, for choosing a reduction.
+    fn approx(&self) -> f64;
+
+    /// The nearest `f64`s to the two ends of the ball, or `None` if it is
+    /// too wide to round.
+    fn bounds(&self) -> Option<(f64, f64)>;
+
+    /// Whether the midpoint is below the granularity `2^-w`; series stop
+    /// there, as the radius never drops below it.
+    fn negligible(&self, w: i64) -> bool;
+
+    /// Whether every value in the ball is below `2^e` in magnitude.
+    fn lt_pow2(&self, e: i64) -> bool;
+
+    /// Widens the radius by `2^k` times the largest magnitude in `other`.
+    fn absorb(self, other: &Self, k: i64) -> Self;
+
+    fn neg(self) -> Self;
+
+    fn mul_pow2(self, k: i64) -> Self;
+
+    fn add(&self, other: &Self) -> Self;
+
+    fn mul(&self, other: &Self, w: i64) -> Self;
+
+    fn div_small(&self, d: u64, w: i64) -> Self;
+
+    /// Fails, with a radius no rounding test passes, unless `other` is
+    /// bounded away from zero.
+    fn div(&self, other: &Self, w: i64) -> Self;
+
+    /// Square root of a ball that lies strictly above zero.
+    fn sqrt(&self, w: i64) -> Self;
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.clone().neg())
+    }
+
+    fn from_i64(v: i64) -> Self {
+        Self::from_dy(&Dy::from_i64(v))
+    }
+
+    fn one() -> Self {
+        Self::from_dy(&Dy::pow2(0))
+    }
+}
+
+// ============================================================================
+// Rounding
+// ============================================================================
+
+/// Rounds the interval `fast(w)`, then `exact(w)`, to the nearest `f64`,
+/// raising `w` until the whole interval rounds to the same value.
+///
+/// `offset` is added to the working precision; kernels that only bound
+/// their absolute error get `-log2 |result|` so that the precision stays
+/// relative to the result.
+fn ziv(offset: i64, fast: impl Fn(i64) -> Fast, exact: impl Fn(i64) -> Ball) -> f64 {
+    if let Some((lo, hi)) = fast(FAST_BITS + offset).bounds() {
+        if lo.to_bits() == hi.to_bits() {
+            return lo;
+        }
+    }
+    for doublings in 0..=MAX_DOUBLINGS {
+        match exact((START_BITS << doublings) + offset).bounds() {
+            Some((lo, hi)) if lo.to_bits() == hi.to_bits() => return lo,
+            Some((lo, hi)) if doublings == MAX_DOUBLINGS => {
+                return if lo.to_bits() & 1 == 0 { lo } else { hi };
+            }
+            _ => {}
+        }
+    }
+    // Exact evaluations only fail for arguments the callers filter out.
+    f64::NAN
+}
+
+/// `ziv(offset, ..)` over one kernel body, written against the arithmetic
+/// type `B` and evaluated as both [`Fast`] and [`Ball`].
+macro_rules! rounded {
+    ($offset:expr, |$w:ident: $b:ident| $body:expr) => {
+        ziv(
+            $offset,
+            |$w| {
+                type $b = Fast;
+                $body
+            },
+            |$w| {
+                type $b = Ball;
+                $body
+            },
+        )
+    };
+}
+
+/// `floor(log2 |x|)` for a finite non-zero `x`.
+fn exponent(x: f64) -> i64 {
+    let bits = x.to_bits() & !(1 << 63);
+    let biased = (bits >> 52) as i64;
+    if biased == 0 {
+        63 - i64::from(bits.leading_zeros()) - 1074
+    } else {
+        biased - 1023
+    }
+}
+
+/// `-floor(log2 |est|)`, the precision offset for a result close to `est`.
+fn offset(est: f64) -> i64 {
+    if est == 0.0 {
+        return 1100;
+    }
+    if !est.is_finite() {
+        return -1024;
+    }
+    -exponent(est)
+}
+
+fn signed(negative: bool, v: f64) -> f64 {
+    if negative {
+        -v
+    } else {
+        v
+    }
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// `π` to within `2^-w`.
+fn pi<B: Arith>(w: i64) -> B {
+    if w > consts::PI_FRAC_BITS {
+        return pi_series(w);
+    }
+    B::from_table(&consts::PI, consts::PI_FRAC_BITS, w)
+}
+
+/// `ln 2` to within `2^-w`.
+fn ln2<B: Arith>(w: i64) -> B {
+    if w > consts::LN2_FRAC_BITS {
+        return ln2_series(w);
+    }
+    B::from_table(&consts::LN2, consts::LN2_FRAC_BITS, w)
+}
+
+/// Machin's formula, `π = 16 atan(1/5) - 4 atan(1/239)`.
+fn pi_series<B: Arith>(w: i64) -> B {
+    arccot::<B>(5, false, w + 6)
+        .mul_pow2(4)
+        .sub(&arccot::<B>(239, false, w + 4).mul_pow2(2))
+}
+
+/// `ln 2 = 2 atanh(1/3)`.
+fn ln2_series<B: Arith>(w: i64) -> B {
+    arccot::<B>(3, true, w + 2).mul_pow2(1)
+}
+
+/// `atan(1/k)`, or `atanh(1/k)` when `hyperbolic`, for an integer `k >= 2`.
+fn arccot<B: Arith>(k: u64, hyperbolic: bool, w: i64) -> B {
+    let wi = w + 8;
+    let mut power = B::one().div_small(k, wi);
+    let mut sum = power.clone();
+    for n in 1u64.. {
+        power = power.div_small(k * k, wi);
+        let term = power.div_small(2 * n + 1, wi);
+        sum = if hyperbolic || n % 2 == 0 {
+            sum.add(&term)
+        } else {
+            sum.sub(&term)
+        };
+        if power.negligible(wi) {
+            break;
+        }
+    }
+    // Every remaining term is below the last power of 1/k².
+    sum.absorb(&power, 0)
+}
+
+// ============================================================================
+// Kernels
+// ============================================================================
+
+/// `e^x` for `|x| < 1100`, to about `w` bits relative to the result.
+fn exp_ball<B: Arith>(x: &B, w: i64) -> B {
+    let wi = w + EXP_HALVINGS + 16;
+    let k = libm::round(x.approx() * core::f64::consts::LOG2_E) as i64;
+    let reduction = ln2::<B>(wi + 12).mul(&B::from_i64(k), wi + 12);
+    let r = x.sub(&reduction).mul_pow2(-EXP_HALVINGS);
+
+    let mut sum = B::one();
+    let mut term = B::one();
+    for n in 1u64.. {
+        term = term.mul(&r, wi).div_small(n, wi);
+        sum = sum.add(&term);
+        if term.negligible(wi) {
+            break;
+        }
+    }
+    let mut y = sum.absorb(&term, 1);
+    for _ in 0..EXP_HALVINGS {
+        y = y.mul(&y, wi);
+    }
+    y.mul_pow2(k)
+}
+
+/// `e^x - 1` for a finite `x`, to about `w` bits relative to the result.
+fn expm1_ball<B: Arith>(x: f64, w: i64) -> B {
+    let e = exponent(x);
+    if e >= 0 {
+        return exp_ball(&B::from_f64(x), w).sub(&B::one());
+    }
+    let wi = w - e + 8;
+    let x = B::from_f64(x);
+    let mut sum = x.clone();
+    let mut term = x.clone();
+    for n in 2u64.. {
+        term = term.mul(&x, wi).div_small(n, wi);
+        sum = sum.add(&term);
+        if term.negligible(wi) {
+            break;
+        }
+    }
+    sum.absorb(&term, 1)
+}
+
+/// `ln((1 + t) / (1 - t)) = 2 atanh t` for `|t| < 1/5`, to within about
+/// `2^-w`.
+fn atanh2_ball<B: Arith>(t: &B, w: i64) -> B {
+    let t2 = t.mul(t, w);
+    let mut sum = t.clone();
+    let mut power = t.clone();
+    for n in 1u64.. {
+        power = power.mul(&t2, w);
+        sum = sum.add(&power.div_small(2 * n + 1, w));
+        if power.negligible(w) {
+            break;
+        }
+    }
+    sum.absorb(&power, 0).mul_pow2(1)
+}
+
+/// `ln x` for `x > 0`, to within about `2^-w`.
+fn ln_ball<B: Arith>(x: &B, w: i64) -> B {
+    let wi = w + LN_ROOTS + 16;
+    // x = m · 2^e with m in [0.75, 1.5).
+    let mut e = exponent(x.approx());
+    let mut m = x.clone().mul_pow2(-e);
+    if m.approx() >= 1.5 {
+        e += 1;
+        m = m.mul_pow2(-1);
+    }
+    // ln m = 2^j ln m^(1/2^j): at most three roots bring m within 1/16 of
+    // one, so that the series below converges quickly.
+    let mut roots = 0;
+    while roots < LN_ROOTS && (m.approx() - 1.0).abs() > 1.0 / 16.0 {
+        m = m.sqrt(wi);
+        roots += 1;
+    }
+    // ln m = 2 atanh t with t = (m - 1) / (m + 1), |t| < 1/32.
+    let t = m.sub(&B::one()).div(&m.add(&B::one()), wi);
+    let ln_m = atanh2_ball(&t, wi).mul_pow2(roots);
+    if e == 0 {
+        return ln_m;
+    }
+    ln_m.add(&ln2::<B>(wi + 12).mul(&B::from_i64(e), wi))
+}
+
+/// `atan t` for `|t| <= 1`, to within about `2^-w`.
+fn atan_ball<B: Arith>(t: &B, w: i64) -> B {
+    let wi = w + 16;
+    // atan t = 2 atan(t / (1 + sqrt(1 + t²))), applied three times: |u| < 0.1.
+    let mut u = t.clone();
+    for _ in 0..3 {
+        let root = B::one().add(&u.mul(&u, wi)).sqrt(wi);
+        u = u.div(&B::one().add(&root), wi);
+    }
+    let u2 = u.mul(&u, wi);
+    let mut sum = u.clone();
+    let mut power = u;
+    for n in 1u64.. {
+        power = power.mul(&u2, wi);
+        let term = power.div_small(2 * n + 1, wi);
+        sum = if n % 2 == 1 {
+            sum.sub(&term)
+        } else {
+            sum.add(&term)
+        };
+        if power.negligible(wi) {
+            break;
+        }
+    }
+    sum.absorb(&power, 0).mul_pow2(3)
+}
+
+/// Four-quadrant `atan2(y, x)` of non-zero balls, to within about `2^-w`.
+fn atan2_ball<B: Arith>(y: &B, x: &B, w: i64) -> B {
+    let wi = w + 8;
+    let (ya, xa) = (y.approx(), x.approx());
+    if ya.abs() <= xa.abs() {
+        let a = atan_ball(&y.div(x, wi), wi);
+        match (xa < 0.0, ya < 0.0) {
+            (false, _) => a,
+            (true, false) => a.add(&pi(wi)),
+            (true, true) => a.sub(&pi(wi)),
+        }
+    } else {
+        let a = atan_ball(&x.div(y, wi), wi);
+        let half_pi = pi::<B>(wi).mul_pow2(-1);
+        if ya < 0.0 {
+            half_pi.neg().sub(&a)
+        } else {
+            half_pi.sub(&a)
+        }
+    }
+}
+
+/// `(sin r, cos r)` for `|r| <= 1`, each to within about `2^-w`.
+fn sin_cos_ball<B: Arith>(r: &B, w: i64) -> (B, B) {
+    let wi = w + 2 * SIN_COS_HALVINGS + 16;
+    let h = r.clone().mul_pow2(-SIN_COS_HALVINGS);
+    let mut sin = B::from_i64(0);
+    let mut cos = B::one();
+    let mut term = B::one();
+    for n in 1u64.. {
+        term = term.mul(&h, wi).div_small(n, wi);
+        match n % 4 {
+            1 => sin = sin.add(&term),
+            2 => cos = cos.sub(&term),
+            3 => sin = sin.sub(&term),
+            _ => cos = cos.add(&term),
+        }
+        if n >= 2 && term.negligible(wi) {
+            break;
+        }
+    }
+    let (mut sin, mut cos) = (sin.absorb(&term, 1), cos.absorb(&term, 1));
+    // sin 2h = 2 sin h cos h, cos 2h = 1 - 2 sin² h.
+    for _ in 0..SIN_COS_HALVINGS {
+        let sin2 = sin.mul(&sin, wi);
+        sin = sin.mul(&cos, wi).mul_pow2(1);
+        cos = B::one().sub(&sin2.mul_pow2(1));
+    }
+    (sin, cos)
+}
+
+/// `x - n·π/2` to within about `2^-w`, and `n mod 4`, for the integer `n`
+/// nearest `x / (π/2)`.
+fn reduce_half_pi<B: Arith>(x: f64, w: i64) -> (B, u8) {
+    let wp = w + exponent(x).max(0) + 8;
+    let half_pi = pi::<B>(wp + 1).mul_pow2(-1);
+    let (n, quadrant) = if x.abs() < 1_073_741_824.0 {
+        let n = libm::round(x * core::f64::consts::FRAC_2_PI) as i64;
+        (B::from_i64(n), n.rem_euclid(4) as u8)
+    } else {
+        let n = Dy::from_f64(x).div(&half_pi.mid(), 2).round_int();
+        (B::from_dy(&n), n.mod4())
+    };
+    let r = B::from_f64(x).sub(&half_pi.mul(&n, wp + 1));
+    if !r.lt_pow2(1) {
+        // Too few bits of π for this `x`: the series would not converge.
+        return (B::failed(), 0);
+    }
+    (r, quadrant)
+}
+
+// ============================================================================
+// Functions
+// ============================================================================
+
+pub(super) fn powf(x: f64, y: f64) -> f64 {
+    let special = !x.is_finite() || !y.is_finite() || x == 0.0 || y == 0.0 || x == 1.0;
+    let integral = libm::trunc(y) == y;
+    if special || (x < 0.0 && !integral) {
+        return libm::pow(x, y);
+    }
+    let negate = x < 0.0 && libm::fmod(y, 2.0) != 0.0;
+    let ax = x.abs();
+    if integral && (1.0..=64.0).contains(&y) {
+        // Small integral powers: a double-double product nearly always
+        // decides the rounding, and the exact one settles the rest,
+        // midpoints included.
+        let base = Fast::from_f64(ax);
+        let power = (1..y as u32).fold(base, |p, _| p.mul(&base, 0));
+        if let Some((lo, hi)) = power.bounds() {
+            if lo.to_bits() == hi.to_bits() {
+                return signed(negate, lo);
+            }
+        }
+        let base = Dy::from_f64(ax);
+        let power = (1..y as u32).fold(base.clone(), |p, _| p.mul(&base));
+        return signed(negate, power.to_f64());
+    }
+    let log2_result = y * libm::log2(ax);
+    if log2_result > 1025.0 {
+        return signed(negate, f64::INFINITY);
+    }
+    if log2_result < -1080.0 {
+        return signed(negate, 0.0);
+    }
+    let y_bits = exponent(y).max(0);
+    let v = rounded!(0, |w: B| {
+        let ln_x: B = ln_ball(&B::from_f64(ax), w + y_bits + 16);
+        exp_ball(&ln_x.mul(&B::from_f64(y), w + 16), w)
+    });
+    signed(negate, v)
+}
+
+pub(super) fn cbrt(x: f64) -> f64 {
+    libm::cbrt(x)
+}
+
+pub(super) fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 710.0 {
+        return f64::INFINITY;
+    }
+    if x < -746.0 {
+        return 0.0;
+    }
+    if x.abs() < TINIER {
+        return 1.0;
+    }
+    rounded!(0, |w: B| exp_ball(&B::from_f64(x), w))
+}
+
+pub(super) fn exp2(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x >= 1024.0 {
+        return f64::INFINITY;
+    }
+    if x < -1080.0 {
+        return 0.0;
+    }
+    if x.abs() < TINIER {
+        return 1.0;
+    }
+    let k = libm::round(x);
+    if k == x {
+        return Dy::pow2(k as i64).to_f64();
+    }
+    let frac = x - k;
+    rounded!(0, |w: B| {
+        let t = ln2::<B>(w + 16).mul(&B::from_f64(frac), w + 16);
+        exp_ball(&t, w).mul_pow2(k as i64)
+    })
+}
+
+pub(super) fn exp_m1(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 710.0 {
+        return f64::INFINITY;
+    }
+    if x < -40.0 {
+        return -1.0;
+    }
+    if x.abs() < TINIER {
+        return x;
+    }
+    rounded!(0, |w: B| expm1_ball::<B>(x, w))
+}
+
+pub(super) fn ln(x: f64) -> f64 {
+    if x.is_nan() || x <= 0.0 || x == f64::INFINITY || x == 1.0 {
+        return libm::log(x);
+    }
+    rounded!(offset(libm::log(x)), |w: B| ln_ball(&B::from_f64(x), w))
+}
+
+pub(super) fn ln_1p(x: f64) -> f64 {
+    if x.is_nan() || x <= -1.0 || x == f64::INFINITY {
+        return libm::log1p(x);
+    }
+    if x.abs() < TINIER {
+        return x;
+    }
+    rounded!(offset(libm::log1p(x)), |w: B| {
+        let xb = B::from_f64(x);
+        if x.abs() < 0.25 {
+            // ln(1 + x) = 2 atanh(x / (2 + x)), without forming 1 + x.
+            let t = xb.div(&xb.add(&B::from_i64(2)), w + 8);
+            atanh2_ball(&t, w + 8)
+        } else {
+            ln_ball(&xb.add(&B::one()), w)
+        }
+    })
+}
+
+pub(super) fn log10(x: f64) -> f64 {
+    if x.is_nan() || x <= 0.0 || x == f64::INFINITY || x == 1.0 {
+        return libm::log10(x);
+    }
+    rounded!(offset(libm::log10(x)), |w: B| {
+        let ln10 = ln_ball(&B::from_i64(10), w + 8);
+        ln_ball(&B::from_f64(x), w + 4).div(&ln10, w + 4)
+    })
+}
+
+pub(super) fn log2(x: f64) -> f64 {
+    if x.is_nan() || x <= 0.0 || x == f64::INFINITY || x == 1.0 {
+        return libm::log2(x);
+    }
+    rounded!(offset(libm::log2(x)), |w: B| {
+        ln_ball(&B::from_f64(x), w + 4).div(&ln2(w + 8), w + 4)
+    })
+}
+
+pub(super) fn sin(x: f64) -> f64 {
+    if !x.is_finite() {
+        return libm::sin(x);
+    }
+    if x.abs() < TINY {
+        return x;
+    }
+    rounded!(offset(libm::sin(x)), |w: B| {
+        let (r, quadrant) = reduce_half_pi::<B>(x, w);
+        let (s, c) = sin_cos_ball(&r, w);
+        match quadrant {
+            0 => s,
+            1 => c,
+            2 => s.neg(),
+            _ => c.neg(),
+        }
+    })
+}
+
+pub(super) fn cos(x: f64) -> f64 {
+    if !x.is_finite() {
+        return libm::cos(x);
+    }
+    if x.abs() < TINY {
+        return 1.0;
+    }
+    rounded!(offset(libm::cos(x)), |w: B| {
+        let (r, quadrant) = reduce_half_pi::<B>(x, w);
+        let (s, c) = sin_cos_ball(&r, w);
+        match quadrant {
+            0 => c,
+            1 => s.neg(),
+            2 => c.neg(),
+            _ => s,
+        }
+    })
+}
+
+pub(super) fn tan(x: f64) -> f64 {
+    if !x.is_finite() {
+        return libm::tan(x);
+    }
+    if x.abs() < TINY {
+        return x;
+    }
+    let est = libm::tan(x);
+    // Near a pole the denominator is as small as the result is large.
+    let pole = (-offset(est)).max(0);
+    rounded!(offset(est), |w: B| {
+        let (r, quadrant) = reduce_half_pi::<B>(x, w + 2 * pole);
+        let (s, c) = sin_cos_ball(&r, w + 2 * pole);
+        if quadrant % 2 == 0 {
+            s.div(&c, w)
+        } else {
+            c.div(&s, w).neg()
+        }
+    })
+}
+
+pub(super) fn asin(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return libm::asin(x);
+    }
+    if x.abs() < TINY {
+        return x;
+    }
+    if x.abs() == 1.0 {
+        return signed(x < 0.0, rounded!(0, |w: B| pi::<B>(w + 1).mul_pow2(-1)));
+    }
+    rounded!(offset(libm::asin(x)), |w: B| {
+        let xb = B::from_f64(x);
+        let cos2 = B::one().sub(&xb).mul(&B::one().add(&xb), 2 * w + 8);
+        atan2_ball(&xb, &cos2.sqrt(w + 8), w)
+    })
+}
+
+pub(super) fn acos(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 || x == 1.0 {
+        return libm::acos(x);
+    }
+    if x == -1.0 {
+        return rounded!(0, |w: B| pi::<B>(w));
+    }
+    rounded!(offset(libm::acos(x)), |w: B| {
+        let xb = B::from_f64(x);
+        let sin2 = B::one().sub(&xb).mul(&B::one().add(&xb), 2 * w + 8);
+        atan2_ball(&sin2.sqrt(w + 8), &xb, w)
+    })
+}
+
+pub(super) fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.is_infinite() {
+        return signed(x < 0.0, rounded!(0, |w: B| pi::<B>(w + 1).mul_pow2(-1)));
+    }
+    if x.abs() < TINY {
+        return x;
+    }
+    rounded!(offset(libm::atan(x)), |w: B| {
+        atan2_ball(&B::from_f64(x), &B::one(), w)
+    })
+}
+
+pub(super) fn atan2(y: f64, x: f64) -> f64 {
+    if y.is_nan() || x.is_nan() {
+        return y + x;
+    }
+    if y == 0.0 || x == 0.0 || y.is_infinite() || x.is_infinite() {
+        // C99 Annex F: a multiple of π/4 carrying the sign of y.
+        let quarters = if y == 0.0 {
+            if x > 0.0 || (x == 0.0 && x.is_sign_positive()) {
+                0
+            } else {
+                4
+            }
+        } else if y.is_infinite() {
+            if x == f64::INFINITY {
+                1
+            } else if x == f64::NEG_INFINITY {
+                3
+            } else {
+                2
+            }
+        } else if x == 0.0 {
+            2
+        } else if x > 0.0 {
+            0
+        } else {
+            4
+        };
+        let v = if quarters == 0 {
+            0.0
+        } else {
+            rounded!(0, |w: B| {
+                pi::<B>(w + 4)
+                    .mul(&B::from_i64(quarters), w + 4)
+                    .mul_pow2(-2)
+            })
+        };
+        return signed(y.is_sign_negative(), v);
+    }
+    rounded!(offset(libm::atan2(y, x)), |w: B| {
+        atan2_ball(&B::from_f64(y), &B::from_f64(x), w)
+    })
+}
+
+pub(super) fn sinh(x: f64) -> f64 {
+    if !x.is_finite() || x.abs() < TINY {
+        return x;
+    }
+    if x.abs() > 711.0 {
+        return signed(x < 0.0, f64::INFINITY);
+    }
+    let a = x.abs();
+    let small = offset(a).max(0);
+    let v = rounded!(0, |w: B| {
+        // sinh a = (m + m / (m + 1)) / 2 with m = e^a - 1.
+        let m = expm1_ball::<B>(a, w + 4);
+        m.add(&m.div(&m.add(&B::one()), w + 4 + small)).mul_pow2(-1)
+    });
+    signed(x < 0.0, v)
+}
+
+pub(super) fn cosh(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.abs() > 711.0 {
+        return f64::INFINITY;
+    }
+    if x.abs() < TINY {
+        return 1.0;
+    }
+    rounded!(0, |w: B| {
+        let e = exp_ball(&B::from_f64(x.abs()), w + 4);
+        e.add(&B::one().div(&e, w + 4)).mul_pow2(-1)
+    })
+}
+
+pub(super) fn tanh(x: f64) -> f64 {
+    if x.is_nan() || x.abs() < TINY {
+        return x;
+    }
+    if x.abs() > 20.0 {
+        return signed(x < 0.0, 1.0);
+    }
+    let a = x.abs();
+    let small = offset(a).max(0);
+    let v = rounded!(0, |w: B| {
+        // tanh a = m / (m + 2) with m = e^(2a) - 1.
+        let m = expm1_ball::<B>(2.0 * a, w + 4);
+        m.div(&m.add(&B::from_i64(2)), w + 4 + small)
+    });
+    signed(x < 0.0, v)
+}
+
+pub(super) fn hypot(x: f64, y: f64) -> f64 {
+    if x.is_infinite() || y.is_infinite() {
+        return f64::INFINITY;
+    }
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x == 0.0 && y == 0.0 {
+        return 0.0;
+    }
+    // Scaled by a power of two so that the larger square is near one.
+    let e = exponent(x.abs().max(y.abs()));
+    rounded!(0, |w: B| {
+        let (xb, yb) = (B::from_f64(x).mul_pow2(-e), B::from_f64(y).mul_pow2(-e));
+        let sum = xb.mul(&xb, w + 8).add(&yb.mul(&yb, w + 8));
+        sum.sqrt(w).mul_pow2(e)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cmp::Ordering;
+
+    fn overlaps(a: &Ball, b: &Ball) -> bool {
+        let reach = a.rad.add(b.rad).to_dy();
+        a.mid.sub(&b.mid).cmp_abs(&reach) != Ordering::Greater
+    }
+
+    #[test]
+    fn test_constant_tables_match_series() {
+        for w in [64, 600, consts::PI_FRAC_BITS] {
+            assert!(overlaps(&pi(w), &pi_series(w)), "π at {w} bits");
+            assert!(overlaps(&ln2(w), &ln2_series(w)), "ln 2 at {w} bits");
+        }
+    }
+
+    #[test]
+    fn test_exact_results() {
+        assert_eq!(exp(1.0), core::f64::consts::E);
+        assert_eq!(ln(2.0), core::f64::consts::LN_2);
+        assert_eq!(atan(1.0), core::f64::consts::FRAC_PI_4);
+        assert_eq!(log10(1e22), 22.0);
+        assert_eq!(log2(0.125), -3.0);
+        assert_eq!(powf(3.0, 30.0), 205_891_132_094_649.0);
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+    }
+
+    #[test]
+    fn test_midpoints_round_to_even() {
+        // 94906267² has 54 significant bits: it lies halfway between doubles.
+        let exact = 94_906_267u128 * 94_906_267;
+        assert_eq!(powf(94_906_267.0, 2.0), exact as f64);
+        assert_eq!(exp2(-1075.0), 0.0);
+        assert_eq!(exp2(-1074.0), f64::from_bits(1));
+    }
+
+    #[test]
+    fn test_special_values() {
+        assert!(exp(f64::NAN).is_nan());
+        assert_eq!(exp(f64::NEG_INFINITY), 0.0);
+        assert_eq!(ln(0.0), f64::NEG_INFINITY);
+        assert!(ln(-1.0).is_nan());
+        assert_eq!(ln_1p(-0.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(sin(-0.0).to_bits(), (-0.0f64).to_bits());
+        assert!(sin(f64::INFINITY).is_nan());
+        assert_eq!(atan2(-0.0, -1.0), -core::f64::consts::PI);
+        assert_eq!(atan2(1.0, f64::NEG_INFINITY), core::f64::consts::PI);
+        assert_eq!(
+            atan2(f64::INFINITY, f64::INFINITY),
+            core::f64::consts::FRAC_PI_4
+        );
+        assert!(powf(-8.0, 1.0 / 3.0).is_nan());
+        assert_eq!(powf(-2.0, 3.0), -8.0);
+        assert_eq!(hypot(f64::INFINITY, f64::NAN), f64::INFINITY);
+        assert_eq!(tanh(f64::NEG_INFINITY), -1.0);
+    }
+}
@@ -0,0 +1,59 @@
+//! Non-transcendental `f64` methods that live in `std`, routed through `libm`
+//! on `no_std`. Transcendentals go through [`super::ops`] instead.
+//!
+//! Import with `#[cfg(not(feature = "std"))] use momoto_core::math::float::FloatFuncs;`
+//! — with `std` enabled the inherent methods are used and this module does
+//! not exist.
+
+/// Floating-point functions missing from `core`.
+pub trait FloatFuncs: Sized {
+    /// `self` raised to an integer power.
+    fn powi(self, n: i32) -> Self;
+    /// Square root.
+    fn sqrt(self) -> Self;
+    /// Largest integer not greater than `self`.
+    fn floor(self) -> Self;
+    /// Smallest integer not less than `self`.
+    fn ceil(self) -> Self;
+    /// Nearest integer, ties away from zero.
+    fn round(self) -> Self;
+    /// Least non-negative remainder.
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+impl FloatFuncs for f64 {
+    #[inline]
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, f64::from(n))
+    }
+
+    #[inline]
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    #[inline]
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    #[inline]
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let r = self % rhs;
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+}
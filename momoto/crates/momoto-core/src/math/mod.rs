@@ -3,6 +3,12 @@
 //! Provides numerical constants and utility functions used throughout
 //! the color perception calculations.
 
+#[cfg(feature = "deterministic")]
+mod cr;
+#[cfg(not(feature = "std"))]
+pub mod float;
+pub mod ops;
+
 /// Mathematical and physical constants used in color calculations.
 pub mod constants {
     /// Euler's number
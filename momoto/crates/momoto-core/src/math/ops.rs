@@ -0,0 +1,125 @@
+//! Transcendental functions with a single, selectable implementation.
+//!
+//! `powf`, `cbrt`, `exp`, `atan2` and friends are not specified by IEEE 754,
+//! so the platform libm, the LLVM intrinsics and wasm32 all round them
+//! differently. Every transcendental call in momoto goes through this module
+//! so that one feature flag decides which implementation is used:
+//!
+//! | Features | Implementation |
+//! |----------|----------------|
+//! | `std` | inherent `f64` methods (fastest, platform-dependent) |
+//! | `deterministic` | correctly rounded software implementation, bit-identical on every target |
+//! | neither (`no_std` + `libm`) | the pure-Rust `libm` port |
+//!
+//! With `deterministic`, every function returns the exact result rounded
+//! to nearest, ties to even, so its bits are fixed by the input bits and by
+//! IEEE 754 alone — the same bits CORE-MATH or MPFR give. The evaluation
+//! uses double-double interval arithmetic and falls back to multi-precision
+//! only when the rounding is not yet certain; it is about ten times slower
+//! than `libm`, which is reproducible but not correctly rounded
+//! (`libm::exp(1.0)` lands one ulp above e). `sqrt` and `powi` are not
+//! routed here: `sqrt` is a correctly rounded IEEE operation and `powi`
+//! lowers to the same multiplication sequence on every target.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::math::ops;
+//!
+//! let l = ops::cbrt(0.008);
+//! assert!((l - 0.2).abs() < 1e-12);
+//! ```
+
+macro_rules! ops {
+    (@call $name:ident $x:ident) => { $x.$name() };
+    (@call $name:ident $x:ident, $y:ident) => { $x.$name($y) };
+    ($(
+        $(#[$doc:meta])*
+        fn $name:ident($($arg:ident),+) => $libm:ident;
+    )*) => {$(
+        $(#[$doc])*
+        #[inline]
+        #[must_use]
+        pub fn $name($($arg: f64),+) -> f64 {
+            #[cfg(feature = "deterministic")]
+            {
+                super::cr::$name($($arg),+)
+            }
+            #[cfg(all(not(feature = "std"), not(feature = "deterministic")))]
+            {
+                libm::$libm($($arg),+)
+            }
+            #[cfg(all(feature = "std", not(feature = "deterministic")))]
+            {
+                ops!(@call $name $($arg),+)
+            }
+        }
+    )*};
+}
+
+ops! {
+    /// `x` raised to the power `y`.
+    fn powf(x, y) => pow;
+    /// Cube root.
+    fn cbrt(x) => cbrt;
+    /// `e^x`.
+    fn exp(x) => exp;
+    /// `2^x`.
+    fn exp2(x) => exp2;
+    /// `e^x - 1`, accurate near zero.
+    fn exp_m1(x) => expm1;
+    /// Natural logarithm.
+    fn ln(x) => log;
+    /// `ln(1 + x)`, accurate near zero.
+    fn ln_1p(x) => log1p;
+    /// Base-10 logarithm.
+    fn log10(x) => log10;
+    /// Base-2 logarithm.
+    fn log2(x) => log2;
+    /// Sine (radians).
+    fn sin(x) => sin;
+    /// Cosine (radians).
+    fn cos(x) => cos;
+    /// Tangent (radians).
+    fn tan(x) => tan;
+    /// Arcsine, in radians.
+    fn asin(x) => asin;
+    /// Arccosine, in radians.
+    fn acos(x) => acos;
+    /// Arctangent, in radians.
+    fn atan(x) => atan;
+    /// Four-quadrant arctangent of `y / x`, in radians.
+    fn atan2(y, x) => atan2;
+    /// Hyperbolic sine.
+    fn sinh(x) => sinh;
+    /// Hyperbolic cosine.
+    fn cosh(x) => cosh;
+    /// Hyperbolic tangent.
+    fn tanh(x) => tanh;
+    /// `sqrt(x² + y²)` without undue overflow.
+    fn hypot(x, y) => hypot;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_std_within_ulps() {
+        let xs = [0.001, 0.25, 0.5, 1.0, 2.2, 7.5, 100.0];
+        for &x in &xs {
+            assert!((powf(x, 2.4) - x.powf(2.4)).abs() <= 1e-12 * x.powf(2.4).max(1.0));
+            assert!((cbrt(x) - x.cbrt()).abs() < 1e-14 * x.max(1.0));
+            assert!((ln(x) - x.ln()).abs() < 1e-14);
+            assert!((sin(x) - x.sin()).abs() < 1e-15);
+            assert!((atan2(x, 1.0 - x) - x.atan2(1.0 - x)).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_argument_order() {
+        assert!((atan2(1.0, 0.0) - core::f64::consts::FRAC_PI_2).abs() < 1e-15);
+        assert_eq!(powf(2.0, 10.0), 1024.0);
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+    }
+}
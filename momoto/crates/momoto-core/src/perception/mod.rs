@@ -4,6 +4,7 @@
 //! and perceptual results.
 
 use crate::color::Color;
+use alloc::vec::Vec;
 
 /// Result of a perceptual contrast calculation.
 ///
@@ -186,8 +186,8 @@ pub enum RenderError {
     },
 }
 
-impl std::fmt::Display for RenderError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             RenderError::UnsupportedFeature { feature, reason } => {
                 write!(f, "Unsupported feature '{}': {}", feature, reason)
@@ -319,6 +319,17 @@ impl RenderContext {
         }
     }
 
+    /// Create context for print output (soft-proofed against coated offset).
+    pub fn print() -> Self {
+        Self {
+            pixel_density: 300.0 / 96.0,
+            viewing_distance_m: 0.4,
+            medium: TargetMedium::Print,
+            background_luminance: 0.88,
+            ..Default::default()
+        }
+    }
+
     /// Check if capability is supported.
     pub fn has_capability(&self, capability: &str) -> bool {
         self.capabilities.get(capability).copied().unwrap_or(false)
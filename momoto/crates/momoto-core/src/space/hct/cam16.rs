@@ -10,7 +10,14 @@
 // Reference implementation: Google material-color-utilities.
 // =============================================================================
 
-use std::f64::consts::PI;
+use core::f64::consts::PI;
+
+use crate::math::ops;
+use crate::space::lab::{EPSILON, KAPPA};
+use crate::space::xyz::Xyz;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
 
 // =============================================================================
 // Chromatic adaptation matrices (M16)
@@ -74,6 +81,33 @@ impl ViewingConditions {
     /// * `discounting` — whether to discount the illuminant (D=1)
     pub fn new(la: f64, yb: f64, yw: f64, surround: f64, discounting: bool) -> Self {
         let white_xyz = [95.047, 100.0, 108.883]; // D65
+        Self::from_white_xyz(white_xyz, la, yb, yw, surround, discounting)
+    }
+
+    /// Create viewing conditions adapted to an arbitrary reference white.
+    ///
+    /// `white` uses the Y = 1.0 scale of [`Xyz`]; e.g.
+    /// `Illuminant::D50.white_point()` for a print viewing booth.
+    ///
+    /// # Arguments
+    /// * `white` — adopted white point
+    /// * `la` — adapting luminance in cd/m²
+    /// * `yb` — background luminance (0–100)
+    /// * `surround` — surround (0=dark, 1=dim, 2=average, 3=bright)
+    /// * `discounting` — whether to discount the illuminant (D=1)
+    pub fn with_white_point(white: Xyz, la: f64, yb: f64, surround: f64, discounting: bool) -> Self {
+        let white_xyz = [white.x * 100.0, white.y * 100.0, white.z * 100.0];
+        Self::from_white_xyz(white_xyz, la, yb, white_xyz[1], surround, discounting)
+    }
+
+    fn from_white_xyz(
+        white_xyz: [f64; 3],
+        la: f64,
+        yb: f64,
+        yw: f64,
+        surround: f64,
+        discounting: bool,
+    ) -> Self {
 
         // Surround-dependent parameters
         let (c, f, nc) = if surround > 1.0 {
@@ -89,15 +123,15 @@ impl ViewingConditions {
         let k = 1.0 / (5.0 * la + 1.0);
         let k4 = k * k * k * k;
         let k4f = 1.0 - k4;
-        let fl = k4 * la + 0.1 * k4f * k4f * (5.0 * la).cbrt();
+        let fl = k4 * la + 0.1 * k4f * k4f * ops::cbrt(5.0 * la);
 
         // Background factor
         let n = yb / yw;
-        let n02 = n.powf(0.2);
+        let n02 = ops::powf(n, 0.2);
         let nbb = 0.725 / n02;
         let ncb = nbb;
         // CAM16 exponent z (Li et al. 2017, Eq. 7; same as CIECAM02)
-        let z = 1.48 + 0.29 * n.sqrt();
+        let z = 1.48 + n.sqrt();
 
         // White point in M16 cone space
         let rgb_w = mat3_mul_vec3(&M16, white_xyz);
@@ -106,7 +140,7 @@ impl ViewingConditions {
         let d = if discounting {
             1.0
         } else {
-            (f * (1.0 - (1.0 / 3.6) * ((-la - 42.0) / 92.0).exp())).clamp(0.0, 1.0)
+            (f * (1.0 - (1.0 / 3.6) * ops::exp((-la - 42.0) / 92.0))).clamp(0.0, 1.0)
         };
 
         // Adaptation factors per channel
@@ -184,11 +218,11 @@ impl CAM16 {
         let b = (ra + ga - 2.0 * ba) / 9.0;
 
         // Step 4: Hue angle — CAM16 uses atan2(b, a) (Li et al. 2017)
-        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+        let h = ops::atan2(b, a).to_degrees().rem_euclid(360.0);
 
         // Step 5: Eccentricity and t
         let h_rad = h * PI / 180.0;
-        let e_t = 0.25 * ((h_rad + 2.0).cos() + 3.8);
+        let e_t = 0.25 * (ops::cos(h_rad + 2.0) + 3.8);
         let denom = ra + ga + 21.0 / 20.0 * ba;
         let t = if denom.abs() < 1e-10 {
             0.0
@@ -201,7 +235,7 @@ impl CAM16 {
         let j = if vc.aw.abs() < 1e-10 {
             0.0
         } else {
-            100.0 * (a_val / vc.aw).max(0.0).powf(vc.c * vc.z)
+            100.0 * ops::powf((a_val / vc.aw).max(0.0), vc.c * vc.z)
         };
 
         // Step 7: Chroma C, brightness Q, colorfulness M, saturation s
@@ -209,10 +243,10 @@ impl CAM16 {
         let c = if t < 0.0 {
             0.0
         } else {
-            t.powf(0.9) * j100 * (1.64 - 0.29_f64.powf(vc.n)).powf(0.73)
+            ops::powf(t, 0.9) * j100 * ops::powf(1.64 - ops::powf(0.29_f64, vc.n), 0.73)
         };
-        let q = (4.0 / vc.c) * j100 * (vc.aw + 4.0) * vc.fl.powf(0.25);
-        let m = c * vc.fl.powf(0.25);
+        let q = (4.0 / vc.c) * j100 * (vc.aw + 4.0) * ops::powf(vc.fl, 0.25);
+        let m = c * ops::powf(vc.fl, 0.25);
         let s = if q.abs() < 1e-10 {
             0.0
         } else {
@@ -236,14 +270,14 @@ impl CAM16 {
         let t = if alpha == 0.0 {
             0.0
         } else {
-            (alpha / (1.64 - 0.29_f64.powf(vc.n)).powf(0.73)).powf(1.0 / 0.9)
+            ops::powf(alpha / ops::powf(1.64 - ops::powf(0.29_f64, vc.n), 0.73), 1.0 / 0.9)
         };
 
         let h_rad = h * PI / 180.0;
-        let e_t = 0.25 * ((h_rad + 2.0).cos() + 3.8);
+        let e_t = 0.25 * (ops::cos(h_rad + 2.0) + 3.8);
 
         // Achromatic response from J
-        let a_val = vc.aw * j100.powi(2).powf(1.0 / (vc.c * vc.z));
+        let a_val = vc.aw * ops::powf(j100.powi(2), 1.0 / (vc.c * vc.z));
 
         let p1 = e_t * (50_000.0 / 13.0) * vc.nc * vc.ncb;
         // p2 = A/Nbb = 2*Ra + Ga + 0.05*Ba - 0.305 (includes -0.305 Hunt offset)
@@ -252,8 +286,8 @@ impl CAM16 {
         // Required by the linear recovery formulas (CIECAM02/CAM16 inverse)
         let p2_adj = p2 + 0.305;
 
-        let h_sin = h_rad.sin();
-        let h_cos = h_rad.cos();
+        let h_sin = ops::sin(h_rad);
+        let h_cos = ops::cos(h_rad);
 
         let (a, b) = if t.abs() < 1e-10 {
             (0.0, 0.0)
@@ -293,17 +327,17 @@ impl CAM16 {
 
 /// CIE L* (lightness) from Y (normalized to D65 white Y = 1.0).
 pub fn lstar_from_y(y: f64) -> f64 {
-    let fy = if y > 0.008856 { y.powf(1.0 / 3.0) } else { 7.787 * y + 16.0 / 116.0 };
+    let fy = if y > EPSILON { ops::cbrt(y) } else { (KAPPA * y + 16.0) / 116.0 };
     116.0 * fy - 16.0
 }
 
 /// Y (D65 normalized to 1.0 = white) from CIE L*.
 pub fn y_from_lstar(lstar: f64) -> f64 {
-    if lstar > 8.0 {
+    if lstar > KAPPA * EPSILON {
         let fy = (lstar + 16.0) / 116.0;
         fy * fy * fy
     } else {
-        lstar / 903.3
+        lstar / KAPPA
     }
 }
 
@@ -325,7 +359,7 @@ pub fn mat3_mul_vec3(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
 /// Handles negative values via sign preservation.
 #[inline]
 fn adapted_response(x: f64, fl: f64) -> f64 {
-    let p = (fl * x.abs() / 100.0).powf(0.42);
+    let p = ops::powf(fl * x.abs() / 100.0, 0.42);
     x.signum() * 400.0 * p / (27.13 + p) + 0.1
 }
 
@@ -335,7 +369,7 @@ fn inverse_adapted_response(ra: f64, fl: f64) -> f64 {
     // Clamp to avoid domain errors (denominator 400-x → 0)
     let x = (ra - 0.1).abs().min(399.99);
     let base = (27.13 * x / (400.0 - x)).max(0.0);
-    (ra - 0.1).signum() * (100.0 / fl.max(1e-10)) * base.powf(1.0 / 0.42)
+    (ra - 0.1).signum() * (100.0 / fl.max(1e-10)) * ops::powf(base, 1.0 / 0.42)
 }
 
 // =============================================================================
@@ -0,0 +1,60 @@
+// =============================================================================
+// Disliked Colors
+// File: crates/momoto-core/src/space/hct/dislike.rs
+//
+// Dark yellow-greens ("bile", "mold") are consistently disliked across
+// cultures; Material's dynamic color lightens them instead of using them
+// as-is.
+//
+// Reference: Palmer & Schloss (2010), "An ecological valence theory of human
+//            color preference", PNAS 107(19).
+// Port of DislikeAnalyzer from Google material-color-utilities (Apache 2.0).
+// =============================================================================
+
+use super::HCT;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// Whether `hct` is a dark yellow-green that users tend to dislike.
+#[must_use]
+pub fn is_disliked(hct: &HCT) -> bool {
+    let hue = hct.hue.round();
+    (90.0..=111.0).contains(&hue) && hct.chroma.round() > 16.0 && hct.tone.round() < 65.0
+}
+
+/// Lighten a disliked color to tone 70; other colors are returned unchanged.
+#[must_use]
+pub fn fix_if_disliked(hct: HCT) -> HCT {
+    if is_disliked(&hct) {
+        HCT::solve(hct.hue, hct.chroma, 70.0)
+    } else {
+        hct
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dark_yellow_green_disliked() {
+        // material-color-utilities DislikeAnalyzer test colors
+        for argb in [0xff95884b, 0xff716b40, 0xffb08e00, 0xff4c4308, 0xff464521] {
+            let hct = HCT::from_argb(argb);
+            assert!(is_disliked(&hct), "{argb:08x}");
+            let fixed = fix_if_disliked(hct);
+            assert!(!is_disliked(&fixed), "{argb:08x}");
+            assert!((fixed.tone - 70.0).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_other_colors_untouched() {
+        for argb in [0xff0000ff, 0xffff0000, 0xff00ff00, 0xfff3f315, 0xff808080] {
+            let hct = HCT::from_argb(argb);
+            assert!(!is_disliked(&hct), "{argb:08x}");
+            assert_eq!(fix_if_disliked(hct), hct);
+        }
+    }
+}
@@ -0,0 +1,952 @@
+// =============================================================================
+// Material Dynamic Colors
+// File: crates/momoto-core/src/space/hct/dynamic/material.rs
+//
+// The Material Design 3 color roles, each defined as a DynamicColor over the
+// palettes of a DynamicScheme. Tones and contrast curves follow the 2021
+// Material spec as implemented in material-color-utilities.
+// =============================================================================
+
+use super::super::dislike::fix_if_disliked;
+use super::super::palette::TonalPalette;
+use super::super::HCT;
+use super::{foreground_tone, ContrastCurve, DynamicColor, DynamicScheme, ToneDeltaPair, TonePolarity, Variant};
+
+/// Standard contrast curves shared by many roles.
+const CURVE_TEXT: ContrastCurve = ContrastCurve::new(4.5, 7.0, 11.0, 21.0);
+const CURVE_ACCENT: ContrastCurve = ContrastCurve::new(3.0, 4.5, 7.0, 7.0);
+const CURVE_CONTAINER: ContrastCurve = ContrastCurve::new(1.0, 1.0, 3.0, 4.5);
+const CURVE_TEXT_VARIANT: ContrastCurve = ContrastCurve::new(3.0, 4.5, 7.0, 11.0);
+
+/// The Material Design 3 color roles.
+///
+/// Each function returns the role definition; resolve it against a scheme
+/// with [`DynamicScheme::argb`].
+#[derive(Debug, Clone, Copy)]
+pub struct MaterialDynamicColors;
+
+// =============================================================================
+// Scheme predicates and palette accessors
+// =============================================================================
+
+fn is_fidelity(s: &DynamicScheme) -> bool {
+    matches!(s.variant, Variant::Fidelity | Variant::Content)
+}
+
+fn is_monochrome(s: &DynamicScheme) -> bool {
+    s.variant == Variant::Monochrome
+}
+
+/// `light` in a light scheme, `dark` in a dark one.
+fn by_mode(s: &DynamicScheme, light: f64, dark: f64) -> f64 {
+    if s.is_dark {
+        dark
+    } else {
+        light
+    }
+}
+
+fn primary_palette(s: &DynamicScheme) -> &TonalPalette {
+    &s.primary_palette
+}
+
+fn secondary_palette(s: &DynamicScheme) -> &TonalPalette {
+    &s.secondary_palette
+}
+
+fn tertiary_palette(s: &DynamicScheme) -> &TonalPalette {
+    &s.tertiary_palette
+}
+
+fn neutral_palette(s: &DynamicScheme) -> &TonalPalette {
+    &s.neutral_palette
+}
+
+fn neutral_variant_palette(s: &DynamicScheme) -> &TonalPalette {
+    &s.neutral_variant_palette
+}
+
+fn error_palette(s: &DynamicScheme) -> &TonalPalette {
+    &s.error_palette
+}
+
+/// The surface with the most contrast against content in this mode.
+fn highest_surface(s: &DynamicScheme) -> DynamicColor {
+    if s.is_dark {
+        MaterialDynamicColors::surface_bright()
+    } else {
+        MaterialDynamicColors::surface_dim()
+    }
+}
+
+/// Tone near `tone` at which `hue` can reach `chroma`, searching toward
+/// darker tones when `by_decreasing_tone`.
+fn find_desired_chroma_by_tone(hue: f64, chroma: f64, tone: f64, by_decreasing_tone: bool) -> f64 {
+    let mut answer = tone;
+    let mut closest = HCT::solve(hue, chroma, tone);
+    if closest.chroma < chroma {
+        let mut chroma_peak = closest.chroma;
+        while closest.chroma < chroma {
+            answer += if by_decreasing_tone { -1.0 } else { 1.0 };
+            let candidate = HCT::solve(hue, chroma, answer);
+            if chroma_peak > candidate.chroma || (candidate.chroma - chroma).abs() < 0.4 {
+                break;
+            }
+            if (candidate.chroma - chroma).abs() < (closest.chroma - chroma).abs() {
+                closest = candidate;
+            }
+            chroma_peak = chroma_peak.max(candidate.chroma);
+        }
+    }
+    answer
+}
+
+/// A surface-like role: drawn behind content, no contrast requirement.
+const fn surface_role(
+    name: &'static str,
+    palette: fn(&DynamicScheme) -> &TonalPalette,
+    tone: fn(&DynamicScheme) -> f64,
+) -> DynamicColor {
+    DynamicColor {
+        is_background: true,
+        ..DynamicColor::from_palette(name, palette, tone)
+    }
+}
+
+/// A content role drawn on `background` with the given contrast target.
+const fn on_role(
+    name: &'static str,
+    palette: fn(&DynamicScheme) -> &TonalPalette,
+    tone: fn(&DynamicScheme) -> f64,
+    background: fn(&DynamicScheme) -> DynamicColor,
+    curve: ContrastCurve,
+) -> DynamicColor {
+    DynamicColor {
+        background: Some(background),
+        contrast_curve: Some(curve),
+        ..DynamicColor::from_palette(name, palette, tone)
+    }
+}
+
+/// An accent or container role on the highest surface, paired with its partner.
+const fn paired_role(
+    name: &'static str,
+    palette: fn(&DynamicScheme) -> &TonalPalette,
+    tone: fn(&DynamicScheme) -> f64,
+    curve: ContrastCurve,
+    pair: fn(&DynamicScheme) -> ToneDeltaPair,
+) -> DynamicColor {
+    DynamicColor {
+        is_background: true,
+        background: Some(highest_surface),
+        contrast_curve: Some(curve),
+        tone_delta_pair: Some(pair),
+        ..DynamicColor::from_palette(name, palette, tone)
+    }
+}
+
+/// Accent and its container: container nearer the surface, 10 tones apart.
+fn container_pair(container: DynamicColor, accent: DynamicColor) -> ToneDeltaPair {
+    ToneDeltaPair {
+        role_a: container,
+        role_b: accent,
+        delta: 10.0,
+        polarity: TonePolarity::Nearer,
+        stay_together: false,
+    }
+}
+
+/// Fixed and fixed-dim: fixed is lighter in both modes, 10 tones apart.
+fn fixed_pair(fixed: DynamicColor, fixed_dim: DynamicColor) -> ToneDeltaPair {
+    ToneDeltaPair {
+        role_a: fixed,
+        role_b: fixed_dim,
+        delta: 10.0,
+        polarity: TonePolarity::Lighter,
+        stay_together: true,
+    }
+}
+
+/// Text on both a fixed role and its dim variant.
+fn on_fixed_role(
+    name: &'static str,
+    palette: fn(&DynamicScheme) -> &TonalPalette,
+    tone: fn(&DynamicScheme) -> f64,
+    fixed: fn(&DynamicScheme) -> DynamicColor,
+    fixed_dim: fn(&DynamicScheme) -> DynamicColor,
+    curve: ContrastCurve,
+) -> DynamicColor {
+    DynamicColor {
+        second_background: Some(fixed),
+        ..on_role(name, palette, tone, fixed_dim, curve)
+    }
+}
+
+// =============================================================================
+// Roles
+// =============================================================================
+
+impl MaterialDynamicColors {
+    // -------------------------------------------------------------------------
+    // Palette key colors
+    // -------------------------------------------------------------------------
+
+    /// Key color of the primary palette.
+    #[must_use]
+    pub fn primary_palette_key_color() -> DynamicColor {
+        DynamicColor::from_palette("primary_palette_key_color", primary_palette, |s| {
+            s.primary_palette.key_color().tone
+        })
+    }
+
+    /// Key color of the secondary palette.
+    #[must_use]
+    pub fn secondary_palette_key_color() -> DynamicColor {
+        DynamicColor::from_palette("secondary_palette_key_color", secondary_palette, |s| {
+            s.secondary_palette.key_color().tone
+        })
+    }
+
+    /// Key color of the tertiary palette.
+    #[must_use]
+    pub fn tertiary_palette_key_color() -> DynamicColor {
+        DynamicColor::from_palette("tertiary_palette_key_color", tertiary_palette, |s| {
+            s.tertiary_palette.key_color().tone
+        })
+    }
+
+    /// Key color of the neutral palette.
+    #[must_use]
+    pub fn neutral_palette_key_color() -> DynamicColor {
+        DynamicColor::from_palette("neutral_palette_key_color", neutral_palette, |s| {
+            s.neutral_palette.key_color().tone
+        })
+    }
+
+    /// Key color of the neutral variant palette.
+    #[must_use]
+    pub fn neutral_variant_palette_key_color() -> DynamicColor {
+        DynamicColor::from_palette("neutral_variant_palette_key_color", neutral_variant_palette, |s| {
+            s.neutral_variant_palette.key_color().tone
+        })
+    }
+
+    // -------------------------------------------------------------------------
+    // Backgrounds and surfaces
+    // -------------------------------------------------------------------------
+
+    /// App background.
+    #[must_use]
+    pub fn background() -> DynamicColor {
+        surface_role("background", neutral_palette, |s| by_mode(s, 98.0, 6.0))
+    }
+
+    /// Content on [`background`](Self::background).
+    #[must_use]
+    pub fn on_background() -> DynamicColor {
+        on_role(
+            "on_background",
+            neutral_palette,
+            |s| by_mode(s, 10.0, 90.0),
+            |_| Self::background(),
+            ContrastCurve::new(3.0, 3.0, 4.5, 7.0),
+        )
+    }
+
+    /// Default surface.
+    #[must_use]
+    pub fn surface() -> DynamicColor {
+        surface_role("surface", neutral_palette, |s| by_mode(s, 98.0, 6.0))
+    }
+
+    /// Dimmest surface.
+    #[must_use]
+    pub fn surface_dim() -> DynamicColor {
+        surface_role("surface_dim", neutral_palette, |s| {
+            if s.is_dark {
+                6.0
+            } else {
+                ContrastCurve::new(87.0, 87.0, 80.0, 75.0).get(s.contrast_level)
+            }
+        })
+    }
+
+    /// Brightest surface.
+    #[must_use]
+    pub fn surface_bright() -> DynamicColor {
+        surface_role("surface_bright", neutral_palette, |s| {
+            if s.is_dark {
+                ContrastCurve::new(24.0, 24.0, 29.0, 34.0).get(s.contrast_level)
+            } else {
+                98.0
+            }
+        })
+    }
+
+    /// Lowest-emphasis container surface.
+    #[must_use]
+    pub fn surface_container_lowest() -> DynamicColor {
+        surface_role("surface_container_lowest", neutral_palette, |s| {
+            if s.is_dark {
+                ContrastCurve::new(4.0, 4.0, 2.0, 0.0).get(s.contrast_level)
+            } else {
+                100.0
+            }
+        })
+    }
+
+    /// Low-emphasis container surface.
+    #[must_use]
+    pub fn surface_container_low() -> DynamicColor {
+        surface_role("surface_container_low", neutral_palette, |s| {
+            if s.is_dark {
+                ContrastCurve::new(10.0, 10.0, 11.0, 12.0).get(s.contrast_level)
+            } else {
+                ContrastCurve::new(96.0, 96.0, 96.0, 95.0).get(s.contrast_level)
+            }
+        })
+    }
+
+    /// Default container surface.
+    #[must_use]
+    pub fn surface_container() -> DynamicColor {
+        surface_role("surface_container", neutral_palette, |s| {
+            if s.is_dark {
+                ContrastCurve::new(12.0, 12.0, 16.0, 20.0).get(s.contrast_level)
+            } else {
+                ContrastCurve::new(94.0, 94.0, 92.0, 90.0).get(s.contrast_level)
+            }
+        })
+    }
+
+    /// High-emphasis container surface.
+    #[must_use]
+    pub fn surface_container_high() -> DynamicColor {
+        surface_role("surface_container_high", neutral_palette, |s| {
+            if s.is_dark {
+                ContrastCurve::new(17.0, 17.0, 21.0, 25.0).get(s.contrast_level)
+            } else {
+                ContrastCurve::new(92.0, 92.0, 88.0, 85.0).get(s.contrast_level)
+            }
+        })
+    }
+
+    /// Highest-emphasis container surface.
+    #[must_use]
+    pub fn surface_container_highest() -> DynamicColor {
+        surface_role("surface_container_highest", neutral_palette, |s| {
+            if s.is_dark {
+                ContrastCurve::new(22.0, 22.0, 26.0, 30.0).get(s.contrast_level)
+            } else {
+                ContrastCurve::new(90.0, 90.0, 84.0, 80.0).get(s.contrast_level)
+            }
+        })
+    }
+
+    /// Text and icons on surfaces.
+    #[must_use]
+    pub fn on_surface() -> DynamicColor {
+        on_role(
+            "on_surface",
+            neutral_palette,
+            |s| by_mode(s, 10.0, 90.0),
+            highest_surface,
+            CURVE_TEXT,
+        )
+    }
+
+    /// Surface variant.
+    #[must_use]
+    pub fn surface_variant() -> DynamicColor {
+        surface_role("surface_variant", neutral_variant_palette, |s| by_mode(s, 90.0, 30.0))
+    }
+
+    /// Lower-emphasis text and icons on surfaces.
+    #[must_use]
+    pub fn on_surface_variant() -> DynamicColor {
+        on_role(
+            "on_surface_variant",
+            neutral_variant_palette,
+            |s| by_mode(s, 30.0, 80.0),
+            highest_surface,
+            CURVE_TEXT_VARIANT,
+        )
+    }
+
+    /// Surface in the opposite mode (snackbars, tooltips).
+    #[must_use]
+    pub fn inverse_surface() -> DynamicColor {
+        DynamicColor::from_palette("inverse_surface", neutral_palette, |s| by_mode(s, 20.0, 90.0))
+    }
+
+    /// Content on [`inverse_surface`](Self::inverse_surface).
+    #[must_use]
+    pub fn inverse_on_surface() -> DynamicColor {
+        on_role(
+            "inverse_on_surface",
+            neutral_palette,
+            |s| by_mode(s, 95.0, 20.0),
+            |_| Self::inverse_surface(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Important boundaries (text field outlines).
+    #[must_use]
+    pub fn outline() -> DynamicColor {
+        on_role(
+            "outline",
+            neutral_variant_palette,
+            |s| by_mode(s, 50.0, 60.0),
+            highest_surface,
+            ContrastCurve::new(1.5, 3.0, 4.5, 7.0),
+        )
+    }
+
+    /// Decorative boundaries (dividers).
+    #[must_use]
+    pub fn outline_variant() -> DynamicColor {
+        on_role(
+            "outline_variant",
+            neutral_variant_palette,
+            |s| by_mode(s, 80.0, 30.0),
+            highest_surface,
+            CURVE_CONTAINER,
+        )
+    }
+
+    /// Elevation shadows.
+    #[must_use]
+    pub fn shadow() -> DynamicColor {
+        DynamicColor::from_palette("shadow", neutral_palette, |_| 0.0)
+    }
+
+    /// Modal scrims.
+    #[must_use]
+    pub fn scrim() -> DynamicColor {
+        DynamicColor::from_palette("scrim", neutral_palette, |_| 0.0)
+    }
+
+    /// Tint applied to elevated surfaces.
+    #[must_use]
+    pub fn surface_tint() -> DynamicColor {
+        surface_role("surface_tint", primary_palette, |s| by_mode(s, 40.0, 80.0))
+    }
+
+    // -------------------------------------------------------------------------
+    // Primary
+    // -------------------------------------------------------------------------
+
+    /// Primary accent.
+    #[must_use]
+    pub fn primary() -> DynamicColor {
+        paired_role(
+            "primary",
+            primary_palette,
+            |s| {
+                if is_monochrome(s) {
+                    by_mode(s, 0.0, 100.0)
+                } else {
+                    by_mode(s, 40.0, 80.0)
+                }
+            },
+            CURVE_ACCENT,
+            |_| container_pair(Self::primary_container(), Self::primary()),
+        )
+    }
+
+    /// Content on [`primary`](Self::primary).
+    #[must_use]
+    pub fn on_primary() -> DynamicColor {
+        on_role(
+            "on_primary",
+            primary_palette,
+            |s| {
+                if is_monochrome(s) {
+                    by_mode(s, 90.0, 10.0)
+                } else {
+                    by_mode(s, 100.0, 20.0)
+                }
+            },
+            |_| Self::primary(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Primary container.
+    #[must_use]
+    pub fn primary_container() -> DynamicColor {
+        paired_role(
+            "primary_container",
+            primary_palette,
+            |s| {
+                if is_fidelity(s) {
+                    s.source_color_hct.tone
+                } else if is_monochrome(s) {
+                    by_mode(s, 25.0, 85.0)
+                } else {
+                    by_mode(s, 90.0, 30.0)
+                }
+            },
+            CURVE_CONTAINER,
+            |_| container_pair(Self::primary_container(), Self::primary()),
+        )
+    }
+
+    /// Content on [`primary_container`](Self::primary_container).
+    #[must_use]
+    pub fn on_primary_container() -> DynamicColor {
+        on_role(
+            "on_primary_container",
+            primary_palette,
+            |s| {
+                if is_fidelity(s) {
+                    foreground_tone((Self::primary_container().tone)(s), 4.5)
+                } else if is_monochrome(s) {
+                    by_mode(s, 100.0, 0.0)
+                } else {
+                    by_mode(s, 10.0, 90.0)
+                }
+            },
+            |_| Self::primary_container(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Primary accent on [`inverse_surface`](Self::inverse_surface).
+    #[must_use]
+    pub fn inverse_primary() -> DynamicColor {
+        on_role(
+            "inverse_primary",
+            primary_palette,
+            |s| by_mode(s, 80.0, 40.0),
+            |_| Self::inverse_surface(),
+            CURVE_ACCENT,
+        )
+    }
+
+    // -------------------------------------------------------------------------
+    // Secondary
+    // -------------------------------------------------------------------------
+
+    /// Secondary accent.
+    #[must_use]
+    pub fn secondary() -> DynamicColor {
+        paired_role(
+            "secondary",
+            secondary_palette,
+            |s| by_mode(s, 40.0, 80.0),
+            CURVE_ACCENT,
+            |_| container_pair(Self::secondary_container(), Self::secondary()),
+        )
+    }
+
+    /// Content on [`secondary`](Self::secondary).
+    #[must_use]
+    pub fn on_secondary() -> DynamicColor {
+        on_role(
+            "on_secondary",
+            secondary_palette,
+            |s| {
+                if is_monochrome(s) {
+                    by_mode(s, 100.0, 10.0)
+                } else {
+                    by_mode(s, 100.0, 20.0)
+                }
+            },
+            |_| Self::secondary(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Secondary container.
+    #[must_use]
+    pub fn secondary_container() -> DynamicColor {
+        paired_role(
+            "secondary_container",
+            secondary_palette,
+            |s| {
+                let initial_tone = by_mode(s, 90.0, 30.0);
+                if is_monochrome(s) {
+                    by_mode(s, 85.0, 30.0)
+                } else if !is_fidelity(s) {
+                    initial_tone
+                } else {
+                    find_desired_chroma_by_tone(
+                        s.secondary_palette.hue(),
+                        s.secondary_palette.chroma(),
+                        initial_tone,
+                        !s.is_dark,
+                    )
+                }
+            },
+            CURVE_CONTAINER,
+            |_| container_pair(Self::secondary_container(), Self::secondary()),
+        )
+    }
+
+    /// Content on [`secondary_container`](Self::secondary_container).
+    #[must_use]
+    pub fn on_secondary_container() -> DynamicColor {
+        on_role(
+            "on_secondary_container",
+            secondary_palette,
+            |s| {
+                if !is_fidelity(s) {
+                    by_mode(s, 10.0, 90.0)
+                } else {
+                    foreground_tone((Self::secondary_container().tone)(s), 4.5)
+                }
+            },
+            |_| Self::secondary_container(),
+            CURVE_TEXT,
+        )
+    }
+
+    // -------------------------------------------------------------------------
+    // Tertiary
+    // -------------------------------------------------------------------------
+
+    /// Tertiary accent.
+    #[must_use]
+    pub fn tertiary() -> DynamicColor {
+        paired_role(
+            "tertiary",
+            tertiary_palette,
+            |s| {
+                if is_monochrome(s) {
+                    by_mode(s, 25.0, 90.0)
+                } else {
+                    by_mode(s, 40.0, 80.0)
+                }
+            },
+            CURVE_ACCENT,
+            |_| container_pair(Self::tertiary_container(), Self::tertiary()),
+        )
+    }
+
+    /// Content on [`tertiary`](Self::tertiary).
+    #[must_use]
+    pub fn on_tertiary() -> DynamicColor {
+        on_role(
+            "on_tertiary",
+            tertiary_palette,
+            |s| {
+                if is_monochrome(s) {
+                    by_mode(s, 90.0, 10.0)
+                } else {
+                    by_mode(s, 100.0, 20.0)
+                }
+            },
+            |_| Self::tertiary(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Tertiary container.
+    #[must_use]
+    pub fn tertiary_container() -> DynamicColor {
+        paired_role(
+            "tertiary_container",
+            tertiary_palette,
+            |s| {
+                if is_monochrome(s) {
+                    by_mode(s, 49.0, 60.0)
+                } else if !is_fidelity(s) {
+                    by_mode(s, 90.0, 30.0)
+                } else {
+                    let proposed = s.tertiary_palette.get_hct(s.source_color_hct.tone);
+                    fix_if_disliked(proposed).tone
+                }
+            },
+            CURVE_CONTAINER,
+            |_| container_pair(Self::tertiary_container(), Self::tertiary()),
+        )
+    }
+
+    /// Content on [`tertiary_container`](Self::tertiary_container).
+    #[must_use]
+    pub fn on_tertiary_container() -> DynamicColor {
+        on_role(
+            "on_tertiary_container",
+            tertiary_palette,
+            |s| {
+                if is_monochrome(s) {
+                    by_mode(s, 100.0, 0.0)
+                } else if !is_fidelity(s) {
+                    by_mode(s, 10.0, 90.0)
+                } else {
+                    foreground_tone((Self::tertiary_container().tone)(s), 4.5)
+                }
+            },
+            |_| Self::tertiary_container(),
+            CURVE_TEXT,
+        )
+    }
+
+    // -------------------------------------------------------------------------
+    // Error
+    // -------------------------------------------------------------------------
+
+    /// Error accent.
+    #[must_use]
+    pub fn error() -> DynamicColor {
+        paired_role(
+            "error",
+            error_palette,
+            |s| by_mode(s, 40.0, 80.0),
+            CURVE_ACCENT,
+            |_| container_pair(Self::error_container(), Self::error()),
+        )
+    }
+
+    /// Content on [`error`](Self::error).
+    #[must_use]
+    pub fn on_error() -> DynamicColor {
+        on_role(
+            "on_error",
+            error_palette,
+            |s| by_mode(s, 100.0, 20.0),
+            |_| Self::error(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Error container.
+    #[must_use]
+    pub fn error_container() -> DynamicColor {
+        paired_role(
+            "error_container",
+            error_palette,
+            |s| by_mode(s, 90.0, 30.0),
+            CURVE_CONTAINER,
+            |_| container_pair(Self::error_container(), Self::error()),
+        )
+    }
+
+    /// Content on [`error_container`](Self::error_container).
+    #[must_use]
+    pub fn on_error_container() -> DynamicColor {
+        on_role(
+            "on_error_container",
+            error_palette,
+            |s| by_mode(s, 10.0, 90.0),
+            |_| Self::error_container(),
+            CURVE_TEXT,
+        )
+    }
+
+    // -------------------------------------------------------------------------
+    // Fixed (same tone in light and dark)
+    // -------------------------------------------------------------------------
+
+    /// Primary fixed.
+    #[must_use]
+    pub fn primary_fixed() -> DynamicColor {
+        paired_role(
+            "primary_fixed",
+            primary_palette,
+            |s| if is_monochrome(s) { 40.0 } else { 90.0 },
+            CURVE_CONTAINER,
+            |_| fixed_pair(Self::primary_fixed(), Self::primary_fixed_dim()),
+        )
+    }
+
+    /// Primary fixed, dimmed.
+    #[must_use]
+    pub fn primary_fixed_dim() -> DynamicColor {
+        paired_role(
+            "primary_fixed_dim",
+            primary_palette,
+            |s| if is_monochrome(s) { 30.0 } else { 80.0 },
+            CURVE_CONTAINER,
+            |_| fixed_pair(Self::primary_fixed(), Self::primary_fixed_dim()),
+        )
+    }
+
+    /// Content on primary fixed and fixed-dim.
+    #[must_use]
+    pub fn on_primary_fixed() -> DynamicColor {
+        on_fixed_role(
+            "on_primary_fixed",
+            primary_palette,
+            |s| if is_monochrome(s) { 100.0 } else { 10.0 },
+            |_| Self::primary_fixed(),
+            |_| Self::primary_fixed_dim(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Lower-emphasis content on primary fixed and fixed-dim.
+    #[must_use]
+    pub fn on_primary_fixed_variant() -> DynamicColor {
+        on_fixed_role(
+            "on_primary_fixed_variant",
+            primary_palette,
+            |s| if is_monochrome(s) { 90.0 } else { 30.0 },
+            |_| Self::primary_fixed(),
+            |_| Self::primary_fixed_dim(),
+            CURVE_TEXT_VARIANT,
+        )
+    }
+
+    /// Secondary fixed.
+    #[must_use]
+    pub fn secondary_fixed() -> DynamicColor {
+        paired_role(
+            "secondary_fixed",
+            secondary_palette,
+            |s| if is_monochrome(s) { 80.0 } else { 90.0 },
+            CURVE_CONTAINER,
+            |_| fixed_pair(Self::secondary_fixed(), Self::secondary_fixed_dim()),
+        )
+    }
+
+    /// Secondary fixed, dimmed.
+    #[must_use]
+    pub fn secondary_fixed_dim() -> DynamicColor {
+        paired_role(
+            "secondary_fixed_dim",
+            secondary_palette,
+            |s| if is_monochrome(s) { 70.0 } else { 80.0 },
+            CURVE_CONTAINER,
+            |_| fixed_pair(Self::secondary_fixed(), Self::secondary_fixed_dim()),
+        )
+    }
+
+    /// Content on secondary fixed and fixed-dim.
+    #[must_use]
+    pub fn on_secondary_fixed() -> DynamicColor {
+        on_fixed_role(
+            "on_secondary_fixed",
+            secondary_palette,
+            |_| 10.0,
+            |_| Self::secondary_fixed(),
+            |_| Self::secondary_fixed_dim(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Lower-emphasis content on secondary fixed and fixed-dim.
+    #[must_use]
+    pub fn on_secondary_fixed_variant() -> DynamicColor {
+        on_fixed_role(
+            "on_secondary_fixed_variant",
+            secondary_palette,
+            |s| if is_monochrome(s) { 25.0 } else { 30.0 },
+            |_| Self::secondary_fixed(),
+            |_| Self::secondary_fixed_dim(),
+            CURVE_TEXT_VARIANT,
+        )
+    }
+
+    /// Tertiary fixed.
+    #[must_use]
+    pub fn tertiary_fixed() -> DynamicColor {
+        paired_role(
+            "tertiary_fixed",
+            tertiary_palette,
+            |s| if is_monochrome(s) { 40.0 } else { 90.0 },
+            CURVE_CONTAINER,
+            |_| fixed_pair(Self::tertiary_fixed(), Self::tertiary_fixed_dim()),
+        )
+    }
+
+    /// Tertiary fixed, dimmed.
+    #[must_use]
+    pub fn tertiary_fixed_dim() -> DynamicColor {
+        paired_role(
+            "tertiary_fixed_dim",
+            tertiary_palette,
+            |s| if is_monochrome(s) { 30.0 } else { 80.0 },
+            CURVE_CONTAINER,
+            |_| fixed_pair(Self::tertiary_fixed(), Self::tertiary_fixed_dim()),
+        )
+    }
+
+    /// Content on tertiary fixed and fixed-dim.
+    #[must_use]
+    pub fn on_tertiary_fixed() -> DynamicColor {
+        on_fixed_role(
+            "on_tertiary_fixed",
+            tertiary_palette,
+            |s| if is_monochrome(s) { 100.0 } else { 10.0 },
+            |_| Self::tertiary_fixed(),
+            |_| Self::tertiary_fixed_dim(),
+            CURVE_TEXT,
+        )
+    }
+
+    /// Lower-emphasis content on tertiary fixed and fixed-dim.
+    #[must_use]
+    pub fn on_tertiary_fixed_variant() -> DynamicColor {
+        on_fixed_role(
+            "on_tertiary_fixed_variant",
+            tertiary_palette,
+            |s| if is_monochrome(s) { 90.0 } else { 30.0 },
+            |_| Self::tertiary_fixed(),
+            |_| Self::tertiary_fixed_dim(),
+            CURVE_TEXT_VARIANT,
+        )
+    }
+
+    // -------------------------------------------------------------------------
+    // Listing
+    // -------------------------------------------------------------------------
+
+    /// Every scheme role (palette key colors excluded), in spec order.
+    #[must_use]
+    pub fn all() -> [DynamicColor; 49] {
+        [
+            Self::background(),
+            Self::on_background(),
+            Self::surface(),
+            Self::surface_dim(),
+            Self::surface_bright(),
+            Self::surface_container_lowest(),
+            Self::surface_container_low(),
+            Self::surface_container(),
+            Self::surface_container_high(),
+            Self::surface_container_highest(),
+            Self::on_surface(),
+            Self::surface_variant(),
+            Self::on_surface_variant(),
+            Self::inverse_surface(),
+            Self::inverse_on_surface(),
+            Self::outline(),
+            Self::outline_variant(),
+            Self::shadow(),
+            Self::scrim(),
+            Self::surface_tint(),
+            Self::primary(),
+            Self::on_primary(),
+            Self::primary_container(),
+            Self::on_primary_container(),
+            Self::inverse_primary(),
+            Self::secondary(),
+            Self::on_secondary(),
+            Self::secondary_container(),
+            Self::on_secondary_container(),
+            Self::tertiary(),
+            Self::on_tertiary(),
+            Self::tertiary_container(),
+            Self::on_tertiary_container(),
+            Self::error(),
+            Self::on_error(),
+            Self::error_container(),
+            Self::on_error_container(),
+            Self::primary_fixed(),
+            Self::primary_fixed_dim(),
+            Self::on_primary_fixed(),
+            Self::on_primary_fixed_variant(),
+            Self::secondary_fixed(),
+            Self::secondary_fixed_dim(),
+            Self::on_secondary_fixed(),
+            Self::on_secondary_fixed_variant(),
+            Self::tertiary_fixed(),
+            Self::tertiary_fixed_dim(),
+            Self::on_tertiary_fixed(),
+            Self::on_tertiary_fixed_variant(),
+        ]
+    }
+}
@@ -0,0 +1,430 @@
+// =============================================================================
+// Dynamic Color
+// File: crates/momoto-core/src/space/hct/dynamic/mod.rs
+//
+// A DynamicColor is a color role (primary, onSurface, …) whose tone is
+// resolved against a DynamicScheme: it starts from the role's nominal tone
+// and is then pushed until it meets a contrast target against its
+// background(s), honouring tone-delta constraints with paired roles.
+//
+// Port of the dynamiccolor package of Google material-color-utilities
+// (Apache 2.0): DynamicColor, ContrastCurve, ToneDeltaPair, DynamicScheme,
+// MaterialDynamicColors and the Scheme* variants.
+// =============================================================================
+
+mod material;
+mod scheme;
+
+pub use material::MaterialDynamicColors;
+pub use scheme::{DynamicScheme, Variant};
+
+use super::cam16::{lstar_from_y, y_from_lstar};
+use super::palette::TonalPalette;
+use super::HCT;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+// =============================================================================
+// ContrastCurve
+// =============================================================================
+
+/// Contrast ratio targets at contrast levels −1, 0, 0.5 and 1.
+///
+/// Levels in between are interpolated linearly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastCurve {
+    /// Target at contrast level −1 (reduced)
+    pub low: f64,
+    /// Target at contrast level 0 (standard)
+    pub normal: f64,
+    /// Target at contrast level 0.5 (medium)
+    pub medium: f64,
+    /// Target at contrast level 1 (high)
+    pub high: f64,
+}
+
+impl ContrastCurve {
+    /// Create a curve from its four anchor ratios.
+    #[must_use]
+    pub const fn new(low: f64, normal: f64, medium: f64, high: f64) -> Self {
+        Self { low, normal, medium, high }
+    }
+
+    /// Target contrast ratio at `contrast_level` (−1 to 1).
+    #[must_use]
+    pub fn get(&self, contrast_level: f64) -> f64 {
+        let lerp = |a: f64, b: f64, t: f64| a + (b - a) * t;
+        if contrast_level <= -1.0 {
+            self.low
+        } else if contrast_level < 0.0 {
+            lerp(self.low, self.normal, contrast_level + 1.0)
+        } else if contrast_level < 0.5 {
+            lerp(self.normal, self.medium, contrast_level / 0.5)
+        } else if contrast_level < 1.0 {
+            lerp(self.medium, self.high, (contrast_level - 0.5) / 0.5)
+        } else {
+            self.high
+        }
+    }
+}
+
+// =============================================================================
+// ToneDeltaPair
+// =============================================================================
+
+/// Which member of a [`ToneDeltaPair`] sits further from the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TonePolarity {
+    /// Role A is darker than role B
+    Darker,
+    /// Role A is lighter than role B
+    Lighter,
+    /// Role A is closer in tone to the background than role B
+    Nearer,
+    /// Role A is further in tone from the background than role B
+    Farther,
+}
+
+/// A constraint keeping two roles at least `delta` tones apart.
+#[derive(Debug, Clone, Copy)]
+pub struct ToneDeltaPair {
+    /// First role of the pair
+    pub role_a: DynamicColor,
+    /// Second role of the pair
+    pub role_b: DynamicColor,
+    /// Minimum tone difference between the two roles
+    pub delta: f64,
+    /// Direction of the difference
+    pub polarity: TonePolarity,
+    /// Whether to move both roles out of the 50–59 tone band together
+    pub stay_together: bool,
+}
+
+// =============================================================================
+// DynamicColor
+// =============================================================================
+
+/// A color role whose tone depends on the scheme it is resolved against.
+///
+/// All fields are plain function pointers, so roles are cheap `Copy` values
+/// that can be defined as constants and reference each other.
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicColor {
+    /// Role name, e.g. `"on_primary_container"`
+    pub name: &'static str,
+    /// Palette the role draws from
+    pub palette: fn(&DynamicScheme) -> &TonalPalette,
+    /// Nominal tone, before contrast adjustment
+    pub tone: fn(&DynamicScheme) -> f64,
+    /// Whether other roles are drawn on top of this one
+    pub is_background: bool,
+    /// Role this one must contrast against
+    pub background: Option<fn(&DynamicScheme) -> DynamicColor>,
+    /// Second role this one must contrast against (e.g. fixed vs fixed-dim)
+    pub second_background: Option<fn(&DynamicScheme) -> DynamicColor>,
+    /// Contrast target against the background(s)
+    pub contrast_curve: Option<ContrastCurve>,
+    /// Tone separation constraint with a paired role
+    pub tone_delta_pair: Option<fn(&DynamicScheme) -> ToneDeltaPair>,
+}
+
+impl DynamicColor {
+    /// A role with a fixed palette and tone, no background and no constraints.
+    #[must_use]
+    pub const fn from_palette(
+        name: &'static str,
+        palette: fn(&DynamicScheme) -> &TonalPalette,
+        tone: fn(&DynamicScheme) -> f64,
+    ) -> Self {
+        Self {
+            name,
+            palette,
+            tone,
+            is_background: false,
+            background: None,
+            second_background: None,
+            contrast_curve: None,
+            tone_delta_pair: None,
+        }
+    }
+
+    /// ARGB color of this role in `scheme`.
+    #[must_use]
+    pub fn get_argb(&self, scheme: &DynamicScheme) -> u32 {
+        (self.palette)(scheme).tone(self.get_tone(scheme))
+    }
+
+    /// HCT color of this role in `scheme`.
+    #[must_use]
+    pub fn get_hct(&self, scheme: &DynamicScheme) -> HCT {
+        (self.palette)(scheme).get_hct(self.get_tone(scheme))
+    }
+
+    /// Tone of this role in `scheme`, after contrast adjustment.
+    #[must_use]
+    pub fn get_tone(&self, scheme: &DynamicScheme) -> f64 {
+        let decreasing_contrast = scheme.contrast_level < 0.0;
+
+        // Case 1: paired roles with a tone-delta constraint
+        if let Some(pair_fn) = self.tone_delta_pair {
+            let pair = pair_fn(scheme);
+            let bg_tone = self.background_tone(scheme);
+
+            let a_is_nearer = match pair.polarity {
+                TonePolarity::Nearer => true,
+                TonePolarity::Farther => false,
+                TonePolarity::Lighter => !scheme.is_dark,
+                TonePolarity::Darker => scheme.is_dark,
+            };
+            let (nearer, farther) = if a_is_nearer {
+                (pair.role_a, pair.role_b)
+            } else {
+                (pair.role_b, pair.role_a)
+            };
+            let am_nearer = self.name == nearer.name;
+            let delta = pair.delta;
+            let expansion_dir = if scheme.is_dark { 1.0 } else { -1.0 };
+
+            // 1st round: solve each role to its own contrast target
+            let n_contrast = nearer.contrast_target(scheme);
+            let f_contrast = farther.contrast_target(scheme);
+            let mut n_tone = initial_or_foreground(bg_tone, (nearer.tone)(scheme), n_contrast);
+            let mut f_tone = initial_or_foreground(bg_tone, (farther.tone)(scheme), f_contrast);
+            if decreasing_contrast {
+                // Reduced contrast: use the bare minimum that satisfies the target
+                n_tone = foreground_tone(bg_tone, n_contrast);
+                f_tone = foreground_tone(bg_tone, f_contrast);
+            }
+
+            // 2nd round: expand farther; 3rd round: contract nearer
+            if (f_tone - n_tone) * expansion_dir < delta {
+                f_tone = (n_tone + delta * expansion_dir).clamp(0.0, 100.0);
+                if (f_tone - n_tone) * expansion_dir < delta {
+                    n_tone = (f_tone - delta * expansion_dir).clamp(0.0, 100.0);
+                }
+            }
+
+            // Avoid the 50–59 band, where neither black nor white text works well
+            let move_both = |n_tone: &mut f64, f_tone: &mut f64| {
+                if expansion_dir > 0.0 {
+                    *n_tone = 60.0;
+                    *f_tone = f_tone.max(*n_tone + delta * expansion_dir);
+                } else {
+                    *n_tone = 49.0;
+                    *f_tone = f_tone.min(*n_tone + delta * expansion_dir);
+                }
+            };
+            if (50.0..60.0).contains(&n_tone) {
+                move_both(&mut n_tone, &mut f_tone);
+            } else if (50.0..60.0).contains(&f_tone) {
+                if pair.stay_together {
+                    move_both(&mut n_tone, &mut f_tone);
+                } else {
+                    f_tone = if expansion_dir > 0.0 { 60.0 } else { 49.0 };
+                }
+            }
+
+            return if am_nearer { n_tone } else { f_tone };
+        }
+
+        // Case 2: a single role, solved against its background
+        let mut answer = (self.tone)(scheme);
+        let Some(background) = self.background else {
+            return answer;
+        };
+        let bg_tone = background(scheme).get_tone(scheme);
+        let desired_ratio = self.contrast_target(scheme);
+
+        answer = initial_or_foreground(bg_tone, answer, desired_ratio);
+        if decreasing_contrast {
+            answer = foreground_tone(bg_tone, desired_ratio);
+        }
+        if self.is_background && (50.0..60.0).contains(&answer) {
+            answer = if ratio_of_tones(49.0, bg_tone) >= desired_ratio { 49.0 } else { 60.0 };
+        }
+
+        // Case 3: two backgrounds, e.g. text on both fixed and fixed-dim
+        let Some(second_background) = self.second_background else {
+            return answer;
+        };
+        let bg_tone_1 = bg_tone;
+        let bg_tone_2 = second_background(scheme).get_tone(scheme);
+        let upper = bg_tone_1.max(bg_tone_2);
+        let lower = bg_tone_1.min(bg_tone_2);
+        if ratio_of_tones(upper, answer) >= desired_ratio
+            && ratio_of_tones(lower, answer) >= desired_ratio
+        {
+            return answer;
+        }
+
+        let light_option = lighter(upper, desired_ratio);
+        let dark_option = darker(lower, desired_ratio);
+        if tone_prefers_light_foreground(bg_tone_1) || tone_prefers_light_foreground(bg_tone_2) {
+            return light_option.unwrap_or(100.0);
+        }
+        match (light_option, dark_option) {
+            (Some(light), None) => light,
+            (_, dark) => dark.unwrap_or(0.0),
+        }
+    }
+
+    fn background_tone(&self, scheme: &DynamicScheme) -> f64 {
+        self.background
+            .map_or(50.0, |background| background(scheme).get_tone(scheme))
+    }
+
+    fn contrast_target(&self, scheme: &DynamicScheme) -> f64 {
+        self.contrast_curve
+            .map_or(1.0, |curve| curve.get(scheme.contrast_level))
+    }
+}
+
+/// Keep `tone` if it already meets `ratio` against `bg_tone`, otherwise
+/// solve for a foreground tone that does.
+fn initial_or_foreground(bg_tone: f64, tone: f64, ratio: f64) -> f64 {
+    if ratio_of_tones(bg_tone, tone) >= ratio {
+        tone
+    } else {
+        foreground_tone(bg_tone, ratio)
+    }
+}
+
+/// The tone closest to meeting `ratio` against `bg_tone`, preferring the
+/// lighter side on dark backgrounds and the darker side on light ones.
+#[must_use]
+pub fn foreground_tone(bg_tone: f64, ratio: f64) -> f64 {
+    let lighter_tone = lighter(bg_tone, ratio).unwrap_or(100.0);
+    let darker_tone = darker(bg_tone, ratio).unwrap_or(0.0);
+    let lighter_ratio = ratio_of_tones(lighter_tone, bg_tone);
+    let darker_ratio = ratio_of_tones(darker_tone, bg_tone);
+
+    if tone_prefers_light_foreground(bg_tone) {
+        // Equally poor either way: still choose light on a dark background
+        let negligible_difference = (lighter_ratio - darker_ratio).abs() < 0.1
+            && lighter_ratio < ratio
+            && darker_ratio < ratio;
+        if lighter_ratio >= ratio || lighter_ratio >= darker_ratio || negligible_difference {
+            lighter_tone
+        } else {
+            darker_tone
+        }
+    } else if darker_ratio >= ratio || darker_ratio >= lighter_ratio {
+        darker_tone
+    } else {
+        lighter_tone
+    }
+}
+
+/// Whether text on a background of `tone` should be light.
+#[must_use]
+pub fn tone_prefers_light_foreground(tone: f64) -> bool {
+    tone.round() < 60.0
+}
+
+/// Whether light text is legible on a background of `tone`.
+#[must_use]
+pub fn tone_allows_light_foreground(tone: f64) -> bool {
+    tone.round() <= 49.0
+}
+
+/// Darken a background that prefers, but cannot host, light text.
+#[must_use]
+pub fn enable_light_foreground(tone: f64) -> f64 {
+    if tone_prefers_light_foreground(tone) && !tone_allows_light_foreground(tone) {
+        49.0
+    } else {
+        tone
+    }
+}
+
+// =============================================================================
+// Tone-based WCAG contrast
+// =============================================================================
+
+/// WCAG 2.x contrast ratio between two tones (CIELAB L*).
+fn ratio_of_tones(tone_a: f64, tone_b: f64) -> f64 {
+    let y_a = y_from_lstar(tone_a.clamp(0.0, 100.0)) * 100.0;
+    let y_b = y_from_lstar(tone_b.clamp(0.0, 100.0)) * 100.0;
+    ratio_of_ys(y_a, y_b)
+}
+
+/// WCAG contrast ratio between two relative luminances on a 0–100 scale.
+fn ratio_of_ys(y_a: f64, y_b: f64) -> f64 {
+    (y_a.max(y_b) + 5.0) / (y_a.min(y_b) + 5.0)
+}
+
+/// Lightest-necessary tone at least `ratio` lighter than `tone`, if one exists.
+fn lighter(tone: f64, ratio: f64) -> Option<f64> {
+    if !(0.0..=100.0).contains(&tone) {
+        return None;
+    }
+    let dark_y = y_from_lstar(tone) * 100.0;
+    let light_y = ratio * (dark_y + 5.0) - 5.0;
+    let real_contrast = ratio_of_ys(light_y, dark_y);
+    if real_contrast < ratio && (real_contrast - ratio).abs() > 0.04 {
+        return None;
+    }
+    // Offset guards against rounding in the 8-bit result
+    let answer = lstar_from_y(light_y / 100.0) + 0.4;
+    (0.0..=100.0).contains(&answer).then_some(answer)
+}
+
+/// Darkest-necessary tone at least `ratio` darker than `tone`, if one exists.
+fn darker(tone: f64, ratio: f64) -> Option<f64> {
+    if !(0.0..=100.0).contains(&tone) {
+        return None;
+    }
+    let light_y = y_from_lstar(tone) * 100.0;
+    let dark_y = (light_y + 5.0) / ratio - 5.0;
+    let real_contrast = ratio_of_ys(light_y, dark_y);
+    if real_contrast < ratio && (real_contrast - ratio).abs() > 0.04 {
+        return None;
+    }
+    let answer = lstar_from_y(dark_y / 100.0) - 0.4;
+    (0.0..=100.0).contains(&answer).then_some(answer)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_curve_interpolation() {
+        let curve = ContrastCurve::new(3.0, 4.5, 7.0, 11.0);
+        assert_eq!(curve.get(-2.0), 3.0);
+        assert_eq!(curve.get(-0.5), 3.75);
+        assert_eq!(curve.get(0.0), 4.5);
+        assert_eq!(curve.get(0.25), 5.75);
+        assert_eq!(curve.get(0.75), 9.0);
+        assert_eq!(curve.get(1.0), 11.0);
+    }
+
+    #[test]
+    fn test_ratio_of_tones_extremes() {
+        assert!((ratio_of_tones(0.0, 100.0) - 21.0).abs() < 1e-9);
+        assert!((ratio_of_tones(40.0, 40.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lighter_darker_meet_ratio() {
+        let light = lighter(20.0, 4.5).unwrap();
+        assert!(ratio_of_tones(20.0, light) >= 4.5);
+        let dark = darker(90.0, 7.0).unwrap();
+        assert!(ratio_of_tones(90.0, dark) >= 7.0);
+        // Nothing is 10:1 lighter than tone 80
+        assert_eq!(lighter(80.0, 10.0), None);
+    }
+
+    #[test]
+    fn test_foreground_tone_side() {
+        assert!(foreground_tone(10.0, 4.5) > 50.0);
+        assert!(foreground_tone(90.0, 4.5) < 50.0);
+        assert_eq!(enable_light_foreground(55.0), 49.0);
+        assert_eq!(enable_light_foreground(30.0), 30.0);
+    }
+}
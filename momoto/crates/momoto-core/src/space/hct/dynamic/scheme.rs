@@ -0,0 +1,454 @@
+// =============================================================================
+// Dynamic Schemes
+// File: crates/momoto-core/src/space/hct/dynamic/scheme.rs
+//
+// A DynamicScheme is a source color plus a variant, light/dark mode and
+// contrast level. The variant decides the six tonal palettes; the roles in
+// MaterialDynamicColors then pick tones from those palettes.
+// =============================================================================
+
+use super::super::dislike::fix_if_disliked;
+use super::super::palette::{error_palette, TonalPalette};
+use super::super::temperature::TemperatureCache;
+use super::super::HCT;
+use super::DynamicColor;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// Material Design 3 scheme variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    /// Grayscale: all palettes have zero chroma
+    Monochrome,
+    /// Nearly grayscale with a hint of the source hue
+    Neutral,
+    /// The Android default: calm, low-chroma accents around the source hue
+    #[default]
+    TonalSpot,
+    /// Maximum-chroma primary with hue-rotated secondary and tertiary
+    Vibrant,
+    /// Playful: the primary hue is deliberately moved away from the source
+    Expressive,
+    /// Source color's chroma preserved; tertiary from color temperature
+    Fidelity,
+    /// Like `Fidelity`, with an analogous rather than complementary tertiary
+    Content,
+}
+
+impl Variant {
+    /// All variants, in declaration order.
+    pub const ALL: [Self; 7] = [
+        Self::Monochrome,
+        Self::Neutral,
+        Self::TonalSpot,
+        Self::Vibrant,
+        Self::Expressive,
+        Self::Fidelity,
+        Self::Content,
+    ];
+
+    /// Parse a variant name (`"tonal-spot"`, `"vibrant"`, …).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().replace('_', "-").as_str() {
+            "monochrome" => Some(Self::Monochrome),
+            "neutral" => Some(Self::Neutral),
+            "tonal-spot" | "tonalspot" => Some(Self::TonalSpot),
+            "vibrant" => Some(Self::Vibrant),
+            "expressive" => Some(Self::Expressive),
+            "fidelity" => Some(Self::Fidelity),
+            "content" => Some(Self::Content),
+            _ => None,
+        }
+    }
+
+    /// Canonical name, as accepted by [`Variant::from_name`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Monochrome => "monochrome",
+            Self::Neutral => "neutral",
+            Self::TonalSpot => "tonal-spot",
+            Self::Vibrant => "vibrant",
+            Self::Expressive => "expressive",
+            Self::Fidelity => "fidelity",
+            Self::Content => "content",
+        }
+    }
+}
+
+/// A Material Design 3 color scheme.
+///
+/// Resolve individual roles with [`DynamicScheme::argb`] and the constants in
+/// [`MaterialDynamicColors`](super::MaterialDynamicColors).
+///
+/// # Example
+///
+/// ```rust
+/// use momoto_core::space::hct::HCT;
+/// use momoto_core::space::hct::dynamic::{DynamicScheme, MaterialDynamicColors, Variant};
+///
+/// let scheme = DynamicScheme::new(HCT::from_argb(0xff0000ff), Variant::TonalSpot, false, 0.0);
+/// assert_eq!(scheme.argb(&MaterialDynamicColors::primary()), 0xff555992);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynamicScheme {
+    /// Source color as ARGB
+    pub source_color_argb: u32,
+    /// Source color as HCT
+    pub source_color_hct: HCT,
+    /// Variant used to build the palettes
+    pub variant: Variant,
+    /// Dark theme
+    pub is_dark: bool,
+    /// Contrast level: −1 (reduced), 0 (standard), 0.5 (medium), 1 (high)
+    pub contrast_level: f64,
+    /// Primary accent palette
+    pub primary_palette: TonalPalette,
+    /// Secondary accent palette
+    pub secondary_palette: TonalPalette,
+    /// Tertiary accent palette
+    pub tertiary_palette: TonalPalette,
+    /// Neutral palette (surfaces, backgrounds)
+    pub neutral_palette: TonalPalette,
+    /// Neutral variant palette (outlines, surface variants)
+    pub neutral_variant_palette: TonalPalette,
+    /// Error palette
+    pub error_palette: TonalPalette,
+}
+
+/// Vibrant: hue ranges and the rotations applied within them.
+const VIBRANT_HUES: [f64; 9] = [0.0, 41.0, 61.0, 101.0, 131.0, 181.0, 251.0, 301.0, 360.0];
+const VIBRANT_SECONDARY_ROTATIONS: [f64; 9] = [18.0, 15.0, 10.0, 12.0, 15.0, 18.0, 15.0, 12.0, 12.0];
+const VIBRANT_TERTIARY_ROTATIONS: [f64; 9] = [35.0, 30.0, 20.0, 25.0, 30.0, 35.0, 30.0, 25.0, 25.0];
+
+/// Expressive: hue ranges and the rotations applied within them.
+const EXPRESSIVE_HUES: [f64; 9] = [0.0, 21.0, 51.0, 121.0, 151.0, 191.0, 271.0, 321.0, 360.0];
+const EXPRESSIVE_SECONDARY_ROTATIONS: [f64; 9] =
+    [45.0, 95.0, 45.0, 20.0, 45.0, 90.0, 45.0, 45.0, 45.0];
+const EXPRESSIVE_TERTIARY_ROTATIONS: [f64; 9] =
+    [120.0, 120.0, 20.0, 45.0, 20.0, 15.0, 20.0, 120.0, 120.0];
+
+impl DynamicScheme {
+    /// Build the scheme for `source` with the given variant, mode and contrast.
+    ///
+    /// `contrast_level` is clamped to [−1, 1].
+    #[must_use]
+    pub fn new(source: HCT, variant: Variant, is_dark: bool, contrast_level: f64) -> Self {
+        let hue = source.hue;
+        let chroma = source.chroma;
+        let palette = TonalPalette::from_hue_and_chroma;
+        let rotate = |degrees: f64| (hue + degrees).rem_euclid(360.0);
+
+        let [primary, secondary, tertiary, neutral, neutral_variant] = match variant {
+            Variant::Monochrome => [palette(hue, 0.0); 5],
+            Variant::Neutral => [
+                palette(hue, 12.0),
+                palette(hue, 8.0),
+                palette(hue, 16.0),
+                palette(hue, 2.0),
+                palette(hue, 2.0),
+            ],
+            Variant::TonalSpot => [
+                palette(hue, 36.0),
+                palette(hue, 16.0),
+                palette(rotate(60.0), 24.0),
+                palette(hue, 6.0),
+                palette(hue, 8.0),
+            ],
+            Variant::Vibrant => [
+                palette(hue, 200.0),
+                palette(Self::get_rotated_hue(source, &VIBRANT_HUES, &VIBRANT_SECONDARY_ROTATIONS), 24.0),
+                palette(Self::get_rotated_hue(source, &VIBRANT_HUES, &VIBRANT_TERTIARY_ROTATIONS), 32.0),
+                palette(hue, 10.0),
+                palette(hue, 12.0),
+            ],
+            Variant::Expressive => [
+                palette(rotate(240.0), 40.0),
+                palette(Self::get_rotated_hue(source, &EXPRESSIVE_HUES, &EXPRESSIVE_SECONDARY_ROTATIONS), 24.0),
+                palette(Self::get_rotated_hue(source, &EXPRESSIVE_HUES, &EXPRESSIVE_TERTIARY_ROTATIONS), 32.0),
+                palette(rotate(15.0), 8.0),
+                palette(rotate(15.0), 12.0),
+            ],
+            Variant::Fidelity | Variant::Content => {
+                let temperatures = TemperatureCache::new(source);
+                let tertiary_source = if variant == Variant::Fidelity {
+                    temperatures.complement()
+                } else {
+                    temperatures.analogous(3, 6)[2]
+                };
+                [
+                    palette(hue, chroma),
+                    palette(hue, (chroma - 32.0).max(chroma * 0.5)),
+                    TonalPalette::from_argb(fix_if_disliked(tertiary_source).to_argb()),
+                    palette(hue, chroma / 8.0),
+                    palette(hue, chroma / 8.0 + 4.0),
+                ]
+            }
+        };
+
+        Self {
+            source_color_argb: source.to_argb(),
+            source_color_hct: source,
+            variant,
+            is_dark,
+            contrast_level: contrast_level.clamp(-1.0, 1.0),
+            primary_palette: primary,
+            secondary_palette: secondary,
+            tertiary_palette: tertiary,
+            neutral_palette: neutral,
+            neutral_variant_palette: neutral_variant,
+            error_palette: error_palette(),
+        }
+    }
+
+    /// Build the scheme for an ARGB source color.
+    #[must_use]
+    pub fn from_argb(argb: u32, variant: Variant, is_dark: bool, contrast_level: f64) -> Self {
+        Self::new(HCT::from_argb(argb), variant, is_dark, contrast_level)
+    }
+
+    /// Rotate the source hue by the rotation assigned to the range it falls in.
+    ///
+    /// `hues` are ascending range boundaries (0 to 360); `rotations[i]`
+    /// applies to sources strictly between `hues[i]` and `hues[i + 1]`.
+    #[must_use]
+    pub fn get_rotated_hue(source: HCT, hues: &[f64], rotations: &[f64]) -> f64 {
+        let source_hue = source.hue;
+        if hues.len() == 1 {
+            return (source_hue + rotations[0]).rem_euclid(360.0);
+        }
+        hues.windows(2)
+            .zip(rotations)
+            .find(|(range, _)| range[0] < source_hue && source_hue < range[1])
+            .map_or(source_hue, |(_, rotation)| (source_hue + rotation).rem_euclid(360.0))
+    }
+
+    /// ARGB color of `color` in this scheme.
+    #[must_use]
+    pub fn argb(&self, color: &DynamicColor) -> u32 {
+        color.get_argb(self)
+    }
+
+    /// HCT color of `color` in this scheme.
+    #[must_use]
+    pub fn hct(&self, color: &DynamicColor) -> HCT {
+        color.get_hct(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{ratio_of_tones, MaterialDynamicColors as M};
+    use super::*;
+
+    const BLUE: u32 = 0xff0000ff;
+
+    #[test]
+    fn test_tonal_spot_blue_golden() {
+        // material-color-utilities SchemeTonalSpot, source 0xff0000ff
+        let light = DynamicScheme::from_argb(BLUE, Variant::TonalSpot, false, 0.0);
+        let dark = DynamicScheme::from_argb(BLUE, Variant::TonalSpot, true, 0.0);
+        let cases = [
+            (M::primary(), 0xff555992, 0xffbec2ff),
+            (M::on_primary(), 0xffffffff, 0xff272b60),
+            (M::primary_container(), 0xffe0e0ff, 0xff3e4278),
+            (M::on_primary_container(), 0xff11144b, 0xffe0e0ff),
+            (M::secondary(), 0xff5c5d72, 0xffc5c4dd),
+            (M::tertiary(), 0xff78536b, 0xffe8b9d5),
+            (M::error(), 0xffba1a1a, 0xffffb4ab),
+            (M::surface(), 0xfffbf8ff, 0xff131318),
+            (M::on_surface(), 0xff1b1b21, 0xffe4e1e9),
+            (M::outline(), 0xff777680, 0xff91909a),
+        ];
+        for (role, light_argb, dark_argb) in cases {
+            assert_eq!(light.argb(&role), light_argb, "light {}", role.name);
+            assert_eq!(dark.argb(&role), dark_argb, "dark {}", role.name);
+        }
+    }
+
+    // Regression values for the other variants and contrast levels. They
+    // are pinned from this implementation, not copied from
+    // material-color-utilities tests, and each one also agrees with a
+    // separate evaluation of the tone rules on the third-party
+    // material-color-utilities-rs HCT solver.
+
+    /// Assert light and dark colors for `BLUE` at one variant and contrast.
+    fn assert_regression(variant: Variant, contrast: f64, cases: &[(DynamicColor, u32, u32)]) {
+        let light = DynamicScheme::from_argb(BLUE, variant, false, contrast);
+        let dark = DynamicScheme::from_argb(BLUE, variant, true, contrast);
+        for (role, light_argb, dark_argb) in cases {
+            let label = format!("{} {} @ {contrast}", variant.name(), role.name);
+            assert_eq!(light.argb(role), *light_argb, "light {label}");
+            assert_eq!(dark.argb(role), *dark_argb, "dark {label}");
+        }
+    }
+
+    #[test]
+    fn test_vibrant_blue_regression() {
+        assert_regression(
+            Variant::Vibrant,
+            0.0,
+            &[
+                (M::primary(), 0xff343dff, 0xffbec2ff),
+                (M::on_primary(), 0xffffffff, 0xff0001ac),
+                (M::on_primary_container(), 0xff00006e, 0xffe0e0ff),
+                (M::secondary(), 0xff62597c, 0xffccc1e9),
+                (M::secondary_container(), 0xffe8ddff, 0xff4a4263),
+                (M::tertiary(), 0xff6e5483, 0xffdbbaf1),
+                (M::surface(), 0xfffbf8ff, 0xff12131c),
+                (M::on_surface(), 0xff1a1b25, 0xffe3e1ef),
+                (M::outline(), 0xff767685, 0xff908f9f),
+                (M::on_surface_variant(), 0xff454654, 0xffc6c5d6),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_medium_and_high_contrast_regression() {
+        assert_regression(
+            Variant::TonalSpot,
+            0.5,
+            &[
+                (M::primary(), 0xff2d3167, 0xffd9d9ff),
+                (M::on_primary(), 0xffffffff, 0xff1c1f55),
+                (M::primary_container(), 0xff6468a2, 0xff888cc8),
+                (M::on_primary_container(), 0xffffffff, 0xff000000),
+                (M::secondary(), 0xff343548, 0xffdbdaf3),
+                (M::on_surface(), 0xff111116, 0xffffffff),
+                (M::outline(), 0xff52525b, 0xffb2b1bb),
+            ],
+        );
+
+        assert_regression(
+            Variant::TonalSpot,
+            1.0,
+            &[
+                (M::primary(), 0xff22265c, 0xfff0eeff),
+                (M::on_primary(), 0xffffffff, 0xff000000),
+                (M::primary_container(), 0xff40447b, 0xffbabefd),
+                (M::on_primary_container(), 0xffffffff, 0xff000000),
+                (M::secondary(), 0xff2a2b3d, 0xfff0eeff),
+                (M::on_surface(), 0xff000000, 0xffffffff),
+                (M::outline(), 0xff2b2b34, 0xfff1eefa),
+            ],
+        );
+
+        assert_regression(
+            Variant::Vibrant,
+            0.5,
+            &[
+                (M::primary(), 0xff0001bd, 0xffd9d9ff),
+                (M::primary_container(), 0xff4b55ff, 0xff7c84ff),
+                (M::secondary(), 0xff393152, 0xffe2d6ff),
+                (M::tertiary(), 0xff442b58, 0xffeed2ff),
+                (M::outline(), 0xff515160, 0xffb1b0c1),
+            ],
+        );
+
+        assert_regression(
+            Variant::Vibrant,
+            1.0,
+            &[
+                (M::primary(), 0xff00019f, 0xfff0eeff),
+                (M::secondary(), 0xff2f2747, 0xfff5edff),
+                (M::tertiary(), 0xff39214d, 0xfffaebff),
+                (M::on_surface_variant(), 0xff000000, 0xffffffff),
+                (M::outline(), 0xff2b2b38, 0xfff0eeff),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_pairs_meet_contrast_curve() {
+        let pairs = [
+            (M::on_primary(), M::primary()),
+            (M::on_primary_container(), M::primary_container()),
+            (M::on_secondary_container(), M::secondary_container()),
+            (M::on_tertiary_container(), M::tertiary_container()),
+            (M::on_error_container(), M::error_container()),
+            (M::on_surface(), M::surface_dim()),
+            (M::on_primary_fixed(), M::primary_fixed_dim()),
+        ];
+        for source in [BLUE, 0xffff0000, 0xff00ff00, 0xff95884b, 0xff808080] {
+            for variant in Variant::ALL {
+                for is_dark in [false, true] {
+                    for contrast in [-1.0, 0.0, 0.5, 1.0] {
+                        let scheme = DynamicScheme::from_argb(source, variant, is_dark, contrast);
+                        for (fg, bg) in pairs {
+                            let target = fg.contrast_curve.unwrap().get(contrast);
+                            let bg_tone = scheme.hct(&bg).tone;
+                            let ratio = ratio_of_tones(scheme.hct(&fg).tone, bg_tone);
+                            // Targets beyond black or white on this background are unreachable
+                            let best = ratio_of_tones(bg_tone, 0.0).max(ratio_of_tones(bg_tone, 100.0));
+                            assert!(
+                                ratio >= target.min(best) - 0.1,
+                                "{source:08x} {} dark={is_dark} contrast={contrast} {}: {ratio:.2} < {target}",
+                                variant.name(),
+                                fg.name,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_container_pair_keeps_delta() {
+        for is_dark in [false, true] {
+            for contrast in [-1.0, 0.0, 1.0] {
+                let scheme = DynamicScheme::from_argb(BLUE, Variant::Vibrant, is_dark, contrast);
+                let accent = M::primary().get_tone(&scheme);
+                let container = M::primary_container().get_tone(&scheme);
+                assert!((accent - container).abs() >= 10.0 - 1e-9, "{accent} vs {container}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_monochrome_is_gray() {
+        let scheme = DynamicScheme::from_argb(BLUE, Variant::Monochrome, false, 0.0);
+        for role in M::all() {
+            let argb = scheme.argb(&role);
+            if role.name.contains("error") {
+                continue;
+            }
+            let (r, g, b) = ((argb >> 16) & 0xff, (argb >> 8) & 0xff, argb & 0xff);
+            assert!(r == g && g == b, "{} = {argb:08x}", role.name);
+        }
+    }
+
+    #[test]
+    fn test_fidelity_keeps_source_chroma() {
+        let blue = HCT::from_argb(BLUE);
+        let scheme = DynamicScheme::new(blue, Variant::Fidelity, false, 0.0);
+        assert_eq!(scheme.primary_palette.chroma(), blue.chroma);
+        // Primary container sits at the source tone
+        assert!((M::primary_container().get_tone(&scheme) - blue.tone).abs() < 1e-9);
+        let content = DynamicScheme::new(blue, Variant::Content, false, 0.0);
+        assert_ne!(content.tertiary_palette, scheme.tertiary_palette);
+    }
+
+    #[test]
+    fn test_rotated_hue() {
+        let source = HCT::new(50.0, 40.0, 50.0);
+        // 50° is in the 41–61 range of Vibrant: secondary +15
+        let hue = DynamicScheme::get_rotated_hue(source, &VIBRANT_HUES, &VIBRANT_SECONDARY_ROTATIONS);
+        assert_eq!(hue, 65.0);
+        let wrapped = HCT::new(350.0, 40.0, 50.0);
+        let hue = DynamicScheme::get_rotated_hue(wrapped, &EXPRESSIVE_HUES, &EXPRESSIVE_TERTIARY_ROTATIONS);
+        assert_eq!(hue, 110.0);
+    }
+
+    #[test]
+    fn test_variant_names_roundtrip() {
+        for variant in Variant::ALL {
+            assert_eq!(Variant::from_name(variant.name()), Some(variant));
+        }
+        assert_eq!(Variant::from_name("TONAL_SPOT"), Some(Variant::TonalSpot));
+        assert_eq!(Variant::from_name("rainbow"), None);
+    }
+}
@@ -15,6 +15,15 @@
 // =============================================================================
 
 pub mod cam16;
+/// Hue-based "disliked color" detection (dark yellow-greens).
+pub mod dislike;
+/// Material Design 3 dynamic colors and schemes.
+pub mod dynamic;
+/// Material Design 3 tonal and core palettes.
+pub mod palette;
+mod solver;
+/// Color temperature theory for analogous and complementary colors.
+pub mod temperature;
 
 use crate::color::Color;
 use cam16::{CAM16, ViewingConditions, lstar_from_y, y_from_lstar, mat3_mul_vec3};
@@ -23,18 +32,21 @@ use cam16::{CAM16, ViewingConditions, lstar_from_y, y_from_lstar, mat3_mul_vec3}
 // sRGB ↔ XYZ matrices (D65 reference white)
 // =============================================================================
 
-/// Linear sRGB → XYZ D65 (IEC 61966-2-1)
+/// Linear sRGB → XYZ D65, as used by material-color-utilities.
+///
+/// The Y row is exactly the Rec. 709 luminance coefficients so that tones
+/// match the Material reference implementation bit-for-bit.
 const M_SRGB_TO_XYZ: [[f64; 3]; 3] = [
-    [0.4124564, 0.3575761, 0.1804375],
-    [0.2126729, 0.7151522, 0.0721750],
-    [0.0193339, 0.1191920, 0.9503041],
+    [0.41233895, 0.35762064, 0.18051042],
+    [0.2126,     0.7152,     0.0722    ],
+    [0.01932141, 0.11916382, 0.95034478],
 ];
 
 /// XYZ D65 → linear sRGB (inverse of above)
 const M_XYZ_TO_SRGB: [[f64; 3]; 3] = [
-    [ 3.2404542, -1.5371385, -0.4985314],
-    [-0.9692660,  1.8760108,  0.0415560],
-    [ 0.0556434, -0.2040259,  1.0572252],
+    [ 3.2413774792388685, -1.5376652402851851, -0.49885366846268053],
+    [-0.9691452513005321,  1.8758853451067872,  0.04156585616912061],
+    [ 0.05562093689691305, -0.20395524564742123, 1.0571799111220335 ],
 ];
 
 // =============================================================================
@@ -76,6 +88,17 @@ impl HCT {
         Self { hue, chroma, tone }
     }
 
+    /// Resolve a requested hue, chroma and tone to the closest sRGB color.
+    ///
+    /// Unlike [`HCT::new`], the returned components describe the color that
+    /// is actually displayed: chroma is reduced to the gamut boundary and all
+    /// three values are re-measured from the 8-bit result. This is `Hct.from`
+    /// in material-color-utilities.
+    #[must_use]
+    pub fn solve(hue: f64, chroma: f64, tone: f64) -> Self {
+        Self::from_argb(Self::new(hue, chroma, tone).to_argb())
+    }
+
     // =========================================================================
     // Forward conversion: Color → HCT
     // =========================================================================
@@ -99,10 +122,7 @@ impl HCT {
 
     /// Create HCT from a packed ARGB integer (0xAARRGGBB, alpha ignored).
     pub fn from_argb(argb: u32) -> Self {
-        let r = ((argb >> 16) & 0xFF) as u8;
-        let g = ((argb >> 8) & 0xFF) as u8;
-        let b = (argb & 0xFF) as u8;
-        Self::from_color(&Color::from_srgb8(r, g, b))
+        Self::from_color(&color_from_argb(argb))
     }
 
     // =========================================================================
@@ -112,49 +132,14 @@ impl HCT {
     /// Convert HCT back to an sRGB color.
     ///
     /// If the requested chroma exceeds the gamut boundary at this hue and tone,
-    /// it is clamped to the maximum achievable in-gamut chroma.
+    /// it is clamped to the maximum achievable in-gamut chroma. Tone is always
+    /// preserved exactly.
     ///
-    /// Uses a binary search over CAM16 J values to guarantee that the Y
-    /// component of the resulting XYZ corresponds exactly to the target
-    /// CIELAB L* tone (following the material-color-utilities HctSolver approach).
+    /// Uses the material-color-utilities HctSolver: Newton iteration on CAM16 J,
+    /// falling back to bisection along the sRGB cube's constant-Y slice.
     pub fn to_color(&self) -> Color {
-        let vc = ViewingConditions::s_rgb();
-
-        // Achromatic shortcut
-        if self.chroma < 1e-4 {
-            return tone_to_achromatic_color(self.tone);
-        }
-
-        // Target Y (XYZ scale [0, 100]) corresponding to the requested tone
-        let target_y = y_from_lstar(self.tone) * 100.0;
-
-        if target_y <= 0.0 {
-            return Color::from_srgb8(0, 0, 0);
-        }
-
-        // Binary search for J such that CAM16⁻¹(J, C, H)[Y] ≈ target_Y.
-        // Different from using J of an achromatic gray because chromatic colors
-        // can have Y ≠ Y_gray at the same J (CAM16 J ≠ CIELAB L*).
-        let mut j_lo = 0.0_f64;
-        let mut j_hi = 100.0_f64;
-
-        for _ in 0..50 {
-            let j_mid = (j_lo + j_hi) / 2.0;
-            let max_c = find_max_chroma(j_mid, self.hue, &vc);
-            let actual_c = self.chroma.min(max_c);
-            let xyz = CAM16::to_xyz_from_jch(j_mid, actual_c, self.hue, &vc);
-            if xyz[1] < target_y {
-                j_lo = j_mid;
-            } else {
-                j_hi = j_mid;
-            }
-        }
-
-        let j = (j_lo + j_hi) / 2.0;
-        let max_c = find_max_chroma(j, self.hue, &vc);
-        let actual_c = self.chroma.min(max_c);
-        let xyz = CAM16::to_xyz_from_jch(j, actual_c, self.hue, &vc);
-        xyz_to_color(xyz)
+        let [r, g, b] = solver::solve(self.hue, self.chroma, self.tone);
+        Color::from_linear(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
     }
 
     /// Convert HCT to a packed ARGB integer (0xFF_RR_GG_BB).
@@ -221,6 +206,14 @@ fn is_xyz_in_srgb_gamut(xyz: [f64; 3]) -> bool {
 // Color conversion helpers
 // =============================================================================
 
+/// Packed ARGB (0xAARRGGBB, alpha ignored) → sRGB Color.
+fn color_from_argb(argb: u32) -> Color {
+    let r = ((argb >> 16) & 0xFF) as u8;
+    let g = ((argb >> 8) & 0xFF) as u8;
+    let b = (argb & 0xFF) as u8;
+    Color::from_srgb8(r, g, b)
+}
+
 /// Linear sRGB (in [0, 1]) → XYZ D65 (in [0, 100]).
 fn linear_srgb_to_xyz(linear: [f64; 3]) -> [f64; 3] {
     // Scale by 100 so white Y = 100
@@ -228,23 +221,6 @@ fn linear_srgb_to_xyz(linear: [f64; 3]) -> [f64; 3] {
     [xyz_norm[0] * 100.0, xyz_norm[1] * 100.0, xyz_norm[2] * 100.0]
 }
 
-/// XYZ D65 (in [0, 100]) → sRGB Color.
-fn xyz_to_color(xyz: [f64; 3]) -> Color {
-    let xyz_norm = [xyz[0] / 100.0, xyz[1] / 100.0, xyz[2] / 100.0];
-    let rgb_lin = mat3_mul_vec3(&M_XYZ_TO_SRGB, xyz_norm);
-    Color::from_linear(
-        rgb_lin[0].clamp(0.0, 1.0),
-        rgb_lin[1].clamp(0.0, 1.0),
-        rgb_lin[2].clamp(0.0, 1.0),
-    )
-}
-
-/// Create an achromatic (gray) color for a given CIELAB tone.
-fn tone_to_achromatic_color(tone: f64) -> Color {
-    let y = y_from_lstar(tone); // [0, 1]
-    Color::from_linear(y as f64, y as f64, y as f64)
-}
-
 // =============================================================================
 // Tests
 // =============================================================================
@@ -365,4 +341,35 @@ mod tests {
             assert!(ch >= -0.01 && ch <= 1.01, "Channel out of gamut: {}", ch);
         }
     }
+
+    /// HCT of the sRGB primaries matches material-color-utilities
+    #[test]
+    fn test_primaries_match_reference() {
+        let cases = [
+            (0xffff0000, 27.408, 113.357, 53.233),
+            (0xff00ff00, 142.139, 108.410, 87.737),
+            (0xff0000ff, 282.788, 87.230, 32.302),
+        ];
+        for (argb, hue, chroma, tone) in cases {
+            let hct = HCT::from_argb(argb);
+            assert!((hct.hue - hue).abs() < 0.001, "{argb:08x} hue {}", hct.hue);
+            assert!((hct.chroma - chroma).abs() < 0.001, "{argb:08x} chroma {}", hct.chroma);
+            assert!((hct.tone - tone).abs() < 0.001, "{argb:08x} tone {}", hct.tone);
+            assert_eq!(hct.to_argb(), argb);
+        }
+    }
+
+    /// Out-of-gamut chroma keeps hue and tone, reducing only chroma
+    #[test]
+    fn test_solve_out_of_gamut() {
+        for hue in [0.0, 60.0, 120.0, 180.0, 240.0, 300.0] {
+            for tone in [10.0, 50.0, 90.0] {
+                let solved = HCT::solve(hue, 200.0, tone);
+                assert!((solved.tone - tone).abs() < 0.5, "tone {} vs {tone}", solved.tone);
+                assert!(solved.chroma < 200.0);
+                let dh = (solved.hue - hue).abs();
+                assert!(dh.min(360.0 - dh) < 3.0, "hue {} vs {hue}", solved.hue);
+            }
+        }
+    }
 }
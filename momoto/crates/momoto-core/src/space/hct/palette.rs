@@ -0,0 +1,269 @@
+// =============================================================================
+// Material Design 3 Palettes
+// File: crates/momoto-core/src/space/hct/palette.rs
+//
+// TonalPalette: one hue and chroma, any tone 0–100.
+// CorePalette:  the five key tonal palettes (plus error) of an M3 theme.
+//
+// Reference: Google material-color-utilities (Apache 2.0)
+// https://github.com/material-foundation/material-color-utilities
+// =============================================================================
+
+use super::HCT;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// Tones generated for a Material theme's tonal palettes.
+pub const STANDARD_TONES: [f64; 13] = [
+    0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 95.0, 99.0, 100.0,
+];
+
+// =============================================================================
+// TonalPalette
+// =============================================================================
+
+/// A hue and chroma from which colors at any tone can be produced.
+///
+/// Colors are solved with [`HCT::solve`], so requesting a chroma the gamut
+/// cannot reach at a given tone yields the most chromatic in-gamut color.
+///
+/// # Example
+///
+/// ```rust
+/// use momoto_core::space::hct::palette::TonalPalette;
+///
+/// let blue = TonalPalette::from_argb(0xff0000ff);
+/// assert_eq!(blue.tone(90.0), 0xffe0e0ff);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TonalPalette {
+    hue: f64,
+    chroma: f64,
+    key_color: HCT,
+}
+
+impl TonalPalette {
+    /// Palette with the hue and chroma of an ARGB color.
+    #[must_use]
+    pub fn from_argb(argb: u32) -> Self {
+        Self::from_hct(HCT::from_argb(argb))
+    }
+
+    /// Palette with the hue and chroma of `hct`, which also becomes the key color.
+    #[must_use]
+    pub fn from_hct(hct: HCT) -> Self {
+        Self {
+            hue: hct.hue,
+            chroma: hct.chroma,
+            key_color: hct,
+        }
+    }
+
+    /// Palette with the given hue and chroma.
+    ///
+    /// The key color is the tone closest to 50 that can display `chroma`.
+    #[must_use]
+    pub fn from_hue_and_chroma(hue: f64, chroma: f64) -> Self {
+        Self {
+            hue,
+            chroma,
+            key_color: key_color(hue, chroma),
+        }
+    }
+
+    /// CAM16 hue of the palette.
+    #[inline]
+    #[must_use]
+    pub fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    /// Requested CAM16 chroma of the palette.
+    #[inline]
+    #[must_use]
+    pub fn chroma(&self) -> f64 {
+        self.chroma
+    }
+
+    /// Key color: the palette's hue and chroma at a representative tone.
+    #[inline]
+    #[must_use]
+    pub fn key_color(&self) -> HCT {
+        self.key_color
+    }
+
+    /// ARGB color at `tone` (0–100).
+    #[must_use]
+    pub fn tone(&self, tone: f64) -> u32 {
+        HCT::new(self.hue, self.chroma, tone).to_argb()
+    }
+
+    /// HCT color at `tone` (0–100), re-measured after gamut mapping.
+    #[must_use]
+    pub fn get_hct(&self, tone: f64) -> HCT {
+        HCT::solve(self.hue, self.chroma, tone)
+    }
+
+    /// ARGB colors at [`STANDARD_TONES`].
+    #[must_use]
+    pub fn standard_tones(&self) -> [u32; 13] {
+        STANDARD_TONES.map(|t| self.tone(t))
+    }
+}
+
+/// Tone closest to 50 whose solved chroma best matches `chroma`.
+fn key_color(hue: f64, chroma: f64) -> HCT {
+    const START_TONE: f64 = 50.0;
+    let mut best = HCT::solve(hue, chroma, START_TONE);
+    let mut best_delta = (best.chroma - chroma).abs();
+    let mut delta = 1.0;
+    while delta < 50.0 {
+        // Good enough: the rounded chroma already matches
+        if chroma.round() == best.chroma.round() {
+            return best;
+        }
+        for tone in [START_TONE + delta, START_TONE - delta] {
+            let candidate = HCT::solve(hue, chroma, tone);
+            let candidate_delta = (candidate.chroma - chroma).abs();
+            if candidate_delta < best_delta {
+                best_delta = candidate_delta;
+                best = candidate;
+            }
+        }
+        delta += 1.0;
+    }
+    best
+}
+
+// =============================================================================
+// CorePalette
+// =============================================================================
+
+/// The key tonal palettes of a Material Design 3 theme.
+///
+/// `a1`–`a3` are the accent palettes (primary, secondary, tertiary),
+/// `n1`/`n2` the neutral and neutral-variant palettes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorePalette {
+    /// Primary accent palette
+    pub a1: TonalPalette,
+    /// Secondary accent palette
+    pub a2: TonalPalette,
+    /// Tertiary accent palette
+    pub a3: TonalPalette,
+    /// Neutral palette
+    pub n1: TonalPalette,
+    /// Neutral variant palette
+    pub n2: TonalPalette,
+    /// Error palette
+    pub error: TonalPalette,
+}
+
+impl CorePalette {
+    /// Standard M3 palettes for a source color: chroma is normalized so that
+    /// every source produces a similarly vivid theme.
+    #[must_use]
+    pub fn of(argb: u32) -> Self {
+        let hct = HCT::from_argb(argb);
+        let (hue, chroma) = (hct.hue, hct.chroma);
+        Self {
+            a1: TonalPalette::from_hue_and_chroma(hue, chroma.max(48.0)),
+            a2: TonalPalette::from_hue_and_chroma(hue, 16.0),
+            a3: TonalPalette::from_hue_and_chroma((hue + 60.0).rem_euclid(360.0), 24.0),
+            n1: TonalPalette::from_hue_and_chroma(hue, 4.0),
+            n2: TonalPalette::from_hue_and_chroma(hue, 8.0),
+            error: error_palette(),
+        }
+    }
+
+    /// Content palettes: chroma follows the source color, for themes derived
+    /// from images or other content.
+    #[must_use]
+    pub fn content_of(argb: u32) -> Self {
+        let hct = HCT::from_argb(argb);
+        let (hue, chroma) = (hct.hue, hct.chroma);
+        Self {
+            a1: TonalPalette::from_hue_and_chroma(hue, chroma),
+            a2: TonalPalette::from_hue_and_chroma(hue, chroma / 3.0),
+            a3: TonalPalette::from_hue_and_chroma((hue + 60.0).rem_euclid(360.0), chroma / 2.0),
+            n1: TonalPalette::from_hue_and_chroma(hue, (chroma / 12.0).min(4.0)),
+            n2: TonalPalette::from_hue_and_chroma(hue, (chroma / 6.0).min(8.0)),
+            error: error_palette(),
+        }
+    }
+}
+
+/// The fixed M3 error palette.
+pub(crate) fn error_palette() -> TonalPalette {
+    TonalPalette::from_hue_and_chroma(25.0, 84.0)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blue_palette_golden() {
+        // material-color-utilities TonalPalette.fromInt(0xff0000ff)
+        let blue = TonalPalette::from_argb(0xff0000ff);
+        let expected = [
+            (100.0, 0xffffffff),
+            (95.0, 0xfff1efff),
+            (90.0, 0xffe0e0ff),
+            (80.0, 0xffbec2ff),
+            (70.0, 0xff9da3ff),
+            (60.0, 0xff7c84ff),
+            (50.0, 0xff5a64ff),
+            (40.0, 0xff343dff),
+            (30.0, 0xff0000ef),
+            (20.0, 0xff0001ac),
+            (10.0, 0xff00006e),
+            (0.0, 0xff000000),
+        ];
+        for (tone, argb) in expected {
+            assert_eq!(blue.tone(tone), argb, "tone {tone}: {:08x}", blue.tone(tone));
+        }
+    }
+
+    #[test]
+    fn test_key_color_reaches_requested_chroma() {
+        let palette = TonalPalette::from_hue_and_chroma(270.0, 16.0);
+        let key = palette.key_color();
+        assert!((key.chroma - 16.0).abs() < 0.5, "key chroma {}", key.chroma);
+        assert!((key.tone - 50.0).abs() < 1.0, "key tone {}", key.tone);
+    }
+
+    #[test]
+    fn test_key_color_unreachable_chroma_picks_most_chromatic() {
+        // Chroma 200 is out of gamut everywhere; key color should still be vivid
+        let palette = TonalPalette::from_hue_and_chroma(149.0, 200.0);
+        assert!(palette.key_color().chroma > 60.0);
+    }
+
+    #[test]
+    fn test_core_palette_chroma_rules() {
+        // Low-chroma source: standard palette boosts a1 to 48, content keeps it
+        let gray_blue = 0xff607080;
+        let core = CorePalette::of(gray_blue);
+        let content = CorePalette::content_of(gray_blue);
+        assert_eq!(core.a1.chroma(), 48.0);
+        assert!(content.a1.chroma() < 48.0);
+        assert_eq!(core.a2.chroma(), 16.0);
+        assert_eq!(core.n1.chroma(), 4.0);
+        assert!((core.a3.hue() - (core.a1.hue() + 60.0).rem_euclid(360.0)).abs() < 1e-9);
+        assert_eq!(core.error.hue(), 25.0);
+        assert_eq!(core.error.chroma(), 84.0);
+    }
+
+    #[test]
+    fn test_standard_tones_endpoints() {
+        let tones = TonalPalette::from_hue_and_chroma(120.0, 30.0).standard_tones();
+        assert_eq!(tones[0], 0xff000000);
+        assert_eq!(tones[12], 0xffffffff);
+    }
+}
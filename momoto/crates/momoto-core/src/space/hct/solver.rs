@@ -0,0 +1,305 @@
+// =============================================================================
+// HCT Solver
+// File: crates/momoto-core/src/space/hct/solver.rs
+//
+// Finds the sRGB color with a requested CAM16 hue and chroma and CIELAB L*,
+// falling back to the most chromatic in-gamut color of that hue and L* when
+// the request is out of gamut.
+//
+// Port of HctSolver from Google material-color-utilities (Apache 2.0):
+//   1. Newton iteration on J for the exact answer (`find_result_by_j`).
+//   2. Otherwise, bisection along the edges of the constant-Y plane through
+//      the sRGB cube, refined on the critical planes where 8-bit sRGB values
+//      change (`bisect_to_limit`).
+//
+// All linear RGB values in this file use the [0, 100] scale.
+// =============================================================================
+
+use core::f64::consts::PI;
+
+use super::cam16::{mat3_mul_vec3, y_from_lstar, ViewingConditions, M16};
+use super::M_SRGB_TO_XYZ;
+use crate::math::ops;
+use crate::space::xyz::{mat3_inverse, mat3_mul};
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// Linear sRGB → relative luminance Y.
+const Y_FROM_LINRGB: [f64; 3] = [0.2126, 0.7152, 0.0722];
+
+/// Precomputed viewing-condition terms used by every solve.
+struct Solver {
+    vc: ViewingConditions,
+    /// Linear sRGB → cone responses already scaled by D and F_L / 100
+    scaled_discount_from_linrgb: [[f64; 3]; 3],
+    /// Inverse of `scaled_discount_from_linrgb`
+    linrgb_from_scaled_discount: [[f64; 3]; 3],
+}
+
+impl Solver {
+    fn new() -> Self {
+        let vc = ViewingConditions::s_rgb();
+        let mut scaled = mat3_mul(&M16, &M_SRGB_TO_XYZ);
+        for (row, d) in scaled.iter_mut().zip(vc.rgb_d) {
+            for v in row.iter_mut() {
+                *v *= d * vc.fl / 100.0;
+            }
+        }
+        Self {
+            linrgb_from_scaled_discount: mat3_inverse(&scaled),
+            scaled_discount_from_linrgb: scaled,
+            vc,
+        }
+    }
+
+    /// CAM16 hue (radians) of a linear RGB color.
+    fn hue_of(&self, linrgb: [f64; 3]) -> f64 {
+        let sd = mat3_mul_vec3(&self.scaled_discount_from_linrgb, linrgb);
+        let r_a = chromatic_adaptation(sd[0]);
+        let g_a = chromatic_adaptation(sd[1]);
+        let b_a = chromatic_adaptation(sd[2]);
+        let a = (11.0 * r_a - 12.0 * g_a + b_a) / 11.0;
+        let b = (r_a + g_a - 2.0 * b_a) / 9.0;
+        ops::atan2(b, a)
+    }
+
+    /// Exact solution by Newton iteration on J, or `None` if out of gamut.
+    fn find_result_by_j(&self, hue_radians: f64, chroma: f64, y: f64) -> Option<[f64; 3]> {
+        let vc = &self.vc;
+        let mut j = y.sqrt() * 11.0;
+        let t_inner_coeff = 1.0 / ops::powf(1.64 - ops::powf(0.29_f64, vc.n), 0.73);
+        let e_hue = 0.25 * (ops::cos(hue_radians + 2.0) + 3.8);
+        let p1 = e_hue * (50_000.0 / 13.0) * vc.nc * vc.ncb;
+        let h_sin = ops::sin(hue_radians);
+        let h_cos = ops::cos(hue_radians);
+
+        for round in 0..5 {
+            let j_normalized = j / 100.0;
+            let alpha = if chroma == 0.0 || j == 0.0 {
+                0.0
+            } else {
+                chroma / j_normalized.sqrt()
+            };
+            let t = ops::powf(alpha * t_inner_coeff, 1.0 / 0.9);
+            let ac = vc.aw * ops::powf(j_normalized, 1.0 / vc.c / vc.z);
+            let p2 = ac / vc.nbb;
+            let gamma = 23.0 * (p2 + 0.305) * t
+                / (23.0 * p1 + 11.0 * t * h_cos + 108.0 * t * h_sin);
+            let a = gamma * h_cos;
+            let b = gamma * h_sin;
+            let r_a = (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0;
+            let g_a = (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0;
+            let b_a = (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0;
+            let scaled = [
+                inverse_chromatic_adaptation(r_a),
+                inverse_chromatic_adaptation(g_a),
+                inverse_chromatic_adaptation(b_a),
+            ];
+            let linrgb = mat3_mul_vec3(&self.linrgb_from_scaled_discount, scaled);
+            if linrgb.iter().any(|&v| v < 0.0) {
+                return None;
+            }
+            let fnj = dot(Y_FROM_LINRGB, linrgb);
+            if fnj <= 0.0 {
+                return None;
+            }
+            if round == 4 || (fnj - y).abs() < 0.002 {
+                if linrgb.iter().any(|&v| v > 100.01) {
+                    return None;
+                }
+                return Some(linrgb);
+            }
+            // Newton step; derivative approximated by 2 * fnj / j
+            j -= (fnj - y) * j / (2.0 * fnj);
+        }
+        None
+    }
+
+    /// The two vertices of the constant-Y polygon that bracket `target_hue`.
+    fn bisect_to_segment(&self, y: f64, target_hue: f64) -> ([f64; 3], [f64; 3]) {
+        let mut left = [-1.0; 3];
+        let mut right = left;
+        let mut left_hue = 0.0;
+        let mut right_hue = 0.0;
+        let mut initialized = false;
+        let mut uncut = true;
+        for n in 0..12 {
+            let Some(mid) = nth_vertex(y, n) else {
+                continue;
+            };
+            let mid_hue = self.hue_of(mid);
+            if !initialized {
+                left = mid;
+                right = mid;
+                left_hue = mid_hue;
+                right_hue = mid_hue;
+                initialized = true;
+                continue;
+            }
+            if uncut || are_in_cyclic_order(left_hue, mid_hue, right_hue) {
+                uncut = false;
+                if are_in_cyclic_order(left_hue, target_hue, mid_hue) {
+                    right = mid;
+                    right_hue = mid_hue;
+                } else {
+                    left = mid;
+                    left_hue = mid_hue;
+                }
+            }
+        }
+        (left, right)
+    }
+
+    /// Most chromatic in-gamut linear RGB of luminance `y` and hue `target_hue`.
+    fn bisect_to_limit(&self, y: f64, target_hue: f64) -> [f64; 3] {
+        let (mut left, mut right) = self.bisect_to_segment(y, target_hue);
+        let mut left_hue = self.hue_of(left);
+        for axis in 0..3 {
+            if left[axis] == right[axis] {
+                continue;
+            }
+            let (mut l_plane, mut r_plane) = if left[axis] < right[axis] {
+                (
+                    critical_plane_below(true_delinearized(left[axis])),
+                    critical_plane_above(true_delinearized(right[axis])),
+                )
+            } else {
+                (
+                    critical_plane_above(true_delinearized(left[axis])),
+                    critical_plane_below(true_delinearized(right[axis])),
+                )
+            };
+            for _ in 0..8 {
+                if (r_plane - l_plane).abs() <= 1 {
+                    break;
+                }
+                let m_plane = (l_plane + r_plane).div_euclid(2);
+                let mid = set_coordinate(left, critical_plane(m_plane), right, axis);
+                let mid_hue = self.hue_of(mid);
+                if are_in_cyclic_order(left_hue, target_hue, mid_hue) {
+                    right = mid;
+                    r_plane = m_plane;
+                } else {
+                    left = mid;
+                    left_hue = mid_hue;
+                    l_plane = m_plane;
+                }
+            }
+        }
+        [
+            (left[0] + right[0]) / 2.0,
+            (left[1] + right[1]) / 2.0,
+            (left[2] + right[2]) / 2.0,
+        ]
+    }
+}
+
+/// Linear sRGB (0–1) of the color with the given hue, chroma and L*.
+///
+/// Out-of-gamut requests return the most chromatic color with the same hue
+/// and L*. Components may exceed [0, 1] by rounding error only.
+pub(super) fn solve(hue_degrees: f64, chroma: f64, lstar: f64) -> [f64; 3] {
+    let y = y_from_lstar(lstar);
+    if chroma < 0.0001 || !(0.0001..=99.9999).contains(&lstar) {
+        return [y, y, y];
+    }
+    let solver = Solver::new();
+    let hue_radians = hue_degrees.rem_euclid(360.0).to_radians();
+    let y = y * 100.0;
+    let linrgb = solver
+        .find_result_by_j(hue_radians, chroma, y)
+        .unwrap_or_else(|| solver.bisect_to_limit(y, hue_radians));
+    [linrgb[0] / 100.0, linrgb[1] / 100.0, linrgb[2] / 100.0]
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn chromatic_adaptation(component: f64) -> f64 {
+    let af = ops::powf(component.abs(), 0.42);
+    component.signum() * 400.0 * af / (af + 27.13)
+}
+
+fn inverse_chromatic_adaptation(adapted: f64) -> f64 {
+    let abs = adapted.abs();
+    let base = (27.13 * abs / (400.0 - abs)).max(0.0);
+    adapted.signum() * ops::powf(base, 1.0 / 0.42)
+}
+
+fn sanitize_radians(angle: f64) -> f64 {
+    (angle + PI * 8.0) % (PI * 2.0)
+}
+
+/// Whether `b` lies on the counter-clockwise arc from `a` to `c`.
+fn are_in_cyclic_order(a: f64, b: f64, c: f64) -> bool {
+    sanitize_radians(b - a) < sanitize_radians(c - a)
+}
+
+/// Point on the segment `source`–`target` whose `axis` equals `coordinate`.
+fn set_coordinate(source: [f64; 3], coordinate: f64, target: [f64; 3], axis: usize) -> [f64; 3] {
+    let t = (coordinate - source[axis]) / (target[axis] - source[axis]);
+    [
+        source[0] + (target[0] - source[0]) * t,
+        source[1] + (target[1] - source[1]) * t,
+        source[2] + (target[2] - source[2]) * t,
+    ]
+}
+
+fn is_bounded(x: f64) -> bool {
+    (0.0..=100.0).contains(&x)
+}
+
+/// The nth of the 12 cube edges intersected with the plane of luminance `y`.
+fn nth_vertex(y: f64, n: usize) -> Option<[f64; 3]> {
+    let [k_r, k_g, k_b] = Y_FROM_LINRGB;
+    let coord_a = if n % 4 <= 1 { 0.0 } else { 100.0 };
+    let coord_b = if n % 2 == 0 { 0.0 } else { 100.0 };
+    let vertex = if n < 4 {
+        let (g, b) = (coord_a, coord_b);
+        [(y - g * k_g - b * k_b) / k_r, g, b]
+    } else if n < 8 {
+        let (b, r) = (coord_a, coord_b);
+        [r, (y - r * k_r - b * k_b) / k_g, b]
+    } else {
+        let (r, g) = (coord_a, coord_b);
+        [r, g, (y - r * k_r - g * k_g) / k_b]
+    };
+    let free = if n < 4 { 0 } else if n < 8 { 1 } else { 2 };
+    is_bounded(vertex[free]).then_some(vertex)
+}
+
+/// sRGB-encoded value (0–255 scale) of a linear component (0–100 scale).
+fn true_delinearized(component: f64) -> f64 {
+    let normalized = component / 100.0;
+    let delinearized = if normalized <= 0.0031308 {
+        normalized * 12.92
+    } else {
+        1.055 * ops::powf(normalized, 1.0 / 2.4) - 0.055
+    };
+    delinearized * 255.0
+}
+
+/// Linear value (0–100 scale) where the 8-bit sRGB value `i` rounds up to `i + 1`.
+fn critical_plane(i: i32) -> f64 {
+    let normalized = (f64::from(i) + 0.5) / 255.0;
+    let linear = if normalized <= 0.040449936 {
+        normalized / 12.92
+    } else {
+        ops::powf((normalized + 0.055) / 1.055, 2.4)
+    };
+    linear * 100.0
+}
+
+fn critical_plane_below(x: f64) -> i32 {
+    (x - 0.5).floor() as i32
+}
+
+fn critical_plane_above(x: f64) -> i32 {
+    (x - 0.5).ceil() as i32
+}
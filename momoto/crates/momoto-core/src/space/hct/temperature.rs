@@ -0,0 +1,309 @@
+// =============================================================================
+// Color Temperature
+// File: crates/momoto-core/src/space/hct/temperature.rs
+//
+// Warm/cool ordering of hues at a fixed chroma and tone, used to find
+// analogous and complementary colors that feel balanced rather than being
+// a fixed number of degrees apart.
+//
+// Reference: Ou, Woodcock & Wright (2004), "A study of colour emotion and
+//            colour preference. Part I", Color Research & Application 29(3).
+// Port of TemperatureCache from Google material-color-utilities (Apache 2.0).
+// =============================================================================
+
+use alloc::{vec, vec::Vec};
+
+use super::{color_from_argb, HCT, M_SRGB_TO_XYZ};
+use super::cam16::mat3_mul_vec3;
+use crate::math::ops;
+use crate::space::lab::{EPSILON, KAPPA};
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// D65 white point used by material-color-utilities (Y = 1).
+const WHITE_D65: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+/// Hue-by-temperature lookup for one input color.
+///
+/// Construction solves one color per integer hue (361 solves), so reuse the
+/// cache when asking several questions about the same input.
+#[derive(Debug, Clone)]
+pub struct TemperatureCache {
+    input: HCT,
+    /// Input chroma and tone at hues 0, 1, …, 360
+    hcts_by_hue: Vec<HCT>,
+    /// Raw temperature of each entry of `hcts_by_hue`
+    temps_by_hue: Vec<f64>,
+    /// `hcts_by_hue` plus the input, coldest first
+    hcts_by_temp: Vec<HCT>,
+    coldest_temp: f64,
+    warmest_temp: f64,
+}
+
+impl TemperatureCache {
+    /// Build the cache for `input`.
+    #[must_use]
+    pub fn new(input: HCT) -> Self {
+        let hcts_by_hue: Vec<HCT> = (0..=360)
+            .map(|hue| HCT::solve(f64::from(hue), input.chroma, input.tone))
+            .collect();
+        let temps_by_hue: Vec<f64> = hcts_by_hue.iter().map(raw_temperature).collect();
+
+        let mut by_temp: Vec<(HCT, f64)> = hcts_by_hue
+            .iter()
+            .copied()
+            .zip(temps_by_hue.iter().copied())
+            .chain(core::iter::once((input, raw_temperature(&input))))
+            .collect();
+        by_temp.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let coldest_temp = by_temp[0].1;
+        let warmest_temp = by_temp[by_temp.len() - 1].1;
+
+        Self {
+            input,
+            hcts_by_hue,
+            temps_by_hue,
+            hcts_by_temp: by_temp.into_iter().map(|(hct, _)| hct).collect(),
+            coldest_temp,
+            warmest_temp,
+        }
+    }
+
+    /// Colors at every integer hue plus the input, coldest first.
+    #[inline]
+    #[must_use]
+    pub fn hcts_by_temp(&self) -> &[HCT] {
+        &self.hcts_by_temp
+    }
+
+    /// Coldest color with the input's chroma and tone.
+    #[must_use]
+    pub fn coldest(&self) -> HCT {
+        self.hcts_by_temp[0]
+    }
+
+    /// Warmest color with the input's chroma and tone.
+    #[must_use]
+    pub fn warmest(&self) -> HCT {
+        self.hcts_by_temp[self.hcts_by_temp.len() - 1]
+    }
+
+    /// Temperature of `hct` relative to the coldest (0) and warmest (1)
+    /// colors of the input's chroma and tone.
+    #[must_use]
+    pub fn relative_temperature(&self, hct: &HCT) -> f64 {
+        self.relative(raw_temperature(hct))
+    }
+
+    /// [`relative_temperature`](Self::relative_temperature) of the input.
+    #[must_use]
+    pub fn input_relative_temperature(&self) -> f64 {
+        self.relative_temperature(&self.input)
+    }
+
+    /// The color on the opposite side of the warm/cool divide whose relative
+    /// temperature mirrors the input's.
+    #[must_use]
+    pub fn complement(&self) -> HCT {
+        let coldest_hue = self.coldest().hue;
+        let warmest_hue = self.warmest().hue;
+        let start_is_cold_to_warm = is_between(self.input.hue, coldest_hue, warmest_hue);
+        let (start_hue, end_hue) = if start_is_cold_to_warm {
+            (warmest_hue, coldest_hue)
+        } else {
+            (coldest_hue, warmest_hue)
+        };
+
+        let target = 1.0 - self.input_relative_temperature();
+        let mut smallest_error = 1000.0;
+        let mut answer = self.hcts_by_hue[hue_index(self.input.hue)];
+        for addend in 0..=360 {
+            let hue = (start_hue + f64::from(addend)).rem_euclid(360.0);
+            if !is_between(hue, start_hue, end_hue) {
+                continue;
+            }
+            let index = hue_index(hue);
+            let error = (target - self.relative(self.temps_by_hue[index])).abs();
+            if error < smallest_error {
+                smallest_error = error;
+                answer = self.hcts_by_hue[index];
+            }
+        }
+        answer
+    }
+
+    /// `count` colors analogous to the input, spaced evenly in temperature.
+    ///
+    /// The hue circle is split into `divisions` steps of equal temperature
+    /// change; the input sits in the middle of the result, with colder
+    /// neighbours before it and warmer ones after.
+    #[must_use]
+    pub fn analogous(&self, count: usize, divisions: usize) -> Vec<HCT> {
+        let start_hue = hue_index(self.input.hue);
+        let start_hct = self.hcts_by_hue[start_hue];
+        let start_temp = self.relative(self.temps_by_hue[start_hue]);
+
+        let relative_at = |hue: usize| self.relative(self.temps_by_hue[hue % 360]);
+
+        let mut absolute_total_temp_delta = 0.0;
+        let mut last_temp = start_temp;
+        for i in 0..360 {
+            let temp = relative_at(start_hue + i);
+            absolute_total_temp_delta += (temp - last_temp).abs();
+            last_temp = temp;
+        }
+
+        let temp_step = absolute_total_temp_delta / divisions as f64;
+        let mut all_colors = vec![start_hct];
+        let mut total_temp_delta = 0.0;
+        let mut last_temp = start_temp;
+        let mut hue_addend = 1;
+        while all_colors.len() < divisions {
+            let hue = (start_hue + hue_addend) % 360;
+            let hct = self.hcts_by_hue[hue];
+            let temp = relative_at(hue);
+            total_temp_delta += (temp - last_temp).abs();
+
+            // Keep adding this hue while it satisfies successive indices, so
+            // colors without `divisions` distinct temperatures (e.g. white)
+            // still fill the result.
+            let mut index_addend = 0;
+            while all_colors.len() < divisions
+                && total_temp_delta >= (all_colors.len() + index_addend) as f64 * temp_step
+            {
+                all_colors.push(hct);
+                index_addend += 1;
+            }
+            last_temp = temp;
+            hue_addend += 1;
+            if hue_addend > 360 {
+                all_colors.resize(divisions, hct);
+                break;
+            }
+        }
+
+        let len = all_colors.len();
+        let colder = count.saturating_sub(1) / 2;
+        let warmer = count.saturating_sub(1) - colder;
+        let mut answers = Vec::with_capacity(count);
+        answers.extend((1..=colder).rev().map(|i| all_colors[(len - i % len) % len]));
+        answers.push(self.input);
+        answers.extend((1..=warmer).map(|i| all_colors[i % len]));
+        answers
+    }
+
+    fn relative(&self, temp: f64) -> f64 {
+        let range = self.warmest_temp - self.coldest_temp;
+        if range == 0.0 {
+            // Only one color exists at this chroma and tone (black or white)
+            return 0.5;
+        }
+        (temp - self.coldest_temp) / range
+    }
+}
+
+/// Warm/cool value of a color: −0.5 for grays, rising toward orange (hue
+/// 50° in CIELAB) and falling toward blue.
+#[must_use]
+pub fn raw_temperature(hct: &HCT) -> f64 {
+    let [_, a, b] = lab_of(hct);
+    let hue = ops::atan2(b, a).to_degrees().rem_euclid(360.0);
+    let chroma = ops::hypot(a, b);
+    -0.5 + 0.02 * ops::powf(chroma, 1.07) * ops::cos((hue - 50.0).rem_euclid(360.0).to_radians())
+}
+
+/// CIELAB of the 8-bit sRGB color that `hct` displays as.
+fn lab_of(hct: &HCT) -> [f64; 3] {
+    let linear = color_from_argb(hct.to_argb()).linear;
+    let xyz = mat3_mul_vec3(&M_SRGB_TO_XYZ, linear);
+    let f = |t: f64| {
+        if t > EPSILON {
+            ops::cbrt(t)
+        } else {
+            (KAPPA * t + 16.0) / 116.0
+        }
+    };
+    let [fx, fy, fz] = [
+        f(xyz[0] / WHITE_D65[0]),
+        f(xyz[1] / WHITE_D65[1]),
+        f(xyz[2] / WHITE_D65[2]),
+    ];
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Index into a 0..=360 per-degree table.
+fn hue_index(hue: f64) -> usize {
+    hue.round().clamp(0.0, 360.0) as usize
+}
+
+/// Whether `angle` lies on the arc from `a` to `b` (inclusive, wrapping).
+fn is_between(angle: f64, a: f64, b: f64) -> bool {
+    if a < b {
+        a <= angle && angle <= b
+    } else {
+        a <= angle || angle <= b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_temperature_golden() {
+        // material-color-utilities TemperatureCache tests
+        let cases = [
+            (0xff0000ff, -1.393),
+            (0xffff0000, 2.351),
+            (0xff00ff00, -0.267),
+            (0xffffffff, -0.5),
+            (0xff000000, -0.5),
+        ];
+        for (argb, expected) in cases {
+            let t = raw_temperature(&HCT::from_argb(argb));
+            assert!((t - expected).abs() < 0.001, "{argb:08x}: {t}");
+        }
+    }
+
+    #[test]
+    fn test_complement_golden() {
+        let cases = [
+            (0xff0000ff, 0xff9d0002),
+            (0xffff0000, 0xff007bfc),
+            (0xff00ff00, 0xffffd2c9),
+            (0xffffffff, 0xffffffff),
+            (0xff000000, 0xff000000),
+        ];
+        for (argb, expected) in cases {
+            let got = TemperatureCache::new(HCT::from_argb(argb)).complement().to_argb();
+            assert_eq!(got, expected, "{argb:08x}: {got:08x}");
+        }
+    }
+
+    #[test]
+    fn test_analogous_golden() {
+        let cases = [
+            (0xff0000ff, [0xff00590c, 0xff00564e, 0xff0000ff, 0xff6700cc, 0xff81009f]),
+            (0xffff0000, [0xfff60082, 0xfffc004c, 0xffff0000, 0xffd95500, 0xffaf7200]),
+            (0xff00ff00, [0xffcee900, 0xff92f500, 0xff00ff00, 0xff00fd6f, 0xff00fab3]),
+            (0xff000000, [0xff000000; 5]),
+        ];
+        for (argb, expected) in cases {
+            let analogous = TemperatureCache::new(HCT::from_argb(argb)).analogous(5, 12);
+            let got: Vec<u32> = analogous.iter().map(HCT::to_argb).collect();
+            assert_eq!(got, expected, "{argb:08x}");
+        }
+    }
+
+    #[test]
+    fn test_relative_temperature_bounds() {
+        let cache = TemperatureCache::new(HCT::from_argb(0xff0000ff));
+        assert_eq!(cache.relative_temperature(&cache.coldest()), 0.0);
+        assert_eq!(cache.relative_temperature(&cache.warmest()), 1.0);
+        let white = TemperatureCache::new(HCT::from_argb(0xffffffff));
+        assert_eq!(white.input_relative_temperature(), 0.5);
+        let green = TemperatureCache::new(HCT::from_argb(0xff00ff00));
+        assert!((green.input_relative_temperature() - 0.467).abs() < 0.001);
+    }
+}
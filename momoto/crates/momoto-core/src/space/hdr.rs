@@ -0,0 +1,543 @@
+//! HDR and Extended-Range Color (BT.2100 PQ/HLG, ICtCp, ΔE ITP)
+//!
+//! [`HdrColor`] holds unclamped linear BT.709 light where 1.0 is the SDR
+//! reference white ([`REFERENCE_WHITE_NITS`], 203 cd/m² per ITU-R BT.2408),
+//! so values above 1.0 are highlights and every value maps to absolute
+//! luminance in nits.
+//!
+//! | Encoding | Standard        | Signal → light                               |
+//! |----------|-----------------|----------------------------------------------|
+//! | PQ       | SMPTE ST 2084   | Absolute, 0–10 000 cd/m²                     |
+//! | HLG      | ARIB STD-B67    | Scene-relative, OOTF to the display peak     |
+//! | ICtCp    | ITU-R BT.2100   | PQ-encoded LMS, perceptually uniform in HDR  |
+//!
+//! Both transfer functions operate on BT.2020 primaries, as BT.2100 specifies.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::space::hdr::{pq_inverse_eotf, HdrColor};
+//!
+//! // A 1000-nit highlight is ~4.9× reference white
+//! let highlight = HdrColor::from_pq([pq_inverse_eotf(1000.0); 3]);
+//! assert!((highlight.luminance_nits() - 1000.0).abs() < 1e-6);
+//! assert!(!highlight.is_sdr());
+//! ```
+//!
+//! # References
+//!
+//! - SMPTE ST 2084:2014 High Dynamic Range EOTF of Mastering Reference Displays
+//! - ITU-R BT.2100-2 (2018) Image parameter values for HDR television
+//! - ITU-R BT.2124-0 (2019) Objective metric for the assessment of the
+//!   potential visibility of colour differences in television (ΔE ITP)
+//! - ITU-R BT.2408-7 (2023) Guidance for operational practices in HDR television
+
+use crate::color::Color;
+use crate::math::ops;
+use crate::perception::Polarity;
+use crate::space::rgb::RgbSpace;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Luminance of SDR reference (graphics) white in cd/m² (ITU-R BT.2408).
+pub const REFERENCE_WHITE_NITS: f64 = 203.0;
+
+/// Peak luminance representable by PQ in cd/m².
+pub const PQ_PEAK_NITS: f64 = 10_000.0;
+
+/// Nominal peak display luminance for HLG in cd/m² (BT.2100 reference).
+pub const HLG_NOMINAL_PEAK_NITS: f64 = 1_000.0;
+
+/// Flare added to both luminances by [`HdrColor::contrast`], as a fraction
+/// of reference white. Matches the 0.05 term of the WCAG ratio.
+pub const CONTRAST_FLARE: f64 = 0.05;
+
+/// Scale factor of ΔE ITP; one unit is roughly one just-noticeable difference.
+pub const DELTA_E_ITP_SCALE: f64 = 720.0;
+
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+const HLG_A: f64 = 0.178_832_77;
+const HLG_B: f64 = 1.0 - 4.0 * HLG_A;
+// c = 0.5 − a·ln(4a)
+const HLG_C: f64 = 0.559_910_729_529_562_4;
+
+/// BT.2020 luma coefficients used by the HLG OOTF.
+const BT2020_LUMA: [f64; 3] = [0.2627, 0.6780, 0.0593];
+
+/// Linear BT.2020 → LMS (BT.2100 Table 6, 12-bit integer form).
+const RGB2020_TO_LMS: [[f64; 3]; 3] = [
+    [1688.0 / 4096.0, 2146.0 / 4096.0, 262.0 / 4096.0],
+    [683.0 / 4096.0, 2951.0 / 4096.0, 462.0 / 4096.0],
+    [99.0 / 4096.0, 309.0 / 4096.0, 3688.0 / 4096.0],
+];
+
+/// PQ-encoded L'M'S' → ICtCp (BT.2100 Table 7).
+const LMS_TO_ICTCP: [[f64; 3]; 3] = [
+    [0.5, 0.5, 0.0],
+    [6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0],
+    [17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0],
+];
+
+// ============================================================================
+// Transfer functions
+// ============================================================================
+
+/// PQ EOTF: non-linear signal in [0, 1] → absolute luminance in cd/m².
+///
+/// # Examples
+///
+/// ```
+/// use momoto_core::space::hdr::pq_eotf;
+///
+/// assert!((pq_eotf(1.0) - 10_000.0).abs() < 1e-6);
+/// assert!((pq_eotf(0.5807) - 203.0).abs() < 0.1);
+/// ```
+#[must_use]
+pub fn pq_eotf(signal: f64) -> f64 {
+    let e = ops::powf(signal.clamp(0.0, 1.0), 1.0 / PQ_M2);
+    let y = ops::powf((e - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * e), 1.0 / PQ_M1);
+    y * PQ_PEAK_NITS
+}
+
+/// Inverse PQ EOTF: absolute luminance in cd/m² → non-linear signal in [0, 1].
+#[must_use]
+pub fn pq_inverse_eotf(nits: f64) -> f64 {
+    let y = ops::powf((nits / PQ_PEAK_NITS).clamp(0.0, 1.0), PQ_M1);
+    ops::powf((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y), PQ_M2)
+}
+
+/// HLG OETF: normalized scene light in [0, 1] → non-linear signal in [0, 1].
+#[must_use]
+pub fn hlg_oetf(scene: f64) -> f64 {
+    let e = scene.max(0.0);
+    if e <= 1.0 / 12.0 {
+        (3.0 * e).sqrt()
+    } else {
+        HLG_A * ops::ln(12.0 * e - HLG_B) + HLG_C
+    }
+}
+
+/// Inverse HLG OETF: non-linear signal in [0, 1] → normalized scene light.
+#[must_use]
+pub fn hlg_inverse_oetf(signal: f64) -> f64 {
+    let e = signal.max(0.0);
+    if e <= 0.5 {
+        e * e / 3.0
+    } else {
+        (ops::exp((e - HLG_C) / HLG_A) + HLG_B) / 12.0
+    }
+}
+
+/// HLG system gamma for a display of the given peak luminance (BT.2100 note 5f).
+///
+/// 1.2 at the 1000 cd/m² nominal peak.
+#[must_use]
+pub fn hlg_system_gamma(peak_nits: f64) -> f64 {
+    1.2 + 0.42 * ops::log10(peak_nits / HLG_NOMINAL_PEAK_NITS)
+}
+
+// ============================================================================
+// HdrColor
+// ============================================================================
+
+/// An extended-range color in linear BT.709 light.
+///
+/// Channels are unclamped: 1.0 is SDR reference white, values above 1.0 are
+/// HDR highlights and negative values are outside the BT.709 gamut.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HdrColor {
+    /// Linear BT.709/sRGB channels relative to reference white.
+    pub linear: [f64; 3],
+    /// Alpha channel (0.0 = transparent, 1.0 = opaque).
+    pub alpha: f64,
+}
+
+impl HdrColor {
+    /// Create from linear BT.709 channels relative to reference white.
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f64, g: f64, b: f64) -> Self {
+        Self {
+            linear: [r, g, b],
+            alpha: 1.0,
+        }
+    }
+
+    /// Create from absolute linear BT.709 channels in cd/m².
+    #[must_use]
+    pub fn from_nits(rgb_nits: [f64; 3]) -> Self {
+        let [r, g, b] = rgb_nits.map(|v| v / REFERENCE_WHITE_NITS);
+        Self::new(r, g, b)
+    }
+
+    /// Lift an SDR color into HDR, placing its white at reference white.
+    #[must_use]
+    pub fn from_color(color: &Color) -> Self {
+        Self {
+            linear: color.linear,
+            alpha: color.alpha,
+        }
+    }
+
+    /// Convert to a [`Color`] without clamping.
+    ///
+    /// Highlights keep their values above 1.0 in `linear` and `srgb`; use
+    /// [`HdrColor::to_sdr`] for a displayable SDR color.
+    #[must_use]
+    pub fn to_color(&self) -> Color {
+        let mut color = Color::from_linear_extended(self.linear);
+        color.alpha = self.alpha;
+        color
+    }
+
+    /// Hard-clip each channel to [0, 1] and convert to an SDR [`Color`].
+    #[must_use]
+    pub fn to_sdr(&self) -> Color {
+        let [r, g, b] = self.linear.map(|v| v.clamp(0.0, 1.0));
+        let mut color = Color::from_linear(r, g, b);
+        color.alpha = self.alpha;
+        color
+    }
+
+    /// Absolute linear BT.709 channels in cd/m².
+    #[must_use]
+    pub fn to_nits(&self) -> [f64; 3] {
+        self.linear.map(|v| v * REFERENCE_WHITE_NITS)
+    }
+
+    /// Relative luminance Y (1.0 = reference white).
+    #[must_use]
+    pub fn luminance(&self) -> f64 {
+        RgbSpace::Srgb.linear_to_xyz(self.linear)[1]
+    }
+
+    /// Absolute luminance in cd/m².
+    #[must_use]
+    pub fn luminance_nits(&self) -> f64 {
+        self.luminance() * REFERENCE_WHITE_NITS
+    }
+
+    /// Whether every channel lies within the SDR [0, 1] range.
+    #[must_use]
+    pub fn is_sdr(&self) -> bool {
+        RgbSpace::contains_linear(self.linear)
+    }
+
+    /// Decode a PQ-encoded BT.2020 signal (BT.2100 PQ).
+    #[must_use]
+    pub fn from_pq(signal: [f64; 3]) -> Self {
+        Self::from_bt2020_nits(signal.map(pq_eotf))
+    }
+
+    /// Encode as a PQ BT.2020 signal. Channels outside BT.2020 are clipped.
+    #[must_use]
+    pub fn to_pq(&self) -> [f64; 3] {
+        self.bt2020_nits().map(pq_inverse_eotf)
+    }
+
+    /// Decode an HLG-encoded BT.2020 signal shown on a display with the given
+    /// peak luminance (BT.2100 HLG EOTF, zero black level).
+    #[must_use]
+    pub fn from_hlg(signal: [f64; 3], peak_nits: f64) -> Self {
+        let scene = signal.map(hlg_inverse_oetf);
+        let ys = dot(BT2020_LUMA, scene);
+        let gamma = hlg_system_gamma(peak_nits);
+        let gain = if ys > 0.0 {
+            peak_nits * ops::powf(ys, gamma - 1.0)
+        } else {
+            0.0
+        };
+        Self::from_bt2020_nits(scene.map(|e| e * gain))
+    }
+
+    /// Encode as an HLG BT.2020 signal for a display with the given peak
+    /// luminance (inverse of [`HdrColor::from_hlg`]).
+    #[must_use]
+    pub fn to_hlg(&self, peak_nits: f64) -> [f64; 3] {
+        let display = self.bt2020_nits().map(|v| v / peak_nits);
+        let yd = dot(BT2020_LUMA, display);
+        if yd <= 0.0 {
+            return [0.0; 3];
+        }
+        let gamma = hlg_system_gamma(peak_nits);
+        let gain = ops::powf(yd, (1.0 - gamma) / gamma);
+        display.map(|v| hlg_oetf(v * gain))
+    }
+
+    /// Convert to ICtCp (BT.2100, PQ variant).
+    #[must_use]
+    pub fn to_ictcp(&self) -> ICtCp {
+        let rgb = self.bt2020_nits();
+        let lms = mat3_mul(&RGB2020_TO_LMS, rgb).map(pq_inverse_eotf);
+        let [i, ct, cp] = mat3_mul(&LMS_TO_ICTCP, lms);
+        ICtCp { i, ct, cp }
+    }
+
+    /// ΔE ITP (ITU-R BT.2124) between two colors. 1.0 ≈ one JND.
+    #[must_use]
+    pub fn delta_e_itp(&self, other: &Self) -> f64 {
+        self.to_ictcp().delta_e_itp(&other.to_ictcp())
+    }
+
+    /// HDR-aware contrast of this color as a foreground over `background`.
+    ///
+    /// See [`HdrContrast`]. For SDR inputs the ratio equals the WCAG 2.x
+    /// contrast ratio; brighter-than-white backgrounds keep raising it
+    /// instead of saturating at 21:1.
+    #[must_use]
+    pub fn contrast(&self, background: &Self) -> HdrContrast {
+        let fg_nits = self.luminance_nits().max(0.0);
+        let bg_nits = background.luminance_nits().max(0.0);
+        let flare = CONTRAST_FLARE * REFERENCE_WHITE_NITS;
+        let (hi, lo) = if fg_nits > bg_nits {
+            (fg_nits, bg_nits)
+        } else {
+            (bg_nits, fg_nits)
+        };
+        let delta_i = (self.to_ictcp().i - background.to_ictcp().i).abs();
+        HdrContrast {
+            foreground_nits: fg_nits,
+            background_nits: bg_nits,
+            ratio: (hi + flare) / (lo + flare),
+            delta_i: delta_i * DELTA_E_ITP_SCALE,
+            polarity: if fg_nits > bg_nits {
+                Polarity::LightOnDark
+            } else {
+                Polarity::DarkOnLight
+            },
+        }
+    }
+
+    /// Worst-case contrast of this color over a set of backgrounds, e.g.
+    /// frames or sampled regions of HDR video behind a UI element.
+    ///
+    /// Returns `None` when `backgrounds` is empty.
+    #[must_use]
+    pub fn min_contrast_over(&self, backgrounds: &[Self]) -> Option<HdrContrast> {
+        backgrounds
+            .iter()
+            .map(|bg| self.contrast(bg))
+            .min_by(|a, b| a.ratio.total_cmp(&b.ratio))
+    }
+
+    fn from_bt2020_nits(rgb: [f64; 3]) -> Self {
+        let bt709 = RgbSpace::Rec2020.convert_linear(rgb, RgbSpace::Srgb);
+        Self::from_nits(bt709)
+    }
+
+    fn bt2020_nits(&self) -> [f64; 3] {
+        RgbSpace::Srgb.convert_linear(self.to_nits(), RgbSpace::Rec2020)
+    }
+}
+
+impl From<Color> for HdrColor {
+    fn from(color: Color) -> Self {
+        Self::from_color(&color)
+    }
+}
+
+// ============================================================================
+// ICtCp
+// ============================================================================
+
+/// ICtCp coordinates (ITU-R BT.2100, PQ variant).
+///
+/// `i` is PQ-encoded intensity in [0, 1]; `ct` (tritan) and `cp` (protan) are
+/// the opponent chroma axes.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ICtCp {
+    /// Intensity
+    pub i: f64,
+    /// Blue–yellow (tritan) axis
+    pub ct: f64,
+    /// Red–green (protan) axis
+    pub cp: f64,
+}
+
+impl ICtCp {
+    /// Create ICtCp coordinates.
+    #[inline]
+    #[must_use]
+    pub const fn new(i: f64, ct: f64, cp: f64) -> Self {
+        Self { i, ct, cp }
+    }
+
+    /// ΔE ITP (ITU-R BT.2124): `720·√(ΔI² + ΔT² + ΔP²)` with `T = Ct/2`.
+    #[must_use]
+    pub fn delta_e_itp(&self, other: &Self) -> f64 {
+        let di = self.i - other.i;
+        let dt = 0.5 * (self.ct - other.ct);
+        let dp = self.cp - other.cp;
+        DELTA_E_ITP_SCALE * (di * di + dt * dt + dp * dp).sqrt()
+    }
+}
+
+// ============================================================================
+// Contrast
+// ============================================================================
+
+/// Contrast of a foreground over a background in absolute luminance.
+///
+/// `ratio` is `(L_hi + F) / (L_lo + F)` in cd/m² with flare
+/// `F = 0.05 × 203 cd/m²`, the WCAG formula scaled to reference white.
+/// `delta_i` is the intensity difference in ΔE ITP units, which stays
+/// perceptually uniform across the whole HDR range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HdrContrast {
+    /// Foreground luminance in cd/m².
+    pub foreground_nits: f64,
+    /// Background luminance in cd/m².
+    pub background_nits: f64,
+    /// Luminance contrast ratio (≥ 1.0).
+    pub ratio: f64,
+    /// Intensity difference in ΔE ITP units (1.0 ≈ one JND).
+    pub delta_i: f64,
+    /// Whether the foreground is darker or lighter than the background.
+    pub polarity: Polarity,
+}
+
+impl HdrContrast {
+    /// Whether the ratio meets a minimum (e.g. 4.5 for WCAG AA body text).
+    #[inline]
+    #[must_use]
+    pub fn passes(&self, min_ratio: f64) -> bool {
+        self.ratio >= min_ratio
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+#[inline]
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn mat3_mul(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [dot(m[0], v), dot(m[1], v), dot(m[2], v)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pq_reference_values() {
+        assert_eq!(pq_eotf(0.0), 0.0);
+        assert!((pq_eotf(1.0) - PQ_PEAK_NITS).abs() < 1e-6);
+        // BT.2408: 203 cd/m² ↔ 58% PQ, 100 cd/m² ↔ 50.8% PQ
+        assert!((pq_inverse_eotf(203.0) - 0.5807).abs() < 1e-3);
+        assert!((pq_inverse_eotf(100.0) - 0.5081).abs() < 1e-3);
+        for nits in [0.01, 1.0, 80.0, 203.0, 1000.0, 4000.0] {
+            let back = pq_eotf(pq_inverse_eotf(nits));
+            assert!((back - nits).abs() / nits < 1e-9, "{nits}: {back}");
+        }
+    }
+
+    #[test]
+    fn test_hlg_reference_values() {
+        assert!((HLG_C - (0.5 - HLG_A * (4.0 * HLG_A).ln())).abs() < 1e-12);
+        assert!((hlg_oetf(1.0 / 12.0) - 0.5).abs() < 1e-12);
+        assert!((hlg_oetf(1.0) - 1.0).abs() < 1e-6);
+        for e in [0.0, 0.01, 1.0 / 12.0, 0.3, 0.9, 1.0] {
+            assert!((hlg_inverse_oetf(hlg_oetf(e)) - e).abs() < 1e-12);
+        }
+        assert!((hlg_system_gamma(1000.0) - 1.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hlg_reference_white() {
+        // BT.2408: HLG reference white is 75% signal ≈ 203 cd/m² at 1000 peak
+        let white = HdrColor::from_hlg([0.75; 3], 1000.0);
+        assert!((white.luminance_nits() - 203.0).abs() < 2.0, "{white:?}");
+        let peak = HdrColor::from_hlg([1.0; 3], 1000.0);
+        assert!((peak.luminance_nits() - 1000.0).abs() < 1e-3);
+        let back = white.to_hlg(1000.0);
+        for v in back {
+            assert!((v - 0.75).abs() < 1e-9, "{back:?}");
+        }
+    }
+
+    #[test]
+    fn test_pq_roundtrip_and_sdr_white() {
+        let white = HdrColor::from_color(&Color::from_srgb8(255, 255, 255));
+        assert!((white.luminance_nits() - REFERENCE_WHITE_NITS).abs() < 1e-9);
+        let red = HdrColor::new(2.0, 0.1, 0.05);
+        let back = HdrColor::from_pq(red.to_pq());
+        for i in 0..3 {
+            assert!((back.linear[i] - red.linear[i]).abs() < 1e-9, "{back:?}");
+        }
+    }
+
+    #[test]
+    fn test_unclamped_color_conversion() {
+        let highlight = HdrColor::new(3.0, 3.0, 3.0);
+        assert!(!highlight.is_sdr());
+        let color = highlight.to_color();
+        assert!(color.linear[0] > 2.9 && color.srgb[0] > 1.0);
+        assert_eq!(HdrColor::from_color(&color), highlight);
+        assert_eq!(highlight.to_sdr().to_srgb8(), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_ictcp_achromatic() {
+        let gray = HdrColor::new(0.5, 0.5, 0.5).to_ictcp();
+        assert!(gray.ct.abs() < 1e-9 && gray.cp.abs() < 1e-9, "{gray:?}");
+        let white = HdrColor::from_nits([PQ_PEAK_NITS; 3]).to_ictcp();
+        assert!((white.i - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_delta_e_itp() {
+        let a = HdrColor::new(0.5, 0.2, 0.1);
+        assert_eq!(a.delta_e_itp(&a), 0.0);
+        let b = HdrColor::new(0.5, 0.2, 0.11);
+        let c = HdrColor::new(0.5, 0.2, 0.3);
+        assert!(a.delta_e_itp(&b) < a.delta_e_itp(&c));
+        // Symmetric
+        assert!((a.delta_e_itp(&c) - c.delta_e_itp(&a)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_contrast_matches_wcag_for_sdr() {
+        let fg = Color::from_srgb8(118, 118, 118);
+        let bg = Color::from_srgb8(255, 255, 255);
+        let c = HdrColor::from(fg).contrast(&HdrColor::from(bg));
+        // #767676 on white is the classic 4.54:1
+        assert!((c.ratio - 4.54).abs() < 0.01, "{c:?}");
+        assert_eq!(c.polarity, Polarity::DarkOnLight);
+        assert!(c.passes(4.5));
+    }
+
+    #[test]
+    fn test_contrast_over_hdr_video() {
+        let label = HdrColor::from(Color::from_srgb8(255, 255, 255));
+        let frames = [
+            HdrColor::from_nits([5.0; 3]),
+            HdrColor::from_nits([400.0; 3]),
+            HdrColor::from_nits([1000.0; 3]),
+        ];
+        let worst = label.min_contrast_over(&frames).unwrap();
+        // White text fails over a 400-nit highlight, not the 1000-nit one
+        assert!((worst.background_nits - 400.0).abs() < 1e-6, "{worst:?}");
+        assert!(!worst.passes(3.0));
+        let bright = label.contrast(&frames[2]);
+        assert_eq!(bright.polarity, Polarity::DarkOnLight);
+        assert!(bright.ratio > worst.ratio);
+        assert!(label.min_contrast_over(&[]).is_none());
+    }
+}
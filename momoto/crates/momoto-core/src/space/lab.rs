@@ -0,0 +1,387 @@
+//! CIELAB and CIELCh(ab)
+//!
+//! CIE 1976 L\*a\*b\* relative to a chosen reference white, its cylindrical
+//! form LCh, and the CIE76 / CIEDE2000 color differences.
+//!
+//! [`Lab::from_color`] follows CSS Color 4 `lab()`: D65 colors are adapted to
+//! a D50 reference white with the Bradford transform. Use [`Lab::from_xyz`]
+//! with any [`Illuminant`] for other conventions (e.g. D65 without adaptation).
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::Color;
+//! use momoto_core::space::lab::Lab;
+//!
+//! let a = Lab::from_color(&Color::from_srgb8(59, 130, 246));
+//! let b = Lab::from_color(&Color::from_srgb8(37, 99, 235));
+//! assert!(a.delta_e_2000(&b) > 2.3); // noticeably different
+//! ```
+//!
+//! # References
+//!
+//! - CIE 15:2004 Colorimetry, 3rd edition
+//! - Sharma, G., Wu, W., Dalal, E. N. (2005). The CIEDE2000 color-difference
+//!   formula: implementation notes, supplementary test data, and mathematical
+//!   observations. Color Research & Application, 30(1), 21–30.
+
+use core::f64::consts::PI;
+
+use crate::color::Color;
+use crate::math::ops;
+use crate::space::xyz::{ChromaticAdaptation, Illuminant, Xyz};
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// CIE ε = (6/29)³, the linear-segment threshold for Y/Yn.
+pub const EPSILON: f64 = 216.0 / 24389.0;
+
+/// CIE κ = (29/3)³, the linear-segment slope.
+pub const KAPPA: f64 = 24389.0 / 27.0;
+
+// ============================================================================
+// Lab
+// ============================================================================
+
+/// CIE 1976 L\*a\*b\* color.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+    /// Lightness L\* (0 = black, 100 = reference white)
+    pub l: f64,
+    /// Green–red opponent axis a\*
+    pub a: f64,
+    /// Blue–yellow opponent axis b\*
+    pub b: f64,
+}
+
+impl Lab {
+    /// Create a Lab color.
+    #[inline]
+    #[must_use]
+    pub const fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Convert XYZ (Y = 1.0 scale) to Lab relative to `white`.
+    ///
+    /// `xyz` must already be expressed under `white`; use [`Xyz::adapt`]
+    /// first if it was measured under a different illuminant.
+    #[must_use]
+    pub fn from_xyz(xyz: Xyz, white: Illuminant) -> Self {
+        let w = white.white_point();
+        let f = |t: f64| {
+            if t > EPSILON {
+                ops::cbrt(t)
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let fx = f(xyz.x / w.x);
+        let fy = f(xyz.y / w.y);
+        let fz = f(xyz.z / w.z);
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Convert to XYZ (Y = 1.0 scale) relative to `white`.
+    #[must_use]
+    pub fn to_xyz(self, white: Illuminant) -> Xyz {
+        let w = white.white_point();
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = self.a / 500.0 + fy;
+        let fz = fy - self.b / 200.0;
+        let f_inv = |f: f64| {
+            let f3 = f * f * f;
+            if f3 > EPSILON {
+                f3
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let y = if self.l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            self.l / KAPPA
+        };
+        Xyz::new(f_inv(fx) * w.x, y * w.y, f_inv(fz) * w.z)
+    }
+
+    /// Lab (D50, Bradford-adapted) of a color, matching CSS `lab()`.
+    #[must_use]
+    pub fn from_color(color: &Color) -> Self {
+        let xyz = Xyz::from_color(color).adapt(
+            Illuminant::D65,
+            Illuminant::D50,
+            ChromaticAdaptation::Bradford,
+        );
+        Self::from_xyz(xyz, Illuminant::D50)
+    }
+
+    /// Convert Lab (D50) back to a color. Out-of-sRGB values are kept.
+    #[must_use]
+    pub fn to_color(self) -> Color {
+        self.to_xyz(Illuminant::D50)
+            .adapt(
+                Illuminant::D50,
+                Illuminant::D65,
+                ChromaticAdaptation::Bradford,
+            )
+            .to_color()
+    }
+
+    /// Chroma C\*ab.
+    #[inline]
+    #[must_use]
+    pub fn chroma(self) -> f64 {
+        (self.a * self.a + self.b * self.b).sqrt()
+    }
+
+    /// Hue angle h_ab in degrees [0, 360).
+    #[inline]
+    #[must_use]
+    pub fn hue(self) -> f64 {
+        ops::atan2(self.b, self.a).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Convert to cylindrical LCh.
+    #[must_use]
+    pub fn to_lch(self) -> Lch {
+        Lch::new(self.l, self.chroma(), self.hue())
+    }
+
+    /// CIE76 color difference (Euclidean distance).
+    #[must_use]
+    pub fn delta_e_76(&self, other: &Self) -> f64 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    /// CIEDE2000 color difference with k_L = k_C = k_H = 1.
+    #[must_use]
+    pub fn delta_e_2000(&self, other: &Self) -> f64 {
+        const POW25_7: f64 = 6_103_515_625.0; // 25^7
+
+        let (l1, a1, b1) = (self.l, self.a, self.b);
+        let (l2, a2, b2) = (other.l, other.a, other.b);
+
+        // a' correction
+        let c_avg = (ops::hypot(a1, b1) + ops::hypot(a2, b2)) / 2.0;
+        let c_avg_7 = c_avg.powi(7);
+        let g = 0.5 * (1.0 - (c_avg_7 / (c_avg_7 + POW25_7)).sqrt());
+        let a1p = a1 * (1.0 + g);
+        let a2p = a2 * (1.0 + g);
+
+        let c1p = ops::hypot(a1p, b1);
+        let c2p = ops::hypot(a2p, b2);
+        let hue = |a: f64, b: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                ops::atan2(b, a).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let h1p = hue(a1p, b1);
+        let h2p = hue(a2p, b2);
+
+        // Differences
+        let dlp = l2 - l1;
+        let dcp = c2p - c1p;
+        let dhp = if c1p * c2p == 0.0 {
+            0.0
+        } else {
+            let d = h2p - h1p;
+            if d > 180.0 {
+                d - 360.0
+            } else if d < -180.0 {
+                d + 360.0
+            } else {
+                d
+            }
+        };
+        let dh_large = 2.0 * (c1p * c2p).sqrt() * ops::sin(dhp.to_radians() / 2.0);
+
+        // Means
+        let l_avg = (l1 + l2) / 2.0;
+        let cp_avg = (c1p + c2p) / 2.0;
+        let hp_avg = if c1p * c2p == 0.0 {
+            h1p + h2p
+        } else if (h1p - h2p).abs() <= 180.0 {
+            (h1p + h2p) / 2.0
+        } else if h1p + h2p < 360.0 {
+            (h1p + h2p + 360.0) / 2.0
+        } else {
+            (h1p + h2p - 360.0) / 2.0
+        };
+
+        // Weighting functions
+        let h = hp_avg.to_radians();
+        let t = 1.0 - 0.17 * ops::cos(h - PI / 6.0)
+            + 0.24 * ops::cos(2.0 * h)
+            + 0.32 * ops::cos(3.0 * h + PI / 30.0)
+            - 0.20 * ops::cos(4.0 * h - 63.0_f64.to_radians());
+        let l50 = (l_avg - 50.0).powi(2);
+        let sl = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+        let sc = 1.0 + 0.045 * cp_avg;
+        let sh = 1.0 + 0.015 * cp_avg * t;
+
+        // Rotation term
+        let dtheta = 30.0 * ops::exp(-((hp_avg - 275.0) / 25.0).powi(2));
+        let cp_avg_7 = cp_avg.powi(7);
+        let rc = 2.0 * (cp_avg_7 / (cp_avg_7 + POW25_7)).sqrt();
+        let rt = -rc * ops::sin(2.0 * dtheta.to_radians());
+
+        let tl = dlp / sl;
+        let tc = dcp / sc;
+        let th = dh_large / sh;
+        (tl * tl + tc * tc + th * th + rt * tc * th).sqrt()
+    }
+}
+
+// ============================================================================
+// LCh
+// ============================================================================
+
+/// CIE LCh(ab): cylindrical form of [`Lab`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lch {
+    /// Lightness L\* (0–100)
+    pub l: f64,
+    /// Chroma C\*ab (≥ 0)
+    pub c: f64,
+    /// Hue angle h_ab in degrees [0, 360)
+    pub h: f64,
+}
+
+impl Lch {
+    /// Create an LCh color. Hue is normalized to [0, 360).
+    #[inline]
+    #[must_use]
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self {
+            l,
+            c,
+            h: h.rem_euclid(360.0),
+        }
+    }
+
+    /// Convert to rectangular Lab.
+    #[must_use]
+    pub fn to_lab(self) -> Lab {
+        let h = self.h.to_radians();
+        Lab::new(self.l, self.c * ops::cos(h), self.c * ops::sin(h))
+    }
+
+    /// LCh (D50, Bradford-adapted) of a color, matching CSS `lch()`.
+    #[must_use]
+    pub fn from_color(color: &Color) -> Self {
+        Lab::from_color(color).to_lch()
+    }
+
+    /// Convert LCh (D50) back to a color. Out-of-sRGB values are kept.
+    #[must_use]
+    pub fn to_color(self) -> Color {
+        self.to_lab().to_color()
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        lab.to_lch()
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        lch.to_lab()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_white_and_black() {
+        let white = Lab::from_color(&Color::from_srgb8(255, 255, 255));
+        assert!((white.l - 100.0).abs() < 1e-6);
+        assert!(white.a.abs() < 1e-6 && white.b.abs() < 1e-6, "{:?}", white);
+        let black = Lab::from_color(&Color::from_srgb8(0, 0, 0));
+        assert!(black.l.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_css_reference_values() {
+        // CSS Color 4: rgb(255 0 0) = lab(54.29 80.80 69.89)
+        let red = Lab::from_color(&Color::from_srgb8(255, 0, 0));
+        assert!((red.l - 54.29).abs() < 0.01, "{:?}", red);
+        assert!((red.a - 80.80).abs() < 0.01, "{:?}", red);
+        assert!((red.b - 69.89).abs() < 0.01, "{:?}", red);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for rgb in [[59, 130, 246], [12, 200, 7], [3, 3, 3], [250, 240, 10]] {
+            let c = Color::from_srgb8(rgb[0], rgb[1], rgb[2]);
+            assert_eq!(Lab::from_color(&c).to_color().to_srgb8(), rgb);
+            assert_eq!(Lch::from_color(&c).to_color().to_srgb8(), rgb);
+        }
+        // Other illuminants roundtrip through XYZ directly
+        let xyz = Xyz::new(0.2, 0.3, 0.4);
+        let back = Lab::from_xyz(xyz, Illuminant::A).to_xyz(Illuminant::A);
+        assert!((back.x - xyz.x).abs() < 1e-12 && (back.z - xyz.z).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lch_conversion() {
+        let lch = Lab::new(50.0, 0.0, -20.0).to_lch();
+        assert!((lch.c - 20.0).abs() < 1e-12);
+        assert!((lch.h - 270.0).abs() < 1e-12);
+        let lab: Lab = lch.into();
+        assert!((lab.b + 20.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ciede2000_sharma_data() {
+        // Selected pairs from Sharma et al. (2005), Table 1
+        let cases = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0000),
+            ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                (2.0776, 0.0795, -1.1350),
+                (0.9033, -0.0636, -0.5514),
+                0.9082,
+            ),
+        ];
+        for ((l1, a1, b1), (l2, a2, b2), expected) in cases {
+            let de = Lab::new(l1, a1, b1).delta_e_2000(&Lab::new(l2, a2, b2));
+            assert!(
+                (de - expected).abs() < 1e-4,
+                "expected {}, got {}",
+                expected,
+                de
+            );
+        }
+    }
+
+    #[test]
+    fn test_delta_e_76() {
+        let a = Lab::new(50.0, 0.0, 0.0);
+        let b = Lab::new(53.0, 4.0, 0.0);
+        assert!((a.delta_e_76(&b) - 5.0).abs() < 1e-12);
+    }
+}
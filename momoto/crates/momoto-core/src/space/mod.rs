@@ -3,9 +3,20 @@
 //! Currently implemented:
 //! - **OKLCH** (Oklab with cylindrical coordinates) — Perceptually uniform color space
 //! - **HCT** (Hue, Chroma, Tone) — Material Design 3 perceptual color space
-//!   (includes CAM16 color appearance model)
+//!   (includes CAM16 color appearance model, M3 tonal palettes and dynamic
+//!   color schemes)
+//! - **RGB spaces** — sRGB, Display P3, Rec.2020 and Adobe RGB with primaries,
+//!   white point, transfer functions and XYZ conversion matrices
+//! - **CIE XYZ** — Standard illuminants and Bradford/CAT02/CAT16 chromatic adaptation
+//! - **CIELAB / LCh** — Lab relative to any white, with CIE76 and CIEDE2000
+//! - **HDR** — Extended-range linear light in nits, BT.2100 PQ/HLG, ICtCp
+//!   and ΔE ITP
 
+pub mod hdr;
+pub mod lab;
 pub mod oklch;
+pub mod rgb;
+pub mod xyz;
 
 /// HCT (Hue, Chroma, Tone) color space from Google Material Design 3.
 ///
@@ -0,0 +1,481 @@
+//! Gamut mapping in OKLCH
+//!
+//! Brings out-of-gamut colors into the gamut of any [`RgbSpace`] and reports
+//! the perceptual cost (ΔEOK) of doing so.
+//!
+//! | Strategy                                   | Moves        | Notes                                  |
+//! |--------------------------------------------|--------------|----------------------------------------|
+//! | [`GamutMapping::Css`]                      | C            | CSS Color 4 §13.2, JND 0.02 clip check |
+//! | [`GamutMapping::Clip`]                     | RGB channels | Cheapest; may shift hue                |
+//! | [`GamutMapping::PreserveLightness`]        | C            | Exact boundary at constant L and h     |
+//! | [`GamutMapping::ProjectToCusp`]            | L and C      | Toward the cusp lightness              |
+//! | [`GamutMapping::AdaptiveL0`]               | L and C      | Ottosson's adaptive L0 (L0 = 0.5 bias) |
+//!
+//! All strategies keep the hue angle, except `Clip` and the final clip step of
+//! `Css` which may move it by less than the JND.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::space::oklch::gamut::{GamutMapper, GamutMapping};
+//! use momoto_core::space::oklch::OKLCH;
+//! use momoto_core::space::rgb::RgbSpace;
+//!
+//! let vivid = OKLCH::new(0.7, 0.35, 150.0);
+//! let srgb = GamutMapper::new(RgbSpace::Srgb).map(&vivid);
+//! let p3 = GamutMapper::new(RgbSpace::DisplayP3).map(&vivid);
+//! assert!(srgb.color.is_in_gamut_of(RgbSpace::Srgb));
+//! assert!(p3.delta_e < srgb.delta_e); // a wider gamut costs less
+//!
+//! let adaptive = GamutMapper::new(RgbSpace::Srgb)
+//!     .with_strategy(GamutMapping::AdaptiveL0 { alpha: 0.05 })
+//!     .map(&vivid);
+//! assert!(adaptive.color.is_in_gamut_of(RgbSpace::Srgb));
+//! ```
+//!
+//! # References
+//!
+//! - [CSS Color 4 §13.2: CSS gamut mapping to an RGB destination](https://www.w3.org/TR/css-color-4/#binsearch)
+//! - [Ottosson, B. (2021). sRGB gamut clipping](https://bottosson.github.io/posts/gamutclipping/)
+
+use crate::color::Color;
+use crate::space::oklch::{OKLab, OKLCH};
+use crate::space::rgb::RgbSpace;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+/// Just-noticeable ΔEOK used by the CSS algorithm.
+pub const JND: f64 = 0.02;
+
+/// Chroma resolution at which the CSS binary search stops.
+const EPSILON: f64 = 0.0001;
+
+/// Iterations for boundary searches along a line (precision ≈ 1e-9).
+const SEARCH_STEPS: usize = 30;
+
+/// Upper chroma bound for boundary searches; covers Rec.2020 (≈ 0.37).
+const MAX_CHROMA: f64 = 0.5;
+
+// ============================================================================
+// Strategy and result
+// ============================================================================
+
+/// Gamut mapping strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamutMapping {
+    /// CSS Color 4 binary-search chroma reduction with a clip-and-compare
+    /// step: stops as soon as clipping is within [`JND`] of the candidate.
+    #[default]
+    Css,
+    /// Clamp each channel of the destination space to [0, 1].
+    Clip,
+    /// Reduce chroma at constant lightness and hue to the exact boundary.
+    PreserveLightness,
+    /// Project toward the achromatic point at the cusp lightness of the hue.
+    ProjectToCusp,
+    /// Ottosson's adaptive L0 projection. `alpha` trades lightness for
+    /// chroma: 0 behaves like [`PreserveLightness`](Self::PreserveLightness),
+    /// larger values project vivid colors toward L = 0.5. Ottosson uses 0.05.
+    AdaptiveL0 {
+        /// Chroma weight of the projection target
+        alpha: f64,
+    },
+}
+
+impl GamutMapping {
+    /// Parse a strategy name: `css`, `clip`, `preserve-lightness`, `cusp`
+    /// or `adaptive-l0` (with Ottosson's α = 0.05).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "css" => Some(Self::Css),
+            "clip" => Some(Self::Clip),
+            "preserve-lightness" => Some(Self::PreserveLightness),
+            "cusp" => Some(Self::ProjectToCusp),
+            "adaptive-l0" => Some(Self::AdaptiveL0 { alpha: 0.05 }),
+            _ => None,
+        }
+    }
+
+    /// Name accepted by [`from_name`](Self::from_name).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Css => "css",
+            Self::Clip => "clip",
+            Self::PreserveLightness => "preserve-lightness",
+            Self::ProjectToCusp => "cusp",
+            Self::AdaptiveL0 { .. } => "adaptive-l0",
+        }
+    }
+}
+
+/// Outcome of a gamut mapping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamutMapResult {
+    /// Mapped color, inside the destination gamut
+    pub color: OKLCH,
+    /// ΔEOK between the original and the mapped color
+    pub delta_e: f64,
+    /// Whether the original was already in gamut (then `delta_e` is 0)
+    pub was_in_gamut: bool,
+}
+
+// ============================================================================
+// Mapper
+// ============================================================================
+
+/// Maps OKLCH colors into the gamut of an RGB space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamutMapper {
+    /// Destination gamut
+    pub target: RgbSpace,
+    /// Mapping strategy
+    pub strategy: GamutMapping,
+}
+
+impl GamutMapper {
+    /// Mapper into `target` using the CSS Color 4 algorithm.
+    #[must_use]
+    pub const fn new(target: RgbSpace) -> Self {
+        Self {
+            target,
+            strategy: GamutMapping::Css,
+        }
+    }
+
+    /// Use a different mapping strategy.
+    #[must_use]
+    pub const fn with_strategy(mut self, strategy: GamutMapping) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Map a color into the destination gamut.
+    #[must_use]
+    pub fn map(&self, color: &OKLCH) -> GamutMapResult {
+        if color.is_in_gamut_of(self.target) {
+            return GamutMapResult {
+                color: *color,
+                delta_e: 0.0,
+                was_in_gamut: true,
+            };
+        }
+
+        let mapped = match self.strategy {
+            GamutMapping::Css => self.css(color),
+            GamutMapping::Clip => self.clip(color),
+            GamutMapping::PreserveLightness => self.project(color, color.l),
+            GamutMapping::ProjectToCusp => self.project(color, self.cusp(color.h).0),
+            GamutMapping::AdaptiveL0 { alpha } => {
+                self.project(color, adaptive_l0(color.l, color.c, alpha))
+            }
+        };
+
+        GamutMapResult {
+            color: mapped,
+            delta_e: delta_e_ok(color, &mapped),
+            was_in_gamut: false,
+        }
+    }
+
+    /// Map a [`Color`] into the destination gamut.
+    #[must_use]
+    pub fn map_color(&self, color: &Color) -> GamutMapResult {
+        self.map(&OKLCH::from_color(color))
+    }
+
+    /// Lightness and chroma `(L, C)` of the most chromatic in-gamut color
+    /// of hue `h` in the destination space.
+    #[must_use]
+    pub fn cusp(&self, h: f64) -> (f64, f64) {
+        // Max chroma is unimodal in L: golden-section search
+        const INV_PHI: f64 = 0.618_033_988_749_894_9;
+        let (mut lo, mut hi) = (0.0f64, 1.0f64);
+        let mut l1 = hi - INV_PHI * (hi - lo);
+        let mut l2 = lo + INV_PHI * (hi - lo);
+        let mut c1 = self.max_chroma(l1, h);
+        let mut c2 = self.max_chroma(l2, h);
+        for _ in 0..SEARCH_STEPS {
+            if c1 < c2 {
+                lo = l1;
+                l1 = l2;
+                c1 = c2;
+                l2 = lo + INV_PHI * (hi - lo);
+                c2 = self.max_chroma(l2, h);
+            } else {
+                hi = l2;
+                l2 = l1;
+                c2 = c1;
+                l1 = hi - INV_PHI * (hi - lo);
+                c1 = self.max_chroma(l1, h);
+            }
+        }
+        let l = (lo + hi) * 0.5;
+        (l, self.max_chroma(l, h))
+    }
+
+    /// Largest in-gamut chroma at lightness `l` and hue `h`.
+    #[must_use]
+    pub fn max_chroma(&self, l: f64, h: f64) -> f64 {
+        let (mut low, mut high) = (0.0f64, MAX_CHROMA);
+        for _ in 0..SEARCH_STEPS {
+            let mid = (low + high) * 0.5;
+            if OKLCH::new(l, mid, h).is_in_gamut_of(self.target) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// CSS Color 4 §13.2.2 "binary search gamut mapping with local MINDE".
+    fn css(&self, origin: &OKLCH) -> OKLCH {
+        if origin.l >= 1.0 {
+            return OKLCH::new(1.0, 0.0, origin.h);
+        }
+        if origin.l <= 0.0 {
+            return OKLCH::new(0.0, 0.0, origin.h);
+        }
+
+        let mut clipped = self.clip(origin);
+        if delta_e_ok(&clipped, origin) < JND {
+            return clipped;
+        }
+
+        let mut min = 0.0f64;
+        let mut max = origin.c;
+        let mut min_in_gamut = true;
+        while max - min > EPSILON {
+            let chroma = (min + max) * 0.5;
+            let current = OKLCH::new(origin.l, chroma, origin.h);
+            if min_in_gamut && current.is_in_gamut_of(self.target) {
+                min = chroma;
+                continue;
+            }
+            clipped = self.clip(&current);
+            let e = delta_e_ok(&clipped, &current);
+            if e < JND {
+                if JND - e < EPSILON {
+                    return clipped;
+                }
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+        clipped
+    }
+
+    /// Clamp the encoded channels of the destination space.
+    fn clip(&self, color: &OKLCH) -> OKLCH {
+        let [r, g, b] = color
+            .to_color()
+            .to_rgb_space(self.target)
+            .map(|v| v.clamp(0.0, 1.0));
+        OKLCH::from_color(&Color::from_rgb_space(self.target, r, g, b))
+    }
+
+    /// Last in-gamut point on the segment from `(l0, 0)` to the color.
+    fn project(&self, color: &OKLCH, l0: f64) -> OKLCH {
+        let l0 = l0.clamp(0.0, 1.0);
+        let mut result = OKLCH::new(l0, 0.0, color.h);
+        let (mut low, mut high) = (0.0f64, 1.0f64);
+        for _ in 0..SEARCH_STEPS {
+            let t = (low + high) * 0.5;
+            let test = OKLCH::new(l0 + t * (color.l - l0), t * color.c, color.h);
+            if test.is_in_gamut_of(self.target) {
+                result = test;
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+        result
+    }
+}
+
+// ============================================================================
+// OKLCH convenience
+// ============================================================================
+
+impl OKLCH {
+    /// Map into the gamut of `target` with the given strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::space::oklch::gamut::GamutMapping;
+    /// use momoto_core::space::oklch::OKLCH;
+    /// use momoto_core::space::rgb::RgbSpace;
+    ///
+    /// let result = OKLCH::new(0.6, 0.3, 30.0)
+    ///     .gamut_map(RgbSpace::Srgb, GamutMapping::PreserveLightness);
+    /// assert_eq!(result.color.l, 0.6);
+    /// assert!(result.delta_e > 0.0);
+    /// ```
+    #[must_use]
+    pub fn gamut_map(&self, target: RgbSpace, strategy: GamutMapping) -> GamutMapResult {
+        GamutMapper::new(target).with_strategy(strategy).map(self)
+    }
+}
+
+/// ΔEOK: Euclidean distance in OKLab.
+fn delta_e_ok(a: &OKLCH, b: &OKLCH) -> f64 {
+    let (a, b): (OKLab, OKLab) = (a.to_oklab(), b.to_oklab());
+    let dl = a.l - b.l;
+    let da = a.a - b.a;
+    let db = a.b - b.b;
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// Ottosson's adaptive L0 with the projection centred on L = 0.5.
+fn adaptive_l0(l: f64, c: f64, alpha: f64) -> f64 {
+    let ld = l - 0.5;
+    let abs_ld = ld.abs();
+    let e1 = 0.5 + abs_ld + alpha * c;
+    0.5 * (1.0 + ld.signum() * (e1 - (e1 * e1 - 2.0 * abs_ld).max(0.0).sqrt()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_STRATEGIES: [GamutMapping; 5] = [
+        GamutMapping::Css,
+        GamutMapping::Clip,
+        GamutMapping::PreserveLightness,
+        GamutMapping::ProjectToCusp,
+        GamutMapping::AdaptiveL0 { alpha: 0.05 },
+    ];
+
+    const ALL_TARGETS: [RgbSpace; 3] = [RgbSpace::Srgb, RgbSpace::DisplayP3, RgbSpace::Rec2020];
+
+    #[test]
+    fn test_in_gamut_is_unchanged() {
+        let color = OKLCH::from_color(&Color::from_srgb8(59, 130, 246));
+        for strategy in ALL_STRATEGIES {
+            let result = color.gamut_map(RgbSpace::Srgb, strategy);
+            assert!(result.was_in_gamut);
+            assert_eq!(result.color, color);
+            assert_eq!(result.delta_e, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_every_strategy_lands_in_gamut() {
+        for target in ALL_TARGETS {
+            for strategy in ALL_STRATEGIES {
+                for h in (0..360).step_by(30) {
+                    for l in [0.2, 0.5, 0.8, 0.95] {
+                        let color = OKLCH::new(l, 0.45, f64::from(h));
+                        let result = color.gamut_map(target, strategy);
+                        assert!(!result.was_in_gamut);
+                        assert!(
+                            result.color.is_in_gamut_of(target),
+                            "{:?} {:?} {:?} -> {:?}",
+                            target,
+                            strategy,
+                            color,
+                            result.color
+                        );
+                        let expected = delta_e_ok(&color, &result.color);
+                        assert!((result.delta_e - expected).abs() < 1e-12);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_css_stays_close_to_chroma_reduction() {
+        // The clipped result is within one JND of the chroma-reduced candidate
+        let mapper = GamutMapper::new(RgbSpace::Srgb);
+        for h in (0..360).step_by(15) {
+            let color = OKLCH::new(0.6, 0.4, f64::from(h));
+            let css = mapper.map(&color).color;
+            let reduced = OKLCH::new(color.l, css.c, color.h);
+            assert!(delta_e_ok(&css, &reduced) <= JND, "h = {}", h);
+            assert!((css.l - color.l).abs() < JND);
+        }
+    }
+
+    #[test]
+    fn test_css_extreme_lightness() {
+        let mapper = GamutMapper::new(RgbSpace::Srgb);
+        let white = mapper.map(&OKLCH::new(1.0, 0.2, 100.0)).color;
+        assert_eq!((white.l, white.c), (1.0, 0.0));
+        let black = mapper.map(&OKLCH::new(0.0, 0.2, 100.0)).color;
+        assert_eq!((black.l, black.c), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_preserve_lightness_matches_boundary() {
+        let color = OKLCH::new(0.7, 0.3, 140.0);
+        let result = color.gamut_map(RgbSpace::Srgb, GamutMapping::PreserveLightness);
+        assert_eq!(result.color.l, color.l);
+        assert_eq!(result.color.h, color.h);
+        let legacy = color.map_to_gamut_of(RgbSpace::Srgb);
+        assert!((result.color.c - legacy.c).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cusp() {
+        // sRGB red cusp: oklch(0.628 0.2577 29.23)
+        let (l, c) = GamutMapper::new(RgbSpace::Srgb).cusp(29.2338851923426);
+        assert!((l - 0.6279554).abs() < 1e-4, "L = {}", l);
+        assert!((c - 0.2576833).abs() < 1e-4, "C = {}", c);
+
+        // Wider gamuts have a more chromatic cusp
+        let (_, c_p3) = GamutMapper::new(RgbSpace::DisplayP3).cusp(29.2338851923426);
+        assert!(c_p3 > c);
+    }
+
+    #[test]
+    fn test_adaptive_l0() {
+        // alpha = 0 keeps lightness, huge alpha projects toward mid-grey
+        assert!((adaptive_l0(0.9, 0.2, 0.0) - 0.9).abs() < 1e-12);
+        assert!((adaptive_l0(0.9, 0.2, 1e6) - 0.5).abs() < 1e-5);
+        assert!((adaptive_l0(0.5, 0.2, 0.05) - 0.5).abs() < 1e-12);
+
+        // Projection toward L0 trades lightness for chroma
+        let color = OKLCH::new(0.9, 0.3, 260.0);
+        let adaptive = color.gamut_map(RgbSpace::Srgb, GamutMapping::AdaptiveL0 { alpha: 0.05 });
+        let preserve = color.gamut_map(RgbSpace::Srgb, GamutMapping::PreserveLightness);
+        assert!(adaptive.color.l < color.l);
+        assert!(adaptive.color.c > preserve.color.c);
+    }
+
+    #[test]
+    fn test_strategy_names() {
+        for strategy in ALL_STRATEGIES {
+            assert_eq!(GamutMapping::from_name(strategy.name()), Some(strategy));
+        }
+        assert_eq!(GamutMapping::from_name("nope"), None);
+    }
+
+    #[test]
+    fn test_wider_target_costs_less() {
+        let color = OKLCH::new(0.8, 0.3, 145.0);
+        let srgb = GamutMapper::new(RgbSpace::Srgb).map(&color);
+        let p3 = GamutMapper::new(RgbSpace::DisplayP3).map(&color);
+        let rec2020 = GamutMapper::new(RgbSpace::Rec2020).map(&color);
+        assert!(srgb.delta_e > p3.delta_e);
+        assert!(p3.delta_e > rec2020.delta_e || rec2020.was_in_gamut);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_result_serde_roundtrip() {
+        let result = OKLCH::new(0.8, 0.3, 145.0).gamut_map(RgbSpace::Srgb, GamutMapping::Css);
+        let json = serde_json::to_string(&result).unwrap();
+        let back: GamutMapResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, result);
+    }
+}
@@ -15,6 +15,13 @@
 //! - [OKLCH Specification](https://www.w3.org/TR/css-color-4/#ok-lab)
 
 use crate::color::Color;
+use crate::math::ops;
+use crate::space::rgb::RgbSpace;
+
+#[cfg(not(feature = "std"))]
+use crate::math::float::FloatFuncs;
+
+pub mod gamut;
 
 // ============================================================================
 // OKLab Transformation Matrices (from Björn Ottosson's paper)
@@ -179,9 +186,9 @@ impl OKLab {
         let s = RGB_TO_LMS[2][0] * r + RGB_TO_LMS[2][1] * g + RGB_TO_LMS[2][2] * b;
 
         // Apply cube root (perceptual transformation)
-        let l_ = l.cbrt();
-        let m_ = m.cbrt();
-        let s_ = s.cbrt();
+        let l_ = ops::cbrt(l);
+        let m_ = ops::cbrt(m);
+        let s_ = ops::cbrt(s);
 
         // LMS to Lab
         Self {
@@ -254,6 +261,7 @@ impl OKLab {
 /// let more_saturated = oklch.saturate(1.5);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OKLCH {
     /// Lightness: 0.0 (black) to 1.0 (white)
     pub l: f64,
@@ -286,7 +294,7 @@ impl OKLCH {
 
         // Cartesian to polar
         let c = (lab.a * lab.a + lab.b * lab.b).sqrt();
-        let h = lab.b.atan2(lab.a).to_degrees();
+        let h = ops::atan2(lab.b, lab.a).to_degrees();
 
         Self::new(lab.l, c, h)
     }
@@ -309,8 +317,8 @@ impl OKLCH {
         let h_rad = self.h.to_radians();
         OKLab {
             l: self.l,
-            a: self.c * h_rad.cos(),
-            b: self.c * h_rad.sin(),
+            a: self.c * ops::cos(h_rad),
+            b: self.c * ops::sin(h_rad),
         }
     }
 
@@ -599,6 +607,62 @@ impl OKLCH {
         result
     }
 
+    /// Check if color is within the gamut of an arbitrary RGB space.
+    ///
+    /// Unlike [`is_in_gamut`](Self::is_in_gamut), this is an exact test: the
+    /// color is converted through XYZ into the target space and every linear
+    /// channel must lie in [0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::space::oklch::OKLCH;
+    /// use momoto_core::space::rgb::RgbSpace;
+    ///
+    /// // Vivid green that only P3 and Rec.2020 can display
+    /// let green = OKLCH::new(0.85, 0.3, 145.0);
+    /// assert!(!green.is_in_gamut_of(RgbSpace::Srgb));
+    /// assert!(green.is_in_gamut_of(RgbSpace::Rec2020));
+    /// ```
+    #[must_use]
+    pub fn is_in_gamut_of(&self, space: RgbSpace) -> bool {
+        self.to_color().is_in_rgb_gamut(space)
+    }
+
+    /// Map to the gamut of an arbitrary RGB space by reducing chroma.
+    ///
+    /// Same strategy as [`map_to_gamut_precise`](Self::map_to_gamut_precise)
+    /// (25-step binary search, lightness and hue preserved), but the boundary
+    /// is tested in the linear channels of `space`. See [`gamut`] for the
+    /// CSS Color 4 algorithm and other strategies.
+    #[must_use]
+    pub fn map_to_gamut_of(&self, space: RgbSpace) -> Self {
+        if self.is_in_gamut_of(space) {
+            return *self;
+        }
+
+        if self.c < 1e-10 {
+            return Self::new(self.l.clamp(0.0, 1.0), 0.0, self.h);
+        }
+
+        let mut low = 0.0f64;
+        let mut high = self.c;
+        let mut result = Self::new(self.l, 0.0, self.h);
+
+        for _ in 0..25 {
+            let mid = (low + high) * 0.5;
+            let test = Self::new(self.l, mid, self.h);
+            if test.is_in_gamut_of(space) {
+                result = test;
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        result
+    }
+
     // ============================================
     // Analysis
     // ============================================
@@ -622,7 +686,7 @@ impl OKLCH {
 
         // Convert hue difference to Cartesian
         let dh_rad = dh.to_radians();
-        let dh_cart = 2.0 * (self.c * other.c).sqrt() * (dh_rad / 2.0).sin();
+        let dh_cart = 2.0 * (self.c * other.c).sqrt() * ops::sin(dh_rad / 2.0);
 
         (dl * dl + dc * dc + dh_cart * dh_cart).sqrt()
     }
@@ -1087,4 +1151,27 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_map_to_gamut_of_wide_spaces() {
+        let vivid = OKLCH::new(0.7, 0.35, 150.0);
+        let mut prev_c = 0.0;
+        for space in [RgbSpace::Srgb, RgbSpace::DisplayP3, RgbSpace::Rec2020] {
+            let mapped = vivid.map_to_gamut_of(space);
+            assert!(mapped.is_in_gamut_of(space), "{:?}: {:?}", space, mapped);
+            assert!((mapped.l - vivid.l).abs() < 1e-10);
+            assert!((mapped.h - vivid.h).abs() < 1e-10);
+            // Wider gamuts keep more chroma
+            assert!(mapped.c >= prev_c, "{:?}: {} < {}", space, mapped.c, prev_c);
+            prev_c = mapped.c;
+        }
+    }
+
+    #[test]
+    fn test_map_to_gamut_of_srgb_matches_precise() {
+        let color = OKLCH::new(0.6, 0.3, 30.0);
+        let a = color.map_to_gamut_of(RgbSpace::Srgb);
+        let b = color.map_to_gamut_precise();
+        assert!((a.c - b.c).abs() < 1e-4, "{} vs {}", a.c, b.c);
+    }
 }
@@ -0,0 +1,652 @@
+//! RGB Color Spaces (sRGB, Display P3, Rec.2020, Adobe RGB)
+//!
+//! Typed definitions of the RGB working spaces Momoto can target. Each space
+//! is described by its primaries, white point and transfer function, and
+//! converts to and from CIE XYZ (D65, Y = 1.0 for white) so that any two
+//! spaces can be bridged through XYZ.
+//!
+//! | Space        | Primaries     | White | Transfer function        |
+//! |--------------|---------------|-------|--------------------------|
+//! | sRGB         | BT.709        | D65   | IEC 61966-2-1 piecewise  |
+//! | Display P3   | DCI-P3        | D65   | sRGB piecewise           |
+//! | Rec.2020     | BT.2020       | D65   | BT.2020 OETF (α, β)      |
+//! | Adobe RGB    | Adobe 1998    | D65   | Pure gamma 563/256       |
+//!
+//! Transfer functions are sign-preserving (CSS Color 4 "extended" form), so
+//! out-of-gamut values survive a decode/encode roundtrip instead of being
+//! clamped.
+//!
+//! # References
+//!
+//! - [CSS Color Module Level 4 §10 — Predefined color spaces](https://www.w3.org/TR/css-color-4/#predefined)
+//! - IEC 61966-2-1:1999 (sRGB), SMPTE EG 432-1 (DCI-P3), ITU-R BT.2020-2,
+//!   Adobe RGB (1998) Color Image Encoding v2005-05
+
+use crate::color::{gamma, Color};
+use crate::math::ops;
+
+// ============================================================================
+// White points
+// ============================================================================
+
+/// CIE 1931 xy chromaticity of the D65 standard illuminant.
+pub const D65_XY: [f64; 2] = [0.3127, 0.3290];
+
+// ============================================================================
+// Linear RGB ↔ XYZ (D65) matrices
+// ============================================================================
+
+/// Linear sRGB → XYZ D65.
+pub const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+
+/// XYZ D65 → linear sRGB.
+pub const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+/// Linear Display P3 → XYZ D65.
+pub const DISPLAY_P3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+/// XYZ D65 → linear Display P3.
+pub const XYZ_TO_DISPLAY_P3: [[f64; 3]; 3] = [
+    [2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+    [-0.8294889695615747, 1.7626640603183463, 0.023624685841943577],
+    [0.03584583024378447, -0.07617238926804182, 0.9568845240076872],
+];
+
+/// Linear Rec.2020 → XYZ D65.
+pub const REC2020_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+    [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+    [0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+/// XYZ D65 → linear Rec.2020.
+pub const XYZ_TO_REC2020: [[f64; 3]; 3] = [
+    [1.716651187971268, -0.355670783776392, -0.253366281373660],
+    [-0.666684351832489, 1.616481236634939, 0.0157685458139111],
+    [0.017639857445311, -0.042770613257809, 0.942103121235474],
+];
+
+/// Linear Adobe RGB (1998) → XYZ D65.
+pub const ADOBE_RGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+    [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+    [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+/// XYZ D65 → linear Adobe RGB (1998).
+pub const XYZ_TO_ADOBE_RGB: [[f64; 3]; 3] = [
+    [2.0415879038107465, -0.5650069742788596, -0.34473135077832956],
+    [-0.9692436362808795, 1.8759675015077202, 0.04155505740717557],
+    [0.013444280632031142, -0.11836239223101838, 1.0151749943912054],
+];
+
+/// Tolerance used when testing linear channels against the [0, 1] gamut cube.
+///
+/// Absorbs floating-point noise from the OKLab → XYZ → RGB roundtrip so that
+/// colors sitting exactly on the gamut boundary are reported as in-gamut.
+pub const GAMUT_EPSILON: f64 = 1e-6;
+
+// ============================================================================
+// Transfer functions
+// ============================================================================
+
+/// Opto-electronic transfer function of an RGB space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferFunction {
+    /// IEC 61966-2-1 piecewise curve (sRGB, Display P3).
+    Srgb,
+    /// ITU-R BT.2020 piecewise curve (Rec.2020).
+    Rec2020,
+    /// Pure power law with the given exponent (Adobe RGB uses 563/256).
+    Gamma(f64),
+    /// Identity (linear-light encoding).
+    Linear,
+}
+
+/// BT.2020 α constant (12-bit precision form used by CSS Color 4).
+const REC2020_ALPHA: f64 = 1.09929682680944;
+/// BT.2020 β constant (12-bit precision form used by CSS Color 4).
+const REC2020_BETA: f64 = 0.018053968510807;
+
+impl TransferFunction {
+    /// Decode an encoded channel value to linear light (EOTF⁻¹ of the OETF).
+    ///
+    /// Negative inputs are mirrored so extended-range values roundtrip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::space::rgb::TransferFunction;
+    ///
+    /// let tf = TransferFunction::Rec2020;
+    /// let linear = tf.decode(0.5);
+    /// assert!((tf.encode(linear) - 0.5).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn decode(self, encoded: f64) -> f64 {
+        let sign = if encoded < 0.0 { -1.0 } else { 1.0 };
+        let v = encoded.abs();
+        let linear = match self {
+            Self::Srgb => gamma::srgb_to_linear(v),
+            Self::Rec2020 => {
+                if v < REC2020_BETA * 4.5 {
+                    v / 4.5
+                } else {
+                    ops::powf((v + REC2020_ALPHA - 1.0) / REC2020_ALPHA, 1.0 / 0.45)
+                }
+            }
+            Self::Gamma(g) => ops::powf(v, g),
+            Self::Linear => v,
+        };
+        sign * linear
+    }
+
+    /// Encode a linear-light channel value with this transfer function.
+    ///
+    /// Negative inputs are mirrored so extended-range values roundtrip.
+    #[must_use]
+    pub fn encode(self, linear: f64) -> f64 {
+        let sign = if linear < 0.0 { -1.0 } else { 1.0 };
+        let v = linear.abs();
+        let encoded = match self {
+            Self::Srgb => gamma::linear_to_srgb(v),
+            Self::Rec2020 => {
+                if v < REC2020_BETA {
+                    v * 4.5
+                } else {
+                    REC2020_ALPHA * ops::powf(v, 0.45) - (REC2020_ALPHA - 1.0)
+                }
+            }
+            Self::Gamma(g) => ops::powf(v, 1.0 / g),
+            Self::Linear => v,
+        };
+        sign * encoded
+    }
+}
+
+// ============================================================================
+// RGB space
+// ============================================================================
+
+/// An RGB working space with defined primaries, white point and transfer function.
+///
+/// # Examples
+///
+/// ```
+/// use momoto_core::color::Color;
+/// use momoto_core::space::rgb::RgbSpace;
+///
+/// // Pure P3 green is outside sRGB...
+/// let p3_green = Color::from_rgb_space(RgbSpace::DisplayP3, 0.0, 1.0, 0.0);
+/// assert!(!p3_green.is_in_rgb_gamut(RgbSpace::Srgb));
+///
+/// // ...but inside Rec.2020.
+/// assert!(p3_green.is_in_rgb_gamut(RgbSpace::Rec2020));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RgbSpace {
+    /// sRGB (IEC 61966-2-1) — the web default.
+    #[default]
+    Srgb,
+    /// Display P3 — DCI-P3 primaries with D65 white and the sRGB curve.
+    DisplayP3,
+    /// ITU-R BT.2020 — ultra-wide gamut used for HDR/UHD content.
+    Rec2020,
+    /// Adobe RGB (1998) — wide gamut common in photography and print.
+    AdobeRgb,
+}
+
+impl RgbSpace {
+    /// All supported RGB spaces, ordered from smallest to largest
+    /// [`gamut_volume`](Self::gamut_volume).
+    pub const ALL: [RgbSpace; 4] = [
+        RgbSpace::Srgb,
+        RgbSpace::DisplayP3,
+        RgbSpace::AdobeRgb,
+        RgbSpace::Rec2020,
+    ];
+
+    /// Parse from a string identifier (case-insensitive).
+    ///
+    /// Accepts CSS `color()` identifiers (`srgb`, `display-p3`, `rec2020`,
+    /// `a98-rgb`) as well as common aliases.
+    #[must_use]
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "srgb" | "s-rgb" => Some(Self::Srgb),
+            "display-p3" | "displayp3" | "p3" => Some(Self::DisplayP3),
+            "rec2020" | "rec-2020" | "rec.2020" | "bt2020" => Some(Self::Rec2020),
+            "a98-rgb" | "adobe-rgb" | "adobergb" | "adobe" => Some(Self::AdobeRgb),
+            _ => None,
+        }
+    }
+
+    /// CSS Color 4 `color()` function identifier for this space.
+    #[must_use]
+    pub const fn css_name(self) -> &'static str {
+        match self {
+            Self::Srgb => "srgb",
+            Self::DisplayP3 => "display-p3",
+            Self::Rec2020 => "rec2020",
+            Self::AdobeRgb => "a98-rgb",
+        }
+    }
+
+    /// CIE 1931 xy chromaticities of the red, green and blue primaries.
+    #[must_use]
+    pub const fn primaries(self) -> [[f64; 2]; 3] {
+        match self {
+            Self::Srgb => [[0.640, 0.330], [0.300, 0.600], [0.150, 0.060]],
+            Self::DisplayP3 => [[0.680, 0.320], [0.265, 0.690], [0.150, 0.060]],
+            Self::Rec2020 => [[0.708, 0.292], [0.170, 0.797], [0.131, 0.046]],
+            Self::AdobeRgb => [[0.640, 0.330], [0.210, 0.710], [0.150, 0.060]],
+        }
+    }
+
+    /// CIE 1931 xy chromaticity of the reference white.
+    #[must_use]
+    pub const fn white_point(self) -> [f64; 2] {
+        D65_XY
+    }
+
+    /// Transfer function used to encode linear light.
+    #[must_use]
+    pub const fn transfer_function(self) -> TransferFunction {
+        match self {
+            Self::Srgb | Self::DisplayP3 => TransferFunction::Srgb,
+            Self::Rec2020 => TransferFunction::Rec2020,
+            Self::AdobeRgb => TransferFunction::Gamma(563.0 / 256.0),
+        }
+    }
+
+    /// Linear RGB → XYZ D65 matrix.
+    #[must_use]
+    pub const fn rgb_to_xyz_matrix(self) -> &'static [[f64; 3]; 3] {
+        match self {
+            Self::Srgb => &SRGB_TO_XYZ,
+            Self::DisplayP3 => &DISPLAY_P3_TO_XYZ,
+            Self::Rec2020 => &REC2020_TO_XYZ,
+            Self::AdobeRgb => &ADOBE_RGB_TO_XYZ,
+        }
+    }
+
+    /// XYZ D65 → linear RGB matrix.
+    #[must_use]
+    pub const fn xyz_to_rgb_matrix(self) -> &'static [[f64; 3]; 3] {
+        match self {
+            Self::Srgb => &XYZ_TO_SRGB,
+            Self::DisplayP3 => &XYZ_TO_DISPLAY_P3,
+            Self::Rec2020 => &XYZ_TO_REC2020,
+            Self::AdobeRgb => &XYZ_TO_ADOBE_RGB,
+        }
+    }
+
+    /// Volume of the gamut in CIE XYZ (D65, white Y = 1.0): the determinant
+    /// of the RGB → XYZ matrix, i.e. the image of the unit linear-RGB cube.
+    ///
+    /// Use it to rank spaces by size; the ranking agrees with the gamut
+    /// volumes in OKLab (sRGB < Display P3 < Adobe RGB < Rec.2020).
+    #[must_use]
+    pub fn gamut_volume(self) -> f64 {
+        let m = self.rgb_to_xyz_matrix();
+        (m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]))
+            .abs()
+    }
+
+    /// Decode encoded channels to linear light.
+    #[must_use]
+    pub fn decode(self, encoded: [f64; 3]) -> [f64; 3] {
+        let tf = self.transfer_function();
+        [
+            tf.decode(encoded[0]),
+            tf.decode(encoded[1]),
+            tf.decode(encoded[2]),
+        ]
+    }
+
+    /// Encode linear-light channels with this space's transfer function.
+    #[must_use]
+    pub fn encode(self, linear: [f64; 3]) -> [f64; 3] {
+        let tf = self.transfer_function();
+        [
+            tf.encode(linear[0]),
+            tf.encode(linear[1]),
+            tf.encode(linear[2]),
+        ]
+    }
+
+    /// Linear RGB in this space → CIE XYZ (D65, white Y = 1.0).
+    #[must_use]
+    pub fn linear_to_xyz(self, linear: [f64; 3]) -> [f64; 3] {
+        mat3_mul_vec3(self.rgb_to_xyz_matrix(), linear)
+    }
+
+    /// CIE XYZ (D65, white Y = 1.0) → linear RGB in this space (unclamped).
+    #[must_use]
+    pub fn xyz_to_linear(self, xyz: [f64; 3]) -> [f64; 3] {
+        mat3_mul_vec3(self.xyz_to_rgb_matrix(), xyz)
+    }
+
+    /// Convert linear RGB from this space into another RGB space (unclamped).
+    #[must_use]
+    pub fn convert_linear(self, linear: [f64; 3], target: RgbSpace) -> [f64; 3] {
+        if self == target {
+            return linear;
+        }
+        target.xyz_to_linear(self.linear_to_xyz(linear))
+    }
+
+    /// Check whether linear channels lie inside the [0, 1] cube of this space.
+    ///
+    /// Uses [`GAMUT_EPSILON`] as tolerance.
+    #[must_use]
+    pub fn contains_linear(linear: [f64; 3]) -> bool {
+        linear
+            .iter()
+            .all(|&c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(&c))
+    }
+}
+
+impl core::fmt::Display for RgbSpace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.css_name())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<crate::render::ColorSpace> for RgbSpace {
+    /// Map a render output space to its RGB gamut.
+    ///
+    /// `LinearRgb` shares sRGB primaries, so it maps to [`RgbSpace::Srgb`].
+    fn from(space: crate::render::ColorSpace) -> Self {
+        use crate::render::ColorSpace;
+        match space {
+            ColorSpace::SRgb | ColorSpace::LinearRgb => Self::Srgb,
+            ColorSpace::DisplayP3 => Self::DisplayP3,
+            ColorSpace::Rec2020 => Self::Rec2020,
+        }
+    }
+}
+
+// ============================================================================
+// Color integration
+// ============================================================================
+
+impl Color {
+    /// Creates a color from encoded channel values in an arbitrary RGB space.
+    ///
+    /// Values outside sRGB are preserved unclamped in the `srgb`/`linear`
+    /// fields, so wide-gamut colors survive conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::Color;
+    /// use momoto_core::space::rgb::RgbSpace;
+    ///
+    /// let white = Color::from_rgb_space(RgbSpace::DisplayP3, 1.0, 1.0, 1.0);
+    /// assert_eq!(white.to_srgb8(), [255, 255, 255]);
+    /// ```
+    #[must_use]
+    pub fn from_rgb_space(space: RgbSpace, r: f64, g: f64, b: f64) -> Self {
+        let linear = space.decode([r, g, b]);
+        let srgb_linear = space.convert_linear(linear, RgbSpace::Srgb);
+        Self::from_linear_extended(srgb_linear)
+    }
+
+    /// Returns the encoded channel values of this color in an RGB space.
+    ///
+    /// The result is unclamped; channels outside [0, 1] mean the color is
+    /// outside that space's gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::color::Color;
+    /// use momoto_core::space::rgb::RgbSpace;
+    ///
+    /// let red = Color::from_srgb8(255, 0, 0);
+    /// let [r, g, b] = red.to_rgb_space(RgbSpace::DisplayP3);
+    /// assert!(r < 1.0 && g > 0.0 && b > 0.0); // sRGB red sits inside P3
+    /// ```
+    #[must_use]
+    pub fn to_rgb_space(&self, space: RgbSpace) -> [f64; 3] {
+        space.encode(self.to_linear_rgb_space(space))
+    }
+
+    /// Returns the linear-light channel values of this color in an RGB space.
+    #[must_use]
+    pub fn to_linear_rgb_space(&self, space: RgbSpace) -> [f64; 3] {
+        RgbSpace::Srgb.convert_linear(self.linear, space)
+    }
+
+    /// Returns CIE XYZ (D65, white Y = 1.0) for this color.
+    #[must_use]
+    pub fn to_xyz_d65(&self) -> [f64; 3] {
+        RgbSpace::Srgb.linear_to_xyz(self.linear)
+    }
+
+    /// Creates a color from CIE XYZ (D65, white Y = 1.0), unclamped.
+    #[must_use]
+    pub fn from_xyz_d65(xyz: [f64; 3]) -> Self {
+        Self::from_linear_extended(RgbSpace::Srgb.xyz_to_linear(xyz))
+    }
+
+    /// Check whether this color is displayable in the given RGB space.
+    #[must_use]
+    pub fn is_in_rgb_gamut(&self, space: RgbSpace) -> bool {
+        RgbSpace::contains_linear(self.to_linear_rgb_space(space))
+    }
+
+    /// Creates a color from unclamped linear sRGB, encoding sign-preservingly.
+    pub(crate) fn from_linear_extended(linear: [f64; 3]) -> Self {
+        let srgb = RgbSpace::Srgb.encode(linear);
+        Self {
+            srgb,
+            linear,
+            alpha: 1.0,
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+#[inline]
+fn mat3_mul_vec3(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Derive the RGB → XYZ matrix from primaries and white point
+    /// (Lindbloom, "RGB/XYZ Matrices").
+    fn derive_matrix(space: RgbSpace) -> [[f64; 3]; 3] {
+        let xyz = |xy: [f64; 2]| [xy[0] / xy[1], 1.0, (1.0 - xy[0] - xy[1]) / xy[1]];
+        let [r, g, b] = space.primaries();
+        let (r, g, b) = (xyz(r), xyz(g), xyz(b));
+        let w = xyz(space.white_point());
+        let m = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+        let s = mat3_mul_vec3(&invert(&m), w);
+        let mut out = m;
+        for row in &mut out {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v *= s[j];
+            }
+        }
+        out
+    }
+
+    fn invert(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+        let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        let c = |a: usize, b: usize, c: usize, d: usize| m[a][b] * m[c][d];
+        [
+            [
+                (c(1, 1, 2, 2) - c(1, 2, 2, 1)) / det,
+                (c(0, 2, 2, 1) - c(0, 1, 2, 2)) / det,
+                (c(0, 1, 1, 2) - c(0, 2, 1, 1)) / det,
+            ],
+            [
+                (c(1, 2, 2, 0) - c(1, 0, 2, 2)) / det,
+                (c(0, 0, 2, 2) - c(0, 2, 2, 0)) / det,
+                (c(0, 2, 1, 0) - c(0, 0, 1, 2)) / det,
+            ],
+            [
+                (c(1, 0, 2, 1) - c(1, 1, 2, 0)) / det,
+                (c(0, 1, 2, 0) - c(0, 0, 2, 1)) / det,
+                (c(0, 0, 1, 1) - c(0, 1, 1, 0)) / det,
+            ],
+        ]
+    }
+
+    #[test]
+    fn test_matrices_match_primaries() {
+        for space in RgbSpace::ALL {
+            let derived = derive_matrix(space);
+            let table = space.rgb_to_xyz_matrix();
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert!(
+                        (derived[i][j] - table[i][j]).abs() < 1e-4,
+                        "{:?}[{}][{}]: derived {} vs table {}",
+                        space, i, j, derived[i][j], table[i][j]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrices_are_inverses() {
+        for space in RgbSpace::ALL {
+            let v = [0.2, 0.5, 0.8];
+            let back = space.xyz_to_linear(space.linear_to_xyz(v));
+            for i in 0..3 {
+                assert!((back[i] - v[i]).abs() < 1e-9, "{:?}: {:?}", space, back);
+            }
+        }
+    }
+
+    #[test]
+    fn test_white_maps_to_d65() {
+        for space in RgbSpace::ALL {
+            let xyz = space.linear_to_xyz([1.0, 1.0, 1.0]);
+            assert!((xyz[0] - 0.9505).abs() < 1e-3, "{:?}: {:?}", space, xyz);
+            assert!((xyz[1] - 1.0).abs() < 1e-9, "{:?}: {:?}", space, xyz);
+            assert!((xyz[2] - 1.089).abs() < 1e-3, "{:?}: {:?}", space, xyz);
+        }
+    }
+
+    #[test]
+    fn test_transfer_roundtrip() {
+        for space in RgbSpace::ALL {
+            let tf = space.transfer_function();
+            for v in [-0.5, 0.0, 0.01, 0.2, 0.5, 0.9, 1.0, 1.3] {
+                let back = tf.encode(tf.decode(v));
+                assert!((back - v).abs() < 1e-10, "{:?} {}: {}", space, v, back);
+            }
+        }
+    }
+
+    #[test]
+    fn test_p3_red_outside_srgb() {
+        let p3_red = Color::from_rgb_space(RgbSpace::DisplayP3, 1.0, 0.0, 0.0);
+        assert!(!p3_red.is_in_rgb_gamut(RgbSpace::Srgb));
+        assert!(p3_red.is_in_rgb_gamut(RgbSpace::DisplayP3));
+        // The P3 red primary sits marginally outside the BT.2020 red–green edge
+        let rec2020 = p3_red.to_linear_rgb_space(RgbSpace::Rec2020);
+        assert!((rec2020[0] - 0.7538).abs() < 1e-3, "{:?}", rec2020);
+        assert!(rec2020[2] < 0.0 && rec2020[2] > -0.002, "{:?}", rec2020);
+        // Known value: color(display-p3 1 0 0) ≈ rgb(109.3%, -22.7%, -15.0%) in sRGB
+        assert!((p3_red.srgb[0] - 1.0931).abs() < 1e-3, "{:?}", p3_red.srgb);
+        assert!(p3_red.srgb[1] < 0.0 && p3_red.srgb[2] < 0.0);
+    }
+
+    #[test]
+    fn test_srgb_roundtrip_through_wide_spaces() {
+        let color = Color::from_srgb8(59, 130, 246);
+        for space in RgbSpace::ALL {
+            let [r, g, b] = color.to_rgb_space(space);
+            let back = Color::from_rgb_space(space, r, g, b);
+            assert_eq!(back.to_srgb8(), color.to_srgb8(), "{:?}", space);
+        }
+    }
+
+    #[test]
+    fn test_gamut_nesting() {
+        // Every sRGB primary is inside all wider gamuts.
+        for rgb in [(255, 0, 0), (0, 255, 0), (0, 0, 255)] {
+            let c = Color::from_srgb8(rgb.0, rgb.1, rgb.2);
+            for space in RgbSpace::ALL {
+                assert!(c.is_in_rgb_gamut(space), "{:?} in {:?}", rgb, space);
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_ordered_by_gamut_volume() {
+        let volumes = RgbSpace::ALL.map(RgbSpace::gamut_volume);
+        assert!(volumes.windows(2).all(|w| w[0] < w[1]), "{:?}", volumes);
+
+        // Independent estimate: the share of a Rec.2020 linear-RGB grid that
+        // lands inside each gamut approximates its volume ratio.
+        let steps = 24;
+        let axis = |i: usize| (i as f64 + 0.5) / steps as f64;
+        for space in RgbSpace::ALL {
+            let mut inside = 0;
+            for r in 0..steps {
+                for g in 0..steps {
+                    for b in 0..steps {
+                        let linear =
+                            RgbSpace::Rec2020.convert_linear([axis(r), axis(g), axis(b)], space);
+                        if RgbSpace::contains_linear(linear) {
+                            inside += 1;
+                        }
+                    }
+                }
+            }
+            let share = f64::from(inside) / (steps * steps * steps) as f64;
+            let expected = space.gamut_volume() / RgbSpace::Rec2020.gamut_volume();
+            assert!(
+                (share - expected).abs() < 0.03,
+                "{:?}: {} vs {}",
+                space,
+                share,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(RgbSpace::from_name("display-p3"), Some(RgbSpace::DisplayP3));
+        assert_eq!(RgbSpace::from_name("Rec2020"), Some(RgbSpace::Rec2020));
+        assert_eq!(RgbSpace::from_name("a98-rgb"), Some(RgbSpace::AdobeRgb));
+        assert_eq!(RgbSpace::from_name("srgb"), Some(RgbSpace::Srgb));
+        assert_eq!(RgbSpace::from_name("cmyk"), None);
+    }
+}
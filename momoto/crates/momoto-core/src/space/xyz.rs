@@ -0,0 +1,446 @@
+//! CIE XYZ, Standard Illuminants and Chromatic Adaptation
+//!
+//! CIE 1931 XYZ is the hub every other space in Momoto converts through.
+//! Values use the relative scale where the reference white has Y = 1.0
+//! (CAM16 works in Y = 100; [`Xyz::to_cam16`] rescales).
+//!
+//! Chromatic adaptation transforms (CATs) move a color between reference
+//! whites with a von Kries scaling in a cone-like space:
+//!
+//! | Transform | Cone matrix            | Used by                          |
+//! |-----------|------------------------|----------------------------------|
+//! | Bradford  | Lam (1985)             | ICC v4, CSS Color 4 (D65 ↔ D50)  |
+//! | CAT02     | CIECAM02 (CIE 159)     | CIECAM02                         |
+//! | CAT16     | M16 (Li et al. 2017)   | CAM16 / HCT                      |
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::space::xyz::{ChromaticAdaptation, Illuminant, Xyz};
+//!
+//! // The D65 white adapts exactly onto the D50 white
+//! let white = Illuminant::D65.white_point();
+//! let adapted = white.adapt(Illuminant::D65, Illuminant::D50, ChromaticAdaptation::Bradford);
+//! let d50 = Illuminant::D50.white_point();
+//! assert!((adapted.x - d50.x).abs() < 1e-12);
+//! assert!((adapted.z - d50.z).abs() < 1e-12);
+//! ```
+//!
+//! # References
+//!
+//! - CIE 15:2004 Colorimetry, 3rd edition
+//! - Lam, K. M. (1985). Metamerism and Colour Constancy (Bradford transform)
+//! - CIE 159:2004 A colour appearance model for colour management systems: CIECAM02
+//! - Li, C. et al. (2017). Comprehensive colour appearance model (CAM16)
+
+use crate::color::Color;
+use crate::space::hct::cam16::{mat3_mul_vec3, ViewingConditions, CAM16, M16};
+use crate::space::oklch::OKLab;
+
+// ============================================================================
+// XYZ
+// ============================================================================
+
+/// CIE 1931 XYZ tristimulus values (reference white Y = 1.0).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xyz {
+    /// X tristimulus value
+    pub x: f64,
+    /// Y tristimulus value (relative luminance)
+    pub y: f64,
+    /// Z tristimulus value
+    pub z: f64,
+}
+
+impl Xyz {
+    /// Create XYZ tristimulus values.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Create XYZ from xyY (chromaticity plus luminance).
+    ///
+    /// Returns black when `y` is zero.
+    #[must_use]
+    pub fn from_chromaticity(x: f64, y: f64, luminance: f64) -> Self {
+        if y == 0.0 {
+            return Self::default();
+        }
+        Self {
+            x: x * luminance / y,
+            y: luminance,
+            z: (1.0 - x - y) * luminance / y,
+        }
+    }
+
+    /// CIE 1931 xy chromaticity, or `None` for black.
+    #[must_use]
+    pub fn chromaticity(self) -> Option<[f64; 2]> {
+        let sum = self.x + self.y + self.z;
+        if sum.abs() < f64::EPSILON {
+            None
+        } else {
+            Some([self.x / sum, self.y / sum])
+        }
+    }
+
+    /// Components as `[x, y, z]`.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Adapt these values from one reference white to another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_core::space::xyz::{ChromaticAdaptation, Illuminant, Xyz};
+    ///
+    /// let red_d65 = Xyz::new(0.4124, 0.2126, 0.0193);
+    /// let red_d50 = red_d65.adapt(Illuminant::D65, Illuminant::D50, ChromaticAdaptation::Bradford);
+    /// assert!((red_d50.x - 0.4360).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn adapt(self, from: Illuminant, to: Illuminant, method: ChromaticAdaptation) -> Self {
+        method.adapt(self, from.white_point(), to.white_point())
+    }
+
+    /// Create XYZ (D65) from a [`Color`]. Unclamped for wide-gamut colors.
+    #[must_use]
+    pub fn from_color(color: &Color) -> Self {
+        color.to_xyz_d65().into()
+    }
+
+    /// Convert XYZ (D65) to a [`Color`]. Out-of-sRGB values are kept.
+    #[must_use]
+    pub fn to_color(self) -> Color {
+        Color::from_xyz_d65(self.to_array())
+    }
+
+    /// CAM16 appearance correlates of these values (Y = 1.0 scale).
+    #[must_use]
+    pub fn to_cam16(self, vc: &ViewingConditions) -> CAM16 {
+        CAM16::from_xyz([self.x * 100.0, self.y * 100.0, self.z * 100.0], vc)
+    }
+
+    /// XYZ (Y = 1.0 scale) of a CAM16 stimulus under the given conditions.
+    #[must_use]
+    pub fn from_cam16(cam: &CAM16, vc: &ViewingConditions) -> Self {
+        let [x, y, z] = cam.to_xyz(vc);
+        Self::new(x / 100.0, y / 100.0, z / 100.0)
+    }
+}
+
+impl From<[f64; 3]> for Xyz {
+    fn from(v: [f64; 3]) -> Self {
+        Self::new(v[0], v[1], v[2])
+    }
+}
+
+impl From<Xyz> for [f64; 3] {
+    fn from(xyz: Xyz) -> Self {
+        xyz.to_array()
+    }
+}
+
+// ============================================================================
+// Illuminants
+// ============================================================================
+
+/// Standard illuminants (CIE 1931 2° observer) used as reference whites.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Illuminant {
+    /// Incandescent / tungsten (2856 K)
+    A,
+    /// Horizon daylight (5003 K) — ICC profile connection space, print
+    D50,
+    /// Mid-morning daylight (5503 K) — photography
+    D55,
+    /// Noon daylight (6504 K) — sRGB, Display P3, Rec.2020
+    #[default]
+    D65,
+    /// North sky daylight (7504 K)
+    D75,
+    /// Equal-energy illuminant
+    E,
+    /// Arbitrary white point (normalized so Y = 1.0 is expected)
+    Custom(Xyz),
+}
+
+impl Illuminant {
+    /// CIE 1931 xy chromaticity of this white.
+    ///
+    /// D50 and D65 use the 4-digit values of CSS Color 4 so that matrices
+    /// derived here agree with browser engines.
+    #[must_use]
+    pub fn chromaticity(self) -> [f64; 2] {
+        match self {
+            Self::A => [0.44757, 0.40745],
+            Self::D50 => [0.3457, 0.3585],
+            Self::D55 => [0.33242, 0.34743],
+            Self::D65 => crate::space::rgb::D65_XY,
+            Self::D75 => [0.29902, 0.31485],
+            Self::E => [1.0 / 3.0, 1.0 / 3.0],
+            Self::Custom(xyz) => xyz.chromaticity().unwrap_or(crate::space::rgb::D65_XY),
+        }
+    }
+
+    /// XYZ of this white with Y = 1.0.
+    #[must_use]
+    pub fn white_point(self) -> Xyz {
+        match self {
+            Self::Custom(xyz) => xyz,
+            other => {
+                let [x, y] = other.chromaticity();
+                Xyz::from_chromaticity(x, y, 1.0)
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Chromatic adaptation
+// ============================================================================
+
+/// Bradford cone response matrix (Lam 1985).
+pub const BRADFORD: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// CAT02 cone response matrix (CIECAM02).
+pub const CAT02: [[f64; 3]; 3] = [
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+];
+
+/// Chromatic adaptation transform used to change reference whites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChromaticAdaptation {
+    /// Bradford transform (ICC, CSS Color 4)
+    #[default]
+    Bradford,
+    /// CIECAM02 transform
+    Cat02,
+    /// CAM16 transform (same cone space as [`ViewingConditions`])
+    Cat16,
+}
+
+impl ChromaticAdaptation {
+    /// Cone response matrix (XYZ → cone-like RGB).
+    #[must_use]
+    pub const fn cone_matrix(self) -> &'static [[f64; 3]; 3] {
+        match self {
+            Self::Bradford => &BRADFORD,
+            Self::Cat02 => &CAT02,
+            Self::Cat16 => &M16,
+        }
+    }
+
+    /// Full adaptation matrix `M⁻¹ · diag(ρ_dst / ρ_src) · M` between two whites.
+    #[must_use]
+    pub fn matrix(self, from_white: Xyz, to_white: Xyz) -> [[f64; 3]; 3] {
+        let m = self.cone_matrix();
+        let src = mat3_mul_vec3(m, from_white.to_array());
+        let dst = mat3_mul_vec3(m, to_white.to_array());
+        let mut scaled = *m;
+        for (row, (s, d)) in scaled.iter_mut().zip(src.iter().zip(dst)) {
+            for v in row.iter_mut() {
+                *v *= d / s;
+            }
+        }
+        mat3_mul(&mat3_inverse(m), &scaled)
+    }
+
+    /// Adapt `xyz` from one white point to another.
+    #[must_use]
+    pub fn adapt(self, xyz: Xyz, from_white: Xyz, to_white: Xyz) -> Xyz {
+        if from_white == to_white {
+            return xyz;
+        }
+        mat3_mul_vec3(&self.matrix(from_white, to_white), xyz.to_array()).into()
+    }
+}
+
+// ============================================================================
+// Interop
+// ============================================================================
+
+impl OKLab {
+    /// Create OKLab from XYZ (D65).
+    #[must_use]
+    pub fn from_xyz(xyz: Xyz) -> Self {
+        Self::from_color(&xyz.to_color())
+    }
+
+    /// Convert to XYZ (D65).
+    #[must_use]
+    pub fn to_xyz(&self) -> Xyz {
+        Xyz::from_color(&self.to_color())
+    }
+}
+
+pub(crate) fn mat3_mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            *v = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    out
+}
+
+pub(crate) fn mat3_inverse(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let cof =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let det = m[0][0] * cof(1, 2, 1, 2) - m[0][1] * cof(1, 2, 0, 2) + m[0][2] * cof(1, 2, 0, 1);
+    let inv = 1.0 / det;
+    [
+        [
+            cof(1, 2, 1, 2) * inv,
+            -cof(0, 2, 1, 2) * inv,
+            cof(0, 1, 1, 2) * inv,
+        ],
+        [
+            -cof(1, 2, 0, 2) * inv,
+            cof(0, 2, 0, 2) * inv,
+            -cof(0, 1, 0, 2) * inv,
+        ],
+        [
+            cof(1, 2, 0, 1) * inv,
+            -cof(0, 2, 0, 1) * inv,
+            cof(0, 1, 0, 1) * inv,
+        ],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_CATS: [ChromaticAdaptation; 3] = [
+        ChromaticAdaptation::Bradford,
+        ChromaticAdaptation::Cat02,
+        ChromaticAdaptation::Cat16,
+    ];
+
+    fn assert_close(a: Xyz, b: Xyz, tol: f64) {
+        assert!(
+            (a.x - b.x).abs() < tol && (a.y - b.y).abs() < tol && (a.z - b.z).abs() < tol,
+            "{:?} vs {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_white_points() {
+        let d65 = Illuminant::D65.white_point();
+        assert_close(d65, Xyz::new(0.95045592705, 1.0, 1.08905775076), 1e-9);
+        let d50 = Illuminant::D50.white_point();
+        assert_close(d50, Xyz::new(0.96429567643, 1.0, 0.82510460251), 1e-9);
+        assert_eq!(Illuminant::E.white_point().x, 1.0);
+    }
+
+    #[test]
+    fn test_bradford_matches_css_color_4() {
+        // CSS Color 4 D65 → D50 Bradford matrix
+        let css = [
+            [
+                1.0479298208405488,
+                0.022946793341019088,
+                -0.05019222954313557,
+            ],
+            [
+                0.029627815688159344,
+                0.990434484573249,
+                -0.01707382502938514,
+            ],
+            [
+                -0.009243058152591178,
+                0.015055144896577895,
+                0.7518742899580008,
+            ],
+        ];
+        let m = ChromaticAdaptation::Bradford
+            .matrix(Illuminant::D65.white_point(), Illuminant::D50.white_point());
+        for i in 0..3 {
+            for j in 0..3 {
+                // CSS rounds its white points differently past 7 decimals
+                assert!((m[i][j] - css[i][j]).abs() < 1e-6, "{:?}", m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_white_maps_to_white() {
+        for cat in ALL_CATS {
+            for (from, to) in [
+                (Illuminant::D65, Illuminant::D50),
+                (Illuminant::A, Illuminant::D65),
+                (Illuminant::E, Illuminant::D75),
+            ] {
+                let adapted = from.white_point().adapt(from, to, cat);
+                assert_close(adapted, to.white_point(), 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_adaptation_roundtrip() {
+        let xyz = Xyz::new(0.3, 0.2, 0.6);
+        for cat in ALL_CATS {
+            let there = xyz.adapt(Illuminant::D65, Illuminant::A, cat);
+            let back = there.adapt(Illuminant::A, Illuminant::D65, cat);
+            assert_close(back, xyz, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_chromaticity_roundtrip() {
+        let xyz = Xyz::new(0.25, 0.4, 0.1);
+        let [x, y] = xyz.chromaticity().unwrap();
+        assert_close(Xyz::from_chromaticity(x, y, xyz.y), xyz, 1e-12);
+        assert_eq!(Xyz::default().chromaticity(), None);
+    }
+
+    #[test]
+    fn test_color_and_oklab_interop() {
+        let color = Color::from_srgb8(59, 130, 246);
+        let xyz = Xyz::from_color(&color);
+        assert_eq!(xyz.to_color().to_srgb8(), [59, 130, 246]);
+
+        let lab = OKLab::from_xyz(xyz);
+        let direct = OKLab::from_color(&color);
+        assert!((lab.l - direct.l).abs() < 1e-12);
+        assert_close(lab.to_xyz(), xyz, 1e-6);
+    }
+
+    #[test]
+    fn test_cam16_interop() {
+        let vc = ViewingConditions::s_rgb();
+        let white = Xyz::from_color(&Color::from_srgb8(255, 255, 255));
+        let cam = white.to_cam16(&vc);
+        assert!((cam.j - 100.0).abs() < 0.1, "J = {}", cam.j);
+
+        let blue = Xyz::from_color(&Color::from_srgb8(59, 130, 246));
+        let back = Xyz::from_cam16(&blue.to_cam16(&vc), &vc);
+        // M16_INV is published to 7 digits
+        assert_close(back, blue, 5e-4);
+
+        // Under D50 viewing conditions the D50 white is achromatic
+        let d50 = Illuminant::D50.white_point();
+        let vc = ViewingConditions::with_white_point(d50, 64.0, 20.0, 2.0, true);
+        let cam = d50.to_cam16(&vc);
+        assert!((cam.j - 100.0).abs() < 1e-6 && cam.c < 1e-3, "{:?}", cam);
+    }
+}
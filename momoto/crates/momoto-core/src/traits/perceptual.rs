@@ -11,6 +11,8 @@
 //!
 //! Hot paths (WASM exports, batch processing) must use `FlatPerceptualMetric`.
 
+use alloc::vec::Vec;
+
 /// Generic perceptual metric over an arbitrary signal type `S`.
 ///
 /// Implementors produce a `Copy + PartialOrd` output that represents a
@@ -0,0 +1,579 @@
+//! Correct rounding of `momoto_core::math::ops` under `deterministic`.
+//!
+//! Each table pairs input bits with the exact result rounded to nearest,
+//! ties to even, computed independently with mpmath at 1300 bits. The
+//! inputs mix uniform samples with the hard spots: huge trigonometric
+//! arguments, results near the overflow and underflow thresholds, arguments
+//! one ulp from 1 and exactly representable powers.
+//!
+//! Run with `cargo test -p momoto-core --features deterministic --test correct_rounding`.
+
+#![cfg(feature = "deterministic")]
+
+use momoto_core::math::ops;
+
+fn check_unary(name: &str, f: fn(f64) -> f64, table: &[(u64, u64)]) {
+    for &(x, expected) in table {
+        let x = f64::from_bits(x);
+        let got = f(x);
+        assert_eq!(
+            got.to_bits(),
+            expected,
+            "{name}({x:e}) = {got:e}, expected {:e}",
+            f64::from_bits(expected)
+        );
+    }
+}
+
+fn check_binary(name: &str, f: fn(f64, f64) -> f64, table: &[(u64, u64, u64)]) {
+    for &(x, y, expected) in table {
+        let (x, y) = (f64::from_bits(x), f64::from_bits(y));
+        let got = f(x, y);
+        assert_eq!(
+            got.to_bits(),
+            expected,
+            "{name}({x:e}, {y:e}) = {got:e}, expected {:e}",
+            f64::from_bits(expected)
+        );
+    }
+}
+
+#[test]
+fn test_exponentials() {
+    check_unary("exp", ops::exp, EXP);
+    check_unary("exp2", ops::exp2, EXP2);
+    check_unary("exp_m1", ops::exp_m1, EXP_M1);
+    check_binary("powf", ops::powf, POWF);
+}
+
+#[test]
+fn test_logarithms() {
+    check_unary("ln", ops::ln, LN);
+    check_unary("ln_1p", ops::ln_1p, LN_1P);
+    check_unary("log2", ops::log2, LOG2);
+    check_unary("log10", ops::log10, LOG10);
+}
+
+#[test]
+fn test_trigonometry() {
+    check_unary("sin", ops::sin, SIN);
+    check_unary("cos", ops::cos, COS);
+    check_unary("tan", ops::tan, TAN);
+}
+
+#[test]
+fn test_inverse_trigonometry() {
+    check_unary("asin", ops::asin, ASIN);
+    check_unary("acos", ops::acos, ACOS);
+    check_unary("atan", ops::atan, ATAN);
+    check_binary("atan2", ops::atan2, ATAN2);
+}
+
+#[test]
+fn test_hyperbolic() {
+    check_unary("sinh", ops::sinh, SINH);
+    check_unary("cosh", ops::cosh, COSH);
+    check_unary("tanh", ops::tanh, TANH);
+}
+
+#[test]
+fn test_hypot() {
+    check_binary("hypot", ops::hypot, HYPOT);
+}
+
+const EXP: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x4005_bf0a_8b14_5769),
+    (0xbff0_0000_0000_0000, 0x3fd7_8b56_362c_ef38),
+    (0x3fe0_0000_0000_0000, 0x3ffa_6129_8e1e_069c),
+    (0x4086_2c00_0000_0000, 0x7fe8_1e9b_4b52_d0c9),
+    (0xc087_4000_0000_0000, 0x0000_0000_0000_0002),
+    (0xc086_2400_0000_0000, 0x000e_6cf6_d088_97ac),
+    (0x3ddb_7cdf_d9d7_bdbb, 0x3ff0_0000_0006_df38),
+    (0xbd8a_6366_41c4_df1a, 0x3fef_ffff_ffff_9672),
+    (0x4059_1000_0000_0000, 0x48f8_c39b_9134_bac4),
+    (0xc073_34f8_f69b_115c, 0x2439_01c9_315d_7674),
+    (0xc055_c075_0481_71b0, 0x3816_3e00_ac5f_6e4d),
+    (0x406c_9bc7_cda8_9d08, 0x5492_36a3_4d61_4054),
+    (0xc035_b0d8_7ddc_8320, 0x3dfa_1d57_6011_e06f),
+    (0x4079_a66c_17e9_db38, 0x64f0_f5b7_a90a_1d88),
+    (0x4083_38ab_bb5e_b97e, 0x7764_cc7b_15cd_75eb),
+    (0x403e_3146_11d8_d260, 0x42a7_9079_e01d_b12d),
+    (0x4053_48cb_3a79_b138, 0x46e3_8134_65db_9d9c),
+    (0x4052_b087_54af_1ea8, 0x46ac_e850_856e_419c),
+    (0xc06d_c8b6_0628_1b28, 0x2a72_f900_60e4_17e8),
+    (0xc07c_529f_9176_ac05, 0x1712_ab60_20da_4d66),
+    (0x4082_9ab2_1f22_2e9e, 0x759d_a506_f574_438a),
+    (0x3faa_9ac9_6598_d680, 0x3ff0_da76_bf8b_fe61),
+    (0x3fc9_938b_640f_8cf0, 0x3ff3_89f0_f631_8e92),
+    (0xbffa_788d_6e92_9d70, 0x3fc8_7943_8031_34f1),
+    (0x3fea_c1ec_12f6_f4cc, 0x4002_75cb_933f_f288),
+    (0xbff5_87c8_95fa_3f7e, 0x3fd0_a9e8_5b79_187d),
+    (0xbfe7_afd8_4187_b648, 0x3fde_8759_c0e8_5fe4),
+    (0xbfc9_e21f_4f22_2c20, 0x3fea_2436_f587_db7b),
+    (0x3fd0_9429_c45e_8258, 0x3ff4_bb25_26ba_e6c0),
+];
+
+const EXP2: &[(u64, u64)] = &[
+    (0x3fe0_0000_0000_0000, 0x3ff6_a09e_667f_3bcd),
+    (0xbfe0_0000_0000_0000, 0x3fe6_a09e_667f_3bcd),
+    (0x408f_fc00_0000_0000, 0x7fe6_a09e_667f_3bcd),
+    (0xc090_b933_3333_3333, 0x0000_0000_0000_000d),
+    (0x3e11_2e0b_e826_d695, 0x3ff0_0000_002f_a1f9),
+    (0x4024_3333_3333_3333, 0x4091_25fb_ee25_0663),
+    (0x407a_2740_7ce5_8920, 0x5a15_e7e5_9b42_fc27),
+    (0xc08e_fa97_7742_4163, 0x01f9_9062_bd86_d39f),
+    (0x408d_d087_6f44_4170, 0x7b90_c01f_32e0_ba0d),
+    (0x4082_ad6d_3a38_ff9c, 0x6549_9aca_144c_fc65),
+    (0x405b_8940_e844_c8a0, 0x46d1_afc6_75c9_5bf7),
+    (0x4081_9ce3_95b1_0e98, 0x6328_706f_a2dd_780c),
+    (0xc076_156d_ee49_9e24, 0x29d9_4afc_7d9f_15af),
+    (0x4031_6e45_27a0_c360, 0x4105_911c_3f3b_44cc),
+    (0xc023_bd02_8215_96c0, 0x3f51_84d6_97b8_c822),
+    (0x408d_641d_3ab6_59f6, 0x7ab6_da35_2eb2_f362),
+    (0x3ff1_204a_7150_25d2, 0x4000_ccc8_590c_4bfe),
+    (0xbff0_9988_d588_fd92, 0x3fdf_2de5_b128_c87d),
+    (0xbfe6_b478_aa5a_eab4, 0x3fe3_9190_ce75_2170),
+    (0xbfd9_5f12_a66b_db08, 0x3fe8_4fc4_379e_c0ca),
+    (0xbfe6_ea10_c922_0c94, 0x3fe3_7ae6_62e8_4ae5),
+    (0xbfe9_4c99_ba24_f5fc, 0x3fe2_7fd2_9c25_28d8),
+];
+
+const EXP_M1: &[(u64, u64)] = &[
+    (0x3ddb_7cdf_d9d7_bdbb, 0x3ddb_7cdf_d9dd_a4e3),
+    (0xbddb_7cdf_d9d7_bdbb, 0xbddb_7cdf_d9d1_d693),
+    (0x3fe0_0000_0000_0000, 0x3fe4_c253_1c3c_0d38),
+    (0xbfe0_0000_0000_0000, 0xbfd9_2e9a_0720_d3ec),
+    (0x3ff0_0000_0000_0000, 0x3ffb_7e15_1628_aed3),
+    (0xc03e_0000_0000_0000, 0xbfef_ffff_ffff_fcb5),
+    (0x4085_e000_0000_0000, 0x7f0d_945d_f4f8_ec8e),
+    (0x3cb5_9e05_f1e2_674d, 0x3cb5_9e05_f1e2_674e),
+    (0xbfeb_7de5_aee4_5f2c, 0xbfe2_7266_0163_fbad),
+    (0x3fe1_9125_7e54_4b1a, 0x3fe7_682a_0600_558d),
+    (0x3fc2_7cd8_858f_b538, 0x3fc3_e3b5_202c_9cb3),
+    (0x3fe5_d67e_2c8b_2d82, 0x3fef_5166_3a94_582a),
+    (0x3fdc_536a_7e7f_8678, 0x3fe1_d0c7_f8ca_a0c8),
+    (0x3feb_a72a_2ad1_af80, 0x3ff5_f7d1_0453_e9b1),
+    (0xbfc5_5784_20f5_5cb0, 0xbfc3_a855_a005_17ce),
+    (0xbfcf_546c_7ec3_75b0, 0xbfcb_ca48_25a5_b378),
+    (0xbdc0_1b79_37c8_457e, 0xbdc0_1b79_37c7_420c),
+    (0xbdcf_0297_06d6_6d9b, 0xbdcf_0297_06d2_abfa),
+    (0xbfd5_9d2e_4fa1_d494, 0xbfd2_57b8_25d5_ba3f),
+    (0xbf4c_5bfd_0ae6_adf0, 0xbf4c_58d9_05e0_7d0b),
+    (0xbd13_1e98_4af0_b5fa, 0xbd13_1e98_4af0_b5cc),
+    (0xbcd3_8d8b_60f3_ccd5, 0xbcd3_8d8b_60f3_ccd2),
+    (0xbf4e_024c_c593_c5ef, 0xbf4d_fec8_81ec_3a79),
+    (0x3d38_be87_0065_8713, 0x3d38_be87_0065_8845),
+];
+
+const LN: &[(u64, u64)] = &[
+    (0x4000_0000_0000_0000, 0x3fe6_2e42_fefa_39ef),
+    (0x4024_0000_0000_0000, 0x4002_6bb1_bbb5_5516),
+    (0x3fe0_0000_0000_0000, 0xbfe6_2e42_fefa_39ef),
+    (0x3ff0_0000_0000_0001, 0x3caf_ffff_ffff_ffff),
+    (0x3fef_ffff_ffff_ffff, 0xbca0_0000_0000_0000),
+    (0x7e37_e43c_8800_759c, 0x4085_9634_47f8_7fb5),
+    (0x0000_0000_0000_0001, 0xc087_4385_446d_71c3),
+    (0x0000_1268_8b70_e62b, 0xc086_4e69_394d_9508),
+    (0x4008_0000_0000_0000, 0x3ff1_93ea_7aad_030b),
+    (0x316a_a035_5903_3bfb, 0xc064_1fce_da56_45e8),
+    (0x0d1f_e184_c6a1_6c8e, 0xc081_9c42_5361_ee90),
+    (0x6b1b_9013_5efc_a9c7, 0x407d_ed0c_0333_b9a5),
+    (0x19ee_5bb7_fc44_d183, 0xc07a_57c7_47db_75e6),
+    (0x7494_6d09_c45c_0e71, 0x4082_3efe_3e6b_a8ae),
+    (0x0763_b4e2_721e_6c2a, 0xc083_98b7_fb29_bd01),
+    (0x6d49_ca13_8d9c_2565, 0x407f_7025_3040_c26b),
+    (0x70a5_610b_e71a_0c53, 0x4080_e203_06fd_6a9f),
+    (0x578b_fd68_b309_4758, 0x4070_5e03_0955_84d3),
+    (0x0f69_c73d_845a_d7e1, 0xc080_d0c9_8e24_73ab),
+    (0x66f2_09e7_b8a5_c37a, 0x407b_0a4c_e2c6_08d0),
+    (0x6f51_0cac_c183_42a3, 0x4080_6bc0_b53a_c14b),
+    (0x3fea_29af_8098_d88a, 0xbfc9_c758_70c8_9e50),
+    (0x3fe0_7c1b_4d77_b988, 0xbfe5_39bc_07df_a903),
+    (0x3ffd_6ca9_445d_ee8a, 0x3fe3_7edf_a6b5_5fc8),
+    (0x3ff4_2cdf_74b8_1a38, 0x3fcd_ade7_3220_56c5),
+    (0x3ffe_5970_fc55_763c, 0x3fe4_7c6a_3c47_de18),
+    (0x3ff3_0b28_4cee_f2d1, 0x3fc6_4a44_d975_a216),
+];
+
+const LN_1P: &[(u64, u64)] = &[
+    (0x3ddb_7cdf_d9d7_bdbb, 0x3ddb_7cdf_d9d1_d693),
+    (0xbddb_7cdf_d9d7_bdbb, 0xbddb_7cdf_d9dd_a4e3),
+    (0x3fe0_0000_0000_0000, 0x3fd9_f323_ecbf_984c),
+    (0xbfe0_0000_0000_0000, 0xbfe6_2e42_fefa_39ef),
+    (0x7e37_e43c_8800_759c, 0x4085_9634_47f8_7fb5),
+    (0xbfef_ffff_fff2_4190, 0xc037_069e_293f_4c5c),
+    (0x3c67_0ef5_4646_d497, 0x3c67_0ef5_4646_d497),
+    (0xbd0d_b420_bfc7_c0f6, 0xbd0d_b420_bfc7_c12d),
+    (0xbe0d_6bfd_5174_3044, 0xbe0d_6bfd_51aa_4a93),
+    (0xbc42_94c1_3b3f_3088, 0xbc42_94c1_3b3f_3088),
+    (0xbd91_ba57_79d8_cf18, 0xbd91_ba57_79d8_f661),
+    (0x3eac_8eb4_cc3f_7d84, 0x3eac_8eb4_005d_bea9),
+    (0x3e37_b483_bd89_3a36, 0x3e37_b483_bc70_42bb),
+    (0x3cb9_8fa1_6aba_7fae, 0x3cb9_8fa1_6aba_7fad),
+    (0xbde1_2b78_d362_64d8, 0xbde1_2b78_d367_000e),
+    (0x3c41_a396_4e94_b922, 0x3c41_a396_4e94_b922),
+    (0xbc55_9259_f465_edae, 0xbc55_9259_f465_edae),
+    (0x401a_88d3_5809_6dd2, 0x4000_42af_de9f_2e13),
+    (0x4271_2628_8be4_1c0e, 0x403b_cb94_f4c2_7197),
+    (0x44a5_4834_e621_ca14, 0x404a_22ba_6c0f_50a3),
+    (0x4251_d717_36f7_0ec2, 0x403a_72ce_2ad5_27fe),
+    (0x42e6_4a5d_fcc7_db56, 0x4040_746a_244f_7436),
+];
+
+const LOG2: &[(u64, u64)] = &[
+    (0x4008_0000_0000_0000, 0x3ff9_5c01_a39f_bd68),
+    (0x4024_0000_0000_0000, 0x400a_934f_0979_a371),
+    (0x3fb9_9999_9999_999a, 0xc00a_934f_0979_a371),
+    (0x3ff0_0000_0000_0001, 0x3cb7_1547_652b_82fd),
+    (0x01a5_6e1f_c2f8_f359, 0xc08f_24a0_9f1a_8b89),
+    (0x4090_0000_0000_0000, 0x4024_0000_0000_0000),
+    (0x4966_9c45_50e7_d47e, 0x4062_eff7_2025_0f90),
+    (0x42a1_9191_655a_9004, 0x4045_9145_5d8a_1f00),
+    (0x6b12_10bb_0bf2_398d, 0x4085_9166_b6f2_1946),
+    (0x35eb_2a2c_1ce3_6e5d, 0xc064_0790_1266_d7b0),
+    (0x2f12_0728_883e_23c9, 0xc070_dd3e_d083_906c),
+    (0x4f5e_41e0_3d83_b5b3, 0x406e_dd6a_30b5_7f50),
+    (0x79b5_4d85_df86_b370, 0x408c_e34d_c4ad_4c42),
+    (0x40c9_97f0_d786_3338, 0x402b_5afc_5c26_b2c0),
+    (0x4277_ba9b_6f10_239c, 0x4044_48c7_1424_99a0),
+    (0x41a2_e8d6_12ec_9b13, 0x403b_3db4_d5b8_d480),
+    (0x0413_19f3_1460_3390, 0xc08d_edf4_85f6_b533),
+    (0x2da1_2e39_57bf_6447, 0xc072_4e5b_519e_7cd2),
+];
+
+const LOG10: &[(u64, u64)] = &[
+    (0x4000_0000_0000_0000, 0x3fd3_4413_509f_79ff),
+    (0x408f_4000_0000_0000, 0x4008_0000_0000_0000),
+    (0x4480_f0cf_064d_d592, 0x4036_0000_0000_0000),
+    (0x44b5_2d02_c7e1_4af6, 0x4037_0000_0000_0000),
+    (0x3fb9_9999_9999_999a, 0xbff0_0000_0000_0000),
+    (0x3ff0_0000_0000_0001, 0x3c9b_cb7b_1526_e50d),
+    (0x5d62_166b_e032_0710, 0x4061_bad4_142c_3bc8),
+    (0x4311_a604_bad8_bff8, 0x402e_302c_47f4_dc49),
+    (0x5c5d_fc56_a57a_67fd, 0x4061_1e17_db52_c9ac),
+    (0x6c61_5e24_f163_0bde, 0x406a_c22c_a693_91c6),
+    (0x7401_1da0_c62b_23a4, 0x406f_5931_3ff6_c522),
+    (0x27bc_9a88_3358_1467, 0xc05d_2307_91b4_feac),
+    (0x1059_629d_0d3b_7559, 0xc06c_a5e6_924e_ac38),
+    (0x363a_8ca3_a148_05fd, 0xc047_5ed0_bbe8_f18e),
+    (0x3ab7_04a2_a84a_0729, 0xc039_20e9_f6e8_82d6),
+    (0x3b4a_516a_659a_920e, 0xc036_5c72_1de6_70e5),
+    (0x5e9c_8df4_e1f8_0b39, 0x4062_7833_38e9_cb0b),
+    (0x78f0_d03b_2aa2_ec75, 0x4071_28f9_698e_11b3),
+];
+
+const SIN: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3fea_ed54_8f09_0cee),
+    (0x4009_21fb_5444_2d18, 0x3ca1_a626_3314_5c07),
+    (0x4480_f0cf_064d_d592, 0xbfeb_453a_b76b_f397),
+    (0x7e37_e43c_8800_759c, 0xbfea_2c16_b010_e385),
+    (0x7fef_ffff_ffff_ffff, 0x3f74_52fc_98b3_4e97),
+    (0x3e45_798e_e230_8c3a, 0x3e45_798e_e230_8c3a),
+    (0x4019_21fb_5444_2d18, 0xbcb1_a626_3314_5c07),
+    (0x4076_3000_0000_0000, 0xbeff_9bd0_307d_1de3),
+    (0xc004_0000_0000_0000, 0xbfe3_26af_0dcf_cab1),
+    (0x4015_d349_8ffd_4fe6, 0xbfe7_8bb0_5554_0a31),
+    (0x3fff_f4ce_5319_6710, 0x3fed_2240_e061_4c65),
+    (0xc012_6dca_75ac_4850, 0x3fef_d2bc_600b_1789),
+    (0x4020_3e89_112d_e202, 0x3fee_db39_15cf_27c8),
+    (0xc01a_14e5_b9c3_194e, 0xbfce_1499_1e21_ae59),
+    (0xc015_d72b_54c1_1b66, 0x3fe7_769d_7130_975d),
+    (0x401d_84db_8f56_53c8, 0x3fec_77f0_78db_6990),
+    (0xc014_1158_e9fc_ebe8, 0x3fee_8704_2031_ce8d),
+    (0x4014_e7ea_ff7b_fba6, 0xbfeb_dd17_d0b3_ff81),
+    (0x4023_9d10_e148_e77c, 0xbfd7_db73_0367_307a),
+    (0x67d6_7362_7d14_f310, 0xbfa4_1a23_8cca_640d),
+    (0xf0bd_7d7e_5cb1_cbbd, 0xbfe8_bf76_fba8_a06f),
+    (0xec35_a2a8_b565_7ea4, 0xbfe7_ff65_03c7_5de7),
+    (0x7885_f63e_6c56_1100, 0x3fee_fc97_494a_ed14),
+    (0xd82d_6684_3c18_8b5b, 0xbfed_db07_6fe3_923e),
+    (0x57d5_0060_f860_9c47, 0x3fe7_28e3_e911_3d33),
+    (0xda37_6785_d093_8748, 0xbfd5_abd6_f076_df4a),
+    (0x59a0_8643_01d0_3e59, 0xbfd7_c510_676b_053b),
+];
+
+const COS: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3fe1_4a28_0fb5_068c),
+    (0x3ff9_21fb_5444_2d18, 0x3c91_a626_3314_5c07),
+    (0x4480_f0cf_064d_d592, 0x3fe0_be2c_ef01_c8f4),
+    (0x7e37_e43c_8800_759c, 0xbfe2_6990_22ad_c4c1),
+    (0x3e45_798e_e230_8c3a, 0x3ff0_0000_0000_0000),
+    (0x4009_21fb_5444_2d18, 0xbff0_0000_0000_0000),
+    (0xc004_0000_0000_0000, 0xbfe9_a2f7_ef85_8b7d),
+    (0xbfed_d91a_1894_c300, 0x3fe3_0f5e_0dc5_32c2),
+    (0x401f_fb28_2ba3_1080, 0xbfc2_065c_e77c_061a),
+    (0xc002_1eb8_f049_2f7c, 0xbfe4_7908_6ae9_6a55),
+    (0xbfe8_144c_98ff_c260, 0x3fe7_5c23_dd75_8471),
+    (0xc015_abfe_d131_d5f3, 0x3fe4_c048_06d7_40e5),
+    (0x4004_6928_49a6_0bcc, 0xbfea_95f1_8067_ff67),
+    (0x3f9f_9032_8fe8_ac00, 0x3fef_fc1b_d6b9_4daa),
+    (0xbfec_578d_3d2e_e280, 0x3fe4_3f8c_434f_e70a),
+    (0x4019_83bf_0bd8_2cd0, 0x3fef_dab1_5b47_7c1d),
+    (0xc023_2cef_32d4_9de9, 0xbfef_936f_4b81_eed9),
+    (0xdbed_1b51_b5c7_640b, 0xbfbd_b11b_7a20_a827),
+    (0x6014_42e6_99c7_0810, 0xbfc2_0989_20a3_e388),
+    (0xcb74_5051_5071_4e3d, 0xbfb5_29d1_c6ef_0e34),
+    (0xf400_4738_85ba_7c41, 0x3fe7_8245_c0f9_e4b9),
+    (0x4423_c9ce_5c69_e787, 0x3fef_daf6_2fc6_08ae),
+    (0xc90e_28c9_e43d_b159, 0x3fee_7482_861d_0d09),
+    (0xe998_8320_5508_287a, 0x3fee_2595_c6d5_8a2a),
+    (0x7bf4_b4be_5642_9657, 0xbfd6_6b9c_2ca1_a2fc),
+];
+
+const TAN: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3ff8_eb24_5cbe_e3a6),
+    (0x3ff9_21fb_5444_2d18, 0x434d_0296_7c31_cdb5),
+    (0x4480_f0cf_064d_d592, 0xbffa_0f79_c1b6_b257),
+    (0x7e37_e43c_8800_759c, 0x3ff6_be41_1f37_ac77),
+    (0x3e45_798e_e230_8c3a, 0x3e45_798e_e230_8c3a),
+    (0x3fe9_21fb_5444_2d18, 0x3fef_ffff_ffff_ffff),
+    (0xc004_0000_0000_0000, 0x3fe7_e79b_4e00_bb15),
+    (0x3ff2_f8b6_5952_d0d8, 0x4003_bcec_340f_40ba),
+    (0x4012_7942_c124_971e, 0x4025_387c_16ca_7887),
+    (0xbff0_58da_f32c_f5b0, 0xbffa_2636_276a_1c2d),
+    (0xc00e_d299_ca63_7d60, 0xbfeb_92ef_ceba_c4b0),
+    (0xc016_82b8_1317_e4d6, 0x3fe8_9b61_05bc_798b),
+    (0x3fda_e392_3e13_e260, 0x3fdc_9764_999b_7180),
+    (0xc012_1efd_a591_32c2, 0xc015_b839_489d_b6a5),
+    (0xbfe0_c159_8df0_7190, 0xbfe2_79b1_0d40_26ab),
+    (0x6107_0011_e921_532b, 0x405e_7069_62d6_46f2),
+    (0x7377_f9a4_2f0d_f12f, 0xc007_04ef_540c_799f),
+    (0xe3a6_cb42_b39b_649b, 0xbfd6_10fb_cb69_edb1),
+    (0x5451_16bc_80f9_9a2a, 0x3fdb_6d15_aa21_be50),
+    (0x6386_3a15_8800_25e6, 0x400c_ec37_de7a_ff13),
+    (0x6ed0_a8cd_2483_e673, 0xc002_be5f_66ab_6768),
+    (0xf848_98ce_d953_9e15, 0xc000_4778_dabb_3004),
+    (0xf626_9dde_247b_2fe1, 0xbfc9_20a5_7a1a_8efe),
+];
+
+const ASIN: &[(u64, u64)] = &[
+    (0x3fe0_0000_0000_0000, 0x3fe0_c152_382d_7366),
+    (0xbfe0_0000_0000_0000, 0xbfe0_c152_382d_7366),
+    (0x3ff0_0000_0000_0000, 0x3ff9_21fb_5444_2d18),
+    (0xbff0_0000_0000_0000, 0xbff9_21fb_5444_2d18),
+    (0x3fef_ffff_ffff_ffff, 0x3ff9_21fb_5044_2d18),
+    (0x3e45_798e_e230_8c3a, 0x3e45_798e_e230_8c3a),
+    (0x3fe6_a09e_667f_3bcd, 0x3fe9_21fb_5444_2d19),
+    (0xbfe9_84a7_7ef3_2d8c, 0xbfed_8997_6664_d0aa),
+    (0x3fec_d1c8_767c_e344, 0x3ff1_f04c_d6db_4be5),
+    (0xbfc9_acb6_e162_7370, 0xbfc9_d99b_2703_d712),
+    (0xbfd7_5fa9_3ac8_8c80, 0xbfd7_ed5c_4aa8_ffe3),
+    (0xbfd2_1bc7_246b_12e0, 0xbfd2_5bf9_52f1_8fd7),
+    (0x3fcd_3946_5e6f_1018, 0x3fcd_7bd7_c40a_1c7b),
+    (0xbfe1_e050_95aa_d9f0, 0xbfe2_f7b0_f14e_8b75),
+    (0xbfda_1f35_0d4b_e9b0, 0xbfda_e85b_0475_8e76),
+    (0x3fd4_e68f_bc4d_e128, 0x3fd5_4a8b_4f7f_f26a),
+    (0x3fcf_8bb4_288a_7d30, 0x3fcf_dfc5_da11_60e0),
+    (0x3db0_2966_bcd0_e94e, 0x3db0_2966_bcd0_e94e),
+    (0x3da6_4975_82f7_1829, 0x3da6_4975_82f7_1829),
+    (0x3def_71b6_d825_64f6, 0x3def_71b6_d825_64f6),
+    (0xbe81_3586_b123_ac49, 0xbe81_3586_b123_ac56),
+    (0xbe76_06d8_90ea_a546, 0xbe76_06d8_90ea_a54d),
+];
+
+const ACOS: &[(u64, u64)] = &[
+    (0x3fe0_0000_0000_0000, 0x3ff0_c152_382d_7366),
+    (0xbfe0_0000_0000_0000, 0x4000_c152_382d_7366),
+    (0xbff0_0000_0000_0000, 0x4009_21fb_5444_2d18),
+    (0x3fef_ffff_ffff_ffff, 0x3e50_0000_0000_0000),
+    (0xbfef_ffff_ffff_ffff, 0x4009_21fb_5244_2d18),
+    (0x3e45_798e_e230_8c3a, 0x3ff9_21fb_5194_fb3c),
+    (0x0000_0000_0000_0000, 0x3ff9_21fb_5444_2d18),
+    (0x3fba_fbb3_caa8_1000, 0x3ff7_7172_69c7_c353),
+    (0x3fea_addf_1df5_a076, 0x3fe2_b822_90c3_c2e7),
+    (0xbfc3_2982_29b0_6a58, 0x3ffb_897b_ef53_909e),
+    (0x3fd5_c511_4147_74b4, 0x3ff3_9457_f3ae_4ffb),
+    (0x3fe5_f309_f82b_a226, 0x3fea_13ec_61b6_fe0d),
+    (0x3fe9_be65_f934_f8d0, 0x3fe4_59eb_b59c_bbe9),
+    (0xbfec_2289_3859_64f6, 0x4005_28fb_07b5_1b90),
+    (0xbfe9_f097_192d_0d64, 0x4004_20c7_0827_c00f),
+    (0xbfef_c69a_a340_4b4a, 0x4008_2f68_5a0a_6127),
+    (0xbfa3_f31d_8e38_f620, 0x3ff9_c19e_991b_0ffe),
+    (0xbe7c_2741_b243_753b, 0x3ff9_21fb_706b_6ecb),
+    (0x3ea4_90cb_4cf2_3f44, 0x3ff9_21fa_afbd_d2b1),
+    (0xbedb_5d5b_88f5_4ed0, 0x3ff9_2202_2b9b_0f56),
+    (0xbeb5_75ea_de05_e573, 0x3ff9_21fc_aba2_daf9),
+    (0xbe69_6999_50e3_fa9b, 0x3ff9_21fb_60f8_f9c1),
+];
+
+const ATAN: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3fe9_21fb_5444_2d18),
+    (0xbff0_0000_0000_0000, 0xbfe9_21fb_5444_2d18),
+    (0x3e45_798e_e230_8c3a, 0x3e45_798e_e230_8c3a),
+    (0x7e37_e43c_8800_759c, 0x3ff9_21fb_5444_2d18),
+    (0x3fe0_0000_0000_0000, 0x3fdd_ac67_0561_bb4f),
+    (0x4000_0000_0000_0000, 0x3ff1_b6e1_92eb_be44),
+    (0x4202_a05f_2000_0000, 0x3ff9_21fb_543d_4de0),
+    (0x81a5_6e1f_c2f8_f359, 0x81a5_6e1f_c2f8_f359),
+    (0xbff4_ae82_520f_d961, 0xbfed_31e7_3016_744b),
+    (0xc001_4995_d995_890a, 0xbff2_32b4_5106_5960),
+    (0xbf98_da8a_b5a9_9600, 0xbf98_d94a_fbeb_6d9a),
+    (0x4006_51c0_8279_388a, 0x3ff3_a044_ec9f_98ba),
+    (0xbfea_c443_61bc_e780, 0xbfe6_4a61_00f1_e9fe),
+    (0x3ff4_c49c_fd45_d734, 0x3fed_4269_88d7_8bff),
+    (0x4003_fd60_856c_73c0, 0x3ff3_0ab4_282a_c41b),
+    (0xc000_c33f_88a8_d262, 0xbff2_021a_646d_c764),
+    (0xc007_1e95_98ec_0b83, 0xbff3_cd78_8cc3_f6cf),
+    (0x4005_d0f2_2499_32d4, 0x3ff3_8257_e298_57cf),
+    (0xbe0a_be28_1e1a_138d, 0xbe0a_be28_1e1a_138d),
+    (0x3dc1_e809_f646_685c, 0x3dc1_e809_f646_685c),
+    (0xc2f5_910c_8952_c23c, 0xbff9_21fb_5444_2d0c),
+    (0x4363_9884_f72b_684d, 0x3ff9_21fb_5444_2d18),
+    (0xc202_ccd7_3a4c_e93e, 0xbff9_21fb_543d_5e21),
+    (0xc1c5_d83f_c1c3_6a0d, 0xbff9_21fb_53e6_6c6d),
+    (0x4286_c514_4446_7f0a, 0x3ff9_21fb_5444_2779),
+    (0xbdcf_6db3_a9f2_b2c4, 0xbdcf_6db3_a9f2_b2c4),
+];
+
+const SINH: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3ff2_cd9f_c44e_b982),
+    (0xbff0_0000_0000_0000, 0xbff2_cd9f_c44e_b982),
+    (0x3e45_798e_e230_8c3a, 0x3e45_798e_e230_8c3a),
+    (0x3fe0_0000_0000_0000, 0x3fe0_acd0_0fe6_3b97),
+    (0x4086_3000_0000_0000, 0x7fe3_e21a_4645_07f9),
+    (0xc085_e000_0000_0000, 0xfefd_945d_f4f8_ec8e),
+    (0x4034_0000_0000_0000, 0x41ac_eb08_8b68_e804),
+    (0x4009_a506_09f3_1938, 0x4028_a114_68fa_e603),
+    (0xc012_0372_d321_0d46, 0xc046_93d2_7d0d_a9f4),
+    (0xbff7_7c04_ecb8_9f4c, 0xc000_6fc3_d8d1_9d10),
+    (0xbff4_6cd5_3cbc_b414, 0xbffa_7152_e4e6_7d1e),
+    (0x400c_0f17_4276_5614, 0x4030_aa4a_4466_d188),
+    (0xc001_ab9a_5094_38cc, 0xc011_fd5e_bfc7_b5f9),
+    (0x4002_caa6_3097_e980, 0x4014_c218_a02e_aa58),
+    (0x4011_8f95_9e1a_6c24, 0x4044_2953_1d74_540b),
+    (0xbf6f_8748_da76_af2e, 0xbf6f_874d_f453_43aa),
+    (0x3e9b_b9e0_2e50_a916, 0x3e9b_b9e0_2e50_a9f4),
+    (0x4017_e442_d8c1_f867, 0x4068_8a50_196b_8a3a),
+    (0xbe28_4193_f1f8_c40c, 0xbe28_4193_f1f8_c40c),
+    (0xbe42_4ba7_7cd7_a466, 0xbe42_4ba7_7cd7_a466),
+    (0x3fc5_e597_7ff3_ce78, 0x3fc6_00f9_06d2_b241),
+    (0x3ff5_38f2_c1e7_7b55, 0x3ffc_042c_d909_0447),
+    (0x3e2b_d2ba_991a_9526, 0x3e2b_d2ba_991a_9526),
+];
+
+const COSH: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3ff8_b075_51d9_f550),
+    (0xbff0_0000_0000_0000, 0x3ff8_b075_51d9_f550),
+    (0x3ee4_f8b5_88e3_68f1, 0x3ff0_0000_0003_6f9c),
+    (0x3fe0_0000_0000_0000, 0x3ff2_0ac1_862a_e8d0),
+    (0x4086_3000_0000_0000, 0x7fe3_e21a_4645_07f9),
+    (0xc085_e000_0000_0000, 0x7efd_945d_f4f8_ec8e),
+    (0x4034_0000_0000_0000, 0x41ac_eb08_8b68_e804),
+    (0xc010_6d0a_08fd_65a7, 0x403e_5fe7_4b92_741c),
+    (0xc008_6cc1_ca54_4918, 0x4025_3a6b_5825_8544),
+    (0x3f7e_f5f5_f241_7c00, 0x3ff0_001d_f499_0487),
+    (0x3fff_8dab_5cd8_0398, 0x400d_4c9e_faba_f1f6),
+    (0x400b_9879_fad0_c338, 0x402f_83cf_2031_fb2c),
+    (0x4007_0f7e_83c9_e3aa, 0x4021_ea80_aa5c_ec06),
+    (0x400c_4f44_cfe0_4308, 0x4031_39c6_d558_0e85),
+    (0x3ffa_65c0_c8c1_d54c, 0x4005_97cb_a17d_2d29),
+    (0xbef5_7b82_57bc_805f, 0x3ff0_0000_000e_6bf8),
+    (0x3f63_adf5_437c_5eda, 0x3ff0_0003_0691_5343),
+    (0xbfa0_b81d_72b3_69d9, 0x3ff0_022f_1ce9_3a46),
+    (0xbf00_23d5_5a13_5a75, 0x3ff0_0000_0020_8ff6),
+    (0x3f28_30ae_9a87_dab8, 0x3ff0_0000_0492_53fe),
+    (0xc041_1a29_178f_6131, 0x42f4_57e8_38a7_505f),
+    (0xbfe1_0a83_3e8d_60e8, 0x3ff2_52a6_e630_6c5a),
+    (0xbfa6_226c_2848_d350, 0x3ff0_03d4_0581_c6ca),
+];
+
+const TANH: &[(u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3fe8_5efa_b514_f394),
+    (0xbff0_0000_0000_0000, 0xbfe8_5efa_b514_f394),
+    (0x3e45_798e_e230_8c3a, 0x3e45_798e_e230_8c3a),
+    (0x3fe0_0000_0000_0000, 0x3fdd_9353_d756_8af3),
+    (0x4033_0000_0000_0000, 0x3fef_ffff_ffff_ffff),
+    (0xc008_0000_0000_0000, 0xbfef_d77d_111a_0b00),
+    (0xbfd8_1718_ee44_cf50, 0xbfd7_0372_db0e_8b57),
+    (0xbff1_4897_6387_3d54, 0xbfe9_6290_149d_44a1),
+    (0xbff1_8eff_84d8_fada, 0xbfe9_960e_470d_9a87),
+    (0xc001_6d4c_f8f1_57f0, 0xbfef_309d_1f6e_9adb),
+    (0xc004_b1c0_4c3b_271b, 0xbfef_a3b8_538d_3e5a),
+    (0x3fb0_afeb_1790_c680, 0x3fb0_a9e0_ce04_60dd),
+    (0x3fd5_6427_abbf_7580, 0x3fd4_a0f3_4940_2fdf),
+    (0xbfbc_3744_3d75_4580, 0xbfbc_1a28_8d4b_182f),
+    (0xbe70_dc68_b03f_e721, 0xbe70_dc68_b03f_e71b),
+    (0xbf00_3cb5_e9ff_1ea8, 0xbf00_3cb5_e9e8_d2dd),
+    (0x3ff8_cafd_4fd3_8e81, 0x3fed_3d22_0f15_280d),
+    (0x3e68_cde4_0495_cf74, 0x3e68_cde4_0495_cf6f),
+    (0x3fa1_254d_b172_d4cc, 0x3fa1_23a9_d8a6_415b),
+    (0xc010_4e4b_048f_1eff, 0xbfef_fb48_d47d_0f93),
+    (0xbf22_55cc_2878_42bb, 0xbf22_55cc_2676_9aef),
+    (0x3fda_c89b_00e5_b905, 0x3fd9_5282_4269_356a),
+];
+
+#[rustfmt::skip]
+const ATAN2: &[(u64, u64, u64)] = &[
+    (0x3ff0_0000_0000_0000, 0x3ff0_0000_0000_0000, 0x3fe9_21fb_5444_2d18),
+    (0x3ff0_0000_0000_0000, 0xbff0_0000_0000_0000, 0x4002_d97c_7f33_21d2),
+    (0xbff0_0000_0000_0000, 0xbff0_0000_0000_0000, 0xc002_d97c_7f33_21d2),
+    (0x01a5_6e1f_c2f8_f359, 0x7e37_e43c_8800_759c, 0x0000_0000_0000_0000),
+    (0x7e37_e43c_8800_759c, 0x01a5_6e1f_c2f8_f359, 0x3ff9_21fb_5444_2d18),
+    (0xc000_0000_0000_0000, 0x4008_0000_0000_0000, 0xbfe2_d0ea_d606_6395),
+    (0x3fe0_0000_0000_0000, 0xbbc7_9ca1_0c92_4223, 0x3ff9_21fb_5444_2d18),
+    (0x3ffa_3238_9892_cc90, 0x4004_ed51_e057_fdce, 0x3fe1_e54e_4558_c6d9),
+    (0x3fe8_c914_9b03_be40, 0xbfff_efb3_a658_301c, 0x4006_2be5_e6ec_c2f9),
+    (0xc003_8766_2be0_58c0, 0x3ff7_78ad_b8b4_ec88, 0xbff0_7997_e626_e6fb),
+    (0xbff3_b07d_ebed_de92, 0x3ffb_63b3_886b_5600, 0xbfe3_f1e3_b55f_37b6),
+    (0xc002_adb5_dec1_f9fc, 0x3fe1_4de5_9969_c208, 0xbff5_7dc2_4f9e_8f77),
+    (0xbffc_aad5_3d57_2f54, 0x400b_1460_069c_0e7c, 0xbfdf_2821_6099_501d),
+    (0x3fff_bbf0_6b12_1218, 0x3fed_bca0_fedf_1840, 0x3ff2_1f4a_b93e_43de),
+    (0xc000_1a36_13e3_355f, 0xc00a_61e0_9b02_4fd4, 0xc004_bfb1_1a8d_0955),
+    (0xc012_5250_82e5_b18b, 0xc00a_cfc4_f207_3768, 0xc001_9ead_a437_8cfb),
+    (0x3fe3_3804_c0e7_1b38, 0x4006_fac2_f14c_a806, 0x3fca_6209_d47c_55a3),
+    (0xc00f_9462_6fd4_45f1, 0xbfbe_2ba6_00ec_fb80, 0xbff9_9c3b_e16a_66a8),
+    (0xbfd4_41d1_41e5_f2f0, 0x4007_dedc_2fac_8558, 0xbfbb_0e05_20d8_85d5),
+];
+
+#[rustfmt::skip]
+const HYPOT: &[(u64, u64, u64)] = &[
+    (0x4008_0000_0000_0000, 0x4010_0000_0000_0000, 0x4014_0000_0000_0000),
+    (0x7e37_e43c_8800_759c, 0x7e37_e43c_8800_759c, 0x7e40_e4d5_0f99_b211),
+    (0x0000_1268_8b70_e62b, 0x0000_1268_8b70_e62b, 0x0000_1a08_8b6b_f34f),
+    (0x3ff0_0000_0000_0000, 0x3ddb_7cdf_d9d7_bdbb, 0x3ff0_0000_0000_0000),
+    (0xc000_0000_0000_0000, 0x4008_0000_0000_0000, 0x400c_d82b_4461_59f3),
+    (0xaf94_fadb_76c5_4256, 0x5653_e2ea_528c_06c0, 0x5653_e2ea_528c_06c0),
+    (0x5b37_1b87_02e3_2ba3, 0xc351_ba68_1d41_5a4f, 0x5b37_1b87_02e3_2ba3),
+    (0x27be_9115_956b_6464, 0xdee7_1d02_9817_8e18, 0x5ee7_1d02_9817_8e18),
+    (0xc818_0cbe_f144_7864, 0xca49_3eca_a32a_52f0, 0x4a49_3eca_a32a_52f0),
+    (0x5d3e_34b1_3d94_5dbb, 0x4bed_6be8_6ee0_f134, 0x5d3e_34b1_3d94_5dbb),
+    (0xc4b0_42d0_6e0a_598b, 0x5904_951d_91ac_ea49, 0x5904_951d_91ac_ea49),
+    (0xa477_6c33_ca4a_f0be, 0xbf1a_7951_f8fd_14f8, 0x3f1a_7951_f8fd_14f8),
+    (0xdcff_ff3a_3a35_e009, 0xdbcd_a01d_78cb_c7e2, 0x5cff_ff3a_3a36_16e5),
+    (0x4677_6570_3cb7_fbdb, 0x25c4_db00_6b34_443e, 0x4677_6570_3cb7_fbdb),
+    (0xce84_25e6_20ae_47c1, 0xd46e_dc60_eb1c_3ae8, 0x546e_dc60_eb1c_3ae8),
+];
+
+#[rustfmt::skip]
+const POWF: &[(u64, u64, u64)] = &[
+    (0x4000_0000_0000_0000, 0x3fe0_0000_0000_0000, 0x3ff6_a09e_667f_3bcd),
+    (0x4024_0000_0000_0000, 0xc000_0000_0000_0000, 0x3f84_7ae1_47ae_147b),
+    (0x3fe0_0000_0000_0000, 0x4090_c800_0000_0000, 0x0000_0000_0000_0001),
+    (0x4000_0000_0000_0000, 0xc090_ca00_0000_0000, 0x0000_0000_0000_0001),
+    (0x3ff0_0000_1ad7_f29b, 0x41cd_cd65_0000_0000, 0x48f3_4944_5c22_8792),
+    (0xc000_0000_0000_0000, 0x4008_0000_0000_0000, 0xc020_0000_0000_0000),
+    (0xc000_0000_0000_0000, 0xc008_0000_0000_0000, 0xbfc0_0000_0000_0000),
+    (0x4008_0000_0000_0000, 0x3fd5_5555_5555_5555, 0x3ff7_1374_4912_3ef6),
+    (0x3fa4_b5dc_c63f_1412, 0x4003_3333_3333_3333, 0x3f3d_b8f1_87ff_e63f),
+    (0x3fec_cccc_cccc_cccd, 0x3fda_aaaa_aaaa_aaab, 0x3fee_a026_2c23_3368),
+    (0x3ffa_b997_0c6a_1ab1, 0xbff5_b304_b3a4_71e8, 0x3fdf_eabc_c7cb_d01c),
+    (0x3fef_ef74_cab8_d984, 0xbfd9_975d_fb1a_8ca0, 0x3ff0_034f_f3ad_3b84),
+    (0x3fe0_7a35_2a20_78a0, 0x400d_340f_2b4e_1fb2, 0x3fb6_b25b_1f62_522a),
+    (0x3ff8_a791_1bcd_d60a, 0x3fef_54c6_8ab1_0390, 0x3ff8_6ec8_ea60_1ebe),
+    (0x3ff2_f055_d01f_bef5, 0x3feb_d8fe_d234_0978, 0x3ff2_8766_bc47_7698),
+    (0x3ffa_c111_9050_16e2, 0xbfc3_357e_5d94_7da0, 0x3fed_9fbe_1699_f672),
+    (0x3ff6_57ab_bfe4_6c34, 0xc002_cf66_8aef_40f0, 0x3fdd_3064_be68_e9a1),
+    (0x3ff6_c642_a16c_aa3c, 0x3fa7_fc8d_af5b_d580, 0x3ff0_444f_a3e2_06f0),
+    (0x3fb4_82f1_e0d1_34a0, 0xc001_c95a_49ce_1480, 0x4071_1b65_6906_56cc),
+    (0x3ffc_7a1e_de08_4cc9, 0x400f_bb57_ad45_ae9c, 0x4023_af5c_3a3e_4c24),
+    (0x3f9a_20e1_bb27_9c40, 0xc007_4ed2_2af4_15e0, 0x40e5_6632_2f0b_afab),
+    (0x3fdc_24a3_4ecb_6294, 0xbfee_bd94_4663_0480, 0x4001_9d25_e251_9e0f),
+    (0x3ebd_585d_a9da_3fcc, 0x401a_a503_d7f4_d850, 0x37f8_59cf_39ec_b685),
+    (0x404b_913a_34ee_6495, 0x403d_9d0b_1797_0734, 0x4aa3_d759_fbcb_90c9),
+    (0x3f99_6493_5166_6257, 0xc036_e566_2345_1929, 0x4791_624e_fd6a_80ad),
+    (0x40d2_7007_e466_6f5d, 0x4031_3cfe_2d18_3488, 0x4f3d_1588_9f4b_547c),
+    (0x407f_7ea3_d683_f925, 0xc00d_b45b_eb91_da88, 0x3dd9_6a85_ef0d_69be),
+    (0x4009_03f2_88c8_86f1, 0x3ffc_9cd8_1954_4550, 0x401e_b94c_43d2_8855),
+    (0x4015_62a4_262a_8cc0, 0xc011_137e_8974_f060, 0x3f49_8c3a_4f9b_4544),
+    (0x3eda_d5b2_0328_9fed, 0x403a_a511_6f9e_76f4, 0x2333_500c_c103_2622),
+];
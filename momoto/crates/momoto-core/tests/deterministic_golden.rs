@@ -0,0 +1,113 @@
+//! Cross-target golden vectors for the `deterministic` feature.
+//!
+//! Each test hashes the exact bit patterns of a batch of results. With
+//! `deterministic` every transcendental is correctly rounded, so the hashes
+//! below must match on every target (x86_64, aarch64, wasm32); CI runs this
+//! file on each of them. A mismatch means a platform-dependent math call has
+//! slipped past `momoto_core::math::ops`.
+//!
+//! Run with `cargo test -p momoto-core --features deterministic --test deterministic_golden`.
+
+#![cfg(feature = "deterministic")]
+
+use momoto_core::{
+    color::Color,
+    evaluated::{Evaluable, MaterialContext},
+    material::GlassMaterial,
+    math::ops,
+    space::{hct::HCT, lab::Lab, oklch::OKLCH},
+};
+
+/// FNV-1a over the bit patterns of `values`.
+fn hash_bits(values: impl IntoIterator<Item = f64>) -> u64 {
+    values
+        .into_iter()
+        .flat_map(|v| v.to_bits().to_le_bytes())
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
+/// 9×9×9 sRGB grid, corners and mid-tones included.
+fn grid() -> impl Iterator<Item = Color> {
+    (0..729).map(|i| {
+        let step = |v: u32| (v * 255 / 8) as u8;
+        Color::from_srgb8(step(i / 81), step(i / 9 % 9), step(i % 9))
+    })
+}
+
+#[test]
+fn test_ops_golden_bits() {
+    // The correctly rounded values; `libm::exp(1.0)` would be one ulp above.
+    assert_eq!(ops::exp(1.0).to_bits(), 0x4005_bf0a_8b14_5769);
+    assert_eq!(ops::ln(10.0).to_bits(), 0x4002_6bb1_bbb5_5516);
+    assert_eq!(ops::cbrt(2.0).to_bits(), 0x3ff4_28a2_f98d_728b);
+    assert_eq!(ops::atan2(1.0, 1.0).to_bits(), 0x3fe9_21fb_5444_2d18);
+    assert_eq!(ops::powf(2.0, 0.5).to_bits(), 0x3ff6_a09e_667f_3bcd);
+    assert_eq!(ops::sin(1.0).to_bits(), 0x3fea_ed54_8f09_0cee);
+}
+
+#[test]
+fn test_oklch_golden_hash() {
+    let hash = hash_bits(grid().flat_map(|c| {
+        let o = OKLCH::from_color(&c);
+        [o.l, o.c, o.h]
+    }));
+    assert_eq!(hash, 9_686_998_480_069_144_070, "OKLCH golden hash");
+}
+
+#[test]
+fn test_lab_golden_hash() {
+    let colors: Vec<Color> = grid().collect();
+    let hash = hash_bits(colors.windows(2).flat_map(|pair| {
+        let a = Lab::from_color(&pair[0]);
+        let b = Lab::from_color(&pair[1]);
+        [a.l, a.a, a.b, a.delta_e_2000(&b)]
+    }));
+    assert_eq!(hash, 9_267_952_903_065_121_022, "Lab / ΔE2000 golden hash");
+}
+
+#[test]
+fn test_hct_golden_hash() {
+    let hash = hash_bits(grid().flat_map(|c| {
+        let hct = HCT::from_color(&c);
+        let back = hct.to_color();
+        [
+            hct.hue,
+            hct.chroma,
+            hct.tone,
+            back.srgb[0],
+            back.srgb[1],
+            back.srgb[2],
+        ]
+    }));
+    assert_eq!(hash, 2_406_573_030_305_099_971, "HCT golden hash");
+}
+
+#[test]
+fn test_material_golden_hash() {
+    let materials = [
+        GlassMaterial::clear(),
+        GlassMaterial::regular(),
+        GlassMaterial::thick(),
+        GlassMaterial::frosted(),
+    ];
+    let hash = hash_bits(materials.iter().flat_map(|glass| {
+        (0..=8).flat_map(move |step| {
+            let evaluated = glass.evaluate(&MaterialContext::at_angle(f64::from(step) * 10.0));
+            [
+                evaluated.opacity,
+                evaluated.fresnel_f0,
+                evaluated.fresnel_edge_intensity,
+                evaluated.scattering_radius_mm,
+                evaluated.base_color.r,
+                evaluated.base_color.g,
+                evaluated.base_color.b,
+            ]
+        })
+    }));
+    assert_eq!(
+        hash, 5_092_682_809_371_697_282,
+        "GlassMaterial evaluation golden hash"
+    );
+}
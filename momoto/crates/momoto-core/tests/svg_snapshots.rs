@@ -0,0 +1,210 @@
+//! # SVG Filter Golden Snapshots
+//!
+//! Regression tests for `SvgBackend` output.
+//!
+//! **Purpose:** Ensure the filter graph generated for each material stays
+//! byte-identical across refactorings, and that it remains referenceable
+//! from CSS.
+//!
+//! ## Snapshot Format
+//!
+//! Each material renders to one hidden `<svg>` element holding a single
+//! `<filter id="momoto-glass-XXXXXXXX">` in `linearRGB`, with primitives in
+//! this order (absent stages are skipped):
+//!
+//! 1. `feTurbulence` — surface noise (`texture_noise`)
+//! 2. `feDisplacementMap` — refraction through the noisy surface
+//! 3. `feGaussianBlur` — volume scattering
+//! 4. `feComponentTransfer` — tint by `base_color` at `opacity`
+//! 5. `feSpecularLighting` + `feComposite` — Fresnel-weighted highlight
+//!
+//! ## When to Update Snapshots
+//!
+//! Update snapshots when:
+//! - Intentionally changing the filter graph or number formatting
+//! - Improving physics calculations that feed `EvaluatedMaterial`
+//!
+//! DO NOT update if:
+//! - Refactoring backend implementation (output should be identical)
+
+use momoto_core::{
+    backend::svg::{SvgBackend, SvgFilter},
+    evaluated::{Evaluable, MaterialContext},
+    material::GlassMaterial,
+    render::{RenderBackend, RenderContext},
+    space::oklch::OKLCH,
+};
+
+/// Render `material` in the default material context on desktop.
+fn render(material: &GlassMaterial) -> SvgFilter {
+    let evaluated = material.evaluate(&MaterialContext::default());
+    SvgBackend::new()
+        .render(&evaluated, &RenderContext::desktop())
+        .unwrap()
+}
+
+/// Extract a numeric attribute from the first element named `element`.
+fn attribute(markup: &str, element: &str, name: &str) -> Option<f64> {
+    let start = markup.find(&format!("<{} ", element))?;
+    let tag = &markup[start..start + markup[start..].find('>')?];
+    let key = format!(" {}=\"", name);
+    let value_start = tag.find(&key)? + key.len();
+    let value_end = value_start + tag[value_start..].find('"')?;
+    tag[value_start..value_end].parse().ok()
+}
+
+//
+// 1. GLASS MATERIAL PRESETS - Exact Snapshots
+//
+
+#[test]
+fn snapshot_clear_glass() {
+    let filter = render(&GlassMaterial::clear());
+
+    let expected = r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0" aria-hidden="true" style="position:absolute">
+<filter id="momoto-glass-e1a62770" x="-10%" y="-10%" width="120%" height="120%" color-interpolation-filters="linearRGB">
+<feGaussianBlur in="SourceGraphic" stdDeviation="2.6" result="scattered"/>
+<feComponentTransfer in="scattered" result="tinted">
+  <feFuncR type="linear" slope="0.6776" intercept="0.2611"/>
+  <feFuncG type="linear" slope="0.6776" intercept="0.2802"/>
+  <feFuncB type="linear" slope="0.6776" intercept="0.2920"/>
+</feComponentTransfer>
+<feSpecularLighting in="SourceAlpha" surfaceScale="1.00" specularConstant="0.975" specularExponent="128.0" lighting-color="white" result="specular">
+  <feDistantLight azimuth="225" elevation="45"/>
+</feSpecularLighting>
+<feComposite in="specular" in2="SourceAlpha" operator="in" result="specular-masked"/>
+<feComposite in="specular-masked" in2="tinted" operator="arithmetic" k1="0" k2="0.0400" k3="1" k4="0"/>
+</filter>
+</svg>"#;
+
+    assert_eq!(filter.id, "momoto-glass-e1a62770");
+    assert_eq!(filter.markup, expected);
+}
+
+#[test]
+fn snapshot_frosted_glass() {
+    let filter = render(&GlassMaterial::frosted());
+
+    let expected = r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0" aria-hidden="true" style="position:absolute">
+<filter id="momoto-glass-65d7d16a" x="-10%" y="-10%" width="120%" height="120%" color-interpolation-filters="linearRGB">
+<feTurbulence type="fractalNoise" baseFrequency="0.0500" numOctaves="4" seed="0" result="noise"/>
+<feDisplacementMap in="SourceGraphic" in2="noise" scale="15.1" xChannelSelector="R" yChannelSelector="G" result="refracted"/>
+<feGaussianBlur in="refracted" stdDeviation="25.7" result="scattered"/>
+<feComponentTransfer in="scattered" result="tinted">
+  <feFuncR type="linear" slope="0.2630" intercept="0.3944"/>
+  <feFuncG type="linear" slope="0.2630" intercept="0.4579"/>
+  <feFuncB type="linear" slope="0.2630" intercept="0.4854"/>
+</feComponentTransfer>
+<feSpecularLighting in="noise" surfaceScale="5.00" specularConstant="0.632" specularExponent="6.9" lighting-color="white" result="specular">
+  <feDistantLight azimuth="225" elevation="45"/>
+</feSpecularLighting>
+<feComposite in="specular" in2="SourceAlpha" operator="in" result="specular-masked"/>
+<feComposite in="specular-masked" in2="tinted" operator="arithmetic" k1="0" k2="0.0400" k3="1" k4="0"/>
+</filter>
+</svg>"#;
+
+    assert_eq!(filter.id, "momoto-glass-65d7d16a");
+    assert_eq!(filter.markup, expected);
+}
+
+//
+// 2. GRAPH PROPERTIES ACROSS PRESETS
+//
+
+#[test]
+fn snapshot_displacement_grows_with_thickness_and_noise() {
+    let regular = render(&GlassMaterial::regular());
+    let thick = render(&GlassMaterial::thick());
+
+    let regular_shift = attribute(&regular.markup, "feDisplacementMap", "scale").unwrap();
+    let thick_shift = attribute(&thick.markup, "feDisplacementMap", "scale").unwrap();
+
+    println!(
+        "Displacement: regular {} px, thick {} px",
+        regular_shift, thick_shift
+    );
+    assert!(thick_shift > regular_shift);
+}
+
+#[test]
+fn snapshot_fresnel_weight_grows_at_grazing_angle() {
+    let material = GlassMaterial::regular();
+    let backend = SvgBackend::new();
+    let weight = |angle: f64| {
+        let evaluated = material.evaluate(&MaterialContext::at_angle(angle));
+        let filter = backend
+            .render(&evaluated, &RenderContext::desktop())
+            .unwrap();
+        let start = filter.markup.find("operator=\"arithmetic\"").unwrap();
+        let k2 = &filter.markup[start..];
+        let k2 = &k2[k2.find("k2=\"").unwrap() + 4..];
+        k2[..k2.find('"').unwrap()].parse::<f64>().unwrap()
+    };
+
+    assert!(weight(75.0) > weight(0.0));
+}
+
+#[test]
+fn snapshot_tint_follows_background() {
+    let material = GlassMaterial::regular();
+    let backend = SvgBackend::new();
+    let render_on = |background: OKLCH| {
+        let evaluated = material.evaluate(&MaterialContext::with_background(background));
+        backend
+            .render(&evaluated, &RenderContext::desktop())
+            .unwrap()
+    };
+
+    let light = render_on(OKLCH::new(0.95, 0.01, 240.0));
+    let dark = render_on(OKLCH::new(0.2, 0.01, 240.0));
+
+    assert!(light.markup.contains("<feComponentTransfer"));
+    assert!(dark.markup.contains("<feComponentTransfer"));
+    assert_ne!(light.id, dark.id, "different graphs must get different ids");
+}
+
+//
+// 3. CSS REFERENCE
+//
+
+#[test]
+fn snapshot_css_reference() {
+    let filter = render(&GlassMaterial::frosted());
+
+    assert_eq!(filter.url(), "url(#momoto-glass-65d7d16a)");
+    assert_eq!(filter.filter_css(), "filter: url(#momoto-glass-65d7d16a);");
+    assert_eq!(
+        filter.backdrop_filter_css(),
+        "backdrop-filter: url(#momoto-glass-65d7d16a);"
+    );
+    assert!(filter
+        .markup
+        .contains(&format!("<filter id=\"{}\"", filter.id)));
+}
+
+//
+// 4. BATCH RENDERING
+//
+
+#[test]
+fn snapshot_batch_rendering_consistency() {
+    let context = MaterialContext::default();
+    let materials: Vec<_> = [
+        GlassMaterial::clear(),
+        GlassMaterial::regular(),
+        GlassMaterial::thick(),
+        GlassMaterial::frosted(),
+    ]
+    .iter()
+    .map(|m| m.evaluate(&context))
+    .collect();
+
+    let backend = SvgBackend::new();
+    let render_ctx = RenderContext::desktop();
+    let batch = backend.render_batch(&materials, &render_ctx).unwrap();
+
+    assert_eq!(batch.len(), 4);
+    for (filter, material) in batch.iter().zip(&materials) {
+        assert_eq!(filter, &backend.render(material, &render_ctx).unwrap());
+    }
+}
@@ -6,16 +6,28 @@
 //   - WCAG 2.1 contrast constraints
 //   - APCA contrast constraints
 //   - Color harmony angle constraints
-//   - Gamut constraints
+//   - Gamut constraints (sRGB, Display P3, Rec.2020, Adobe RGB)
 //   - Lightness / chroma range constraints
+//   - Pairwise ΔE distinguishability, with and without simulated CVD
+//   - Locked colors, APCA polarity and maximum drift from a reference
 //
 // Algorithm: Penalty method with finite-difference gradient + backtracking.
 // Convergence: penalty < threshold OR max_iterations reached.
+// Infeasibility: when the solver does not converge, a deletion filter over
+// the constraints involved finds a minimal subset that cannot be satisfied
+// together, which is reported instead of a silent best effort.
 // =============================================================================
 
+use momoto_core::color::cvd::{simulate_cvd, CVDType};
 use momoto_core::color::Color;
+use momoto_core::perception::{ContrastMetric, Polarity};
 use momoto_core::space::oklch::OKLCH;
+use momoto_core::space::rgb::RgbSpace;
 use momoto_core::luminance::relative_luminance_srgb;
+use momoto_metrics::apca::APCAMetric;
+use momoto_metrics::difference::{
+    Cam16Ucs, Cie76, Cie94, Ciede2000, Cmc, ColorDifference, DeltaEItp, DeltaEOk,
+};
 
 // =============================================================================
 // Constraint types
@@ -30,6 +42,124 @@ pub struct ColorConstraint {
     pub kind: ConstraintKind,
 }
 
+impl ColorConstraint {
+    /// Constraint of `kind` on color `color_idx`.
+    pub fn new(color_idx: usize, kind: ConstraintKind) -> Self {
+        Self { color_idx, kind }
+    }
+
+    /// [`ConstraintKind::MinDeltaE`] between every pair of `indices`, e.g. the
+    /// categorical colors of a chart.
+    pub fn pairwise_distinct(indices: &[usize], metric: DeltaEMetric, target: f64) -> Vec<Self> {
+        pairs(indices)
+            .map(|(a, b)| Self::new(a, ConstraintKind::MinDeltaE { other_idx: b, metric, target }))
+            .collect()
+    }
+
+    /// [`ConstraintKind::MinCvdDeltaE`] between every pair of `indices` for
+    /// each deficiency in `cvds`.
+    pub fn pairwise_cvd_distinct(
+        indices: &[usize],
+        cvds: &[CVDType],
+        metric: DeltaEMetric,
+        target: f64,
+    ) -> Vec<Self> {
+        pairs(indices)
+            .flat_map(|(a, b)| {
+                cvds.iter().map(move |&cvd| {
+                    Self::new(a, ConstraintKind::MinCvdDeltaE { other_idx: b, cvd, metric, target })
+                })
+            })
+            .collect()
+    }
+}
+
+/// Unordered pairs `(a, b)` of distinct entries of `indices`.
+fn pairs(indices: &[usize]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    indices
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, &a)| indices[i + 1..].iter().map(move |&b| (a, b)))
+}
+
+/// Color-difference formula used by ΔE constraints.
+///
+/// Targets are in the metric's own units, e.g. ΔE00 10 or ΔEOK 0.1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeltaEMetric {
+    /// CIE 1976 ΔE*ab.
+    Cie76,
+    /// CIE 1994 ΔE*94, graphic-arts weights.
+    Cie94,
+    /// CIEDE2000 ΔE00.
+    #[default]
+    Ciede2000,
+    /// CMC l:c, 2:1 acceptability weights.
+    Cmc,
+    /// Euclidean distance in OKLab.
+    Ok,
+    /// CAM16-UCS ΔE' under sRGB viewing conditions.
+    Cam16Ucs,
+    /// ITU-R BT.2124 ΔE ITP.
+    Itp,
+}
+
+impl DeltaEMetric {
+    /// ΔE between two colors.
+    pub fn delta_e(self, a: &Color, b: &Color) -> f64 {
+        match self {
+            Self::Cie76 => Cie76.delta_e(a, b),
+            Self::Cie94 => Cie94::graphic_arts().delta_e(a, b),
+            Self::Ciede2000 => Ciede2000.delta_e(a, b),
+            Self::Cmc => Cmc::acceptability().delta_e(a, b),
+            Self::Ok => DeltaEOk.delta_e(a, b),
+            Self::Cam16Ucs => Cam16Ucs::default().delta_e(a, b),
+            Self::Itp => DeltaEItp.delta_e(a, b),
+        }
+    }
+
+    /// Just-noticeable difference in this metric's units.
+    pub fn jnd(self) -> f64 {
+        match self {
+            Self::Cie76 => Cie76.jnd(),
+            Self::Cie94 => Cie94::graphic_arts().jnd(),
+            Self::Ciede2000 => Ciede2000.jnd(),
+            Self::Cmc => Cmc::acceptability().jnd(),
+            Self::Ok => DeltaEOk.jnd(),
+            Self::Cam16Ucs => Cam16Ucs::default().jnd(),
+            Self::Itp => DeltaEItp.jnd(),
+        }
+    }
+
+    /// Short display name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cie76 => "ΔE76",
+            Self::Cie94 => "ΔE94",
+            Self::Ciede2000 => "ΔE00",
+            Self::Cmc => "ΔE CMC",
+            Self::Ok => "ΔEOK",
+            Self::Cam16Ucs => "ΔE CAM16-UCS",
+            Self::Itp => "ΔE ITP",
+        }
+    }
+
+    /// Parse a metric name (`"cie76"`, `"cie94"`, `"ciede2000"`, `"cmc"`,
+    /// `"ok"`, `"cam16-ucs"`, `"itp"`; case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "cie76" | "de76" => Some(Self::Cie76),
+            "cie94" | "de94" => Some(Self::Cie94),
+            "ciede2000" | "de2000" | "de00" => Some(Self::Ciede2000),
+            "cmc" => Some(Self::Cmc),
+            "ok" | "oklab" | "deok" => Some(Self::Ok),
+            "cam16-ucs" | "cam16ucs" | "cam16" => Some(Self::Cam16Ucs),
+            "itp" | "ictcp" => Some(Self::Itp),
+            _ => None,
+        }
+    }
+}
+
 /// Kinds of constraints the solver understands.
 #[derive(Debug, Clone)]
 pub enum ConstraintKind {
@@ -68,6 +198,77 @@ pub enum ConstraintKind {
         min: f64,
         max: f64,
     },
+
+    /// ΔE between `color_idx` and `other_idx` ≥ `target`, keeping
+    /// categorical colors distinguishable.
+    MinDeltaE {
+        /// The other color.
+        other_idx: usize,
+        /// Difference formula; `target` is in its units.
+        metric: DeltaEMetric,
+        /// Minimum difference.
+        target: f64,
+    },
+
+    /// ΔE between `color_idx` and `other_idx` as seen with `cvd` ≥ `target`.
+    MinCvdDeltaE {
+        /// The other color.
+        other_idx: usize,
+        /// Simulated deficiency (full severity).
+        cvd: CVDType,
+        /// Difference formula; `target` is in its units.
+        metric: DeltaEMetric,
+        /// Minimum difference.
+        target: f64,
+    },
+
+    /// Color must not move. The solver never updates a locked color.
+    Locked,
+
+    /// APCA polarity of `color_idx` (text) on `other_idx` (background).
+    ///
+    /// Pair with [`MinAPCA`](Self::MinAPCA) to also require a magnitude.
+    ApcaPolarity {
+        /// Background color.
+        other_idx: usize,
+        /// Required polarity.
+        polarity: Polarity,
+    },
+
+    /// ΔE from `reference` (e.g. the original brand color) ≤ `max`.
+    MaxDrift {
+        /// Color the result must stay close to.
+        reference: OKLCH,
+        /// Difference formula; `max` is in its units.
+        metric: DeltaEMetric,
+        /// Maximum difference.
+        max: f64,
+    },
+
+    /// Color must be inside the gamut of `space`.
+    ///
+    /// [`InGamut`](Self::InGamut) is `InGamutOf { space: RgbSpace::Srgb }`.
+    /// The solver maps each color into the narrowest gamut it is constrained
+    /// to, and into sRGB when it has no gamut constraint.
+    InGamutOf {
+        /// Target RGB space, e.g. Display P3 or Rec.2020.
+        space: RgbSpace,
+    },
+}
+
+impl ConstraintKind {
+    /// The second color this constraint relates `color_idx` to, if any.
+    pub fn other_idx(&self) -> Option<usize> {
+        match self {
+            Self::MinContrast { other_idx, .. }
+            | Self::MinAPCA { other_idx, .. }
+            | Self::HarmonyAngle { other_idx, .. }
+            | Self::MinDeltaE { other_idx, .. }
+            | Self::MinCvdDeltaE { other_idx, .. }
+            | Self::ApcaPolarity { other_idx, .. } => Some(*other_idx),
+            _ => None,
+        }
+    }
 }
 
 // =============================================================================
@@ -111,6 +312,8 @@ impl Default for SolverConfig {
 /// A constraint violation with details.
 #[derive(Debug, Clone)]
 pub struct ConstraintViolation {
+    /// Index of the violated constraint.
+    pub constraint_idx: usize,
     /// Index of the violating color.
     pub color_idx: usize,
     /// Description of the violated constraint.
@@ -136,6 +339,10 @@ pub struct SolverResult {
     pub final_penalty: f64,
     /// Remaining violations at convergence.
     pub violations: Vec<ConstraintViolation>,
+    /// Indices of a minimal set of constraints the solver could not satisfy
+    /// together; empty when converged. Dropping any one of them lets the
+    /// rest converge.
+    pub infeasible: Vec<usize>,
 }
 
 // =============================================================================
@@ -198,11 +405,39 @@ impl ConstraintSolver {
 
     /// Run the solver until convergence or max_iterations.
     ///
-    /// Modifies `self.colors` in place and returns the result.
+    /// Modifies `self.colors` in place and returns the result. If the
+    /// constraints cannot all be met, [`SolverResult::infeasible`] names the
+    /// ones that conflict.
     pub fn solve(&mut self) -> SolverResult {
+        let start = self.colors.clone();
+        let iterations = self.descend();
+
+        let final_penalty = self.total_penalty(&self.colors.clone());
+        let converged = final_penalty < self.config.convergence_threshold;
+        let violations = self.compute_violations();
+        let infeasible = if converged {
+            Vec::new()
+        } else {
+            self.conflicting_constraints(&start, &violations)
+        };
+
+        SolverResult {
+            colors: self.colors.clone(),
+            converged,
+            iterations,
+            final_penalty,
+            violations,
+            infeasible,
+        }
+    }
+
+    /// Penalty descent on `self.colors`; returns the iteration count.
+    fn descend(&mut self) -> usize {
         let mut step = self.config.step_size;
         let mut prev_penalty = self.total_penalty(&self.colors.clone());
         let mut iterations = 0;
+        let locked = self.locked();
+        let gamuts: Vec<RgbSpace> = (0..self.colors.len()).map(|i| self.gamut_target(i)).collect();
 
         for iter in 0..self.config.max_iterations {
             iterations = iter + 1;
@@ -212,16 +447,22 @@ impl ConstraintSolver {
             }
 
             // Compute finite-difference gradient for each color × each OKLCH dimension
-            let grad = self.finite_diff_gradient(step);
+            let grad = self.finite_diff_gradient(step, &locked);
 
             // Gradient descent step with backtracking
             let mut new_colors = self.colors.clone();
             for (i, g) in grad.iter().enumerate() {
+                if locked[i] {
+                    continue;
+                }
                 new_colors[i].l = (new_colors[i].l - step * g[0]).clamp(0.0, 1.0);
                 new_colors[i].c = (new_colors[i].c - step * g[1]).max(0.0);
                 new_colors[i].h = (new_colors[i].h - step * g[2]).rem_euclid(360.0);
                 // Gamut-map after each update
-                new_colors[i] = new_colors[i].map_to_gamut();
+                new_colors[i] = match gamuts[i] {
+                    RgbSpace::Srgb => new_colors[i].map_to_gamut(),
+                    space => new_colors[i].map_to_gamut_of(space),
+                };
             }
 
             let new_penalty = self.total_penalty(&new_colors);
@@ -241,17 +482,106 @@ impl ConstraintSolver {
             }
         }
 
-        let final_penalty = self.total_penalty(&self.colors.clone());
-        let converged = final_penalty < self.config.convergence_threshold;
-        let violations = self.compute_violations();
+        iterations
+    }
 
-        SolverResult {
-            colors: self.colors.clone(),
-            converged,
-            iterations,
-            final_penalty,
-            violations,
+    /// Which colors carry a [`ConstraintKind::Locked`] constraint.
+    fn locked(&self) -> Vec<bool> {
+        let mut locked = vec![false; self.colors.len()];
+        for c in &self.constraints {
+            if matches!(c.kind, ConstraintKind::Locked) && c.color_idx < locked.len() {
+                locked[c.color_idx] = true;
+            }
+        }
+        locked
+    }
+
+    /// Narrowest gamut color `idx` is constrained to; sRGB if none.
+    fn gamut_target(&self, idx: usize) -> RgbSpace {
+        self.constraints
+            .iter()
+            .filter(|c| c.color_idx == idx)
+            .filter_map(|c| match c.kind {
+                ConstraintKind::InGamut => Some(RgbSpace::Srgb),
+                ConstraintKind::InGamutOf { space } => Some(space),
+                _ => None,
+            })
+            .min_by(|a, b| a.gamut_volume().total_cmp(&b.gamut_volume()))
+            .unwrap_or(RgbSpace::Srgb)
+    }
+
+    // =========================================================================
+    // Internal: infeasibility
+    // =========================================================================
+
+    /// Minimal subset of constraints that cannot be satisfied together.
+    ///
+    /// Candidates are the constraints connected (through shared colors) to a
+    /// remaining violation. A deletion filter then re-solves from `start`
+    /// without each candidate in turn and drops it for good whenever the
+    /// rest still fails, leaving a set in which every constraint is needed
+    /// for the conflict.
+    fn conflicting_constraints(
+        &self,
+        start: &[OKLCH],
+        violations: &[ConstraintViolation],
+    ) -> Vec<usize> {
+        let touches = |c: &ColorConstraint, colors: &[bool]| {
+            colors.get(c.color_idx).copied().unwrap_or(false)
+                || c.kind.other_idx().and_then(|o| colors.get(o).copied()).unwrap_or(false)
+        };
+
+        let mut involved = vec![false; start.len()];
+        for v in violations {
+            let c = &self.constraints[v.constraint_idx];
+            involved[c.color_idx] = true;
+            if let Some(o) = c.kind.other_idx().filter(|&o| o < involved.len()) {
+                involved[o] = true;
+            }
+        }
+        loop {
+            let mut grown = involved.clone();
+            for c in self.constraints.iter().filter(|c| touches(c, &involved)) {
+                if c.color_idx < grown.len() {
+                    grown[c.color_idx] = true;
+                }
+                if let Some(o) = c.kind.other_idx().filter(|&o| o < grown.len()) {
+                    grown[o] = true;
+                }
+            }
+            if grown == involved {
+                break;
+            }
+            involved = grown;
+        }
+
+        let mut conflict: Vec<usize> = (0..self.constraints.len())
+            .filter(|&k| touches(&self.constraints[k], &involved))
+            .collect();
+        if self.subset_converges(&conflict, start) {
+            // Only this run's descent got stuck; report what it left violated.
+            return violations.iter().map(|v| v.constraint_idx).collect();
+        }
+
+        let mut i = 0;
+        while i < conflict.len() {
+            let mut without = conflict.clone();
+            without.remove(i);
+            if self.subset_converges(&without, start) {
+                i += 1;
+            } else {
+                conflict = without;
+            }
         }
+        conflict
+    }
+
+    /// Whether the constraints at `subset` converge from `start`.
+    fn subset_converges(&self, subset: &[usize], start: &[OKLCH]) -> bool {
+        let constraints = subset.iter().map(|&k| self.constraints[k].clone()).collect();
+        let mut solver = ConstraintSolver::new(start.to_vec(), constraints, self.config.clone());
+        solver.descend();
+        solver.total_penalty(&solver.colors) < self.config.convergence_threshold
     }
 
     // =========================================================================
@@ -332,16 +662,68 @@ impl ConstraintSolver {
                 let hi_viol = (ch - max).max(0.0);
                 (lo_viol + hi_viol).powi(2)
             }
+
+            ConstraintKind::MinDeltaE { other_idx, metric, target } => {
+                if *other_idx >= colors.len() {
+                    return 0.0;
+                }
+                let de = metric.delta_e(&color.to_color(), &colors[*other_idx].to_color());
+                let violation = ((target - de) / metric.jnd()).max(0.0);
+                violation * violation * 0.01 // In JND units, scaled like APCA
+            }
+
+            ConstraintKind::MinCvdDeltaE { other_idx, cvd, metric, target } => {
+                if *other_idx >= colors.len() {
+                    return 0.0;
+                }
+                let de = cvd_delta_e(color, colors[*other_idx], *cvd, *metric);
+                let violation = ((target - de) / metric.jnd()).max(0.0);
+                violation * violation * 0.01
+            }
+
+            // Locked colors are never moved, so the constraint always holds.
+            ConstraintKind::Locked => 0.0,
+
+            ConstraintKind::ApcaPolarity { other_idx, polarity } => {
+                if *other_idx >= colors.len() {
+                    return 0.0;
+                }
+                let lc = APCAMetric.evaluate(color.to_color(), colors[*other_idx].to_color()).value;
+                // Positive Lc = dark text on a light background.
+                let violation = match polarity {
+                    Polarity::DarkOnLight => (-lc).max(0.0),
+                    Polarity::LightOnDark => lc.max(0.0),
+                };
+                violation * violation * 0.01
+            }
+
+            ConstraintKind::MaxDrift { reference, metric, max } => {
+                let de = metric.delta_e(&reference.to_color(), &color.to_color());
+                let violation = ((de - max) / metric.jnd()).max(0.0);
+                violation * violation * 0.01
+            }
+
+            ConstraintKind::InGamutOf { space } => {
+                color.to_color().to_rgb_space(*space).iter().map(|&v| {
+                    if v < 0.0 { (-v).powi(2) }
+                    else if v > 1.0 { (v - 1.0).powi(2) }
+                    else { 0.0 }
+                }).sum::<f64>()
+            }
         }
     }
 
     /// Compute finite-difference gradient of penalty w.r.t. each color's [L, C, H].
-    fn finite_diff_gradient(&self, step: f64) -> Vec<[f64; 3]> {
+    fn finite_diff_gradient(&self, step: f64, locked: &[bool]) -> Vec<[f64; 3]> {
         let eps = self.config.fd_epsilon.max(step * 0.01);
         let base_penalty = self.total_penalty(&self.colors);
 
         (0..self.colors.len())
             .map(|i| {
+                if locked[i] {
+                    return [0.0; 3];
+                }
+
                 let mut colors_l = self.colors.clone();
                 colors_l[i].l = (colors_l[i].l + eps).clamp(0.0, 1.0);
                 let dl = (self.total_penalty(&colors_l) - base_penalty) / eps;
@@ -363,7 +745,7 @@ impl ConstraintSolver {
     fn compute_violations(&self) -> Vec<ConstraintViolation> {
         let mut violations = Vec::new();
 
-        for constraint in &self.constraints {
+        for (constraint_idx, constraint) in self.constraints.iter().enumerate() {
             if constraint.color_idx >= self.colors.len() {
                 continue;
             }
@@ -397,9 +779,47 @@ impl ConstraintSolver {
                         format!("Chroma {:.3} not in [{:.3}, {:.3}]",
                             self.colors[constraint.color_idx].c, min, max)
                     }
+                    ConstraintKind::MinDeltaE { other_idx, metric, target } => {
+                        let de = metric.delta_e(
+                            &self.colors[constraint.color_idx].to_color(),
+                            &self.colors[*other_idx].to_color(),
+                        );
+                        format!("{} {:.2} < {:.2} (colors {} vs {})",
+                            metric.name(), de, target, constraint.color_idx, other_idx)
+                    }
+                    ConstraintKind::MinCvdDeltaE { other_idx, cvd, metric, target } => {
+                        let de = cvd_delta_e(
+                            self.colors[constraint.color_idx], self.colors[*other_idx], *cvd, *metric,
+                        );
+                        format!("{} under {:?} {:.2} < {:.2} (colors {} vs {})",
+                            metric.name(), cvd, de, target, constraint.color_idx, other_idx)
+                    }
+                    ConstraintKind::Locked => {
+                        format!("Color {} is locked", constraint.color_idx)
+                    }
+                    ConstraintKind::ApcaPolarity { other_idx, polarity } => {
+                        let lc = APCAMetric.evaluate(
+                            self.colors[constraint.color_idx].to_color(),
+                            self.colors[*other_idx].to_color(),
+                        ).value;
+                        format!("APCA Lc {:.1} is not {:?} (colors {} vs {})",
+                            lc, polarity, constraint.color_idx, other_idx)
+                    }
+                    ConstraintKind::MaxDrift { reference, metric, max } => {
+                        let de = metric.delta_e(
+                            &reference.to_color(),
+                            &self.colors[constraint.color_idx].to_color(),
+                        );
+                        format!("{} drift {:.2} > {:.2} (color {})",
+                            metric.name(), de, max, constraint.color_idx)
+                    }
+                    ConstraintKind::InGamutOf { space } => {
+                        format!("Color {} out of {} gamut", constraint.color_idx, space.css_name())
+                    }
                 };
 
                 violations.push(ConstraintViolation {
+                    constraint_idx,
                     color_idx: constraint.color_idx,
                     description,
                     magnitude: penalty.sqrt(),
@@ -425,6 +845,11 @@ fn wcag_contrast(a: OKLCH, b: OKLCH) -> f64 {
     (lighter + 0.05) / (darker + 0.05)
 }
 
+/// ΔE between two colors as seen with a color vision deficiency.
+fn cvd_delta_e(a: OKLCH, b: OKLCH, cvd: CVDType, metric: DeltaEMetric) -> f64 {
+    metric.delta_e(&simulate_cvd(&a.to_color(), cvd), &simulate_cvd(&b.to_color(), cvd))
+}
+
 /// Compute APCA Lc contrast (simplified approximation using APCA luminance).
 fn apca_lc(text: OKLCH, bg: OKLCH) -> f64 {
     use momoto_core::luminance::relative_luminance_apca;
@@ -536,4 +961,145 @@ mod tests {
         let result = solver.solve();
         assert!(result.final_penalty >= 0.0);
     }
+
+    #[test]
+    fn test_locked_color_does_not_move() {
+        let bg = OKLCH::new(0.97, 0.01, 90.0);
+        let colors = vec![OKLCH::new(0.75, 0.1, 250.0), bg];
+        let constraints = vec![
+            ColorConstraint::new(0, ConstraintKind::MinContrast { other_idx: 1, target: 4.5 }),
+            ColorConstraint::new(1, ConstraintKind::Locked),
+        ];
+
+        let result = ConstraintSolver::with_defaults(colors, constraints).solve();
+        assert!(result.converged, "violations: {:?}", result.violations);
+        assert_eq!(result.colors[1], bg);
+        assert!(wcag_contrast(result.colors[0], bg) >= 4.4);
+    }
+
+    #[test]
+    fn test_pairwise_distinct() {
+        let colors = vec![
+            OKLCH::new(0.6, 0.12, 250.0),
+            OKLCH::new(0.61, 0.12, 252.0),
+            OKLCH::new(0.6, 0.11, 255.0),
+        ];
+        let constraints = ColorConstraint::pairwise_distinct(&[0, 1, 2], DeltaEMetric::Ciede2000, 10.0);
+        assert_eq!(constraints.len(), 3);
+
+        let result = ConstraintSolver::with_defaults(colors, constraints).solve();
+        assert!(result.converged, "violations: {:?}", result.violations);
+        for (a, b) in [(0, 1), (0, 2), (1, 2)] {
+            let de = DeltaEMetric::Ciede2000.delta_e(&result.colors[a].to_color(), &result.colors[b].to_color());
+            assert!(de >= 9.0, "ΔE00({a}, {b}) = {de}");
+        }
+    }
+
+    #[test]
+    fn test_cvd_distinct() {
+        // A red and its own deuteranopia simulation look identical to a deuteranope.
+        let red = OKLCH::new(0.6, 0.15, 30.0);
+        let confused = OKLCH::from_color(&simulate_cvd(&red.to_color(), CVDType::Deuteranopia));
+        let colors = vec![red, confused];
+        let before = cvd_delta_e(colors[0], colors[1], CVDType::Deuteranopia, DeltaEMetric::Ciede2000);
+        let constraints = ColorConstraint::pairwise_cvd_distinct(
+            &[0, 1],
+            &[CVDType::Deuteranopia],
+            DeltaEMetric::Ciede2000,
+            20.0,
+        );
+        assert!(before < 20.0, "already distinct: {before}");
+
+        let result = ConstraintSolver::with_defaults(colors, constraints).solve();
+        assert!(result.converged, "violations: {:?}", result.violations);
+        let after = cvd_delta_e(result.colors[0], result.colors[1], CVDType::Deuteranopia, DeltaEMetric::Ciede2000);
+        assert!(after >= 19.0, "ΔE00 under deuteranopia = {after}");
+    }
+
+    #[test]
+    fn test_apca_polarity() {
+        // Dark text on a mid background, required to be light-on-dark.
+        let colors = vec![OKLCH::new(0.3, 0.05, 250.0), OKLCH::new(0.45, 0.05, 250.0)];
+        let constraints = vec![
+            ColorConstraint::new(0, ConstraintKind::ApcaPolarity { other_idx: 1, polarity: Polarity::LightOnDark }),
+            ColorConstraint::new(1, ConstraintKind::Locked),
+        ];
+
+        let result = ConstraintSolver::with_defaults(colors, constraints).solve();
+        assert!(result.converged, "violations: {:?}", result.violations);
+        let lc = APCAMetric.evaluate(result.colors[0].to_color(), result.colors[1].to_color()).value;
+        assert!(lc <= 0.0, "Lc = {lc}");
+    }
+
+    #[test]
+    fn test_max_drift() {
+        let brand = OKLCH::new(0.75, 0.15, 30.0);
+        let white = OKLCH::new(1.0, 0.0, 0.0);
+        let constraints = |max: f64| {
+            vec![
+                ColorConstraint::new(0, ConstraintKind::MinContrast { other_idx: 1, target: 4.5 }),
+                ColorConstraint::new(1, ConstraintKind::Locked),
+                ColorConstraint::new(0, ConstraintKind::MaxDrift { reference: brand, metric: DeltaEMetric::Ciede2000, max }),
+            ]
+        };
+
+        let result = ConstraintSolver::with_defaults(vec![brand, white], constraints(30.0)).solve();
+        assert!(result.converged, "violations: {:?}", result.violations);
+        let drift = DeltaEMetric::Ciede2000.delta_e(&brand.to_color(), &result.colors[0].to_color());
+        assert!(drift <= 31.0, "drift = {drift}");
+
+        let result = ConstraintSolver::with_defaults(vec![brand, white], constraints(2.0)).solve();
+        assert!(!result.converged);
+        // White cannot get any lighter, so the lock is not part of the conflict.
+        assert_eq!(result.infeasible, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_wide_gamut_target() {
+        // Far outside even Display P3.
+        let colors = vec![OKLCH::new(0.7, 0.4, 150.0)];
+        let constraints = vec![ColorConstraint::new(0, ConstraintKind::InGamutOf { space: RgbSpace::DisplayP3 })];
+
+        let result = ConstraintSolver::with_defaults(colors, constraints).solve();
+        assert!(result.converged, "violations: {:?}", result.violations);
+        let c = result.colors[0];
+        assert!(c.to_color().to_rgb_space(RgbSpace::DisplayP3).iter().all(|v| (-0.01..=1.01).contains(v)));
+        // Keeps chroma that sRGB cannot show.
+        assert!(c.c > c.map_to_gamut().c + 0.01, "chroma {} vs sRGB {}", c.c, c.map_to_gamut().c);
+    }
+
+    #[test]
+    fn test_gamut_target_is_smallest_volume() {
+        // Display P3 is the smaller gamut, whichever order the constraints come in.
+        let p3 = ConstraintKind::InGamutOf { space: RgbSpace::DisplayP3 };
+        let adobe = ConstraintKind::InGamutOf { space: RgbSpace::AdobeRgb };
+        for kinds in [[p3.clone(), adobe.clone()], [adobe, p3]] {
+            let constraints = kinds.into_iter().map(|k| ColorConstraint::new(0, k)).collect();
+            let solver = ConstraintSolver::with_defaults(vec![OKLCH::new(0.7, 0.1, 150.0)], constraints);
+            assert_eq!(solver.gamut_target(0), RgbSpace::DisplayP3);
+        }
+    }
+
+    #[test]
+    fn test_reports_infeasible_subset() {
+        // Two locked mid grays cannot reach 4.5:1; color 2 is unrelated.
+        let colors = vec![
+            OKLCH::new(0.55, 0.0, 0.0),
+            OKLCH::new(0.6, 0.0, 0.0),
+            OKLCH::new(0.5, 0.1, 30.0),
+        ];
+        let constraints = vec![
+            ColorConstraint::new(2, ConstraintKind::LightnessRange { min: 0.6, max: 0.7 }),
+            ColorConstraint::new(0, ConstraintKind::Locked),
+            ColorConstraint::new(0, ConstraintKind::MinContrast { other_idx: 1, target: 4.5 }),
+            ColorConstraint::new(1, ConstraintKind::Locked),
+            ColorConstraint::new(1, ConstraintKind::ChromaRange { min: 0.0, max: 0.1 }),
+        ];
+
+        let result = ConstraintSolver::with_defaults(colors, constraints).solve();
+        assert!(!result.converged);
+        assert_eq!(result.infeasible, vec![1, 2, 3]);
+        assert!(result.violations.iter().any(|v| v.constraint_idx == 2));
+        assert!((result.colors[2].l - 0.6).abs() < 0.11);
+    }
 }
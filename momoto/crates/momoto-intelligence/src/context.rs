@@ -3,6 +3,8 @@
 //! Defines the context in which colors will be used, enabling
 //! context-aware recommendations.
 
+use momoto_metrics::apca::readability::{min_lc, ConformanceLevel, TextRole};
+
 /// Usage context for color recommendations.
 ///
 /// Different contexts have different requirements:
@@ -100,6 +102,34 @@ impl UsageContext {
         }
     }
 
+    /// Get the minimum absolute APCA Lc for text set in a given font.
+    ///
+    /// Driven by the APCA font lookup table at Silver level (see
+    /// [`momoto_metrics::apca::readability`]): body text needs 15 Lc more
+    /// than other text, icons keep [`min_apca_lc`](Self::min_apca_lc), and
+    /// contexts without a requirement return `Some(0.0)`. Returns `None` if
+    /// no contrast makes the font readable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use momoto_intelligence::context::UsageContext;
+    ///
+    /// assert_eq!(UsageContext::BodyText.min_apca_lc_for_font(18.0, 400), Some(90.0));
+    /// assert_eq!(UsageContext::LargeText.min_apca_lc_for_font(24.0, 700), Some(45.0));
+    /// assert_eq!(UsageContext::BodyText.min_apca_lc_for_font(8.0, 400), None);
+    /// ```
+    #[must_use]
+    pub fn min_apca_lc_for_font(self, font_size_px: f64, font_weight: u16) -> Option<f64> {
+        let role = match self {
+            UsageContext::BodyText => TextRole::Body,
+            UsageContext::LargeText | UsageContext::Interactive => TextRole::Content,
+            UsageContext::IconsGraphics => return Some(self.min_apca_lc()),
+            UsageContext::Decorative | UsageContext::Disabled => return Some(0.0),
+        };
+        min_lc(font_size_px, font_weight, ConformanceLevel::Silver, role)
+    }
+
     /// Returns whether this context requires accessibility compliance.
     ///
     /// # Examples
@@ -235,6 +265,27 @@ mod tests {
         assert_eq!(UsageContext::Interactive.min_apca_lc(), 60.0);
     }
 
+    #[test]
+    fn test_apca_requirements_for_font() {
+        // Bold 16px interactive labels need Lc 60, regular 16px need Lc 90
+        assert_eq!(
+            UsageContext::Interactive.min_apca_lc_for_font(16.0, 700),
+            Some(60.0)
+        );
+        assert_eq!(
+            UsageContext::Interactive.min_apca_lc_for_font(16.0, 400),
+            Some(90.0)
+        );
+        assert_eq!(
+            UsageContext::IconsGraphics.min_apca_lc_for_font(16.0, 400),
+            Some(45.0)
+        );
+        assert_eq!(
+            UsageContext::Decorative.min_apca_lc_for_font(10.0, 100),
+            Some(0.0)
+        );
+    }
+
     #[test]
     fn test_requires_compliance() {
         assert!(UsageContext::BodyText.requires_compliance());
@@ -8,6 +8,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use momoto_core::color::naming::{describe, Locale};
+use momoto_core::color::Color;
+
 use crate::advanced_scoring::{AdvancedScore, PriorityAssessment};
 use crate::scoring::QualityScore;
 
@@ -285,6 +288,21 @@ impl ExplanationGenerator {
         Self
     }
 
+    /// Describe a hex color in words, e.g. "muted dark teal".
+    ///
+    /// Returns `None` if `color` is not a valid hex color.
+    pub fn describe_color(&self, color: &str, locale: Locale) -> Option<String> {
+        Color::from_hex(color).ok().map(|c| describe(&c).text(locale))
+    }
+
+    /// Hex color followed by its English description, e.g. "#2f5c5c (muted dark teal)".
+    fn label(&self, color: &str) -> String {
+        match self.describe_color(color, Locale::En) {
+            Some(description) => format!("{} ({})", color, description),
+            None => color.to_string(),
+        }
+    }
+
     /// Generate explanation for a contrast improvement
     pub fn generate_contrast_improvement(
         &self,
@@ -309,7 +327,7 @@ impl ExplanationGenerator {
         let problem = format!(
             "The current color {} on {} has a contrast ratio of {:.2}:1, \
              which is below the required {:.1}:1 for accessibility compliance.",
-            original_color, background, original_ratio, target_ratio
+            self.label(original_color), self.label(background), original_ratio, target_ratio
         );
 
         let mut builder = ExplanationBuilder::new()
@@ -349,6 +367,18 @@ impl ExplanationGenerator {
             builder = builder.benefit("Preserves original color identity (hue unchanged)");
         }
 
+        if let (Ok(original), Ok(recommended)) =
+            (Color::from_hex(original_color), Color::from_hex(recommended_color))
+        {
+            let (before, after) = (describe(&original), describe(&recommended));
+            if before.base == after.base && !after.base.is_neutral() {
+                builder = builder.benefit(format!(
+                    "Still reads as {} ({} → {})",
+                    Locale::En.base(after.base), before, after
+                ));
+            }
+        }
+
         // Add trade-offs
         if oklch_changes.delta_l.abs() > 0.15 {
             builder = builder.trade_off(format!(
@@ -584,6 +614,28 @@ mod tests {
         assert!(!explanation.benefits.is_empty());
     }
 
+    #[test]
+    fn test_color_descriptions() {
+        let generator = ExplanationGenerator::new();
+        assert_eq!(
+            generator.describe_color("#2f5c5c", Locale::En).as_deref(),
+            Some("muted dark teal")
+        );
+        assert_eq!(generator.describe_color("not a color", Locale::En), None);
+
+        let explanation = generator.generate_contrast_improvement(
+            "#6a8fd8",
+            "#3a5fa8",
+            "#ffffff",
+            3.3,
+            6.1,
+            4.5,
+            OklchChanges::new(-0.14, 0.0, 0.0),
+        );
+        assert!(explanation.problem_addressed.contains("#ffffff (white)"));
+        assert!(explanation.benefits.iter().any(|b| b.starts_with("Still reads as blue")));
+    }
+
     #[test]
     fn test_markdown_output() {
         let explanation = ExplanationBuilder::new()
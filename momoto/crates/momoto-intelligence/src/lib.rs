@@ -106,6 +106,10 @@ pub mod advanced_scoring;
 pub mod context;
 pub mod explanation;
 pub mod harmony;
+/// N×N palette accessibility matrix and usable-pair recommendations.
+pub mod palette_matrix;
+/// Image color quantization (Wu, octree, k-means) and theme seed extraction.
+pub mod quantize;
 pub mod recommendation;
 pub mod scoring;
 pub mod constraints;
@@ -150,8 +154,20 @@ pub use harmony::{
     hex_to_oklch, oklch_to_hex, design_system_palette,
 };
 
+// Palette matrix types
+pub use palette_matrix::{
+    CvdDeltaE, MatrixSummary, PairMetrics, PairUsage, PaletteEntry,
+    PaletteMatrix, UsageThresholds,
+};
+
+// Quantization and seed extraction types
+pub use quantize::{
+    ColorCluster, Quantizer, ScoreOptions, SeedColor,
+    extract_seeds, palette_from_image, scheme_from_image, score_seeds,
+};
+
 // Constraint solver types
 pub use constraints::{
     ColorConstraint, ConstraintKind, ConstraintSolver, SolverResult,
-    ConstraintViolation, SolverConfig,
+    ConstraintViolation, SolverConfig, DeltaEMetric,
 };
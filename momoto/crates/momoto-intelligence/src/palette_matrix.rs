@@ -0,0 +1,498 @@
+// =============================================================================
+// momoto-intelligence: Palette Accessibility Matrix
+// File: crates/momoto-intelligence/src/palette_matrix.rs
+//
+// Evaluates every foreground/background combination of a palette or token
+// set — the N×N review a design system needs before shipping — instead of
+// one pair at a time as `QualityScorer::score` does.
+//
+// Per ordered pair (foreground row, background column):
+//   - WCAG 2.x contrast ratio
+//   - APCA Lc in both polarities
+//   - CIEDE2000 ΔE, normally and under simulated protanopia, deuteranopia
+//     and tritanopia
+//   - the most demanding use the pair is good for: body text, large text,
+//     UI components, or nothing
+//
+// Usage thresholds default to the `UsageContext` minimums (WCAG AA + APCA)
+// for text and to WCAG 2.2 SC 1.4.11 (3:1) plus APCA Lc 30 for non-text UI.
+// =============================================================================
+
+use serde::Serialize;
+
+use momoto_core::color::cvd::{simulate_cvd, CVDType};
+use momoto_core::color::Color;
+use momoto_core::perception::ContrastMetric;
+use momoto_metrics::apca::APCAMetric;
+use momoto_metrics::difference::{Ciede2000, ColorDifference};
+use momoto_metrics::wcag::non_text::NON_TEXT_MIN_RATIO;
+use momoto_metrics::wcag::WCAGMetric;
+
+use crate::context::UsageContext;
+use crate::harmony::{oklch_to_hex, Palette};
+
+// =============================================================================
+// Classification
+// =============================================================================
+
+/// Most demanding use a foreground/background pair is good for.
+///
+/// Ordered from least to most demanding, so a pair usable for body text is
+/// also usable for everything below it: `usage >= PairUsage::LargeText`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum PairUsage {
+    /// Fails every threshold; decoration only.
+    Unusable,
+    /// Icons, borders, focus rings and other non-text UI.
+    UiComponent,
+    /// Headings and other large text.
+    LargeText,
+    /// Body text.
+    BodyText,
+}
+
+impl PairUsage {
+    /// Every class, most demanding first.
+    pub const ALL: [Self; 4] = [
+        Self::BodyText,
+        Self::LargeText,
+        Self::UiComponent,
+        Self::Unusable,
+    ];
+
+    /// Human-readable label.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::BodyText => "body text",
+            Self::LargeText => "large text",
+            Self::UiComponent => "UI components",
+            Self::Unusable => "not usable",
+        }
+    }
+}
+
+/// Minimum contrast for each [`PairUsage`] class.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct UsageThresholds {
+    /// Body text: minimum WCAG ratio.
+    pub body_wcag: f64,
+    /// Body text: minimum |APCA Lc|.
+    pub body_apca: f64,
+    /// Large text: minimum WCAG ratio.
+    pub large_wcag: f64,
+    /// Large text: minimum |APCA Lc|.
+    pub large_apca: f64,
+    /// UI components: minimum WCAG ratio.
+    pub ui_wcag: f64,
+    /// UI components: minimum |APCA Lc|.
+    pub ui_apca: f64,
+    /// CIEDE2000 ΔE under simulated CVD below which a pair that normal
+    /// vision tells apart (ΔE at least this much) is flagged as confusable.
+    pub min_cvd_delta_e: f64,
+}
+
+impl Default for UsageThresholds {
+    fn default() -> Self {
+        Self {
+            body_wcag: UsageContext::BodyText.min_wcag_aa(),
+            body_apca: UsageContext::BodyText.min_apca_lc(),
+            large_wcag: UsageContext::LargeText.min_wcag_aa(),
+            large_apca: UsageContext::LargeText.min_apca_lc(),
+            ui_wcag: NON_TEXT_MIN_RATIO,
+            ui_apca: 30.0,
+            min_cvd_delta_e: 10.0,
+        }
+    }
+}
+
+impl UsageThresholds {
+    /// Most demanding class met by a WCAG ratio and APCA Lc.
+    #[must_use]
+    pub fn classify(&self, wcag_ratio: f64, apca_lc: f64) -> PairUsage {
+        let lc = apca_lc.abs();
+        if wcag_ratio >= self.body_wcag && lc >= self.body_apca {
+            PairUsage::BodyText
+        } else if wcag_ratio >= self.large_wcag && lc >= self.large_apca {
+            PairUsage::LargeText
+        } else if wcag_ratio >= self.ui_wcag && lc >= self.ui_apca {
+            PairUsage::UiComponent
+        } else {
+            PairUsage::Unusable
+        }
+    }
+}
+
+// =============================================================================
+// Matrix types
+// =============================================================================
+
+/// A named palette color.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PaletteEntry {
+    /// Token or display name.
+    pub name: String,
+    /// sRGB hex, e.g. `#1e40af`.
+    pub hex: String,
+    #[serde(skip)]
+    color: Color,
+}
+
+impl PaletteEntry {
+    /// Entry for `color` named `name`.
+    pub fn new(name: impl Into<String>, color: Color) -> Self {
+        let [r, g, b] = color.to_srgb8();
+        Self {
+            name: name.into(),
+            hex: format!("#{:02x}{:02x}{:02x}", r, g, b),
+            color,
+        }
+    }
+
+    /// The color.
+    #[must_use]
+    pub const fn color(&self) -> Color {
+        self.color
+    }
+}
+
+/// CIEDE2000 ΔE between two colors as seen with each dichromacy.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CvdDeltaE {
+    /// Under simulated protanopia.
+    pub protanopia: f64,
+    /// Under simulated deuteranopia.
+    pub deuteranopia: f64,
+    /// Under simulated tritanopia.
+    pub tritanopia: f64,
+}
+
+impl CvdDeltaE {
+    fn between(a: &Color, b: &Color) -> Self {
+        let delta = |cvd| Ciede2000.delta_e(&simulate_cvd(a, cvd), &simulate_cvd(b, cvd));
+        Self {
+            protanopia: delta(CVDType::Protanopia),
+            deuteranopia: delta(CVDType::Deuteranopia),
+            tritanopia: delta(CVDType::Tritanopia),
+        }
+    }
+
+    /// Smallest difference over the three deficiencies.
+    #[must_use]
+    pub fn min(&self) -> f64 {
+        self.protanopia.min(self.deuteranopia).min(self.tritanopia)
+    }
+}
+
+/// Metrics for one foreground/background pair.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PairMetrics {
+    /// Row: index of the foreground entry.
+    pub foreground: usize,
+    /// Column: index of the background entry.
+    pub background: usize,
+    /// WCAG contrast ratio (symmetric).
+    pub wcag_ratio: f64,
+    /// APCA Lc of the foreground on the background.
+    pub apca_lc: f64,
+    /// APCA Lc with the roles swapped (background on foreground).
+    pub apca_lc_reversed: f64,
+    /// CIEDE2000 ΔE with normal vision.
+    pub delta_e: f64,
+    /// CIEDE2000 ΔE under simulated dichromacy.
+    pub cvd_delta_e: CvdDeltaE,
+    /// Whether the pair is distinct with normal vision but hard to tell
+    /// apart with some dichromacy.
+    pub cvd_confusable: bool,
+    /// Most demanding use of the foreground on the background.
+    pub usage: PairUsage,
+}
+
+/// Counts of ordered pairs (excluding each color against itself) per class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct MatrixSummary {
+    /// Ordered pairs evaluated.
+    pub pairs: usize,
+    /// Pairs usable for body text.
+    pub body_text: usize,
+    /// Pairs usable for large text but not body text.
+    pub large_text: usize,
+    /// Pairs usable only for UI components.
+    pub ui_component: usize,
+    /// Pairs not usable for anything.
+    pub unusable: usize,
+    /// Pairs confusable under simulated CVD.
+    pub cvd_confusable: usize,
+}
+
+/// N×N accessibility analysis of a palette.
+///
+/// # Example
+///
+/// ```
+/// use momoto_core::color::Color;
+/// use momoto_intelligence::palette_matrix::{PairUsage, PaletteEntry, PaletteMatrix};
+///
+/// let matrix = PaletteMatrix::new(vec![
+///     PaletteEntry::new("ink", Color::from_srgb8(17, 24, 39)),
+///     PaletteEntry::new("paper", Color::from_srgb8(255, 255, 255)),
+///     PaletteEntry::new("accent", Color::from_srgb8(37, 99, 235)),
+/// ]);
+///
+/// assert_eq!(matrix.get(0, 1).unwrap().usage, PairUsage::BodyText);
+/// let best = matrix.best_foreground(1, PairUsage::BodyText).unwrap();
+/// assert_eq!(matrix.entries[best.foreground].name, "ink");
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct PaletteMatrix {
+    /// Palette colors; row and column order of the matrix.
+    pub entries: Vec<PaletteEntry>,
+    /// Row-major cells: `cells[fg * n + bg]`.
+    pub cells: Vec<PairMetrics>,
+    /// Thresholds used for classification.
+    pub thresholds: UsageThresholds,
+}
+
+impl PaletteMatrix {
+    /// Analyze `entries` with the default [`UsageThresholds`].
+    #[must_use]
+    pub fn new(entries: Vec<PaletteEntry>) -> Self {
+        Self::with_thresholds(entries, UsageThresholds::default())
+    }
+
+    /// Analyze `entries` with custom thresholds.
+    #[must_use]
+    pub fn with_thresholds(entries: Vec<PaletteEntry>, thresholds: UsageThresholds) -> Self {
+        let n = entries.len();
+        let mut cells = Vec::with_capacity(n * n);
+        for (fg, fg_entry) in entries.iter().enumerate() {
+            for (bg, bg_entry) in entries.iter().enumerate() {
+                let (a, b) = (fg_entry.color, bg_entry.color);
+                let wcag_ratio = WCAGMetric.evaluate(a, b).value;
+                let apca_lc = APCAMetric.evaluate(a, b).value;
+                let cvd_delta_e = CvdDeltaE::between(&a, &b);
+                let delta_e = Ciede2000.delta_e(&a, &b);
+                let usage = if fg == bg {
+                    PairUsage::Unusable
+                } else {
+                    thresholds.classify(wcag_ratio, apca_lc)
+                };
+                cells.push(PairMetrics {
+                    foreground: fg,
+                    background: bg,
+                    wcag_ratio,
+                    apca_lc,
+                    apca_lc_reversed: APCAMetric.evaluate(b, a).value,
+                    delta_e,
+                    cvd_delta_e,
+                    cvd_confusable: fg != bg
+                        && delta_e >= thresholds.min_cvd_delta_e
+                        && cvd_delta_e.min() < thresholds.min_cvd_delta_e,
+                    usage,
+                });
+            }
+        }
+        Self {
+            entries,
+            cells,
+            thresholds,
+        }
+    }
+
+    /// Analyze a generated palette; entries are named by hex.
+    #[must_use]
+    pub fn from_palette(palette: &Palette) -> Self {
+        Self::new(
+            palette
+                .colors
+                .iter()
+                .map(|&c| PaletteEntry::new(oklch_to_hex(c), c.to_color()))
+                .collect(),
+        )
+    }
+
+    /// Number of palette colors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the palette is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Metrics for `foreground` on `background`.
+    #[must_use]
+    pub fn get(&self, foreground: usize, background: usize) -> Option<&PairMetrics> {
+        let n = self.len();
+        if foreground < n && background < n {
+            self.cells.get(foreground * n + background)
+        } else {
+            None
+        }
+    }
+
+    /// Ordered pairs of distinct colors good for at least `usage`, highest
+    /// |APCA Lc| first.
+    #[must_use]
+    pub fn usable_pairs(&self, usage: PairUsage) -> Vec<&PairMetrics> {
+        let mut pairs: Vec<&PairMetrics> = self
+            .cells
+            .iter()
+            .filter(|c| c.foreground != c.background && c.usage >= usage)
+            .collect();
+        pairs.sort_by(|a, b| b.apca_lc.abs().total_cmp(&a.apca_lc.abs()));
+        pairs
+    }
+
+    /// Highest-contrast foreground for `background` that is good for at
+    /// least `usage`.
+    #[must_use]
+    pub fn best_foreground(&self, background: usize, usage: PairUsage) -> Option<&PairMetrics> {
+        self.usable_pairs(usage)
+            .into_iter()
+            .find(|c| c.background == background)
+    }
+
+    /// Pair counts per class.
+    #[must_use]
+    pub fn summary(&self) -> MatrixSummary {
+        let mut summary = MatrixSummary::default();
+        for cell in self.cells.iter().filter(|c| c.foreground != c.background) {
+            summary.pairs += 1;
+            match cell.usage {
+                PairUsage::BodyText => summary.body_text += 1,
+                PairUsage::LargeText => summary.large_text += 1,
+                PairUsage::UiComponent => summary.ui_component += 1,
+                PairUsage::Unusable => summary.unusable += 1,
+            }
+            if cell.cvd_confusable {
+                summary.cvd_confusable += 1;
+            }
+        }
+        summary
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harmony::{generate_palette, HarmonyType};
+    use momoto_core::space::oklch::OKLCH;
+
+    fn tokens() -> PaletteMatrix {
+        PaletteMatrix::new(vec![
+            PaletteEntry::new("black", Color::from_srgb8(0, 0, 0)),
+            PaletteEntry::new("white", Color::from_srgb8(255, 255, 255)),
+            PaletteEntry::new("gray", Color::from_srgb8(118, 118, 118)),
+            PaletteEntry::new("light", Color::from_srgb8(230, 230, 230)),
+            PaletteEntry::new("red", Color::from_srgb8(204, 0, 0)),
+        ])
+    }
+
+    #[test]
+    fn test_matrix_shape_and_diagonal() {
+        let matrix = tokens();
+        assert_eq!(matrix.cells.len(), 25);
+        let own = matrix.get(2, 2).unwrap();
+        assert!((own.wcag_ratio - 1.0).abs() < 1e-9);
+        assert_eq!(own.usage, PairUsage::Unusable);
+        assert!(!own.cvd_confusable);
+        assert!(matrix.get(5, 0).is_none());
+    }
+
+    #[test]
+    fn test_both_polarities() {
+        let matrix = tokens();
+        let dark_on_light = matrix.get(0, 1).unwrap();
+        let light_on_dark = matrix.get(1, 0).unwrap();
+        assert_eq!(dark_on_light.wcag_ratio, light_on_dark.wcag_ratio);
+        assert!(dark_on_light.apca_lc > 0.0 && light_on_dark.apca_lc < 0.0);
+        assert_eq!(dark_on_light.apca_lc_reversed, light_on_dark.apca_lc);
+    }
+
+    #[test]
+    fn test_classification() {
+        let matrix = tokens();
+        // #767676 on white: 4.54:1 but APCA Lc ≈ 71.
+        assert_eq!(matrix.get(2, 1).unwrap().usage, PairUsage::BodyText);
+        assert_eq!(matrix.get(0, 1).unwrap().usage, PairUsage::BodyText);
+        assert_eq!(matrix.get(3, 1).unwrap().usage, PairUsage::Unusable);
+
+        let thresholds = UsageThresholds::default();
+        assert_eq!(thresholds.classify(3.2, 50.0), PairUsage::LargeText);
+        assert_eq!(thresholds.classify(3.2, -35.0), PairUsage::UiComponent);
+        assert_eq!(thresholds.classify(5.0, 40.0), PairUsage::UiComponent);
+    }
+
+    #[test]
+    fn test_cvd_confusion() {
+        // A red and the olive it collapses to with protanopia.
+        let red = Color::from_srgb8(204, 0, 0);
+        let olive = simulate_cvd(&red, CVDType::Protanopia);
+        let matrix = PaletteMatrix::new(vec![
+            PaletteEntry::new("red", red),
+            PaletteEntry::new("olive", olive),
+            PaletteEntry::new("white", Color::from_srgb8(255, 255, 255)),
+        ]);
+
+        let pair = matrix.get(0, 1).unwrap();
+        assert!(pair.delta_e > 20.0);
+        assert!(pair.cvd_delta_e.protanopia < pair.delta_e / 4.0);
+        assert!(pair.cvd_confusable);
+        assert!(!matrix.get(0, 2).unwrap().cvd_confusable);
+        assert_eq!(matrix.summary().cvd_confusable, 2);
+    }
+
+    #[test]
+    fn test_similar_colors_are_not_cvd_confusable() {
+        // Already close with normal vision: CVD takes nothing away.
+        let matrix = PaletteMatrix::new(vec![
+            PaletteEntry::new("gray", Color::from_srgb8(118, 118, 118)),
+            PaletteEntry::new("gray2", Color::from_srgb8(122, 120, 118)),
+        ]);
+
+        let pair = matrix.get(0, 1).unwrap();
+        assert!(pair.delta_e < matrix.thresholds.min_cvd_delta_e);
+        assert!(pair.cvd_delta_e.min() < matrix.thresholds.min_cvd_delta_e);
+        assert!(!pair.cvd_confusable);
+        assert_eq!(matrix.summary().cvd_confusable, 0);
+    }
+
+    #[test]
+    fn test_usable_pairs_and_summary() {
+        let matrix = tokens();
+        let body = matrix.usable_pairs(PairUsage::BodyText);
+        assert!(body.iter().all(|c| c.usage == PairUsage::BodyText));
+        assert!(body
+            .windows(2)
+            .all(|w| w[0].apca_lc.abs() >= w[1].apca_lc.abs()));
+        assert!(matrix.usable_pairs(PairUsage::UiComponent).len() >= body.len());
+
+        let summary = matrix.summary();
+        assert_eq!(summary.pairs, 20);
+        assert_eq!(
+            summary.body_text + summary.large_text + summary.ui_component + summary.unusable,
+            20
+        );
+        assert_eq!(summary.body_text, body.len());
+
+        let best = matrix.best_foreground(1, PairUsage::BodyText).unwrap();
+        assert_eq!(best.foreground, 0);
+    }
+
+    #[test]
+    fn test_from_palette() {
+        let palette = generate_palette(OKLCH::new(0.5, 0.15, 250.0), HarmonyType::Triadic);
+        let matrix = PaletteMatrix::from_palette(&palette);
+        assert_eq!(matrix.len(), 3);
+        assert!(matrix.entries[0].name.starts_with('#'));
+        assert_eq!(matrix.entries[0].name, matrix.entries[0].hex);
+    }
+}
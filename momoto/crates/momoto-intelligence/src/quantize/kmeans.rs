@@ -0,0 +1,157 @@
+// =============================================================================
+// momoto-intelligence: Weighted K-Means Quantizer
+// File: crates/momoto-intelligence/src/quantize/kmeans.rs
+//
+// Lloyd's algorithm over the image's unique colors, each weighted by its
+// pixel count, with distances measured in OKLab so clusters follow
+// perceived rather than RGB differences. Following Celebi (2011), the
+// centroids start from the Wu result instead of random picks, which makes
+// the output deterministic and typically converges in a few iterations.
+// =============================================================================
+
+use momoto_core::color::Color;
+use momoto_core::space::oklch::OKLab;
+
+use super::{channels, histogram, quantize_wu, sort_clusters, ColorCluster};
+
+/// Upper bound on Lloyd iterations.
+const MAX_ITERATIONS: usize = 10;
+
+/// Quantize with Wu-seeded weighted k-means in OKLab to at most
+/// `max_colors` clusters.
+///
+/// Clusters that end up empty are dropped, so fewer than `max_colors`
+/// may be returned even for busy images.
+#[must_use]
+pub fn quantize_kmeans(pixels: &[u8], max_colors: usize) -> Vec<ColorCluster> {
+    let counts = histogram(pixels);
+    if counts.is_empty() || max_colors == 0 {
+        return Vec::new();
+    }
+    let points: Vec<OKLab> = counts
+        .iter()
+        .map(|&(rgb, _)| {
+            let [r, g, b] = channels(rgb);
+            OKLab::from_color(&Color::from_srgb8(r, g, b))
+        })
+        .collect();
+    let weights: Vec<f64> = counts.iter().map(|&(_, n)| f64::from(n)).collect();
+
+    let mut centroids: Vec<OKLab> = quantize_wu(pixels, max_colors)
+        .iter()
+        .map(|cluster| OKLab::from_color(&cluster.color))
+        .collect();
+    let mut assignment = vec![usize::MAX; points.len()];
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (point, slot) in points.iter().zip(assignment.iter_mut()) {
+            let nearest = nearest(&centroids, point);
+            if *slot != nearest {
+                *slot = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![[0.0; 4]; centroids.len()];
+        for ((point, &weight), &cluster) in points.iter().zip(&weights).zip(&assignment) {
+            let sum = &mut sums[cluster];
+            sum[0] += point.l * weight;
+            sum[1] += point.a * weight;
+            sum[2] += point.b * weight;
+            sum[3] += weight;
+        }
+        for (centroid, sum) in centroids.iter_mut().zip(&sums) {
+            if sum[3] > 0.0 {
+                *centroid = OKLab::new(sum[0] / sum[3], sum[1] / sum[3], sum[2] / sum[3]);
+            }
+        }
+    }
+
+    let mut populations = vec![0u32; centroids.len()];
+    for (&(_, count), &cluster) in counts.iter().zip(&assignment) {
+        populations[cluster] += count;
+    }
+    let mut clusters: Vec<ColorCluster> = centroids
+        .iter()
+        .zip(populations)
+        .filter(|&(_, population)| population > 0)
+        .map(|(centroid, population)| ColorCluster {
+            color: to_srgb8_color(centroid),
+            population,
+        })
+        .collect();
+    sort_clusters(&mut clusters);
+    clusters
+}
+
+/// Index of the centroid closest to `point` (squared OKLab distance).
+fn nearest(centroids: &[OKLab], point: &OKLab) -> usize {
+    let mut best = 0;
+    let mut best_distance = f64::INFINITY;
+    for (i, centroid) in centroids.iter().enumerate() {
+        let (dl, da, db) = (
+            point.l - centroid.l,
+            point.a - centroid.a,
+            point.b - centroid.b,
+        );
+        let distance = dl * dl + da * da + db * db;
+        if distance < best_distance {
+            best_distance = distance;
+            best = i;
+        }
+    }
+    best
+}
+
+/// Centroid to an 8-bit sRGB color. A mean of in-gamut colors can land a
+/// hair outside the cube, so channels are clamped.
+fn to_srgb8_color(lab: &OKLab) -> Color {
+    let srgb = lab.to_color().srgb;
+    let channel = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color::from_srgb8(channel(srgb[0]), channel(srgb[1]), channel(srgb[2]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::image;
+    use super::*;
+
+    #[test]
+    fn test_weighted_centroid() {
+        // One cluster: the centroid is pulled toward the heavier color
+        let pixels = image(&[(255, 255, 255, 9), (0, 0, 0, 1)]);
+        let clusters = quantize_kmeans(&pixels, 1);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].population, 10);
+        let [r, g, b] = clusters[0].color.to_srgb8();
+        assert!(r == g && g == b && r > 200, "{r} {g} {b}");
+    }
+
+    #[test]
+    fn test_groups_perceptually_close_colors() {
+        let pixels = image(&[
+            (200, 30, 30, 10),
+            (210, 40, 35, 10),
+            (30, 30, 200, 10),
+            (35, 45, 210, 10),
+        ]);
+        let clusters = quantize_kmeans(&pixels, 2);
+        assert_eq!(clusters.len(), 2);
+        assert!(clusters.iter().all(|c| c.population == 20));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let pixels = image(&[
+            (10, 200, 90, 3),
+            (240, 180, 20, 5),
+            (60, 60, 60, 2),
+            (90, 20, 150, 4),
+        ]);
+        assert_eq!(quantize_kmeans(&pixels, 3), quantize_kmeans(&pixels, 3));
+    }
+}
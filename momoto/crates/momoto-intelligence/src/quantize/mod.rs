@@ -0,0 +1,197 @@
+// =============================================================================
+// momoto-intelligence: Image Color Quantization
+// File: crates/momoto-intelligence/src/quantize/mod.rs
+//
+// Reduces an RGBA pixel buffer to a small set of representative colors and
+// ranks them as theme seeds.
+//
+// Scientific references:
+//   Wu, X. (1991). Efficient Statistical Computations for Optimal Color
+//     Quantization. Graphics Gems II, 126–133.
+//   Gervautz, M. & Purgathofer, W. (1988). A Simple Method for Color
+//     Quantization: Octree Quantization. New Trends in Computer Graphics.
+//   Celebi, M. E. (2011). Improving the performance of k-means for color
+//     quantization. Image and Vision Computing 29(4).
+//
+// Pixels are `[r, g, b, a]` bytes. Only fully opaque pixels are counted, so
+// transparent padding and anti-aliased edges don't dilute the result.
+// =============================================================================
+
+mod kmeans;
+mod octree;
+mod score;
+mod wu;
+
+pub use kmeans::quantize_kmeans;
+pub use octree::quantize_octree;
+pub use score::{
+    extract_seeds, palette_from_image, scheme_from_image, score_seeds, ScoreOptions, SeedColor,
+};
+pub use wu::quantize_wu;
+
+use std::collections::BTreeMap;
+
+use momoto_core::color::Color;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// A representative color and the number of pixels it stands for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorCluster {
+    /// Representative (mean) color of the cluster.
+    pub color: Color,
+    /// Number of opaque pixels assigned to the cluster.
+    pub population: u32,
+}
+
+/// Quantization algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Quantizer {
+    /// Wu's variance-minimizing box splitting in RGB. Fast and stable.
+    Wu,
+    /// Octree in RGB, merging the least-populated branches first.
+    Octree,
+    /// Weighted k-means in OKLab, seeded with Wu. Slowest, best clusters.
+    #[default]
+    KMeans,
+}
+
+impl Quantizer {
+    /// Parse a quantizer name (`"wu"`, `"octree"`, `"kmeans"`).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "wu" => Some(Self::Wu),
+            "octree" => Some(Self::Octree),
+            "kmeans" => Some(Self::KMeans),
+            _ => None,
+        }
+    }
+
+    /// Quantize `pixels` (RGBA bytes) to at most `max_colors` clusters,
+    /// sorted by descending population.
+    #[must_use]
+    pub fn quantize(self, pixels: &[u8], max_colors: usize) -> Vec<ColorCluster> {
+        match self {
+            Self::Wu => quantize_wu(pixels, max_colors),
+            Self::Octree => quantize_octree(pixels, max_colors),
+            Self::KMeans => quantize_kmeans(pixels, max_colors),
+        }
+    }
+}
+
+// =============================================================================
+// Shared helpers
+// =============================================================================
+
+/// Count opaque pixels by `0xRRGGBB`, in ascending color order.
+fn histogram(pixels: &[u8]) -> Vec<(u32, u32)> {
+    let mut counts: BTreeMap<u32, u32> = BTreeMap::new();
+    for px in pixels.chunks_exact(4) {
+        if px[3] < 255 {
+            continue;
+        }
+        let rgb = (u32::from(px[0]) << 16) | (u32::from(px[1]) << 8) | u32::from(px[2]);
+        *counts.entry(rgb).or_insert(0) += 1;
+    }
+    counts.into_iter().collect()
+}
+
+/// Split `0xRRGGBB` into channels.
+#[inline]
+fn channels(rgb: u32) -> [u8; 3] {
+    [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8]
+}
+
+/// Sort clusters by descending population, then by color for stable output.
+fn sort_clusters(clusters: &mut [ColorCluster]) {
+    clusters.sort_by(|a, b| {
+        b.population
+            .cmp(&a.population)
+            .then_with(|| a.color.to_srgb8().cmp(&b.color.to_srgb8()))
+    });
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// RGBA buffer with `count` opaque pixels of each `(r, g, b, count)`.
+    pub(crate) fn image(colors: &[(u8, u8, u8, usize)]) -> Vec<u8> {
+        colors
+            .iter()
+            .flat_map(|&(r, g, b, n)| std::iter::repeat([r, g, b, 255]).take(n))
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    fn test_histogram_skips_transparent() {
+        let mut pixels = image(&[(255, 0, 0, 3)]);
+        pixels.extend_from_slice(&[0, 0, 255, 128, 0, 255, 0]); // translucent + partial
+        assert_eq!(histogram(&pixels), vec![(0xff0000, 3)]);
+    }
+
+    #[test]
+    fn test_all_quantizers_recover_distinct_colors() {
+        let pixels = image(&[(255, 0, 0, 50), (0, 128, 0, 30), (0, 0, 255, 20)]);
+        for quantizer in [Quantizer::Wu, Quantizer::Octree, Quantizer::KMeans] {
+            let clusters = quantizer.quantize(&pixels, 8);
+            assert_eq!(clusters.len(), 3, "{quantizer:?}");
+            assert_eq!(clusters[0].color.to_srgb8(), [255, 0, 0], "{quantizer:?}");
+            assert_eq!(clusters[0].population, 50);
+            assert_eq!(clusters[1].color.to_srgb8(), [0, 128, 0], "{quantizer:?}");
+            assert_eq!(clusters[2].population, 20);
+        }
+    }
+
+    #[test]
+    fn test_all_quantizers_respect_max_colors() {
+        // 6×6×6 color cube, one pixel each
+        let mut colors = Vec::new();
+        for r in 0..6u8 {
+            for g in 0..6u8 {
+                for b in 0..6u8 {
+                    colors.push((r * 51, g * 51, b * 51, 1));
+                }
+            }
+        }
+        let pixels = image(&colors);
+        for quantizer in [Quantizer::Wu, Quantizer::Octree, Quantizer::KMeans] {
+            let clusters = quantizer.quantize(&pixels, 16);
+            assert!(
+                !clusters.is_empty() && clusters.len() <= 16,
+                "{quantizer:?}: {}",
+                clusters.len()
+            );
+            let total: u32 = clusters.iter().map(|c| c.population).sum();
+            assert_eq!(total, 216, "{quantizer:?}");
+            for pair in clusters.windows(2) {
+                assert!(pair[0].population >= pair[1].population);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quantizer_from_name() {
+        assert_eq!(Quantizer::from_name("wu"), Some(Quantizer::Wu));
+        assert_eq!(Quantizer::from_name("Octree"), Some(Quantizer::Octree));
+        assert_eq!(Quantizer::from_name("k-means"), Some(Quantizer::KMeans));
+        assert_eq!(Quantizer::from_name("median-cut"), None);
+    }
+
+    #[test]
+    fn test_empty_and_transparent_images() {
+        for quantizer in [Quantizer::Wu, Quantizer::Octree, Quantizer::KMeans] {
+            assert!(quantizer.quantize(&[], 8).is_empty());
+            assert!(quantizer.quantize(&[10, 20, 30, 0], 8).is_empty());
+            assert!(quantizer.quantize(&image(&[(1, 2, 3, 4)]), 0).is_empty());
+        }
+    }
+}
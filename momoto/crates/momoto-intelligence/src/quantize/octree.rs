@@ -0,0 +1,186 @@
+// =============================================================================
+// momoto-intelligence: Octree Quantizer
+// File: crates/momoto-intelligence/src/quantize/octree.rs
+//
+// Gervautz & Purgathofer (1988): every color is a path of eight 3-bit steps
+// (one bit of R, G and B per level) through an octree. The tree is then
+// folded from the bottom up — deepest level first, least-populated node
+// first — until no more than `max_colors` leaves remain.
+// =============================================================================
+
+use momoto_core::color::Color;
+
+use super::{channels, histogram, sort_clusters, ColorCluster};
+
+/// Bits per channel, and therefore tree depth.
+const MAX_DEPTH: usize = 8;
+
+/// Quantize with an octree to at most `max_colors` clusters.
+#[must_use]
+pub fn quantize_octree(pixels: &[u8], max_colors: usize) -> Vec<ColorCluster> {
+    let counts = histogram(pixels);
+    if counts.is_empty() || max_colors == 0 {
+        return Vec::new();
+    }
+    let mut tree = Octree::new();
+    for &(rgb, count) in &counts {
+        tree.insert(channels(rgb), u64::from(count));
+    }
+    tree.reduce(max_colors);
+
+    let mut clusters: Vec<ColorCluster> = tree
+        .leaves()
+        .map(|node| {
+            let mean =
+                |k: usize| ((node.sum[k] as f64 / node.count as f64).round()).min(255.0) as u8;
+            ColorCluster {
+                color: Color::from_srgb8(mean(0), mean(1), mean(2)),
+                population: node.count as u32,
+            }
+        })
+        .collect();
+    sort_clusters(&mut clusters);
+    clusters
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Child node indices; 0 means "no child" (the root is never a child).
+    children: [usize; 8],
+    /// Pixels in this subtree.
+    count: u64,
+    /// Channel sums; only meaningful on leaves.
+    sum: [u64; 3],
+    is_leaf: bool,
+}
+
+struct Octree {
+    nodes: Vec<Node>,
+    /// Interior node indices per depth (0..MAX_DEPTH).
+    levels: Vec<Vec<usize>>,
+    leaf_count: usize,
+}
+
+impl Octree {
+    fn new() -> Self {
+        let mut levels = vec![Vec::new(); MAX_DEPTH];
+        levels[0].push(0);
+        Self {
+            nodes: vec![Node::default()],
+            levels,
+            leaf_count: 0,
+        }
+    }
+
+    fn insert(&mut self, rgb: [u8; 3], count: u64) {
+        let mut index = 0;
+        self.nodes[0].count += count;
+        for depth in 0..MAX_DEPTH {
+            let shift = 7 - depth;
+            let octant = (usize::from((rgb[0] >> shift) & 1) << 2)
+                | (usize::from((rgb[1] >> shift) & 1) << 1)
+                | usize::from((rgb[2] >> shift) & 1);
+            let mut child = self.nodes[index].children[octant];
+            if child == 0 {
+                child = self.nodes.len();
+                self.nodes.push(Node::default());
+                self.nodes[index].children[octant] = child;
+                if depth + 1 < MAX_DEPTH {
+                    self.levels[depth + 1].push(child);
+                }
+            }
+            index = child;
+            self.nodes[index].count += count;
+        }
+        let leaf = &mut self.nodes[index];
+        if !leaf.is_leaf {
+            leaf.is_leaf = true;
+            self.leaf_count += 1;
+        }
+        for (sum, &channel) in leaf.sum.iter_mut().zip(&rgb) {
+            *sum += count * u64::from(channel);
+        }
+    }
+
+    /// Merge subtrees into their parents until at most `max_leaves` remain.
+    fn reduce(&mut self, max_leaves: usize) {
+        for depth in (0..MAX_DEPTH).rev() {
+            if self.leaf_count <= max_leaves {
+                return;
+            }
+            // Every child at this depth is a leaf, since deeper levels are
+            // fully merged before we get here.
+            let mut level = std::mem::take(&mut self.levels[depth]);
+            level.sort_by_key(|&i| (self.nodes[i].count, i));
+            for index in level {
+                if self.leaf_count <= max_leaves {
+                    return;
+                }
+                self.merge(index);
+            }
+        }
+    }
+
+    fn merge(&mut self, index: usize) {
+        let children = std::mem::take(&mut self.nodes[index].children);
+        let mut sum = [0u64; 3];
+        let mut merged = 0;
+        for child in children.into_iter().filter(|&c| c != 0) {
+            for (total, part) in sum.iter_mut().zip(self.nodes[child].sum) {
+                *total += part;
+            }
+            merged += 1;
+        }
+        let node = &mut self.nodes[index];
+        node.sum = sum;
+        node.is_leaf = true;
+        self.leaf_count = self.leaf_count + 1 - merged;
+    }
+
+    fn leaves(&self) -> impl Iterator<Item = &Node> + '_ {
+        let mut stack = vec![0];
+        std::iter::from_fn(move || {
+            while let Some(index) = stack.pop() {
+                let node = &self.nodes[index];
+                if node.is_leaf {
+                    return Some(node);
+                }
+                stack.extend(node.children.iter().copied().filter(|&c| c != 0));
+            }
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::image;
+    use super::*;
+
+    #[test]
+    fn test_single_color() {
+        let clusters = quantize_octree(&image(&[(12, 34, 56, 7)]), 4);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].color.to_srgb8(), [12, 34, 56]);
+        assert_eq!(clusters[0].population, 7);
+    }
+
+    #[test]
+    fn test_merges_nearest_branches_first() {
+        // The two near-whites share a deep branch and merge before the
+        // distant black is touched.
+        let pixels = image(&[(255, 255, 255, 10), (254, 254, 254, 10), (0, 0, 0, 1)]);
+        let clusters = quantize_octree(&pixels, 2);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].population, 20);
+        assert_eq!(clusters[1].color.to_srgb8(), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_single_cluster_is_weighted_mean() {
+        let pixels = image(&[(0, 0, 0, 3), (200, 100, 40, 1)]);
+        let clusters = quantize_octree(&pixels, 1);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].color.to_srgb8(), [50, 25, 10]);
+    }
+}
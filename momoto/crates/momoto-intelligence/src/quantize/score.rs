@@ -0,0 +1,312 @@
+// =============================================================================
+// momoto-intelligence: Theme Seed Scoring
+// File: crates/momoto-intelligence/src/quantize/score.rs
+//
+// Ranks quantized clusters as candidate theme seeds. Each cluster's score
+// combines how much of the image lives near its hue (a ±15° window) with
+// how far its HCT chroma is from an ideal of 48; candidates are then picked
+// greedily, widening hue separation as much as the image allows. Port of
+// Score from Google material-color-utilities (Apache 2.0).
+// =============================================================================
+
+use momoto_core::color::Color;
+use momoto_core::space::hct::dynamic::{DynamicScheme, Variant};
+use momoto_core::space::hct::HCT;
+
+use super::{quantize_kmeans, ColorCluster};
+use crate::harmony::{generate_palette, HarmonyType, Palette};
+
+/// Chroma the scorer considers ideal for a theme seed.
+const TARGET_CHROMA: f64 = 48.0;
+const WEIGHT_PROPORTION: f64 = 0.7;
+const WEIGHT_CHROMA_ABOVE: f64 = 0.3;
+const WEIGHT_CHROMA_BELOW: f64 = 0.1;
+/// Clusters below this chroma are too gray to seed a theme.
+const CUTOFF_CHROMA: f64 = 5.0;
+/// Clusters whose hue neighborhood covers at most this share are noise.
+const CUTOFF_EXCITED_PROPORTION: f64 = 0.01;
+
+/// Clusters kept before scoring when extracting seeds from an image.
+const IMAGE_CLUSTERS: usize = 128;
+
+/// A ranked theme seed candidate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeedColor {
+    /// The seed color.
+    pub color: Color,
+    /// The seed in HCT, ready for scheme generation.
+    pub hct: HCT,
+    /// Ranking score; higher is better. Zero for the fallback.
+    pub score: f64,
+    /// Share of the image within ±15° of the seed's hue.
+    pub proportion: f64,
+}
+
+/// Options for [`score_seeds`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreOptions {
+    /// Maximum number of seeds to return.
+    pub desired: usize,
+    /// Seed returned when no cluster qualifies (e.g. grayscale images).
+    pub fallback: Color,
+    /// Drop near-gray clusters and hues covering ≤ 1% of the image.
+    pub filter: bool,
+}
+
+impl Default for ScoreOptions {
+    fn default() -> Self {
+        Self {
+            desired: 4,
+            // Google Blue, as in material-color-utilities
+            fallback: Color::from_srgb8(0x42, 0x85, 0xf4),
+            filter: true,
+        }
+    }
+}
+
+/// Rank `clusters` as theme seeds, best first.
+///
+/// Returns at most `options.desired` seeds, spread as far apart in hue as
+/// possible (90° down to 15°). Never empty: if no cluster qualifies, the
+/// result is the fallback alone.
+///
+/// # Example
+///
+/// ```rust
+/// use momoto_core::color::Color;
+/// use momoto_intelligence::quantize::{score_seeds, ColorCluster, ScoreOptions};
+///
+/// let clusters = [
+///     ColorCluster { color: Color::from_srgb8(0, 0, 0), population: 10 },
+///     ColorCluster { color: Color::from_srgb8(0, 0, 255), population: 1 },
+/// ];
+/// let seeds = score_seeds(&clusters, &ScoreOptions::default());
+/// assert_eq!(seeds[0].color.to_srgb8(), [0, 0, 255]);
+/// ```
+#[must_use]
+pub fn score_seeds(clusters: &[ColorCluster], options: &ScoreOptions) -> Vec<SeedColor> {
+    let hcts: Vec<HCT> = clusters.iter().map(|c| HCT::from_color(&c.color)).collect();
+
+    let mut hue_population = [0.0; 360];
+    let mut population_sum = 0.0;
+    for (cluster, hct) in clusters.iter().zip(&hcts) {
+        let population = f64::from(cluster.population);
+        hue_population[hue_index(hct.hue.floor())] += population;
+        population_sum += population;
+    }
+
+    let mut excited = [0.0; 360];
+    if population_sum > 0.0 {
+        for (hue, &population) in hue_population.iter().enumerate() {
+            let proportion = population / population_sum;
+            for neighbor in hue + 360 - 14..hue + 360 + 16 {
+                excited[neighbor % 360] += proportion;
+            }
+        }
+    }
+
+    let mut scored: Vec<SeedColor> = clusters
+        .iter()
+        .zip(&hcts)
+        .filter_map(|(cluster, &hct)| {
+            let proportion = excited[hue_index(hct.hue.round())];
+            if options.filter
+                && (hct.chroma < CUTOFF_CHROMA || proportion <= CUTOFF_EXCITED_PROPORTION)
+            {
+                return None;
+            }
+            let chroma_weight = if hct.chroma < TARGET_CHROMA {
+                WEIGHT_CHROMA_BELOW
+            } else {
+                WEIGHT_CHROMA_ABOVE
+            };
+            Some(SeedColor {
+                color: cluster.color,
+                hct,
+                score: proportion * 100.0 * WEIGHT_PROPORTION
+                    + (hct.chroma - TARGET_CHROMA) * chroma_weight,
+                proportion,
+            })
+        })
+        .collect();
+    scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    let mut chosen: Vec<SeedColor> = Vec::new();
+    for min_distance in (15..=90).rev().map(f64::from) {
+        chosen.clear();
+        for candidate in &scored {
+            let duplicate = chosen
+                .iter()
+                .any(|seed| hue_distance(candidate.hct.hue, seed.hct.hue) < min_distance);
+            if !duplicate {
+                chosen.push(*candidate);
+            }
+            if chosen.len() >= options.desired {
+                break;
+            }
+        }
+        if chosen.len() >= options.desired {
+            break;
+        }
+    }
+
+    if chosen.is_empty() {
+        chosen.push(SeedColor {
+            color: options.fallback,
+            hct: HCT::from_color(&options.fallback),
+            score: 0.0,
+            proportion: 0.0,
+        });
+    }
+    chosen
+}
+
+/// Extract up to `desired` theme seeds from an RGBA pixel buffer.
+///
+/// Quantizes to 128 clusters with [`quantize_kmeans`], then ranks them with
+/// [`score_seeds`] under the default options.
+#[must_use]
+pub fn extract_seeds(pixels: &[u8], desired: usize) -> Vec<SeedColor> {
+    let clusters = quantize_kmeans(pixels, IMAGE_CLUSTERS);
+    let options = ScoreOptions {
+        desired,
+        ..ScoreOptions::default()
+    };
+    score_seeds(&clusters, &options)
+}
+
+/// Harmony palette seeded by the best color in an RGBA pixel buffer.
+#[must_use]
+pub fn palette_from_image(pixels: &[u8], harmony: HarmonyType) -> Palette {
+    let seed = best_seed(pixels);
+    generate_palette(seed.color.to_oklch(), harmony)
+}
+
+/// Material Design 3 scheme seeded by the best color in an RGBA pixel buffer.
+#[must_use]
+pub fn scheme_from_image(
+    pixels: &[u8],
+    variant: Variant,
+    is_dark: bool,
+    contrast_level: f64,
+) -> DynamicScheme {
+    DynamicScheme::new(best_seed(pixels).hct, variant, is_dark, contrast_level)
+}
+
+fn best_seed(pixels: &[u8]) -> SeedColor {
+    // score_seeds never returns an empty list
+    extract_seeds(pixels, 1)[0]
+}
+
+/// Integer hue bucket in 0..360.
+#[inline]
+fn hue_index(hue: f64) -> usize {
+    (hue as i64).rem_euclid(360) as usize
+}
+
+/// Shortest angular distance between two hues, in degrees.
+#[inline]
+fn hue_distance(a: f64, b: f64) -> f64 {
+    180.0 - ((a - b).abs() - 180.0).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::image;
+    use super::*;
+
+    fn clusters(argbs: &[u32]) -> Vec<ColorCluster> {
+        argbs
+            .iter()
+            .map(|&argb| ColorCluster {
+                color: Color::from_srgb8((argb >> 16) as u8, (argb >> 8) as u8, argb as u8),
+                population: 1,
+            })
+            .collect()
+    }
+
+    fn argbs(seeds: &[SeedColor]) -> Vec<u32> {
+        seeds.iter().map(|s| s.hct.to_argb()).collect()
+    }
+
+    // Cases from material-color-utilities score_test
+
+    #[test]
+    fn test_prioritizes_chroma() {
+        let input = clusters(&[0xff000000, 0xffffffff, 0xff0000ff]);
+        let seeds = score_seeds(&input, &ScoreOptions::default());
+        assert_eq!(argbs(&seeds), vec![0xff0000ff]);
+    }
+
+    #[test]
+    fn test_prioritizes_chroma_when_proportions_equal() {
+        let input = clusters(&[0xffff0000, 0xff00ff00, 0xff0000ff]);
+        let seeds = score_seeds(&input, &ScoreOptions::default());
+        assert_eq!(argbs(&seeds), vec![0xffff0000, 0xff00ff00, 0xff0000ff]);
+    }
+
+    #[test]
+    fn test_fallback_when_no_colors_qualify() {
+        let seeds = score_seeds(&clusters(&[0xff000000]), &ScoreOptions::default());
+        assert_eq!(argbs(&seeds), vec![0xff4285f4]);
+        assert_eq!(seeds[0].score, 0.0);
+    }
+
+    #[test]
+    fn test_dedupes_nearby_hues() {
+        let seeds = score_seeds(
+            &clusters(&[0xff008772, 0xff318477]),
+            &ScoreOptions::default(),
+        );
+        assert_eq!(argbs(&seeds), vec![0xff008772]);
+    }
+
+    #[test]
+    fn test_maximizes_hue_distance() {
+        let options = ScoreOptions {
+            desired: 2,
+            ..ScoreOptions::default()
+        };
+        let seeds = score_seeds(&clusters(&[0xff008772, 0xff008587, 0xff007ebc]), &options);
+        assert_eq!(argbs(&seeds), vec![0xff007ebc, 0xff008772]);
+    }
+
+    #[test]
+    fn test_unfiltered_keeps_grays() {
+        let options = ScoreOptions {
+            filter: false,
+            ..ScoreOptions::default()
+        };
+        let seeds = score_seeds(&clusters(&[0xff000000]), &options);
+        assert_eq!(argbs(&seeds), vec![0xff000000]);
+    }
+
+    #[test]
+    fn test_extract_seeds_from_image() {
+        // Mostly gray with a dominant orange and a small teal accent
+        let pixels = image(&[
+            (128, 128, 128, 400),
+            (230, 120, 20, 300),
+            (225, 125, 25, 100),
+            (0, 140, 140, 50),
+        ]);
+        let seeds = extract_seeds(&pixels, 2);
+        assert_eq!(seeds.len(), 2);
+        let [r, g, b] = seeds[0].color.to_srgb8();
+        assert!(r > 200 && g > 100 && b < 40, "{r} {g} {b}");
+        assert!(seeds[1].hct.hue > 150.0 && seeds[1].hct.hue < 220.0);
+        assert!(seeds[0].score > seeds[1].score);
+    }
+
+    #[test]
+    fn test_palette_and_scheme_from_image() {
+        let pixels = image(&[(0, 0, 255, 10), (240, 240, 240, 30)]);
+        let palette = palette_from_image(&pixels, HarmonyType::Complementary);
+        assert_eq!(palette.colors.len(), 2);
+        let blue = Color::from_srgb8(0, 0, 255).to_oklch();
+        assert!((palette.colors[0].h - blue.h).abs() < 1.0);
+
+        let scheme = scheme_from_image(&pixels, Variant::TonalSpot, false, 0.0);
+        assert_eq!(scheme.source_color_argb, 0xff0000ff);
+    }
+}
@@ -0,0 +1,356 @@
+// =============================================================================
+// momoto-intelligence: Wu Quantizer
+// File: crates/momoto-intelligence/src/quantize/wu.rs
+//
+// Wu (1991): build cumulative color moments over a 32×32×32 RGB grid, then
+// repeatedly split the box with the largest variance at the plane that
+// maximizes the between-class variance. Port of QuantizerWu from Google
+// material-color-utilities (Apache 2.0), with cluster populations kept.
+// =============================================================================
+
+use momoto_core::color::Color;
+
+use super::{channels, histogram, sort_clusters, ColorCluster};
+
+/// Bits kept per channel.
+const INDEX_BITS: u32 = 5;
+/// Grid side including the zero border used by the cumulative sums.
+const SIDE_LENGTH: usize = (1 << INDEX_BITS) + 1;
+const TOTAL_SIZE: usize = SIDE_LENGTH * SIDE_LENGTH * SIDE_LENGTH;
+
+/// Quantize with Wu's algorithm to at most `max_colors` clusters.
+///
+/// Cluster colors are the mean of their pixels, so they may be colors that
+/// never appear in the image.
+#[must_use]
+pub fn quantize_wu(pixels: &[u8], max_colors: usize) -> Vec<ColorCluster> {
+    let counts = histogram(pixels);
+    if counts.is_empty() || max_colors == 0 {
+        return Vec::new();
+    }
+    let mut moments = Moments::from_histogram(&counts);
+    moments.accumulate();
+
+    let boxes = moments.create_boxes(max_colors);
+    let mut clusters: Vec<ColorCluster> = boxes
+        .iter()
+        .filter_map(|cube| {
+            let weight = moments.volume(cube, &moments.weights);
+            if weight <= 0.0 {
+                return None;
+            }
+            let mean =
+                |m: &[f64]| (moments.volume(cube, m) / weight).round().clamp(0.0, 255.0) as u8;
+            Some(ColorCluster {
+                color: Color::from_srgb8(mean(&moments.r), mean(&moments.g), mean(&moments.b)),
+                population: weight.round() as u32,
+            })
+        })
+        .collect();
+    sort_clusters(&mut clusters);
+    clusters
+}
+
+/// A box in grid coordinates; the lower bounds are exclusive.
+#[derive(Debug, Clone, Copy, Default)]
+struct Cube {
+    r0: usize,
+    r1: usize,
+    g0: usize,
+    g1: usize,
+    b0: usize,
+    b1: usize,
+    vol: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Red,
+    Green,
+    Blue,
+}
+
+/// Per-cell (then cumulative) pixel count, channel sums and sum of squares.
+struct Moments {
+    weights: Vec<f64>,
+    r: Vec<f64>,
+    g: Vec<f64>,
+    b: Vec<f64>,
+    squares: Vec<f64>,
+}
+
+#[inline]
+fn index(r: usize, g: usize, b: usize) -> usize {
+    (r * SIDE_LENGTH + g) * SIDE_LENGTH + b
+}
+
+impl Moments {
+    fn from_histogram(counts: &[(u32, u32)]) -> Self {
+        let mut m = Self {
+            weights: vec![0.0; TOTAL_SIZE],
+            r: vec![0.0; TOTAL_SIZE],
+            g: vec![0.0; TOTAL_SIZE],
+            b: vec![0.0; TOTAL_SIZE],
+            squares: vec![0.0; TOTAL_SIZE],
+        };
+        let shift = 8 - INDEX_BITS;
+        for &(rgb, count) in counts {
+            let [red, green, blue] = channels(rgb);
+            let i = index(
+                (red >> shift) as usize + 1,
+                (green >> shift) as usize + 1,
+                (blue >> shift) as usize + 1,
+            );
+            let n = f64::from(count);
+            let (red, green, blue) = (f64::from(red), f64::from(green), f64::from(blue));
+            m.weights[i] += n;
+            m.r[i] += n * red;
+            m.g[i] += n * green;
+            m.b[i] += n * blue;
+            m.squares[i] += n * (red * red + green * green + blue * blue);
+        }
+        m
+    }
+
+    /// Turn per-cell moments into 3-D prefix sums.
+    fn accumulate(&mut self) {
+        for r in 1..SIDE_LENGTH {
+            let mut area = [[0.0; 5]; SIDE_LENGTH];
+            for g in 1..SIDE_LENGTH {
+                let mut line = [0.0; 5];
+                for (b, area) in area.iter_mut().enumerate().skip(1) {
+                    let i = index(r, g, b);
+                    let prev = index(r - 1, g, b);
+                    for (k, table) in self.tables_mut().into_iter().enumerate() {
+                        line[k] += table[i];
+                        area[k] += line[k];
+                        table[i] = table[prev] + area[k];
+                    }
+                }
+            }
+        }
+    }
+
+    fn tables_mut(&mut self) -> [&mut Vec<f64>; 5] {
+        [
+            &mut self.weights,
+            &mut self.r,
+            &mut self.g,
+            &mut self.b,
+            &mut self.squares,
+        ]
+    }
+
+    fn create_boxes(&self, max_colors: usize) -> Vec<Cube> {
+        let mut cubes = vec![Cube::default(); max_colors];
+        let mut variances = vec![0.0; max_colors];
+        cubes[0] = Cube {
+            r1: SIDE_LENGTH - 1,
+            g1: SIDE_LENGTH - 1,
+            b1: SIDE_LENGTH - 1,
+            ..Cube::default()
+        };
+        let mut generated = max_colors;
+        let mut next = 0;
+        let mut i = 1;
+        while i < max_colors {
+            let (one, two) = (cubes[next], cubes[i]);
+            if let Some((one, two)) = self.cut(one, two) {
+                cubes[next] = one;
+                cubes[i] = two;
+                variances[next] = if one.vol > 1 {
+                    self.variance(&one)
+                } else {
+                    0.0
+                };
+                variances[i] = if two.vol > 1 {
+                    self.variance(&two)
+                } else {
+                    0.0
+                };
+            } else {
+                variances[next] = 0.0;
+                i -= 1;
+            }
+
+            next = 0;
+            let mut temp = variances[0];
+            for (j, &v) in variances.iter().enumerate().take(i + 1).skip(1) {
+                if v > temp {
+                    temp = v;
+                    next = j;
+                }
+            }
+            if temp <= 0.0 {
+                generated = i + 1;
+                break;
+            }
+            i += 1;
+        }
+        cubes.truncate(generated);
+        cubes
+    }
+
+    fn variance(&self, cube: &Cube) -> f64 {
+        let dr = self.volume(cube, &self.r);
+        let dg = self.volume(cube, &self.g);
+        let db = self.volume(cube, &self.b);
+        let xx = self.volume(cube, &self.squares);
+        let hypotenuse = dr * dr + dg * dg + db * db;
+        xx - hypotenuse / self.volume(cube, &self.weights)
+    }
+
+    /// Split `one` in two along the best axis; `None` if it can't be split.
+    fn cut(&self, mut one: Cube, mut two: Cube) -> Option<(Cube, Cube)> {
+        let whole = [
+            self.volume(&one, &self.r),
+            self.volume(&one, &self.g),
+            self.volume(&one, &self.b),
+            self.volume(&one, &self.weights),
+        ];
+        let (cut_r, max_r) = self.maximize(&one, Direction::Red, one.r0 + 1, one.r1, whole);
+        let (cut_g, max_g) = self.maximize(&one, Direction::Green, one.g0 + 1, one.g1, whole);
+        let (cut_b, max_b) = self.maximize(&one, Direction::Blue, one.b0 + 1, one.b1, whole);
+
+        let direction = if max_r >= max_g && max_r >= max_b {
+            cut_r?;
+            Direction::Red
+        } else if max_g >= max_r && max_g >= max_b {
+            Direction::Green
+        } else {
+            Direction::Blue
+        };
+
+        two.r1 = one.r1;
+        two.g1 = one.g1;
+        two.b1 = one.b1;
+        match direction {
+            Direction::Red => {
+                one.r1 = cut_r?;
+                two.r0 = one.r1;
+                two.g0 = one.g0;
+                two.b0 = one.b0;
+            }
+            Direction::Green => {
+                one.g1 = cut_g?;
+                two.r0 = one.r0;
+                two.g0 = one.g1;
+                two.b0 = one.b0;
+            }
+            Direction::Blue => {
+                one.b1 = cut_b?;
+                two.r0 = one.r0;
+                two.g0 = one.g0;
+                two.b0 = one.b1;
+            }
+        }
+        one.vol = (one.r1 - one.r0) * (one.g1 - one.g0) * (one.b1 - one.b0);
+        two.vol = (two.r1 - two.r0) * (two.g1 - two.g0) * (two.b1 - two.b0);
+        Some((one, two))
+    }
+
+    /// Best cut position along `direction` in `[first, last)` and its score.
+    fn maximize(
+        &self,
+        cube: &Cube,
+        direction: Direction,
+        first: usize,
+        last: usize,
+        whole: [f64; 4],
+    ) -> (Option<usize>, f64) {
+        let tables = [&self.r, &self.g, &self.b, &self.weights];
+        let bottom = tables.map(|t| self.bottom(cube, direction, t));
+        let mut max = 0.0;
+        let mut cut = None;
+        for i in first..last {
+            let half: [f64; 4] =
+                std::array::from_fn(|k| bottom[k] + self.top(cube, direction, i, tables[k]));
+            if half[3] == 0.0 {
+                continue;
+            }
+            let mut temp = (half[0] * half[0] + half[1] * half[1] + half[2] * half[2]) / half[3];
+            let rest: [f64; 4] = std::array::from_fn(|k| whole[k] - half[k]);
+            if rest[3] == 0.0 {
+                continue;
+            }
+            temp += (rest[0] * rest[0] + rest[1] * rest[1] + rest[2] * rest[2]) / rest[3];
+            if temp > max {
+                max = temp;
+                cut = Some(i);
+            }
+        }
+        (cut, max)
+    }
+
+    fn volume(&self, c: &Cube, m: &[f64]) -> f64 {
+        m[index(c.r1, c.g1, c.b1)] - m[index(c.r1, c.g1, c.b0)] - m[index(c.r1, c.g0, c.b1)]
+            + m[index(c.r1, c.g0, c.b0)]
+            - m[index(c.r0, c.g1, c.b1)]
+            + m[index(c.r0, c.g1, c.b0)]
+            + m[index(c.r0, c.g0, c.b1)]
+            - m[index(c.r0, c.g0, c.b0)]
+    }
+
+    fn bottom(&self, c: &Cube, direction: Direction, m: &[f64]) -> f64 {
+        match direction {
+            Direction::Red => {
+                -m[index(c.r0, c.g1, c.b1)]
+                    + m[index(c.r0, c.g1, c.b0)]
+                    + m[index(c.r0, c.g0, c.b1)]
+                    - m[index(c.r0, c.g0, c.b0)]
+            }
+            Direction::Green => {
+                -m[index(c.r1, c.g0, c.b1)]
+                    + m[index(c.r1, c.g0, c.b0)]
+                    + m[index(c.r0, c.g0, c.b1)]
+                    - m[index(c.r0, c.g0, c.b0)]
+            }
+            Direction::Blue => {
+                -m[index(c.r1, c.g1, c.b0)]
+                    + m[index(c.r1, c.g0, c.b0)]
+                    + m[index(c.r0, c.g1, c.b0)]
+                    - m[index(c.r0, c.g0, c.b0)]
+            }
+        }
+    }
+
+    fn top(&self, c: &Cube, direction: Direction, pos: usize, m: &[f64]) -> f64 {
+        match direction {
+            Direction::Red => {
+                m[index(pos, c.g1, c.b1)] - m[index(pos, c.g1, c.b0)] - m[index(pos, c.g0, c.b1)]
+                    + m[index(pos, c.g0, c.b0)]
+            }
+            Direction::Green => {
+                m[index(c.r1, pos, c.b1)] - m[index(c.r1, pos, c.b0)] - m[index(c.r0, pos, c.b1)]
+                    + m[index(c.r0, pos, c.b0)]
+            }
+            Direction::Blue => {
+                m[index(c.r1, c.g1, pos)] - m[index(c.r1, c.g0, pos)] - m[index(c.r0, c.g1, pos)]
+                    + m[index(c.r0, c.g0, pos)]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::image;
+    use super::*;
+
+    #[test]
+    fn test_single_color() {
+        let clusters = quantize_wu(&image(&[(255, 0, 0, 1)]), 128);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].color.to_srgb8(), [255, 0, 0]);
+    }
+
+    #[test]
+    fn test_similar_colors_merge_when_budget_is_small() {
+        let pixels = image(&[(250, 10, 10, 10), (245, 5, 15, 10), (10, 10, 250, 5)]);
+        let clusters = quantize_wu(&pixels, 2);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].population, 20);
+        let [r, _, b] = clusters[0].color.to_srgb8();
+        assert!(r > 240 && b < 20);
+    }
+}
@@ -26,9 +26,14 @@
 //! let config = CssRenderConfig::premium();
 //! let css = EnhancedCssBackend::render(&evaluated, &config);
 //! ```
+//!
+//! For wide-gamut targets, [`EnhancedCssBackend::render_with_context`] emits
+//! a progressive stylesheet with sRGB fallbacks and `@supports` cascades.
 
+use momoto_core::backend::css_cascade::CssCascade;
 use momoto_core::backend::css_config::CssRenderConfig;
 use momoto_core::evaluated::{EvaluatedMaterial, LinearRgba};
+use momoto_core::render::RenderContext;
 use momoto_core::Color;
 
 use crate::glass_physics::blinn_phong::{
@@ -62,9 +67,59 @@ impl EnhancedCssBackend {
 
         // 2. Enhanced backdrop-filter
         if let Some(filter) = Self::generate_backdrop_filter(material, config) {
-            css_parts.push(filter);
+            css_parts.push(format!("backdrop-filter: {};", filter));
+        }
+
+        // 3-6. Shadows, border, radius, opacity
+        css_parts.extend(Self::generate_surface_declarations(material, config));
+
+        css_parts.join("\n")
+    }
+
+    /// Render material for a render context
+    ///
+    /// For Display P3 and Rec.2020 contexts, returns a progressive stylesheet
+    /// scoped to `selector` (see [`CssCascade`]): the base color layer of the
+    /// `background` is re-emitted as an sRGB hex fallback, then as wide-gamut
+    /// colors behind `@supports` / `@media (color-gamut)`, and browsers without
+    /// `backdrop-filter` get a more opaque tint. Other contexts get the flat
+    /// [`render`](Self::render) output.
+    pub fn render_with_context(
+        material: &EvaluatedMaterial,
+        config: &CssRenderConfig,
+        context: &RenderContext,
+        selector: &str,
+    ) -> String {
+        if !CssCascade::is_wide_gamut(context.color_space) {
+            return Self::render(material, config);
+        }
+
+        // Layers above the base color are gamut-independent
+        let mut layers = Self::generate_background_layers(material, config);
+        layers.pop();
+
+        let mut cascade = CssCascade::new(selector, context.color_space);
+        if let Some(filter) = Self::generate_backdrop_filter(material, config) {
+            cascade = cascade.backdrop_filter(filter);
+        }
+        for declaration in Self::generate_surface_declarations(material, config) {
+            cascade = cascade.declaration(declaration);
         }
 
+        cascade.render(&material.base_color, material.opacity, |color| {
+            let mut background = layers.clone();
+            background.push(color.to_string());
+            format!("background: {};", background.join(", "))
+        })
+    }
+
+    /// Generate the shadow, border, radius and opacity declarations
+    fn generate_surface_declarations(
+        material: &EvaluatedMaterial,
+        config: &CssRenderConfig,
+    ) -> Vec<String> {
+        let mut css_parts = Vec::new();
+
         // 3. Box-shadow layers
         let shadows = Self::generate_box_shadows(config);
         if !shadows.is_empty() {
@@ -87,7 +142,7 @@ impl EnhancedCssBackend {
         // 6. Opacity
         css_parts.push(format!("opacity: {:.2};", material.opacity));
 
-        css_parts.join("\n")
+        css_parts
     }
 
     /// Generate background layers as CSS gradients
@@ -156,7 +211,7 @@ impl EnhancedCssBackend {
         layers
     }
 
-    /// Generate backdrop-filter CSS
+    /// Generate backdrop-filter CSS value
     fn generate_backdrop_filter(
         material: &EvaluatedMaterial,
         config: &CssRenderConfig,
@@ -172,11 +227,11 @@ impl EnhancedCssBackend {
 
         if config.saturate && config.saturation_factor > 1.0 {
             Some(format!(
-                "backdrop-filter: blur({:.0}px) saturate({:.1});",
+                "blur({:.0}px) saturate({:.1})",
                 blur_px, config.saturation_factor
             ))
         } else {
-            Some(format!("backdrop-filter: blur({:.0}px);", blur_px))
+            Some(format!("blur({:.0}px)", blur_px))
         }
     }
 
@@ -428,6 +483,40 @@ mod tests {
         assert!(css.contains("border-radius:"), "Should have border-radius");
     }
 
+    #[test]
+    fn test_render_with_context_display_p3() {
+        use momoto_core::render::ColorSpace;
+
+        let material = create_test_material();
+        let config = CssRenderConfig::premium();
+        let ctx = RenderContext {
+            color_space: ColorSpace::DisplayP3,
+            ..RenderContext::desktop()
+        };
+
+        let css = EnhancedCssBackend::render_with_context(&material, &config, &ctx, ".panel");
+
+        assert!(css.starts_with(".panel {\n  background: radial-gradient"));
+        assert!(css.contains("-webkit-backdrop-filter: blur(20px) saturate("));
+        assert!(css.contains("@supports not ((backdrop-filter"));
+        assert!(css.contains("@media (color-gamut: p3)"));
+        // Every tier keeps the highlight layers above the base color
+        let tiers = css.matches("background: radial-gradient").count();
+        assert_eq!(tiers, 4);
+        assert_eq!(css.matches("box-shadow:").count(), 1);
+
+        // sRGB contexts keep the flat output
+        assert_eq!(
+            EnhancedCssBackend::render_with_context(
+                &material,
+                &config,
+                &RenderContext::desktop(),
+                ".panel"
+            ),
+            EnhancedCssBackend::render(&material, &config)
+        );
+    }
+
     #[test]
     fn test_dark_mode() {
         let material = create_test_material();
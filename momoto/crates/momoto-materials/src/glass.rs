@@ -18,6 +18,7 @@
 //! - WWDC25 Video: "Meet Liquid Glass"
 //! - Apple HIG: Materials
 
+use momoto_core::color::background::{Background, BackgroundError, Region};
 use momoto_core::color::Color;
 use momoto_core::space::oklch::OKLCH;
 
@@ -181,6 +182,45 @@ impl LiquidGlass {
         }
     }
 
+    /// Recommend text color for glass over a gradient or image
+    ///
+    /// Like [`recommend_text_color`](Self::recommend_text_color), but the
+    /// candidate must pass over every sample of `background` inside
+    /// `region`, not just one color. Falls back on the area-weighted mean
+    /// lightness of the glass when neither candidate passes everywhere.
+    pub fn recommend_text_color_over(
+        &self,
+        background: &Background<'_>,
+        region: &Region,
+        prefer_white: bool,
+    ) -> Result<Color, BackgroundError> {
+        let samples = background.samples(region)?;
+        let effective: Vec<(OKLCH, f64)> = samples
+            .iter()
+            .map(|s| (self.effective_color(s.color).to_oklch(), s.weight()))
+            .collect();
+        let passes_everywhere = |text: OKLCH| {
+            effective
+                .iter()
+                .all(|(bg, _)| self.validate_text_contrast(text, *bg))
+        };
+
+        let white = OKLCH::new(1.0, 0.0, 0.0);
+        let dark = OKLCH::new(0.15, 0.01, 240.0);
+        let candidates = if prefer_white {
+            [white, dark]
+        } else {
+            [dark, white]
+        };
+        if let Some(text) = candidates.into_iter().find(|&c| passes_everywhere(c)) {
+            return Ok(text.to_color());
+        }
+
+        let total: f64 = effective.iter().map(|(_, w)| w).sum();
+        let mean_l = effective.iter().map(|(c, w)| c.l * w).sum::<f64>() / total;
+        Ok(if mean_l > 0.5 { dark } else { white }.to_color())
+    }
+
     /// Validate text contrast against effective glass color
     fn validate_text_contrast(&self, text: OKLCH, background: OKLCH) -> bool {
         // Simplified contrast check (can integrate momoto-metrics for full WCAG/APCA)
@@ -345,6 +385,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_text_recommendation_over_gradient() {
+        use momoto_core::color::background::{Gradient, GradientStop};
+
+        let glass = LiquidGlass::new(GlassVariant::Clear);
+        let gradient = Gradient::linear(
+            90.0,
+            vec![
+                GradientStop::from_color(Color::from_srgb8(0, 0, 0), None),
+                GradientStop::from_color(Color::from_srgb8(255, 255, 255), None),
+            ],
+        )
+        .unwrap();
+        let background = Background::Gradient {
+            gradient,
+            width: 100.0,
+            height: 20.0,
+        };
+
+        // Dark text passes over the whole light glass, even where the
+        // backdrop is black.
+        let text = glass
+            .recommend_text_color_over(&background, &Region::new(0.0, 0.0, 100.0, 20.0), true)
+            .unwrap();
+        assert!(text.to_oklch().l < 0.5);
+
+        assert_eq!(
+            glass.recommend_text_color_over(
+                &background,
+                &Region::new(200.0, 0.0, 10.0, 10.0),
+                true
+            ),
+            Err(BackgroundError::EmptyRegion)
+        );
+    }
+
     #[test]
     fn test_dark_mode_adaptation() {
         let mut glass = LiquidGlass::new(GlassVariant::Regular);
@@ -29,6 +29,8 @@ use serde::{Deserialize, Serialize};
 
 use super::unified_bsdf::{BSDF, BSDFContext, BSDFResponse, BSDFSample, Vector3};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // Color Type for Material Responses
 // ============================================================================
@@ -162,8 +164,8 @@ impl AnisotropicBSDF {
             return (ctx.tangent, ctx.bitangent);
         }
 
-        let cos_r = self.rotation.cos();
-        let sin_r = self.rotation.sin();
+        let cos_r = ops::cos(self.rotation);
+        let sin_r = ops::sin(self.rotation);
 
         let new_tangent = Vector3::new(
             ctx.tangent.x * cos_r - ctx.bitangent.x * sin_r,
@@ -283,10 +285,10 @@ impl BSDF for AnisotropicBSDF {
         let ay = self.alpha_y;
 
         let aspect = ay / ax;
-        let phi_h = (aspect * phi.tan()).atan() + if phi > PI / 2.0 { PI } else { 0.0 };
+        let phi_h = ops::atan(aspect * ops::tan(phi)) + if phi > PI / 2.0 { PI } else { 0.0 };
 
-        let cos_phi = phi_h.cos();
-        let sin_phi = phi_h.sin();
+        let cos_phi = ops::cos(phi_h);
+        let sin_phi = ops::sin(phi_h);
         let alpha_h = 1.0 / ((cos_phi / ax).powi(2) + (sin_phi / ay).powi(2)).sqrt();
 
         let cos_theta_h = ((1.0 - u2) / (u2 * (alpha_h * alpha_h - 1.0) + 1.0)).sqrt();
@@ -384,7 +386,7 @@ impl AshikhminShirleyBSDF {
 
         let norm = ((self.nu + 1.0) * (self.nv + 1.0)).sqrt() / (8.0 * PI);
 
-        norm * h_dot_n.powf(exponent)
+        norm * ops::powf(h_dot_n, exponent)
     }
 }
 
@@ -429,8 +431,8 @@ impl BSDF for AshikhminShirleyBSDF {
         let sin_theta = (u2).sqrt();
 
         let wo = Vector3::new(
-            sin_theta * phi.cos(),
-            sin_theta * phi.sin(),
+            sin_theta * ops::cos(phi),
+            sin_theta * ops::sin(phi),
             cos_theta,
         );
 
@@ -530,7 +532,7 @@ impl HairBSDF {
         let theta_h = (theta_r + theta_i) / 2.0;
 
         let gaussian = |x: f64, sigma: f64| {
-            (-x * x / (2.0 * sigma * sigma)).exp() / (sigma * (2.0 * PI).sqrt())
+            ops::exp(-x * x / (2.0 * sigma * sigma)) / (sigma * (2.0 * PI).sqrt())
         };
 
         let v_scaled = v * self.roughness;
@@ -541,7 +543,7 @@ impl HairBSDF {
     fn n_term(&self, phi: f64, eta: f64, h: f64) -> f64 {
         // Simplified azimuthal distribution
         let gamma = phi / 2.0;
-        let cos_gamma = gamma.cos();
+        let cos_gamma = ops::cos(gamma);
         let fresnel = 0.04 + 0.96 * (1.0 - cos_gamma).powi(5);
 
         (1.0 - fresnel) * cos_gamma.abs().max(0.01)
@@ -557,8 +559,8 @@ impl BSDF for HairBSDF {
         let sin_theta_i = ctx.wi.dot(hair_dir);
         let sin_theta_o = ctx.wo.dot(hair_dir);
 
-        let theta_i = sin_theta_i.asin();
-        let theta_o = sin_theta_o.asin();
+        let theta_i = ops::asin(sin_theta_i);
+        let theta_o = ops::asin(sin_theta_o);
 
         // R lobe (primary specular)
         let eta = 1.55; // IOR of keratin
@@ -602,8 +604,8 @@ impl BSDF for HairBSDF {
         let v = 0.726 * self.roughness + 0.812 * self.roughness * self.roughness + 3.7e-3;
         let theta = v * (2.0 * u2 - 1.0);
 
-        let sin_theta = theta.sin();
-        let cos_theta = theta.cos();
+        let sin_theta = ops::sin(theta);
+        let cos_theta = ops::cos(theta);
 
         // Create sampled direction
         let reflected = ctx.wo.reflect(&ctx.normal);
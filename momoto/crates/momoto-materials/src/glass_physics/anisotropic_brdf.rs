@@ -28,6 +28,8 @@ use super::unified_bsdf::{BSDF, BSDFContext, BSDFResponse, BSDFSample, Vector3};
 use super::fresnel::fresnel_schlick;
 use super::complex_ior::{ComplexIOR, fresnel_conductor_unpolarized};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // ANISOTROPIC GGX MODEL
 // ============================================================================
@@ -131,9 +133,9 @@ impl AnisotropicGGX {
         let tan2_theta = (1.0 - cos_theta * cos_theta) / (cos_theta * cos_theta);
 
         // Anisotropic alpha computation
-        let phi = w_local.y.atan2(w_local.x);
-        let cos_phi = phi.cos();
-        let sin_phi = phi.sin();
+        let phi = ops::atan2(w_local.y, w_local.x);
+        let cos_phi = ops::cos(phi);
+        let sin_phi = ops::sin(phi);
         let alpha2 = (self.alpha_x * cos_phi).powi(2) + (self.alpha_y * sin_phi).powi(2);
 
         let a2_tan2 = alpha2 * tan2_theta;
@@ -174,8 +176,8 @@ impl AnisotropicGGX {
             PI + (u2 - a) / (1.0 - a) * PI
         };
 
-        let p1 = r * phi.cos();
-        let p2 = r * phi.sin() * if u2 < a { 1.0 } else { wi_stretched.z };
+        let p1 = r * ops::cos(phi);
+        let p2 = r * ops::sin(phi) * if u2 < a { 1.0 } else { wi_stretched.z };
 
         // Compute normal in stretched space
         let n_stretched = t1 * p1 + t2 * p2 + wi_stretched * (1.0 - p1 * p1 - p2 * p2).max(0.0).sqrt();
@@ -347,8 +349,8 @@ impl AnisotropicConductor {
     ) -> Self {
         // Rotate roughness based on brush angle
         let angle = angle_deg.to_radians();
-        let c = angle.cos();
-        let s = angle.sin();
+        let c = ops::cos(angle);
+        let s = ops::sin(angle);
 
         let alpha_x = roughness_along * c * c + roughness_across * s * s;
         let alpha_y = roughness_along * s * s + roughness_across * c * c;
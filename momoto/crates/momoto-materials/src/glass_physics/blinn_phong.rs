@@ -48,6 +48,8 @@
 
 use super::light_model::Vec3;
 
+use momoto_core::math::ops;
+
 /// Calculate Blinn-Phong specular intensity
 ///
 /// This is the **core specular calculation** used for all glass highlights.
@@ -107,7 +109,7 @@ pub fn blinn_phong_specular(normal: Vec3, light_dir: Vec3, view_dir: Vec3, shini
 
     // Apply shininess power
     if n_dot_h > 0.0 {
-        n_dot_h.powf(shininess)
+        ops::powf(n_dot_h, shininess)
     } else {
         0.0
     }
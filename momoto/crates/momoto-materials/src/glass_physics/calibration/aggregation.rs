@@ -4,6 +4,8 @@
 
 use super::sources::{BRDFObservation, SpectralObservation, TemporalObservation};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // LOSS WEIGHTS
 // ============================================================================
@@ -228,7 +230,7 @@ impl AggregatedLoss {
     fn compute_quality_score(total: f64, components: &LossComponents) -> f64 {
         // Map loss to 0-100 score using exponential decay
         // Lower loss = higher score
-        let loss_factor = (-total * 10.0).exp();
+        let loss_factor = ops::exp(-total * 10.0);
 
         // Bonus for good perceptual fit (ΔE < 2.0 is "imperceptible")
         let perceptual_bonus = if components.perceptual < 2.0 {
@@ -7,6 +7,8 @@
 // ============================================================================
 
 /// Metadata about a calibration data source.
+use momoto_core::math::ops;
+
 #[derive(Debug, Clone)]
 pub struct SourceMetadata {
     /// Source name/identifier.
@@ -134,12 +136,12 @@ impl BRDFObservation {
 
     /// Get cosine of incident angle.
     pub fn cos_theta_i(&self) -> f64 {
-        self.theta_i.cos()
+        ops::cos(self.theta_i)
     }
 
     /// Get cosine of outgoing angle.
     pub fn cos_theta_o(&self) -> f64 {
-        self.theta_o.cos()
+        ops::cos(self.theta_o)
     }
 
     /// Check if this is a specular configuration (theta_i = theta_o, phi_o = phi_i + PI).
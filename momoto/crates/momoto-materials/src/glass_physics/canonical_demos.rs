@@ -29,6 +29,8 @@ use super::scattering::henyey_greenstein;
 use super::perceptual_loss::{rgb_to_lab, delta_e_2000, Illuminant};
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // DEMO RESULTS
 // ============================================================================
@@ -118,7 +120,7 @@ pub fn demo_dielectric_vs_conductor() -> DemoResult {
 
     for &angle_deg in &angles {
         let angle_rad = angle_deg.to_radians();
-        let cos_theta = angle_rad.cos();
+        let cos_theta = ops::cos(angle_rad);
 
         let glass_r = fresnel_dielectric_full(cos_theta, glass_ior);
         let gold_r = fresnel_conductor_full(cos_theta, gold_n, gold_k);
@@ -176,7 +178,7 @@ pub fn demo_thin_film_soap_bubble() -> DemoResult {
             let optical_path = 2.0 * film_ior * thickness; // nm
             let phase = 2.0 * PI * optical_path / wl;
             let r_amplitude = ((1.0 - film_ior) / (1.0 + film_ior)).powi(2);
-            rgb[i] = r_amplitude * (1.0 + phase.cos()) / 2.0;
+            rgb[i] = r_amplitude * (1.0 + ops::cos(phase)) / 2.0;
         }
 
         outputs.push((format!("R(450nm) @ {}nm", thickness), rgb[0]));
@@ -275,7 +277,7 @@ pub fn demo_fog_vs_smoke() -> DemoResult {
 
     for &angle_deg in &angles {
         let angle_rad = angle_deg.to_radians();
-        let cos_theta = angle_rad.cos();
+        let cos_theta = ops::cos(angle_rad);
 
         // henyey_greenstein(cos_theta, g)
         let fog_scatter = henyey_greenstein(cos_theta, fog_g);
@@ -368,7 +370,7 @@ pub fn demo_copper_patina() -> DemoResult {
 pub fn demo_spectral_vs_rgb() -> DemoResult {
     // Test material: Crown glass at 60° incidence
     let ior_rgb = [1.516, 1.520, 1.527]; // Red, Green, Blue dispersion
-    let cos_theta = 60.0_f64.to_radians().cos();
+    let cos_theta = ops::cos(60.0_f64.to_radians());
 
     let mut outputs = Vec::new();
 
@@ -30,6 +30,8 @@ use std::f64::consts::PI;
 use super::fresnel::fresnel_schlick;
 use super::enhanced_presets::QualityTier;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // EFFECT LAYER DEFINITIONS
 // ============================================================================
@@ -236,7 +238,7 @@ impl CombinedMaterial {
             }
 
             EffectLayer::Mie { g, extinction } => {
-                henyey_greenstein_phase(cos_theta, *g) * (1.0 - (-*extinction).exp())
+                henyey_greenstein_phase(cos_theta, *g) * (1.0 - ops::exp(-*extinction))
             }
 
             EffectLayer::Roughness { value, model } => {
@@ -244,7 +246,7 @@ impl CombinedMaterial {
             }
 
             EffectLayer::Absorption { coefficient, thickness } => {
-                (-*coefficient * *thickness).exp()
+                ops::exp(-*coefficient * *thickness)
             }
 
             EffectLayer::Oxidation { oxide_n, oxide_k, thickness_nm } => {
@@ -255,7 +257,7 @@ impl CombinedMaterial {
 
     /// Generate CSS gradient
     pub fn to_css(&self, angle_deg: f64) -> String {
-        let cos_theta = (angle_deg * PI / 180.0).cos();
+        let cos_theta = ops::cos(angle_deg * PI / 180.0);
         let rgb = self.evaluate_rgb(cos_theta);
 
         let r = (rgb[0] * 255.0).round() as u8;
@@ -282,7 +284,7 @@ impl CombinedMaterial {
         for i in 0..=10 {
             let t = i as f64 / 10.0;
             let angle = t * 60.0; // 0 to 60 degrees
-            let cos_theta = (angle * PI / 180.0).cos();
+            let cos_theta = ops::cos(angle * PI / 180.0);
             let rgb = self.evaluate_rgb(cos_theta);
 
             let r = (rgb[0] * 255.0).round() as u8;
@@ -440,8 +442,8 @@ fn thin_film_reflectance(wavelength_nm: f64, n_film: f64, thickness_nm: f64, n_s
     let r2 = (n_film - n_substrate) / (n_film + n_substrate);
 
     // Airy formula (simplified)
-    let numerator = r1 * r1 + r2 * r2 + 2.0 * r1 * r2 * delta.cos();
-    let denominator = 1.0 + r1 * r1 * r2 * r2 + 2.0 * r1 * r2 * delta.cos();
+    let numerator = r1 * r1 + r2 * r2 + 2.0 * r1 * r2 * ops::cos(delta);
+    let denominator = 1.0 + r1 * r1 * r2 * r2 + 2.0 * r1 * r2 * ops::cos(delta);
 
     (numerator / denominator).abs().min(1.0)
 }
@@ -487,7 +489,7 @@ fn roughness_factor(cos_theta: f64, roughness: f64, model: RoughnessModel) -> f6
         }
         RoughnessModel::BlinnPhong => {
             // Simple power law
-            cos_theta.powf(1.0 / roughness.max(0.01))
+            ops::powf(cos_theta, 1.0 / roughness.max(0.01))
         }
     }
 }
@@ -501,10 +503,10 @@ fn oxide_reflectance(n: f64, k: f64, thickness_nm: f64, wavelength_nm: f64) -> f
     let r1 = ((n - 1.0).powi(2) + k.powi(2)) / ((n + 1.0).powi(2) + k.powi(2));
 
     // Interference modulation
-    let interference = 0.5 * (1.0 + phase.cos() * 0.3);
+    let interference = 0.5 * (1.0 + ops::cos(phase) * 0.3);
 
     // Absorption in oxide
-    let absorption = (-k * thickness_nm / 100.0).exp();
+    let absorption = ops::exp(-k * thickness_nm / 100.0);
 
     r1 * interference * absorption
 }
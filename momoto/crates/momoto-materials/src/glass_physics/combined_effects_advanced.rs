@@ -36,6 +36,8 @@ use super::thin_film_dynamic::{DynamicFilmLayer, DynamicThinFilmStack, HeightMap
 use super::metal_oxidation_dynamic::{DynamicOxidizedMetal, AlloyComposition, Element};
 use super::combined_effects::{BlendMode, RoughnessModel};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // DISPERSION MODELS
 // ============================================================================
@@ -107,7 +109,7 @@ impl DispersionModel {
                 n2.sqrt()
             }
             Self::Conrady { a, b, c } => {
-                a + b / lambda_nm + c / lambda_nm.powf(3.5)
+                a + b / lambda_nm + c / ops::powf(lambda_nm, 3.5)
             }
             Self::Constant { n } => *n,
         }
@@ -239,7 +241,7 @@ impl TemperatureGradientConfig {
             GradientType::Linear => r,
             GradientType::Radial => r,
             GradientType::Gaussian { sigma } => {
-                1.0 - (-r * r / (2.0 * sigma * sigma)).exp()
+                1.0 - ops::exp(-r * r / (2.0 * sigma * sigma))
             }
         };
 
@@ -555,7 +557,7 @@ impl AdvancedCombinedMaterial {
             }
 
             AdvancedEffectLayer::Mie { g, extinction } => {
-                henyey_greenstein_phase(cos_theta, *g) * (1.0 - (-*extinction).exp())
+                henyey_greenstein_phase(cos_theta, *g) * (1.0 - ops::exp(-*extinction))
             }
 
             AdvancedEffectLayer::Roughness { value, model } => {
@@ -563,7 +565,7 @@ impl AdvancedCombinedMaterial {
             }
 
             AdvancedEffectLayer::Absorption { coefficient, thickness } => {
-                (-*coefficient * *thickness).exp()
+                ops::exp(-*coefficient * *thickness)
             }
 
             AdvancedEffectLayer::Oxidation { oxide_n, oxide_k, thickness_nm } => {
@@ -582,7 +584,7 @@ impl AdvancedCombinedMaterial {
             AdvancedEffectLayer::MiePolydisperse { distribution, g_mean, extinction } => {
                 let effective_g = distribution.effective_g(*g_mean);
                 let effective_ext = extinction * distribution.extinction_factor();
-                henyey_greenstein_phase(cos_theta, effective_g) * (1.0 - (-effective_ext).exp())
+                henyey_greenstein_phase(cos_theta, effective_g) * (1.0 - ops::exp(-effective_ext))
             }
 
             AdvancedEffectLayer::SpectralDispersion { dispersion } => {
@@ -610,7 +612,7 @@ impl AdvancedCombinedMaterial {
     }
 
     fn angle_from_cos(&self, cos_theta: f64) -> f64 {
-        cos_theta.acos().to_degrees()
+        ops::acos(cos_theta).to_degrees()
     }
 
     /// Update physical state
@@ -666,7 +668,7 @@ impl AdvancedCombinedMaterial {
 
     /// Generate CSS gradient
     pub fn to_css(&self, angle_deg: f64) -> String {
-        let cos_theta = (angle_deg * PI / 180.0).cos();
+        let cos_theta = ops::cos(angle_deg * PI / 180.0);
         let rgb = self.evaluate_rgb(cos_theta);
 
         let r = (rgb[0] * 255.0).round() as u8;
@@ -916,8 +918,8 @@ fn thin_film_reflectance(wavelength_nm: f64, n_film: f64, thickness_nm: f64, n_s
     let delta = 4.0 * PI * n_film * thickness_nm * cos_theta / wavelength_nm;
     let r1 = (1.0 - n_film) / (1.0 + n_film);
     let r2 = (n_film - n_substrate) / (n_film + n_substrate);
-    let numerator = r1 * r1 + r2 * r2 + 2.0 * r1 * r2 * delta.cos();
-    let denominator = 1.0 + r1 * r1 * r2 * r2 + 2.0 * r1 * r2 * delta.cos();
+    let numerator = r1 * r1 + r2 * r2 + 2.0 * r1 * r2 * ops::cos(delta);
+    let denominator = 1.0 + r1 * r1 * r2 * r2 + 2.0 * r1 * r2 * ops::cos(delta);
     (numerator / denominator).abs().min(1.0)
 }
 
@@ -955,7 +957,7 @@ fn roughness_factor(cos_theta: f64, roughness: f64, model: RoughnessModel) -> f6
             }
         }
         RoughnessModel::BlinnPhong => {
-            cos_theta.powf(1.0 / roughness.max(0.01))
+            ops::powf(cos_theta, 1.0 / roughness.max(0.01))
         }
     }
 }
@@ -964,8 +966,8 @@ fn roughness_factor(cos_theta: f64, roughness: f64, model: RoughnessModel) -> f6
 fn oxide_reflectance(n: f64, k: f64, thickness_nm: f64, wavelength_nm: f64) -> f64 {
     let phase = 4.0 * PI * n * thickness_nm / wavelength_nm;
     let r1 = ((n - 1.0).powi(2) + k.powi(2)) / ((n + 1.0).powi(2) + k.powi(2));
-    let interference = 0.5 * (1.0 + phase.cos() * 0.3);
-    let absorption = (-k * thickness_nm / 100.0).exp();
+    let interference = 0.5 * (1.0 + ops::cos(phase) * 0.3);
+    let absorption = ops::exp(-k * thickness_nm / 100.0);
     r1 * interference * absorption
 }
 
@@ -38,6 +38,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // COMPLEX NUMBER TYPE
 // ============================================================================
@@ -559,7 +561,7 @@ pub fn to_css_metallic_gradient(metal: &SpectralComplexIOR, intensity: f64) -> S
 /// Generate CSS for metallic button/surface
 pub fn to_css_metallic_surface(metal: &SpectralComplexIOR, light_angle_deg: f64) -> String {
     let f0 = metal.f0_rgb();
-    let cos_light = (light_angle_deg * PI / 180.0).cos().abs();
+    let cos_light = ops::cos(light_angle_deg * PI / 180.0).abs();
 
     // Fresnel at light angle
     let fresnel = metal.fresnel_schlick_rgb(cos_light);
@@ -37,6 +37,8 @@
 use super::external_validation::ExternalDataset;
 use std::collections::HashMap;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // CONSTANTS
 // ============================================================================
@@ -107,8 +109,8 @@ impl MerlMaterial {
                 let theta_d = td as f32 * std::f32::consts::FRAC_PI_2 / THETA_D_SAMPLES as f32;
 
                 // Cook-Torrance-like model
-                let cos_th = theta_h.cos();
-                let cos_td = theta_d.cos();
+                let cos_th = ops::cos(f64::from(theta_h)) as f32;
+                let cos_td = ops::cos(f64::from(theta_d)) as f32;
 
                 // Diffuse component
                 let d = (1.0 - metallic) * cos_td / std::f32::consts::PI;
@@ -6,6 +6,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // FRESNEL GRADIENTS
 // ============================================================================
@@ -220,7 +222,7 @@ pub fn beer_lambert_gradient(absorption: f64, distance: f64) -> (f64, f64, f64)
     let absorption = absorption.max(0.0);
     let distance = distance.max(0.0);
 
-    let transmittance = (-absorption * distance).exp();
+    let transmittance = ops::exp(-absorption * distance);
 
     // ∂T/∂α = -d × T
     let dt_dalpha = -distance * transmittance;
@@ -272,8 +274,8 @@ pub fn thin_film_gradient(
 
     // Phase difference
     let delta = 4.0 * PI * n_film * thickness_nm * cos_theta_film / wavelength_nm;
-    let cos_delta = delta.cos();
-    let sin_delta = delta.sin();
+    let cos_delta = ops::cos(delta);
+    let sin_delta = ops::sin(delta);
 
     // Reflectance (Airy formula)
     let r01_sq = r01 * r01;
@@ -319,7 +321,7 @@ pub fn henyey_greenstein_gradient(cos_theta: f64, g: f64) -> (f64, f64) {
 
     // p(θ) = (1 - g²) / (4π × (1 + g² - 2g×cosθ)^(3/2))
     let denom_inner = 1.0 + g2 - 2.0 * g * cos_theta;
-    let denom_pow = denom_inner.powf(1.5);
+    let denom_pow = ops::powf(denom_inner, 1.5);
 
     let phase = (1.0 - g2) / (4.0 * PI * denom_pow);
 
@@ -329,7 +331,7 @@ pub fn henyey_greenstein_gradient(cos_theta: f64, g: f64) -> (f64, f64) {
     //                    = -3(g - cosθ) / denom^(5/2)
 
     let d_num_dg = -2.0 * g;
-    let denom_pow_5_2 = denom_inner.powf(2.5);
+    let denom_pow_5_2 = ops::powf(denom_inner, 2.5);
     let d_inv_denom_dg = -3.0 * (g - cos_theta) / (4.0 * PI * denom_pow_5_2);
 
     let dp_dg = d_num_dg / (4.0 * PI * denom_pow) + (1.0 - g2) * d_inv_denom_dg;
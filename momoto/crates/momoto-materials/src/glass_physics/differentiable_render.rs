@@ -41,6 +41,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // MATERIAL PARAMETERS (Differentiable)
 // ============================================================================
@@ -227,7 +229,7 @@ pub fn fresnel_schlick_diff(cos_theta: f64, n: f64) -> (f64, f64) {
 
 /// Beer-Lambert transmission with gradient
 pub fn beer_lambert_diff(alpha: f64, distance: f64) -> (f64, f64, f64) {
-    let t = (-alpha * distance).exp();
+    let t = ops::exp(-alpha * distance);
 
     // ∂T/∂α = -d × exp(-αd)
     let dt_dalpha = -distance * t;
@@ -276,8 +278,8 @@ pub fn thin_film_reflectance_diff(
     // Reflectance (Airy formula)
     let r01_sq = r01 * r01;
     let r12_sq = r12 * r12;
-    let cos_delta = delta.cos();
-    let sin_delta = delta.sin();
+    let cos_delta = ops::cos(delta);
+    let sin_delta = ops::sin(delta);
 
     let numerator = r01_sq + r12_sq + 2.0 * r01 * r12 * cos_delta;
     let denominator = 1.0 + r01_sq * r12_sq + 2.0 * r01 * r12 * cos_delta;
@@ -706,7 +708,7 @@ pub fn forward_dielectric(params: &MaterialParams, wavelengths: &[f64]) -> Rende
 
         // Beer-Lambert absorption
         let thickness = 1.0; // mm
-        let t_abs = (-params.absorption * thickness).exp();
+        let t_abs = ops::exp(-params.absorption * thickness);
 
         reflectance.push(r);
         transmittance.push((1.0 - r) * t_abs * (1.0 - r)); // Through both surfaces
@@ -26,6 +26,8 @@
 // ============================================================================
 
 /// Standard wavelengths for RGB spectral sampling (in nanometers)
+use momoto_core::math::ops;
+
 pub mod wavelengths {
     /// Red channel dominant wavelength (C-line, Hydrogen)
     pub const RED: f64 = 656.3;
@@ -446,15 +448,15 @@ pub fn chromatic_angle_separation<D: Dispersion>(
     let n_red = dispersion.n(wavelengths::RED);
     let n_blue = dispersion.n(wavelengths::BLUE);
 
-    let sin_i = incident_angle_rad.sin();
+    let sin_i = ops::sin(incident_angle_rad);
 
     // Snell's law: sin(θ_t) = sin(θ_i) / n
     let sin_t_red = sin_i / n_red;
     let sin_t_blue = sin_i / n_blue;
 
     // Clamp to avoid domain errors at TIR
-    let theta_red = sin_t_red.clamp(-1.0, 1.0).asin();
-    let theta_blue = sin_t_blue.clamp(-1.0, 1.0).asin();
+    let theta_red = ops::asin(sin_t_red.clamp(-1.0, 1.0));
+    let theta_blue = ops::asin(sin_t_blue.clamp(-1.0, 1.0));
 
     (theta_red - theta_blue).abs()
 }
@@ -28,6 +28,8 @@ use super::spectral_error::{
 
 use std::time::Instant;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // EXTERNAL DATASET TRAIT
 // ============================================================================
@@ -685,7 +687,7 @@ fn spectral_to_lab(spectral: &[f64]) -> [f64; 3] {
     // Simple approximation: use average as luminance
     let y: f64 = spectral.iter().sum::<f64>() / spectral.len() as f64;
     let l = if y > 0.008856 {
-        116.0 * y.powf(1.0 / 3.0) - 16.0
+        116.0 * ops::powf(y, 1.0 / 3.0) - 16.0
     } else {
         903.3 * y
     };
@@ -47,6 +47,8 @@
 
 use super::light_model::Vec3;
 
+use momoto_core::math::ops;
+
 /// Calculate Fresnel reflectance using Schlick's approximation
 ///
 /// This is the **primary function** for calculating glass reflectivity in UI contexts.
@@ -211,7 +213,7 @@ pub fn fresnel_full(ior1: f64, ior2: f64, cos_theta_i: f64) -> (f64, f64) {
 /// ```
 #[inline]
 pub fn brewster_angle(ior1: f64, ior2: f64) -> f64 {
-    (ior2 / ior1).atan()
+    ops::atan(ior2 / ior1)
 }
 
 /// Calculate view angle cosine from surface normal and view direction
@@ -300,7 +302,7 @@ pub fn calculate_view_angle(normal: Vec3, view_dir: Vec3) -> f64 {
 #[inline]
 pub fn edge_intensity(cos_theta: f64, edge_power: f64) -> f64 {
     let cos_clamped = cos_theta.clamp(0.0, 1.0);
-    (1.0 - cos_clamped).powf(edge_power)
+    ops::powf(1.0 - cos_clamped, edge_power)
 }
 
 /// Calculate Fresnel reflectivity across a surface for gradient generation
@@ -633,4 +635,29 @@ mod tests {
         let cos_grazing = calculate_view_angle(normal, view_grazing);
         assert!(cos_grazing < 0.1);
     }
+
+    /// Cross-target golden hash; must match on every CI target.
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_golden_hash_deterministic() {
+        let iors = [1.0, 1.33, 1.5, 1.77, 2.42];
+        let hash = iors
+            .iter()
+            .flat_map(|&n2| (0..=32).map(move |i| (n2, f64::from(i) / 32.0)))
+            .flat_map(|(n2, cos_theta)| {
+                let (rs, rp) = fresnel_full(1.0, n2, cos_theta);
+                [
+                    fresnel_schlick(1.0, n2, cos_theta),
+                    rs,
+                    rp,
+                    brewster_angle(1.0, n2),
+                    edge_intensity(cos_theta, 2.5),
+                ]
+            })
+            .flat_map(|v| v.to_bits().to_le_bytes())
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+            });
+        assert_eq!(hash, 6_110_316_477_398_946_098);
+    }
 }
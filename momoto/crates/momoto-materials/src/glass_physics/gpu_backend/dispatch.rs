@@ -5,6 +5,8 @@
 #[cfg(feature = "gpu")]
 use std::sync::Arc;
 
+use momoto_core::math::ops;
+
 #[cfg(feature = "gpu")]
 use wgpu;
 
@@ -355,7 +357,7 @@ impl GpuBatchEvaluator {
             let reflectance = f0 + (1.0 - f0) * pow5;
 
             let path = mat.thickness as f64 / cos_theta.max(1e-7);
-            let transmittance = (1.0 - reflectance) * (-mat.absorption as f64 * path).exp();
+            let transmittance = (1.0 - reflectance) * ops::exp(-mat.absorption as f64 * path);
 
             result.reflectance_r.push(reflectance.clamp(0.0, 1.0));
             result.reflectance_g.push(reflectance.clamp(0.0, 1.0));
@@ -10,6 +10,8 @@
 use super::buffers::{MaterialGpuData, ResponseGpuData};
 use super::dispatch::GpuBatchResult;
 
+use momoto_core::math::ops;
+
 /// Configuration for parity testing.
 #[derive(Debug, Clone)]
 pub struct ParityConfig {
@@ -292,7 +294,7 @@ impl GpuCpuParityTest {
     fn lab_f(t: f64) -> f64 {
         let delta = 6.0 / 29.0;
         if t > delta.powi(3) {
-            t.cbrt()
+            ops::cbrt(t)
         } else {
             t / (3.0 * delta * delta) + 4.0 / 29.0
         }
@@ -7,6 +7,8 @@
 // ============================================================================
 
 /// Information about rank deficiency.
+use momoto_core::math::ops;
+
 #[derive(Debug, Clone)]
 pub struct RankDeficiency {
     /// Index of the problematic parameter.
@@ -200,7 +202,7 @@ impl JacobianRankAnalyzer {
         let score = if identifiability_ratio < 1.0 {
             identifiability_ratio * 0.5
         } else if condition_number > 1e6 {
-            0.5 + 0.2 / (1.0 + (condition_number / 1e6).log10())
+            0.5 + 0.2 / (1.0 + ops::log10(condition_number / 1e6))
         } else {
             0.7 + 0.3 * (1.0 - (condition_number / 1e6).min(1.0))
         };
@@ -268,7 +270,7 @@ impl JacobianRankAnalyzer {
         }
 
         // Initial random vector
-        let mut v: Vec<f64> = (0..n).map(|i| (i as f64 + 1.0).sin()).collect();
+        let mut v: Vec<f64> = (0..n).map(|i| ops::sin(i as f64 + 1.0)).collect();
 
         // Normalize
         let norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
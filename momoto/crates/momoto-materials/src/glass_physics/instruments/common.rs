@@ -7,6 +7,8 @@ use std::fmt;
 
 use crate::glass_physics::metrology::{CalibrationReference, Unit};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // NOISE MODELS
 // ============================================================================
@@ -83,7 +85,7 @@ impl NoiseModel {
         // Box-Muller for normal distribution
         let u1 = rng().max(1e-10);
         let u2 = rng();
-        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        let z = (-2.0 * ops::ln(u1)).sqrt() * ops::cos(2.0 * std::f64::consts::PI * u2);
         let noise = z * std;
 
         (value + noise, std)
@@ -592,7 +594,7 @@ impl SimpleRng {
     pub fn normal(&mut self, mean: f64, std: f64) -> f64 {
         let u1 = self.next().max(1e-10);
         let u2 = self.next();
-        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        let z = (-2.0 * ops::ln(u1)).sqrt() * ops::cos(2.0 * std::f64::consts::PI * u2);
         mean + std * z
     }
 }
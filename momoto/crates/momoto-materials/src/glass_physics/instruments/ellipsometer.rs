@@ -10,6 +10,8 @@ use crate::glass_physics::metrology::{
 
 use super::common::{EnvironmentConditions, InstrumentConfig, NoiseModel, Resolution, SimpleRng};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // ELLIPSOMETRY CONFIGURATION
 // ============================================================================
@@ -187,8 +189,8 @@ impl VirtualEllipsometer {
     /// Calculate Psi and Delta from optical constants.
     fn calculate_psi_delta(&self, n: f64, k: f64, _wavelength_nm: f64) -> (f64, f64) {
         let theta_i = self.angle_of_incidence_deg.to_radians();
-        let cos_i = theta_i.cos();
-        let sin_i = theta_i.sin();
+        let cos_i = ops::cos(theta_i);
+        let sin_i = ops::sin(theta_i);
 
         // Complex refractive index
         let n_complex = Complex::new(n, -k);
@@ -204,7 +206,7 @@ impl VirtualEllipsometer {
 
         // rho = rp / rs = tan(Psi) * exp(i * Delta)
         let rho_magnitude = (rp / rs).abs();
-        let psi_rad = rho_magnitude.atan();
+        let psi_rad = ops::atan(rho_magnitude);
 
         // For real coefficients, delta is 0 or 180
         let delta_deg = if rp * rs > 0.0 { 0.0 } else { 180.0 };
@@ -369,7 +371,7 @@ impl VirtualEllipsometer {
         wavelength_nm: f64,
     ) -> (f64, f64) {
         let theta_i = self.angle_of_incidence_deg.to_radians();
-        let cos_i = theta_i.cos();
+        let cos_i = ops::cos(theta_i);
 
         // Phase shift from film
         let phase = 4.0 * std::f64::consts::PI * n_film * thickness_nm * cos_i / wavelength_nm;
@@ -378,8 +380,8 @@ impl VirtualEllipsometer {
         let base_psi = 35.0; // Typical Psi
         let base_delta = 90.0; // Typical Delta
 
-        let psi = base_psi + 10.0 * (phase).sin() * (1.0 - k_film);
-        let delta = base_delta + 90.0 * (phase).cos() * (n_sub - n_film).abs() / n_sub;
+        let psi = base_psi + 10.0 * ops::sin(phase) * (1.0 - k_film);
+        let delta = base_delta + 90.0 * ops::cos(phase) * (n_sub - n_film).abs() / n_sub;
 
         (psi.clamp(0.0, 90.0), delta)
     }
@@ -432,9 +434,9 @@ impl EllipsometryPoint {
         let psi_rad = self.psi.value.to_radians();
         let delta_rad = self.delta.value.to_radians();
 
-        let magnitude = psi_rad.tan();
-        let real = magnitude * delta_rad.cos();
-        let imag = magnitude * delta_rad.sin();
+        let magnitude = ops::tan(psi_rad);
+        let real = magnitude * ops::cos(delta_rad);
+        let imag = magnitude * ops::sin(delta_rad);
 
         (real, imag)
     }
@@ -442,7 +444,7 @@ impl EllipsometryPoint {
     /// Calculate pseudo-dielectric function (approximate).
     pub fn pseudo_dielectric(&self) -> (f64, f64) {
         let theta_i = self.angle_of_incidence_deg.to_radians();
-        let sin_i = theta_i.sin();
+        let sin_i = ops::sin(theta_i);
 
         let (rho_re, rho_im) = self.rho();
         let rho_sq = rho_re * rho_re + rho_im * rho_im;
@@ -450,7 +452,7 @@ impl EllipsometryPoint {
         // <epsilon> = sin^2(theta) * (1 + tan^2(theta) * ((1-rho)/(1+rho))^2)
         let factor = (1.0 - rho_sq) / (1.0 + rho_sq + 2.0 * rho_re);
 
-        let epsilon_re = sin_i * sin_i * (1.0 + theta_i.tan().powi(2) * factor);
+        let epsilon_re = sin_i * sin_i * (1.0 + ops::tan(theta_i).powi(2) * factor);
         let epsilon_im = 0.0; // Simplified
 
         (epsilon_re, epsilon_im)
@@ -12,6 +12,8 @@ use super::common::{
     DetectorGeometry, EnvironmentConditions, InstrumentConfig, LightSource, NoiseModel, Resolution, SimpleRng,
 };
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // GONIOREFLECTOMETER CONFIGURATION
 // ============================================================================
@@ -271,8 +273,8 @@ impl VirtualGonioreflectometer {
 
         for (i, &theta_deg) in result.reflected_angles_deg.iter().enumerate() {
             let theta_rad = theta_deg.to_radians();
-            let cos_theta = theta_rad.cos();
-            let sin_theta = theta_rad.sin();
+            let cos_theta = ops::cos(theta_rad);
+            let sin_theta = ops::sin(theta_rad);
 
             // Weight by solid angle element
             let d_omega = 2.0 * std::f64::consts::PI * sin_theta * self.angular_step.to_radians();
@@ -427,7 +429,7 @@ pub fn phong_brdf(diffuse: f64, specular: f64, exponent: f64) -> impl Fn(f64, f6
 
         // Specular: maximum when theta_o = theta_i
         let angle_diff = (theta_o - theta_i).abs();
-        let specular_term = specular * angle_diff.cos().max(0.0).powf(exponent);
+        let specular_term = specular * ops::powf(ops::cos(angle_diff).max(0.0), exponent);
 
         diffuse_term + specular_term
     }
@@ -441,7 +443,7 @@ pub fn fresnel_brdf(n: f64) -> impl Fn(f64, f64, f64) -> f64 {
             return 0.0;
         }
 
-        let cos_i = theta_i.cos();
+        let cos_i = ops::cos(theta_i);
         let sin_t = (1.0 - cos_i * cos_i) / (n * n);
 
         if sin_t > 1.0 {
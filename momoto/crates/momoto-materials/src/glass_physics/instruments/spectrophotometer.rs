@@ -13,6 +13,8 @@ use super::common::{
     Resolution, SimpleRng,
 };
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // SPECTROPHOTOMETER CONFIGURATION
 // ============================================================================
@@ -370,7 +372,7 @@ impl VirtualSpectrophotometer {
             let t_clamped = noisy.clamp(1e-6, 1.0);
 
             // Convert to absorbance: A = -log10(T)
-            let absorbance = -t_clamped.log10();
+            let absorbance = -ops::log10(t_clamped);
 
             // Propagate uncertainty: dA = dT / (T * ln(10))
             let abs_uncertainty = noise_std / (t_clamped * std::f64::consts::LN_10);
@@ -584,7 +586,7 @@ impl SpectroResult {
 
 /// Gaussian function for CMF approximation.
 fn gaussian(x: f64, mean: f64, std: f64) -> f64 {
-    (-0.5 * ((x - mean) / std).powi(2)).exp()
+    ops::exp(-0.5 * ((x - mean) / std).powi(2))
 }
 
 // ============================================================================
@@ -612,7 +614,7 @@ pub fn gaussian_absorption(
     width_nm: f64,
 ) -> impl Fn(f64) -> f64 {
     move |wavelength: f64| {
-        let absorption = peak_depth * (-0.5 * ((wavelength - center_nm) / width_nm).powi(2)).exp();
+        let absorption = peak_depth * ops::exp(-0.5 * ((wavelength - center_nm) / width_nm).powi(2));
         baseline - absorption
     }
 }
@@ -19,6 +19,8 @@
 // ============================================================================
 
 /// Methods for projecting parameters back to valid bounds.
+use momoto_core::math::ops;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProjectionMethod {
     /// Simple clamp to bounds (discontinuous gradient at boundaries).
@@ -127,14 +129,14 @@ impl ParameterBound {
     pub fn sigmoid_project(&self, value: f64, sharpness: f64) -> f64 {
         // Map value to [0, 1] via sigmoid, then scale to bounds
         let centered = (value - (self.min + self.max) / 2.0) * sharpness / (self.max - self.min);
-        let sigmoid = 1.0 / (1.0 + (-centered).exp());
+        let sigmoid = 1.0 / (1.0 + ops::exp(-centered));
         self.min + sigmoid * (self.max - self.min)
     }
 
     /// Sigmoid projection gradient (for backprop).
     pub fn sigmoid_gradient(&self, value: f64, sharpness: f64) -> f64 {
         let centered = (value - (self.min + self.max) / 2.0) * sharpness / (self.max - self.min);
-        let sigmoid = 1.0 / (1.0 + (-centered).exp());
+        let sigmoid = 1.0 / (1.0 + ops::exp(-centered));
         let sigmoid_grad = sigmoid * (1.0 - sigmoid);
         sigmoid_grad * sharpness
     }
@@ -174,7 +176,7 @@ impl ParameterBound {
         let eps = 1e-10;
         let dist_min = (value - self.min).max(eps);
         let dist_max = (self.max - value).max(eps);
-        -strength * (dist_min.ln() + dist_max.ln())
+        -strength * (ops::ln(dist_min) + ops::ln(dist_max))
     }
 
     /// Log-barrier gradient.
@@ -4,6 +4,8 @@
 
 use std::collections::VecDeque;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // OPTIMIZER TRAIT
 // ============================================================================
@@ -149,8 +151,8 @@ impl DifferentiableOptimizer for AdamOptimizer {
             self.v[i] = self.config.beta2 * self.v[i] + (1.0 - self.config.beta2) * g * g;
 
             // Compute bias-corrected estimates
-            let m_hat = self.m[i] / (1.0 - self.config.beta1.powf(t));
-            let v_hat = self.v[i] / (1.0 - self.config.beta2.powf(t));
+            let m_hat = self.m[i] / (1.0 - ops::powf(self.config.beta1, t));
+            let v_hat = self.v[i] / (1.0 - ops::powf(self.config.beta2, t));
 
             // Compute update
             let update = -self.config.learning_rate * m_hat / (v_hat.sqrt() + self.config.epsilon);
@@ -20,6 +20,8 @@ use super::bounds::BoundsEnforcer;
 use super::super::differentiable::traits::DifferentiableBSDF;
 use super::super::unified_bsdf::{BSDFContext, Vector3};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // LOSS FUNCTIONS
 // ============================================================================
@@ -71,15 +73,15 @@ impl LossFunction {
                 }
             }
             Self::LogCosh => {
-                let cosh_r = residual.cosh();
-                let loss = cosh_r.ln();
-                let grad = residual.tanh();
+                let cosh_r = ops::cosh(residual);
+                let loss = ops::ln(cosh_r);
+                let grad = ops::tanh(residual);
                 (loss, grad)
             }
             Self::Perceptual => {
                 // Weight reflectance errors more heavily in perceptually important ranges
                 // Human vision is more sensitive to changes around 4% reflectance
-                let sensitivity = 1.0 + 10.0 * (-10.0 * (residual - 0.04).powi(2)).exp();
+                let sensitivity = 1.0 + 10.0 * ops::exp(-10.0 * (residual - 0.04).powi(2));
                 let loss = 0.5 * sensitivity * residual * residual;
                 let grad = sensitivity * residual;
                 (loss, grad)
@@ -14,6 +14,8 @@ use super::optimizer::{DifferentiableOptimizer, AdamOptimizer, AdamConfig};
 use super::super::differentiable::traits::DifferentiableBSDF;
 use super::super::unified_bsdf::{BSDFContext, Vector3};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // TEMPORAL SEQUENCE
 // ============================================================================
@@ -198,11 +200,11 @@ impl EvolutionModel {
             Self::Linear => initial + params.rate * t,
             Self::Exponential => {
                 let asymp = params.asymptote.unwrap_or(0.0);
-                asymp + (initial - asymp) * (-params.rate * t).exp()
+                asymp + (initial - asymp) * ops::exp(-params.rate * t)
             }
             Self::Logarithmic => {
                 let tau = params.tau.unwrap_or(1.0);
-                initial + params.rate * (1.0 + t / tau).ln()
+                initial + params.rate * ops::ln(1.0 + t / tau)
             }
             Self::Polynomial { degree } => {
                 let mut result = initial;
@@ -221,9 +223,9 @@ impl EvolutionModel {
             Self::Linear => t,
             Self::Exponential => {
                 let asymp = params.asymptote.unwrap_or(0.0);
-                -t * (initial - asymp) * (-params.rate * t).exp()
+                -t * (initial - asymp) * ops::exp(-params.rate * t)
             }
-            Self::Logarithmic => (1.0 + t / params.tau.unwrap_or(1.0)).ln(),
+            Self::Logarithmic => ops::ln(1.0 + t / params.tau.unwrap_or(1.0)),
             Self::Polynomial { .. } => 0.0, // Rate not used in polynomial
         }
     }
@@ -232,7 +234,7 @@ impl EvolutionModel {
     pub fn gradient_initial(&self, t: f64, params: &EvolutionParams) -> f64 {
         match self {
             Self::Linear => 1.0,
-            Self::Exponential => (-params.rate * t).exp(),
+            Self::Exponential => ops::exp(-params.rate * t),
             Self::Logarithmic => 1.0,
             Self::Polynomial { .. } => 1.0,
         }
@@ -562,7 +564,7 @@ impl TemporalFitter {
 
             // Gradient w.r.t. asymptote (for exponential)
             if matches!(self.config.evolution_model, EvolutionModel::Exponential) {
-                let exp_term = (-evolution_params.rate * frame.time).exp();
+                let exp_term = ops::exp(-evolution_params.rate * frame.time);
                 evol_grad[2] += frame.weight * residual * dr_dn * (1.0 - exp_term);
             }
         }
@@ -24,6 +24,7 @@
 //!
 //! This is enough to create convincing glass without full ray tracing.
 
+use momoto_core::math::ops;
 use momoto_core::space::oklch::OKLCH;
 use std::f64::consts::PI;
 use std::ops::Add;
@@ -272,7 +273,7 @@ fn calculate_specular(normal: &Vec3, view: &Vec3, light_direction: &Vec3, shinin
     let spec_angle = normal.dot(&half_vector).max(0.0);
 
     if spec_angle > 0.0 {
-        spec_angle.powf(shininess)
+        ops::powf(spec_angle, shininess)
     } else {
         0.0
     }
@@ -310,7 +311,7 @@ pub fn derive_gradient(
         let normal = if surface_curvature > 0.0 {
             // Curved surface: normal rotates from top to bottom
             let angle = (t - 0.5) * PI * surface_curvature;
-            Vec3::new(0.0, angle.sin(), angle.cos())
+            Vec3::new(0.0, ops::sin(angle), ops::cos(angle))
         } else {
             // Flat surface: normal always faces forward
             Vec3::new(0.0, 0.0, 1.0)
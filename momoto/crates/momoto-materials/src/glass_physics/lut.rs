@@ -23,6 +23,8 @@
 
 use std::sync::OnceLock;
 
+use momoto_core::math::ops;
+
 // Import existing functions for LUT generation
 use super::fresnel::fresnel_schlick;
 
@@ -188,7 +190,7 @@ impl BeerLambertLUT {
                 let distance = Self::DISTANCE_MIN + j as f64 * Self::DISTANCE_STEP;
 
                 // Beer-Lambert law: I = I₀ * e^(-α*d)
-                let transmittance = (-absorption * distance).exp();
+                let transmittance = ops::exp(-absorption * distance);
 
                 table[i][j] = transmittance as f32;
             }
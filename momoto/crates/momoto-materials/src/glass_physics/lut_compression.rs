@@ -21,6 +21,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // QUANTIZATION
 // ============================================================================
@@ -540,7 +542,7 @@ impl CompressedHGLUT {
             }
             let g2 = g * g;
             let denom = 1.0 + g2 - 2.0 * g * cos_theta;
-            (1.0 - g2) / (4.0 * PI * denom.powf(1.5))
+            (1.0 - g2) / (4.0 * PI * ops::powf(denom, 1.5))
         };
 
         let lut = CompressedLUT2D::build(
@@ -18,6 +18,8 @@
 
 use std::collections::HashMap;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // SPECTRAL MEASUREMENT STRUCTURES
 // ============================================================================
@@ -474,7 +476,7 @@ pub mod builtin {
             let peak = 0.55;
             let center = 500.0;
             let width = 100.0;
-            base + (peak - base) * (-(w - center).powi(2) / (2.0 * width * width)).exp()
+            base + (peak - base) * ops::exp(-(w - center).powi(2) / (2.0 * width * width))
         }).collect();
 
         SpectralMeasurement::new("Silicon", MaterialCategory::Semiconductor, wavelengths, reflectance)
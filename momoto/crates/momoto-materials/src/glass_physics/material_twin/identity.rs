@@ -5,6 +5,8 @@
 use super::super::differentiable::DifferentiableBSDF;
 use super::super::unified_bsdf::{BSDFContext, Vector3};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // CONSTANTS
 // ============================================================================
@@ -99,7 +101,7 @@ impl SpectralSignature {
         }
 
         let cos_angle = dot / (norm_a.sqrt() * norm_b.sqrt());
-        cos_angle.clamp(-1.0, 1.0).acos()
+        ops::acos(cos_angle.clamp(-1.0, 1.0))
     }
 
     /// Get dominant wavelength (highest reflectance).
@@ -146,8 +148,8 @@ impl SpectralIdentity {
         for i in 0..IDENTITY_ANGLES {
             let angle_deg = (i as f64) * 5.0;
             let angle_rad = angle_deg.to_radians();
-            let cos_theta = angle_rad.cos();
-            let sin_theta = angle_rad.sin();
+            let cos_theta = ops::cos(angle_rad);
+            let sin_theta = ops::sin(angle_rad);
 
             // Create context for this angle
             let ctx = BSDFContext {
@@ -418,7 +420,7 @@ pub fn compute_spectral_distance(a: &SpectralIdentity, b: &SpectralIdentity) ->
     let f0_diff = (a.f0 - b.f0).abs();
 
     // Compute similarity (0-1)
-    let dist_factor = (-avg_dist * 10.0).exp();
+    let dist_factor = ops::exp(-avg_dist * 10.0);
     let sam_factor = (std::f64::consts::FRAC_PI_2 - avg_sam) / std::f64::consts::FRAC_PI_2;
     let f0_factor = 1.0 - f0_diff.min(1.0);
     let similarity = (dist_factor + sam_factor + f0_factor) / 3.0;
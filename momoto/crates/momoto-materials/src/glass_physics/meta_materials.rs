@@ -36,6 +36,8 @@ use serde::{Deserialize, Serialize};
 use super::unified_bsdf::{BSDF, BSDFContext, BSDFResponse, BSDFSample, Vector3};
 use super::anisotropic::Color;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // Material Reference (for composite materials)
 // ============================================================================
@@ -210,7 +212,7 @@ impl PhotonicCrystal {
         let width = self.bandgap_width();
 
         // Shift center with angle (Bragg's law)
-        let shifted_center = center * angle.cos();
+        let shifted_center = center * ops::cos(angle);
         let distance = (wavelength - shifted_center).abs();
 
         if distance < width / 2.0 {
@@ -245,7 +247,7 @@ impl PhotonicCrystal {
 impl BSDF for PhotonicCrystal {
     fn evaluate(&self, ctx: &BSDFContext) -> BSDFResponse {
         let cos_theta = ctx.cos_theta_i();
-        let angle = cos_theta.acos();
+        let angle = ops::acos(cos_theta);
         let wavelength = ctx.wavelength;
 
         let reflectance = self.reflectance_at(wavelength, angle);
@@ -409,7 +411,7 @@ impl StructuralColor {
                 self.evaluate_grating(*period, *depth, *profile, wavelength, cos_theta)
             }
             NanostructureType::PhotonicCrystal(pc) => {
-                let r = pc.reflectance_at(wavelength, cos_theta.acos());
+                let r = pc.reflectance_at(wavelength, ops::acos(cos_theta));
                 (r, 1.0 - r)
             }
             NanostructureType::MorphoButterfly => {
@@ -450,7 +452,7 @@ impl StructuralColor {
             let r = (r_s + r_p) / 2.0;
 
             // Interference
-            total_r += r * (1.0 + phase.cos()) / 2.0;
+            total_r += r * (1.0 + ops::cos(phase)) / 2.0;
         }
 
         let r = total_r.clamp(0.0, 1.0);
@@ -483,7 +485,7 @@ impl StructuralColor {
                 let efficiency = if phase.abs() < 0.01 {
                     1.0
                 } else {
-                    (phase.sin() / phase).powi(2)
+                    (ops::sin(phase) / phase).powi(2)
                 };
 
                 total_efficiency += efficiency / (m_max as f64 * 2.0 + 1.0);
@@ -507,11 +509,11 @@ impl StructuralColor {
 
         // Interference from multiple layers
         let n_layers = 10.0;
-        let coherence = (n_layers * phase / 2.0).sin() / (phase / 2.0).sin();
+        let coherence = ops::sin(n_layers * phase / 2.0) / ops::sin(phase / 2.0);
         let intensity = (coherence / n_layers).powi(2);
 
         // Blue selectivity
-        let wavelength_selectivity = (-(wavelength - peak_wavelength).powi(2) / 2000.0).exp();
+        let wavelength_selectivity = ops::exp(-(wavelength - peak_wavelength).powi(2) / 2000.0);
 
         let r = (intensity * wavelength_selectivity * 0.8).clamp(0.0, 0.8);
 
@@ -562,7 +564,7 @@ impl StructuralColor {
             let phase = 2.0 * PI * path / wavelength;
 
             let r = 0.04; // Fresnel at interface
-            total_r += r * (1.0 + phase.cos()) / 2.0;
+            total_r += r * (1.0 + ops::cos(phase)) / 2.0;
         }
 
         let r = total_r.clamp(0.0, 0.7);
@@ -649,11 +651,11 @@ impl DiffractionGrating {
 
     /// Calculate diffraction angle for order m.
     pub fn diffraction_angle(&self, wavelength: f64, incident_angle: f64, order: i32) -> Option<f64> {
-        let sin_i = incident_angle.sin();
+        let sin_i = ops::sin(incident_angle);
         let sin_m = sin_i + order as f64 * wavelength / self.period;
 
         if sin_m.abs() <= 1.0 {
-            Some(sin_m.asin())
+            Some(ops::asin(sin_m))
         } else {
             None
         }
@@ -664,7 +666,7 @@ impl DiffractionGrating {
         if let Some(diff_angle) = self.diffraction_angle(wavelength, incident_angle, order) {
             // Blaze efficiency
             let blaze_diff = (diff_angle - self.blaze_angle).abs();
-            let blaze_efficiency = (-blaze_diff.powi(2) / 0.1).exp();
+            let blaze_efficiency = ops::exp(-blaze_diff.powi(2) / 0.1);
 
             self.efficiency * blaze_efficiency / (order.abs() as f64 + 1.0)
         } else {
@@ -676,7 +678,7 @@ impl DiffractionGrating {
 impl BSDF for DiffractionGrating {
     fn evaluate(&self, ctx: &BSDFContext) -> BSDFResponse {
         let cos_theta = ctx.cos_theta_i();
-        let incident_angle = cos_theta.acos();
+        let incident_angle = ops::acos(cos_theta);
         let wavelength = ctx.wavelength;
 
         // Sum efficiency over diffraction orders
@@ -699,12 +701,12 @@ impl BSDF for DiffractionGrating {
     fn sample(&self, ctx: &BSDFContext, u1: f64, _u2: f64) -> BSDFSample {
         // Sample a diffraction order
         let order = (u1 * 7.0).floor() as i32 - 3;
-        let incident_angle = ctx.cos_theta_i().acos();
+        let incident_angle = ops::acos(ctx.cos_theta_i());
 
         let wo = if let Some(diff_angle) = self.diffraction_angle(ctx.wavelength, incident_angle, order) {
             // Create direction at diffraction angle
-            let cos_diff = diff_angle.cos();
-            let sin_diff = diff_angle.sin();
+            let cos_diff = ops::cos(diff_angle);
+            let sin_diff = ops::sin(diff_angle);
             Vector3::new(sin_diff, 0.0, cos_diff)
         } else {
             ctx.wo.reflect(&ctx.normal)
@@ -21,6 +21,8 @@
 use std::f64::consts::PI;
 use std::collections::HashMap;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // ELEMENT AND ALLOY DEFINITIONS
 // ============================================================================
@@ -216,13 +218,13 @@ impl OxidationKinetics {
     /// Calculate effective rate constant at temperature
     pub fn effective_k_parabolic(&self, temp_k: f64) -> f64 {
         let kb = 8.617e-5; // eV/K
-        self.k0_parabolic * (-self.activation_energy / (kb * temp_k)).exp()
+        self.k0_parabolic * ops::exp(-self.activation_energy / (kb * temp_k))
     }
 
     /// Calculate effective linear rate constant
     pub fn effective_k_linear(&self, temp_k: f64, humidity: f64) -> f64 {
         let kb = 8.617e-5;
-        let base = self.k0_linear * (-self.activation_energy / (kb * temp_k)).exp();
+        let base = self.k0_linear * ops::exp(-self.activation_energy / (kb * temp_k));
         base * (1.0 + (self.humidity_factor - 1.0) * humidity)
     }
 }
@@ -597,7 +599,7 @@ impl DynamicOxidizedMetal {
         let dx = match rate_law {
             RateLaw::Logarithmic => {
                 let x_max = self.kinetics.k_log *
-                    (1.0 + self.state.age_seconds / self.kinetics.tau_log).ln();
+                    ops::ln(1.0 + self.state.age_seconds / self.kinetics.tau_log);
                 (x_max - self.state.oxide_thickness).max(0.0).min(dt_seconds * 0.01)
             }
             RateLaw::Linear => {
@@ -654,7 +656,7 @@ impl DynamicOxidizedMetal {
 
             // Beer-Lambert transmission through layer
             let alpha = 4.0 * PI * layer.k / wavelength_nm;
-            let t_layer = (-alpha * layer.thickness).exp();
+            let t_layer = ops::exp(-alpha * layer.thickness);
 
             // Effective porosity adjustment
             let t_effective = t_layer * (1.0 - layer.porosity * 0.5);
@@ -732,7 +734,7 @@ impl OxidationSimulation {
         for hour in 0..24 {
             let t = hour as f64 * 3600.0;
             let temp = min_temp + (max_temp - min_temp) *
-                (0.5 + 0.5 * (2.0 * PI * hour as f64 / 24.0 - PI / 2.0).sin());
+                (0.5 + 0.5 * ops::sin(2.0 * PI * hour as f64 / 24.0 - PI / 2.0));
             schedule.push((t, temp, humidity));
         }
         Self {
@@ -34,6 +34,8 @@ use std::f64::consts::PI;
 use super::complex_ior::{Complex, ComplexIOR, SpectralComplexIOR};
 use super::thin_film::ThinFilm;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // DRUDE MODEL
 // ============================================================================
@@ -402,11 +404,11 @@ impl TempOxidizedMetal {
 
         // Absorption in oxide layer reduces reflectance
         // Thicker oxide (higher oxidation_level) = more absorption
-        let absorption = (-self.oxide.k * self.oxidation_level * oxide_film.thickness_nm * 0.01).exp();
+        let absorption = ops::exp(-self.oxide.k * self.oxidation_level * oxide_film.thickness_nm * 0.01);
 
         // Approximate interference effect (can shift wavelength response)
         let phase = oxide_film.phase_difference(wavelength_nm, cos_theta);
-        let interference = 1.0 + 0.1 * phase.cos(); // Small modulation
+        let interference = 1.0 + 0.1 * ops::cos(phase); // Small modulation
 
         // Combined reflectance: oxide + transmitted->metal->transmitted back
         // Absorption dampens the overall reflectance
@@ -502,7 +504,7 @@ pub mod oxidized_presets {
 
 /// Generate CSS for temperature-dependent metal effect
 pub fn to_css_temp_metal(metal: &TempOxidizedMetal, light_angle_deg: f64) -> String {
-    let cos_light = (light_angle_deg * PI / 180.0).cos().abs();
+    let cos_light = ops::cos(light_angle_deg * PI / 180.0).abs();
     let rgb = metal.effective_reflectance_rgb(cos_light);
 
     let r = (rgb[0] * 255.0).clamp(0.0, 255.0) as u8;
@@ -6,6 +6,8 @@
 use super::measurement::{Measurement, MeasurementId, MeasurementQuality, MeasurementSource, Uncertainty};
 use super::units::Unit;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // PROPAGATION METHODS
 // ============================================================================
@@ -254,7 +256,7 @@ impl UncertaintyPropagator {
         let normal = |mean: f64, std: f64, rng: &mut dyn FnMut() -> f64| {
             let u1 = rng();
             let u2 = rng();
-            let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+            let z = (-2.0 * ops::ln(u1)).sqrt() * ops::cos(2.0 * std::f64::consts::PI * u2);
             mean + std * z
         };
 
@@ -31,6 +31,8 @@ use std::f64::consts::PI;
 
 use super::mie_lut::{mie_fast, mie_asymmetry_g};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // SIZE DISTRIBUTIONS
 // ============================================================================
@@ -105,10 +107,10 @@ impl SizeDistribution {
                 if r <= 0.0 {
                     return 0.0;
                 }
-                let mu = geometric_mean_um.ln();
+                let mu = ops::ln(*geometric_mean_um);
                 let sigma = *geometric_std;
-                let x = (r.ln() - mu) / sigma;
-                (1.0 / (r * sigma * (2.0 * PI).sqrt())) * (-0.5 * x * x).exp()
+                let x = (ops::ln(r) - mu) / sigma;
+                (1.0 / (r * sigma * (2.0 * PI).sqrt())) * ops::exp(-0.5 * x * x)
             }
             Self::Gamma { shape, scale_um } => {
                 if r <= 0.0 {
@@ -117,7 +119,7 @@ impl SizeDistribution {
                 let alpha = *shape;
                 let beta = *scale_um;
                 let gamma_alpha = gamma_function(alpha);
-                (r.powf(alpha - 1.0) * (-r / beta).exp()) / (beta.powf(alpha) * gamma_alpha)
+                (ops::powf(r, alpha - 1.0) * ops::exp(-r / beta)) / (ops::powf(beta, alpha) * gamma_alpha)
             }
             Self::Bimodal { mode1, mode2, weight1 } => {
                 weight1 * mode1.pdf(r) + (1.0 - weight1) * mode2.pdf(r)
@@ -130,7 +132,7 @@ impl SizeDistribution {
         match self {
             Self::Monodisperse { radius_um } => *radius_um,
             Self::LogNormal { geometric_mean_um, geometric_std } => {
-                geometric_mean_um * (0.5 * geometric_std * geometric_std).exp()
+                geometric_mean_um * ops::exp(0.5 * geometric_std * geometric_std)
             }
             Self::Gamma { shape, scale_um } => shape * scale_um,
             Self::Bimodal { mode1, mode2, weight1 } => {
@@ -146,12 +148,12 @@ impl SizeDistribution {
         let max_r = mean * 5.0;
 
         // Log-space sampling
-        let log_min = min_r.ln();
-        let log_max = max_r.ln();
+        let log_min = ops::ln(min_r);
+        let log_max = ops::ln(max_r);
         let log_step = (log_max - log_min) / (num_samples - 1) as f64;
 
         (0..num_samples)
-            .map(|i| (log_min + i as f64 * log_step).exp())
+            .map(|i| ops::exp(log_min + i as f64 * log_step))
             .collect()
     }
 }
@@ -159,11 +161,11 @@ impl SizeDistribution {
 /// Simple gamma function approximation (Stirling)
 fn gamma_function(x: f64) -> f64 {
     if x < 0.5 {
-        PI / ((PI * x).sin() * gamma_function(1.0 - x))
+        PI / (ops::sin(PI * x) * gamma_function(1.0 - x))
     } else {
         // Stirling approximation
         let x_adj = x - 1.0;
-        (2.0 * PI / x_adj).sqrt() * (x_adj / std::f64::consts::E).powf(x_adj)
+        (2.0 * PI / x_adj).sqrt() * ops::powf(x_adj / std::f64::consts::E, x_adj)
     }
 }
 
@@ -351,7 +353,7 @@ pub fn anisotropic_phase(
     }
 
     // Anisotropic modulation
-    let modulation = 1.0 + params.anisotropy * (2.0 * phi).cos();
+    let modulation = 1.0 + params.anisotropy * ops::cos(2.0 * phi);
 
     isotropic_phase * modulation.max(0.0)
 }
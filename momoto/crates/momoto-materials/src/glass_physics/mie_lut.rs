@@ -35,6 +35,8 @@
 use std::f64::consts::PI;
 use std::sync::OnceLock;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // MIE PARAMETERS
 // ============================================================================
@@ -203,7 +205,7 @@ pub fn mie_efficiencies(size_param: f64, relative_ior: f64) -> (f64, f64) {
     let rho = 2.0 * x * (m - 1.0);
 
     // van de Hulst formulas
-    let q_ext = 2.0 - (4.0 / rho) * rho.sin() + (4.0 / (rho * rho)) * (1.0 - rho.cos());
+    let q_ext = 2.0 - (4.0 / rho) * ops::sin(rho) + (4.0 / (rho * rho)) * (1.0 - ops::cos(rho));
 
     // For non-absorbing particles: Q_sca ≈ Q_ext
     (q_ext.max(0.0), q_ext.max(0.0))
@@ -251,15 +253,15 @@ impl MieLUT {
         let mut g_table = Box::new([[0.0f32; 8]; 32]);
 
         // Log-spaced size parameters
-        let log_min = Self::SIZE_MIN.ln();
-        let log_max = Self::SIZE_MAX.ln();
+        let log_min = ops::ln(Self::SIZE_MIN);
+        let log_max = ops::ln(Self::SIZE_MAX);
         let log_step = (log_max - log_min) / (Self::SIZE_COUNT - 1) as f64;
 
         let ior_step = (Self::IOR_MAX - Self::IOR_MIN) / (Self::IOR_COUNT - 1) as f64;
         let angle_step = 2.0 / (Self::ANGLE_COUNT - 1) as f64;
 
         for i_size in 0..Self::SIZE_COUNT {
-            let size_param = (log_min + i_size as f64 * log_step).exp();
+            let size_param = ops::exp(log_min + i_size as f64 * log_step);
 
             for i_ior in 0..Self::IOR_COUNT {
                 let rel_ior = Self::IOR_MIN + i_ior as f64 * ior_step;
@@ -302,10 +304,10 @@ impl MieLUT {
         let cos_t = cos_theta.clamp(-1.0, 1.0);
 
         // Log-space interpolation for size
-        let log_min = Self::SIZE_MIN.ln();
-        let log_max = Self::SIZE_MAX.ln();
+        let log_min = ops::ln(Self::SIZE_MIN);
+        let log_max = ops::ln(Self::SIZE_MAX);
         let log_step = (log_max - log_min) / (Self::SIZE_COUNT - 1) as f64;
-        let log_x = x.ln();
+        let log_x = ops::ln(x);
 
         let i_size_f = (log_x - log_min) / log_step;
         let i_size_0 = (i_size_f.floor() as usize).min(Self::SIZE_COUNT - 2);
@@ -357,11 +359,11 @@ impl MieLUT {
         let x = size_param.clamp(Self::SIZE_MIN, Self::SIZE_MAX);
         let m = relative_ior.clamp(Self::IOR_MIN, Self::IOR_MAX);
 
-        let log_min = Self::SIZE_MIN.ln();
-        let log_max = Self::SIZE_MAX.ln();
+        let log_min = ops::ln(Self::SIZE_MIN);
+        let log_max = ops::ln(Self::SIZE_MAX);
         let log_step = (log_max - log_min) / (Self::SIZE_COUNT - 1) as f64;
 
-        let i_size_f = (x.ln() - log_min) / log_step;
+        let i_size_f = (ops::ln(x) - log_min) / log_step;
         let i_size_0 = (i_size_f.floor() as usize).min(Self::SIZE_COUNT - 2);
         let t_size = i_size_f - i_size_0 as f64;
 
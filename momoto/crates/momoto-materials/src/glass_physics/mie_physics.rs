@@ -20,6 +20,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // PARTICLE REPRESENTATION
 // ============================================================================
@@ -268,7 +270,7 @@ impl ParticleDynamics {
         let u2 = (*seed as f64) / (u64::MAX as f64);
 
         let u1 = u1.max(1e-10);
-        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+        (-2.0 * ops::ln(u1)).sqrt() * ops::cos(2.0 * PI * u2)
     }
 
     /// Update particle position and velocity for one time step
@@ -292,9 +294,9 @@ impl ParticleDynamics {
 
         // Turbulent velocity fluctuations
         if self.turbulence {
-            let eta_k = (self.medium.viscosity.powi(3) /
-                        (self.medium.density.powi(3) * self.epsilon_turb)).powf(0.25);
-            let v_rms = (self.epsilon_turb * eta_k).powf(1.0/3.0) * 1e6;
+            let eta_k = ops::powf(self.medium.viscosity.powi(3) /
+                        (self.medium.density.powi(3) * self.epsilon_turb), 0.25);
+            let v_rms = ops::powf(self.epsilon_turb * eta_k, 1.0/3.0) * 1e6;
 
             particle.velocity[0] += v_rms * Self::rand_normal(seed) * dt.sqrt();
             particle.velocity[1] += v_rms * Self::rand_normal(seed) * dt.sqrt();
@@ -330,7 +332,7 @@ impl ParticleDynamics {
         if dist < p1.radius + p2.radius {
             // Volume-conserving coalescence
             let v_total = p1.volume() + p2.volume();
-            let r_new = (3.0 * v_total / (4.0 * PI)).powf(1.0/3.0);
+            let r_new = ops::powf(3.0 * v_total / (4.0 * PI), 1.0/3.0);
 
             // Center of mass position
             let m1 = p1.volume();
@@ -405,13 +407,13 @@ impl ParticleEnsemble {
     ) {
         self.particles.clear();
 
-        let mu = geometric_mean.ln();
-        let sigma = geometric_std.ln();
+        let mu = ops::ln(geometric_mean);
+        let sigma = ops::ln(geometric_std);
 
         for _ in 0..n_particles {
             // Log-normal radius
             let z = ParticleDynamics::rand_normal(&mut self.seed);
-            let r = (mu + sigma * z).exp();
+            let r = ops::exp(mu + sigma * z);
 
             // Random position in domain
             let x = self.domain[0] + ParticleDynamics::rand_normal(&mut self.seed).abs()
@@ -490,8 +492,8 @@ impl ParticleEnsemble {
         sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
         let median = sorted[sorted.len() / 2];
 
-        let log_mean = radii.iter().map(|r| r.ln()).sum::<f64>() / n;
-        let log_var = radii.iter().map(|r| (r.ln() - log_mean).powi(2)).sum::<f64>() / n;
+        let log_mean = radii.iter().map(|&r| ops::ln(r)).sum::<f64>() / n;
+        let log_var = radii.iter().map(|&r| (ops::ln(r) - log_mean).powi(2)).sum::<f64>() / n;
 
         SizeStatistics {
             count: self.particles.len(),
@@ -500,8 +502,8 @@ impl ParticleEnsemble {
             median_radius: median,
             min_radius: sorted[0],
             max_radius: sorted[sorted.len() - 1],
-            geometric_mean: log_mean.exp(),
-            geometric_std: log_var.sqrt().exp(),
+            geometric_mean: ops::exp(log_mean),
+            geometric_std: ops::exp(log_var.sqrt()),
         }
     }
 }
@@ -643,7 +645,7 @@ impl ScatteringField {
 
     /// Get transmission along a ray
     pub fn transmission(&self, start: [f64; 3], direction: [f64; 3], max_distance: f64) -> f64 {
-        (-self.optical_depth(start, direction, max_distance)).exp()
+        ops::exp(-self.optical_depth(start, direction, max_distance))
     }
 }
 
@@ -672,7 +674,7 @@ pub fn mie_approximation(x: f64, m: f64) -> (f64, f64, f64) {
     let q_ext = if rho.abs() < 0.1 {
         2.0 * rho.powi(2) / 3.0
     } else {
-        2.0 - 4.0 * rho.sin() / rho + 4.0 * (1.0 - rho.cos()) / rho.powi(2)
+        2.0 - 4.0 * ops::sin(rho) / rho + 4.0 * (1.0 - ops::cos(rho)) / rho.powi(2)
     };
 
     // Scattering efficiency (assume no absorption for dielectric)
@@ -691,7 +693,7 @@ pub fn mie_approximation(x: f64, m: f64) -> (f64, f64, f64) {
 /// Henyey-Greenstein phase function
 pub fn henyey_greenstein(cos_theta: f64, g: f64) -> f64 {
     let g2 = g * g;
-    (1.0 - g2) / (4.0 * PI * (1.0 + g2 - 2.0 * g * cos_theta).powf(1.5))
+    (1.0 - g2) / (4.0 * PI * ops::powf(1.0 + g2 - 2.0 * g * cos_theta, 1.5))
 }
 
 /// Phase function for ensemble at a point
@@ -734,9 +736,9 @@ pub struct TurbulenceParams {
 impl TurbulenceParams {
     /// Create from dissipation rate and viscosity
     pub fn from_epsilon(epsilon: f64, viscosity: f64) -> Self {
-        let eta_k = (viscosity.powi(3) / epsilon).powf(0.25);
+        let eta_k = ops::powf(viscosity.powi(3) / epsilon, 0.25);
         let length_scale = eta_k * 100.0; // Rough estimate
-        let tke = (epsilon * length_scale).powf(2.0/3.0);
+        let tke = ops::powf(epsilon * length_scale, 2.0/3.0);
 
         Self {
             tke,
@@ -767,7 +769,7 @@ impl TurbulenceParams {
             self.tke
         } else if scale > self.eta_k {
             // Inertial subrange: Kolmogorov scaling
-            (self.epsilon * scale).powf(2.0/3.0)
+            ops::powf(self.epsilon * scale, 2.0/3.0)
         } else {
             // Dissipation range
             self.epsilon * scale.powi(2) / self.eta_k.powi(2)
@@ -857,7 +859,7 @@ pub fn to_css_scattering(field: &ScatteringField, depth: f64) -> String {
 
     // Optical depth
     let tau = avg_ext * depth;
-    let transmission = (-tau).exp();
+    let transmission = ops::exp(-tau);
 
     // Single-scattering albedo
     let albedo = if avg_ext > 1e-10 { avg_sca / avg_ext } else { 0.0 };
@@ -894,9 +896,9 @@ pub fn to_css_scattering_animation(
          100% {{ opacity: {:.3}; backdrop-filter: blur({:.1}px); }}\n\
          }}\n\
          animation: scatter_evolve {:.1}s ease-in-out forwards;",
-        1.0 - (-tau_i).exp(),
+        1.0 - ops::exp(-tau_i),
         (tau_i * 2.0).clamp(0.0, 10.0),
-        1.0 - (-tau_f).exp(),
+        1.0 - ops::exp(-tau_f),
         (tau_f * 2.0).clamp(0.0, 10.0),
         duration_s
     )
@@ -16,6 +16,8 @@ use serde::{Deserialize, Serialize};
 
 use super::unified_bsdf::{BSDF, BSDFContext, BSDFResponse, BSDFSample, EnergyValidation};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // CORRECTION INPUT/OUTPUT
 // ============================================================================
@@ -277,7 +279,7 @@ impl NeuralCorrectionMLP {
             for j in 0..Self::INPUT_DIM {
                 sum += self.w0[i * Self::INPUT_DIM + j] * x[j];
             }
-            h0[i] = (self.config.omega_0 * sum).sin();
+            h0[i] = ops::sin(self.config.omega_0 * sum);
         }
 
         // Layer 1: y = sin(W1 @ h0 + b1)
@@ -287,7 +289,7 @@ impl NeuralCorrectionMLP {
             for j in 0..hidden {
                 sum += self.w1[i * hidden + j] * h0[j];
             }
-            h1[i] = sum.sin();
+            h1[i] = ops::sin(sum);
         }
 
         // Output layer: y = tanh(W_out @ h1 + b_out) * max_correction
@@ -297,7 +299,7 @@ impl NeuralCorrectionMLP {
             for j in 0..hidden {
                 sum += self.w_out[i * hidden + j] * h1[j];
             }
-            out[i] = sum.tanh() * self.config.max_correction;
+            out[i] = ops::tanh(sum) * self.config.max_correction;
         }
 
         CorrectionOutput::new(out[0], out[1])
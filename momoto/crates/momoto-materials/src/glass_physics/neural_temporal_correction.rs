@@ -28,6 +28,8 @@ use super::neural_correction::{
 use super::unified_bsdf::{BSDF, BSDFContext, BSDFResponse, BSDFSample, EnergyValidation};
 use super::temporal::TemporalContext;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // TEMPORAL CORRECTION INPUT
 // ============================================================================
@@ -388,7 +390,7 @@ impl TemporalNeuralCorrection {
             for j in 0..Self::INPUT_DIM {
                 sum += self.w0[i * Self::INPUT_DIM + j] * x[j];
             }
-            h0[i] = (omega_0 * sum).sin();
+            h0[i] = ops::sin(omega_0 * sum);
         }
 
         // Layer 1: sin(W1 @ h0 + b1)
@@ -398,7 +400,7 @@ impl TemporalNeuralCorrection {
             for j in 0..hidden {
                 sum += self.w1[i * hidden + j] * h0[j];
             }
-            h1[i] = sum.sin();
+            h1[i] = ops::sin(sum);
         }
 
         // Output: tanh(W_out @ h1 + b_out) * max_correction
@@ -408,7 +410,7 @@ impl TemporalNeuralCorrection {
             for j in 0..hidden {
                 sum += self.w_out[i * hidden + j] * h1[j];
             }
-            out[i] = sum.tanh() * max_correction;
+            out[i] = ops::tanh(sum) * max_correction;
         }
 
         CorrectionOutput::new(out[0], out[1])
@@ -4,6 +4,8 @@
 
 use super::bsdf::BSDFContext;
 
+use momoto_core::math::ops;
+
 /// 3D direction vector.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vector3 {
@@ -129,8 +131,8 @@ impl EvaluationContext {
     /// Create for a specific incident angle.
     pub fn at_angle(theta_degrees: f64) -> Self {
         let theta = theta_degrees.to_radians();
-        let cos_theta = theta.cos();
-        let sin_theta = theta.sin();
+        let cos_theta = ops::cos(theta);
+        let sin_theta = ops::sin(theta);
 
         Self {
             wi: Vector3::new(sin_theta, 0.0, cos_theta),
@@ -24,6 +24,10 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+use momoto_core::space::lab::Lab;
+use momoto_core::space::xyz::{Illuminant as CoreIlluminant, Xyz};
+
 // ============================================================================
 // COLOR STRUCTURES
 // ============================================================================
@@ -51,12 +55,12 @@ impl LabColor {
 
     /// Hue angle in radians
     pub fn hue(&self) -> f64 {
-        self.b.atan2(self.a)
+        ops::atan2(self.b, self.a)
     }
 
     /// Hue angle in degrees (0-360)
     pub fn hue_degrees(&self) -> f64 {
-        let h = self.b.atan2(self.a) * 180.0 / PI;
+        let h = ops::atan2(self.b, self.a) * 180.0 / PI;
         if h < 0.0 { h + 360.0 } else { h }
     }
 }
@@ -87,6 +91,30 @@ impl Default for XyzColor {
     }
 }
 
+impl From<LabColor> for Lab {
+    fn from(lab: LabColor) -> Self {
+        Lab::new(lab.l, lab.a, lab.b)
+    }
+}
+
+impl From<Lab> for LabColor {
+    fn from(lab: Lab) -> Self {
+        LabColor::new(lab.l, lab.a, lab.b)
+    }
+}
+
+impl From<XyzColor> for Xyz {
+    fn from(xyz: XyzColor) -> Self {
+        Xyz::new(xyz.x, xyz.y, xyz.z)
+    }
+}
+
+impl From<Xyz> for XyzColor {
+    fn from(xyz: Xyz) -> Self {
+        XyzColor::new(xyz.x, xyz.y, xyz.z)
+    }
+}
+
 /// Reference white point (illuminant)
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Illuminant {
@@ -118,6 +146,12 @@ impl Default for Illuminant {
     }
 }
 
+impl From<Illuminant> for CoreIlluminant {
+    fn from(illuminant: Illuminant) -> Self {
+        CoreIlluminant::Custom(illuminant.xyz().into())
+    }
+}
+
 // ============================================================================
 // DELTA E FORMULAS
 // ============================================================================
@@ -144,7 +178,7 @@ pub fn srgb_to_linear(c: f64) -> f64 {
     if c <= 0.04045 {
         c / 12.92
     } else {
-        ((c + 0.055) / 1.055).powf(2.4)
+        ops::powf((c + 0.055) / 1.055, 2.4)
     }
 }
 
@@ -154,7 +188,7 @@ pub fn linear_to_srgb(c: f64) -> f64 {
     if c <= 0.0031308 {
         c * 12.92
     } else {
-        1.055 * c.powf(1.0 / 2.4) - 0.055
+        1.055 * ops::powf(c, 1.0 / 2.4) - 0.055
     }
 }
 
@@ -188,59 +222,14 @@ pub fn xyz_to_rgb(xyz: XyzColor) -> [f64; 3] {
     ]
 }
 
-/// LAB f function (cube root with linear segment)
-#[inline]
-fn lab_f(t: f64) -> f64 {
-    const DELTA: f64 = 6.0 / 29.0;
-    const DELTA_CUBED: f64 = DELTA * DELTA * DELTA;
-
-    if t > DELTA_CUBED {
-        t.cbrt()
-    } else {
-        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
-    }
-}
-
-/// Inverse LAB f function
-#[inline]
-fn lab_f_inv(t: f64) -> f64 {
-    const DELTA: f64 = 6.0 / 29.0;
-
-    if t > DELTA {
-        t * t * t
-    } else {
-        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
-    }
-}
-
 /// Convert CIE XYZ to CIE LAB
 pub fn xyz_to_lab(xyz: XyzColor, illuminant: Illuminant) -> LabColor {
-    let white = illuminant.xyz();
-
-    let fx = lab_f(xyz.x / white.x);
-    let fy = lab_f(xyz.y / white.y);
-    let fz = lab_f(xyz.z / white.z);
-
-    LabColor {
-        l: 116.0 * fy - 16.0,
-        a: 500.0 * (fx - fy),
-        b: 200.0 * (fy - fz),
-    }
+    Lab::from_xyz(xyz.into(), illuminant.into()).into()
 }
 
 /// Convert CIE LAB to CIE XYZ
 pub fn lab_to_xyz(lab: LabColor, illuminant: Illuminant) -> XyzColor {
-    let white = illuminant.xyz();
-
-    let fy = (lab.l + 16.0) / 116.0;
-    let fx = lab.a / 500.0 + fy;
-    let fz = fy - lab.b / 200.0;
-
-    XyzColor {
-        x: white.x * lab_f_inv(fx),
-        y: white.y * lab_f_inv(fy),
-        z: white.z * lab_f_inv(fz),
-    }
+    Lab::from(lab).to_xyz(illuminant.into()).into()
 }
 
 /// Convert sRGB to CIE LAB
@@ -259,10 +248,7 @@ pub fn lab_to_rgb(lab: LabColor, illuminant: Illuminant) -> [f64; 3] {
 
 /// CIE76 Delta E (classic Euclidean distance)
 pub fn delta_e_76(lab1: LabColor, lab2: LabColor) -> f64 {
-    let dl = lab1.l - lab2.l;
-    let da = lab1.a - lab2.a;
-    let db = lab1.b - lab2.b;
-    (dl * dl + da * da + db * db).sqrt()
+    Lab::from(lab1).delta_e_76(&lab2.into())
 }
 
 /// CIE94 Delta E (perceptual weighting for graphics)
@@ -295,111 +281,7 @@ pub fn delta_e_94(lab1: LabColor, lab2: LabColor) -> f64 {
 
 /// CIEDE2000 Delta E (industry standard, most accurate)
 pub fn delta_e_2000(lab1: LabColor, lab2: LabColor) -> f64 {
-    // Parametric weighting factors (standard values)
-    const KL: f64 = 1.0;
-    const KC: f64 = 1.0;
-    const KH: f64 = 1.0;
-
-    let l1 = lab1.l;
-    let a1 = lab1.a;
-    let b1 = lab1.b;
-    let l2 = lab2.l;
-    let a2 = lab2.a;
-    let b2 = lab2.b;
-
-    // Calculate C1, C2
-    let c1 = (a1 * a1 + b1 * b1).sqrt();
-    let c2 = (a2 * a2 + b2 * b2).sqrt();
-    let c_avg = (c1 + c2) / 2.0;
-
-    // Calculate G factor
-    let c_avg_7 = c_avg.powi(7);
-    let g = 0.5 * (1.0 - (c_avg_7 / (c_avg_7 + 6103515625.0)).sqrt()); // 25^7 = 6103515625
-
-    // Adjust a values
-    let a1_prime = a1 * (1.0 + g);
-    let a2_prime = a2 * (1.0 + g);
-
-    // Calculate C' values
-    let c1_prime = (a1_prime * a1_prime + b1 * b1).sqrt();
-    let c2_prime = (a2_prime * a2_prime + b2 * b2).sqrt();
-
-    // Calculate h' values
-    let h1_prime = if a1_prime == 0.0 && b1 == 0.0 {
-        0.0
-    } else {
-        let h = b1.atan2(a1_prime) * 180.0 / PI;
-        if h < 0.0 { h + 360.0 } else { h }
-    };
-
-    let h2_prime = if a2_prime == 0.0 && b2 == 0.0 {
-        0.0
-    } else {
-        let h = b2.atan2(a2_prime) * 180.0 / PI;
-        if h < 0.0 { h + 360.0 } else { h }
-    };
-
-    // Calculate differences
-    let dl_prime = l2 - l1;
-    let dc_prime = c2_prime - c1_prime;
-
-    let dh_prime = if c1_prime * c2_prime == 0.0 {
-        0.0
-    } else {
-        let diff = h2_prime - h1_prime;
-        if diff.abs() <= 180.0 {
-            diff
-        } else if diff > 180.0 {
-            diff - 360.0
-        } else {
-            diff + 360.0
-        }
-    };
-
-    let dh_prime_rad = dh_prime * PI / 180.0;
-    let dh_large = 2.0 * (c1_prime * c2_prime).sqrt() * (dh_prime_rad / 2.0).sin();
-
-    // Calculate averages
-    let l_avg = (l1 + l2) / 2.0;
-    let c_avg_prime = (c1_prime + c2_prime) / 2.0;
-
-    let h_avg_prime = if c1_prime * c2_prime == 0.0 {
-        h1_prime + h2_prime
-    } else {
-        let diff = (h1_prime - h2_prime).abs();
-        if diff <= 180.0 {
-            (h1_prime + h2_prime) / 2.0
-        } else if h1_prime + h2_prime < 360.0 {
-            (h1_prime + h2_prime + 360.0) / 2.0
-        } else {
-            (h1_prime + h2_prime - 360.0) / 2.0
-        }
-    };
-
-    // Calculate T
-    let h_rad = h_avg_prime * PI / 180.0;
-    let t = 1.0 - 0.17 * (h_rad - PI / 6.0).cos()
-        + 0.24 * (2.0 * h_rad).cos()
-        + 0.32 * (3.0 * h_rad + PI / 30.0).cos()
-        - 0.20 * (4.0 * h_rad - 63.0 * PI / 180.0).cos();
-
-    // Calculate weighting functions
-    let sl = 1.0 + (0.015 * (l_avg - 50.0).powi(2)) / (20.0 + (l_avg - 50.0).powi(2)).sqrt();
-    let sc = 1.0 + 0.045 * c_avg_prime;
-    let sh = 1.0 + 0.015 * c_avg_prime * t;
-
-    // Calculate RT (rotation term)
-    let dtheta = 30.0 * (-(((h_avg_prime - 275.0) / 25.0).powi(2))).exp();
-    let c_avg_prime_7 = c_avg_prime.powi(7);
-    let rc = 2.0 * (c_avg_prime_7 / (c_avg_prime_7 + 6103515625.0)).sqrt();
-    let rt = -rc * (2.0 * dtheta * PI / 180.0).sin();
-
-    // Calculate final Delta E
-    let term_l = dl_prime / (KL * sl);
-    let term_c = dc_prime / (KC * sc);
-    let term_h = dh_large / (KH * sh);
-
-    (term_l * term_l + term_c * term_c + term_h * term_h + rt * term_c * term_h).sqrt()
+    Lab::from(lab1).delta_e_2000(&lab2.into())
 }
 
 /// Calculate Delta E using specified formula
@@ -492,7 +374,7 @@ pub fn perceptual_loss(
         let lab_t = rgb_to_lab(*t, config.illuminant);
 
         let de = delta_e(lab_r, lab_t, config.formula);
-        total_loss += de.powf(config.exponent);
+        total_loss += ops::powf(de, config.exponent);
     }
 
     total_loss / rendered.len() as f64
@@ -531,7 +413,7 @@ pub fn perceptual_loss_weighted(
             + config.weight_chroma * dc
             + config.weight_hue * dh;
 
-        total_loss += loss.powf(config.exponent);
+        total_loss += ops::powf(loss, config.exponent);
     }
 
     total_loss / rendered.len() as f64
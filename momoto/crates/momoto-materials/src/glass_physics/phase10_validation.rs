@@ -22,6 +22,8 @@ use super::training_pipeline::total_training_pipeline_memory;
 use super::unified_bsdf::BSDFResponse;
 use super::perceptual_loss::{rgb_to_lab, delta_e_2000, Illuminant};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // VALIDATION REPORT STRUCTURES
 // ============================================================================
@@ -217,7 +219,7 @@ pub fn validate_perceptual_improvement(
     let avg_before = if n > 0.0 { total_before / n } else { 0.0 };
     let avg_after = if n > 0.0 { total_after / n } else { 0.0 };
     let improvement_db = if avg_after > 1e-10 {
-        20.0 * (avg_before / avg_after).log10()
+        20.0 * ops::log10(avg_before / avg_after)
     } else {
         f64::INFINITY
     };
@@ -23,6 +23,8 @@ use super::neural_temporal_correction::{
 use super::neural_correction::CorrectionOutput;
 use super::unified_bsdf::{BSDF, BSDFContext, DielectricBSDF};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // VALIDATION CONFIGURATION
 // ============================================================================
@@ -368,7 +370,7 @@ impl Phase12ValidationSuite {
                 let wavelength = packet.wavelengths[i];
                 let t = frame as f64 * 0.01;
                 // Smooth spectral curve with slow evolution
-                *v = 0.5 + 0.3 * ((wavelength - 500.0) / 100.0 + t).sin();
+                *v = 0.5 + 0.3 * ops::sin((wavelength - 500.0) / 100.0 + t);
             }
 
             validator.validate(&mut packet);
@@ -400,7 +402,7 @@ impl Phase12ValidationSuite {
             // Create varying spectral data
             for (i, v) in packet.values.iter_mut().enumerate() {
                 let phase = frame as f64 * 0.1 + i as f64 * 0.1;
-                *v = 0.5 + 0.4 * phase.sin();
+                *v = 0.5 + 0.4 * ops::sin(phase);
             }
 
             let result = interpolator.process(packet.clone());
@@ -494,8 +496,8 @@ impl Phase12ValidationSuite {
             let input = TemporalCorrectionInput::new(
                 super::neural_correction::CorrectionInput::new(
                     400.0 + i as f64 * 3.0,
-                    (i as f64 * 0.1).cos(),
-                    (i as f64 * 0.1).sin().abs(),
+                    ops::cos(i as f64 * 0.1),
+                    ops::sin(i as f64 * 0.1).abs(),
                     (i % 10) as f64 * 0.1,
                     1.0 + (i % 20) as f64 * 0.1,
                     0.0, 0.0, 0.0, 0.0, 0.0,
@@ -24,6 +24,8 @@ use super::mie_lut::{self, MieParams, particles};
 use super::thin_film::{self, presets as thin_film_presets};
 use super::fresnel::fresnel_schlick;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // COMPARISON RESULT TYPES
 // ============================================================================
@@ -98,7 +100,7 @@ pub fn compare_complex_vs_dielectric_fresnel() -> Vec<Phase3ComparisonResult> {
 
     for (name, metal, n_dielectric) in test_cases {
         let mut errors = Vec::new();
-        let angles: Vec<f64> = (0..90).step_by(10).map(|a| (a as f64).to_radians().cos()).collect();
+        let angles: Vec<f64> = (0..90).step_by(10).map(|a| ops::cos((a as f64).to_radians())).collect();
 
         // Time complex Fresnel
         let start_complex = Instant::now();
@@ -164,7 +166,7 @@ pub fn compare_metal_schlick_vs_full() -> Vec<Phase3ComparisonResult> {
     ];
 
     for (name, metal) in metals_list {
-        let angles: Vec<f64> = (0..90).step_by(5).map(|a| (a as f64).to_radians().cos()).collect();
+        let angles: Vec<f64> = (0..90).step_by(5).map(|a| ops::cos((a as f64).to_radians())).collect();
         let mut errors = Vec::new();
 
         // Time full Fresnel
@@ -366,7 +368,7 @@ pub fn benchmark_thin_film() -> Vec<Phase3ComparisonResult> {
 
     let film = thin_film_presets::SOAP_BUBBLE_MEDIUM;
     let n_substrate = 1.0;
-    let angles: Vec<f64> = (0..90).step_by(10).map(|a| (a as f64).to_radians().cos()).collect();
+    let angles: Vec<f64> = (0..90).step_by(10).map(|a| ops::cos((a as f64).to_radians())).collect();
     let wavelengths = [450.0, 550.0, 650.0];
 
     // Time single wavelength
@@ -35,6 +35,8 @@ use super::mie_dynamic::{
 };
 use super::thin_film::ThinFilm;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // LUT COMPRESSION BENCHMARKS
 // ============================================================================
@@ -257,7 +259,7 @@ pub fn compare_thin_film_methods() -> Vec<ThinFilmComparisonResult> {
         let angle: f64 = 0.0;
 
         let start_simple = Instant::now();
-        let simple_rgb = simple.reflectance_rgb(1.5, angle.to_radians().cos());
+        let simple_rgb = simple.reflectance_rgb(1.5, ops::cos(angle.to_radians()));
         let simple_time = start_simple.elapsed().as_nanos() as f64;
 
         let start_tm = Instant::now();
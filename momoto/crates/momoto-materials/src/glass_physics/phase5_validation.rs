@@ -29,6 +29,8 @@ use super::mie_physics::{
     ScatteringField, mie_approximation, ensemble_presets,
 };
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // DIFFERENTIABLE RENDERING VALIDATION
 // ============================================================================
@@ -399,7 +401,7 @@ pub fn validate_mie_physics() -> ValidationResult {
 
         result.add_check(
             "Beer-Lambert transmission",
-            (transmission - (-tau).exp()).abs() < 1e-6,
+            (transmission - ops::exp(-tau)).abs() < 1e-6,
             format!("τ = {:.4}, T = {:.4}", tau, transmission),
         );
     }
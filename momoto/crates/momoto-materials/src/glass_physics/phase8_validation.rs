@@ -18,6 +18,8 @@ use super::plugin_api::{
 use std::collections::HashMap;
 use std::time::Instant;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // Benchmark Results
 // ============================================================================
@@ -188,7 +190,7 @@ pub fn benchmark_reference_accuracy() -> LutVsReferenceResults {
 
     for &ior in &test_iors {
         for &angle in &test_angles {
-            let cos_theta = angle.cos();
+            let cos_theta = ops::cos(angle);
 
             // Reference computation
             let start = Instant::now();
@@ -396,7 +398,7 @@ pub fn benchmark_plugin_overhead() -> PluginOverheadResults {
     for _ in 0..iterations {
         let ior: f64 = 1.5;
         let theta: f64 = 0.5;
-        let cos_theta = theta.cos();
+        let cos_theta = ops::cos(theta);
         let f0 = ((ior - 1.0) / (ior + 1.0)).powi(2);
         let _fresnel = f0 + (1.0 - f0) * (1.0 - cos_theta).powi(5);
     }
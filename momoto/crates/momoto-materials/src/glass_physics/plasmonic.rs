@@ -37,6 +37,8 @@ use serde::{Deserialize, Serialize};
 use super::unified_bsdf::{BSDF, BSDFContext, BSDFResponse, BSDFSample, Vector3};
 use super::anisotropic::Color;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // Metal Types
 // ============================================================================
@@ -181,7 +183,7 @@ impl ParticleShape {
                     // Prolate spheroid
                     let e = (1.0 - 1.0 / (r * r)).sqrt();
                     let l_long = (1.0 - e * e) / (e * e)
-                        * (((1.0 + e) / (1.0 - e)).ln() / (2.0 * e) - 1.0);
+                        * (ops::ln((1.0 + e) / (1.0 - e)) / (2.0 * e) - 1.0);
                     let l_trans = (1.0 - l_long) / 2.0;
                     (l_long, l_trans)
                 } else {
@@ -382,11 +384,11 @@ fn wavelength_to_xyz(wavelength: f64) -> (f64, f64, f64) {
     let t2 = (wavelength - 599.8) * (if wavelength < 599.8 { 0.0264 } else { 0.0323 });
     let t3 = (wavelength - 501.1) * (if wavelength < 501.1 { 0.0490 } else { 0.0382 });
 
-    let x = 0.362 * (-0.5 * t1 * t1).exp() + 1.056 * (-0.5 * t2 * t2).exp() - 0.065 * (-0.5 * t3 * t3).exp();
-    let y = 0.821 * (-0.5 * ((wavelength - 568.8) * 0.0213).powi(2)).exp()
-        + 0.286 * (-0.5 * ((wavelength - 530.9) * 0.0613).powi(2)).exp();
-    let z = 1.217 * (-0.5 * ((wavelength - 437.0) * 0.0845).powi(2)).exp()
-        + 0.681 * (-0.5 * ((wavelength - 459.0) * 0.0385).powi(2)).exp();
+    let x = 0.362 * ops::exp(-0.5 * t1 * t1) + 1.056 * ops::exp(-0.5 * t2 * t2) - 0.065 * ops::exp(-0.5 * t3 * t3);
+    let y = 0.821 * ops::exp(-0.5 * ((wavelength - 568.8) * 0.0213).powi(2))
+        + 0.286 * ops::exp(-0.5 * ((wavelength - 530.9) * 0.0613).powi(2));
+    let z = 1.217 * ops::exp(-0.5 * ((wavelength - 437.0) * 0.0845).powi(2))
+        + 0.681 * ops::exp(-0.5 * ((wavelength - 459.0) * 0.0385).powi(2));
 
     (x.max(0.0), y.max(0.0), z.max(0.0))
 }
@@ -429,8 +431,8 @@ impl BSDF for PlasmonicNanoparticle {
         let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
 
         let wo = Vector3::new(
-            sin_theta * phi.cos(),
-            sin_theta * phi.sin(),
+            sin_theta * ops::cos(phi),
+            sin_theta * ops::sin(phi),
             cos_theta,
         );
 
@@ -589,7 +591,7 @@ impl BSDF for PlasmonicFilm {
         let ext = self.total_extinction(wavelength);
 
         // Beer-Lambert transmission
-        let transmission = (-ext).exp();
+        let transmission = ops::exp(-ext);
 
         // Some scattering from particles
         let scattering = (1.0 - transmission) * 0.1;
@@ -610,7 +612,7 @@ impl BSDF for PlasmonicFilm {
             let phi = 2.0 * PI * u1;
             let cos_theta = 1.0 - 2.0 * u1;
             let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
-            Vector3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta)
+            Vector3::new(sin_theta * ops::cos(phi), sin_theta * ops::sin(phi), cos_theta)
         } else {
             // Transmit
             -ctx.wo
@@ -11,6 +11,8 @@
 
 use std::collections::HashMap;
 
+use momoto_core::math::ops;
+
 /// Plugin API version - semver (major, minor, patch)
 pub const PLUGIN_API_VERSION: (u32, u32, u32) = (1, 0, 0);
 
@@ -798,7 +800,7 @@ impl MetricPlugin for SamMetricPlugin {
         }
 
         let cos_angle = (dot / (mag_m * mag_r)).clamp(-1.0, 1.0);
-        cos_angle.acos()
+        ops::acos(cos_angle)
     }
 
     fn description(&self) -> &str {
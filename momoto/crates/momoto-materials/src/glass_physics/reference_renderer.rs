@@ -37,6 +37,8 @@
 use std::f64::consts::PI;
 use std::time::Instant;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // CONFIGURATION
 // ============================================================================
@@ -620,7 +622,7 @@ pub fn compare_fresnel_approximations(
         let error = (schlick - full).abs();
         if error > max_error {
             max_error = error;
-            worst_angle = cos_theta.acos().to_degrees();
+            worst_angle = ops::acos(cos_theta).to_degrees();
         }
     }
 
@@ -702,7 +704,7 @@ pub fn fresnel_conductor_full(cos_theta_i: f64, n: f64, k: f64) -> f64 {
 pub fn beer_lambert_exact(absorption_coeff: f64, thickness: f64, cos_theta: f64) -> f64 {
     let cos_t = cos_theta.clamp(0.001, 1.0);
     let path_length = thickness / cos_t;
-    (-absorption_coeff * path_length).exp()
+    ops::exp(-absorption_coeff * path_length)
 }
 
 // ============================================================================
@@ -735,8 +737,8 @@ pub fn thin_film_transfer_matrix(
     let r12 = (n_film * cos_film - n_substrate) / (n_film * cos_film + n_substrate);
 
     // Multiple reflection formula (Airy formula)
-    let numerator = r01 * r01 + r12 * r12 + 2.0 * r01 * r12 * (2.0 * delta).cos();
-    let denominator = 1.0 + r01 * r01 * r12 * r12 + 2.0 * r01 * r12 * (2.0 * delta).cos();
+    let numerator = r01 * r01 + r12 * r12 + 2.0 * r01 * r12 * ops::cos(2.0 * delta);
+    let denominator = 1.0 + r01 * r01 * r12 * r12 + 2.0 * r01 * r12 * ops::cos(2.0 * delta);
 
     (numerator / denominator).clamp(0.0, 1.0)
 }
@@ -757,7 +759,7 @@ pub fn thin_film_simple(
     let f0 = ((n_film - 1.0) / (n_film + 1.0)).powi(2);
 
     // Interference modulation
-    let modulation = 0.5 * (1.0 + phase.cos());
+    let modulation = 0.5 * (1.0 + ops::cos(phase));
 
     f0 * modulation
 }
@@ -798,7 +800,7 @@ pub fn thin_film_stack_transfer_matrix(
         let delta = 2.0 * PI * n_layer * thickness * cos_layer / wavelength_nm;
 
         // Accumulate (simplified - full transfer matrix would use 2x2 matrices)
-        total_r += r_interface.powi(2) * (2.0 * phase_acc).cos();
+        total_r += r_interface.powi(2) * ops::cos(2.0 * phase_acc);
         phase_acc += delta;
 
         n_prev = n_layer;
@@ -809,7 +811,7 @@ pub fn thin_film_stack_transfer_matrix(
     if sin_sub < 1.0 {
         let cos_sub = (1.0 - sin_sub * sin_sub).sqrt();
         let r_final = (n_prev - n_substrate * cos_sub) / (n_prev + n_substrate * cos_sub);
-        total_r += r_final.powi(2) * (2.0 * phase_acc).cos();
+        total_r += r_final.powi(2) * ops::cos(2.0 * phase_acc);
     }
 
     total_r.clamp(0.0, 1.0)
@@ -828,7 +830,7 @@ pub fn henyey_greenstein_exact(g: f64, cos_theta: f64) -> f64 {
         return 0.0;
     }
 
-    (1.0 - g2) / (4.0 * PI * denom.powf(1.5))
+    (1.0 - g2) / (4.0 * PI * ops::powf(denom, 1.5))
 }
 
 // ============================================================================
@@ -842,9 +844,9 @@ fn cie_x_bar(wavelength: f64) -> f64 {
     let t2 = (wavelength - 599.8) * if wavelength < 599.8 { 0.0264 } else { 0.0323 };
     let t3 = (wavelength - 501.1) * if wavelength < 501.1 { 0.0490 } else { 0.0382 };
 
-    0.362 * (-0.5 * t1 * t1).exp()
-        + 1.056 * (-0.5 * t2 * t2).exp()
-        - 0.065 * (-0.5 * t3 * t3).exp()
+    0.362 * ops::exp(-0.5 * t1 * t1)
+        + 1.056 * ops::exp(-0.5 * t2 * t2)
+        - 0.065 * ops::exp(-0.5 * t3 * t3)
 }
 
 /// CIE 1931 2-degree observer y-bar function
@@ -852,8 +854,8 @@ fn cie_y_bar(wavelength: f64) -> f64 {
     let t1 = (wavelength - 568.8) * if wavelength < 568.8 { 0.0213 } else { 0.0247 };
     let t2 = (wavelength - 530.9) * if wavelength < 530.9 { 0.0613 } else { 0.0322 };
 
-    0.821 * (-0.5 * t1 * t1).exp()
-        + 0.286 * (-0.5 * t2 * t2).exp()
+    0.821 * ops::exp(-0.5 * t1 * t1)
+        + 0.286 * ops::exp(-0.5 * t2 * t2)
 }
 
 /// CIE 1931 2-degree observer z-bar function
@@ -861,8 +863,8 @@ fn cie_z_bar(wavelength: f64) -> f64 {
     let t1 = (wavelength - 437.0) * if wavelength < 437.0 { 0.0845 } else { 0.0278 };
     let t2 = (wavelength - 459.0) * if wavelength < 459.0 { 0.0385 } else { 0.0725 };
 
-    1.217 * (-0.5 * t1 * t1).exp()
-        + 0.681 * (-0.5 * t2 * t2).exp()
+    1.217 * ops::exp(-0.5 * t1 * t1)
+        + 0.681 * ops::exp(-0.5 * t2 * t2)
 }
 
 /// Convert XYZ to sRGB
@@ -878,7 +880,7 @@ fn xyz_to_srgb(xyz: [f64; 3]) -> [f64; 3] {
         if x <= 0.0031308 {
             12.92 * x
         } else {
-            1.055 * x.powf(1.0 / 2.4) - 0.055
+            1.055 * ops::powf(x, 1.0 / 2.4) - 0.055
         }
     }
 
@@ -26,6 +26,7 @@
 //! Real glass has n ≈ 1.5-1.9, but perceptually we can use lower values
 //! (1.05-1.3) that give the "feel" of glass without extreme distortion.
 
+use momoto_core::math::ops;
 use momoto_core::space::oklch::OKLCH;
 
 /// Refraction parameters for perceptual light bending
@@ -107,8 +108,8 @@ pub fn calculate_refraction(
     let angle_rad = angle.to_radians();
 
     // sin(θ₂) = (n₁/n₂) × sin(θ₁)
-    let sin_refracted = (n1 / n2) * angle_rad.sin();
-    let refracted_angle = sin_refracted.asin();
+    let sin_refracted = (n1 / n2) * ops::sin(angle_rad);
+    let refracted_angle = ops::asin(sin_refracted);
 
     // Angular deviation
     let deviation = angle_rad - refracted_angle;
@@ -119,7 +120,7 @@ pub fn calculate_refraction(
     let edge_factor = 1.0 + params.edge_lensing * edge_distance;
 
     // 3. Calculate displacement
-    let base_displacement = deviation.tan() * params.distortion_strength;
+    let base_displacement = ops::tan(deviation) * params.distortion_strength;
     let offset_x = base_displacement * edge_factor * (pos_x - 0.5).signum();
     let offset_y = base_displacement * edge_factor * (pos_y - 0.5).signum();
 
@@ -146,8 +147,8 @@ pub fn calculate_refraction(
 /// Fresnel equations describe how much light is reflected vs transmitted
 /// based on incident angle. This affects apparent brightness through glass.
 fn calculate_fresnel_brightness(n1: f64, n2: f64, incident_angle: f64) -> f64 {
-    let cos_i = incident_angle.cos();
-    let sin_i = incident_angle.sin();
+    let cos_i = ops::cos(incident_angle);
+    let sin_i = ops::sin(incident_angle);
 
     // Snell's law for transmitted angle
     let sin_t = (n1 / n2) * sin_i;
@@ -11,6 +11,8 @@
 
 use std::collections::HashMap;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // Forward Function Trait
 // ============================================================================
@@ -442,7 +444,7 @@ impl MaterialForwardFunction {
         let f0 = ((ior - 1.0) / (ior + 1.0)).powi(2);
 
         // Beer-Lambert absorption
-        let transmitted = (-absorption * (1.0 - color_factor)).exp();
+        let transmitted = ops::exp(-absorption * (1.0 - color_factor));
 
         // Mix reflection and transmission
         f0 * (1.0 - transmission) + transmission * transmitted * color_factor
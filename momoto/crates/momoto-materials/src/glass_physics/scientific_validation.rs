@@ -23,6 +23,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // Statistical Metrics
 // ============================================================================
@@ -145,8 +147,8 @@ impl ValidationMetrics {
 
 /// Exact Fresnel reflectance for dielectric-dielectric interface
 pub fn fresnel_dielectric_exact(n1: f64, n2: f64, theta_i: f64) -> (f64, f64, f64) {
-    let cos_i = theta_i.cos();
-    let sin_i = theta_i.sin();
+    let cos_i = ops::cos(theta_i);
+    let sin_i = ops::sin(theta_i);
 
     // Snell's law
     let sin_t = n1 / n2 * sin_i;
@@ -174,8 +176,8 @@ pub fn fresnel_dielectric_exact(n1: f64, n2: f64, theta_i: f64) -> (f64, f64, f6
 
 /// Fresnel reflectance for conductor (using complex IOR)
 pub fn fresnel_conductor_exact(n: f64, k: f64, theta_i: f64) -> f64 {
-    let cos_i = theta_i.cos();
-    let sin_i = theta_i.sin();
+    let cos_i = ops::cos(theta_i);
+    let sin_i = ops::sin(theta_i);
     let sin_i_sqr = sin_i * sin_i;
 
     // Complex refractive index: n + ik
@@ -224,9 +226,9 @@ pub fn airy_thin_film_reflectance(
     let r01 = r01.sqrt(); // amplitude
 
     // Snell's law for angle in film
-    let sin_t1 = n0 / n1 * theta_i.sin();
+    let sin_t1 = n0 / n1 * ops::sin(theta_i);
     let theta_t1 = if sin_t1.abs() < 1.0 {
-        sin_t1.asin()
+        ops::asin(sin_t1)
     } else {
         return 1.0; // TIR
     };
@@ -235,7 +237,7 @@ pub fn airy_thin_film_reflectance(
     let r12 = r12.sqrt(); // amplitude
 
     // Phase difference from optical path
-    let optical_path = 2.0 * n1 * thickness_nm * theta_t1.cos();
+    let optical_path = 2.0 * n1 * thickness_nm * ops::cos(theta_t1);
     let delta = 2.0 * PI * optical_path / wavelength_nm;
 
     // Airy formula for intensity reflectance
@@ -243,8 +245,8 @@ pub fn airy_thin_film_reflectance(
     let r2_sqr = r12 * r12;
     let r1r2 = r01 * r12;
 
-    let numerator = r1_sqr + r2_sqr + 2.0 * r1r2 * delta.cos();
-    let denominator = 1.0 + r1_sqr * r2_sqr + 2.0 * r1r2 * delta.cos();
+    let numerator = r1_sqr + r2_sqr + 2.0 * r1r2 * ops::cos(delta);
+    let denominator = 1.0 + r1_sqr * r2_sqr + 2.0 * r1r2 * ops::cos(delta);
 
     (numerator / denominator).min(1.0).max(0.0)
 }
@@ -275,22 +277,22 @@ pub fn transfer_matrix_multilayer(
         let thickness = thicknesses_nm[i - 1];
 
         // Snell's law
-        let sin_theta = n_prev / n_curr * theta_prev.sin();
+        let sin_theta = n_prev / n_curr * ops::sin(theta_prev);
         let theta_curr = if sin_theta.abs() < 1.0 {
-            sin_theta.asin()
+            ops::asin(sin_theta)
         } else {
             return 1.0; // TIR
         };
 
         // Phase thickness
-        let beta = 2.0 * PI * n_curr * thickness * theta_curr.cos() / wavelength_nm;
+        let beta = 2.0 * PI * n_curr * thickness * ops::cos(theta_curr) / wavelength_nm;
 
         // Characteristic matrix for this layer
-        let cos_beta = beta.cos();
-        let sin_beta = beta.sin();
+        let cos_beta = ops::cos(beta);
+        let sin_beta = ops::sin(beta);
 
         // For s-polarization
-        let eta = n_curr * theta_curr.cos();
+        let eta = n_curr * ops::cos(theta_curr);
 
         let p11 = cos_beta;
         let p12 = sin_beta / eta;
@@ -313,15 +315,15 @@ pub fn transfer_matrix_multilayer(
 
     // Final interface
     let n_sub = n_layers[n_layers.len() - 1];
-    let sin_theta_sub = n_layers[n_layers.len() - 2] / n_sub * theta_prev.sin();
+    let sin_theta_sub = n_layers[n_layers.len() - 2] / n_sub * ops::sin(theta_prev);
     let theta_sub = if sin_theta_sub.abs() < 1.0 {
-        sin_theta_sub.asin()
+        ops::asin(sin_theta_sub)
     } else {
         return 1.0;
     };
 
-    let eta0 = n0 * theta_i.cos();
-    let eta_sub = n_sub * theta_sub.cos();
+    let eta0 = n0 * ops::cos(theta_i);
+    let eta_sub = n_sub * ops::cos(theta_sub);
 
     let numerator = eta0 * m11 + eta0 * eta_sub * m12 - m21 - eta_sub * m22;
     let denominator = eta0 * m11 + eta0 * eta_sub * m12 + m21 + eta_sub * m22;
@@ -599,7 +601,7 @@ pub fn validate_fresnel_dielectric(tolerance: f64) -> PhenomenonValidation {
         reference.push(r_exact);
 
         // Momoto implementation (Schlick approximation)
-        let r_momoto = crate::glass_physics::fresnel::fresnel_schlick(n1, n2, theta.cos());
+        let r_momoto = crate::glass_physics::fresnel::fresnel_schlick(n1, n2, ops::cos(theta));
         measured.push(r_momoto);
     }
 
@@ -28,6 +28,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // CONFIGURATION
 // ============================================================================
@@ -341,7 +343,7 @@ pub fn fresnel_schlick_scalar(cos_theta: f64, ior: f64) -> f64 {
 /// Beer-Lambert transmittance (scalar)
 #[inline(always)]
 pub fn beer_lambert_scalar(absorption: f64, thickness: f64) -> f64 {
-    (-absorption * thickness).exp()
+    ops::exp(-absorption * thickness)
 }
 
 /// Henyey-Greenstein phase function (scalar)
@@ -10,6 +10,8 @@
 
 use super::packet::SpectralPacket;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // FLICKER STATUS
 // ============================================================================
@@ -250,10 +252,10 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
     let c2_prime = (a2_prime * a2_prime + b2 * b2).sqrt();
     let c_bar_prime = (c1_prime + c2_prime) / 2.0;
 
-    let h1_prime = b1.atan2(a1_prime).to_degrees();
+    let h1_prime = ops::atan2(b1, a1_prime).to_degrees();
     let h1_prime = if h1_prime < 0.0 { h1_prime + 360.0 } else { h1_prime };
 
-    let h2_prime = b2.atan2(a2_prime).to_degrees();
+    let h2_prime = ops::atan2(b2, a2_prime).to_degrees();
     let h2_prime = if h2_prime < 0.0 { h2_prime + 360.0 } else { h2_prime };
 
     // Calculate delta values
@@ -272,7 +274,7 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
     };
 
     let delta_h_prime_rad = 2.0 * (c1_prime * c2_prime).sqrt()
-        * (delta_h_prime.to_radians() / 2.0).sin();
+        * ops::sin(delta_h_prime.to_radians() / 2.0);
 
     // Calculate H'bar
     let h_bar_prime = if c1_prime * c2_prime == 0.0 {
@@ -287,10 +289,10 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
 
     // Calculate T
     let t = 1.0
-        - 0.17 * (h_bar_prime - 30.0).to_radians().cos()
-        + 0.24 * (2.0 * h_bar_prime).to_radians().cos()
-        + 0.32 * (3.0 * h_bar_prime + 6.0).to_radians().cos()
-        - 0.20 * (4.0 * h_bar_prime - 63.0).to_radians().cos();
+        - 0.17 * ops::cos((h_bar_prime - 30.0).to_radians())
+        + 0.24 * ops::cos((2.0 * h_bar_prime).to_radians())
+        + 0.32 * ops::cos((3.0 * h_bar_prime + 6.0).to_radians())
+        - 0.20 * ops::cos((4.0 * h_bar_prime - 63.0).to_radians());
 
     let l_bar_prime = (l1 + l2) / 2.0;
     let l_minus_50_sq = (l_bar_prime - 50.0).powi(2);
@@ -302,8 +304,8 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
 
     let c_bar_prime_7 = c_bar_prime.powi(7);
     let r_c = 2.0 * (c_bar_prime_7 / (c_bar_prime_7 + 25.0_f64.powi(7))).sqrt();
-    let delta_theta = 30.0 * (-((h_bar_prime - 275.0) / 25.0).powi(2)).exp();
-    let r_t = -r_c * (2.0 * delta_theta.to_radians()).sin();
+    let delta_theta = 30.0 * ops::exp(-((h_bar_prime - 275.0) / 25.0).powi(2));
+    let r_t = -r_c * ops::sin(2.0 * delta_theta.to_radians());
 
     // Final calculation
     let term1 = delta_l_prime / (k_l * s_l);
@@ -323,7 +325,7 @@ pub fn xyz_to_lab(xyz: [f64; 3]) -> [f64; 3] {
     let f = |t: f64| -> f64 {
         let delta: f64 = 6.0 / 29.0;
         if t > delta.powi(3) {
-            t.powf(1.0 / 3.0)
+            ops::powf(t, 1.0 / 3.0)
         } else {
             t / (3.0 * delta * delta) + 4.0 / 29.0
         }
@@ -347,7 +349,7 @@ pub fn rgb_to_xyz(rgb: [f64; 3]) -> [f64; 3] {
         if v <= 0.04045 {
             v / 12.92
         } else {
-            ((v + 0.055) / 1.055).powf(2.4)
+            ops::powf((v + 0.055) / 1.055, 2.4)
         }
     };
 
@@ -31,6 +31,8 @@
 // ============================================================================
 
 /// Comprehensive spectral error metrics
+use momoto_core::math::ops;
+
 #[derive(Debug, Clone)]
 pub struct SpectralErrorMetrics {
     /// Root Mean Square Error
@@ -354,7 +356,7 @@ pub fn compute_spectral_angle(a: &[f64], b: &[f64]) -> f64 {
 
     // Clamp to [-1, 1] for numerical stability
     let cos_angle = (dot / denom).clamp(-1.0, 1.0);
-    cos_angle.acos()
+    ops::acos(cos_angle)
 }
 
 /// Compute luminance-weighted RMSE (CIE Y weighting)
@@ -387,7 +389,7 @@ fn cie_y_weight(wavelength: f64) -> f64 {
     let t1 = (wavelength - 568.8) * if wavelength < 568.8 { 0.0213 } else { 0.0247 };
     let t2 = (wavelength - 530.9) * if wavelength < 530.9 { 0.0613 } else { 0.0322 };
 
-    0.821 * (-0.5 * t1 * t1).exp() + 0.286 * (-0.5 * t2 * t2).exp()
+    0.821 * ops::exp(-0.5 * t1 * t1) + 0.286 * ops::exp(-0.5 * t2 * t2)
 }
 
 /// Compute perceptual error metrics between two Lab colors
@@ -578,7 +580,7 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
     let h1_prime = if a1_prime.abs() < 1e-10 && b1.abs() < 1e-10 {
         0.0
     } else {
-        let h = b1.atan2(a1_prime).to_degrees();
+        let h = ops::atan2(b1, a1_prime).to_degrees();
         if h < 0.0 {
             h + 360.0
         } else {
@@ -589,7 +591,7 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
     let h2_prime = if a2_prime.abs() < 1e-10 && b2.abs() < 1e-10 {
         0.0
     } else {
-        let h = b2.atan2(a2_prime).to_degrees();
+        let h = ops::atan2(b2, a2_prime).to_degrees();
         if h < 0.0 {
             h + 360.0
         } else {
@@ -613,7 +615,7 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
     };
 
     let dh_prime_capital =
-        2.0 * (c1_prime * c2_prime).sqrt() * (dh_prime.to_radians() / 2.0).sin();
+        2.0 * (c1_prime * c2_prime).sqrt() * ops::sin(dh_prime.to_radians() / 2.0);
 
     let l_bar_prime = (l1 + l2) / 2.0;
     let c_bar_prime = (c1_prime + c2_prime) / 2.0;
@@ -628,10 +630,10 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
         (h1_prime + h2_prime - 360.0) / 2.0
     };
 
-    let t = 1.0 - 0.17 * (h_bar_prime - 30.0).to_radians().cos()
-        + 0.24 * (2.0 * h_bar_prime).to_radians().cos()
-        + 0.32 * (3.0 * h_bar_prime + 6.0).to_radians().cos()
-        - 0.20 * (4.0 * h_bar_prime - 63.0).to_radians().cos();
+    let t = 1.0 - 0.17 * ops::cos((h_bar_prime - 30.0).to_radians())
+        + 0.24 * ops::cos((2.0 * h_bar_prime).to_radians())
+        + 0.32 * ops::cos((3.0 * h_bar_prime + 6.0).to_radians())
+        - 0.20 * ops::cos((4.0 * h_bar_prime - 63.0).to_radians());
 
     let sl = 1.0
         + (0.015 * (l_bar_prime - 50.0).powi(2))
@@ -642,8 +644,8 @@ pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
     let c_bar_prime_7 = c_bar_prime.powi(7);
     let rc = 2.0 * (c_bar_prime_7 / (c_bar_prime_7 + 6103515625.0)).sqrt();
 
-    let dt = 30.0 * (-((h_bar_prime - 275.0) / 25.0).powi(2)).exp();
-    let rt = -rc * (2.0 * dt.to_radians()).sin();
+    let dt = 30.0 * ops::exp(-((h_bar_prime - 275.0) / 25.0).powi(2));
+    let rt = -rc * ops::sin(2.0 * dt.to_radians());
 
     let term_l = dl_prime / (k_l * sl);
     let term_c = dc_prime / (k_c * sc);
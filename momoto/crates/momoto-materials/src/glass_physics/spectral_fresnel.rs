@@ -28,6 +28,8 @@ use super::dispersion::{CauchyDispersion, Dispersion, f0_from_ior};
 use super::fresnel::fresnel_schlick;
 use super::lut::FresnelLUT;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // SPECTRAL FRESNEL CALCULATIONS
 // ============================================================================
@@ -101,7 +103,7 @@ pub fn edge_intensity_rgb<D: Dispersion>(
 ) -> [f64; 3] {
     let fresnel = fresnel_rgb(dispersion, cos_theta);
     let cos_clamped = cos_theta.clamp(0.0, 1.0);
-    let edge_factor = (1.0 - cos_clamped).powf(edge_power);
+    let edge_factor = ops::powf(1.0 - cos_clamped, edge_power);
 
     [
         fresnel[0] * edge_factor,
@@ -14,6 +14,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // LAB COLOR
 // ============================================================================
@@ -52,7 +54,7 @@ impl Lab {
 
     /// Hue angle in radians.
     pub fn hue(&self) -> f64 {
-        self.b.atan2(self.a)
+        ops::atan2(self.b, self.a)
     }
 
     /// Hue angle in degrees (0-360).
@@ -70,7 +72,7 @@ impl Lab {
 
         fn f(t: f64) -> f64 {
             if t > 0.008856 {
-                t.cbrt()
+                ops::cbrt(t)
             } else {
                 7.787 * t + 16.0 / 116.0
             }
@@ -204,14 +206,14 @@ pub fn delta_e_2000_full(
     let h1_prime = if c1_prime.abs() < 1e-10 {
         0.0
     } else {
-        let h = lab1.b.atan2(a1_prime) * 180.0 / PI;
+        let h = ops::atan2(lab1.b, a1_prime) * 180.0 / PI;
         if h < 0.0 { h + 360.0 } else { h }
     };
 
     let h2_prime = if c2_prime.abs() < 1e-10 {
         0.0
     } else {
-        let h = lab2.b.atan2(a2_prime) * 180.0 / PI;
+        let h = ops::atan2(lab2.b, a2_prime) * 180.0 / PI;
         if h < 0.0 { h + 360.0 } else { h }
     };
 
@@ -231,7 +233,7 @@ pub fn delta_e_2000_full(
         dh
     };
 
-    let delta_h = 2.0 * (c1_prime * c2_prime).sqrt() * (delta_h_prime * PI / 360.0).sin();
+    let delta_h = 2.0 * (c1_prime * c2_prime).sqrt() * ops::sin(delta_h_prime * PI / 360.0);
 
     // Step 3: Calculate CIEDE2000
     let l_bar = (lab1.l + lab2.l) / 2.0;
@@ -251,10 +253,10 @@ pub fn delta_e_2000_full(
     };
 
     let t = 1.0
-        - 0.17 * ((h_bar_prime - 30.0) * PI / 180.0).cos()
-        + 0.24 * ((2.0 * h_bar_prime) * PI / 180.0).cos()
-        + 0.32 * ((3.0 * h_bar_prime + 6.0) * PI / 180.0).cos()
-        - 0.20 * ((4.0 * h_bar_prime - 63.0) * PI / 180.0).cos();
+        - 0.17 * ops::cos((h_bar_prime - 30.0) * PI / 180.0)
+        + 0.24 * ops::cos((2.0 * h_bar_prime) * PI / 180.0)
+        + 0.32 * ops::cos((3.0 * h_bar_prime + 6.0) * PI / 180.0)
+        - 0.20 * ops::cos((4.0 * h_bar_prime - 63.0) * PI / 180.0);
 
     let l_bar_minus_50_sq = (l_bar - 50.0).powi(2);
     let sl = 1.0 + 0.015 * l_bar_minus_50_sq / (20.0 + l_bar_minus_50_sq).sqrt();
@@ -263,8 +265,8 @@ pub fn delta_e_2000_full(
 
     let c_bar_prime_7 = c_bar_prime.powi(7);
     let rc = 2.0 * (c_bar_prime_7 / (c_bar_prime_7 + 6103515625.0)).sqrt();
-    let delta_theta = 30.0 * (-((h_bar_prime - 275.0) / 25.0).powi(2)).exp();
-    let rt = -rc * (2.0 * delta_theta * PI / 180.0).sin();
+    let delta_theta = 30.0 * ops::exp(-((h_bar_prime - 275.0) / 25.0).powi(2));
+    let rt = -rc * ops::sin(2.0 * delta_theta * PI / 180.0);
 
     let delta_e = (
         (delta_l / (kl * sl)).powi(2)
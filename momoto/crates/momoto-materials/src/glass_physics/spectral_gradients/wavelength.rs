@@ -20,6 +20,8 @@
 // ============================================================================
 
 /// Number of spectral samples.
+use momoto_core::math::ops;
+
 pub const N_SPECTRAL_SAMPLES: usize = 31;
 
 /// Wavelength range start (nm).
@@ -163,7 +165,7 @@ fn luminous_efficiency(wavelength: f64) -> f64 {
     let center = 555.0;
     let sigma = 60.0;
     let diff = wavelength - center;
-    (-0.5 * (diff / sigma).powi(2)).exp()
+    ops::exp(-0.5 * (diff / sigma).powi(2))
 }
 
 // ============================================================================
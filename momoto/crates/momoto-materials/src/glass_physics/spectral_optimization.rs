@@ -13,6 +13,8 @@
 
 use super::spectral_pipeline::*;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // Quality Tiers
 // ============================================================================
@@ -319,14 +321,14 @@ fn gamma_expand(v: f64) -> f64 {
     if v <= 0.04045 {
         v / 12.92
     } else {
-        ((v + 0.055) / 1.055).powf(2.4)
+        ops::powf((v + 0.055) / 1.055, 2.4)
     }
 }
 
 fn lab_f(t: f64) -> f64 {
     let delta: f64 = 6.0 / 29.0;
     if t > delta.powi(3) {
-        t.cbrt()
+        ops::cbrt(t)
     } else {
         t / (3.0 * delta * delta) + 4.0 / 29.0
     }
@@ -20,6 +20,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // SPECTRAL CONSTANTS (GLOBAL STANDARD)
 // ============================================================================
@@ -120,7 +122,7 @@ impl SpectralSignal {
             // Planck-like + atmospheric filtering approximation
             let t = 6500.0; // Color temperature
             let x = (1.4388e7 / (w * t)).min(50.0);
-            let planck = 1.0 / (w.powi(5) * (x.exp() - 1.0));
+            let planck = 1.0 / (w.powi(5) * (ops::exp(x) - 1.0));
             // Normalize to peak at ~1.0
             planck * 1e20
         }).collect();
@@ -278,7 +280,7 @@ fn cie_1931_cmf(wavelength_nm: f64) -> (f64, f64, f64) {
 /// Asymmetric Gaussian helper for CMF approximation
 fn gaussian(x: f64, mu: f64, sigma1: f64, sigma2: f64) -> f64 {
     let sigma = if x < mu { sigma1 } else { sigma2 };
-    (-(x - mu).powi(2) / (2.0 * sigma.powi(2))).exp()
+    ops::exp(-(x - mu).powi(2) / (2.0 * sigma.powi(2)))
 }
 
 /// Convert CIE XYZ to sRGB (linear to gamma-corrected)
@@ -293,7 +295,7 @@ fn xyz_to_srgb(xyz: [f64; 3]) -> [f64; 3] {
         if u <= 0.0031308 {
             12.92 * u
         } else {
-            1.055 * u.powf(1.0 / 2.4) - 0.055
+            1.055 * ops::powf(u, 1.0 / 2.4) - 0.055
         }
     }
 
@@ -364,7 +366,7 @@ impl Default for EvaluationContext {
 
 impl EvaluationContext {
     pub fn with_angle_deg(mut self, angle_deg: f64) -> Self {
-        self.cos_theta = (angle_deg * PI / 180.0).cos();
+        self.cos_theta = ops::cos(angle_deg * PI / 180.0);
         self
     }
 
@@ -522,8 +524,8 @@ impl ThinFilmStage {
         let delta = 4.0 * PI * n2 * d * cos_theta2 / wavelength_nm;
 
         // Airy formula
-        let r_num = r12 * r12 + r23 * r23 + 2.0 * r12 * r23 * delta.cos();
-        let r_den = 1.0 + r12 * r12 * r23 * r23 + 2.0 * r12 * r23 * delta.cos();
+        let r_num = r12 * r12 + r23 * r23 + 2.0 * r12 * r23 * ops::cos(delta);
+        let r_den = 1.0 + r12 * r12 * r23 * r23 + 2.0 * r12 * r23 * ops::cos(delta);
 
         (r_num / r_den).clamp(0.0, 1.0)
     }
@@ -659,8 +661,8 @@ impl MieScatteringStage {
         } else {
             // Simplified Mie (Henyey-Greenstein approximation)
             let g = 0.85_f64.min(0.1 * x.sqrt());  // Asymmetry parameter
-            let hg = (1.0 - g * g) / (1.0 + g * g - 2.0 * g * cos_theta).powf(1.5);
-            let q_sca = 2.0 * (1.0 - (-0.1 * x).exp());  // Extinction efficiency
+            let hg = (1.0 - g * g) / ops::powf(1.0 + g * g - 2.0 * g * cos_theta, 1.5);
+            let q_sca = 2.0 * (1.0 - ops::exp(-0.1 * x));  // Extinction efficiency
             q_sca * hg / (4.0 * PI)
         }
     }
@@ -754,7 +756,7 @@ impl ThermoOpticStage {
 
         // Single surface reflectance modified by interference
         let base_r = r12 * r12;
-        base_r * (1.0 + 0.5 * delta.cos())  // Simplified interference term
+        base_r * (1.0 + 0.5 * ops::cos(delta))  // Simplified interference term
     }
 }
 
@@ -16,6 +16,8 @@ use std::f64::consts::PI;
 use super::perceptual_loss::{XyzColor, Illuminant, xyz_to_rgb};
 use super::combined_effects::CombinedMaterial;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // CONSTANTS
 // ============================================================================
@@ -509,7 +511,7 @@ pub fn spectral_angle(a: &[f64; WAVELENGTH_COUNT], b: &[f64; WAVELENGTH_COUNT])
         return PI / 2.0; // 90 degrees for zero vectors
     }
 
-    (dot / (mag_a * mag_b)).clamp(-1.0, 1.0).acos()
+    ops::acos((dot / (mag_a * mag_b)).clamp(-1.0, 1.0))
 }
 
 // ============================================================================
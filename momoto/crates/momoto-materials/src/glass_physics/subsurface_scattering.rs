@@ -32,6 +32,8 @@ use std::f64::consts::PI;
 use super::unified_bsdf::{BSDF, BSDFContext, BSDFResponse, BSDFSample, Vector3};
 use super::fresnel::fresnel_schlick;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // SUBSURFACE PARAMETERS
 // ============================================================================
@@ -228,8 +230,8 @@ impl DiffusionBSSRDF {
         let dv = (r * r + zv * zv).sqrt();
 
         // Dipole formula
-        let c1 = zr * (1.0 + sigma_tr * dr) * (-sigma_tr * dr).exp() / (dr * dr * dr);
-        let c2 = zv * (1.0 + sigma_tr * dv) * (-sigma_tr * dv).exp() / (dv * dv * dv);
+        let c1 = zr * (1.0 + sigma_tr * dr) * ops::exp(-sigma_tr * dr) / (dr * dr * dr);
+        let c2 = zv * (1.0 + sigma_tr * dv) * ops::exp(-sigma_tr * dv) / (dv * dv * dv);
 
         let rd = (c1 + c2) / (4.0 * PI);
 
@@ -250,7 +252,7 @@ impl DiffusionBSSRDF {
 
         // Simplified single-scattering
         let phase = henyey_greenstein(cos_theta_i * cos_theta_o, self.params.g);
-        let extinction = (-sigma_t * self.scale).exp();
+        let extinction = ops::exp(-sigma_t * self.scale);
 
         sigma_s * phase * extinction / (4.0 * PI)
     }
@@ -385,20 +387,20 @@ impl BSDF for SubsurfaceBSDF {
 
     fn sample(&self, ctx: &BSDFContext, u1: f64, u2: f64) -> BSDFSample {
         // Cosine-weighted hemisphere sampling
-        let theta = (1.0 - u1).sqrt().acos();
+        let theta = ops::acos((1.0 - u1).sqrt());
         let phi = 2.0 * PI * u2;
 
         let wo = Vector3::new(
-            theta.sin() * phi.cos(),
-            theta.sin() * phi.sin(),
-            theta.cos(),
+            ops::sin(theta) * ops::cos(phi),
+            ops::sin(theta) * ops::sin(phi),
+            ops::cos(theta),
         );
 
         let mut sample_ctx = ctx.clone();
         sample_ctx.wo = wo;
 
         let value = self.evaluate(&sample_ctx);
-        let pdf = theta.cos() / PI;
+        let pdf = ops::cos(theta) / PI;
 
         BSDFSample::new(wo, value, pdf.max(1e-10), false)
     }
@@ -529,7 +531,7 @@ fn approximate_rd(alpha: f64, eta: f64) -> f64 {
     let a = (1.0 + fdr) / (1.0 - fdr);
 
     let s = (3.0 * (1.0 - alpha)).sqrt();
-    let f = (1.0 + (-s / a).exp()) / (1.0 - (-s).exp());
+    let f = (1.0 + ops::exp(-s / a)) / (1.0 - ops::exp(-s));
 
     0.5 * alpha * f.min(1.0)
 }
@@ -5,6 +5,8 @@
 use super::context::TemporalContext;
 use super::super::unified_bsdf::{BSDF, BSDFResponse};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // TEMPORAL BSDF TRAIT
 // ============================================================================
@@ -42,12 +44,12 @@ impl EvolutionRate {
 
             EvolutionRate::Exponential { rate, asymptote } => {
                 // value = asymptote + (base - asymptote) * exp(-rate * time)
-                asymptote + (base_value - asymptote) * (-rate * time).exp()
+                asymptote + (base_value - asymptote) * ops::exp(-rate * time)
             }
 
             EvolutionRate::Oscillating { frequency, amplitude } => {
                 use std::f64::consts::TAU;
-                base_value + amplitude * (frequency * TAU * time).sin()
+                base_value + amplitude * ops::sin(frequency * TAU * time)
             }
 
             EvolutionRate::Step { threshold, before, after } => {
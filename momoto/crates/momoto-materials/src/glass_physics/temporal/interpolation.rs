@@ -9,6 +9,8 @@
 /// Smoothstep interpolation (cubic Hermite).
 ///
 /// Returns 0 for t <= 0, 1 for t >= 1, smooth curve in between.
+use momoto_core::math::ops;
+
 #[inline]
 pub fn smoothstep(t: f64) -> f64 {
     if t <= 0.0 {
@@ -243,7 +245,7 @@ impl RateLimiter {
             self.current = self.target;
         } else if self.config.smooth {
             // Smooth approach
-            let approach = 1.0 - (-dt * self.config.max_rate).exp();
+            let approach = 1.0 - ops::exp(-dt * self.config.max_rate);
             self.current += delta * approach;
         } else {
             // Linear clamp
@@ -304,7 +306,7 @@ impl ExponentialMovingAverage {
     /// Create EMA from time constant (tau in seconds).
     pub fn from_time_constant(tau: f64, dt: f64) -> Self {
         let alpha = if tau > 0.0 {
-            1.0 - (-dt / tau).exp()
+            1.0 - ops::exp(-dt / tau)
         } else {
             1.0
         };
@@ -10,6 +10,8 @@ use super::super::unified_bsdf::{
     DielectricBSDF, ConductorBSDF, ThinFilmBSDF,
 };
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // DIELECTRIC EVOLUTION
 // ============================================================================
@@ -94,7 +96,7 @@ impl TemporalDielectric {
     fn roughness_at(&self, time: f64) -> f64 {
         let e = &self.evolution;
         // Exponential approach to target: r(t) = target + (base - target) * exp(-t/tau)
-        e.roughness_target + (e.roughness_base - e.roughness_target) * (-time / e.roughness_tau).exp()
+        e.roughness_target + (e.roughness_base - e.roughness_target) * ops::exp(-time / e.roughness_tau)
     }
 
     /// Compute IOR at temperature.
@@ -242,12 +244,12 @@ impl TemporalThinFilm {
 
         // Damped oscillation: A * exp(-damping * t) * sin(2π * f * t)
         let envelope = if e.damping > 0.0 {
-            (-e.damping * time).exp()
+            ops::exp(-e.damping * time)
         } else {
             1.0
         };
 
-        let oscillation = (e.thickness_frequency * TAU * time).sin();
+        let oscillation = ops::sin(e.thickness_frequency * TAU * time);
         e.thickness_base + e.thickness_amplitude * envelope * oscillation
     }
 }
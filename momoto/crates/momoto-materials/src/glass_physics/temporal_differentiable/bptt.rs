@@ -25,6 +25,8 @@
 
 use super::evolution_gradients::{EvolutionGradients, EvolutionType, compute_evolution_gradient};
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // BPTT CONFIGURATION
 // ============================================================================
@@ -229,7 +231,7 @@ impl BPTT {
             // Temporal decay weight
             let time_weight = if self.config.temporal_decay > 0.0 {
                 let frames_from_end = (n_frames - 1 - i) as f64;
-                (-self.config.temporal_decay * frames_from_end).exp()
+                ops::exp(-self.config.temporal_decay * frames_from_end)
             } else {
                 1.0
             };
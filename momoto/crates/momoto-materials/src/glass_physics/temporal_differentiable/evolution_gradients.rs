@@ -14,6 +14,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // EVOLUTION GRADIENT TRAIT
 // ============================================================================
@@ -201,36 +203,36 @@ impl ExponentialEvolutionGradient {
     pub fn gradient_asymptote(&self, t: f64, _initial: f64) -> f64 {
         // ∂/∂asymp (asymp + (p₀ - asymp)×e^(-rt))
         // = 1 - e^(-rt)
-        let exp_term = (-self.rate * t).exp();
+        let exp_term = ops::exp(-self.rate * t);
         1.0 - exp_term
     }
 }
 
 impl EvolutionGradient for ExponentialEvolutionGradient {
     fn evaluate(&self, t: f64, initial: f64) -> f64 {
-        let exp_term = (-self.rate * t).exp();
+        let exp_term = ops::exp(-self.rate * t);
         self.asymptote + (initial - self.asymptote) * exp_term
     }
 
     fn gradient_initial(&self, t: f64) -> f64 {
         // ∂/∂p₀ = e^(-rt)
-        (-self.rate * t).exp()
+        ops::exp(-self.rate * t)
     }
 
     fn gradient_rate(&self, t: f64, initial: f64) -> f64 {
         // ∂/∂rate = -t × (p₀ - asymp) × e^(-rt)
-        let exp_term = (-self.rate * t).exp();
+        let exp_term = ops::exp(-self.rate * t);
         -t * (initial - self.asymptote) * exp_term
     }
 
     fn gradient_time(&self, t: f64, initial: f64) -> f64 {
         // ∂/∂t = -rate × (p₀ - asymp) × e^(-rt)
-        let exp_term = (-self.rate * t).exp();
+        let exp_term = ops::exp(-self.rate * t);
         -self.rate * (initial - self.asymptote) * exp_term
     }
 
     fn all_gradients(&self, t: f64, initial: f64) -> EvolutionGradients {
-        let exp_term = (-self.rate * t).exp();
+        let exp_term = ops::exp(-self.rate * t);
         let diff = initial - self.asymptote;
 
         EvolutionGradients {
@@ -278,25 +280,25 @@ impl OscillatingEvolutionGradient {
     /// Gradient w.r.t. amplitude.
     pub fn gradient_amplitude(&self, t: f64) -> f64 {
         // ∂/∂amp = sin(2πft + φ)
-        (2.0 * PI * self.frequency * t + self.phase).sin()
+        ops::sin(2.0 * PI * self.frequency * t + self.phase)
     }
 
     /// Gradient w.r.t. frequency.
     pub fn gradient_frequency(&self, t: f64) -> f64 {
         // ∂/∂freq = amp × 2π × t × cos(2πft + φ)
-        self.amplitude * 2.0 * PI * t * (2.0 * PI * self.frequency * t + self.phase).cos()
+        self.amplitude * 2.0 * PI * t * ops::cos(2.0 * PI * self.frequency * t + self.phase)
     }
 
     /// Gradient w.r.t. phase.
     pub fn gradient_phase(&self, t: f64) -> f64 {
         // ∂/∂φ = amp × cos(2πft + φ)
-        self.amplitude * (2.0 * PI * self.frequency * t + self.phase).cos()
+        self.amplitude * ops::cos(2.0 * PI * self.frequency * t + self.phase)
     }
 }
 
 impl EvolutionGradient for OscillatingEvolutionGradient {
     fn evaluate(&self, t: f64, initial: f64) -> f64 {
-        initial + self.amplitude * (2.0 * PI * self.frequency * t + self.phase).sin()
+        initial + self.amplitude * ops::sin(2.0 * PI * self.frequency * t + self.phase)
     }
 
     fn gradient_initial(&self, _t: f64) -> f64 {
@@ -311,13 +313,13 @@ impl EvolutionGradient for OscillatingEvolutionGradient {
 
     fn gradient_time(&self, t: f64, _initial: f64) -> f64 {
         // ∂/∂t = amp × 2π × freq × cos(2πft + φ)
-        self.amplitude * 2.0 * PI * self.frequency * (2.0 * PI * self.frequency * t + self.phase).cos()
+        self.amplitude * 2.0 * PI * self.frequency * ops::cos(2.0 * PI * self.frequency * t + self.phase)
     }
 
     fn all_gradients(&self, t: f64, _initial: f64) -> EvolutionGradients {
         let arg = 2.0 * PI * self.frequency * t + self.phase;
-        let sin_arg = arg.sin();
-        let cos_arg = arg.cos();
+        let sin_arg = ops::sin(arg);
+        let cos_arg = ops::cos(arg);
 
         EvolutionGradients {
             d_initial: 1.0,
@@ -365,7 +367,7 @@ impl LogarithmicEvolutionGradient {
 
 impl EvolutionGradient for LogarithmicEvolutionGradient {
     fn evaluate(&self, t: f64, initial: f64) -> f64 {
-        initial + self.rate * (1.0 + t / self.tau).ln()
+        initial + self.rate * ops::ln(1.0 + t / self.tau)
     }
 
     fn gradient_initial(&self, _t: f64) -> f64 {
@@ -374,7 +376,7 @@ impl EvolutionGradient for LogarithmicEvolutionGradient {
 
     fn gradient_rate(&self, t: f64, _initial: f64) -> f64 {
         // ∂/∂rate = ln(1 + t/τ)
-        (1.0 + t / self.tau).ln()
+        ops::ln(1.0 + t / self.tau)
     }
 
     fn gradient_time(&self, t: f64, _initial: f64) -> f64 {
@@ -383,7 +385,7 @@ impl EvolutionGradient for LogarithmicEvolutionGradient {
     }
 
     fn all_gradients(&self, t: f64, _initial: f64) -> EvolutionGradients {
-        let log_term = (1.0 + t / self.tau).ln();
+        let log_term = ops::ln(1.0 + t / self.tau);
 
         EvolutionGradients {
             d_initial: 1.0,
@@ -35,6 +35,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // THIN FILM PARAMETERS
 // ============================================================================
@@ -94,7 +96,7 @@ impl ThinFilm {
 
         // Phase difference
         let delta = self.phase_difference(wavelength_nm, cos_t);
-        let cos_delta = delta.cos();
+        let cos_delta = ops::cos(delta);
 
         // Airy formula for reflectance
         let r1_sq = r1 * r1;
@@ -290,7 +292,7 @@ pub fn to_css_iridescent_gradient(
     let mut stops = Vec::new();
 
     for (i, &angle_deg) in angles.iter().enumerate() {
-        let cos_theta = (angle_deg * PI / 180.0).cos();
+        let cos_theta = ops::cos(angle_deg * PI / 180.0);
         let (r, g, b) = thin_film_to_rgb(film, n_substrate, cos_theta);
         let position = (i as f64 / (angles.len() - 1) as f64) * 100.0;
 
@@ -411,7 +413,7 @@ impl ThinFilmStack {
             let phase = layer.phase_difference(wavelength_nm, cos_theta) + accumulated_phase;
 
             // Add with interference
-            total_r += r * r + 2.0 * r * total_r.sqrt() * phase.cos();
+            total_r += r * r + 2.0 * r * total_r.sqrt() * ops::cos(phase);
             accumulated_phase += phase;
             n_prev = layer.n_film;
             let _ = n_next; // Suppress unused warning
@@ -32,6 +32,8 @@ use std::f64::consts::PI;
 
 use super::complex_ior::Complex;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // COMPLEX 2x2 MATRIX
 // ============================================================================
@@ -209,8 +211,8 @@ impl TransferMatrixFilm {
 
     /// Propagation matrix through a layer
     fn propagation_matrix(phase: Complex) -> Matrix2x2 {
-        let exp_pos = Complex::exp_i(phase.re) * Complex::real((-phase.im).exp());
-        let exp_neg = Complex::exp_i(-phase.re) * Complex::real(phase.im.exp());
+        let exp_pos = Complex::exp_i(phase.re) * Complex::real(ops::exp(-phase.im));
+        let exp_neg = Complex::exp_i(-phase.re) * Complex::real(ops::exp(phase.im));
 
         Matrix2x2 {
             m11: exp_pos,
@@ -223,8 +225,8 @@ impl TransferMatrixFilm {
     /// Calculate reflection and transmission coefficients
     fn calculate_rt(&self, wavelength_nm: f64, angle_deg: f64, pol: Polarization) -> (Complex, Complex) {
         let angle_rad = angle_deg * PI / 180.0;
-        let cos_i = angle_rad.cos();
-        let sin_i = angle_rad.sin();
+        let cos_i = ops::cos(angle_rad);
+        let sin_i = ops::sin(angle_rad);
 
         // Get dynamical matrix function for polarization
         let dyn_matrix: fn(Complex, Complex) -> Matrix2x2 = match pol {
@@ -284,8 +286,8 @@ impl TransferMatrixFilm {
     /// Calculate transmittance for a single wavelength and angle
     pub fn transmittance(&self, wavelength_nm: f64, angle_deg: f64, pol: Polarization) -> f64 {
         let angle_rad = angle_deg * PI / 180.0;
-        let cos_i = angle_rad.cos();
-        let sin_i = angle_rad.sin();
+        let cos_i = ops::cos(angle_rad);
+        let sin_i = ops::sin(angle_rad);
         let cos_s = self.cos_in_layer(self.n_substrate, sin_i);
 
         // Correction factor for substrate
@@ -336,7 +338,7 @@ impl TransferMatrixFilm {
 impl Complex {
     /// Compute e^(ix) = cos(x) + i*sin(x)
     pub fn exp_i(x: f64) -> Self {
-        Self::new(x.cos(), x.sin())
+        Self::new(ops::cos(x), ops::sin(x))
     }
 }
 
@@ -20,6 +20,8 @@
 
 use std::f64::consts::PI;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // DYNAMIC FILM LAYER
 // ============================================================================
@@ -200,7 +202,7 @@ impl HeightMap {
         for y in 0..resolution.1 {
             for x in 0..resolution.0 {
                 let px = x as f64 / resolution.0 as f64 * size.0;
-                heights[y][x] = amplitude * (2.0 * PI * px / period).sin();
+                heights[y][x] = amplitude * ops::sin(2.0 * PI * px / period);
             }
         }
 
@@ -360,7 +362,7 @@ impl DynamicThinFilmStack {
         if let Some(ref hm) = self.height_map {
             let normal = hm.normal(pos);
             // Simple approximation: adjust angle based on normal tilt
-            let tilt = normal[2].acos();
+            let tilt = ops::acos(normal[2]);
             (global_angle + tilt).abs()
         } else {
             global_angle
@@ -392,7 +394,7 @@ impl DynamicThinFilmStack {
     pub fn reflectance_at(&self, pos: Vec2, wavelength: f64, angle_deg: f64) -> f64 {
         let angle_rad = angle_deg.to_radians();
         let local_angle = self.local_incidence_angle(pos, angle_rad);
-        let cos_theta = local_angle.cos();
+        let cos_theta = ops::cos(local_angle);
 
         let layers = self.effective_layers_at(pos);
 
@@ -421,7 +423,7 @@ impl DynamicThinFilmStack {
             .max(0.0).sqrt();
         let delta = 4.0 * PI * n_film * d * cos_theta_film / wavelength;
 
-        let cos_delta = delta.cos();
+        let cos_delta = ops::cos(delta);
         let r01_sq = r01 * r01;
         let r12_sq = r12 * r12;
 
@@ -450,7 +452,7 @@ impl DynamicThinFilmStack {
 
             // Propagation matrix
             let delta = 2.0 * PI * n * d * cos_curr / wavelength;
-            let mp = [[delta.cos(), delta.sin()], [-delta.sin(), delta.cos()]];
+            let mp = [[ops::cos(delta), ops::sin(delta)], [-ops::sin(delta), ops::cos(delta)]];
 
             // Multiply matrices
             m = self.mat_mul(&self.mat_mul(&m, &mi), &mp);
@@ -24,6 +24,8 @@ use super::enhanced_presets::QualityTier;
 use super::perceptual_loss::{rgb_to_lab, delta_e_2000, Illuminant};
 use std::time::Instant;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // VALIDATION RESULTS
 // ============================================================================
@@ -167,7 +169,7 @@ fn evaluate_tier_absorption(tier: QualityTier, absorption: f64, thickness: f64,
         }
         QualityTier::Standard => {
             // Simplified exponential
-            (-absorption * distance * 0.1).exp()
+            ops::exp(-absorption * distance * 0.1)
         }
         QualityTier::High | QualityTier::UltraHigh | QualityTier::Experimental | QualityTier::Reference => {
             // Full Beer-Lambert
@@ -218,7 +220,7 @@ pub fn run_cross_validation() -> CrossValidationReport {
 
     for &ior in &iors {
         for &angle in &angles {
-            let cos_theta = angle.cos();
+            let cos_theta = ops::cos(angle);
             let start = Instant::now();
             let ref_fresnel = fresnel_dielectric_full(cos_theta, ior);
             reference_time_total += start.elapsed().as_nanos() as f64 / 1000.0;
@@ -237,7 +239,7 @@ pub fn run_cross_validation() -> CrossValidationReport {
         let mut times: Vec<f64> = Vec::new();
 
         for &(ior, angle, ref_fresnel) in &reference_values {
-            let cos_theta = angle.cos();
+            let cos_theta = ops::cos(angle);
 
             // Evaluate tier Fresnel
             let start = Instant::now();
@@ -319,8 +321,8 @@ pub fn run_metal_validation() -> String {
 
     for (name, n, k) in metals {
         let r_normal = fresnel_conductor_full(1.0, n, k);
-        let r_60 = fresnel_conductor_full(60.0_f64.to_radians().cos(), n, k);
-        let r_80 = fresnel_conductor_full(80.0_f64.to_radians().cos(), n, k);
+        let r_60 = fresnel_conductor_full(ops::cos(60.0_f64.to_radians()), n, k);
+        let r_80 = fresnel_conductor_full(ops::cos(80.0_f64.to_radians()), n, k);
 
         report.push_str(&format!(
             "| {} | {:.2} | {:.2} | {:.4} | {:.4} | {:.4} |\n",
@@ -354,7 +356,7 @@ pub fn run_dispersion_validation() -> String {
         // Simplified Cauchy-like dispersion
         let ior = base_ior + 10000.0 / (abbe * wl * wl) * 1e4;
         let f_normal = fresnel_dielectric_full(1.0, ior);
-        let f_60 = fresnel_dielectric_full(60.0_f64.to_radians().cos(), ior);
+        let f_60 = fresnel_dielectric_full(ops::cos(60.0_f64.to_radians()), ior);
 
         report.push_str(&format!(
             "| {:.0} | {:.4} | {:.6} | {:.6} |\n",
@@ -372,7 +374,7 @@ pub fn run_dispersion_validation() -> String {
     for &wl in &wavelengths {
         let ior = base_ior_flint + 10000.0 / (abbe_flint * wl * wl) * 1e4;
         let f_normal = fresnel_dielectric_full(1.0, ior);
-        let f_60 = fresnel_dielectric_full(60.0_f64.to_radians().cos(), ior);
+        let f_60 = fresnel_dielectric_full(ops::cos(60.0_f64.to_radians()), ior);
 
         report.push_str(&format!(
             "| {:.0} | {:.4} | {:.6} | {:.6} |\n",
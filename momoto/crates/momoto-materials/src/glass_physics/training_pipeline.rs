@@ -19,6 +19,8 @@ use super::unified_bsdf::BSDFResponse;
 use super::perceptual_loss::{rgb_to_lab, delta_e_2000, Illuminant};
 use std::collections::VecDeque;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // TRAINING CONFIGURATION
 // ============================================================================
@@ -309,7 +311,7 @@ impl TrainingPipeline {
 
         // Compute improvement in dB
         let improvement_db = if mean_delta_e_after > 1e-10 {
-            20.0 * (mean_delta_e_before / mean_delta_e_after).log10()
+            20.0 * ops::log10(mean_delta_e_before / mean_delta_e_after)
         } else {
             f64::INFINITY
         };
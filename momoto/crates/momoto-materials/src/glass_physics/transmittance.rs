@@ -28,6 +28,8 @@
 
 use std::f64::consts::E;
 
+use momoto_core::math::ops;
+
 /// Optical properties of glass material
 #[derive(Debug, Clone, Copy)]
 pub struct OpticalProperties {
@@ -128,12 +130,13 @@ pub fn calculate_transmittance(
 
     // 2. Volume absorption (Beer-Lambert law)
     // I = I₀ × e^(-μₐ × d)
-    let absorption_factor = E.powf(-props.absorption_coefficient * props.thickness);
+    let absorption_factor = ops::powf(E, -props.absorption_coefficient * props.thickness);
     let after_absorption = entering * absorption_factor;
 
     // 3. Volume scattering (Rayleigh-inspired)
     // Scattering increases with thickness but saturates
-    let scattering_factor = 1.0 - props.scattering_coefficient * (1.0 - E.powf(-props.thickness));
+    let scattering_factor =
+        1.0 - props.scattering_coefficient * (1.0 - ops::powf(E, -props.thickness));
     let after_scattering = after_absorption * scattering_factor;
 
     // Light that was scattered
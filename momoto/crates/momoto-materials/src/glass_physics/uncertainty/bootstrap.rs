@@ -7,6 +7,8 @@
 // ============================================================================
 
 /// Confidence interval for a parameter.
+use momoto_core::math::ops;
+
 #[derive(Debug, Clone, Copy)]
 pub struct ConfidenceInterval {
     /// Lower bound.
@@ -394,7 +396,7 @@ impl BootstrapResampler {
             return 0.0;
         }
 
-        num / (6.0 * den.powf(1.5))
+        num / (6.0 * ops::powf(den, 1.5))
     }
 }
 
@@ -477,7 +479,7 @@ fn quantile_normal(p: f64) -> f64 {
     let p_high = 1.0 - p_low;
 
     if p < p_low {
-        let q = (-2.0 * p.ln()).sqrt();
+        let q = (-2.0 * ops::ln(p)).sqrt();
         (((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
             / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
     } else if p <= p_high {
@@ -486,7 +488,7 @@ fn quantile_normal(p: f64) -> f64 {
         (((((a[0] * r + a[1]) * r + a[2]) * r + a[3]) * r + a[4]) * r + a[5]) * q
             / (((((b[0] * r + b[1]) * r + b[2]) * r + b[3]) * r + b[4]) * r + 1.0)
     } else {
-        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        let q = (-2.0 * ops::ln(1.0 - p)).sqrt();
         -(((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
             / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
     }
@@ -509,7 +511,7 @@ fn erf(x: f64) -> f64 {
     let sign = if x < 0.0 { -1.0 } else { 1.0 };
     let x = x.abs();
     let t = 1.0 / (1.0 + p * x);
-    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
+    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * ops::exp(-x * x);
 
     sign * y
 }
@@ -37,6 +37,8 @@ use super::fresnel::{fresnel_schlick, fresnel_full};
 use super::complex_ior::{ComplexIOR, SpectralComplexIOR, fresnel_conductor_unpolarized};
 use super::thin_film::ThinFilm;
 
+use momoto_core::math::ops;
+
 // ============================================================================
 // VECTOR TYPES
 // ============================================================================
@@ -480,20 +482,20 @@ pub trait BSDF: Send + Sync {
     /// Returns a sampled direction with its probability.
     fn sample(&self, ctx: &BSDFContext, u1: f64, u2: f64) -> BSDFSample {
         // Default implementation: cosine-weighted hemisphere sampling
-        let theta = (1.0 - u1).sqrt().acos();
+        let theta = ops::acos((1.0 - u1).sqrt());
         let phi = 2.0 * PI * u2;
 
         let wo = Vector3::new(
-            theta.sin() * phi.cos(),
-            theta.sin() * phi.sin(),
-            theta.cos(),
+            ops::sin(theta) * ops::cos(phi),
+            ops::sin(theta) * ops::sin(phi),
+            ops::cos(theta),
         );
 
         let mut sample_ctx = ctx.clone();
         sample_ctx.wo = wo;
 
         let value = self.evaluate(&sample_ctx);
-        let pdf = theta.cos() / PI;
+        let pdf = ops::cos(theta) / PI;
 
         BSDFSample::new(wo, value, pdf.max(1e-10), false)
     }
@@ -676,20 +678,20 @@ impl BSDF for DielectricBSDF {
             }
         } else {
             // Rough surface: use default cosine-weighted sampling
-            let theta = (1.0 - u1).sqrt().acos();
+            let theta = ops::acos((1.0 - u1).sqrt());
             let phi = 2.0 * PI * u2;
 
             let wo = Vector3::new(
-                theta.sin() * phi.cos(),
-                theta.sin() * phi.sin(),
-                theta.cos(),
+                ops::sin(theta) * ops::cos(phi),
+                ops::sin(theta) * ops::sin(phi),
+                ops::cos(theta),
             );
 
             let mut sample_ctx = ctx.clone();
             sample_ctx.wo = wo;
 
             let value = self.evaluate(&sample_ctx);
-            let pdf = theta.cos() / PI;
+            let pdf = ops::cos(theta) / PI;
 
             BSDFSample::new(wo, value, pdf.max(1e-10), false)
         }
@@ -818,20 +820,20 @@ impl BSDF for ConductorBSDF {
         } else {
             // Rough metal: GGX-like sampling would go here
             // For now, use cosine-weighted
-            let theta = (1.0 - u1).sqrt().acos();
+            let theta = ops::acos((1.0 - u1).sqrt());
             let phi = 2.0 * PI * u2;
 
             let wo = Vector3::new(
-                theta.sin() * phi.cos(),
-                theta.sin() * phi.sin(),
-                theta.cos(),
+                ops::sin(theta) * ops::cos(phi),
+                ops::sin(theta) * ops::sin(phi),
+                ops::cos(theta),
             );
 
             let mut sample_ctx = ctx.clone();
             sample_ctx.wo = wo;
 
             let value = self.evaluate(&sample_ctx);
-            let pdf = theta.cos() / PI;
+            let pdf = ops::cos(theta) / PI;
 
             BSDFSample::new(wo, value, pdf.max(1e-10), false)
         }
@@ -1041,20 +1043,20 @@ impl BSDF for LambertianBSDF {
 
     fn sample(&self, ctx: &BSDFContext, u1: f64, u2: f64) -> BSDFSample {
         // Cosine-weighted hemisphere sampling
-        let theta = (1.0 - u1).sqrt().acos();
+        let theta = ops::acos((1.0 - u1).sqrt());
         let phi = 2.0 * PI * u2;
 
         let wo = Vector3::new(
-            theta.sin() * phi.cos(),
-            theta.sin() * phi.sin(),
-            theta.cos(),
+            ops::sin(theta) * ops::cos(phi),
+            ops::sin(theta) * ops::sin(phi),
+            ops::cos(theta),
         );
 
         let mut sample_ctx = ctx.clone();
         sample_ctx.wo = wo;
 
         let value = self.evaluate(&sample_ctx);
-        let pdf = theta.cos() / PI;
+        let pdf = ops::cos(theta) / PI;
 
         BSDFSample::new(wo, value, pdf.max(1e-10), false)
     }
@@ -1102,7 +1104,7 @@ pub fn validate_energy_conservation(bsdf: &dyn BSDF) -> EnergyValidation {
     let mut max_error: f64 = 0.0;
 
     for &angle_deg in &angles {
-        let cos_theta = (angle_deg * PI / 180.0).cos();
+        let cos_theta = ops::cos(angle_deg * PI / 180.0);
         let ctx = BSDFContext::new_simple(cos_theta);
         let response = bsdf.evaluate(&ctx);
         let error = (response.total_energy() - 1.0).abs();
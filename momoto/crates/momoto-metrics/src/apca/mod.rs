@@ -12,7 +12,13 @@
 //! - FASE 2: Golden vector corrections
 //! - FASE 3: Rust/WASM migration validation
 
+pub mod readability;
+
+use alloc::vec::Vec;
+
+use momoto_core::color::blend::composite_for_contrast;
 use momoto_core::color::Color;
+use momoto_core::math::ops;
 use momoto_core::perception::{ContrastMetric, PerceptualResult, Polarity};
 
 // ============================================================================
@@ -234,7 +240,7 @@ impl APCAMetric {
     /// Applies gamma correction with MAIN_TRC exponent (2.4)
     #[inline]
     fn srgb_to_linear(channel: f64) -> f64 {
-        channel.powf(MAIN_TRC)
+        ops::powf(channel, MAIN_TRC)
     }
 
     /// Convert sRGB color to luminance (Y) using APCA coefficients
@@ -255,7 +261,7 @@ impl APCAMetric {
     #[inline]
     fn soft_clamp(y: f64) -> f64 {
         if y <= BLK_THRS {
-            y + (BLK_THRS - y).powf(BLK_CLMP)
+            y + ops::powf(BLK_THRS - y, BLK_CLMP)
         } else {
             y
         }
@@ -308,10 +314,10 @@ impl APCAMetric {
         // Step 5: Calculate SAPC with polarity-specific normalization
         let sapc = if is_dark_on_light {
             // Dark text on light background (normal polarity)
-            (back_y.powf(NORM_BG) - text_y.powf(NORM_TXT)) * SCALE_BOW
+            (ops::powf(back_y, NORM_BG) - ops::powf(text_y, NORM_TXT)) * SCALE_BOW
         } else {
             // Light text on dark background (reverse polarity)
-            (back_y.powf(REV_BG) - text_y.powf(REV_TXT)) * SCALE_WOB
+            (ops::powf(back_y, REV_BG) - ops::powf(text_y, REV_TXT)) * SCALE_WOB
         };
 
         // Step 6: Apply low contrast clipping and offset
@@ -338,7 +344,10 @@ impl APCAMetric {
 }
 
 impl ContrastMetric for APCAMetric {
+    /// Translucent colors are composited first (see
+    /// [`composite_for_contrast`]), so Lc is that of the rendered pair.
     fn evaluate(&self, foreground: Color, background: Color) -> PerceptualResult {
+        let (foreground, background) = composite_for_contrast(foreground, background);
         let lc = Self::calculate_lc(foreground, background);
 
         // Determine polarity from sign
@@ -383,16 +392,10 @@ impl ContrastMetric for APCAMetric {
         let mut text_ys = Vec::with_capacity(len);
         let mut back_ys = Vec::with_capacity(len);
 
-        for fg in foregrounds {
-            let mut y = Self::srgb_to_y(fg.srgb[0], fg.srgb[1], fg.srgb[2]);
-            y = Self::soft_clamp(y);
-            text_ys.push(y);
-        }
-
-        for bg in backgrounds {
-            let mut y = Self::srgb_to_y(bg.srgb[0], bg.srgb[1], bg.srgb[2]);
-            y = Self::soft_clamp(y);
-            back_ys.push(y);
+        for (&fg, &bg) in foregrounds.iter().zip(backgrounds) {
+            let (fg, bg) = composite_for_contrast(fg, bg);
+            text_ys.push(Self::soft_clamp(Self::srgb_to_y(fg.srgb[0], fg.srgb[1], fg.srgb[2])));
+            back_ys.push(Self::soft_clamp(Self::srgb_to_y(bg.srgb[0], bg.srgb[1], bg.srgb[2])));
         }
 
         // Step 2-7: Process each pair
@@ -411,9 +414,9 @@ impl ContrastMetric for APCAMetric {
 
             // Calculate SAPC with polarity-specific normalization
             let sapc = if is_dark_on_light {
-                (back_y.powf(NORM_BG) - text_y.powf(NORM_TXT)) * SCALE_BOW
+                (ops::powf(back_y, NORM_BG) - ops::powf(text_y, NORM_TXT)) * SCALE_BOW
             } else {
-                (back_y.powf(REV_BG) - text_y.powf(REV_TXT)) * SCALE_WOB
+                (ops::powf(back_y, REV_BG) - ops::powf(text_y, REV_TXT)) * SCALE_WOB
             };
 
             // Apply low contrast clipping and offset
@@ -809,4 +812,75 @@ mod tests {
 
         APCAMetric.evaluate_batch(&fgs, &bgs);
     }
+
+    // ============================================
+    // Translucent Colors
+    // ============================================
+
+    #[test]
+    fn test_translucent_foreground_is_composited() {
+        let white = Color::from_srgb8(255, 255, 255);
+        let half_black = Color::from_srgb8(0, 0, 0).with_alpha(0.5);
+        // What a browser paints: 50% of the way from white to black
+        let gray = Color::from_srgb(0.5, 0.5, 0.5);
+
+        let translucent = APCAMetric.evaluate(half_black, white);
+        let rendered = APCAMetric.evaluate(gray, white);
+        assert!((translucent.value - rendered.value).abs() < 1e-9);
+
+        // Half alpha keeps the polarity but loses contrast: dark-on-light
+        // stays positive, light-on-dark stays negative
+        let black = Color::from_srgb8(0, 0, 0);
+        let opaque = APCAMetric.evaluate(black, white);
+        assert!(translucent.value > 0.0 && translucent.value < opaque.value);
+        let half_white = white.with_alpha(0.5);
+        let reversed = APCAMetric.evaluate(half_white, black);
+        let reversed_opaque = APCAMetric.evaluate(white, black);
+        assert!(reversed.value < 0.0 && reversed.value > reversed_opaque.value);
+        let reversed_rendered = APCAMetric.evaluate(gray, black);
+        assert!((reversed.value - reversed_rendered.value).abs() < 1e-9);
+
+        // Fully transparent text has no contrast at all: Lc is clipped to 0
+        let invisible = APCAMetric.evaluate(half_black.with_alpha(0.0), white);
+        assert!(invisible.value.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_translucent_batch_matches_single() {
+        let white = Color::from_srgb8(255, 255, 255);
+        let fgs = vec![
+            Color::from_srgb8(0, 0, 0).with_alpha(0.6),
+            Color::from_srgb8(255, 255, 255).with_alpha(0.3),
+        ];
+        let bgs = vec![white, Color::from_srgb8(20, 40, 80).with_alpha(0.5)];
+
+        let batch = APCAMetric.evaluate_batch(&fgs, &bgs);
+        for i in 0..fgs.len() {
+            let single = APCAMetric.evaluate(fgs[i], bgs[i]);
+            assert!((batch[i].value - single.value).abs() < 1e-9);
+        }
+    }
+
+    /// Cross-target golden hash; must match on every CI target.
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_golden_hash_deterministic() {
+        let colors: Vec<Color> = (0..64)
+            .map(|i| Color::from_srgb8((i * 4) as u8, (255 - i * 3) as u8, (i * 37 % 256) as u8))
+            .collect();
+        let hash = colors
+            .iter()
+            .flat_map(|fg| colors.iter().map(move |bg| (*fg, *bg)))
+            .flat_map(|(fg, bg)| {
+                [
+                    APCAMetric.evaluate(fg, bg).value,
+                    crate::WCAGMetric.evaluate(fg, bg).value,
+                ]
+            })
+            .flat_map(|v| v.to_bits().to_le_bytes())
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+            });
+        assert_eq!(hash, 16_719_530_746_650_188_246);
+    }
 }
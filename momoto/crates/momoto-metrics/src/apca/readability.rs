@@ -0,0 +1,506 @@
+//! APCA font lookup table and readability conformance.
+//!
+//! Lc alone does not decide pass or fail: thin, small text needs far more
+//! contrast than heavy, large text. This module implements the APCA-W3
+//! 0.1.9 font lookup table (G-4g, `fontLookupAPCA`) and the Bronze / Silver
+//! levels of the APCA Readability Criterion.
+//!
+//! # Lookup table
+//!
+//! Rows are |Lc| in steps of 5 (0–125), columns are font weights 100–900,
+//! and cells are the minimum font size in CSS px. Two cells are not sizes:
+//!
+//! - [`FontRequirement::NonTextOnly`] — too low for text, but usable for
+//!   non-text elements (dividers, large solid icons, focus rings)
+//! - [`FontRequirement::Prohibited`] — too low for anything
+//!
+//! Between rows, sizes are linearly interpolated on |Lc|; a row next to a
+//! non-size cell is not interpolated. Weights between columns round down to
+//! the next lighter column.
+//!
+//! # Conformance levels
+//!
+//! | Level | [`TextRole::Body`] | [`TextRole::Content`] | [`TextRole::NonText`] |
+//! |---|---|---|---|
+//! | Bronze | Lc 75 | Lc 75 / 60 / 45 by size | Lc 30 |
+//! | Silver | table at Lc − 15 | table | Lc 30 |
+//!
+//! Bronze ("simple mode") uses fixed Lc steps: content text needs Lc 60
+//! from 24px (16px bold) and Lc 45 from 36px (24px bold). Silver looks the
+//! font up in the table, and body text (columns of text read fluently)
+//! gives up 15 Lc of headroom.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::Color;
+//! use momoto_metrics::apca::readability::{check_readability, ConformanceLevel, TextRole};
+//!
+//! let gray = Color::from_srgb8(0x88, 0x88, 0x88); // Lc 63 on white
+//! let white = Color::from_srgb8(255, 255, 255);
+//!
+//! let small = check_readability(gray, white, 14.0, 400, ConformanceLevel::Silver, TextRole::Content);
+//! assert!(!small.passes);
+//! assert_eq!(small.min_weight, None); // no weight makes 14px work at Lc 63
+//!
+//! let heading = check_readability(gray, white, 24.0, 400, ConformanceLevel::Silver, TextRole::Content);
+//! assert!(heading.passes);
+//! ```
+//!
+//! # References
+//!
+//! - [APCA-W3 `fontLookupAPCA`](https://github.com/Myndex/apca-w3)
+//! - [APCA Readability Criterion](https://readtech.org/ARC/)
+
+use momoto_core::color::Color;
+use momoto_core::perception::ContrastMetric;
+
+use super::APCAMetric;
+
+/// Font weights of the lookup table columns.
+pub const FONT_WEIGHTS: [u16; 9] = [100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+/// Lc step between lookup table rows.
+const LC_STEP: f64 = 5.0;
+
+/// Sentinel: usable for non-text elements only.
+const NON_TEXT: f64 = 777.0;
+
+/// Sentinel: not usable at all.
+const PROHIBITED: f64 = 999.0;
+
+/// Lc headroom Silver requires for body text.
+const SILVER_BODY_LC_OFFSET: f64 = 15.0;
+
+/// Minimum Lc for meaningful non-text elements at every level.
+const NON_TEXT_MIN_LC: f64 = 30.0;
+
+/// Minimum font size (px) per weight, one row per Lc step from 0 to 125.
+#[rustfmt::skip]
+const FONT_LOOKUP: [[f64; 9]; 26] = [
+    //  100     200    300    400    500     600     700   800   900
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0], //   0
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0], //   5
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0], //  10
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0], //  15
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0], //  20
+    [777.0, 777.0, 777.0, 120.0, 120.0, 108.0,  96.0,  96.0,  96.0], //  25
+    [777.0, 777.0, 120.0, 108.0, 108.0,  96.0,  72.0,  72.0,  72.0], //  30
+    [777.0, 120.0, 108.0,  96.0,  72.0,  60.0,  48.0,  48.0,  48.0], //  35
+    [120.0, 108.0,  96.0,  60.0,  48.0,  42.0,  32.0,  32.0,  32.0], //  40
+    [108.0,  96.0,  72.0,  42.0,  32.0,  28.0,  24.0,  24.0,  24.0], //  45
+    [ 96.0,  72.0,  60.0,  32.0,  28.0,  24.0,  21.0,  21.0,  21.0], //  50
+    [ 80.0,  60.0,  48.0,  28.0,  24.0,  21.0,  18.0,  18.0,  18.0], //  55
+    [ 72.0,  48.0,  42.0,  24.0,  21.0,  18.0,  16.0,  16.0,  18.0], //  60
+    [ 68.0,  46.0,  32.0, 21.75,  19.0,  17.0,  15.0,  16.0,  18.0], //  65
+    [ 64.0,  44.0,  28.0,  19.5,  18.0,  16.0,  14.5,  16.0,  18.0], //  70
+    [ 60.0,  42.0,  24.0,  18.0,  16.0,  15.0,  14.0,  16.0,  18.0], //  75
+    [ 56.0, 38.25,  23.0, 17.25, 15.81, 14.81,  14.0,  16.0,  18.0], //  80
+    [ 52.0,  34.5,  22.0,  16.5, 15.625, 14.625, 14.0, 16.0,  18.0], //  85
+    [ 48.0,  32.0,  21.0,  16.0,  15.5,  14.5,  14.0,  16.0,  18.0], //  90
+    [ 45.0,  28.0,  19.5,  15.5,  15.0,  14.0,  13.5,  16.0,  18.0], //  95
+    [ 42.0,  26.5,  18.5,  15.0,  14.5,  13.5,  13.0,  16.0,  18.0], // 100
+    [ 39.0,  25.0,  18.0,  14.5,  14.0,  13.0,  12.0,  16.0,  18.0], // 105
+    [ 36.0,  24.0,  18.0,  14.0,  13.0,  12.0,  11.0,  16.0,  18.0], // 110
+    [ 34.0,  22.5,  17.5,  13.5,  12.5,  11.5,  10.5,  16.0,  18.0], // 115
+    [ 32.0,  21.0,  17.0,  13.0,  12.0,  11.0,  10.0,  16.0,  18.0], // 120
+    [ 30.0,  20.0,  16.5,  12.5,  11.5,  10.5,   9.5,  16.0,  18.0], // 125
+];
+
+/// What the lookup table allows for one weight at one Lc.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontRequirement {
+    /// Text is allowed at this size (px) or larger.
+    MinSize(f64),
+    /// Too little contrast for text; non-text elements only.
+    NonTextOnly,
+    /// Too little contrast for any use.
+    Prohibited,
+}
+
+impl FontRequirement {
+    fn from_cell(cell: f64) -> Self {
+        if cell >= PROHIBITED {
+            Self::Prohibited
+        } else if cell >= NON_TEXT {
+            Self::NonTextOnly
+        } else {
+            Self::MinSize(cell)
+        }
+    }
+
+    /// Whether text of `size_px` meets this requirement.
+    #[inline]
+    #[must_use]
+    pub fn allows(self, size_px: f64) -> bool {
+        matches!(self, Self::MinSize(min) if size_px >= min)
+    }
+}
+
+/// APCA Readability Criterion conformance level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConformanceLevel {
+    /// Simple mode: fixed Lc steps by text role and size
+    Bronze,
+    /// Full font lookup table
+    Silver,
+}
+
+/// What the foreground is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextRole {
+    /// Body text: columns or paragraphs read fluently
+    Body,
+    /// Other readable text: labels, buttons, headlines, captions
+    Content,
+    /// Non-text elements: icons, dividers, focus indicators
+    NonText,
+}
+
+/// Outcome of [`check_readability`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadabilityResult {
+    /// Signed APCA Lc of the pair
+    pub lc: f64,
+    /// Whether the font passes at the requested level and role
+    pub passes: bool,
+    /// Table lookup for the requested weight, at the role's effective Lc
+    pub requirement: FontRequirement,
+    /// Smallest passing size (px) at the requested weight, if any
+    pub min_size_px: Option<f64>,
+    /// Lightest passing weight at the requested size, if any
+    pub min_weight: Option<u16>,
+    /// The contrast only supports non-text use
+    pub non_text_only: bool,
+}
+
+/// Table column for a font weight (rounded down, clamped to 100–900).
+fn weight_column(weight: u16) -> usize {
+    usize::from(weight.clamp(100, 900) / 100 - 1)
+}
+
+/// Look up the table for |`lc`| and `weight`.
+///
+/// # Examples
+///
+/// ```
+/// use momoto_metrics::apca::readability::{font_requirement, FontRequirement};
+///
+/// assert_eq!(font_requirement(75.0, 400), FontRequirement::MinSize(18.0));
+/// assert_eq!(font_requirement(-90.0, 700), FontRequirement::MinSize(14.0));
+/// assert_eq!(font_requirement(20.0, 400), FontRequirement::NonTextOnly);
+/// assert_eq!(font_requirement(5.0, 900), FontRequirement::Prohibited);
+/// ```
+#[must_use]
+pub fn font_requirement(lc: f64, weight: u16) -> FontRequirement {
+    let lc = lc.abs();
+    let column = weight_column(weight);
+    let last = FONT_LOOKUP.len() - 1;
+    let position = lc / LC_STEP;
+    // Saturating float-to-int cast; NaN maps to row 0.
+    let row = (position as usize).min(last);
+    let lower = FONT_LOOKUP[row][column];
+    if row == last || lower >= NON_TEXT {
+        return FontRequirement::from_cell(lower);
+    }
+
+    let upper = FONT_LOOKUP[row + 1][column];
+    if upper >= NON_TEXT {
+        return FontRequirement::from_cell(lower);
+    }
+    let t = position - row as f64;
+    FontRequirement::MinSize(lower + (upper - lower) * t)
+}
+
+/// Lightest table weight whose minimum size at |`lc`| is at most `size_px`.
+///
+/// # Examples
+///
+/// ```
+/// use momoto_metrics::apca::readability::min_font_weight;
+///
+/// assert_eq!(min_font_weight(60.0, 24.0), Some(400));
+/// assert_eq!(min_font_weight(60.0, 16.0), Some(700));
+/// assert_eq!(min_font_weight(60.0, 12.0), None);
+/// ```
+#[must_use]
+pub fn min_font_weight(lc: f64, size_px: f64) -> Option<u16> {
+    FONT_WEIGHTS
+        .iter()
+        .copied()
+        .find(|&weight| font_requirement(lc, weight).allows(size_px))
+}
+
+/// Smallest |Lc| at which the table allows `size_px` at `weight`.
+///
+/// Inverse of [`font_requirement`]; `None` if no Lc up to 125 suffices.
+///
+/// # Examples
+///
+/// ```
+/// use momoto_metrics::apca::readability::min_lc_for_font;
+///
+/// assert_eq!(min_lc_for_font(18.0, 400), Some(75.0));
+/// assert_eq!(min_lc_for_font(96.0, 700), Some(25.0));
+/// assert_eq!(min_lc_for_font(8.0, 400), None);
+/// ```
+#[must_use]
+pub fn min_lc_for_font(size_px: f64, weight: u16) -> Option<f64> {
+    let column = weight_column(weight);
+    let (row, size) = FONT_LOOKUP
+        .iter()
+        .map(|cells| cells[column])
+        .enumerate()
+        .find(|&(_, cell)| cell < NON_TEXT && size_px >= cell)?;
+    let lc = row as f64 * LC_STEP;
+
+    match row
+        .checked_sub(1)
+        .map(|previous| FONT_LOOKUP[previous][column])
+    {
+        // Interpolate back into the previous step, as font_requirement does.
+        Some(previous) if previous < NON_TEXT => {
+            Some(lc - LC_STEP * (size_px - size) / (previous - size))
+        }
+        _ => Some(lc),
+    }
+}
+
+/// Check a foreground/background pair against the font lookup table.
+///
+/// `size_px` is the CSS font size and `weight` the CSS `font-weight`.
+/// See the [module docs](self) for the level and role rules.
+///
+/// # Examples
+///
+/// ```
+/// use momoto_core::color::Color;
+/// use momoto_metrics::apca::readability::{check_readability, ConformanceLevel, TextRole};
+///
+/// let black = Color::from_srgb8(0, 0, 0);
+/// let white = Color::from_srgb8(255, 255, 255);
+///
+/// let result = check_readability(black, white, 16.0, 400, ConformanceLevel::Silver, TextRole::Body);
+/// assert!(result.passes);
+/// assert_eq!(result.min_weight, Some(400));
+/// ```
+#[must_use]
+pub fn check_readability(
+    foreground: Color,
+    background: Color,
+    size_px: f64,
+    weight: u16,
+    level: ConformanceLevel,
+    role: TextRole,
+) -> ReadabilityResult {
+    let lc = APCAMetric.evaluate(foreground, background).value;
+    evaluate_readability(lc, size_px, weight, level, role)
+}
+
+/// [`check_readability`] for an already computed Lc.
+#[must_use]
+pub fn evaluate_readability(
+    lc: f64,
+    size_px: f64,
+    weight: u16,
+    level: ConformanceLevel,
+    role: TextRole,
+) -> ReadabilityResult {
+    let magnitude = lc.abs();
+    let effective = match (level, role) {
+        (ConformanceLevel::Silver, TextRole::Body) => (magnitude - SILVER_BODY_LC_OFFSET).max(0.0),
+        _ => magnitude,
+    };
+    let requirement = font_requirement(effective, weight);
+
+    let passes = match (level, role) {
+        (_, TextRole::NonText) => magnitude >= NON_TEXT_MIN_LC,
+        (ConformanceLevel::Bronze, role) => magnitude >= bronze_min_lc(size_px, weight, role),
+        (ConformanceLevel::Silver, _) => requirement.allows(size_px),
+    };
+
+    ReadabilityResult {
+        lc,
+        passes,
+        requirement,
+        min_size_px: match requirement {
+            FontRequirement::MinSize(size) => Some(size),
+            _ => None,
+        },
+        min_weight: min_font_weight(effective, size_px),
+        non_text_only: role == TextRole::NonText
+            || matches!(requirement, FontRequirement::NonTextOnly),
+    }
+}
+
+/// Smallest |Lc| at which a font passes at `level` for `role`.
+///
+/// Inverse of [`evaluate_readability`]; `None` if no Lc suffices.
+///
+/// # Examples
+///
+/// ```
+/// use momoto_metrics::apca::readability::{min_lc, ConformanceLevel, TextRole};
+///
+/// assert_eq!(min_lc(18.0, 400, ConformanceLevel::Silver, TextRole::Content), Some(75.0));
+/// assert_eq!(min_lc(18.0, 400, ConformanceLevel::Silver, TextRole::Body), Some(90.0));
+/// assert_eq!(min_lc(18.0, 400, ConformanceLevel::Bronze, TextRole::Body), Some(75.0));
+/// ```
+#[must_use]
+pub fn min_lc(size_px: f64, weight: u16, level: ConformanceLevel, role: TextRole) -> Option<f64> {
+    match (level, role) {
+        (_, TextRole::NonText) => Some(NON_TEXT_MIN_LC),
+        (ConformanceLevel::Bronze, role) => Some(bronze_min_lc(size_px, weight, role)),
+        (ConformanceLevel::Silver, TextRole::Body) => {
+            min_lc_for_font(size_px, weight).map(|lc| lc + SILVER_BODY_LC_OFFSET)
+        }
+        (ConformanceLevel::Silver, TextRole::Content) => min_lc_for_font(size_px, weight),
+    }
+}
+
+/// Bronze (simple mode) minimum |Lc| for text.
+///
+/// # Examples
+///
+/// ```
+/// use momoto_metrics::apca::readability::{bronze_min_lc, TextRole};
+///
+/// assert_eq!(bronze_min_lc(16.0, 400, TextRole::Body), 75.0);
+/// assert_eq!(bronze_min_lc(24.0, 400, TextRole::Content), 60.0);
+/// assert_eq!(bronze_min_lc(24.0, 700, TextRole::Content), 45.0);
+/// ```
+#[must_use]
+pub fn bronze_min_lc(size_px: f64, weight: u16, role: TextRole) -> f64 {
+    let bold = weight >= 700;
+    match role {
+        TextRole::NonText => NON_TEXT_MIN_LC,
+        TextRole::Body => 75.0,
+        TextRole::Content if size_px >= 36.0 || (bold && size_px >= 24.0) => 45.0,
+        TextRole::Content if size_px >= 24.0 || (bold && size_px >= 16.0) => 60.0,
+        TextRole::Content => 75.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_rows_match_lc_steps() {
+        // Published anchor points of the G-4g table
+        assert_eq!(font_requirement(90.0, 300), FontRequirement::MinSize(21.0));
+        assert_eq!(font_requirement(60.0, 400), FontRequirement::MinSize(24.0));
+        assert_eq!(font_requirement(45.0, 700), FontRequirement::MinSize(24.0));
+        assert_eq!(font_requirement(125.0, 900), FontRequirement::MinSize(18.0));
+        assert_eq!(font_requirement(30.0, 100), FontRequirement::NonTextOnly);
+        assert_eq!(font_requirement(14.9, 400), FontRequirement::Prohibited);
+    }
+
+    #[test]
+    fn test_interpolation_between_rows() {
+        // Halfway between Lc 60 (24px) and Lc 65 (21.75px) at 400
+        assert_eq!(
+            font_requirement(62.5, 400),
+            FontRequirement::MinSize(22.875)
+        );
+        // Next to a non-text cell the lower row applies
+        assert_eq!(font_requirement(22.0, 400), FontRequirement::NonTextOnly);
+        // Beyond the table the last row applies
+        assert_eq!(font_requirement(130.0, 400), FontRequirement::MinSize(12.5));
+    }
+
+    #[test]
+    fn test_weights_round_down() {
+        assert_eq!(font_requirement(75.0, 450), font_requirement(75.0, 400));
+        assert_eq!(font_requirement(75.0, 50), font_requirement(75.0, 100));
+        assert_eq!(font_requirement(75.0, 1000), font_requirement(75.0, 900));
+    }
+
+    #[test]
+    fn test_min_lc_inverts_lookup() {
+        for &weight in &FONT_WEIGHTS {
+            for size in [12.0, 14.0, 16.0, 18.5, 24.0, 40.0, 100.0] {
+                if let Some(lc) = min_lc_for_font(size, weight) {
+                    assert!(font_requirement(lc + 1e-9, weight).allows(size));
+                    assert!(!font_requirement(lc - 0.01, weight).allows(size));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_silver_body_needs_extra_lc() {
+        // Lc 75 allows 18px/400 as content, but body text needs Lc 90
+        let content =
+            evaluate_readability(75.0, 18.0, 400, ConformanceLevel::Silver, TextRole::Content);
+        let body = evaluate_readability(75.0, 18.0, 400, ConformanceLevel::Silver, TextRole::Body);
+        assert!(content.passes);
+        assert!(!body.passes);
+        assert_eq!(body.min_size_px, Some(24.0));
+        assert_eq!(body.min_weight, Some(600));
+    }
+
+    #[test]
+    fn test_min_lc_matches_evaluate() {
+        for level in [ConformanceLevel::Bronze, ConformanceLevel::Silver] {
+            for role in [TextRole::Body, TextRole::Content, TextRole::NonText] {
+                for (size, weight) in [(14.0, 400), (16.0, 700), (24.0, 300), (40.0, 200)] {
+                    if let Some(lc) = min_lc(size, weight, level, role) {
+                        assert!(evaluate_readability(lc + 1e-9, size, weight, level, role).passes);
+                        assert!(!evaluate_readability(lc - 0.01, size, weight, level, role).passes);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bronze_steps() {
+        let check = |lc, size, weight| {
+            evaluate_readability(
+                lc,
+                size,
+                weight,
+                ConformanceLevel::Bronze,
+                TextRole::Content,
+            )
+            .passes
+        };
+        assert!(check(75.0, 14.0, 400));
+        assert!(!check(74.0, 14.0, 400));
+        assert!(check(60.0, 24.0, 400));
+        assert!(check(-45.0, 36.0, 400));
+        assert!(!check(44.0, 36.0, 400));
+    }
+
+    #[test]
+    fn test_non_text_designation() {
+        let faint =
+            evaluate_readability(20.0, 48.0, 700, ConformanceLevel::Silver, TextRole::Content);
+        assert!(!faint.passes);
+        assert!(faint.non_text_only);
+        assert_eq!(faint.min_size_px, None);
+        assert_eq!(faint.min_weight, None);
+
+        let icon =
+            evaluate_readability(35.0, 24.0, 400, ConformanceLevel::Bronze, TextRole::NonText);
+        assert!(icon.passes);
+        assert!(icon.non_text_only);
+    }
+
+    #[test]
+    fn test_check_readability_uses_apca() {
+        let black = Color::from_srgb8(0, 0, 0);
+        let white = Color::from_srgb8(255, 255, 255);
+        let result = check_readability(
+            white,
+            black,
+            12.0,
+            400,
+            ConformanceLevel::Silver,
+            TextRole::Content,
+        );
+        assert!(result.lc < -100.0);
+        assert_eq!(result.requirement, font_requirement(result.lc, 400));
+    }
+}
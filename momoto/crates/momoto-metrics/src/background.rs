@@ -0,0 +1,276 @@
+//! Contrast of text over gradients and images
+//!
+//! Runs any [`ContrastMetric`] over every [`BackgroundSample`] of a text
+//! region and summarizes the result the way a reviewer reads it:
+//!
+//! - **worst**: lowest contrast anywhere under the text, and where it is;
+//! - **percentile**: contrast that all but the lowest `percentile`% of the
+//!   text area reaches, which ignores a few stray pixels;
+//! - **mean**: area-weighted mean contrast;
+//! - **failing region**: bounding box and area fraction below the threshold.
+//!
+//! Contrast is compared by magnitude, so APCA's negative Lc for light text
+//! on dark backgrounds counts the same as positive Lc. Translucent
+//! backgrounds are composited over white, as the metrics do for flat colors.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::background::{Background, Gradient, GradientStop, Region};
+//! use momoto_core::color::Color;
+//! use momoto_metrics::background::{evaluate_background, BackgroundContrastOptions};
+//! use momoto_metrics::WCAGMetric;
+//!
+//! // Hero heading: white text over a navy → sky gradient.
+//! let gradient = Gradient::linear(
+//!     90.0,
+//!     vec![
+//!         GradientStop::from_color(Color::from_srgb8(15, 23, 42), None),
+//!         GradientStop::from_color(Color::from_srgb8(125, 211, 252), None),
+//!     ],
+//! )
+//! .unwrap();
+//! let background = Background::Gradient { gradient, width: 800.0, height: 200.0 };
+//!
+//! let result = evaluate_background(
+//!     &WCAGMetric,
+//!     Color::from_srgb8(255, 255, 255),
+//!     &background,
+//!     &Region::new(40.0, 80.0, 720.0, 48.0),
+//!     &BackgroundContrastOptions::new(4.5),
+//! )
+//! .unwrap();
+//!
+//! assert!(!result.passes);
+//! // Only the light right-hand end of the gradient fails.
+//! let failing = result.failing_region.unwrap();
+//! assert!(failing.x > 400.0);
+//! ```
+
+use alloc::vec::Vec;
+
+use momoto_core::color::background::{Background, BackgroundError, Region};
+use momoto_core::color::Color;
+use momoto_core::perception::ContrastMetric;
+
+/// Percentile reported when none is given: the 5% of text area with the
+/// lowest contrast is excluded.
+pub const DEFAULT_PERCENTILE: f64 = 5.0;
+
+/// Options for [`evaluate_background`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackgroundContrastOptions {
+    /// Minimum contrast magnitude (WCAG ratio, APCA |Lc|, …) to pass.
+    pub threshold: f64,
+    /// Low-tail percentile in [0, 100] for [`BackgroundContrast::percentile`].
+    pub percentile: f64,
+}
+
+impl BackgroundContrastOptions {
+    /// Options with `threshold` and the [`DEFAULT_PERCENTILE`].
+    #[must_use]
+    pub const fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            percentile: DEFAULT_PERCENTILE,
+        }
+    }
+
+    /// Report the contrast at `percentile` instead.
+    #[must_use]
+    pub const fn with_percentile(mut self, percentile: f64) -> Self {
+        self.percentile = percentile;
+        self
+    }
+}
+
+/// Contrast of text over a non-uniform background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackgroundContrast {
+    /// Lowest contrast magnitude under the text.
+    pub worst: f64,
+    /// Center of the cell with the lowest contrast.
+    pub worst_at: (f64, f64),
+    /// Contrast magnitude at the requested low-tail percentile.
+    pub percentile: f64,
+    /// Area-weighted mean contrast magnitude.
+    pub mean: f64,
+    /// Whether the worst case meets the threshold.
+    pub passes: bool,
+    /// Fraction of the text area below the threshold, in [0, 1].
+    pub failing_fraction: f64,
+    /// Bounding box of every cell below the threshold.
+    pub failing_region: Option<Region>,
+    /// Number of background samples evaluated.
+    pub samples: usize,
+}
+
+/// Evaluate `text` over `background` within `region`.
+///
+/// # Errors
+///
+/// [`BackgroundError::EmptyRegion`] if `region` does not overlap the
+/// background.
+pub fn evaluate_background<M: ContrastMetric + ?Sized>(
+    metric: &M,
+    text: Color,
+    background: &Background<'_>,
+    region: &Region,
+    options: &BackgroundContrastOptions,
+) -> Result<BackgroundContrast, BackgroundError> {
+    let samples = background.samples(region)?;
+
+    // (|contrast|, weight, index)
+    let mut values: Vec<(f64, f64, usize)> = samples
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (metric.evaluate(text, s.color).value.abs(), s.weight(), i))
+        .collect();
+    let total: f64 = values.iter().map(|v| v.1).sum();
+    if total <= 0.0 {
+        return Err(BackgroundError::EmptyRegion);
+    }
+
+    let mean = values.iter().map(|v| v.0 * v.1).sum::<f64>() / total;
+
+    let mut failing_area = 0.0;
+    let mut failing_region: Option<Region> = None;
+    for &(value, weight, i) in &values {
+        if value < options.threshold {
+            failing_area += weight;
+            let cell = samples[i].cell;
+            failing_region = Some(failing_region.map_or(cell, |r| r.union(&cell)));
+        }
+    }
+
+    values.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let (worst, _, worst_index) = values[0];
+    let target = total * options.percentile.clamp(0.0, 100.0) / 100.0;
+    let mut cumulative = 0.0;
+    let mut percentile = values[values.len() - 1].0;
+    for &(value, weight, _) in &values {
+        cumulative += weight;
+        if cumulative >= target {
+            percentile = value;
+            break;
+        }
+    }
+
+    Ok(BackgroundContrast {
+        worst,
+        worst_at: samples[worst_index].cell.center(),
+        percentile,
+        mean,
+        passes: worst >= options.threshold,
+        failing_fraction: failing_area / total,
+        failing_region,
+        samples: samples.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{APCAMetric, WCAGMetric};
+    use alloc::vec;
+    use momoto_core::color::background::{Gradient, GradientStop, Raster};
+
+    fn black_to_white() -> Background<'static> {
+        let gradient = Gradient::linear(
+            90.0,
+            vec![
+                GradientStop::from_color(Color::from_srgb8(0, 0, 0), None),
+                GradientStop::from_color(Color::from_srgb8(255, 255, 255), None),
+            ],
+        )
+        .unwrap();
+        Background::Gradient {
+            gradient,
+            width: 100.0,
+            height: 10.0,
+        }
+    }
+
+    #[test]
+    fn test_solid_background_matches_flat_metric() {
+        let fg = Color::from_srgb8(0, 0, 0);
+        let bg = Color::from_srgb8(119, 119, 119);
+        let result = evaluate_background(
+            &WCAGMetric,
+            fg,
+            &Background::Solid(bg),
+            &Region::new(0.0, 0.0, 50.0, 20.0),
+            &BackgroundContrastOptions::new(4.5),
+        )
+        .unwrap();
+
+        let flat = WCAGMetric.evaluate(fg, bg).value;
+        assert_eq!(result.worst, flat);
+        assert_eq!(result.mean, flat);
+        assert_eq!(result.percentile, flat);
+        assert_eq!(result.samples, 1);
+        assert!(result.passes);
+    }
+
+    #[test]
+    fn test_gradient_failing_region_is_located() {
+        let result = evaluate_background(
+            &WCAGMetric,
+            Color::from_srgb8(255, 255, 255),
+            &black_to_white(),
+            &Region::new(0.0, 0.0, 100.0, 10.0),
+            &BackgroundContrastOptions::new(4.5),
+        )
+        .unwrap();
+
+        assert!(!result.passes);
+        assert!(result.worst < 1.1);
+        assert!(result.worst_at.0 > 95.0);
+        assert!(result.worst < result.percentile && result.percentile < result.mean);
+
+        let failing = result.failing_region.unwrap();
+        assert!((failing.x + failing.width - 100.0).abs() < 1e-9);
+        assert!(failing.x > 40.0 && failing.x < 70.0);
+        assert!((result.failing_fraction - failing.width / 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apca_uses_magnitude() {
+        // Light text on the dark half only: reverse polarity, negative Lc.
+        let result = evaluate_background(
+            &APCAMetric,
+            Color::from_srgb8(255, 255, 255),
+            &black_to_white(),
+            &Region::new(0.0, 0.0, 20.0, 10.0),
+            &BackgroundContrastOptions::new(60.0),
+        )
+        .unwrap();
+
+        assert!(result.worst > 60.0);
+        assert!(result.passes);
+        assert_eq!(result.failing_region, None);
+    }
+
+    #[test]
+    fn test_raster_percentile_ignores_stray_pixels() {
+        // 10×10 white image with one black pixel.
+        let mut pixels = vec![255u8; 10 * 10 * 4];
+        pixels[..3].copy_from_slice(&[0, 0, 0]);
+        let raster = Raster::new(10, 10, &pixels).unwrap();
+
+        let result = evaluate_background(
+            &WCAGMetric,
+            Color::from_srgb8(0, 0, 0),
+            &Background::Raster(raster),
+            &Region::new(0.0, 0.0, 10.0, 10.0),
+            &BackgroundContrastOptions::new(4.5),
+        )
+        .unwrap();
+
+        assert_eq!(result.samples, 100);
+        assert!((result.worst - 1.0).abs() < 1e-9);
+        assert_eq!(result.worst_at, (0.5, 0.5));
+        assert!((result.percentile - 21.0).abs() < 1e-9);
+        assert!((result.failing_fraction - 0.01).abs() < 1e-12);
+    }
+}
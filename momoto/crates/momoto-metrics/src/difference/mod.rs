@@ -0,0 +1,564 @@
+//! Color-difference metrics
+//!
+//! Seven ΔE formulas behind one interface. Every metric implements
+//! [`PerceptualMetric`] over a `(reference, sample)` pair and
+//! [`ColorDifference`], which adds batch evaluation and a just-noticeable
+//! difference (JND) so results from different formulas can be compared in
+//! JND units.
+//!
+//! | Metric | Space | JND | Notes |
+//! |---|---|---|---|
+//! | [`Cie76`] | CIELAB | 2.3 | Euclidean; overstates chroma differences |
+//! | [`Cie94`] | CIELAB | 1.0 | Asymmetric: weights from the reference |
+//! | [`Ciede2000`] | CIELAB | 1.0 | Current CIE recommendation |
+//! | [`Cmc`] | CIELAB | 1.0 | Asymmetric; 2:1 for acceptability, 1:1 for perceptibility |
+//! | [`DeltaEOk`] | OKLab | 0.02 | Threshold used by CSS Color 4 gamut mapping |
+//! | [`Cam16Ucs`] | CAM16-UCS | 1.0 | Scaled to match CIEDE2000 units |
+//! | [`DeltaEItp`] | ICtCp | 1.0 | ITU-R BT.2124; valid for HDR |
+//!
+//! A difference below one JND is invisible side by side for most
+//! observers. Around 2–3 JND is a small but obvious difference, and
+//! above ~10 the colors read as different colors rather than variants.
+//!
+//! CIELAB metrics use [`Lab::from_color`] (D50, matching CSS `lab()`).
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::Color;
+//! use momoto_metrics::difference::{Ciede2000, ColorDifference, DeltaEOk};
+//!
+//! let a = Color::from_srgb8(59, 130, 246);
+//! let b = Color::from_srgb8(37, 99, 235);
+//!
+//! assert!(Ciede2000.is_noticeable(&a, &b));
+//! assert!(DeltaEOk.jnd_units(&a, &b) > 2.0);
+//! assert_eq!(DeltaEOk.nearest(&a, &[a, b]), Some((0, 0.0)));
+//! ```
+//!
+//! # References
+//!
+//! - CIE 116:1995 Industrial colour-difference evaluation (CIE94)
+//! - CIE 142:2001 Improvement to industrial colour-difference evaluation
+//!   (CIEDE2000)
+//! - Clarke, F. J. J., McDonald, R., Rigg, B. (1984). Modification to the
+//!   JPC79 colour-difference formula. JSDC 100(4), 128–132 (CMC l:c)
+//! - Li, C. et al. (2017). Comprehensive color solutions: CAM16, CAT16, and
+//!   CAM16-UCS. Color Research & Application, 42(6), 703–718
+//! - ITU-R BT.2124-0 (2019). Objective metric for the assessment of the
+//!   potential visibility of colour differences in television
+
+use alloc::vec::Vec;
+
+use momoto_core::color::Color;
+use momoto_core::math::ops;
+use momoto_core::space::hct::cam16::{ViewingConditions, CAM16};
+use momoto_core::space::hdr::HdrColor;
+use momoto_core::space::lab::Lab;
+use momoto_core::space::oklch::OKLab;
+use momoto_core::traits::perceptual::PerceptualMetric;
+
+#[cfg(not(feature = "std"))]
+use momoto_core::math::float::FloatFuncs;
+
+/// A `(reference, sample)` color pair.
+pub type ColorPair = (Color, Color);
+
+/// Shared interface of the ΔE metrics in this module.
+///
+/// Order matters for [`Cie94`] and [`Cmc`], whose weights come from the
+/// reference; the other metrics are symmetric.
+pub trait ColorDifference {
+    /// Difference between `reference` and `sample` in the metric's units.
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64;
+
+    /// Just-noticeable difference in the metric's units.
+    fn jnd(&self) -> f64;
+
+    /// Difference expressed in multiples of [`jnd`](Self::jnd).
+    fn jnd_units(&self, reference: &Color, sample: &Color) -> f64 {
+        self.delta_e(reference, sample) / self.jnd()
+    }
+
+    /// Whether the difference reaches one JND.
+    fn is_noticeable(&self, reference: &Color, sample: &Color) -> bool {
+        self.delta_e(reference, sample) >= self.jnd()
+    }
+
+    /// Difference for every `(reference, sample)` pair.
+    fn delta_e_pairs(&self, pairs: &[ColorPair]) -> Vec<f64> {
+        pairs.iter().map(|(r, s)| self.delta_e(r, s)).collect()
+    }
+
+    /// Difference between one reference and each sample.
+    fn delta_e_batch(&self, reference: &Color, samples: &[Color]) -> Vec<f64> {
+        samples.iter().map(|s| self.delta_e(reference, s)).collect()
+    }
+
+    /// Index and difference of the sample closest to `reference`.
+    ///
+    /// Returns `None` for an empty slice.
+    fn nearest(&self, reference: &Color, samples: &[Color]) -> Option<(usize, f64)> {
+        samples
+            .iter()
+            .map(|s| self.delta_e(reference, s))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+/// Implements [`PerceptualMetric`] over [`ColorPair`] via [`ColorDifference`].
+macro_rules! perceptual_metric {
+    ($($metric:ty => $name:literal, $unit:literal;)*) => {$(
+        impl PerceptualMetric<ColorPair> for $metric {
+            type Output = f64;
+
+            fn measure(&self, signal: &ColorPair) -> f64 {
+                self.delta_e(&signal.0, &signal.1)
+            }
+
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn unit(&self) -> &'static str {
+                $unit
+            }
+
+            fn higher_is_better(&self) -> bool {
+                false
+            }
+        }
+    )*};
+}
+
+perceptual_metric! {
+    Cie76 => "delta_e_76", "ΔE*ab";
+    Cie94 => "delta_e_94", "ΔE*94";
+    Ciede2000 => "delta_e_2000", "ΔE00";
+    Cmc => "delta_e_cmc", "ΔE CMC";
+    DeltaEOk => "delta_e_ok", "ΔEOK";
+    Cam16Ucs => "delta_e_cam16_ucs", "ΔE'";
+    DeltaEItp => "delta_e_itp", "ΔE ITP";
+}
+
+/// `(ΔL, ΔC, ΔH², C₁, h₁)` of a reference/sample pair, `h₁` in degrees.
+fn lch_differences(reference: &Lab, sample: &Lab) -> (f64, f64, f64, f64, f64) {
+    let c1 = reference.chroma();
+    let dl = reference.l - sample.l;
+    let dc = c1 - sample.chroma();
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    let dh2 = (da * da + db * db - dc * dc).max(0.0);
+    (dl, dc, dh2, c1, reference.hue())
+}
+
+// ============================================================================
+// CIE76
+// ============================================================================
+
+/// CIE 1976 ΔE\*ab: Euclidean distance in CIELAB.
+///
+/// JND ≈ 2.3 (Sharma & Trussell, 1997). Overestimates differences between
+/// saturated colors; prefer [`Ciede2000`] for new work.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cie76;
+
+impl ColorDifference for Cie76 {
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64 {
+        Lab::from_color(reference).delta_e_76(&Lab::from_color(sample))
+    }
+
+    fn jnd(&self) -> f64 {
+        2.3
+    }
+}
+
+// ============================================================================
+// CIE94
+// ============================================================================
+
+/// CIE 1994 ΔE\*94 with application-specific weights.
+///
+/// Chroma and hue tolerances scale with the reference chroma, so swapping
+/// reference and sample changes the result. JND ≈ 1.0.
+#[derive(Debug, Clone, Copy)]
+pub struct Cie94 {
+    /// Lightness weight k_L.
+    pub k_l: f64,
+    /// Chroma weighting constant K₁.
+    pub k1: f64,
+    /// Hue weighting constant K₂.
+    pub k2: f64,
+}
+
+impl Cie94 {
+    /// Graphic-arts weights: k_L = 1, K₁ = 0.045, K₂ = 0.015.
+    #[must_use]
+    pub const fn graphic_arts() -> Self {
+        Self {
+            k_l: 1.0,
+            k1: 0.045,
+            k2: 0.015,
+        }
+    }
+
+    /// Textile weights: k_L = 2, K₁ = 0.048, K₂ = 0.014.
+    #[must_use]
+    pub const fn textiles() -> Self {
+        Self {
+            k_l: 2.0,
+            k1: 0.048,
+            k2: 0.014,
+        }
+    }
+
+    /// ΔE\*94 between two CIELAB colors.
+    #[must_use]
+    pub fn delta_e_lab(&self, reference: &Lab, sample: &Lab) -> f64 {
+        let (dl, dc, dh2, c1, _) = lch_differences(reference, sample);
+        let sc = 1.0 + self.k1 * c1;
+        let sh = 1.0 + self.k2 * c1;
+        let tl = dl / self.k_l;
+        let tc = dc / sc;
+        (tl * tl + tc * tc + dh2 / (sh * sh)).sqrt()
+    }
+}
+
+impl Default for Cie94 {
+    fn default() -> Self {
+        Self::graphic_arts()
+    }
+}
+
+impl ColorDifference for Cie94 {
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64 {
+        self.delta_e_lab(&Lab::from_color(reference), &Lab::from_color(sample))
+    }
+
+    fn jnd(&self) -> f64 {
+        1.0
+    }
+}
+
+// ============================================================================
+// CIEDE2000
+// ============================================================================
+
+/// CIEDE2000 ΔE00 with k_L = k_C = k_H = 1.
+///
+/// JND ≈ 1.0. The CIE-recommended formula for small differences between
+/// surface colors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ciede2000;
+
+impl ColorDifference for Ciede2000 {
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64 {
+        Lab::from_color(reference).delta_e_2000(&Lab::from_color(sample))
+    }
+
+    fn jnd(&self) -> f64 {
+        1.0
+    }
+}
+
+// ============================================================================
+// CMC l:c
+// ============================================================================
+
+/// CMC l:c (1984) with lightness weight `l` and chroma weight `c`.
+///
+/// Tolerances come from the reference, so the metric is asymmetric.
+/// ΔE CMC = 1.0 is the tolerance boundary the weights were fitted to: use
+/// [`Cmc::acceptability`] (2:1) for pass/fail and [`Cmc::perceptibility`]
+/// (1:1) for "can it be seen at all".
+#[derive(Debug, Clone, Copy)]
+pub struct Cmc {
+    /// Lightness weight.
+    pub l: f64,
+    /// Chroma weight.
+    pub c: f64,
+}
+
+impl Cmc {
+    /// Create a CMC l:c metric.
+    #[must_use]
+    pub const fn new(l: f64, c: f64) -> Self {
+        Self { l, c }
+    }
+
+    /// CMC 2:1, for acceptability judgements.
+    #[must_use]
+    pub const fn acceptability() -> Self {
+        Self::new(2.0, 1.0)
+    }
+
+    /// CMC 1:1, for perceptibility judgements.
+    #[must_use]
+    pub const fn perceptibility() -> Self {
+        Self::new(1.0, 1.0)
+    }
+
+    /// ΔE CMC between two CIELAB colors.
+    #[must_use]
+    pub fn delta_e_lab(&self, reference: &Lab, sample: &Lab) -> f64 {
+        let (dl, dc, dh2, c1, h1) = lch_differences(reference, sample);
+        let l1 = reference.l;
+
+        let sl = if l1 < 16.0 {
+            0.511
+        } else {
+            0.040975 * l1 / (1.0 + 0.01765 * l1)
+        };
+        let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+        let c1_4 = c1.powi(4);
+        let f = (c1_4 / (c1_4 + 1900.0)).sqrt();
+        let t = if (164.0..=345.0).contains(&h1) {
+            0.56 + (0.2 * ops::cos((h1 + 168.0).to_radians())).abs()
+        } else {
+            0.36 + (0.4 * ops::cos((h1 + 35.0).to_radians())).abs()
+        };
+        let sh = sc * (f * t + 1.0 - f);
+
+        let tl = dl / (self.l * sl);
+        let tc = dc / (self.c * sc);
+        (tl * tl + tc * tc + dh2 / (sh * sh)).sqrt()
+    }
+}
+
+impl Default for Cmc {
+    fn default() -> Self {
+        Self::acceptability()
+    }
+}
+
+impl ColorDifference for Cmc {
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64 {
+        self.delta_e_lab(&Lab::from_color(reference), &Lab::from_color(sample))
+    }
+
+    fn jnd(&self) -> f64 {
+        1.0
+    }
+}
+
+// ============================================================================
+// ΔEOK
+// ============================================================================
+
+/// ΔEOK: Euclidean distance in OKLab (L in 0–1).
+///
+/// JND ≈ 0.02, the threshold CSS Color 4 gamut mapping treats as
+/// imperceptible. Multiply by 100 to compare with CIELAB-scaled metrics.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeltaEOk;
+
+impl ColorDifference for DeltaEOk {
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64 {
+        let a = OKLab::from_color(reference);
+        let b = OKLab::from_color(sample);
+        let dl = a.l - b.l;
+        let da = a.a - b.a;
+        let db = a.b - b.b;
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    fn jnd(&self) -> f64 {
+        0.02
+    }
+}
+
+// ============================================================================
+// CAM16-UCS
+// ============================================================================
+
+/// CAM16-UCS ΔE': Euclidean distance in J'a'b'.
+///
+/// `J' = 1.7J / (1 + 0.007J)`, `M' = ln(1 + 0.0228M) / 0.0228`,
+/// `a' = M'·cos h`, `b' = M'·sin h`. The space is scaled so one unit is
+/// close to one CIEDE2000 unit, giving JND ≈ 1.0.
+#[derive(Debug, Clone)]
+pub struct Cam16Ucs {
+    /// Viewing conditions for the CAM16 forward model.
+    pub viewing_conditions: ViewingConditions,
+}
+
+impl Cam16Ucs {
+    /// CAM16-UCS under the given viewing conditions.
+    #[must_use]
+    pub fn new(viewing_conditions: ViewingConditions) -> Self {
+        Self { viewing_conditions }
+    }
+
+    /// `[J', a', b']` of a color.
+    #[must_use]
+    pub fn jab(&self, color: &Color) -> [f64; 3] {
+        let xyz = color.to_xyz_d65().map(|v| v * 100.0);
+        let cam = CAM16::from_xyz(xyz, &self.viewing_conditions);
+        let j = 1.7 * cam.j / (1.0 + 0.007 * cam.j);
+        let m = ops::ln_1p(0.0228 * cam.m) / 0.0228;
+        let h = cam.h.to_radians();
+        [j, m * ops::cos(h), m * ops::sin(h)]
+    }
+}
+
+impl Default for Cam16Ucs {
+    /// Default sRGB viewing conditions, as used by HCT.
+    fn default() -> Self {
+        Self::new(ViewingConditions::s_rgb())
+    }
+}
+
+impl ColorDifference for Cam16Ucs {
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64 {
+        let [j1, a1, b1] = self.jab(reference);
+        let [j2, a2, b2] = self.jab(sample);
+        let (dj, da, db) = (j1 - j2, a1 - a2, b1 - b2);
+        (dj * dj + da * da + db * db).sqrt()
+    }
+
+    fn jnd(&self) -> f64 {
+        1.0
+    }
+}
+
+// ============================================================================
+// ΔE ITP
+// ============================================================================
+
+/// ΔE ITP (ITU-R BT.2124) in ICtCp, with SDR white at 203 cd/m².
+///
+/// JND = 1.0 by construction. Unlike the CIELAB metrics it stays valid for
+/// HDR content; use [`HdrColor::delta_e_itp`] directly for colors brighter
+/// than reference white.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeltaEItp;
+
+impl ColorDifference for DeltaEItp {
+    fn delta_e(&self, reference: &Color, sample: &Color) -> f64 {
+        HdrColor::from_color(reference).delta_e_itp(&HdrColor::from_color(sample))
+    }
+
+    fn jnd(&self) -> f64 {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+
+    /// Sharma et al. (2005), pair 1.
+    const SHARMA_1: (Lab, Lab) = (
+        Lab::new(50.0, 2.6772, -79.7751),
+        Lab::new(50.0, 0.0, -82.7485),
+    );
+
+    fn all_metrics() -> [Box<dyn ColorDifference>; 7] {
+        [
+            Box::new(Cie76),
+            Box::new(Cie94::default()),
+            Box::new(Ciede2000),
+            Box::new(Cmc::default()),
+            Box::new(DeltaEOk),
+            Box::new(Cam16Ucs::default()),
+            Box::new(DeltaEItp),
+        ]
+    }
+
+    #[test]
+    fn test_cie94_reference_values() {
+        let (a, b) = SHARMA_1;
+        assert!((Cie94::graphic_arts().delta_e_lab(&a, &b) - 1.3950).abs() < 1e-4);
+        assert!((Cie94::textiles().delta_e_lab(&a, &b) - 1.4230).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_cmc_reference_values() {
+        let (a, b) = SHARMA_1;
+        assert!((Cmc::acceptability().delta_e_lab(&a, &b) - 1.7387).abs() < 1e-4);
+
+        let dark = Lab::new(10.0, 5.0, 5.0);
+        let lighter = Lab::new(12.0, 5.0, 5.0);
+        let ratio = Cmc::perceptibility().delta_e_lab(&dark, &lighter)
+            / Cmc::acceptability().delta_e_lab(&dark, &lighter);
+        assert!((ratio - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_identical_colors_are_zero() {
+        let c = Color::from_srgb8(200, 80, 40);
+        for metric in all_metrics() {
+            assert!(metric.delta_e(&c, &c).abs() < 1e-9);
+            assert!(!metric.is_noticeable(&c, &c));
+        }
+    }
+
+    #[test]
+    fn test_black_white_scales() {
+        let black = Color::from_srgb8(0, 0, 0);
+        let white = Color::from_srgb8(255, 255, 255);
+
+        assert!((Cie76.delta_e(&black, &white) - 100.0).abs() < 0.01);
+        assert!((DeltaEOk.delta_e(&black, &white) - 1.0).abs() < 1e-3);
+        // J' of white: 1.7·100 / 1.7 = 100.
+        assert!((Cam16Ucs::default().jab(&white)[0] - 100.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let a = Color::from_srgb8(30, 144, 255);
+        let b = Color::from_srgb8(40, 120, 200);
+
+        for metric in [
+            Box::new(Cie76) as Box<dyn ColorDifference>,
+            Box::new(Ciede2000),
+            Box::new(DeltaEOk),
+            Box::new(Cam16Ucs::default()),
+            Box::new(DeltaEItp),
+        ] {
+            assert!((metric.delta_e(&a, &b) - metric.delta_e(&b, &a)).abs() < 1e-9);
+        }
+        assert!((Cie94::default().delta_e(&a, &b) - Cie94::default().delta_e(&b, &a)).abs() > 1e-3);
+    }
+
+    #[test]
+    fn test_jnd_units_flag_visible_difference() {
+        // Two Tailwind blues, clearly distinguishable side by side.
+        let a = Color::from_srgb8(59, 130, 246);
+        let b = Color::from_srgb8(37, 99, 235);
+
+        for metric in all_metrics() {
+            let units = metric.jnd_units(&a, &b);
+            assert!(units > 2.0 && units.is_finite(), "{}", units);
+        }
+    }
+
+    #[test]
+    fn test_batch_and_nearest() {
+        let reference = Color::from_srgb8(255, 0, 0);
+        let samples = [
+            Color::from_srgb8(0, 0, 255),
+            Color::from_srgb8(250, 10, 10),
+            Color::from_srgb8(0, 255, 0),
+        ];
+
+        let batch = Ciede2000.delta_e_batch(&reference, &samples);
+        assert_eq!(batch.len(), 3);
+        assert_eq!(Ciede2000.nearest(&reference, &samples).unwrap().0, 1);
+        assert_eq!(Ciede2000.nearest(&reference, &[]), None);
+
+        let pairs: Vec<ColorPair> = samples.iter().map(|s| (reference, *s)).collect();
+        assert_eq!(Ciede2000.delta_e_pairs(&pairs), batch);
+    }
+
+    #[test]
+    fn test_perceptual_metric_impl() {
+        let pair = (Color::from_srgb8(0, 0, 0), Color::from_srgb8(255, 255, 255));
+        assert!((PerceptualMetric::measure(&Cie76, &pair) - 100.0).abs() < 0.01);
+        assert_eq!(PerceptualMetric::name(&DeltaEItp), "delta_e_itp");
+        assert_eq!(PerceptualMetric::unit(&Ciede2000), "ΔE00");
+        assert!(!Cmc::default().higher_is_better());
+    }
+}
@@ -1,12 +1,16 @@
 //! # Momoto Metrics
 //!
-//! Contrast metric implementations (APCA, WCAG 2.x).
+//! Contrast metric implementations (APCA, SAPC, WCAG 2.x) and color-difference
+//! metrics (CIE76 through ΔE ITP).
 //!
 //! This crate provides concrete implementations of the `ContrastMetric` trait
 //! from `momoto-core`.
 //!
 //! ## Features
 //!
+//! - `std` (default): Standard library support
+//! - `libm`: `no_std` + `alloc` build with float math from `libm`; APCA,
+//!   SAPC and WCAG are fully available
 //! - `internals`: Exposes algorithm constants for debugging and education
 //!
 //! ## Quick Start
@@ -28,7 +32,7 @@
 //! assert!((wcag.value - 21.0).abs() < 0.01);
 //! ```
 
-// Note: no_std support planned for future release
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(
     missing_docs,
     missing_debug_implementations,
@@ -36,15 +40,27 @@
     unreachable_pub
 )]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("momoto-metrics requires either the `std` or the `libm` feature");
+
+extern crate alloc;
+
 pub mod apca;
+pub mod background;
+pub mod difference;
+pub mod sapc;
 pub mod wcag;
 
-// TODO: SAPC (Simplified APCA) implementation
-// Planned for future release
-// pub mod sapc;
-
 // Re-export metrics
+pub use apca::readability::{
+    check_readability, min_lc, ConformanceLevel, FontRequirement, ReadabilityResult, TextRole,
+};
 pub use apca::APCAMetric;
+pub use background::{evaluate_background, BackgroundContrast, BackgroundContrastOptions};
+pub use difference::{
+    Cam16Ucs, Cie76, Cie94, Ciede2000, Cmc, ColorDifference, DeltaEItp, DeltaEOk,
+};
+pub use sapc::SAPCMetric;
 pub use wcag::{TextSize, WCAGLevel, WCAGMetric, WCAG_REQUIREMENTS};
 
 // Re-export APCA constants when internals feature is enabled
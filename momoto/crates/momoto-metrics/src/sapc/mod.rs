@@ -1,5 +1,342 @@
-//! SAPC (S-Luv Advanced Perceptual Contrast) implementation.
+//! SAPC (S-Luv Advanced Perceptual Contrast) Implementation
+//!
+//! Implements SAPC 0.98G-4g, the research model APCA was later derived
+//! from. Both share the power-curve luminance estimate and the polarity
+//! exponents, so above the low-contrast region SAPC and APCA-W3 0.1.9 agree.
+//! They differ near the threshold of visibility:
+//!
+//! | | SAPC 0.98G-4g | APCA-W3 0.1.9 |
+//! |---|---|---|
+//! | Clip threshold | 0.001 | 0.1 |
+//! | Low contrast | offset ramps in below 0.035991 | full offset |
+//!
+//! so SAPC keeps reporting (small) Lc values for near-identical pairs where
+//! APCA reports 0, which makes it useful for ranking subtle UI surfaces.
+//!
+//! # References
+//!
+//! - [SAPC-APCA GitHub](https://github.com/Myndex/SAPC-APCA)
+//! - [SAPC research tool](https://www.myndex.com/SAPC/)
 
-/// SAPC contrast metric (placeholder).
-#[derive(Debug)]
+use momoto_core::color::blend::composite_for_contrast;
+use momoto_core::color::Color;
+use momoto_core::math::ops;
+use momoto_core::perception::{ContrastMetric, PerceptualResult, Polarity};
+use momoto_core::traits::perceptual::FlatPerceptualMetric;
+
+// ============================================================================
+// SAPC Constants (SA98G, series 4g)
+// ============================================================================
+
+/// Main TRC (gamma) of the luminance estimate
+const MAIN_TRC: f64 = 2.4;
+
+/// sRGB luminance coefficients
+const S_R_CO: f64 = 0.2126729;
+const S_G_CO: f64 = 0.7151522;
+const S_B_CO: f64 = 0.0721750;
+
+/// Soft clamp threshold and exponent for very dark colors
+const BLK_THRS: f64 = 0.022;
+const BLK_CLMP: f64 = 1.414;
+
+/// Polarity exponents and scale factors
+const NORM_BG: f64 = 0.56;
+const NORM_TXT: f64 = 0.57;
+const REV_BG: f64 = 0.65;
+const REV_TXT: f64 = 0.62;
+const SCALE_BOW: f64 = 1.14;
+const SCALE_WOB: f64 = 1.14;
+
+/// Low contrast ramp: below the threshold the offset is scaled by
+/// `SAPC * LO_FACTOR` (`LO_FACTOR = 1 / LO_THRESH`), so output is continuous
+const LO_BOW_THRESH: f64 = 0.035991;
+const LO_WOB_THRESH: f64 = 0.035991;
+const LO_BOW_FACTOR: f64 = 27.7847239587675;
+const LO_WOB_FACTOR: f64 = 27.7847239587675;
+const LO_BOW_OFFSET: f64 = 0.027;
+const LO_WOB_OFFSET: f64 = 0.027;
+
+/// Clipping threshold (values closer to zero become 0)
+const LO_CLIP: f64 = 0.001;
+
+/// Minimum luminance delta threshold
+const DELTA_Y_MIN: f64 = 0.0005;
+
+// ============================================================================
+// SAPC Metric Implementation
+// ============================================================================
+
+/// SAPC (S-Luv Advanced Perceptual Contrast) Metric
+///
+/// # Output
+///
+/// Returns a signed Lc value on the same scale as [`APCAMetric`]:
+/// - Positive: Dark text on light background
+/// - Negative: Light text on dark background
+/// - Range: approximately -108 to +106
+///
+/// # Examples
+///
+/// ```
+/// use momoto_core::color::Color;
+/// use momoto_core::perception::ContrastMetric;
+/// use momoto_metrics::sapc::SAPCMetric;
+///
+/// let white = Color::from_srgb8(255, 255, 255);
+///
+/// // Same as APCA at high contrast
+/// let result = SAPCMetric.evaluate(Color::from_srgb8(0, 0, 0), white);
+/// assert!((result.value - 106.04).abs() < 0.01);
+///
+/// // Still measurable where APCA clips to 0
+/// let subtle = SAPCMetric.evaluate(Color::from_srgb8(240, 240, 240), white);
+/// assert!((subtle.value - 6.37).abs() < 0.01);
+/// ```
+///
+/// [`APCAMetric`]: crate::apca::APCAMetric
+#[derive(Debug, Clone, Copy, Default)]
 pub struct SAPCMetric;
+
+impl SAPCMetric {
+    /// Creates a new SAPC metric instance
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Estimate screen luminance (Y) of an sRGB color
+    ///
+    /// Uses a pure 2.4 power curve (no linear toe) per channel, then soft
+    /// clamps near black so flare keeps very dark pairs from overstating
+    /// contrast.
+    #[inline]
+    #[must_use]
+    pub fn estimate_luminance(color: Color) -> f64 {
+        let [r, g, b] = color.srgb;
+        let y = S_R_CO * ops::powf(r, MAIN_TRC)
+            + S_G_CO * ops::powf(g, MAIN_TRC)
+            + S_B_CO * ops::powf(b, MAIN_TRC);
+
+        if y <= BLK_THRS {
+            y + ops::powf(BLK_THRS - y, BLK_CLMP)
+        } else {
+            y
+        }
+    }
+
+    /// Calculate SAPC contrast (Lc) from estimated luminances
+    ///
+    /// # Algorithm Flow
+    ///
+    /// 1. Return 0 if the luminances are within `DELTA_Y_MIN`
+    /// 2. Pick polarity (normal: background lighter than text)
+    /// 3. Apply the polarity's exponents and scale
+    /// 4. Clip below `LO_CLIP`, ramp the offset in below `LO_*_THRESH`,
+    ///    otherwise subtract the full offset
+    /// 5. Scale to Lc (× 100)
+    fn calculate_lc(text_y: f64, back_y: f64) -> f64 {
+        if (back_y - text_y).abs() < DELTA_Y_MIN {
+            return 0.0;
+        }
+
+        let output_contrast = if back_y > text_y {
+            // Dark text on light background (normal polarity)
+            let sapc = (ops::powf(back_y, NORM_BG) - ops::powf(text_y, NORM_TXT)) * SCALE_BOW;
+            if sapc < LO_CLIP {
+                0.0
+            } else if sapc < LO_BOW_THRESH {
+                sapc - sapc * LO_BOW_FACTOR * LO_BOW_OFFSET
+            } else {
+                sapc - LO_BOW_OFFSET
+            }
+        } else {
+            // Light text on dark background (reverse polarity)
+            let sapc = (ops::powf(back_y, REV_BG) - ops::powf(text_y, REV_TXT)) * SCALE_WOB;
+            if sapc > -LO_CLIP {
+                0.0
+            } else if sapc > -LO_WOB_THRESH {
+                sapc - sapc * LO_WOB_FACTOR * LO_WOB_OFFSET
+            } else {
+                sapc + LO_WOB_OFFSET
+            }
+        };
+
+        output_contrast * 100.0
+    }
+
+    /// Build the result, deriving polarity from the sign of Lc
+    fn to_result(lc: f64) -> PerceptualResult {
+        let polarity = if lc < 0.0 {
+            Polarity::LightOnDark
+        } else {
+            Polarity::DarkOnLight
+        };
+        PerceptualResult::with_polarity(lc, polarity)
+    }
+}
+
+impl ContrastMetric for SAPCMetric {
+    /// Translucent colors are composited first (see
+    /// [`composite_for_contrast`]), so Lc is that of the rendered pair.
+    fn evaluate(&self, foreground: Color, background: Color) -> PerceptualResult {
+        let (foreground, background) = composite_for_contrast(foreground, background);
+        Self::to_result(Self::calculate_lc(
+            Self::estimate_luminance(foreground),
+            Self::estimate_luminance(background),
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "SAPC"
+    }
+
+    fn version(&self) -> &'static str {
+        "0.98G-4g"
+    }
+}
+
+/// Flat layout: `[text_r, text_g, text_b, bg_r, bg_g, bg_b]`, gamma-encoded
+/// sRGB in 0.0-1.0. Extra samples are ignored; fewer than 6 measure 0.
+impl FlatPerceptualMetric for SAPCMetric {
+    fn measure_flat(&self, samples: &[f32]) -> f32 {
+        let [tr, tg, tb, br, bg, bb] = match samples {
+            [tr, tg, tb, br, bg, bb, ..] => [*tr, *tg, *tb, *br, *bg, *bb].map(f64::from),
+            _ => return 0.0,
+        };
+        let text = Color::from_srgb(tr, tg, tb);
+        let back = Color::from_srgb(br, bg, bb);
+        Self::calculate_lc(
+            Self::estimate_luminance(text),
+            Self::estimate_luminance(back),
+        ) as f32
+    }
+
+    fn name(&self) -> &'static str {
+        "sapc_lc"
+    }
+
+    fn unit(&self) -> &'static str {
+        "Lc"
+    }
+
+    fn min_samples(&self) -> usize {
+        6
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use crate::apca::APCAMetric;
+
+    fn hex(rgb: u32) -> Color {
+        Color::from_srgb8((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+
+    /// (text, background, Lc) for SAPC 0.98G-4g.
+    const GOLDEN: [(u32, u32, f64); 9] = [
+        (0x000000, 0xffffff, 106.040_673_212_688_6),
+        (0xffffff, 0x000000, -107.884_733_183_098_5),
+        (0x888888, 0xffffff, 63.056_469_930_209_42),
+        (0xffffff, 0x888888, -68.541_464_366_449_62),
+        (0x112233, 0xddeeff, 91.668_308_114_816_31),
+        (0xeeeeee, 0xffffff, 7.567_424_744_881_615),
+        (0xf0f0f0, 0xffffff, 6.373_095_311_965_697),
+        (0xfafafa, 0xffffff, 0.761_129_609_571_343_7),
+        (0x202020, 0x000000, -0.737_941_128_031_917_7),
+    ];
+
+    #[test]
+    fn test_golden_vectors() {
+        for (text, back, expected) in GOLDEN {
+            let lc = SAPCMetric.evaluate(hex(text), hex(back)).value;
+            assert!(
+                (lc - expected).abs() < 1e-9,
+                "#{:06x} on #{:06x}: {} != {}",
+                text,
+                back,
+                lc,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_apca_above_low_contrast_region() {
+        for (text, back, _) in &GOLDEN[..5] {
+            let sapc = SAPCMetric.evaluate(hex(*text), hex(*back)).value;
+            let apca = APCAMetric.evaluate(hex(*text), hex(*back)).value;
+            assert!((sapc - apca).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_low_contrast_ramp_is_continuous() {
+        // Just above and just below the ramp threshold, in luminance steps
+        // small enough that the outputs must be close.
+        let lc = |sapc: f64| {
+            let back_y = 1.0;
+            let text_y = ops::powf(1.0 - sapc / SCALE_BOW, 1.0 / NORM_TXT);
+            SAPCMetric::calculate_lc(text_y, back_y)
+        };
+        let below = lc(LO_BOW_THRESH - 1e-9);
+        let above = lc(LO_BOW_THRESH + 1e-9);
+        assert!((below - above).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_identical_colors_zero() {
+        let gray = hex(0x777777);
+        let result = SAPCMetric.evaluate(gray, gray);
+        assert_eq!(result.value, 0.0);
+        assert_eq!(result.polarity, Some(Polarity::DarkOnLight));
+    }
+
+    #[test]
+    fn test_polarity() {
+        let black = hex(0x000000);
+        let white = hex(0xffffff);
+        assert_eq!(
+            SAPCMetric.evaluate(black, white).polarity,
+            Some(Polarity::DarkOnLight)
+        );
+        assert_eq!(
+            SAPCMetric.evaluate(white, black).polarity,
+            Some(Polarity::LightOnDark)
+        );
+    }
+
+    #[test]
+    fn test_flat_matches_color_api() {
+        let flat = SAPCMetric.measure_flat(&[0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+        assert!((f64::from(flat) - GOLDEN[0].2).abs() < 1e-4);
+        assert_eq!(SAPCMetric.measure_flat(&[0.0, 0.0, 0.0]), 0.0);
+        assert_eq!(SAPCMetric.min_samples(), 6);
+        assert_eq!(FlatPerceptualMetric::name(&SAPCMetric), "sapc_lc");
+        assert_eq!(ContrastMetric::name(&SAPCMetric), "SAPC");
+    }
+
+    #[test]
+    fn test_batch_matches_single() {
+        let fgs: Vec<Color> = GOLDEN.iter().map(|(t, _, _)| hex(*t)).collect();
+        let bgs: Vec<Color> = GOLDEN.iter().map(|(_, b, _)| hex(*b)).collect();
+        let batch = SAPCMetric.evaluate_batch(&fgs, &bgs);
+        for (i, result) in batch.iter().enumerate() {
+            assert_eq!(result.value, SAPCMetric.evaluate(fgs[i], bgs[i]).value);
+        }
+    }
+
+    #[test]
+    fn test_translucent_foreground_is_composited() {
+        let white = hex(0xffffff);
+        let half_black = hex(0x000000).with_alpha(0.5);
+        let gray = Color::from_srgb(0.5, 0.5, 0.5);
+
+        let translucent = SAPCMetric.evaluate(half_black, white).value;
+        let rendered = SAPCMetric.evaluate(gray, white).value;
+        assert!((translucent - rendered).abs() < 1e-9);
+    }
+}
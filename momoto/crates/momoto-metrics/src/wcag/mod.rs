@@ -6,6 +6,11 @@
 //! This implementation follows the canonical Rust core principle:
 //! it is the source of truth for WCAG contrast calculations.
 
+pub mod non_text;
+
+use alloc::vec::Vec;
+
+use momoto_core::color::blend::composite_for_contrast;
 use momoto_core::color::Color;
 use momoto_core::luminance::relative_luminance_srgb;
 use momoto_core::perception::{ContrastMetric, PerceptualResult};
@@ -213,7 +218,10 @@ impl WCAGMetric {
 }
 
 impl ContrastMetric for WCAGMetric {
+    /// Translucent colors are composited first (see
+    /// [`composite_for_contrast`]), so the ratio is that of the rendered pair.
     fn evaluate(&self, foreground: Color, background: Color) -> PerceptualResult {
+        let (foreground, background) = composite_for_contrast(foreground, background);
         let ratio = Self::calculate_ratio(foreground, background);
         PerceptualResult::new(ratio)
     }
@@ -247,12 +255,10 @@ impl ContrastMetric for WCAGMetric {
         let mut fg_luminances = Vec::with_capacity(len);
         let mut bg_luminances = Vec::with_capacity(len);
 
-        for fg in foregrounds {
-            fg_luminances.push(relative_luminance_srgb(fg).value());
-        }
-
-        for bg in backgrounds {
-            bg_luminances.push(relative_luminance_srgb(bg).value());
+        for (&fg, &bg) in foregrounds.iter().zip(backgrounds) {
+            let (fg, bg) = composite_for_contrast(fg, bg);
+            fg_luminances.push(relative_luminance_srgb(&fg).value());
+            bg_luminances.push(relative_luminance_srgb(&bg).value());
         }
 
         // Compute contrast ratios
@@ -639,4 +645,40 @@ mod tests {
 
         WCAGMetric.evaluate_batch(&fgs, &bgs);
     }
+
+    // ============================================
+    // Translucent Colors
+    // ============================================
+
+    #[test]
+    fn test_translucent_foreground_is_composited() {
+        let white = Color::from_srgb8(255, 255, 255);
+        let half_black = Color::from_srgb8(0, 0, 0).with_alpha(0.5);
+        // What a browser paints: 50% of the way from white to black
+        let gray = Color::from_srgb(0.5, 0.5, 0.5);
+
+        let translucent = WCAGMetric.evaluate(half_black, white);
+        let rendered = WCAGMetric.evaluate(gray, white);
+        assert!((translucent.value - rendered.value).abs() < 1e-9);
+
+        // Fully transparent text has no contrast at all
+        let invisible = WCAGMetric.evaluate(half_black.with_alpha(0.0), white);
+        assert!(invisible.value.abs() < 1.01);
+    }
+
+    #[test]
+    fn test_translucent_batch_matches_single() {
+        let white = Color::from_srgb8(255, 255, 255);
+        let fgs = vec![
+            Color::from_srgb8(0, 0, 0).with_alpha(0.6),
+            Color::from_srgb8(255, 255, 255).with_alpha(0.3),
+        ];
+        let bgs = vec![white, Color::from_srgb8(20, 40, 80).with_alpha(0.5)];
+
+        let batch = WCAGMetric.evaluate_batch(&fgs, &bgs);
+        for i in 0..fgs.len() {
+            let single = WCAGMetric.evaluate(fgs[i], bgs[i]);
+            assert!((batch[i].value - single.value).abs() < 1e-9);
+        }
+    }
 }
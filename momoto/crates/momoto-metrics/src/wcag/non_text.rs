@@ -0,0 +1,533 @@
+//! WCAG 2.2 non-text contrast and focus evaluators
+//!
+//! Geometry-aware checks for UI components:
+//!
+//! | SC | Level | Rule | Check |
+//! |----|-------|------|-------|
+//! | 1.4.11 Non-text Contrast | AA | `WCAG_1.4.11_component_boundary` | fill or border ≥ 3:1 against adjacent colors |
+//! | | | `WCAG_1.4.11_state_indicator` | checkmark / knob / dot ≥ 3:1 against the fill |
+//! | | | `WCAG_1.4.11_focus_indicator` | focus indicator ≥ 3:1 against adjacent colors |
+//! | 2.4.11 Focus Not Obscured (Minimum) | AA | `WCAG_2.4.11_focus_obscured` | focused component not entirely covered |
+//! | 2.4.12 Focus Not Obscured (Enhanced) | AAA | `WCAG_2.4.12_focus_partially_obscured` | no part covered |
+//! | 2.4.13 Focus Appearance | AAA | `WCAG_2.4.13_area` | indicator area ≥ a 2 px perimeter of the component |
+//! | | | `WCAG_2.4.13_contrast` | indicator pixels change ≥ 3:1 between states |
+//!
+//! AA failures are [`ViolationSeverity::Error`], AAA failures
+//! [`ViolationSeverity::Warning`]. Each violation's `measured`/`threshold`
+//! carry the contrast ratio, area in px² or visible fraction.
+//!
+//! State colors are the rendered colors of the same pixels in the
+//! unfocused and focused states, e.g. a component resolved for
+//! `UIState::Idle` and `UIState::Focus`.
+//!
+//! # Example
+//!
+//! ```
+//! use momoto_core::color::background::Region;
+//! use momoto_core::color::Color;
+//! use momoto_metrics::wcag::non_text::{evaluate_component, Component, FocusIndicator};
+//!
+//! let white = Color::from_srgb8(255, 255, 255);
+//! let button = Component::new(Region::new(0.0, 0.0, 120.0, 40.0), white, white)
+//!     .with_border(1.0, Color::from_srgb8(118, 118, 118));
+//!
+//! // 2px blue outline, 2px away from the button.
+//! let focus = FocusIndicator::outline(2.0, 2.0, Color::from_srgb8(37, 99, 235), white);
+//!
+//! let report = evaluate_component(&button, Some(&focus), &[]);
+//! assert!(report.passes);
+//! ```
+//!
+//! # References
+//!
+//! - [Understanding SC 1.4.11](https://www.w3.org/WAI/WCAG22/Understanding/non-text-contrast.html)
+//! - [Understanding SC 2.4.11](https://www.w3.org/WAI/WCAG22/Understanding/focus-not-obscured-minimum.html)
+//! - [Understanding SC 2.4.13](https://www.w3.org/WAI/WCAG22/Understanding/focus-appearance.html)
+
+use alloc::vec::Vec;
+
+use momoto_core::color::background::Region;
+use momoto_core::color::Color;
+use momoto_core::perception::ContrastMetric;
+use momoto_core::traits::compliance::{ComplianceReport, ComplianceViolation, ViolationSeverity};
+
+use super::WCAGMetric;
+
+/// Standard name used in every report from this module.
+pub const STANDARD: &str = "WCAG 2.2";
+
+/// Minimum contrast for non-text content and focus indicators.
+pub const NON_TEXT_MIN_RATIO: f64 = 3.0;
+
+/// Perimeter thickness, in CSS px, the 2.4.13 indicator area is compared to.
+pub const FOCUS_PERIMETER_PX: f64 = 2.0;
+
+/// A solid component border.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Border {
+    /// Width in CSS px.
+    pub width: f64,
+    /// Border color.
+    pub color: Color,
+}
+
+/// A UI component in its unfocused state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Component {
+    /// Position and size in CSS px.
+    pub bounds: Region,
+    /// Background fill.
+    pub fill: Color,
+    /// Border, if any.
+    pub border: Option<Border>,
+    /// Color surrounding the component.
+    pub adjacent: Color,
+    /// State indicator drawn on the fill (checkmark, radio dot, toggle knob).
+    pub state_indicator: Option<Color>,
+}
+
+impl Component {
+    /// Borderless component with `fill` on an `adjacent` background.
+    #[must_use]
+    pub const fn new(bounds: Region, fill: Color, adjacent: Color) -> Self {
+        Self {
+            bounds,
+            fill,
+            border: None,
+            adjacent,
+            state_indicator: None,
+        }
+    }
+
+    /// Add a solid border.
+    #[must_use]
+    pub const fn with_border(mut self, width: f64, color: Color) -> Self {
+        self.border = Some(Border { width, color });
+        self
+    }
+
+    /// Add a state indicator drawn on the fill.
+    #[must_use]
+    pub const fn with_state_indicator(mut self, color: Color) -> Self {
+        self.state_indicator = Some(color);
+        self
+    }
+
+    /// Area of a [`FOCUS_PERIMETER_PX`]-thick perimeter: the minimum
+    /// 2.4.13 indicator area.
+    #[must_use]
+    pub fn focus_perimeter_area(&self) -> f64 {
+        2.0 * FOCUS_PERIMETER_PX * (self.bounds.width + self.bounds.height)
+    }
+}
+
+/// Where the focus indicator is drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndicatorShape {
+    /// Ring outside the component (`outline` with `outline-offset`).
+    Outline {
+        /// Ring thickness in CSS px.
+        width: f64,
+        /// Gap between component and ring in CSS px (≥ 0).
+        offset: f64,
+    },
+    /// Ring inside the component edge (border color change, inset shadow).
+    Border {
+        /// Ring thickness in CSS px.
+        width: f64,
+    },
+    /// Whole component changes color.
+    Fill,
+}
+
+/// Focus indicator: its shape and the color of its pixels in each state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusIndicator {
+    /// Indicator geometry.
+    pub shape: IndicatorShape,
+    /// Indicator pixels in the focused state.
+    pub focused: Color,
+    /// The same pixels in the unfocused state.
+    pub unfocused: Color,
+}
+
+impl FocusIndicator {
+    /// Outline of `color`; unfocused, those pixels show `adjacent`.
+    #[must_use]
+    pub const fn outline(width: f64, offset: f64, color: Color, adjacent: Color) -> Self {
+        Self {
+            shape: IndicatorShape::Outline { width, offset },
+            focused: color,
+            unfocused: adjacent,
+        }
+    }
+
+    /// Indicator area in px² around or within `bounds`.
+    #[must_use]
+    pub fn area(&self, bounds: &Region) -> f64 {
+        let (w, h) = (bounds.width.max(0.0), bounds.height.max(0.0));
+        match self.shape {
+            IndicatorShape::Outline { width, offset } => {
+                let inner = (w + 2.0 * offset.max(0.0), h + 2.0 * offset.max(0.0));
+                let t = width.max(0.0);
+                (inner.0 + 2.0 * t) * (inner.1 + 2.0 * t) - inner.0 * inner.1
+            }
+            IndicatorShape::Border { width } => {
+                let t = width.max(0.0);
+                w * h - (w - 2.0 * t).max(0.0) * (h - 2.0 * t).max(0.0)
+            }
+            IndicatorShape::Fill => w * h,
+        }
+    }
+}
+
+fn ratio(a: Color, b: Color) -> f64 {
+    WCAGMetric.evaluate(a, b).value
+}
+
+fn violation(
+    rule: &'static str,
+    severity: ViolationSeverity,
+    measured: f64,
+    threshold: f64,
+    description: &'static str,
+) -> ComplianceViolation {
+    ComplianceViolation::with_description(
+        rule,
+        severity,
+        measured as f32,
+        threshold as f32,
+        description,
+    )
+}
+
+/// SC 1.4.11 for the component itself: boundary and state indicator.
+///
+/// The boundary passes if either the fill or the border reaches 3:1
+/// against the adjacent color.
+#[must_use]
+pub fn check_non_text_contrast(component: &Component) -> ComplianceReport {
+    let mut report = ComplianceReport::new(STANDARD);
+
+    let fill = ratio(component.fill, component.adjacent);
+    let border = component
+        .border
+        .filter(|b| b.width > 0.0)
+        .map_or(1.0, |b| ratio(b.color, component.adjacent));
+    let boundary = fill.max(border);
+    report.require(
+        boundary >= NON_TEXT_MIN_RATIO,
+        violation(
+            "WCAG_1.4.11_component_boundary",
+            ViolationSeverity::Error,
+            boundary,
+            NON_TEXT_MIN_RATIO,
+            "Neither fill nor border separates the component from its surroundings",
+        ),
+    );
+
+    if let Some(indicator) = component.state_indicator {
+        let contrast = ratio(indicator, component.fill);
+        report.require(
+            contrast >= NON_TEXT_MIN_RATIO,
+            violation(
+                "WCAG_1.4.11_state_indicator",
+                ViolationSeverity::Error,
+                contrast,
+                NON_TEXT_MIN_RATIO,
+                "State indicator is hard to see against the component fill",
+            ),
+        );
+    }
+
+    report
+}
+
+/// SC 2.4.13 Focus Appearance, plus SC 1.4.11 for the indicator.
+///
+/// The indicator must reach 3:1 against every color it touches: the
+/// adjacent color, and the component's outer edge when it is not separated
+/// from it by an offset.
+#[must_use]
+pub fn check_focus_appearance(
+    component: &Component,
+    indicator: &FocusIndicator,
+) -> ComplianceReport {
+    let mut report = ComplianceReport::new(STANDARD);
+
+    let edge = component
+        .border
+        .filter(|b| b.width > 0.0)
+        .map_or(component.fill, |b| b.color);
+    let mut neighbours = Vec::with_capacity(2);
+    match indicator.shape {
+        IndicatorShape::Outline { offset, .. } => {
+            neighbours.push(component.adjacent);
+            if offset <= 0.0 {
+                neighbours.push(edge);
+            }
+        }
+        IndicatorShape::Border { .. } => {
+            neighbours.push(component.adjacent);
+            neighbours.push(component.fill);
+        }
+        IndicatorShape::Fill => neighbours.push(component.adjacent),
+    }
+    let adjacent_contrast = neighbours
+        .iter()
+        .map(|&c| ratio(indicator.focused, c))
+        .fold(f64::INFINITY, f64::min);
+    report.require(
+        adjacent_contrast >= NON_TEXT_MIN_RATIO,
+        violation(
+            "WCAG_1.4.11_focus_indicator",
+            ViolationSeverity::Error,
+            adjacent_contrast,
+            NON_TEXT_MIN_RATIO,
+            "Focus indicator does not stand out from adjacent colors",
+        ),
+    );
+
+    let area = indicator.area(&component.bounds);
+    let min_area = component.focus_perimeter_area();
+    report.require(
+        area >= min_area,
+        violation(
+            "WCAG_2.4.13_area",
+            ViolationSeverity::Warning,
+            area,
+            min_area,
+            "Focus indicator is smaller than a 2px perimeter of the component",
+        ),
+    );
+
+    let change = ratio(indicator.focused, indicator.unfocused);
+    report.require(
+        change >= NON_TEXT_MIN_RATIO,
+        violation(
+            "WCAG_2.4.13_contrast",
+            ViolationSeverity::Warning,
+            change,
+            NON_TEXT_MIN_RATIO,
+            "Focus indicator pixels change too little between states",
+        ),
+    );
+
+    report
+}
+
+/// Fraction of `bounds` not covered by any of `obscuring`, in [0, 1].
+#[must_use]
+pub fn visible_fraction(bounds: &Region, obscuring: &[Region]) -> f64 {
+    let total = bounds.area();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    let covers: Vec<Region> = obscuring
+        .iter()
+        .filter_map(|r| r.intersect(bounds))
+        .collect();
+    if covers.is_empty() {
+        return 1.0;
+    }
+
+    // Split bounds along every cover edge; each cell is covered or not.
+    let mut xs = Vec::with_capacity(2 * covers.len() + 2);
+    let mut ys = Vec::with_capacity(2 * covers.len() + 2);
+    xs.extend([bounds.x, bounds.x + bounds.width]);
+    ys.extend([bounds.y, bounds.y + bounds.height]);
+    for r in &covers {
+        xs.extend([r.x, r.x + r.width]);
+        ys.extend([r.y, r.y + r.height]);
+    }
+    xs.sort_by(f64::total_cmp);
+    ys.sort_by(f64::total_cmp);
+    xs.dedup();
+    ys.dedup();
+
+    let mut covered = 0.0;
+    for x in xs.windows(2) {
+        for y in ys.windows(2) {
+            let (cx, cy) = ((x[0] + x[1]) / 2.0, (y[0] + y[1]) / 2.0);
+            let hit = covers
+                .iter()
+                .any(|r| cx > r.x && cx < r.x + r.width && cy > r.y && cy < r.y + r.height);
+            if hit {
+                covered += (x[1] - x[0]) * (y[1] - y[0]);
+            }
+        }
+    }
+    (1.0 - covered / total).clamp(0.0, 1.0)
+}
+
+/// SC 2.4.11 and 2.4.12: is the focused component hidden by author content
+/// such as sticky headers, cookie banners or non-modal dialogs?
+#[must_use]
+pub fn check_focus_not_obscured(component: &Component, obscuring: &[Region]) -> ComplianceReport {
+    let mut report = ComplianceReport::new(STANDARD);
+    let visible = visible_fraction(&component.bounds, obscuring);
+
+    if visible <= 0.0 {
+        report.add_violation(violation(
+            "WCAG_2.4.11_focus_obscured",
+            ViolationSeverity::Error,
+            visible,
+            0.0,
+            "Focused component is entirely hidden by other content",
+        ));
+    } else if visible < 1.0 {
+        report.add_violation(violation(
+            "WCAG_2.4.12_focus_partially_obscured",
+            ViolationSeverity::Warning,
+            visible,
+            1.0,
+            "Part of the focused component is hidden by other content",
+        ));
+    }
+
+    report
+}
+
+/// Run every check in this module on one component.
+///
+/// Focus checks are skipped when `focus` is `None`.
+#[must_use]
+pub fn evaluate_component(
+    component: &Component,
+    focus: Option<&FocusIndicator>,
+    obscuring: &[Region],
+) -> ComplianceReport {
+    let mut report = check_non_text_contrast(component);
+    if let Some(indicator) = focus {
+        report.merge(check_focus_appearance(component, indicator));
+        report.merge(check_focus_not_obscured(component, obscuring));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(v: u8) -> Color {
+        Color::from_srgb8(v, v, v)
+    }
+
+    fn rules(report: &ComplianceReport) -> Vec<&'static str> {
+        report.violations.iter().map(|v| v.rule).collect()
+    }
+
+    fn button() -> Component {
+        Component::new(Region::new(10.0, 10.0, 100.0, 40.0), gray(255), gray(255))
+            .with_border(1.0, gray(118))
+    }
+
+    #[test]
+    fn test_boundary_from_border_or_fill() {
+        assert!(check_non_text_contrast(&button()).passes);
+
+        let filled = Component::new(Region::new(0.0, 0.0, 100.0, 40.0), gray(0), gray(255));
+        assert!(check_non_text_contrast(&filled).passes);
+
+        let ghost = Component::new(Region::new(0.0, 0.0, 100.0, 40.0), gray(240), gray(255))
+            .with_border(1.0, gray(200));
+        let report = check_non_text_contrast(&ghost);
+        assert_eq!(rules(&report), ["WCAG_1.4.11_component_boundary"]);
+        assert_eq!(report.violations[0].severity, ViolationSeverity::Error);
+    }
+
+    #[test]
+    fn test_state_indicator() {
+        let checkbox = button().with_state_indicator(gray(200));
+        assert_eq!(
+            rules(&check_non_text_contrast(&checkbox)),
+            ["WCAG_1.4.11_state_indicator"]
+        );
+    }
+
+    #[test]
+    fn test_indicator_area() {
+        let bounds = Region::new(0.0, 0.0, 100.0, 40.0);
+        let outline = FocusIndicator::outline(2.0, 0.0, gray(0), gray(255));
+        // (104 × 44) − (100 × 40)
+        assert_eq!(outline.area(&bounds), 576.0);
+        assert_eq!(button().focus_perimeter_area(), 560.0);
+
+        let inset = FocusIndicator {
+            shape: IndicatorShape::Border { width: 1.0 },
+            ..outline
+        };
+        assert_eq!(inset.area(&bounds), 100.0 * 40.0 - 98.0 * 38.0);
+    }
+
+    #[test]
+    fn test_focus_appearance_failures() {
+        // 1px light-blue outline: too thin, too faint.
+        let faint = FocusIndicator::outline(1.0, 2.0, Color::from_srgb8(147, 197, 253), gray(255));
+        let report = check_focus_appearance(&button(), &faint);
+
+        assert_eq!(
+            rules(&report),
+            [
+                "WCAG_1.4.11_focus_indicator",
+                "WCAG_2.4.13_area",
+                "WCAG_2.4.13_contrast"
+            ]
+        );
+        assert_eq!(report.worst_severity(), Some(ViolationSeverity::Error));
+    }
+
+    #[test]
+    fn test_outline_without_offset_must_contrast_with_edge() {
+        // Black outline touching a black border: blends into the edge.
+        let component = button().with_border(2.0, gray(0));
+        let outline = FocusIndicator::outline(2.0, 0.0, gray(20), gray(255));
+        let report = check_focus_appearance(&component, &outline);
+        assert_eq!(rules(&report), ["WCAG_1.4.11_focus_indicator"]);
+
+        let offset = FocusIndicator::outline(2.0, 2.0, gray(20), gray(255));
+        assert!(check_focus_appearance(&component, &offset).passes);
+    }
+
+    #[test]
+    fn test_visible_fraction() {
+        let bounds = Region::new(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(visible_fraction(&bounds, &[]), 1.0);
+
+        // Two overlapping banners covering the top 60%.
+        let covers = [
+            Region::new(-10.0, -10.0, 200.0, 50.0),
+            Region::new(0.0, 30.0, 100.0, 30.0),
+        ];
+        assert!((visible_fraction(&bounds, &covers) - 0.4).abs() < 1e-12);
+        assert_eq!(
+            visible_fraction(&bounds, &[Region::new(0.0, 0.0, 100.0, 100.0)]),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_focus_not_obscured() {
+        let header = Region::new(0.0, 0.0, 1000.0, 30.0);
+        let report = check_focus_not_obscured(&button(), &[header]);
+        assert_eq!(rules(&report), ["WCAG_2.4.12_focus_partially_obscured"]);
+
+        let modal = Region::new(0.0, 0.0, 1000.0, 1000.0);
+        let report = check_focus_not_obscured(&button(), &[modal]);
+        assert_eq!(rules(&report), ["WCAG_2.4.11_focus_obscured"]);
+        assert!(report.has_error_or_above());
+    }
+
+    #[test]
+    fn test_evaluate_component_merges_reports() {
+        let focus = FocusIndicator::outline(2.0, 2.0, Color::from_srgb8(37, 99, 235), gray(255));
+        assert!(evaluate_component(&button(), Some(&focus), &[]).passes);
+
+        let header = Region::new(0.0, 0.0, 1000.0, 30.0);
+        let report = evaluate_component(&button(), Some(&focus), &[header]);
+        assert_eq!(report.standard, STANDARD);
+        assert_eq!(rules(&report), ["WCAG_2.4.12_focus_partially_obscured"]);
+
+        assert!(evaluate_component(&button(), None, &[header]).passes);
+    }
+}
@@ -200,3 +200,45 @@ pub fn hct_max_chroma(hue: f64, tone: f64) -> f64 {
     }
     lo
 }
+
+/// Generate a Material Design 3 tonal palette from a source color.
+///
+/// Returns ARGB integers for tones 0, 10, 20, 30, 40, 50, 60, 70, 80, 90,
+/// 95, 99, 100, matching material-color-utilities `TonalPalette`.
+#[wasm_bindgen(js_name = "hctMaterialTonalPalette")]
+pub fn hct_material_tonal_palette(source_argb: u32) -> Box<[u32]> {
+    use momoto_core::space::hct::palette::TonalPalette;
+    Box::new(TonalPalette::from_argb(source_argb).standard_tones())
+}
+
+/// Generate a Material Design 3 dynamic color scheme.
+///
+/// # Arguments
+/// * `source_argb` — source color (0xAARRGGBB)
+/// * `variant` — "tonal-spot", "vibrant", "expressive", "content",
+///   "fidelity", "monochrome" or "neutral"
+/// * `is_dark` — dark theme
+/// * `contrast_level` — −1 (reduced) to 1 (high); 0 is standard
+///
+/// # Returns
+/// Object mapping role names (e.g. `primary`, `on_surface_variant`) to ARGB
+/// integers.
+#[wasm_bindgen(js_name = "hctDynamicScheme")]
+pub fn hct_dynamic_scheme(
+    source_argb: u32,
+    variant: &str,
+    is_dark: bool,
+    contrast_level: f64,
+) -> Result<JsValue, JsValue> {
+    use momoto_core::space::hct::dynamic::{DynamicScheme, MaterialDynamicColors, Variant};
+    use std::collections::BTreeMap;
+
+    let variant = Variant::from_name(variant)
+        .ok_or_else(|| JsValue::from_str(&format!("Unknown scheme variant: {}", variant)))?;
+    let scheme = DynamicScheme::from_argb(source_argb, variant, is_dark, contrast_level);
+    let roles: BTreeMap<&str, u32> = MaterialDynamicColors::all()
+        .iter()
+        .map(|role| (role.name, scheme.argb(role)))
+        .collect();
+    serde_wasm_bindgen::to_value(&roles).map_err(|e| JsValue::from_str(&e.to_string()))
+}
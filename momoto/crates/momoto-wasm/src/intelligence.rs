@@ -1006,12 +1006,79 @@ pub fn hex_to_oklch_wasm(hex: &str) -> Box<[f64]> {
     }
 }
 
+// =============================================================================
+// IMAGE QUANTIZATION BINDINGS
+// =============================================================================
+
+use momoto_intelligence::quantize::{
+    Quantizer as CoreQuantizer,
+    extract_seeds as core_extract_seeds,
+    palette_from_image as core_palette_from_image,
+};
+
+fn color_to_argb(color: &CoreColor) -> u32 {
+    let [r, g, b] = color.to_srgb8();
+    0xff00_0000 | (u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b)
+}
+
+/// Quantize an RGBA pixel buffer (e.g. `ImageData.data`).
+///
+/// # Arguments
+/// * `pixels` — RGBA bytes; pixels that aren't fully opaque are ignored
+/// * `max_colors` — maximum number of clusters
+/// * `algorithm` — `"wu"`, `"octree"` or `"kmeans"`
+///
+/// # Returns
+///
+/// Flat array `[argb0, population0, argb1, population1, ...]`, most
+/// populous first.
+#[wasm_bindgen(js_name = "quantizeImage")]
+pub fn quantize_image(pixels: &[u8], max_colors: u32, algorithm: &str) -> Result<Box<[u32]>, JsValue> {
+    let quantizer = CoreQuantizer::from_name(algorithm)
+        .ok_or_else(|| JsValue::from_str(&format!("Unknown quantizer: {}", algorithm)))?;
+    let clusters = quantizer.quantize(pixels, max_colors as usize);
+    let mut out = Vec::with_capacity(clusters.len() * 2);
+    for cluster in &clusters {
+        out.push(color_to_argb(&cluster.color));
+        out.push(cluster.population);
+    }
+    Ok(out.into_boxed_slice())
+}
+
+/// Extract up to `desired` theme seed colors from an RGBA pixel buffer.
+///
+/// Returns ARGB integers, best first. Never empty: images without a usable
+/// color yield the fallback blue `0xff4285f4`.
+#[wasm_bindgen(js_name = "extractSeedColors")]
+pub fn extract_seed_colors(pixels: &[u8], desired: u32) -> Box<[u32]> {
+    core_extract_seeds(pixels, desired as usize)
+        .iter()
+        .map(|seed| color_to_argb(&seed.color))
+        .collect()
+}
+
+/// Generate a harmony palette seeded by the best color in an RGBA buffer.
+///
+/// Returns flat array `[L0, C0, H0, L1, C1, H1, ...]`.
+#[wasm_bindgen(js_name = "generatePaletteFromImage")]
+pub fn generate_palette_from_image(pixels: &[u8], harmony: WasmHarmonyType) -> Box<[f64]> {
+    let palette = core_palette_from_image(pixels, harmony_from_wasm(harmony));
+    let mut out = Vec::with_capacity(palette.colors.len() * 3);
+    for color in &palette.colors {
+        out.push(color.l);
+        out.push(color.c);
+        out.push(color.h);
+    }
+    out.into_boxed_slice()
+}
+
 // =============================================================================
 // CVD (Color Vision Deficiency) BINDINGS
 // =============================================================================
 
 use momoto_core::color::cvd::{
     CVDType, simulate_cvd, cvd_delta_e,
+    simulate_cvd_with_severity, cvd_delta_e_with_severity,
     simulate_cvd_hex, parse_hex, to_hex,
 };
 
@@ -1019,7 +1086,8 @@ use momoto_core::color::cvd::{
 ///
 /// # Arguments
 /// * `hex` — input color as hex string (e.g. "#ff0000")
-/// * `cvd_type` — "protanopia", "deuteranopia", or "tritanopia"
+/// * `cvd_type` — "protanopia", "deuteranopia", "tritanopia", "protanomaly",
+///   "deuteranomaly", "tritanomaly", "achromatopsia" or "blue-cone-monochromacy"
 ///
 /// # Returns
 ///
@@ -1044,6 +1112,26 @@ pub fn cvd_delta_e_wasm(hex: &str, cvd_type: &str) -> f64 {
     cvd_delta_e(&color, cvd)
 }
 
+/// Simulate CVD at a given severity (0.0 = normal vision, 1.0 = full).
+///
+/// Returns the simulated hex string, or the original hex on error.
+#[wasm_bindgen(js_name = "simulateCVDWithSeverity")]
+pub fn simulate_cvd_with_severity_wasm(hex: &str, cvd_type: &str, severity: f64) -> String {
+    let (Some(cvd), Some(color)) = (CVDType::from_str(cvd_type), parse_hex(hex)) else {
+        return hex.to_string();
+    };
+    to_hex(&simulate_cvd_with_severity(&color, cvd, severity))
+}
+
+/// Perceptual ΔE between a color and its CVD simulation at a given severity.
+#[wasm_bindgen(js_name = "cvdDeltaEWithSeverity")]
+pub fn cvd_delta_e_with_severity_wasm(hex: &str, cvd_type: &str, severity: f64) -> f64 {
+    let (Some(cvd), Some(color)) = (CVDType::from_str(cvd_type), parse_hex(hex)) else {
+        return 0.0;
+    };
+    cvd_delta_e_with_severity(&color, cvd, severity)
+}
+
 /// Simulate CVD for an OKLCH color. Returns flat `[L, C, H]` of simulated color.
 #[wasm_bindgen(js_name = "simulateCVDOklch")]
 pub fn simulate_cvd_oklch(l: f64, c: f64, h: f64, cvd_type: &str) -> Box<[f64]> {
@@ -1068,8 +1156,11 @@ use momoto_intelligence::constraints::{
     ConstraintSolver as CoreConstraintSolver,
     ColorConstraint as CoreColorConstraint,
     ConstraintKind as CoreConstraintKind,
+    DeltaEMetric as CoreDeltaEMetric,
     SolverConfig as CoreSolverConfig,
 };
+use momoto_core::perception::Polarity as CorePolarity;
+use momoto_core::space::rgb::RgbSpace as CoreRgbSpace;
 
 /// Solve a set of color constraints for a palette.
 ///
@@ -1085,13 +1176,25 @@ use momoto_intelligence::constraints::{
 ///   {"colorIdx":0,"kind":"MinAPCA","otherIdx":1,"target":60.0},
 ///   {"colorIdx":0,"kind":"HarmonyAngle","otherIdx":1,"expectedDeltaH":180,"tolerance":5},
 ///   {"colorIdx":0,"kind":"InGamut"},
+///   {"colorIdx":0,"kind":"InGamut","space":"display-p3"},
 ///   {"colorIdx":0,"kind":"LightnessRange","min":0.7,"max":1.0},
-///   {"colorIdx":0,"kind":"ChromaRange","min":0.0,"max":0.2}
+///   {"colorIdx":0,"kind":"ChromaRange","min":0.0,"max":0.2},
+///   {"colorIdx":0,"kind":"MinDeltaE","otherIdx":1,"metric":"ciede2000","target":10},
+///   {"colorIdx":0,"kind":"MinCvdDeltaE","otherIdx":1,"cvd":"deuteranopia","metric":"ciede2000","target":10},
+///   {"colorIdx":1,"kind":"Locked"},
+///   {"colorIdx":0,"kind":"ApcaPolarity","otherIdx":1,"polarity":"dark-on-light"},
+///   {"colorIdx":0,"kind":"MaxDrift","reference":[0.6,0.15,30],"metric":"ciede2000","max":5}
 /// ]
 /// ```
 ///
+/// `metric` is optional (default `"ciede2000"`); `space` is any RGB space
+/// name such as `"srgb"`, `"display-p3"` or `"rec2020"`.
+///
 /// # Returns
-/// JSON `{colors:[L,C,H,...], converged:bool, iterations:number, finalPenalty:number, violations:[...]}`
+/// JSON `{colors:[L,C,H,...], converged:bool, iterations:number, finalPenalty:number, violations:[...], infeasible:[...]}`
+///
+/// `infeasible` lists indices into `constraints_json` of a minimal set of
+/// constraints that cannot be met together (empty when converged).
 #[wasm_bindgen(js_name = "solveColorConstraints")]
 pub fn solve_color_constraints(
     lch_flat: &[f64],
@@ -1106,10 +1209,12 @@ pub fn solve_color_constraints(
     let specs: Vec<serde_json::Value> = serde_json::from_str(constraints_json)
         .map_err(|e| JsValue::from_str(&format!("Invalid constraints JSON: {}", e)))?;
 
-    let constraints: Vec<CoreColorConstraint> = specs
+    // Keep each parsed constraint's position in the input for reporting.
+    let (spec_idx, constraints): (Vec<usize>, Vec<CoreColorConstraint>) = specs
         .iter()
-        .filter_map(parse_wasm_constraint)
-        .collect();
+        .enumerate()
+        .filter_map(|(i, spec)| Some((i, parse_wasm_constraint(spec)?)))
+        .unzip();
 
     let mut config = CoreSolverConfig::default();
     if max_iterations > 0 {
@@ -1127,6 +1232,7 @@ pub fn solve_color_constraints(
     let violations_json: Vec<serde_json::Value> = result.violations
         .iter()
         .map(|v| serde_json::json!({
+            "constraintIdx": spec_idx[v.constraint_idx],
             "colorIdx": v.color_idx,
             "description": v.description,
             "magnitude": v.magnitude,
@@ -1139,6 +1245,7 @@ pub fn solve_color_constraints(
         "iterations": result.iterations,
         "finalPenalty": result.final_penalty,
         "violations": violations_json,
+        "infeasible": result.infeasible.iter().map(|&k| spec_idx[k]).collect::<Vec<_>>(),
     });
 
     serde_wasm_bindgen::to_value(&out)
@@ -1163,7 +1270,10 @@ fn parse_wasm_constraint(c: &serde_json::Value) -> Option<CoreColorConstraint> {
             expected_delta_h: c["expectedDeltaH"].as_f64()?,
             tolerance: c["tolerance"].as_f64().unwrap_or(5.0),
         },
-        "InGamut" => CoreConstraintKind::InGamut,
+        "InGamut" => match c["space"].as_str() {
+            Some(name) => CoreConstraintKind::InGamutOf { space: CoreRgbSpace::from_name(name)? },
+            None => CoreConstraintKind::InGamut,
+        },
         "LightnessRange" => CoreConstraintKind::LightnessRange {
             min: c["min"].as_f64()?,
             max: c["max"].as_f64()?,
@@ -1172,8 +1282,48 @@ fn parse_wasm_constraint(c: &serde_json::Value) -> Option<CoreColorConstraint> {
             min: c["min"].as_f64()?,
             max: c["max"].as_f64()?,
         },
+        "MinDeltaE" => CoreConstraintKind::MinDeltaE {
+            other_idx: c["otherIdx"].as_u64()? as usize,
+            metric: parse_wasm_metric(c)?,
+            target: c["target"].as_f64()?,
+        },
+        "MinCvdDeltaE" => CoreConstraintKind::MinCvdDeltaE {
+            other_idx: c["otherIdx"].as_u64()? as usize,
+            cvd: CVDType::from_str(c["cvd"].as_str()?)?,
+            metric: parse_wasm_metric(c)?,
+            target: c["target"].as_f64()?,
+        },
+        "Locked" => CoreConstraintKind::Locked,
+        "ApcaPolarity" => CoreConstraintKind::ApcaPolarity {
+            other_idx: c["otherIdx"].as_u64()? as usize,
+            polarity: match c["polarity"].as_str()? {
+                "dark-on-light" | "DarkOnLight" => CorePolarity::DarkOnLight,
+                "light-on-dark" | "LightOnDark" => CorePolarity::LightOnDark,
+                _ => return None,
+            },
+        },
+        "MaxDrift" => {
+            let reference = c["reference"].as_array()?;
+            CoreConstraintKind::MaxDrift {
+                reference: CoreOKLCH::new(
+                    reference.first()?.as_f64()?,
+                    reference.get(1)?.as_f64()?,
+                    reference.get(2)?.as_f64()?,
+                ),
+                metric: parse_wasm_metric(c)?,
+                max: c["max"].as_f64()?,
+            }
+        }
         _ => return None,
     };
 
     Some(CoreColorConstraint { color_idx, kind })
 }
+
+/// Optional `"metric"` field of a constraint spec; CIEDE2000 when absent.
+fn parse_wasm_metric(c: &serde_json::Value) -> Option<CoreDeltaEMetric> {
+    match c["metric"].as_str() {
+        Some(name) => CoreDeltaEMetric::from_name(name),
+        None => Some(CoreDeltaEMetric::default()),
+    }
+}
@@ -80,10 +80,14 @@ pub use audio::*;
 // const results = wcag.evaluate_batch(foregrounds, backgrounds);
 // ```
 
+use momoto_core::color::blend::{BlendMode, CompositeSpace};
+use momoto_core::color::css::CssColorSpace;
 use momoto_core::color::Color as CoreColor;
 use momoto_core::material::GlassMaterial as CoreGlassMaterial;
 use momoto_core::perception::{ContrastMetric as CoreContrastMetric, Polarity as CorePolarity};
+use momoto_core::space::oklch::gamut::GamutMapping;
 use momoto_core::space::oklch::{HuePath as CoreHuePath, OKLCH as CoreOKLCH};
+use momoto_core::space::rgb::RgbSpace;
 use momoto_intelligence::context::{
     ComplianceTarget as CoreComplianceTarget, RecommendationContext as CoreRecommendationContext,
     UsageContext as CoreUsageContext,
@@ -334,6 +338,23 @@ impl Color {
         self.inner.to_hex()
     }
 
+    /// Parse any CSS Color 4/5 string (`oklch()`, `color(display-p3 …)`,
+    /// `color-mix()`, relative colors, named colors, …).
+    #[wasm_bindgen(js_name = fromCss)]
+    pub fn from_css(css: &str) -> Result<Color, JsValue> {
+        CoreColor::from_css(css)
+            .map(|inner| Color { inner })
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Serialize to CSS in the given color space (`rgb`, `oklch`, `lab`,
+    /// `display-p3`, …). Defaults to `rgb` for unknown spaces.
+    #[wasm_bindgen(js_name = toCss)]
+    pub fn to_css(&self, space: &str) -> String {
+        let space = CssColorSpace::from_name(space).unwrap_or(CssColorSpace::Rgb);
+        self.inner.to_css(space)
+    }
+
     // ========================================================================
     // Alpha Channel (Gap 1 - P1)
     // ========================================================================
@@ -364,6 +385,33 @@ impl Color {
         }
     }
 
+    /// Composite this color over `backdrop` (Porter-Duff source-over).
+    ///
+    /// # Arguments
+    /// * `backdrop` - Color underneath
+    /// * `linear` - Mix in linear light instead of gamma-encoded sRGB
+    ///   (browsers use sRGB)
+    pub fn over(&self, backdrop: &Color, linear: bool) -> Color {
+        let space = if linear {
+            CompositeSpace::Linear
+        } else {
+            CompositeSpace::Srgb
+        };
+        Color {
+            inner: self.inner.over_in(&backdrop.inner, space),
+        }
+    }
+
+    /// Blend this color onto `backdrop` with a CSS `mix-blend-mode`
+    /// (`multiply`, `screen`, `soft-light`, `luminosity`, …), then composite.
+    pub fn blend(&self, backdrop: &Color, mode: &str) -> Result<Color, JsValue> {
+        let mode = BlendMode::from_name(mode)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown blend mode: {}", mode)))?;
+        Ok(Color {
+            inner: self.inner.blend(&backdrop.inner, mode),
+        })
+    }
+
     // ========================================================================
     // Color Manipulation (convenience methods)
     // ========================================================================
@@ -7771,6 +7819,36 @@ impl GamutUtils {
         let oklch = CoreOKLCH::new(l, c, h).map_to_gamut();
         OKLCH { inner: oklch }
     }
+
+    /// Map OKLCH color into an RGB gamut with a named strategy.
+    ///
+    /// # Arguments
+    /// * `gamut` - `srgb`, `display-p3`, `rec2020` or `a98-rgb`
+    /// * `strategy` - `css` (CSS Color 4), `clip`, `preserve-lightness`,
+    ///   `cusp` or `adaptive-l0`
+    ///
+    /// # Returns
+    /// `[l, c, h, deltaE]` where `deltaE` is the ΔEOK cost of the mapping
+    #[wasm_bindgen(js_name = mapToGamutOf)]
+    pub fn map_to_gamut_of(
+        l: f64,
+        c: f64,
+        h: f64,
+        gamut: &str,
+        strategy: &str,
+    ) -> Result<Vec<f64>, JsValue> {
+        let space = RgbSpace::from_name(gamut)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown gamut '{}'", gamut)))?;
+        let strategy = GamutMapping::from_name(strategy)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown strategy '{}'", strategy)))?;
+        let result = CoreOKLCH::new(l, c, h).gamut_map(space, strategy);
+        Ok(vec![
+            result.color.l,
+            result.color.c,
+            result.color.h,
+            result.delta_e,
+        ])
+    }
 }
 
 // ============================================================================